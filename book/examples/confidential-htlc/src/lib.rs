@@ -1,17 +1,40 @@
 // pallets/confidential-htlc/src/lib.rs
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 extern crate alloc;
 
-use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT, scalar::Scalar};
+use frame_support::{
+    dispatch::DispatchResult,
+    pallet_prelude::*,
+    traits::{
+        UnixTime,
+        fungible::{self, MutateHold},
+        tokens::{Fortitude, Precision, Restriction},
+    },
+    transactional,
+};
 use frame_system::pallet_prelude::*;
+use parity_scale_codec::DecodeWithMemTracking;
 use scale_info::TypeInfo;
+use sp_runtime::traits::{Saturating, Zero};
 use sp_std::prelude::*;
 
 use confidential_assets_primitives::{
-    AdaptorSigBackend, BridgeHtlc, EncryptedAmount, EscrowTrust, InputProof,
+    AdaptorSigBackend, BridgeHtlc, ConfidentialBackend, EncryptedAmount, EscrowTrust, Expiry,
+    InputProof, ProofKind, ProofSystem,
 };
 
+/// Balance of the currency HTLC deposits are held in.
+pub type DepositBalanceOf<T> = <<T as pallet::Config>::Currency as fungible::Inspect<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -31,6 +54,44 @@ pub mod pallet {
         /// Crypto for hashlock + adaptor-signature math.
         type Crypto: AdaptorSigBackend;
 
+        type Balance: Parameter + MaxEncodedLen + TypeInfo; // only names the backend's balance
+
+        /// Backend behind the escrow; HTLCs of an asset it has paused cannot move.
+        type Backend: ConfidentialBackend<Self::AccountId, Self::AssetId, Self::Balance>;
+
+        /// Proof system of the escrow's transfer proofs, used to check the shape of
+        /// proofs stored for later, e.g. a partial redeem's `remainder_proof`.
+        type Proofs: ProofSystem;
+
+        /// Most HTLCs one `open_htlc_batch` call may open.
+        #[pallet::constant]
+        type MaxOpenPerBatch: Get<u32>;
+
+        /// Most blocks one `extend_expiry` may push an HTLC's expiry out by.
+        #[pallet::constant]
+        type MaxExpiryExtension: Get<BlockNumberFor<Self>>;
+
+        /// Most seconds one `extend_expiry` may push a wall-clock expiry out by.
+        #[pallet::constant]
+        type MaxExpiryExtensionSecs: Get<u64>;
+
+        /// Clock `Expiry::AtTime` is measured against, e.g. `pallet_timestamp::Pallet`.
+        type UnixTime: UnixTime;
+
+        /// Currency HTLC deposits are held in.
+        type Currency: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Held from the maker of each HTLC until its record is pruned.
+        #[pallet::constant]
+        type HtlcDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Blocks a redeemed or refunded HTLC is kept before `prune_htlc` may remove it.
+        #[pallet::constant]
+        type HtlcRetention: Get<BlockNumberFor<Self>>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -39,6 +100,13 @@ pub mod pallet {
         fn redeem_with_secret() -> Weight;
         fn redeem_with_adaptor_sig() -> Weight;
         fn refund() -> Weight;
+        fn redeem_partial_with_secret() -> Weight;
+        fn refund_expired() -> Weight;
+        fn extend_expiry() -> Weight;
+        fn prune_htlc() -> Weight;
+        fn open_htlc_batch(n: u32) -> Weight;
+        /// Sweeping one expired HTLC in `on_idle`.
+        fn expire() -> Weight;
     }
     impl WeightInfo for () {
         fn open_htlc() -> Weight {
@@ -53,6 +121,24 @@ pub mod pallet {
         fn refund() -> Weight {
             Weight::from_parts(30_000, 0)
         }
+        fn redeem_partial_with_secret() -> Weight {
+            Weight::from_parts(35_000, 0)
+        }
+        fn refund_expired() -> Weight {
+            Weight::from_parts(30_000, 0)
+        }
+        fn extend_expiry() -> Weight {
+            Weight::from_parts(15_000, 0)
+        }
+        fn prune_htlc() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn open_htlc_batch(n: u32) -> Weight {
+            Weight::from_parts(20_000, 0).saturating_mul(n.into())
+        }
+        fn expire() -> Weight {
+            Weight::from_parts(5_000, 0)
+        }
     }
 
     // ---------------------------
@@ -66,21 +152,149 @@ pub mod pallet {
         Refunded,
     }
 
+    /// Hash a hashlock is checked with, so an HTLC can pair with a counterparty on a
+    /// chain that fixes it, e.g. SHA-256 for Bitcoin and Lightning.
+    #[derive(
+        Clone,
+        Copy,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        MaxEncodedLen,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+    )]
+    pub enum HashAlgo {
+        Blake2b,
+        Sha256,
+        Keccak,
+        /// `RIPEMD160(SHA256(x))`, Bitcoin's `OP_HASH160`.
+        Ripemd160Sha256,
+    }
+
+    impl HashAlgo {
+        /// Hash `preimage`. The 20-byte RIPEMD-160 digest is zero-padded to 32 bytes.
+        pub fn hash(self, preimage: &[u8]) -> [u8; 32] {
+            match self {
+                HashAlgo::Blake2b => sp_io::hashing::blake2_256(preimage),
+                HashAlgo::Sha256 => sp_io::hashing::sha2_256(preimage),
+                HashAlgo::Keccak => sp_io::hashing::keccak_256(preimage),
+                HashAlgo::Ripemd160Sha256 => {
+                    use ripemd::{Digest, Ripemd160};
+                    let digest = Ripemd160::digest(sp_io::hashing::sha2_256(preimage));
+                    let mut out = [0u8; 32];
+                    out[..20].copy_from_slice(&digest);
+                    out
+                }
+            }
+        }
+    }
+
+    /// What redeeming an HTLC must reveal.
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        MaxEncodedLen,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+    )]
+    pub enum HtlcLock<HashLock> {
+        /// A preimage of `hashlock` under `hash_algo`, or `Config::Crypto` if `None`.
+        Hash {
+            hashlock: HashLock,
+            hash_algo: Option<HashAlgo>,
+        },
+        /// A PTLC: the scalar `x` with `x·G` equal to this compressed Ristretto point.
+        /// Unlike a hash it can be tweaked per chain, so the legs of a swap do not share
+        /// a lock that links them, and it is what an adaptor signature reveals.
+        Point([u8; 32]),
+    }
+
+    /// One HTLC to open with `open_htlc_batch`, as the arguments of `open_htlc`.
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        MaxEncodedLen,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+    )]
+    pub struct OpenParams<AccountId, AssetId, BlockNumber, HashLock, Balance> {
+        pub taker: Option<AccountId>,
+        pub asset: AssetId,
+        pub delta: EncryptedAmount,
+        pub proof: InputProof,
+        pub lock: HtlcLock<HashLock>,
+        pub expiry: Expiry<BlockNumber>,
+        pub adaptor_partial: Option<BoundedVec<u8, ConstU32<64>>>,
+        /// Required with `adaptor_partial`, which is verified against it.
+        pub adaptor_check: Option<AdaptorCheck>,
+        /// Paid to whoever submits the redeem or refund.
+        pub bounty: Balance,
+    }
+
+    /// Longest message an adaptor pre-signature is checked against at open.
+    pub const MAX_ADAPTOR_MSG_LEN: u32 = 256;
+
+    /// The message and signer key an adaptor pre-signature must verify under, in the
+    /// encoding `Config::Crypto` expects, e.g. with the adaptor point appended.
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        MaxEncodedLen,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+    )]
+    pub struct AdaptorCheck {
+        pub msg: BoundedVec<u8, ConstU32<MAX_ADAPTOR_MSG_LEN>>,
+        pub pubkey: [u8; 32],
+    }
+
     #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug)]
-    pub struct Htlc<AccountId, AssetId, BlockNumber, HashLock> {
+    pub struct Htlc<AccountId, AssetId, BlockNumber, HashLock, Balance> {
         pub maker: AccountId,
         pub taker: Option<AccountId>,
         pub asset: AssetId,
         pub param: EscrowParam, // (EncryptedAmount, InputProof)
-        pub hashlock: HashLock,
-        pub expiry: BlockNumber,
+        /// Never `Expiry::AfterBlocks`, which is resolved when the HTLC opens.
+        pub expiry: Expiry<BlockNumber>,
         pub adaptor_partial: Option<BoundedVec<u8, ConstU32<64>>>, // 64 bytes expected (opaque)
         pub state: HtlcState,
+        /// An expiry one party proposed and the other has yet to agree to.
+        pub pending_extension: Option<(Expiry<BlockNumber>, AccountId)>,
+        /// Held from the maker until the record is pruned.
+        pub deposit: Balance,
+        /// Held from the maker and paid to whoever closes the HTLC. With one, anyone may
+        /// submit the redeem, though the funds still go to the taker.
+        pub bounty: Balance,
+        /// Block the HTLC was redeemed or refunded at.
+        pub closed_at: Option<BlockNumber>,
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit for an HTLC record, released when it is pruned.
+        HtlcDeposit,
+        /// Relayer bounty of an HTLC, paid out when it closes.
+        RelayerBounty,
+    }
+
     /// Monotonic HTLC id counter.
     #[pallet::storage]
     pub(super) type NextId<T: Config> = StorageValue<_, u64, ValueQuery>;
@@ -96,10 +310,34 @@ pub mod pallet {
             T::AssetId,
             BlockNumberFor<T>,
             <T::Crypto as AdaptorSigBackend>::HashLock,
+            DepositBalanceOf<T>,
         >,
         OptionQuery,
     >;
 
+    /// htlc_id -> `blake2_256` of the SCALE-encoded final record of a pruned HTLC, kept
+    /// so its outcome can still be proven in a dispute.
+    #[pallet::storage]
+    pub(super) type ArchivedHtlcs<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, [u8; 32], OptionQuery>;
+
+    /// HTLCs by the block they expire at, for the `on_idle` sweep. Entries of HTLCs
+    /// closed meanwhile are skipped.
+    #[pallet::storage]
+    pub(super) type ExpiredHtlcs<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, u64, (), OptionQuery>;
+
+    /// First block whose `ExpiredHtlcs` have not been swept yet.
+    #[pallet::storage]
+    pub(super) type NextExpirySweep<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_expired(now, remaining_weight)
+        }
+    }
+
     // ---------------------------
     // Events / Errors
     // ---------------------------
@@ -113,7 +351,9 @@ pub mod pallet {
             taker: Option<T::AccountId>,
             asset: T::AssetId,
             param: EscrowParam,
-            expiry: BlockNumberFor<T>,
+            lock: HtlcLock<<T::Crypto as AdaptorSigBackend>::HashLock>,
+            expiry: Expiry<BlockNumberFor<T>>,
+            bounty: DepositBalanceOf<T>,
         },
         HtlcRedeemed {
             id: u64,
@@ -124,6 +364,46 @@ pub mod pallet {
             id: u64,
             maker: T::AccountId,
         },
+        /// Part of an HTLC was redeemed; `remaining` stays locked under the same lock.
+        HtlcPartiallyRedeemed {
+            id: u64,
+            redeemer: T::AccountId,
+            released: EncryptedAmount,
+            remaining: EncryptedAmount,
+            secret: Vec<u8>,
+        },
+        /// An HTLC passed its expiry unredeemed; anyone may now `refund_expired` it.
+        HtlcExpired {
+            id: u64,
+            maker: T::AccountId,
+        },
+        /// One party proposed a later expiry; it applies once the other agrees.
+        ExpiryExtensionProposed {
+            id: u64,
+            who: T::AccountId,
+            expiry: Expiry<BlockNumberFor<T>>,
+        },
+        ExpiryExtended {
+            id: u64,
+            expiry: Expiry<BlockNumberFor<T>>,
+        },
+        /// `count` HTLCs were opened by one `open_htlc_batch`, with ids from `first`.
+        HtlcBatchOpened {
+            maker: T::AccountId,
+            first: u64,
+            count: u32,
+        },
+        /// The relayer bounty of a closed HTLC went to `to`, who submitted the close.
+        BountyPaid {
+            id: u64,
+            to: T::AccountId,
+            amount: DepositBalanceOf<T>,
+        },
+        /// A closed HTLC's record was removed, leaving `digest` in `ArchivedHtlcs`.
+        HtlcPruned {
+            id: u64,
+            digest: [u8; 32],
+        },
     }
 
     #[pallet::error]
@@ -136,6 +416,22 @@ pub mod pallet {
         BadSignature,
         Arithmetic,
         MalformedSignature,
+        AssetPaused,
+        /// The released portion could not be split off the locked ciphertext.
+        InvalidSplit,
+        /// The escrow refused to release the redeemed amount, e.g. a stale proof.
+        EscrowReleaseFailed,
+        /// A proof stored for later is not a well-formed transfer proof.
+        MalformedProof,
+        /// The new expiry is not a later one of the same kind, or exceeds
+        /// `MaxExpiryExtension` or `MaxExpiryExtensionSecs`.
+        InvalidExtension,
+        /// The HTLC has not been redeemed or refunded.
+        NotClosed,
+        /// `HtlcRetention` has not passed since the HTLC closed.
+        RetentionNotElapsed,
+        /// An adaptor pre-signature was given without the message and key to check it.
+        AdaptorUnchecked,
     }
 
     impl<T: Config> Pallet<T> {
@@ -148,33 +444,59 @@ pub mod pallet {
             arr.copy_from_slice(bytes);
             Ok(arr)
         }
-    }
 
-    // ---------------------------
-    // Calls (extrinsics)
-    // ---------------------------
+        #[inline]
+        fn ensure_not_paused(asset: T::AssetId) -> Result<(), Error<T>> {
+            ensure!(!T::Backend::is_paused(asset), Error::<T>::AssetPaused);
+            Ok(())
+        }
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Maker opens + funds an HTLC. Escrows the (Δ, proof).
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::open_htlc())]
-        pub fn open_htlc(
-            origin: OriginFor<T>,
-            taker: Option<T::AccountId>,
-            asset: T::AssetId,
-            delta: EncryptedAmount,
-            proof: InputProof,
-            hashlock: <T::Crypto as AdaptorSigBackend>::HashLock,
-            expiry: BlockNumberFor<T>,
-            adaptor_partial: Option<Vec<u8>>,
-        ) -> DispatchResult {
-            let maker = ensure_signed(origin)?;
+        /// Escrow and record an HTLC for `maker`, returning its id.
+        fn do_open(
+            maker: &T::AccountId,
+            params: OpenParams<
+                T::AccountId,
+                T::AssetId,
+                BlockNumberFor<T>,
+                <T::Crypto as AdaptorSigBackend>::HashLock,
+                DepositBalanceOf<T>,
+            >,
+        ) -> Result<u64, DispatchError> {
+            let OpenParams {
+                taker,
+                asset,
+                delta,
+                proof,
+                lock,
+                expiry,
+                adaptor_partial,
+                adaptor_check,
+                bounty,
+            } = params;
             let param: EscrowParam = (delta, proof);
+            let expiry = Self::resolve_expiry(expiry);
+
+            // Catch a bad adaptor now, not when the swap is already under way
+            if let Some(partial) = &adaptor_partial {
+                let check = adaptor_check.ok_or(Error::<T>::AdaptorUnchecked)?;
+                let partial = Self::vec_to_array_64(partial)?;
+                <T::Crypto as AdaptorSigBackend>::verify_adaptor_sig(
+                    &check.msg,
+                    &check.pubkey,
+                    &partial,
+                )
+                .map_err(|_| Error::<T>::BadSignature)?;
+            }
 
             // Lock into escrow
-            T::Escrow::escrow_lock(asset, &maker, param.clone())
+            Self::ensure_not_paused(asset)?;
+            T::Escrow::escrow_lock(asset, maker, param.clone())
                 .map_err(|_| Error::<T>::Arithmetic)?;
+            let deposit = T::HtlcDeposit::get();
+            T::Currency::hold(&HoldReason::HtlcDeposit.into(), maker, deposit)?;
+            if !bounty.is_zero() {
+                T::Currency::hold(&HoldReason::RelayerBounty.into(), maker, bounty)?;
+            }
 
             // Store HTLC
             let id = NextId::<T>::mutate(|x| {
@@ -183,37 +505,236 @@ pub mod pallet {
                 id
             });
 
-            // Bound adaptor bytes (if present)
-            let adaptor_bounded: Option<BoundedVec<_, ConstU32<64>>> = match adaptor_partial {
-                Some(bytes) => Some(bytes.try_into().map_err(|_| Error::<T>::Arithmetic)?),
-                None => None,
-            };
-
-            let rec = Htlc::<T::AccountId, T::AssetId, BlockNumberFor<T>, _> {
+            let rec = Htlc::<T::AccountId, T::AssetId, BlockNumberFor<T>, _, _> {
                 maker: maker.clone(),
-                taker,
+                taker: taker.clone(),
                 asset,
                 param: param.clone(),
-                hashlock,
+                lock: lock.clone(),
                 expiry,
-                adaptor_partial: adaptor_bounded,
+                adaptor_partial,
                 state: HtlcState::Open,
+                pending_extension: None,
+                deposit,
+                bounty,
+                closed_at: None,
             };
             Htlcs::<T>::insert(id, rec);
+            Self::schedule_expiry(id, &expiry);
 
-            let taker_for_event = Htlcs::<T>::get(id).and_then(|r| r.taker);
             Self::deposit_event(Event::HtlcOpened {
                 id,
-                maker,
-                taker: taker_for_event,
+                maker: maker.clone(),
+                taker,
                 asset,
                 param,
+                lock,
                 expiry,
+                bounty,
             });
+            Ok(id)
+        }
+
+        /// Close HTLC `id` in `state`, starting its retention period, and pay its bounty
+        /// to `submitter`.
+        fn close(
+            id: u64,
+            rec: &mut Htlc<
+                T::AccountId,
+                T::AssetId,
+                BlockNumberFor<T>,
+                <T::Crypto as AdaptorSigBackend>::HashLock,
+                DepositBalanceOf<T>,
+            >,
+            state: HtlcState,
+            submitter: &T::AccountId,
+        ) {
+            rec.state = state;
+            rec.closed_at = Some(frame_system::Pallet::<T>::block_number());
+            if rec.bounty.is_zero() {
+                return;
+            }
+            // Never blocks the close: whatever is still held is paid
+            let reason = HoldReason::RelayerBounty.into();
+            let paid = if submitter == &rec.maker {
+                T::Currency::release(&reason, &rec.maker, rec.bounty, Precision::BestEffort)
+            } else {
+                T::Currency::transfer_on_hold(
+                    &reason,
+                    &rec.maker,
+                    submitter,
+                    rec.bounty,
+                    Precision::BestEffort,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )
+            };
+            if let Ok(amount) = paid {
+                Self::deposit_event(Event::BountyPaid {
+                    id,
+                    to: submitter.clone(),
+                    amount,
+                });
+            }
+        }
+
+        /// `expiry` with a relative block count resolved against the current block.
+        fn resolve_expiry(expiry: Expiry<BlockNumberFor<T>>) -> Expiry<BlockNumberFor<T>> {
+            match expiry {
+                Expiry::AfterBlocks(blocks) => Expiry::AtBlock(
+                    frame_system::Pallet::<T>::block_number().saturating_add(blocks),
+                ),
+                expiry => expiry,
+            }
+        }
+
+        /// Whether `expiry` has passed, and refunds are valid.
+        fn is_expired(expiry: &Expiry<BlockNumberFor<T>>) -> bool {
+            match *expiry {
+                Expiry::AtBlock(block) | Expiry::AfterBlocks(block) => {
+                    frame_system::Pallet::<T>::block_number() >= block
+                }
+                Expiry::AtTime(secs) => T::UnixTime::now().as_secs() >= secs,
+            }
+        }
+
+        /// Index HTLC `id` for the sweep at its expiry block, or now if that has already
+        /// passed. Wall-clock expiries are not indexed; `refund_expired` checks them as
+        /// it is called.
+        fn schedule_expiry(id: u64, expiry: &Expiry<BlockNumberFor<T>>) {
+            if let Expiry::AtBlock(block) = *expiry {
+                let block = block.max(frame_system::Pallet::<T>::block_number());
+                ExpiredHtlcs::<T>::insert(block, id, ());
+            }
+        }
+
+        /// Emit `HtlcExpired` for the open HTLCs whose expiry block has passed, as far as
+        /// `remaining_weight` allows, resuming where the last sweep stopped.
+        pub(crate) fn sweep_expired(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let per_block = db.reads(1);
+            let per_htlc = T::WeightInfo::expire();
+            let mut used = db.reads_writes(1, 1);
+            if remaining_weight.any_lt(used.saturating_add(per_block)) {
+                return Weight::zero();
+            }
+
+            let mut block = NextExpirySweep::<T>::get();
+            while block <= now && used.saturating_add(per_block).all_lte(remaining_weight) {
+                used.saturating_accrue(per_block);
+                let budget: usize = remaining_weight
+                    .saturating_sub(used)
+                    .checked_div_per_component(&per_htlc)
+                    .unwrap_or(u64::MAX)
+                    .try_into()
+                    .unwrap_or(usize::MAX);
+                let due: Vec<_> = ExpiredHtlcs::<T>::iter_key_prefix(block)
+                    .take(budget.saturating_add(1))
+                    .collect();
+                let finished = due.len() <= budget;
+                for id in due.into_iter().take(budget) {
+                    used.saturating_accrue(per_htlc);
+                    ExpiredHtlcs::<T>::remove(block, id);
+                    // An HTLC extended since it was indexed is due again at its new expiry
+                    let open = Htlcs::<T>::get(id).filter(|rec| {
+                        rec.state == HtlcState::Open
+                            && matches!(rec.expiry, Expiry::AtBlock(due) if due <= block)
+                    });
+                    if let Some(rec) = open {
+                        Self::deposit_event(Event::HtlcExpired {
+                            id,
+                            maker: rec.maker,
+                        });
+                    }
+                }
+                if !finished {
+                    break;
+                }
+                block.saturating_inc();
+            }
+            NextExpirySweep::<T>::put(block);
+            used
+        }
+
+        /// Whether `secret` opens `lock`. A `hash_algo` hashes the SCALE-encoded secret,
+        /// the raw bytes for a `[u8; 32]` one, and compares it with the encoded hashlock;
+        /// a point lock reads those bytes as a canonical scalar.
+        fn unlocks(
+            lock: &HtlcLock<<T::Crypto as AdaptorSigBackend>::HashLock>,
+            secret: &<T::Crypto as AdaptorSigBackend>::Secret,
+        ) -> bool {
+            match lock {
+                HtlcLock::Hash {
+                    hashlock,
+                    hash_algo: None,
+                } => <T::Crypto as AdaptorSigBackend>::hash_secret(secret) == *hashlock,
+                HtlcLock::Hash {
+                    hashlock,
+                    hash_algo: Some(algo),
+                } => algo.hash(&secret.encode())[..] == hashlock.encode()[..],
+                HtlcLock::Point(point) => {
+                    let Ok(bytes) = <[u8; 32]>::try_from(secret.encode()) else {
+                        return false;
+                    };
+                    let Some(x) = Option::<Scalar>::from(Scalar::from_canonical_bytes(bytes))
+                    else {
+                        return false;
+                    };
+                    (RISTRETTO_BASEPOINT_POINT * x).compress().to_bytes() == *point
+                }
+            }
+        }
+    }
+
+    // ---------------------------
+    // Calls (extrinsics)
+    // ---------------------------
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Maker opens + funds an HTLC. Escrows the (Δ, proof). `lock` is the hashlock, with
+        /// the hash it is checked with, or the point of a PTLC. An `adaptor_partial` must be
+        /// 64 bytes and verify under `adaptor_check`. A non-zero `bounty` is held from the
+        /// maker for whoever submits the redeem or refund, e.g. a relayer acting for a
+        /// taker that cannot reach this chain.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::open_htlc())]
+        pub fn open_htlc(
+            origin: OriginFor<T>,
+            taker: Option<T::AccountId>,
+            asset: T::AssetId,
+            delta: EncryptedAmount,
+            proof: InputProof,
+            lock: HtlcLock<<T::Crypto as AdaptorSigBackend>::HashLock>,
+            expiry: Expiry<BlockNumberFor<T>>,
+            adaptor_partial: Option<Vec<u8>>,
+            adaptor_check: Option<AdaptorCheck>,
+            bounty: DepositBalanceOf<T>,
+        ) -> DispatchResult {
+            let maker = ensure_signed(origin)?;
+            // Bound adaptor bytes (if present)
+            let adaptor_partial = match adaptor_partial {
+                Some(bytes) => Some(bytes.try_into().map_err(|_| Error::<T>::Arithmetic)?),
+                None => None,
+            };
+            Self::do_open(
+                &maker,
+                OpenParams {
+                    taker,
+                    asset,
+                    delta,
+                    proof,
+                    lock,
+                    expiry,
+                    adaptor_partial,
+                    adaptor_check,
+                    bounty,
+                },
+            )?;
             Ok(())
         }
 
-        /// Redeem with preimage `secret`. `who` must be the taker if specified, else anyone presenting the valid secret.
+        /// Redeem with `secret`, the preimage or scalar the lock asks for. `who` must be the taker if specified, else anyone presenting the valid secret.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::redeem_with_secret())]
         pub fn redeem_with_secret(
@@ -225,19 +746,22 @@ pub mod pallet {
             let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
             ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
             if let Some(taker) = &rec.taker {
-                ensure!(&who == taker, Error::<T>::NotAuthorized);
+                ensure!(
+                    &who == taker || !rec.bounty.is_zero(),
+                    Error::<T>::NotAuthorized
+                );
             }
 
-            // Check hashlock
-            let h = <T::Crypto as AdaptorSigBackend>::hash_secret(&secret);
-            ensure!(h == rec.hashlock, Error::<T>::BadSecret);
+            // Check the lock
+            ensure!(Self::unlocks(&rec.lock, &secret), Error::<T>::BadSecret);
 
             // Release escrow to taker (or to `who`)
             let to = rec.taker.as_ref().unwrap_or(&who);
+            Self::ensure_not_paused(rec.asset)?;
             T::Escrow::escrow_release(rec.asset, to, rec.param.clone())
-                .map_err(|_| Error::<T>::Arithmetic)?;
+                .map_err(|_| Error::<T>::EscrowReleaseFailed)?;
 
-            rec.state = HtlcState::Redeemed;
+            Self::close(htlc_id, &mut rec, HtlcState::Redeemed, &who);
             Htlcs::<T>::insert(htlc_id, &rec);
 
             // Emit secret bytes so the *other* chain can learn it (bridge watches this)
@@ -251,7 +775,7 @@ pub mod pallet {
         }
 
         /// Redeem with final signature. Pallet recovers the secret using (partial, final),
-        /// verifies hashlock, releases escrow, and emits the recovered secret.
+        /// verifies the lock, releases escrow, and emits the recovered secret.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::redeem_with_adaptor_sig())]
         pub fn redeem_with_adaptor_sig(
@@ -263,7 +787,10 @@ pub mod pallet {
             let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
             ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
             if let Some(taker) = &rec.taker {
-                ensure!(&who == taker, Error::<T>::NotAuthorized);
+                ensure!(
+                    &who == taker || !rec.bounty.is_zero(),
+                    Error::<T>::NotAuthorized
+                );
             }
 
             // Get the stored adaptor partial (must be 64 bytes)
@@ -285,16 +812,16 @@ pub mod pallet {
             )
             .map_err(|_| Error::<T>::BadSignature)?;
 
-            // Check hashlock
-            let h = <T::Crypto as AdaptorSigBackend>::hash_secret(&secret);
-            ensure!(h == rec.hashlock, Error::<T>::BadSecret);
+            // Check the lock
+            ensure!(Self::unlocks(&rec.lock, &secret), Error::<T>::BadSecret);
 
             // Release escrow to taker (or `who`)
             let to = rec.taker.as_ref().unwrap_or(&who);
+            Self::ensure_not_paused(rec.asset)?;
             T::Escrow::escrow_release(rec.asset, to, rec.param.clone())
-                .map_err(|_| Error::<T>::Arithmetic)?;
+                .map_err(|_| Error::<T>::EscrowReleaseFailed)?;
 
-            rec.state = HtlcState::Redeemed;
+            Self::close(htlc_id, &mut rec, HtlcState::Redeemed, &who);
             Htlcs::<T>::insert(htlc_id, &rec);
 
             let secret_bytes = secret.encode();
@@ -314,15 +841,13 @@ pub mod pallet {
             let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
             ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
             ensure!(who == rec.maker, Error::<T>::NotAuthorized);
-            ensure!(
-                frame_system::Pallet::<T>::block_number() >= rec.expiry,
-                Error::<T>::NotYetExpired
-            );
+            ensure!(Self::is_expired(&rec.expiry), Error::<T>::NotYetExpired);
 
+            Self::ensure_not_paused(rec.asset)?;
             T::Escrow::escrow_refund(rec.asset, &rec.maker, rec.param.clone())
                 .map_err(|_| Error::<T>::Arithmetic)?;
 
-            rec.state = HtlcState::Refunded;
+            Self::close(htlc_id, &mut rec, HtlcState::Refunded, &who);
             Htlcs::<T>::insert(htlc_id, &rec);
 
             Self::deposit_event(Event::HtlcRefunded {
@@ -331,6 +856,214 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Redeem part of an HTLC with `secret`, for fills taken in tranches. `released` is
+        /// moved out of escrow with `release_proof`; what is left, the locked ciphertext
+        /// minus `released`, stays open under the same lock and expiry, and
+        /// `remainder_proof` is the proof it is later redeemed or refunded with. Only its
+        /// shape is checked here; whether it moves the remainder is only known when it
+        /// is used. The secret is public from here on, so an HTLC without a taker can be
+        /// redeemed by anyone.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::redeem_partial_with_secret())]
+        pub fn redeem_partial_with_secret(
+            origin: OriginFor<T>,
+            htlc_id: u64,
+            secret: <T::Crypto as AdaptorSigBackend>::Secret,
+            released: EncryptedAmount,
+            release_proof: InputProof,
+            remainder_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
+            ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
+            if let Some(taker) = &rec.taker {
+                ensure!(&who == taker, Error::<T>::NotAuthorized);
+            }
+
+            ensure!(Self::unlocks(&rec.lock, &secret), Error::<T>::BadSecret);
+
+            // Split homomorphically: what is left is exactly the lock minus the release
+            let remaining = rec
+                .param
+                .0
+                .sub(&released)
+                .map_err(|_| Error::<T>::InvalidSplit)?;
+
+            // A remainder proof that cannot even be parsed would leave the rest
+            // unredeemable and unrefundable
+            T::Proofs::open(ProofKind::Transfer, &remainder_proof)
+                .map_err(|_| Error::<T>::MalformedProof)?;
+
+            let to = rec.taker.as_ref().unwrap_or(&who);
+            Self::ensure_not_paused(rec.asset)?;
+            T::Escrow::escrow_release(rec.asset, to, (released, release_proof))
+                .map_err(|_| Error::<T>::EscrowReleaseFailed)?;
+
+            rec.param = (remaining, remainder_proof);
+            Htlcs::<T>::insert(htlc_id, &rec);
+
+            Self::deposit_event(Event::HtlcPartiallyRedeemed {
+                id: htlc_id,
+                redeemer: who,
+                released,
+                remaining,
+                secret: secret.encode(),
+            });
+            Ok(())
+        }
+
+        /// Refund an expired HTLC to its maker, with the proof stored when it was
+        /// opened. Anyone may submit it, so funds need not wait for the maker.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::refund_expired())]
+        pub fn refund_expired(origin: OriginFor<T>, htlc_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
+            ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
+            ensure!(Self::is_expired(&rec.expiry), Error::<T>::NotYetExpired);
+
+            Self::ensure_not_paused(rec.asset)?;
+            T::Escrow::escrow_refund(rec.asset, &rec.maker, rec.param.clone())
+                .map_err(|_| Error::<T>::Arithmetic)?;
+
+            Self::close(htlc_id, &mut rec, HtlcState::Refunded, &who);
+            Htlcs::<T>::insert(htlc_id, &rec);
+
+            Self::deposit_event(Event::HtlcRefunded {
+                id: htlc_id,
+                maker: rec.maker,
+            });
+            Ok(())
+        }
+
+        /// Push an HTLC's expiry out to `new_expiry`, of the same kind, by at most
+        /// `MaxExpiryExtension` blocks or `MaxExpiryExtensionSecs` seconds.
+        /// Both parties have to ask for the same expiry: the first call records it and the
+        /// second applies it. An HTLC without a taker is extended by its maker alone.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::extend_expiry())]
+        pub fn extend_expiry(
+            origin: OriginFor<T>,
+            htlc_id: u64,
+            new_expiry: Expiry<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
+            ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
+            ensure!(
+                who == rec.maker || rec.taker.as_ref() == Some(&who),
+                Error::<T>::NotAuthorized
+            );
+            let new_expiry = Self::resolve_expiry(new_expiry);
+            let in_bounds = match (rec.expiry, new_expiry) {
+                (Expiry::AtBlock(old), Expiry::AtBlock(new)) => {
+                    new > old && new - old <= T::MaxExpiryExtension::get()
+                }
+                (Expiry::AtTime(old), Expiry::AtTime(new)) => {
+                    new > old && new - old <= T::MaxExpiryExtensionSecs::get()
+                }
+                _ => false,
+            };
+            ensure!(in_bounds, Error::<T>::InvalidExtension);
+
+            let agreed = match (&rec.taker, &rec.pending_extension) {
+                (None, _) => true,
+                (Some(_), Some((expiry, proposer))) => *expiry == new_expiry && *proposer != who,
+                (Some(_), None) => false,
+            };
+            if !agreed {
+                rec.pending_extension = Some((new_expiry, who.clone()));
+                Htlcs::<T>::insert(htlc_id, &rec);
+                Self::deposit_event(Event::ExpiryExtensionProposed {
+                    id: htlc_id,
+                    who,
+                    expiry: new_expiry,
+                });
+                return Ok(());
+            }
+
+            if let Expiry::AtBlock(block) = rec.expiry {
+                ExpiredHtlcs::<T>::remove(block, htlc_id);
+            }
+            rec.expiry = new_expiry;
+            rec.pending_extension = None;
+            Htlcs::<T>::insert(htlc_id, &rec);
+            Self::schedule_expiry(htlc_id, &new_expiry);
+
+            Self::deposit_event(Event::ExpiryExtended {
+                id: htlc_id,
+                expiry: new_expiry,
+            });
+            Ok(())
+        }
+
+        /// Remove a redeemed or refunded HTLC once `HtlcRetention` has passed since it
+        /// closed, releasing the maker's deposit and archiving a digest of its final
+        /// record. Anyone may call it.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::prune_htlc())]
+        pub fn prune_htlc(origin: OriginFor<T>, htlc_id: u64) -> DispatchResult {
+            ensure_signed(origin)?;
+            let rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
+            let closed_at = rec.closed_at.ok_or(Error::<T>::NotClosed)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number()
+                    >= closed_at.saturating_add(T::HtlcRetention::get()),
+                Error::<T>::RetentionNotElapsed
+            );
+
+            // Never blocks pruning: whatever is still held is returned
+            let _ = T::Currency::release(
+                &HoldReason::HtlcDeposit.into(),
+                &rec.maker,
+                rec.deposit,
+                Precision::BestEffort,
+            );
+
+            let digest = sp_io::hashing::blake2_256(&rec.encode());
+            Htlcs::<T>::remove(htlc_id);
+            ArchivedHtlcs::<T>::insert(htlc_id, digest);
+
+            Self::deposit_event(Event::HtlcPruned {
+                id: htlc_id,
+                digest,
+            });
+            Ok(())
+        }
+
+        /// Open several HTLCs from the caller in one transaction, e.g. a swap coordinator
+        /// posting offers. They get consecutive ids, starting at `first` in the
+        /// `HtlcBatchOpened` event; if any fails to open, none do.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::open_htlc_batch(htlcs.len() as u32))]
+        #[transactional]
+        pub fn open_htlc_batch(
+            origin: OriginFor<T>,
+            htlcs: BoundedVec<
+                OpenParams<
+                    T::AccountId,
+                    T::AssetId,
+                    BlockNumberFor<T>,
+                    <T::Crypto as AdaptorSigBackend>::HashLock,
+                    DepositBalanceOf<T>,
+                >,
+                T::MaxOpenPerBatch,
+            >,
+        ) -> DispatchResult {
+            let maker = ensure_signed(origin)?;
+            let count = htlcs.len() as u32;
+            let first = NextId::<T>::get();
+            for params in htlcs {
+                Self::do_open(&maker, params)?;
+            }
+            Self::deposit_event(Event::HtlcBatchOpened {
+                maker,
+                first,
+                count,
+            });
+            Ok(())
+        }
     }
 
     // ---------------------------
@@ -347,35 +1080,54 @@ pub mod pallet {
             asset: T::AssetId,
             amount: EscrowParam,
             hashlock: Self::HashLock,
-            expiry_abs: u32,
+            expiry: Expiry<u32>,
             adaptor_partial: Option<Vec<u8>>,
         ) -> Result<u64, DispatchError> {
             // Lock into escrow
+            Pallet::<T>::ensure_not_paused(asset)?;
             T::Escrow::escrow_lock(asset, maker, amount.clone())?;
+            let deposit = T::HtlcDeposit::get();
+            T::Currency::hold(&HoldReason::HtlcDeposit.into(), maker, deposit)?;
 
             let id = NextId::<T>::mutate(|x| {
                 let id = *x;
                 *x = x.saturating_add(1);
                 id
             });
-            let expiry_bn: BlockNumberFor<T> = expiry_abs.into();
+            let expiry = Pallet::<T>::resolve_expiry(match expiry {
+                Expiry::AtBlock(block) => Expiry::AtBlock(block.into()),
+                Expiry::AfterBlocks(blocks) => Expiry::AfterBlocks(blocks.into()),
+                Expiry::AtTime(secs) => Expiry::AtTime(secs),
+            });
 
+            // No message to verify it against here, but at least reject a malformed one
+            if let Some(bytes) = &adaptor_partial {
+                Pallet::<T>::vec_to_array_64(bytes)?;
+            }
             let adaptor_bounded: Option<BoundedVec<_, ConstU32<64>>> = match adaptor_partial {
                 Some(bytes) => Some(bytes.try_into().map_err(|_| Error::<T>::Arithmetic)?),
                 None => None,
             };
 
-            let rec = Htlc::<T::AccountId, T::AssetId, BlockNumberFor<T>, _> {
+            let rec = Htlc::<T::AccountId, T::AssetId, BlockNumberFor<T>, _, _> {
                 maker: maker.clone(),
                 taker,
                 asset,
                 param: amount,
-                hashlock,
-                expiry: expiry_bn,
+                lock: HtlcLock::Hash {
+                    hashlock,
+                    hash_algo: None,
+                },
+                expiry,
                 adaptor_partial: adaptor_bounded,
                 state: HtlcState::Open,
+                pending_extension: None,
+                deposit,
+                bounty: Zero::zero(),
+                closed_at: None,
             };
             Htlcs::<T>::insert(id, rec);
+            Pallet::<T>::schedule_expiry(id, &expiry);
             Ok(id)
         }
 
@@ -386,17 +1138,21 @@ pub mod pallet {
         ) -> Result<Self::Secret, DispatchError> {
             let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
             if let Some(taker) = &rec.taker {
-                ensure!(who == taker, Error::<T>::NotAuthorized);
+                ensure!(
+                    who == taker || !rec.bounty.is_zero(),
+                    Error::<T>::NotAuthorized
+                );
             }
             ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
             ensure!(
-                <T::Crypto as AdaptorSigBackend>::hash_secret(&secret) == rec.hashlock,
+                Pallet::<T>::unlocks(&rec.lock, &secret),
                 Error::<T>::BadSecret
             );
 
             let to = rec.taker.as_ref().unwrap_or(who);
+            Pallet::<T>::ensure_not_paused(rec.asset)?;
             T::Escrow::escrow_release(rec.asset, to, rec.param.clone())?;
-            rec.state = HtlcState::Redeemed;
+            Self::close(htlc_id, &mut rec, HtlcState::Redeemed, who);
             Htlcs::<T>::insert(htlc_id, &rec);
             Ok(secret)
         }
@@ -408,7 +1164,10 @@ pub mod pallet {
         ) -> Result<Self::Secret, DispatchError> {
             let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
             if let Some(taker) = &rec.taker {
-                ensure!(who == taker, Error::<T>::NotAuthorized);
+                ensure!(
+                    who == taker || !rec.bounty.is_zero(),
+                    Error::<T>::NotAuthorized
+                );
             }
             ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
 
@@ -426,13 +1185,14 @@ pub mod pallet {
                 &final_arr,
             )?;
             ensure!(
-                <T::Crypto as AdaptorSigBackend>::hash_secret(&secret) == rec.hashlock,
+                Pallet::<T>::unlocks(&rec.lock, &secret),
                 Error::<T>::BadSecret
             );
 
             let to = rec.taker.as_ref().unwrap_or(who);
+            Pallet::<T>::ensure_not_paused(rec.asset)?;
             T::Escrow::escrow_release(rec.asset, to, rec.param.clone())?;
-            rec.state = HtlcState::Redeemed;
+            Self::close(htlc_id, &mut rec, HtlcState::Redeemed, who);
             Htlcs::<T>::insert(htlc_id, &rec);
             Ok(secret)
         }
@@ -441,12 +1201,10 @@ pub mod pallet {
             let mut rec = Htlcs::<T>::get(htlc_id).ok_or(Error::<T>::NotFound)?;
             ensure!(matches!(rec.state, HtlcState::Open), Error::<T>::NotOpen);
             ensure!(who == &rec.maker, Error::<T>::NotAuthorized);
-            ensure!(
-                frame_system::Pallet::<T>::block_number() >= rec.expiry,
-                Error::<T>::NotYetExpired
-            );
+            ensure!(Self::is_expired(&rec.expiry), Error::<T>::NotYetExpired);
+            Pallet::<T>::ensure_not_paused(rec.asset)?;
             T::Escrow::escrow_refund(rec.asset, &rec.maker, rec.param.clone())?;
-            rec.state = HtlcState::Refunded;
+            Self::close(htlc_id, &mut rec, HtlcState::Refunded, who);
             Htlcs::<T>::insert(htlc_id, &rec);
             Ok(())
         }
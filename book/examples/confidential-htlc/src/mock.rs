@@ -0,0 +1,391 @@
+use crate::pallet as pallet_confidential_htlc;
+use confidential_assets_primitives::{
+    AdaptorSigBackend, Commitment, ConfidentialBackend, EncryptedAmount, EscrowTrust, InputProof,
+    ProofKind, ProofSystem, ProofSystemId, PublicKeyBytes, VerifyError, ZeroNetworkId, ZkVerifier,
+    seal_proof,
+};
+use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT, scalar::Scalar};
+use frame_support::{
+    construct_runtime, derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, UnixTime},
+};
+use sp_runtime::{BuildStorage, DispatchError};
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+pub type Balance = u64;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const ASSET: AssetId = 7;
+pub const ENDOWMENT: Balance = 1_000;
+pub const DEPOSIT: Balance = 10;
+
+// --- Escrow ------------------------------------------------------------------
+// Records what the pallet moves instead of moving it, so tests can assert on the
+// amounts and proofs each call hands the escrow.
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EscrowMove {
+    Lock(AccountId, EncryptedAmount, InputProof),
+    Release(AccountId, EncryptedAmount, InputProof),
+    Refund(AccountId, EncryptedAmount, InputProof),
+}
+
+thread_local! {
+    /// Every escrow call made, in order.
+    pub static ESCROW_MOVES: core::cell::RefCell<Vec<EscrowMove>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+}
+
+pub fn escrow_moves() -> Vec<EscrowMove> {
+    ESCROW_MOVES.with(|moves| moves.borrow().clone())
+}
+
+pub struct MockEscrow;
+impl EscrowTrust<AccountId, AssetId, (EncryptedAmount, InputProof)> for MockEscrow {
+    fn escrow_lock(
+        _asset: AssetId,
+        who: &AccountId,
+        (amount, proof): (EncryptedAmount, InputProof),
+    ) -> Result<(), DispatchError> {
+        ESCROW_MOVES.with(|moves| {
+            moves
+                .borrow_mut()
+                .push(EscrowMove::Lock(*who, amount, proof))
+        });
+        Ok(())
+    }
+    fn escrow_release(
+        _asset: AssetId,
+        to: &AccountId,
+        (amount, proof): (EncryptedAmount, InputProof),
+    ) -> Result<(), DispatchError> {
+        ESCROW_MOVES.with(|moves| {
+            moves
+                .borrow_mut()
+                .push(EscrowMove::Release(*to, amount, proof))
+        });
+        Ok(())
+    }
+    fn escrow_refund(
+        _asset: AssetId,
+        to: &AccountId,
+        (amount, proof): (EncryptedAmount, InputProof),
+    ) -> Result<(), DispatchError> {
+        ESCROW_MOVES.with(|moves| {
+            moves
+                .borrow_mut()
+                .push(EscrowMove::Refund(*to, amount, proof))
+        });
+        Ok(())
+    }
+}
+
+// --- Crypto ------------------------------------------------------------------
+// Hashlocks are blake2-256 of the secret; the secret recovered from a signature pair
+// is the last 32 bytes of the final signature.
+
+pub struct MockCrypto;
+impl AdaptorSigBackend for MockCrypto {
+    type Secret = [u8; 32];
+    type HashLock = [u8; 32];
+
+    fn hash_secret(secret: &[u8; 32]) -> [u8; 32] {
+        sp_io::hashing::blake2_256(secret)
+    }
+    fn recover_secret_from_sigs(
+        _partial_sig: &[u8; 64],
+        final_sig: &[u8; 64],
+    ) -> Result<[u8; 32], DispatchError> {
+        let mut secret = [0u8; 32];
+        secret.copy_from_slice(&final_sig[32..]);
+        Ok(secret)
+    }
+    fn verify_adaptor_sig(
+        _msg: &[u8],
+        _pubkey: &[u8; 32],
+        _adaptor_partial: &[u8; 64],
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+}
+
+// --- Proofs ------------------------------------------------------------------
+// Only the envelope of stored proofs is checked, so any body opens.
+
+pub struct MockProofs;
+impl ZkVerifier for MockProofs {
+    type NetworkIdProvider = ZeroNetworkId;
+
+    fn verify_pubkey_ownership(
+        _account: &[u8],
+        _pk: &[u8],
+        _proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        Ok(())
+    }
+    fn disclose(
+        _asset: &[u8],
+        _pk: &[u8],
+        _cipher: &[u8],
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+    fn verify_transfer_sent(
+        _asset: &[u8],
+        _from_pk: &[u8],
+        _to_pk: &[u8],
+        _from_old_avail: &[u8],
+        _to_old_pending: &[u8],
+        _delta_ct: &[u8],
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+    fn verify_transfer_received(
+        _asset: &[u8],
+        _who_pk: &[u8],
+        _avail_old: &[u8],
+        _pending_old: &[u8],
+        _commits: &[[u8; 32]],
+        _envelope: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+    fn verify_mint(
+        _asset: &[u8],
+        _to_pk: &PublicKeyBytes,
+        _to_old_pending: &[u8],
+        _total_old: &[u8],
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+    fn verify_burn(
+        _asset: &[u8],
+        _from_pk: &PublicKeyBytes,
+        _from_old_avail: &[u8],
+        _total_old: &[u8],
+        _amount_ct: &EncryptedAmount,
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>, u64), VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+}
+impl ProofSystem for MockProofs {
+    const ID: ProofSystemId = 0;
+
+    fn proof_len(_kind: ProofKind) -> Option<(usize, usize)> {
+        Some((0, usize::MAX))
+    }
+}
+
+// --- Backend -----------------------------------------------------------------
+// The pallet only asks the backend whether an asset is paused; nothing is.
+
+pub struct MockBackend;
+impl ConfidentialBackend<AccountId, AssetId, Balance> for MockBackend {
+    fn set_public_key(
+        _who: &AccountId,
+        _elgamal_pk: &PublicKeyBytes,
+        _proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn total_supply(_asset: AssetId) -> Commitment {
+        Commitment::identity()
+    }
+    fn balance_of(_asset: AssetId, _who: &AccountId) -> Commitment {
+        Commitment::identity()
+    }
+    fn pending_balance_of(_asset: AssetId, _who: &AccountId) -> Commitment {
+        Commitment::identity()
+    }
+    fn pending_transfers(_asset: AssetId, _who: &AccountId) -> Vec<(u64, EncryptedAmount)> {
+        Vec::new()
+    }
+    fn last_pending_id(_asset: AssetId, _who: &AccountId) -> Option<u64> {
+        None
+    }
+    fn disclose_amount(
+        _asset: AssetId,
+        _encrypted_amount: &EncryptedAmount,
+        _who: &AccountId,
+        _proof: InputProof,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn is_audited(_asset: AssetId) -> bool {
+        false
+    }
+    fn disclose_audited(
+        _asset: AssetId,
+        _who: &AccountId,
+        _proof: InputProof,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn transfer_encrypted(
+        _asset: AssetId,
+        _from: &AccountId,
+        _to: &AccountId,
+        _encrypted_amount: EncryptedAmount,
+        _input_proof: InputProof,
+    ) -> Result<EncryptedAmount, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn transfer_split(
+        _asset: AssetId,
+        _from: &AccountId,
+        _total: EncryptedAmount,
+        _outputs: &[(AccountId, EncryptedAmount)],
+        _input_proof: InputProof,
+    ) -> Result<Vec<u64>, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn claim_encrypted(
+        _asset: AssetId,
+        _from: &AccountId,
+        _input_proof: InputProof,
+    ) -> Result<EncryptedAmount, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn mint_encrypted(
+        _asset: AssetId,
+        _to: &AccountId,
+        _input_proof: InputProof,
+    ) -> Result<EncryptedAmount, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn burn_encrypted(
+        _asset: AssetId,
+        _from: &AccountId,
+        _amount: EncryptedAmount,
+        _input_proof: InputProof,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn burn_confidential(
+        _asset: AssetId,
+        _from: &AccountId,
+        _amount: EncryptedAmount,
+        _input_proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn burn_held(
+        _asset: AssetId,
+        _from: &AccountId,
+        _amount: Commitment,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn close_account(
+        _asset: AssetId,
+        _who: &AccountId,
+        _proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn is_paused(_asset: AssetId) -> bool {
+        false
+    }
+    fn seize_balance(
+        _asset: AssetId,
+        _who: &AccountId,
+        _encrypted_balance: EncryptedAmount,
+        _proof: InputProof,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+}
+
+// --- Clock -------------------------------------------------------------------
+
+thread_local! {
+    /// Unix time `MockTime` reports, in seconds.
+    pub static NOW: core::cell::Cell<u64> = const { core::cell::Cell::new(0) };
+}
+
+pub struct MockTime;
+impl UnixTime for MockTime {
+    fn now() -> core::time::Duration {
+        core::time::Duration::from_secs(NOW.with(|now| now.get()))
+    }
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const HtlcDeposit: Balance = DEPOSIT;
+}
+impl pallet_confidential_htlc::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = AssetId;
+    type Escrow = MockEscrow;
+    type Crypto = MockCrypto;
+    type Balance = Balance;
+    type Backend = MockBackend;
+    type Proofs = MockProofs;
+    type MaxOpenPerBatch = ConstU32<4>;
+    type MaxExpiryExtension = ConstU64<100>;
+    type MaxExpiryExtensionSecs = ConstU64<3_600>;
+    type UnixTime = MockTime;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type HtlcDeposit = HtlcDeposit;
+    type HtlcRetention = ConstU64<10>;
+    type WeightInfo = ();
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Balances: pallet_balances,
+        Htlc: pallet_confidential_htlc,
+    }
+);
+
+// Build a fresh externalities for each test.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![(ALICE, ENDOWMENT), (BOB, ENDOWMENT), (CHARLIE, ENDOWMENT)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    ESCROW_MOVES.with(|moves| moves.borrow_mut().clear());
+    NOW.with(|now| now.set(0));
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Construct InputProof from raw bytes, enveloped for the mock proof system
+pub fn proof(bytes: &[u8]) -> InputProof {
+    seal_proof(MockProofs::ID, bytes)
+        .try_into()
+        .expect("bounded vec")
+}
+
+// Both halves of the ciphertext are `v·G`, so ciphertexts add and subtract like `v`
+pub fn ct(v: u64) -> EncryptedAmount {
+    let point = (RISTRETTO_BASEPOINT_POINT * Scalar::from(v))
+        .compress()
+        .to_bytes();
+    EncryptedAmount::from_parts(point, point)
+}
@@ -0,0 +1,257 @@
+use crate::{
+    mock::*,
+    pallet::{
+        ArchivedHtlcs, Error, Event, ExpiredHtlcs, HtlcLock, HtlcState, Htlcs, NextExpirySweep,
+        NextId,
+    },
+};
+use confidential_assets_primitives::{AdaptorSigBackend, Expiry, InputProof};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use parity_scale_codec::Encode;
+
+// helpers
+const SECRET: [u8; 32] = [9u8; 32];
+fn hashlock() -> HtlcLock<[u8; 32]> {
+    HtlcLock::Hash {
+        hashlock: MockCrypto::hash_secret(&SECRET),
+        hash_algo: None,
+    }
+}
+fn open(taker: Option<AccountId>, amount: u64, expiry: Expiry<u64>) -> u64 {
+    let id = NextId::<Runtime>::get();
+    assert_ok!(Htlc::open_htlc(
+        RuntimeOrigin::signed(ALICE),
+        taker,
+        ASSET,
+        ct(amount),
+        proof(&[0]),
+        hashlock(),
+        expiry,
+        None,
+        None,
+        0,
+    ));
+    id
+}
+fn has_event(event: Event<Runtime>) -> bool {
+    System::events()
+        .iter()
+        .any(|record| record.event == RuntimeEvent::Htlc(event.clone()))
+}
+
+#[test]
+fn partial_redeem_leaves_the_remainder_redeemable_under_the_same_lock() {
+    new_test_ext().execute_with(|| {
+        let id = open(Some(BOB), 5, Expiry::AtBlock(20));
+        assert_eq!(Balances::reserved_balance(ALICE), DEPOSIT);
+
+        assert_noop!(
+            Htlc::redeem_partial_with_secret(
+                RuntimeOrigin::signed(CHARLIE),
+                id,
+                SECRET,
+                ct(2),
+                proof(&[1]),
+                proof(&[2]),
+            ),
+            Error::<Runtime>::NotAuthorized
+        );
+        assert_noop!(
+            Htlc::redeem_partial_with_secret(
+                RuntimeOrigin::signed(BOB),
+                id,
+                [8u8; 32],
+                ct(2),
+                proof(&[1]),
+                proof(&[2]),
+            ),
+            Error::<Runtime>::BadSecret
+        );
+        // A remainder proof of another proof system could never move the rest
+        let foreign: InputProof = vec![9u8, 2].try_into().expect("bounded vec");
+        assert_noop!(
+            Htlc::redeem_partial_with_secret(
+                RuntimeOrigin::signed(BOB),
+                id,
+                SECRET,
+                ct(2),
+                proof(&[1]),
+                foreign,
+            ),
+            Error::<Runtime>::MalformedProof
+        );
+
+        assert_ok!(Htlc::redeem_partial_with_secret(
+            RuntimeOrigin::signed(BOB),
+            id,
+            SECRET,
+            ct(2),
+            proof(&[1]),
+            proof(&[2]),
+        ));
+        assert!(has_event(Event::HtlcPartiallyRedeemed {
+            id,
+            redeemer: BOB,
+            released: ct(2),
+            remaining: ct(3),
+            secret: SECRET.encode(),
+        }));
+        let rec = Htlcs::<Runtime>::get(id).expect("still open");
+        assert_eq!(rec.state, HtlcState::Open);
+        assert_eq!(rec.param, (ct(3), proof(&[2])));
+
+        // The rest goes out with the remainder proof stored by the partial redeem
+        assert_ok!(Htlc::redeem_with_secret(
+            RuntimeOrigin::signed(BOB),
+            id,
+            SECRET
+        ));
+        let rec = Htlcs::<Runtime>::get(id).expect("kept until pruned");
+        assert_eq!(rec.state, HtlcState::Redeemed);
+        assert_eq!(rec.closed_at, Some(1));
+        assert_eq!(
+            escrow_moves(),
+            vec![
+                EscrowMove::Lock(ALICE, ct(5), proof(&[0])),
+                EscrowMove::Release(BOB, ct(2), proof(&[1])),
+                EscrowMove::Release(BOB, ct(3), proof(&[2])),
+            ]
+        );
+
+        assert_noop!(
+            Htlc::redeem_with_secret(RuntimeOrigin::signed(BOB), id, SECRET),
+            Error::<Runtime>::NotOpen
+        );
+    });
+}
+
+#[test]
+fn partial_redeem_of_an_open_htlc_pays_the_redeemer() {
+    new_test_ext().execute_with(|| {
+        let id = open(None, 4, Expiry::AtBlock(20));
+        assert_ok!(Htlc::redeem_partial_with_secret(
+            RuntimeOrigin::signed(BOB),
+            id,
+            SECRET,
+            ct(1),
+            proof(&[1]),
+            proof(&[2]),
+        ));
+        // The secret is public now; whoever redeems the rest receives it
+        assert_ok!(Htlc::redeem_with_secret(
+            RuntimeOrigin::signed(CHARLIE),
+            id,
+            SECRET
+        ));
+        assert_eq!(
+            escrow_moves()[1..],
+            [
+                EscrowMove::Release(BOB, ct(1), proof(&[1])),
+                EscrowMove::Release(CHARLIE, ct(3), proof(&[2])),
+            ]
+        );
+    });
+}
+
+#[test]
+fn expired_htlcs_are_swept_and_refundable_by_anyone() {
+    new_test_ext().execute_with(|| {
+        let expired = open(Some(BOB), 5, Expiry::AfterBlocks(4));
+        let redeemed = open(Some(BOB), 1, Expiry::AtBlock(5));
+        assert_eq!(
+            Htlcs::<Runtime>::get(expired).unwrap().expiry,
+            Expiry::AtBlock(5)
+        );
+        assert_noop!(
+            Htlc::refund_expired(RuntimeOrigin::signed(CHARLIE), expired),
+            Error::<Runtime>::NotYetExpired
+        );
+
+        System::set_block_number(2);
+        assert_ok!(Htlc::redeem_with_secret(
+            RuntimeOrigin::signed(BOB),
+            redeemed,
+            SECRET
+        ));
+
+        // Nothing is due before the expiry block
+        Htlc::on_idle(4, Weight::MAX);
+        assert!(!has_event(Event::HtlcExpired {
+            id: expired,
+            maker: ALICE
+        }));
+
+        System::set_block_number(5);
+        Htlc::on_idle(5, Weight::MAX);
+        assert!(has_event(Event::HtlcExpired {
+            id: expired,
+            maker: ALICE
+        }));
+        // Closed HTLCs are skipped, and the index is drained either way
+        assert!(!has_event(Event::HtlcExpired {
+            id: redeemed,
+            maker: ALICE
+        }));
+        assert_eq!(ExpiredHtlcs::<Runtime>::iter().count(), 0);
+        assert_eq!(NextExpirySweep::<Runtime>::get(), 6);
+
+        // Only the maker may `refund`, but anyone may refund it to the maker
+        assert_noop!(
+            Htlc::refund(RuntimeOrigin::signed(CHARLIE), expired),
+            Error::<Runtime>::NotAuthorized
+        );
+        assert_ok!(Htlc::refund_expired(
+            RuntimeOrigin::signed(CHARLIE),
+            expired
+        ));
+        assert!(has_event(Event::HtlcRefunded {
+            id: expired,
+            maker: ALICE
+        }));
+        assert_eq!(
+            escrow_moves().last(),
+            Some(&EscrowMove::Refund(ALICE, ct(5), proof(&[0])))
+        );
+        assert_eq!(
+            Htlcs::<Runtime>::get(expired).unwrap().state,
+            HtlcState::Refunded
+        );
+        assert_noop!(
+            Htlc::refund_expired(RuntimeOrigin::signed(CHARLIE), expired),
+            Error::<Runtime>::NotOpen
+        );
+
+        // Both deposits stay held until their records are pruned
+        assert_eq!(Balances::reserved_balance(ALICE), 2 * DEPOSIT);
+        assert_noop!(
+            Htlc::prune_htlc(RuntimeOrigin::signed(CHARLIE), expired),
+            Error::<Runtime>::RetentionNotElapsed
+        );
+        System::set_block_number(15);
+        assert_ok!(Htlc::prune_htlc(RuntimeOrigin::signed(CHARLIE), expired));
+        assert!(Htlcs::<Runtime>::get(expired).is_none());
+        assert!(ArchivedHtlcs::<Runtime>::contains_key(expired));
+        assert_eq!(Balances::reserved_balance(ALICE), DEPOSIT);
+    });
+}
+
+#[test]
+fn wall_clock_expiries_are_refundable_once_the_time_passes() {
+    new_test_ext().execute_with(|| {
+        let id = open(Some(BOB), 2, Expiry::AtTime(1_000));
+        // Not indexed for the sweep; checked when the refund is submitted
+        assert_eq!(ExpiredHtlcs::<Runtime>::iter().count(), 0);
+
+        NOW.with(|now| now.set(999));
+        assert_noop!(
+            Htlc::refund_expired(RuntimeOrigin::signed(CHARLIE), id),
+            Error::<Runtime>::NotYetExpired
+        );
+        NOW.with(|now| now.set(1_000));
+        assert_ok!(Htlc::refund_expired(RuntimeOrigin::signed(CHARLIE), id));
+        assert_eq!(
+            escrow_moves().last(),
+            Some(&EscrowMove::Refund(ALICE, ct(2), proof(&[0])))
+        );
+    });
+}
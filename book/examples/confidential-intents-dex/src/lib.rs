@@ -1,13 +1,37 @@
 // pallets/confidential-intents-dex/src/lib.rs
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 extern crate alloc;
 
-use frame_support::{dispatch::DispatchResult, pallet_prelude::*, transactional};
+use frame_support::{
+    dispatch::DispatchResult,
+    pallet_prelude::*,
+    traits::{
+        fungible::{self, MutateHold},
+        tokens::Precision,
+    },
+    transactional,
+};
 use frame_system::pallet_prelude::*;
+use parity_scale_codec::DecodeWithMemTracking;
+use sp_runtime::traits::{IdentifyAccount, Verify};
 use sp_std::prelude::*;
 
-use confidential_assets_primitives::{ConfidentialSwapIntents, EncryptedAmount, InputProof};
+use confidential_assets_primitives::{
+    ConfidentialSwapIntents, EncryptedAmount, InputProof, SwapTerms,
+};
+
+/// Domain tag of the message a taker signs for a solver to clear its match.
+pub const MATCH_CONTEXT: &[u8] = b"confidential-dex/match";
+
+pub type DepositBalanceOf<T> = <<T as pallet::Config>::Currency as fungible::Inspect<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -16,21 +40,83 @@ pub mod pallet {
     #[derive(
         Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, sp_runtime::RuntimeDebug,
     )]
-    pub struct DexIntent<AccountId, AssetId> {
+    pub struct DexIntent<AccountId, AssetId, SwapId, BlockNumber, Balance> {
         pub maker: AccountId,
         pub asset_a: AssetId,
         pub asset_b: AssetId,
-        pub a_to_b_ct: EncryptedAmount,
-        pub a_to_b_proof: InputProof,
-        pub terms_hash: Option<[u8; 32]>, // None => accept any taker ciphertext on asset_b
+        pub swap_id: SwapId, // open intent in the Swaps pallet holding the maker leg
+        pub expires_at: BlockNumber, // not matchable from this block on
+        pub deposit: Balance, // held from the maker while the intent is open
+    }
+
+    pub type DexIntentOf<T> = DexIntent<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::AssetId,
+        <T as Config>::SwapId,
+        BlockNumberFor<T>,
+        DepositBalanceOf<T>,
+    >;
+
+    /// One match of a `match_many` batch: `taker` buys intent `id` with its leg.
+    /// `signature` is the taker's over [`MATCH_CONTEXT`], `id`, `b_to_a_ct` and the
+    /// swap fee it will pay; a solver matching for itself leaves it `None`.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        TypeInfo,
+        MaxEncodedLen,
+        sp_runtime::RuntimeDebug,
+    )]
+    pub struct BatchMatch<AccountId, Signature> {
+        pub id: u64,
+        pub taker: AccountId,
+        pub b_to_a_ct: EncryptedAmount,
+        pub b_to_a_proof: InputProof,
+        pub terms_proof: InputProof,
+        pub signature: Option<Signature>,
+    }
+
+    /// Taker's request for quotes: it buys `asset_a` and pays in `asset_b`.
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, sp_runtime::RuntimeDebug,
+    )]
+    pub struct QuoteRequest<AccountId, AssetId, Balance, BlockNumber> {
+        pub taker: AccountId,
+        pub asset_a: AssetId,
+        pub asset_b: AssetId,
+        pub size: Option<(Balance, Balance)>, // disclosed min/max of asset_a, a hint for makers
+        pub expires_at: BlockNumber,          // no offers from this block on
     }
 
+    /// Maker's answer to a quote request: a swap intent bound to the request's taker.
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, sp_runtime::RuntimeDebug,
+    )]
+    pub struct QuoteOffer<AccountId, SwapId, BlockNumber, Balance> {
+        pub maker: AccountId,
+        pub request_id: u64,
+        pub swap_id: SwapId,
+        pub expires_at: BlockNumber, // not acceptable from this block on
+        pub deposit: Balance,        // held from the maker while the offer is open
+    }
+
+    pub type QuoteOfferOf<T> = QuoteOffer<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::SwapId,
+        BlockNumberFor<T>,
+        DepositBalanceOf<T>,
+    >;
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         type AssetId: Parameter + Member + Copy + Ord + MaxEncodedLen + TypeInfo;
-        /// Kept because some upstream traits still carry a Balance type; unused here.
+        /// Balance of disclosed quote sizes.
         type Balance: Parameter + Member + Copy + Ord + MaxEncodedLen + TypeInfo + Default;
 
         /// The opaque identifier used by the Swaps pallet for an opened intent.
@@ -46,7 +132,44 @@ pub mod pallet {
 
         /// A swaps pallet that implements `ConfidentialSwapIntents` (open/execute/cancel),
         /// and whose `SwapId` matches `Self::SwapId`.
-        type Swaps: ConfidentialSwapIntents<Self::AccountId, Self::AssetId, SwapId = Self::SwapId>;
+        type Swaps: ConfidentialSwapIntents<
+                Self::AccountId,
+                Self::AssetId,
+                BlockNumberFor<Self>,
+                SwapId = Self::SwapId,
+            >;
+
+        /// Most matches one `match_many` call may clear.
+        #[pallet::constant]
+        type MaxBatch: Get<u32>;
+
+        /// Registers solvers.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Signature a taker gives a solver for each match cleared on its behalf.
+        type TakerSignature: Verify<Signer = Self::TakerPublic> + Parameter;
+
+        /// Public key behind `TakerSignature`.
+        type TakerPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Currency intent deposits are held in.
+        type Currency: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Held from the maker of each intent or quote offer until it is matched,
+        /// canceled or pruned.
+        #[pallet::constant]
+        type IntentDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Most intents and quote offers one account may have open at once.
+        #[pallet::constant]
+        type MaxIntentsPerAccount: Get<u32>;
+
+        /// Most offers one quote request may collect.
+        #[pallet::constant]
+        type MaxOffersPerRequest: Get<u32>;
 
         type WeightInfo: WeightInfo;
     }
@@ -55,6 +178,15 @@ pub mod pallet {
         fn open_intent() -> Weight;
         fn cancel_intent() -> Weight;
         fn match_intent() -> Weight;
+        fn prune_intent() -> Weight;
+        fn request_quote() -> Weight;
+        fn cancel_request() -> Weight;
+        fn offer_quote() -> Weight;
+        fn withdraw_offer() -> Weight;
+        fn accept_quote() -> Weight;
+        fn set_solver() -> Weight;
+        fn prune_request() -> Weight;
+        fn prune_offer() -> Weight;
     }
     impl WeightInfo for () {
         fn open_intent() -> Weight {
@@ -66,11 +198,44 @@ pub mod pallet {
         fn match_intent() -> Weight {
             30_000.into()
         }
+        fn prune_intent() -> Weight {
+            10_000.into()
+        }
+        fn request_quote() -> Weight {
+            10_000.into()
+        }
+        fn cancel_request() -> Weight {
+            5_000.into()
+        }
+        fn offer_quote() -> Weight {
+            15_000.into()
+        }
+        fn withdraw_offer() -> Weight {
+            10_000.into()
+        }
+        fn accept_quote() -> Weight {
+            30_000.into()
+        }
+        fn set_solver() -> Weight {
+            5_000.into()
+        }
+        fn prune_request() -> Weight {
+            5_000.into()
+        }
+        fn prune_offer() -> Weight {
+            10_000.into()
+        }
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit for an open intent or quote offer, released when it closes.
+        IntentDeposit,
+    }
+
     // Storage
     #[pallet::storage]
     #[pallet::getter(fn next_id)]
@@ -78,8 +243,47 @@ pub mod pallet {
 
     #[pallet::storage]
     #[pallet::getter(fn intents)]
-    pub type Intents<T: Config> =
-        StorageMap<_, Blake2_128Concat, u64, DexIntent<T::AccountId, T::AssetId>, OptionQuery>;
+    pub type Intents<T: Config> = StorageMap<_, Blake2_128Concat, u64, DexIntentOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_request_id)]
+    pub type NextRequestId<T> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn quote_requests)]
+    pub type QuoteRequests<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        QuoteRequest<T::AccountId, T::AssetId, T::Balance, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_offer_id)]
+    pub type NextOfferId<T> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn quote_offers)]
+    pub type QuoteOffers<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, QuoteOfferOf<T>, OptionQuery>;
+
+    /// Open offers on each quote request.
+    #[pallet::storage]
+    #[pallet::getter(fn request_offers)]
+    pub type RequestOffers<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, BoundedVec<u64, T::MaxOffersPerRequest>, ValueQuery>;
+
+    /// Number of open intents and quote offers of each maker.
+    #[pallet::storage]
+    #[pallet::getter(fn open_intents)]
+    pub type OpenIntents<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Accounts that may clear matches on behalf of other takers.
+    #[pallet::storage]
+    #[pallet::getter(fn solvers)]
+    pub type Solvers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
     // Events / Errors
     #[pallet::event]
@@ -90,6 +294,7 @@ pub mod pallet {
             maker: T::AccountId,
             asset_a: T::AssetId,
             asset_b: T::AssetId,
+            expires_at: BlockNumberFor<T>,
         },
         IntentCanceled {
             id: u64,
@@ -101,28 +306,189 @@ pub mod pallet {
             taker: T::AccountId,
             swap_id: T::SwapId,
         },
+        /// An expired intent was removed by `pruned_by`.
+        IntentExpired {
+            id: u64,
+            maker: T::AccountId,
+            pruned_by: T::AccountId,
+        },
+        SolverSet {
+            who: T::AccountId,
+            enabled: bool,
+        },
+        /// `solver` cleared `count` matches in one batch.
+        BatchMatched {
+            solver: T::AccountId,
+            count: u32,
+        },
+        QuoteRequested {
+            id: u64,
+            taker: T::AccountId,
+            asset_a: T::AssetId,
+            asset_b: T::AssetId,
+            size: Option<(T::Balance, T::Balance)>,
+            expires_at: BlockNumberFor<T>,
+        },
+        QuoteRequestCanceled {
+            id: u64,
+            taker: T::AccountId,
+        },
+        QuoteOffered {
+            request_id: u64,
+            offer_id: u64,
+            maker: T::AccountId,
+            swap_id: T::SwapId,
+            expires_at: BlockNumberFor<T>,
+        },
+        QuoteOfferWithdrawn {
+            offer_id: u64,
+            maker: T::AccountId,
+        },
+        QuoteAccepted {
+            request_id: u64,
+            offer_id: u64,
+            maker: T::AccountId,
+            taker: T::AccountId,
+            swap_id: T::SwapId,
+        },
+        /// Another offer on the same request was accepted. The leg stays escrowed in
+        /// the Swaps pallet until the maker cancels it there or it expires.
+        QuoteOfferSuperseded {
+            offer_id: u64,
+            maker: T::AccountId,
+            swap_id: T::SwapId,
+        },
+        /// An expired quote request was removed by `pruned_by`.
+        QuoteRequestExpired {
+            id: u64,
+            taker: T::AccountId,
+            pruned_by: T::AccountId,
+        },
+        /// An expired offer was removed by `pruned_by`.
+        QuoteOfferExpired {
+            offer_id: u64,
+            maker: T::AccountId,
+            pruned_by: T::AccountId,
+        },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         UnknownIntent,
         NotMaker,
+        Expired,
+        NotExpired,
+        TooManyIntents, // the maker has MaxIntentsPerAccount intents and offers open
+        NotSolver,      // matched for another taker without being a solver
+        BadTakerSignature, // the taker did not sign this match
+        UnknownRequest,
+        UnknownOffer,
+        NotTaker,
+        TooManyOffers, // the request has MaxOffersPerRequest offers open
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Take a slot from `maker`'s `MaxIntentsPerAccount` and hold `IntentDeposit`
+        /// for it, returning the deposit held.
+        fn open_slot(maker: &T::AccountId) -> Result<DepositBalanceOf<T>, DispatchError> {
+            let open = OpenIntents::<T>::get(maker);
+            ensure!(
+                open < T::MaxIntentsPerAccount::get(),
+                Error::<T>::TooManyIntents
+            );
+            let deposit = T::IntentDeposit::get();
+            T::Currency::hold(&HoldReason::IntentDeposit.into(), maker, deposit)?;
+            OpenIntents::<T>::insert(maker, open.saturating_add(1));
+            Ok(deposit)
+        }
+
+        /// Return a closed intent or offer's deposit and free its slot.
+        fn close_slot(maker: &T::AccountId, deposit: DepositBalanceOf<T>) {
+            // Never blocks closing: whatever is still held is returned
+            let _ = T::Currency::release(
+                &HoldReason::IntentDeposit.into(),
+                maker,
+                deposit,
+                Precision::BestEffort,
+            );
+            OpenIntents::<T>::mutate(maker, |n| *n = n.saturating_sub(1));
+        }
+
+        /// Execute intent `id` for `taker` and emit `IntentMatched`.
+        fn do_match(
+            id: u64,
+            taker: T::AccountId,
+            b_to_a_ct: EncryptedAmount,
+            b_to_a_proof: InputProof,
+            terms_proof: InputProof,
+        ) -> DispatchResult {
+            let intent = Intents::<T>::take(id).ok_or(Error::<T>::UnknownIntent)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < intent.expires_at,
+                Error::<T>::Expired
+            );
+            let (swap_id, _) = <T as Config>::Swaps::execute_intent_cc(
+                &taker,
+                intent.swap_id,
+                b_to_a_ct,
+                b_to_a_proof,
+                terms_proof,
+            )?;
+            Self::close_slot(&intent.maker, intent.deposit);
+            <Pallet<T>>::deposit_event(Event::IntentMatched {
+                id,
+                maker: intent.maker,
+                taker,
+                swap_id,
+            });
+            Ok(())
+        }
+
+        /// Take offer `offer_id` out of its request's open offers.
+        fn unlist_offer(request_id: u64, offer_id: u64) {
+            RequestOffers::<T>::mutate_exists(request_id, |offers| {
+                if let Some(list) = offers {
+                    list.retain(|id| *id != offer_id);
+                    if list.is_empty() {
+                        *offers = None;
+                    }
+                }
+            });
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Maker posts an *open* confidential intent (no counterparty yet).
+        /// Maker posts an *open* confidential intent (no counterparty yet). The Swaps
+        /// pallet escrows the maker leg under `lock_proof` and binds the taker when it
+        /// executes. Holds `IntentDeposit` from the maker until the intent closes.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::open_intent())]
+        #[transactional]
+        #[allow(clippy::too_many_arguments)]
         pub fn open_intent(
             origin: OriginFor<T>,
             asset_a: T::AssetId,
             asset_b: T::AssetId,
             a_to_b_ct: EncryptedAmount,
+            lock_proof: InputProof,
             a_to_b_proof: InputProof,
-            terms_hash: Option<[u8; 32]>,
+            terms: SwapTerms,
+            expires_at: BlockNumberFor<T>,
         ) -> DispatchResult {
             let maker = ensure_signed(origin)?;
+            let deposit = Self::open_slot(&maker)?;
+            let swap_id = <T as Config>::Swaps::open_intent_cc(
+                &maker,
+                None,
+                asset_a,
+                asset_b,
+                a_to_b_ct,
+                lock_proof,
+                a_to_b_proof,
+                terms,
+                expires_at,
+            )?;
             let id = NextId::<T>::mutate(|n| {
                 let cur = *n;
                 *n = n.saturating_add(1);
@@ -135,9 +501,9 @@ pub mod pallet {
                     maker: maker.clone(),
                     asset_a,
                     asset_b,
-                    a_to_b_ct,
-                    a_to_b_proof,
-                    terms_hash,
+                    swap_id,
+                    expires_at,
+                    deposit,
                 },
             );
 
@@ -146,27 +512,32 @@ pub mod pallet {
                 maker,
                 asset_a,
                 asset_b,
+                expires_at,
             });
             Ok(())
         }
 
-        /// Maker cancels their open intent.
+        /// Maker cancels their open intent, refunding its escrowed leg under `refund_proof`.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::cancel_intent())]
-        pub fn cancel_intent(origin: OriginFor<T>, id: u64) -> DispatchResult {
+        #[transactional]
+        pub fn cancel_intent(
+            origin: OriginFor<T>,
+            id: u64,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let intent = Intents::<T>::take(id).ok_or(Error::<T>::UnknownIntent)?;
             ensure!(intent.maker == who, Error::<T>::NotMaker);
+            <T as Config>::Swaps::cancel_intent_cc(&who, intent.swap_id, refund_proof)?;
+            Self::close_slot(&intent.maker, intent.deposit);
             <Pallet<T>>::deposit_event(Event::IntentCanceled { id, maker: who });
             Ok(())
         }
 
-        /// Taker matches an intent by supplying their ciphertext leg.
-        ///
-        /// Flow:
-        ///  1) DEX binds the taker by opening a concrete swap in the Swaps pallet.
-        ///  2) DEX immediately calls `execute_intent_cc` to execute atomically.
-        ///  3) Emits an event with the swap id.
+        /// Taker matches an intent by supplying their ciphertext leg, and a ratio proof
+        /// for intents with `SwapTerms::Rate`; the Swaps pallet executes both legs
+        /// atomically.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::match_intent())]
         #[transactional]
@@ -175,33 +546,314 @@ pub mod pallet {
             id: u64,
             b_to_a_ct: EncryptedAmount,
             b_to_a_proof: InputProof,
+            terms_proof: InputProof,
         ) -> DispatchResult {
             let taker = ensure_signed(origin)?;
-            let intent = Intents::<T>::take(id).ok_or(Error::<T>::UnknownIntent)?;
+            Self::do_match(id, taker, b_to_a_ct, b_to_a_proof, terms_proof)
+        }
 
-            // 1) Bind taker & open a concrete swap in Swaps pallet
-            let swap_id: T::SwapId = <T as Config>::Swaps::open_intent_cc(
-                &intent.maker,
-                &taker,
-                intent.asset_a,
-                intent.asset_b,
-                intent.a_to_b_ct.clone(),
-                intent.a_to_b_proof.clone(),
-                intent.terms_hash,
+        /// Remove an expired intent and its swap. Anyone may call this; the maker leg is
+        /// refunded through the escrow.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::prune_intent())]
+        #[transactional]
+        pub fn prune_expired(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let intent = Intents::<T>::get(id).ok_or(Error::<T>::UnknownIntent)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= intent.expires_at,
+                Error::<T>::NotExpired
+            );
+            Intents::<T>::remove(id);
+            <T as Config>::Swaps::prune_intent_cc(&who, intent.swap_id)?;
+            Self::close_slot(&intent.maker, intent.deposit);
+            <Pallet<T>>::deposit_event(Event::IntentExpired {
+                id,
+                maker: intent.maker,
+                pruned_by: who,
+            });
+            Ok(())
+        }
+
+        /// Taker asks makers to quote buying `asset_a` for `asset_b` until `expires_at`,
+        /// optionally disclosing the size it is after.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::request_quote())]
+        pub fn request_quote(
+            origin: OriginFor<T>,
+            asset_a: T::AssetId,
+            asset_b: T::AssetId,
+            size: Option<(T::Balance, T::Balance)>,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let taker = ensure_signed(origin)?;
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::Expired
+            );
+            let id = NextRequestId::<T>::mutate(|n| {
+                let cur = *n;
+                *n = n.saturating_add(1);
+                cur
+            });
+            QuoteRequests::<T>::insert(
+                id,
+                QuoteRequest {
+                    taker: taker.clone(),
+                    asset_a,
+                    asset_b,
+                    size,
+                    expires_at,
+                },
+            );
+            <Pallet<T>>::deposit_event(Event::QuoteRequested {
+                id,
+                taker,
+                asset_a,
+                asset_b,
+                size,
+                expires_at,
+            });
+            Ok(())
+        }
+
+        /// Taker closes its request; outstanding offers stay with their makers, who
+        /// withdraw them or let them expire for anyone to prune.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::cancel_request())]
+        pub fn cancel_request(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let request = QuoteRequests::<T>::get(id).ok_or(Error::<T>::UnknownRequest)?;
+            ensure!(request.taker == who, Error::<T>::NotTaker);
+            QuoteRequests::<T>::remove(id);
+            <Pallet<T>>::deposit_event(Event::QuoteRequestCanceled { id, taker: who });
+            Ok(())
+        }
+
+        /// Maker answers request `request_id` with an offer only its taker can accept:
+        /// the Swaps pallet escrows `a_to_b_ct` of the request's `asset_a` under
+        /// `lock_proof` until `expires_at`. Takes one of the maker's
+        /// `MaxIntentsPerAccount` slots and holds `IntentDeposit` until the offer closes.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::offer_quote())]
+        #[transactional]
+        #[allow(clippy::too_many_arguments)]
+        pub fn offer_quote(
+            origin: OriginFor<T>,
+            request_id: u64,
+            a_to_b_ct: EncryptedAmount,
+            lock_proof: InputProof,
+            a_to_b_proof: InputProof,
+            terms: SwapTerms,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let maker = ensure_signed(origin)?;
+            let request = QuoteRequests::<T>::get(request_id).ok_or(Error::<T>::UnknownRequest)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < request.expires_at,
+                Error::<T>::Expired
+            );
+            let deposit = Self::open_slot(&maker)?;
+            let offer_id = NextOfferId::<T>::get();
+            RequestOffers::<T>::try_mutate(request_id, |offers| offers.try_push(offer_id))
+                .map_err(|_| Error::<T>::TooManyOffers)?;
+            NextOfferId::<T>::put(offer_id.saturating_add(1));
+            let swap_id = <T as Config>::Swaps::open_intent_cc(
+                &maker,
+                Some(&request.taker),
+                request.asset_a,
+                request.asset_b,
+                a_to_b_ct,
+                lock_proof,
+                a_to_b_proof,
+                terms,
+                expires_at,
             )?;
+            QuoteOffers::<T>::insert(
+                offer_id,
+                QuoteOffer {
+                    maker: maker.clone(),
+                    request_id,
+                    swap_id,
+                    expires_at,
+                    deposit,
+                },
+            );
+            <Pallet<T>>::deposit_event(Event::QuoteOffered {
+                request_id,
+                offer_id,
+                maker,
+                swap_id,
+                expires_at,
+            });
+            Ok(())
+        }
 
-            // 2) Execute the swap on behalf of the taker (SwapId is Copy; no move issues)
-            let _ =
-                <T as Config>::Swaps::execute_intent_cc(&taker, swap_id, b_to_a_ct, b_to_a_proof)?;
+        /// Maker withdraws its offer, refunding the escrowed leg under `refund_proof`.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::withdraw_offer())]
+        #[transactional]
+        pub fn withdraw_offer(
+            origin: OriginFor<T>,
+            offer_id: u64,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let offer = QuoteOffers::<T>::take(offer_id).ok_or(Error::<T>::UnknownOffer)?;
+            ensure!(offer.maker == who, Error::<T>::NotMaker);
+            <T as Config>::Swaps::cancel_intent_cc(&who, offer.swap_id, refund_proof)?;
+            Self::unlist_offer(offer.request_id, offer_id);
+            Self::close_slot(&who, offer.deposit);
+            <Pallet<T>>::deposit_event(Event::QuoteOfferWithdrawn {
+                offer_id,
+                maker: who,
+            });
+            Ok(())
+        }
 
-            // 3) Emit
-            <Pallet<T>>::deposit_event(Event::IntentMatched {
-                id,
-                maker: intent.maker,
+        /// Taker accepts one offer on its open request, closing the request and its
+        /// other offers; the Swaps pallet executes both legs as for `match_intent`. The
+        /// other offers' legs stay escrowed there until their makers cancel them or
+        /// they expire and anyone prunes them.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::accept_quote().saturating_add(
+            T::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_mul(T::MaxOffersPerRequest::get().into())
+        ))]
+        #[transactional]
+        pub fn accept_quote(
+            origin: OriginFor<T>,
+            offer_id: u64,
+            b_to_a_ct: EncryptedAmount,
+            b_to_a_proof: InputProof,
+            terms_proof: InputProof,
+        ) -> DispatchResult {
+            let taker = ensure_signed(origin)?;
+            let offer = QuoteOffers::<T>::take(offer_id).ok_or(Error::<T>::UnknownOffer)?;
+            let request =
+                QuoteRequests::<T>::take(offer.request_id).ok_or(Error::<T>::UnknownRequest)?;
+            ensure!(request.taker == taker, Error::<T>::NotTaker);
+            let (swap_id, _) = <T as Config>::Swaps::execute_intent_cc(
+                &taker,
+                offer.swap_id,
+                b_to_a_ct,
+                b_to_a_proof,
+                terms_proof,
+            )?;
+            Self::close_slot(&offer.maker, offer.deposit);
+            <Pallet<T>>::deposit_event(Event::QuoteAccepted {
+                request_id: offer.request_id,
+                offer_id,
+                maker: offer.maker,
                 taker,
                 swap_id,
             });
+            for other in RequestOffers::<T>::take(offer.request_id) {
+                if let Some(rest) = QuoteOffers::<T>::take(other) {
+                    Self::close_slot(&rest.maker, rest.deposit);
+                    <Pallet<T>>::deposit_event(Event::QuoteOfferSuperseded {
+                        offer_id: other,
+                        maker: rest.maker,
+                        swap_id: rest.swap_id,
+                    });
+                }
+            }
+            Ok(())
+        }
+
+        /// Clear a batch of matches at once, all or none. A registered solver may match
+        /// for any taker that signed the match, relaying its leg proofs; anyone else
+        /// only for itself. The signature covers the swap fee, so a fee raised since the
+        /// taker signed fails the batch.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::match_intent().saturating_mul(matches.len() as u64))]
+        #[transactional]
+        pub fn match_many(
+            origin: OriginFor<T>,
+            matches: BoundedVec<BatchMatch<T::AccountId, T::TakerSignature>, T::MaxBatch>,
+        ) -> DispatchResult {
+            let solver = ensure_signed(origin)?;
+            let registered = Solvers::<T>::contains_key(&solver);
+            let count = matches.len() as u32;
+            for m in matches {
+                if m.taker != solver {
+                    ensure!(registered, Error::<T>::NotSolver);
+                    let intent = Intents::<T>::get(m.id).ok_or(Error::<T>::UnknownIntent)?;
+                    let fee = <T as Config>::Swaps::execution_fee(intent.swap_id)?;
+                    let message = (MATCH_CONTEXT, m.id, &m.b_to_a_ct, fee).encode();
+                    ensure!(
+                        m.signature
+                            .as_ref()
+                            .is_some_and(|sig| sig.verify(&message[..], &m.taker)),
+                        Error::<T>::BadTakerSignature
+                    );
+                }
+                Self::do_match(m.id, m.taker, m.b_to_a_ct, m.b_to_a_proof, m.terms_proof)?;
+            }
+            <Pallet<T>>::deposit_event(Event::BatchMatched { solver, count });
+            Ok(())
+        }
+
+        /// Register or deregister `who` as a solver.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_solver())]
+        pub fn set_solver(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if enabled {
+                Solvers::<T>::insert(&who, ());
+            } else {
+                Solvers::<T>::remove(&who);
+            }
+            <Pallet<T>>::deposit_event(Event::SolverSet { who, enabled });
+            Ok(())
+        }
+
+        /// Remove an expired quote request. Anyone may call this; its offers stay until
+        /// they are withdrawn or expire themselves.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::prune_request())]
+        pub fn prune_request(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let request = QuoteRequests::<T>::get(id).ok_or(Error::<T>::UnknownRequest)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= request.expires_at,
+                Error::<T>::NotExpired
+            );
+            QuoteRequests::<T>::remove(id);
+            <Pallet<T>>::deposit_event(Event::QuoteRequestExpired {
+                id,
+                taker: request.taker,
+                pruned_by: who,
+            });
+            Ok(())
+        }
 
+        /// Remove an expired offer and its swap. Anyone may call this; the maker leg is
+        /// refunded through the escrow.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::prune_offer())]
+        #[transactional]
+        pub fn prune_offer(origin: OriginFor<T>, offer_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let offer = QuoteOffers::<T>::get(offer_id).ok_or(Error::<T>::UnknownOffer)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= offer.expires_at,
+                Error::<T>::NotExpired
+            );
+            QuoteOffers::<T>::remove(offer_id);
+            <T as Config>::Swaps::prune_intent_cc(&who, offer.swap_id)?;
+            Self::unlist_offer(offer.request_id, offer_id);
+            Self::close_slot(&offer.maker, offer.deposit);
+            <Pallet<T>>::deposit_event(Event::QuoteOfferExpired {
+                offer_id,
+                maker: offer.maker,
+                pruned_by: who,
+            });
             Ok(())
         }
     }
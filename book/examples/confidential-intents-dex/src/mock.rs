@@ -0,0 +1,200 @@
+use crate::pallet as pallet_confidential_intents_dex;
+use confidential_assets_primitives::{
+    ConfidentialSwapIntents, EncryptedAmount, InputProof, SwapTerms,
+};
+use frame_support::{
+    Blake2_128Concat, construct_runtime, derive_impl, pallet_prelude::ValueQuery, parameter_types,
+    storage_alias, traits::ConstU32,
+};
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::{
+    BuildStorage, DispatchError, DispatchResult,
+    testing::{TestSignature, UintAuthorityId},
+};
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+pub type Balance = u64;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const SOLVER: AccountId = 4;
+pub const ASSET_A: AssetId = 7;
+pub const ASSET_B: AssetId = 8;
+pub const ENDOWMENT: Balance = 1_000;
+pub const DEPOSIT: Balance = 10;
+pub const FEE: Balance = 2;
+
+// --- Swaps -------------------------------------------------------------------
+// Keeps intents in storage rather than thread-locals, so a failed transactional
+// call rolls the mock back with the pallet and tests can see what was undone.
+
+/// An intent opened in the mock Swaps pallet.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct MockSwap {
+    pub maker: AccountId,
+    pub counterparty: Option<AccountId>,
+    pub a_to_b_ct: EncryptedAmount,
+}
+
+/// How a mock swap was closed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum Outcome {
+    Executed(AccountId),
+    Canceled,
+    Pruned(AccountId),
+}
+
+#[storage_alias]
+pub type NextSwapId = StorageValue<MockSwaps, u64, ValueQuery>;
+#[storage_alias]
+pub type OpenSwaps = StorageMap<MockSwaps, Blake2_128Concat, u64, MockSwap>;
+#[storage_alias]
+pub type Outcomes = StorageMap<MockSwaps, Blake2_128Concat, u64, Outcome>;
+
+/// Rejected by `execute_intent_cc`, as the Swaps pallet rejects a bad leg proof.
+pub const BAD_LEG: DispatchError = DispatchError::Other("bad taker leg");
+
+pub struct MockSwaps;
+impl ConfidentialSwapIntents<AccountId, AssetId, u64> for MockSwaps {
+    type SwapId = u64;
+    type Fee = Balance;
+
+    fn open_intent_cc(
+        maker: &AccountId,
+        counterparty: Option<&AccountId>,
+        _asset_a: AssetId,
+        _asset_b: AssetId,
+        a_to_b_ct: EncryptedAmount,
+        _lock_proof: InputProof,
+        _a_to_b_proof: InputProof,
+        _terms: SwapTerms,
+        _expires_at: u64,
+    ) -> Result<u64, DispatchError> {
+        let id = NextSwapId::mutate(|n| {
+            let cur = *n;
+            *n += 1;
+            cur
+        });
+        OpenSwaps::insert(
+            id,
+            MockSwap {
+                maker: *maker,
+                counterparty: counterparty.copied(),
+                a_to_b_ct,
+            },
+        );
+        Ok(id)
+    }
+
+    // An empty `b_to_a_proof` stands in for one that fails verification
+    fn execute_intent_cc(
+        who: &AccountId,
+        id: u64,
+        b_to_a_ct: EncryptedAmount,
+        b_to_a_proof: InputProof,
+        _terms_proof: InputProof,
+    ) -> Result<(u64, EncryptedAmount), DispatchError> {
+        let swap = OpenSwaps::take(id).ok_or(DispatchError::Other("unknown swap"))?;
+        if swap.counterparty.is_some_and(|c| c != *who) {
+            return Err(DispatchError::BadOrigin);
+        }
+        if b_to_a_proof.is_empty() {
+            return Err(BAD_LEG);
+        }
+        Outcomes::insert(id, Outcome::Executed(*who));
+        Ok((id, b_to_a_ct))
+    }
+
+    fn cancel_intent_cc(maker: &AccountId, id: u64, _refund_proof: InputProof) -> DispatchResult {
+        let swap = OpenSwaps::take(id).ok_or(DispatchError::Other("unknown swap"))?;
+        if swap.maker != *maker {
+            return Err(DispatchError::BadOrigin);
+        }
+        Outcomes::insert(id, Outcome::Canceled);
+        Ok(())
+    }
+
+    fn prune_intent_cc(who: &AccountId, id: u64) -> DispatchResult {
+        OpenSwaps::take(id).ok_or(DispatchError::Other("unknown swap"))?;
+        Outcomes::insert(id, Outcome::Pruned(*who));
+        Ok(())
+    }
+
+    fn execution_fee(id: u64) -> Result<Balance, DispatchError> {
+        OpenSwaps::get(id)
+            .map(|_| FEE)
+            .ok_or(DispatchError::Other("unknown swap"))
+    }
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const IntentDeposit: Balance = DEPOSIT;
+}
+impl pallet_confidential_intents_dex::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = AssetId;
+    type Balance = Balance;
+    type SwapId = u64;
+    type Swaps = MockSwaps;
+    type MaxBatch = ConstU32<4>;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type TakerSignature = TestSignature;
+    type TakerPublic = UintAuthorityId;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type IntentDeposit = IntentDeposit;
+    type MaxIntentsPerAccount = ConstU32<3>;
+    type MaxOffersPerRequest = ConstU32<3>;
+    type WeightInfo = ();
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Balances: pallet_balances,
+        Dex: pallet_confidential_intents_dex,
+    }
+);
+
+// Build a fresh externalities for each test.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![
+            (ALICE, ENDOWMENT),
+            (BOB, ENDOWMENT),
+            (CHARLIE, ENDOWMENT),
+            (SOLVER, ENDOWMENT),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Construct InputProof from raw bytes; the mock Swaps pallet only checks it is non-empty
+pub fn proof(bytes: &[u8]) -> InputProof {
+    bytes.to_vec().try_into().expect("bounded vec")
+}
+
+// A ciphertext standing for `v`; the mock never decrypts it
+pub fn ct(v: u8) -> EncryptedAmount {
+    EncryptedAmount::from_parts([v; 32], [v; 32])
+}
@@ -0,0 +1,338 @@
+use crate::{
+    MATCH_CONTEXT,
+    mock::*,
+    pallet::{
+        BatchMatch, Error, Event, Intents, OpenIntents, QuoteOffers, QuoteRequests, RequestOffers,
+    },
+};
+use confidential_assets_primitives::{EncryptedAmount, SwapTerms};
+use frame_support::{BoundedVec, assert_noop, assert_ok};
+use parity_scale_codec::Encode;
+use sp_runtime::testing::TestSignature;
+
+// helpers
+fn has_event(event: Event<Runtime>) -> bool {
+    System::events()
+        .iter()
+        .any(|record| record.event == RuntimeEvent::Dex(event.clone()))
+}
+// ALICE offers 3 of ASSET_A for any ASSET_B leg until `expires_at`
+fn open(expires_at: u64) -> u64 {
+    let id = Dex::next_id();
+    assert_ok!(Dex::open_intent(
+        RuntimeOrigin::signed(ALICE),
+        ASSET_A,
+        ASSET_B,
+        ct(3),
+        proof(&[0]),
+        proof(&[1]),
+        SwapTerms::Any,
+        expires_at,
+    ));
+    id
+}
+fn offer(maker: AccountId, request_id: u64) -> u64 {
+    let id = Dex::next_offer_id();
+    assert_ok!(Dex::offer_quote(
+        RuntimeOrigin::signed(maker),
+        request_id,
+        ct(3),
+        proof(&[0]),
+        proof(&[1]),
+        SwapTerms::Any,
+        10,
+    ));
+    id
+}
+// `taker` buys intent `id` with `b_to_a_ct`, signed over the current swap fee
+fn signed_match(
+    id: u64,
+    taker: AccountId,
+    b_to_a_ct: EncryptedAmount,
+    b_to_a_proof: &[u8],
+) -> BatchMatch<AccountId, TestSignature> {
+    let message = (MATCH_CONTEXT, id, &b_to_a_ct, FEE).encode();
+    BatchMatch {
+        id,
+        taker,
+        b_to_a_ct,
+        b_to_a_proof: proof(b_to_a_proof),
+        terms_proof: proof(&[]),
+        signature: Some(TestSignature(taker, message)),
+    }
+}
+fn batch(
+    matches: Vec<BatchMatch<AccountId, TestSignature>>,
+) -> BoundedVec<BatchMatch<AccountId, TestSignature>, <Runtime as crate::pallet::Config>::MaxBatch>
+{
+    matches.try_into().expect("within MaxBatch")
+}
+
+#[test]
+fn accepting_a_quote_closes_the_request_and_its_rival_offers() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dex::request_quote(
+            RuntimeOrigin::signed(BOB),
+            ASSET_A,
+            ASSET_B,
+            None,
+            10,
+        ));
+        let rival = offer(ALICE, 0);
+        let accepted = offer(CHARLIE, 0);
+        assert_eq!(
+            RequestOffers::<Runtime>::get(0).into_inner(),
+            vec![rival, accepted]
+        );
+        assert_eq!(Balances::reserved_balance(ALICE), DEPOSIT);
+        assert_eq!(Balances::reserved_balance(CHARLIE), DEPOSIT);
+        // Offers are bound to the request's taker
+        assert_eq!(
+            OpenSwaps::get(QuoteOffers::<Runtime>::get(accepted).unwrap().swap_id)
+                .unwrap()
+                .counterparty,
+            Some(BOB)
+        );
+
+        assert_noop!(
+            Dex::accept_quote(
+                RuntimeOrigin::signed(ALICE),
+                accepted,
+                ct(4),
+                proof(&[2]),
+                proof(&[]),
+            ),
+            Error::<Runtime>::NotTaker
+        );
+        assert_ok!(Dex::accept_quote(
+            RuntimeOrigin::signed(BOB),
+            accepted,
+            ct(4),
+            proof(&[2]),
+            proof(&[]),
+        ));
+        assert!(has_event(Event::QuoteAccepted {
+            request_id: 0,
+            offer_id: accepted,
+            maker: CHARLIE,
+            taker: BOB,
+            swap_id: 1,
+        }));
+        assert!(has_event(Event::QuoteOfferSuperseded {
+            offer_id: rival,
+            maker: ALICE,
+            swap_id: 0,
+        }));
+        assert_eq!(Outcomes::get(1), Some(Outcome::Executed(BOB)));
+
+        // Request and offers are gone, and both makers have their deposits back
+        assert!(QuoteRequests::<Runtime>::get(0).is_none());
+        assert!(RequestOffers::<Runtime>::get(0).is_empty());
+        assert!(QuoteOffers::<Runtime>::get(rival).is_none());
+        assert!(QuoteOffers::<Runtime>::get(accepted).is_none());
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+        assert_eq!(OpenIntents::<Runtime>::get(ALICE), 0);
+        assert_eq!(OpenIntents::<Runtime>::get(CHARLIE), 0);
+
+        // The rival leg stays escrowed in the Swaps pallet for its maker to cancel there
+        assert!(OpenSwaps::get(0).is_some());
+        assert_noop!(
+            Dex::withdraw_offer(RuntimeOrigin::signed(ALICE), rival, proof(&[5])),
+            Error::<Runtime>::UnknownOffer
+        );
+        assert_noop!(
+            Dex::offer_quote(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                ct(3),
+                proof(&[0]),
+                proof(&[1]),
+                SwapTerms::Any,
+                10,
+            ),
+            Error::<Runtime>::UnknownRequest
+        );
+    });
+}
+
+#[test]
+fn match_many_rolls_back_every_match_when_one_fails() {
+    new_test_ext().execute_with(|| {
+        let first = open(10);
+        let second = open(10);
+        assert_ok!(Dex::set_solver(RuntimeOrigin::root(), SOLVER, true));
+
+        // The second leg's proof is rejected by the Swaps pallet
+        assert_noop!(
+            Dex::match_many(
+                RuntimeOrigin::signed(SOLVER),
+                batch(vec![
+                    signed_match(first, BOB, ct(4), &[2]),
+                    signed_match(second, CHARLIE, ct(4), &[]),
+                ]),
+            ),
+            BAD_LEG
+        );
+        // So the first match was undone with it
+        assert!(Intents::<Runtime>::get(first).is_some());
+        assert!(Intents::<Runtime>::get(second).is_some());
+        assert!(Outcomes::get(0).is_none());
+        assert!(OpenSwaps::get(0).is_some());
+        assert_eq!(Balances::reserved_balance(ALICE), 2 * DEPOSIT);
+        assert_eq!(OpenIntents::<Runtime>::get(ALICE), 2);
+
+        assert_ok!(Dex::match_many(
+            RuntimeOrigin::signed(SOLVER),
+            batch(vec![
+                signed_match(first, BOB, ct(4), &[2]),
+                signed_match(second, CHARLIE, ct(4), &[3]),
+            ]),
+        ));
+        assert!(has_event(Event::BatchMatched {
+            solver: SOLVER,
+            count: 2,
+        }));
+        assert_eq!(Outcomes::get(0), Some(Outcome::Executed(BOB)));
+        assert_eq!(Outcomes::get(1), Some(Outcome::Executed(CHARLIE)));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(OpenIntents::<Runtime>::get(ALICE), 0);
+    });
+}
+
+#[test]
+fn match_many_needs_a_solver_and_each_takers_signature() {
+    new_test_ext().execute_with(|| {
+        let id = open(10);
+        let m = signed_match(id, BOB, ct(4), &[2]);
+        assert_noop!(
+            Dex::match_many(RuntimeOrigin::signed(SOLVER), batch(vec![m.clone()])),
+            Error::<Runtime>::NotSolver
+        );
+        assert_ok!(Dex::set_solver(RuntimeOrigin::root(), SOLVER, true));
+
+        // Signed for another leg, for a lower fee, or not at all
+        let other_leg = BatchMatch {
+            b_to_a_ct: ct(5),
+            ..m.clone()
+        };
+        let lower_fee = BatchMatch {
+            signature: Some(TestSignature(
+                BOB,
+                (MATCH_CONTEXT, id, &ct(4), FEE - 1).encode(),
+            )),
+            ..m.clone()
+        };
+        let unsigned = BatchMatch {
+            signature: None,
+            ..m.clone()
+        };
+        for bad in [other_leg, lower_fee, unsigned] {
+            assert_noop!(
+                Dex::match_many(RuntimeOrigin::signed(SOLVER), batch(vec![bad])),
+                Error::<Runtime>::BadTakerSignature
+            );
+        }
+
+        // A taker matching for itself needs neither
+        assert_ok!(Dex::match_many(
+            RuntimeOrigin::signed(BOB),
+            batch(vec![BatchMatch {
+                signature: None,
+                ..m
+            }]),
+        ));
+        assert!(has_event(Event::IntentMatched {
+            id,
+            maker: ALICE,
+            taker: BOB,
+            swap_id: 0,
+        }));
+    });
+}
+
+#[test]
+fn intent_deposits_are_returned_on_cancel_and_on_expiry() {
+    new_test_ext().execute_with(|| {
+        let canceled = open(5);
+        let expired = open(5);
+        assert_eq!(Balances::reserved_balance(ALICE), 2 * DEPOSIT);
+        assert_eq!(OpenIntents::<Runtime>::get(ALICE), 2);
+
+        assert_noop!(
+            Dex::cancel_intent(RuntimeOrigin::signed(BOB), canceled, proof(&[4])),
+            Error::<Runtime>::NotMaker
+        );
+        assert_ok!(Dex::cancel_intent(
+            RuntimeOrigin::signed(ALICE),
+            canceled,
+            proof(&[4])
+        ));
+        assert!(has_event(Event::IntentCanceled {
+            id: canceled,
+            maker: ALICE,
+        }));
+        assert_eq!(Outcomes::get(0), Some(Outcome::Canceled));
+        assert_eq!(Balances::reserved_balance(ALICE), DEPOSIT);
+        assert_eq!(OpenIntents::<Runtime>::get(ALICE), 1);
+
+        assert_noop!(
+            Dex::prune_expired(RuntimeOrigin::signed(CHARLIE), expired),
+            Error::<Runtime>::NotExpired
+        );
+        System::set_block_number(5);
+        assert_noop!(
+            Dex::match_intent(
+                RuntimeOrigin::signed(BOB),
+                expired,
+                ct(4),
+                proof(&[2]),
+                proof(&[]),
+            ),
+            Error::<Runtime>::Expired
+        );
+
+        // Anyone may prune it; the deposit goes back to the maker
+        assert_ok!(Dex::prune_expired(RuntimeOrigin::signed(CHARLIE), expired));
+        assert!(has_event(Event::IntentExpired {
+            id: expired,
+            maker: ALICE,
+            pruned_by: CHARLIE,
+        }));
+        assert_eq!(Outcomes::get(1), Some(Outcome::Pruned(CHARLIE)));
+        assert!(Intents::<Runtime>::get(expired).is_none());
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), ENDOWMENT);
+        assert_eq!(OpenIntents::<Runtime>::get(ALICE), 0);
+    });
+}
+
+#[test]
+fn open_intents_are_capped_per_maker() {
+    new_test_ext().execute_with(|| {
+        let first = open(10);
+        open(10);
+        open(10);
+        assert_noop!(
+            Dex::open_intent(
+                RuntimeOrigin::signed(ALICE),
+                ASSET_A,
+                ASSET_B,
+                ct(3),
+                proof(&[0]),
+                proof(&[1]),
+                SwapTerms::Any,
+                10,
+            ),
+            Error::<Runtime>::TooManyIntents
+        );
+        // Closing one frees its slot
+        assert_ok!(Dex::cancel_intent(
+            RuntimeOrigin::signed(ALICE),
+            first,
+            proof(&[4])
+        ));
+        open(10);
+        assert_eq!(Balances::reserved_balance(ALICE), 3 * DEPOSIT);
+    });
+}
@@ -1,38 +1,117 @@
 // pallets/confidential-swap/src/lib.rs
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 extern crate alloc;
 
-use frame_support::{dispatch::DispatchResult, pallet_prelude::*, transactional};
+use frame_support::{
+    dispatch::DispatchResult,
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate},
+        tokens::Preservation,
+    },
+    transactional,
+};
 use frame_system::pallet_prelude::*;
-use sp_runtime::RuntimeDebug;
+use parity_scale_codec::DecodeWithMemTracking;
+use sp_runtime::{
+    Permill, RuntimeDebug,
+    traits::{AtLeast32BitUnsigned, Zero},
+};
 use sp_std::prelude::*;
 
 use confidential_assets_primitives::{
-    ConfidentialBackend, ConfidentialSwapIntents, EncryptedAmount, InputProof,
+    ConfidentialBackend, ConfidentialEscrow, ConfidentialSwapIntents, EncryptedAmount, EscrowId,
+    EscrowPurpose, EscrowTrust, InputProof, Ramp, RatioVerifier, SwapTerms,
 };
 
+/// Purpose tag of the escrow locks holding maker legs.
+pub const SWAP_PURPOSE: EscrowPurpose = *b"swapping";
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
-    /// Optional commitment to taker-leg terms (KISS: blake2 hash of (asset_b, b_to_a_ct)).
-    /// If all zeros, any taker leg is accepted.
-    #[derive(
-        Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default, RuntimeDebug,
-    )]
-    pub struct TermsHash(pub [u8; 32]);
-
     /// Confidential↔Confidential maker intent.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
-    pub struct SwapIntentCc<AccountId, AssetId> {
+    pub struct SwapIntentCc<AccountId, AssetId, BlockNumber> {
+        pub proposer: AccountId,
+        pub counterparty: Option<AccountId>, // None -> open to whoever accepts first
+        pub asset_a: AssetId,                // maker sends on A
+        pub asset_b: AssetId,                // taker sends on B
+        pub a_to_b_ct: EncryptedAmount,      // maker ciphertext (A -> counterparty)
+        pub a_to_b_proof: InputProof,        // maker proof releasing the escrowed leg
+        pub escrow_id: EscrowId,             // lock holding the maker leg until execution
+        pub terms: SwapTerms,                // what the taker leg must satisfy
+        pub expires_at: BlockNumber,         // not executable from this block on
+    }
+
+    pub type SwapIntentCcOf<T> = SwapIntentCc<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::AssetId,
+        BlockNumberFor<T>,
+    >;
+
+    /// Maker side of a Confidential↔Public intent.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    pub enum MakerLeg<Balance> {
+        /// An escrowed confidential amount, sold for `price` units of the public asset.
+        Confidential {
+            ct: EncryptedAmount,
+            release_proof: InputProof,
+            escrow_id: EscrowId,
+            price: Balance,
+        },
+        /// `amount` escrowed units of the public asset, sold for a confidential leg
+        /// meeting `terms` (`Any` or `Exact`; there is no ciphertext to rate it against).
+        Public { amount: Balance, terms: SwapTerms },
+    }
+
+    /// Confidential↔Public maker intent: one leg moves in plaintext through the public
+    /// asset's `Ramp`, the other stays confidential.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    pub struct SwapIntentCp<AccountId, AssetId, Balance, BlockNumber> {
         pub proposer: AccountId,
-        pub counterparty: AccountId,
-        pub asset_a: AssetId,           // maker sends on A
-        pub asset_b: AssetId,           // taker sends on B
-        pub a_to_b_ct: EncryptedAmount, // maker ciphertext (A -> counterparty)
-        pub a_to_b_proof: InputProof,   // maker proof
-        pub terms_hash: TermsHash,      // optional predicate binding taker leg
+        pub counterparty: Option<AccountId>,
+        pub confidential_asset: AssetId,
+        pub public_asset: AssetId,
+        pub maker_leg: MakerLeg<Balance>,
+        pub expires_at: BlockNumber,
+    }
+
+    pub type SwapIntentCpOf<T> = SwapIntentCp<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::AssetId,
+        <T as Config>::Balance,
+        BlockNumberFor<T>,
+    >;
+
+    pub type NativeBalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Protocol fee the taker pays when a swap executes.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        TypeInfo,
+        MaxEncodedLen,
+        Default,
+        RuntimeDebug,
+    )]
+    pub struct SwapFee<Balance> {
+        /// Native fee per execution, partial or whole.
+        pub flat: Balance,
+        /// Basis points of the disclosed notional of swaps with a public leg.
+        pub bps: u16,
     }
 
     #[pallet::config]
@@ -40,10 +119,44 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         type AssetId: Parameter + Member + Copy + Ord + MaxEncodedLen + TypeInfo;
-        type Balance: Parameter + Member + Copy + Ord + MaxEncodedLen + TypeInfo + Default; // kept to satisfy ConfidentialSwap trait signature
+        /// Balance of public legs.
+        type Balance: Parameter
+            + Member
+            + Copy
+            + Ord
+            + MaxEncodedLen
+            + TypeInfo
+            + Default
+            + AtLeast32BitUnsigned;
 
         type Backend: ConfidentialBackend<Self::AccountId, Self::AssetId, Self::Balance>;
 
+        /// Holds the maker leg from open to execution, so the maker cannot spend it
+        /// meanwhile. Locks expire with their intent.
+        type Escrow: ConfidentialEscrow<Self::AccountId, Self::AssetId, BlockNumberFor<Self>>;
+
+        /// Checks the ratio proofs of `SwapTerms::Rate` intents.
+        type RatioVerifier: RatioVerifier;
+
+        /// Moves public legs between accounts.
+        type Ramp: Ramp<Self::AccountId, Self::AssetId, Self::Balance>;
+
+        /// Holds public maker legs from open to execution.
+        type PublicEscrow: EscrowTrust<Self::AccountId, Self::AssetId, Self::Balance>;
+
+        /// Native currency the flat fee is paid in.
+        type Currency: Mutate<Self::AccountId>;
+
+        /// Receives swap fees.
+        type FeeCollector: Get<Self::AccountId>;
+
+        /// Flat fee of executions on assets without an `AssetFees` entry.
+        #[pallet::constant]
+        type DefaultFee: Get<NativeBalanceOf<Self>>;
+
+        /// Sets per-asset fees.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -51,6 +164,13 @@ pub mod pallet {
         fn open_cc() -> Weight;
         fn cancel_cc() -> Weight;
         fn accept_cc() -> Weight;
+        fn prune_cc() -> Weight;
+        fn fill_cc() -> Weight;
+        fn set_asset_fee() -> Weight;
+        fn open_cp() -> Weight;
+        fn cancel_cp() -> Weight;
+        fn accept_cp() -> Weight;
+        fn prune_cp() -> Weight;
     }
 
     impl WeightInfo for () {
@@ -63,12 +183,34 @@ pub mod pallet {
         fn accept_cc() -> Weight {
             25_000.into()
         }
+        fn prune_cc() -> Weight {
+            5_000.into()
+        }
+        fn fill_cc() -> Weight {
+            35_000.into()
+        }
+        fn set_asset_fee() -> Weight {
+            5_000.into()
+        }
+        fn open_cp() -> Weight {
+            15_000.into()
+        }
+        fn cancel_cp() -> Weight {
+            10_000.into()
+        }
+        fn accept_cp() -> Weight {
+            25_000.into()
+        }
+        fn prune_cp() -> Weight {
+            10_000.into()
+        }
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
     // ---- Storage ----
+    /// Next intent id, shared by C↔C and C↔P intents.
     #[pallet::storage]
     #[pallet::getter(fn next_cc_id)]
     pub type NextCcId<T> = StorageValue<_, u64, ValueQuery>;
@@ -76,7 +218,18 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn cc_swaps)]
     pub type CcSwaps<T: Config> =
-        StorageMap<_, Blake2_128Concat, u64, SwapIntentCc<T::AccountId, T::AssetId>, OptionQuery>;
+        StorageMap<_, Blake2_128Concat, u64, SwapIntentCcOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn cp_swaps)]
+    pub type CpSwaps<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, SwapIntentCpOf<T>, OptionQuery>;
+
+    /// Fee overrides for swaps whose maker sells the asset; `DefaultFee` otherwise.
+    #[pallet::storage]
+    #[pallet::getter(fn asset_fees)]
+    pub type AssetFees<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, SwapFee<NativeBalanceOf<T>>, OptionQuery>;
 
     // ---- Events / Errors ----
     #[pallet::event]
@@ -85,9 +238,10 @@ pub mod pallet {
         CcOpened {
             id: u64,
             proposer: T::AccountId,
-            counterparty: T::AccountId,
+            counterparty: Option<T::AccountId>,
             asset_a: T::AssetId,
             asset_b: T::AssetId,
+            expires_at: BlockNumberFor<T>,
         },
         CcCanceled {
             id: u64,
@@ -98,6 +252,61 @@ pub mod pallet {
             proposer: T::AccountId,
             counterparty: T::AccountId,
         },
+        /// Part of intent `id` was swapped; the rest of the maker leg is re-listed as
+        /// `remainder_id`.
+        CcPartiallyFilled {
+            id: u64,
+            proposer: T::AccountId,
+            counterparty: T::AccountId,
+            remainder_id: u64,
+        },
+        /// An expired intent was removed by `pruned_by`.
+        CcExpired {
+            id: u64,
+            proposer: T::AccountId,
+            pruned_by: T::AccountId,
+        },
+        CpOpened {
+            id: u64,
+            proposer: T::AccountId,
+            counterparty: Option<T::AccountId>,
+            confidential_asset: T::AssetId,
+            public_asset: T::AssetId,
+            expires_at: BlockNumberFor<T>,
+        },
+        CpCanceled {
+            id: u64,
+            proposer: T::AccountId,
+        },
+        CpExecuted {
+            id: u64,
+            proposer: T::AccountId,
+            counterparty: T::AccountId,
+        },
+        /// An expired C↔P intent was removed by `pruned_by`.
+        CpExpired {
+            id: u64,
+            proposer: T::AccountId,
+            pruned_by: T::AccountId,
+        },
+        /// `payer` paid `amount` in fees for executing intent `id`.
+        FeeCharged {
+            id: u64,
+            payer: T::AccountId,
+            amount: NativeBalanceOf<T>,
+        },
+        /// `payer` paid `amount` of `asset` in fees on the public leg of intent `id`.
+        PublicFeeCharged {
+            id: u64,
+            payer: T::AccountId,
+            asset: T::AssetId,
+            amount: T::Balance,
+        },
+        /// Executions selling `asset` pay `fee`, or the default fee if `None`.
+        AssetFeeSet {
+            asset: T::AssetId,
+            fee: Option<SwapFee<NativeBalanceOf<T>>>,
+        },
     }
 
     #[pallet::error]
@@ -105,43 +314,161 @@ pub mod pallet {
         UnknownSwap,
         NotProposer,
         NotCounterparty,
-        TermsMismatch, // taker leg did not match maker's hash predicate
+        TermsMismatch, // taker leg did not satisfy the maker's terms
         BackendError,
+        AssetPaused, // the backend has paused one of the legs' assets
+        EscrowError,
+        InvalidExpiry, // expires_at is not in the future
+        Expired,
+        NotExpired,
+        InvalidCiphertext,
+        RampError,
+        MissingTakerLeg, // public maker legs are bought with a confidential taker leg
     }
 
     impl<T: Config> Pallet<T> {
-        /// Core C↔C execution with checks (no events). Used by both extrinsic & trait.
-        fn exec_cc_inner(
+        fn next_id() -> u64 {
+            NextCcId::<T>::mutate(|n| {
+                let cur = *n;
+                *n = n.saturating_add(1);
+                cur
+            })
+        }
+
+        /// Escrow the maker leg under `lock_proof`, store a new C↔C intent and emit
+        /// `CcOpened`. Used by both extrinsic & trait.
+        #[allow(clippy::too_many_arguments)]
+        fn open_cc(
+            proposer: T::AccountId,
+            counterparty: Option<T::AccountId>,
+            asset_a: T::AssetId,
+            asset_b: T::AssetId,
+            a_to_b_ct: EncryptedAmount,
+            lock_proof: InputProof,
+            a_to_b_proof: InputProof,
+            terms: SwapTerms,
+            expires_at: BlockNumberFor<T>,
+        ) -> Result<u64, DispatchError> {
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidExpiry
+            );
+            let escrow_id = T::Escrow::escrow_lock_until(
+                asset_a,
+                &proposer,
+                a_to_b_ct,
+                SWAP_PURPOSE,
+                expires_at,
+                lock_proof,
+            )
+            .map_err(|_| Error::<T>::EscrowError)?;
+            let id = Self::next_id();
+            CcSwaps::<T>::insert(
+                id,
+                SwapIntentCc {
+                    proposer: proposer.clone(),
+                    counterparty: counterparty.clone(),
+                    asset_a,
+                    asset_b,
+                    a_to_b_ct,
+                    a_to_b_proof,
+                    escrow_id,
+                    terms,
+                    expires_at,
+                },
+            );
+            Self::deposit_event(Event::CcOpened {
+                id,
+                proposer,
+                counterparty,
+                asset_a,
+                asset_b,
+                expires_at,
+            });
+            Ok(id)
+        }
+
+        /// Drop `maker`'s intent `id` and refund its escrowed leg.
+        fn cancel_cc(maker: &T::AccountId, id: u64, refund_proof: InputProof) -> DispatchResult {
+            let intent = CcSwaps::<T>::take(id).ok_or(Error::<T>::UnknownSwap)?;
+            ensure!(intent.proposer == *maker, Error::<T>::NotProposer);
+            T::Escrow::escrow_refund(intent.escrow_id, intent.a_to_b_ct, refund_proof)
+                .map_err(|_| Error::<T>::EscrowError)?;
+            Self::deposit_event(Event::CcCanceled {
+                id,
+                proposer: maker.clone(),
+            });
+            Ok(())
+        }
+
+        /// Drop expired intent `id`; its lock expired with it.
+        fn prune_cc(who: &T::AccountId, id: u64) -> DispatchResult {
+            let intent = CcSwaps::<T>::get(id).ok_or(Error::<T>::UnknownSwap)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= intent.expires_at,
+                Error::<T>::NotExpired
+            );
+            CcSwaps::<T>::remove(id);
+            Self::deposit_event(Event::CcExpired {
+                id,
+                proposer: intent.proposer,
+                pruned_by: who.clone(),
+            });
+            Ok(())
+        }
+
+        /// Take live intent `id` for `counterparty`. An open intent binds to
+        /// `counterparty` here.
+        fn take_for(
             id: u64,
             counterparty: &T::AccountId,
-            b_to_a_ct: EncryptedAmount,
-            b_to_a_proof: InputProof,
-        ) -> Result<SwapIntentCc<T::AccountId, T::AssetId>, DispatchError> {
+        ) -> Result<SwapIntentCcOf<T>, DispatchError> {
             let intent = CcSwaps::<T>::take(id).ok_or(Error::<T>::UnknownSwap)?;
             ensure!(
-                &intent.counterparty == counterparty,
-                Error::<T>::NotCounterparty
+                frame_system::Pallet::<T>::block_number() < intent.expires_at,
+                Error::<T>::Expired
             );
-
-            // Optional predicate: bind taker leg (asset_b, b_to_a_ct).
-            if intent.terms_hash.0 != [0u8; 32] {
-                let mut enc = intent.asset_b.encode();
-                enc.extend_from_slice(&b_to_a_ct);
-                let h = sp_io::hashing::blake2_256(&enc);
-                ensure!(h == intent.terms_hash.0, Error::<T>::TermsMismatch);
+            if let Some(named) = &intent.counterparty {
+                ensure!(named == counterparty, Error::<T>::NotCounterparty);
             }
+            ensure!(
+                !T::Backend::is_paused(intent.asset_a) && !T::Backend::is_paused(intent.asset_b),
+                Error::<T>::AssetPaused
+            );
+            Ok(intent)
+        }
 
-            // Leg 1: proposer -> counterparty on asset_a
-            T::Backend::transfer_encrypted(
-                intent.asset_a,
-                &intent.proposer,
-                counterparty,
-                intent.a_to_b_ct,
-                intent.a_to_b_proof.clone(),
-            )
-            .map_err(|_| Error::<T>::BackendError)?;
+        /// Check the taker leg `b_to_a_ct` against the intent's terms for `maker_leg`,
+        /// the part of the maker leg it buys.
+        fn check_terms(
+            intent: &SwapIntentCcOf<T>,
+            maker_leg: &EncryptedAmount,
+            b_to_a_ct: &EncryptedAmount,
+            terms_proof: &InputProof,
+        ) -> DispatchResult {
+            let ok = match &intent.terms {
+                SwapTerms::Any => true,
+                // Bind taker leg (asset_b, b_to_a_ct).
+                SwapTerms::Exact(hash) => {
+                    let mut enc = intent.asset_b.encode();
+                    enc.extend_from_slice(b_to_a_ct);
+                    sp_io::hashing::blake2_256(&enc) == *hash
+                }
+                SwapTerms::Rate { min, max } => {
+                    T::RatioVerifier::verify_ratio(maker_leg, b_to_a_ct, *min, *max, terms_proof)
+                }
+            };
+            ensure!(ok, Error::<T>::TermsMismatch);
+            Ok(())
+        }
 
-            // Leg 2: counterparty -> proposer on asset_b
+        /// Taker leg: counterparty -> proposer on asset_b.
+        fn pay_proposer(
+            intent: &SwapIntentCcOf<T>,
+            counterparty: &T::AccountId,
+            b_to_a_ct: EncryptedAmount,
+            b_to_a_proof: InputProof,
+        ) -> DispatchResult {
             T::Backend::transfer_encrypted(
                 intent.asset_b,
                 counterparty,
@@ -150,69 +477,302 @@ pub mod pallet {
                 b_to_a_proof,
             )
             .map_err(|_| Error::<T>::BackendError)?;
+            Ok(())
+        }
+
+        /// Fee schedule of swaps selling `asset`.
+        pub fn fee_for(asset: T::AssetId) -> SwapFee<NativeBalanceOf<T>> {
+            AssetFees::<T>::get(asset).unwrap_or(SwapFee {
+                flat: T::DefaultFee::get(),
+                bps: 0,
+            })
+        }
+
+        /// Charge `payer` the flat fee of executing intent `id`, which sells `asset`, and
+        /// emit `FeeCharged`.
+        fn charge_fee(id: u64, asset: T::AssetId, payer: &T::AccountId) -> DispatchResult {
+            let amount = Self::fee_for(asset).flat;
+            if amount.is_zero() {
+                return Ok(());
+            }
+            T::Currency::transfer(
+                payer,
+                &T::FeeCollector::get(),
+                amount,
+                Preservation::Preserve,
+            )?;
+            Self::deposit_event(Event::FeeCharged {
+                id,
+                payer: payer.clone(),
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Core C↔C execution with checks. Used by both extrinsic & trait.
+        fn exec_cc_inner(
+            id: u64,
+            counterparty: &T::AccountId,
+            b_to_a_ct: EncryptedAmount,
+            b_to_a_proof: InputProof,
+            terms_proof: InputProof,
+        ) -> Result<SwapIntentCcOf<T>, DispatchError> {
+            let intent = Self::take_for(id, counterparty)?;
+            Self::check_terms(&intent, &intent.a_to_b_ct, &b_to_a_ct, &terms_proof)?;
+            Self::charge_fee(id, intent.asset_a, counterparty)?;
+
+            // Leg 1: escrowed maker leg -> counterparty on asset_a
+            T::Escrow::escrow_release(
+                intent.escrow_id,
+                counterparty,
+                intent.a_to_b_ct,
+                intent.a_to_b_proof.clone(),
+            )
+            .map_err(|_| Error::<T>::EscrowError)?;
+
+            Self::pay_proposer(&intent, counterparty, b_to_a_ct, b_to_a_proof)?;
+            Ok(intent)
+        }
+
+        /// Swap `partial` of intent `id`'s maker leg for the taker leg and re-list the
+        /// rest under a new id, which is returned with the intent.
+        #[allow(clippy::too_many_arguments)]
+        fn fill_cc_inner(
+            id: u64,
+            counterparty: &T::AccountId,
+            partial: EncryptedAmount,
+            release_proof: InputProof,
+            remainder_proof: InputProof,
+            b_to_a_ct: EncryptedAmount,
+            b_to_a_proof: InputProof,
+            terms_proof: InputProof,
+        ) -> Result<(SwapIntentCcOf<T>, u64), DispatchError> {
+            let intent = Self::take_for(id, counterparty)?;
+            Self::check_terms(&intent, &partial, &b_to_a_ct, &terms_proof)?;
+            Self::charge_fee(id, intent.asset_a, counterparty)?;
+            let remainder = intent
+                .a_to_b_ct
+                .sub(&partial)
+                .map_err(|_| Error::<T>::InvalidCiphertext)?;
 
+            // Leg 1: `partial` of the escrowed maker leg -> counterparty on asset_a
+            T::Escrow::escrow_release_partial(
+                intent.escrow_id,
+                counterparty,
+                partial,
+                release_proof,
+                remainder_proof,
+            )
+            .map_err(|_| Error::<T>::EscrowError)?;
+
+            Self::pay_proposer(&intent, counterparty, b_to_a_ct, b_to_a_proof)?;
+
+            // The rest stays in the same lock. Its release proof went with the whole leg,
+            // so it fills through `fill_swap_cc` or goes back to the maker.
+            let remainder_id = Self::next_id();
+            CcSwaps::<T>::insert(
+                remainder_id,
+                SwapIntentCc {
+                    a_to_b_ct: remainder,
+                    a_to_b_proof: InputProof::default(),
+                    ..intent.clone()
+                },
+            );
+            Ok((intent, remainder_id))
+        }
+
+        /// Store a new C↔P intent and emit `CpOpened`; its maker leg is escrowed.
+        fn insert_cp(intent: SwapIntentCpOf<T>) -> Result<u64, DispatchError> {
+            ensure!(
+                intent.expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidExpiry
+            );
+            let id = Self::next_id();
+            Self::deposit_event(Event::CpOpened {
+                id,
+                proposer: intent.proposer.clone(),
+                counterparty: intent.counterparty.clone(),
+                confidential_asset: intent.confidential_asset,
+                public_asset: intent.public_asset,
+                expires_at: intent.expires_at,
+            });
+            CpSwaps::<T>::insert(id, intent);
+            Ok(id)
+        }
+
+        /// Basis-point fee of `notional`, the public leg of an intent on `asset`.
+        fn public_fee(asset: T::AssetId, notional: T::Balance) -> T::Balance {
+            let bps = Self::fee_for(asset).bps.min(10_000);
+            Permill::from_parts(u32::from(bps) * 100).mul_floor(notional)
+        }
+
+        /// Execute C↔P intent `id` for `counterparty`. A public maker leg is bought with
+        /// `taker_leg`, a confidential one with the public `price`.
+        fn exec_cp_inner(
+            id: u64,
+            counterparty: &T::AccountId,
+            taker_leg: Option<(EncryptedAmount, InputProof)>,
+        ) -> Result<SwapIntentCpOf<T>, DispatchError> {
+            let intent = CpSwaps::<T>::take(id).ok_or(Error::<T>::UnknownSwap)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < intent.expires_at,
+                Error::<T>::Expired
+            );
+            if let Some(named) = &intent.counterparty {
+                ensure!(named == counterparty, Error::<T>::NotCounterparty);
+            }
+            let collector = T::FeeCollector::get();
+
+            match &intent.maker_leg {
+                MakerLeg::Confidential {
+                    ct,
+                    release_proof,
+                    escrow_id,
+                    price,
+                } => {
+                    Self::charge_fee(id, intent.confidential_asset, counterparty)?;
+                    // Public leg: counterparty -> proposer, plus the fee on it
+                    let fee = Self::public_fee(intent.public_asset, *price);
+                    T::Ramp::transfer_from(
+                        counterparty,
+                        &intent.proposer,
+                        intent.public_asset,
+                        *price,
+                    )
+                    .map_err(|_| Error::<T>::RampError)?;
+                    if !fee.is_zero() {
+                        T::Ramp::transfer_from(counterparty, &collector, intent.public_asset, fee)
+                            .map_err(|_| Error::<T>::RampError)?;
+                        Self::deposit_event(Event::PublicFeeCharged {
+                            id,
+                            payer: counterparty.clone(),
+                            asset: intent.public_asset,
+                            amount: fee,
+                        });
+                    }
+                    // Confidential leg: escrowed maker leg -> counterparty
+                    T::Escrow::escrow_release(*escrow_id, counterparty, *ct, release_proof.clone())
+                        .map_err(|_| Error::<T>::EscrowError)?;
+                }
+                MakerLeg::Public { amount, terms } => {
+                    let (b_to_a_ct, b_to_a_proof) = taker_leg.ok_or(Error::<T>::MissingTakerLeg)?;
+                    let ok = match terms {
+                        SwapTerms::Any => true,
+                        SwapTerms::Exact(hash) => {
+                            let mut enc = intent.confidential_asset.encode();
+                            enc.extend_from_slice(&b_to_a_ct);
+                            sp_io::hashing::blake2_256(&enc) == *hash
+                        }
+                        SwapTerms::Rate { .. } => false,
+                    };
+                    ensure!(ok, Error::<T>::TermsMismatch);
+                    Self::charge_fee(id, intent.public_asset, counterparty)?;
+                    // Confidential leg: counterparty -> proposer
+                    T::Backend::transfer_encrypted(
+                        intent.confidential_asset,
+                        counterparty,
+                        &intent.proposer,
+                        b_to_a_ct,
+                        b_to_a_proof,
+                    )
+                    .map_err(|_| Error::<T>::BackendError)?;
+                    // Public leg: escrowed maker leg -> counterparty, less the fee on it
+                    let fee = Self::public_fee(intent.public_asset, *amount);
+                    T::PublicEscrow::escrow_release(
+                        intent.public_asset,
+                        counterparty,
+                        amount.saturating_sub(fee),
+                    )?;
+                    if !fee.is_zero() {
+                        T::PublicEscrow::escrow_release(intent.public_asset, &collector, fee)?;
+                        Self::deposit_event(Event::PublicFeeCharged {
+                            id,
+                            payer: counterparty.clone(),
+                            asset: intent.public_asset,
+                            amount: fee,
+                        });
+                    }
+                }
+            }
             Ok(intent)
         }
+
+        /// Give the escrowed maker leg of C↔P intent `intent` back. A confidential leg
+        /// needs `refund_proof`, unless its lock expired, when `None` leaves it to the
+        /// escrow.
+        fn refund_cp(
+            intent: &SwapIntentCpOf<T>,
+            refund_proof: Option<InputProof>,
+        ) -> DispatchResult {
+            match &intent.maker_leg {
+                MakerLeg::Confidential { ct, escrow_id, .. } => {
+                    if let Some(proof) = refund_proof {
+                        T::Escrow::escrow_refund(*escrow_id, *ct, proof)
+                            .map_err(|_| Error::<T>::EscrowError)?;
+                    }
+                }
+                MakerLeg::Public { amount, .. } => {
+                    T::PublicEscrow::escrow_refund(intent.public_asset, &intent.proposer, *amount)?;
+                }
+            }
+            Ok(())
+        }
     }
 
     // ---- Calls ----
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Maker opens a C↔C intent, optionally binding taker leg with a terms hash.
+        /// Maker opens a C↔C intent whose taker leg must satisfy `terms`.
+        /// Without a `counterparty` the first account to accept it becomes one.
+        ///
+        /// `a_to_b_ct` is escrowed under `lock_proof` until the intent executes, is
+        /// canceled or expires; `a_to_b_proof` releases it to the taker.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::open_cc())]
+        #[transactional]
+        #[allow(clippy::too_many_arguments)]
         pub fn open_swap_cc(
             origin: OriginFor<T>,
-            counterparty: T::AccountId,
+            counterparty: Option<T::AccountId>,
             asset_a: T::AssetId,
             asset_b: T::AssetId,
             a_to_b_ct: EncryptedAmount,
+            lock_proof: InputProof,
             a_to_b_proof: InputProof,
-            terms_hash: Option<[u8; 32]>, // None -> accept any taker ciphertext on (asset_b)
+            terms: SwapTerms,
+            expires_at: BlockNumberFor<T>,
         ) -> DispatchResult {
             let proposer = ensure_signed(origin)?;
-
-            let id = NextCcId::<T>::mutate(|n| {
-                let cur = *n;
-                *n = n.saturating_add(1);
-                cur
-            });
-            let th = TermsHash(terms_hash.unwrap_or([0u8; 32]));
-            CcSwaps::<T>::insert(
-                id,
-                SwapIntentCc {
-                    proposer: proposer.clone(),
-                    counterparty: counterparty.clone(),
-                    asset_a,
-                    asset_b,
-                    a_to_b_ct,
-                    a_to_b_proof,
-                    terms_hash: th,
-                },
-            );
-
-            Self::deposit_event(Event::CcOpened {
-                id,
+            Self::open_cc(
                 proposer,
                 counterparty,
                 asset_a,
                 asset_b,
-            });
+                a_to_b_ct,
+                lock_proof,
+                a_to_b_proof,
+                terms,
+                expires_at,
+            )?;
             Ok(())
         }
 
-        /// Cancel a C↔C intent (maker only).
+        /// Cancel a C↔C intent (maker only), refunding the escrowed leg under
+        /// `refund_proof`.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::cancel_cc())]
-        pub fn cancel_swap_cc(origin: OriginFor<T>, id: u64) -> DispatchResult {
+        #[transactional]
+        pub fn cancel_swap_cc(
+            origin: OriginFor<T>,
+            id: u64,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let intent = CcSwaps::<T>::take(id).ok_or(Error::<T>::UnknownSwap)?;
-            ensure!(intent.proposer == who, Error::<T>::NotProposer);
-            Self::deposit_event(Event::CcCanceled { id, proposer: who });
-            Ok(())
+            Self::cancel_cc(&who, id, refund_proof)
         }
 
-        /// Accept and atomically execute a C↔C swap.
+        /// Accept and atomically execute a C↔C swap. `terms_proof` is the ratio proof
+        /// of `SwapTerms::Rate` intents and ignored otherwise.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::accept_cc())]
         #[transactional]
@@ -221,9 +781,11 @@ pub mod pallet {
             id: u64,
             b_to_a_ct: EncryptedAmount,
             b_to_a_proof: InputProof,
+            terms_proof: InputProof,
         ) -> DispatchResult {
             let counterparty = ensure_signed(origin)?;
-            let intent = Self::exec_cc_inner(id, &counterparty, b_to_a_ct, b_to_a_proof)?;
+            let intent =
+                Self::exec_cc_inner(id, &counterparty, b_to_a_ct, b_to_a_proof, terms_proof)?;
             Self::deposit_event(Event::CcExecuted {
                 id,
                 proposer: intent.proposer,
@@ -231,46 +793,227 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Remove an expired C↔C intent. Anyone may call this; its escrow lock expired
+        /// with it, so the maker leg is refunded through the escrow.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::prune_cc())]
+        pub fn prune_expired_cc(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::prune_cc(&who, id)
+        }
+
+        /// Swap part of a C↔C intent's maker leg: `partial` is split off the escrowed leg
+        /// under `release_proof` and `remainder_proof` and swapped for the taker leg; the
+        /// rest is re-listed as a new intent with the same terms, which a `Rate` ratio
+        /// proof checks against `partial`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::fill_cc())]
+        #[transactional]
+        #[allow(clippy::too_many_arguments)]
+        pub fn fill_swap_cc(
+            origin: OriginFor<T>,
+            id: u64,
+            partial: EncryptedAmount,
+            release_proof: InputProof,
+            remainder_proof: InputProof,
+            b_to_a_ct: EncryptedAmount,
+            b_to_a_proof: InputProof,
+            terms_proof: InputProof,
+        ) -> DispatchResult {
+            let counterparty = ensure_signed(origin)?;
+            let (intent, remainder_id) = Self::fill_cc_inner(
+                id,
+                &counterparty,
+                partial,
+                release_proof,
+                remainder_proof,
+                b_to_a_ct,
+                b_to_a_proof,
+                terms_proof,
+            )?;
+            Self::deposit_event(Event::CcPartiallyFilled {
+                id,
+                proposer: intent.proposer,
+                counterparty,
+                remainder_id,
+            });
+            Ok(())
+        }
+
+        /// Set the fee of executions selling `asset`; `None` restores `DefaultFee`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_asset_fee())]
+        pub fn set_asset_fee(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            fee: Option<SwapFee<NativeBalanceOf<T>>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            AssetFees::<T>::set(asset, fee.clone());
+            Self::deposit_event(Event::AssetFeeSet { asset, fee });
+            Ok(())
+        }
+
+        /// Maker sells an escrowed confidential amount of `confidential_asset` for
+        /// `price` units of `public_asset`, paid in plaintext through the `Ramp`.
+        /// `a_to_b_ct` is escrowed under `lock_proof`; `a_to_b_proof` releases it.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::open_cp())]
+        #[transactional]
+        #[allow(clippy::too_many_arguments)]
+        pub fn open_swap_cp(
+            origin: OriginFor<T>,
+            counterparty: Option<T::AccountId>,
+            confidential_asset: T::AssetId,
+            public_asset: T::AssetId,
+            a_to_b_ct: EncryptedAmount,
+            lock_proof: InputProof,
+            a_to_b_proof: InputProof,
+            price: T::Balance,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            let escrow_id = T::Escrow::escrow_lock_until(
+                confidential_asset,
+                &proposer,
+                a_to_b_ct,
+                SWAP_PURPOSE,
+                expires_at,
+                lock_proof,
+            )
+            .map_err(|_| Error::<T>::EscrowError)?;
+            Self::insert_cp(SwapIntentCp {
+                proposer,
+                counterparty,
+                confidential_asset,
+                public_asset,
+                maker_leg: MakerLeg::Confidential {
+                    ct: a_to_b_ct,
+                    release_proof: a_to_b_proof,
+                    escrow_id,
+                    price,
+                },
+                expires_at,
+            })?;
+            Ok(())
+        }
+
+        /// Maker sells `amount` escrowed units of `public_asset` for a confidential leg
+        /// of `confidential_asset` meeting `terms`.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::open_cp())]
+        #[transactional]
+        pub fn open_swap_pc(
+            origin: OriginFor<T>,
+            counterparty: Option<T::AccountId>,
+            public_asset: T::AssetId,
+            confidential_asset: T::AssetId,
+            amount: T::Balance,
+            terms: SwapTerms,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            T::PublicEscrow::escrow_lock(public_asset, &proposer, amount)?;
+            Self::insert_cp(SwapIntentCp {
+                proposer,
+                counterparty,
+                confidential_asset,
+                public_asset,
+                maker_leg: MakerLeg::Public { amount, terms },
+                expires_at,
+            })?;
+            Ok(())
+        }
+
+        /// Accept and atomically execute a C↔P swap. `taker_leg` is the confidential
+        /// payment for a public maker leg; a confidential maker leg is paid its public
+        /// price instead.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::accept_cp())]
+        #[transactional]
+        pub fn accept_swap_cp(
+            origin: OriginFor<T>,
+            id: u64,
+            taker_leg: Option<(EncryptedAmount, InputProof)>,
+        ) -> DispatchResult {
+            let counterparty = ensure_signed(origin)?;
+            let intent = Self::exec_cp_inner(id, &counterparty, taker_leg)?;
+            Self::deposit_event(Event::CpExecuted {
+                id,
+                proposer: intent.proposer,
+                counterparty,
+            });
+            Ok(())
+        }
+
+        /// Cancel a C↔P intent (maker only), refunding its escrowed leg; a confidential
+        /// one under `refund_proof`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::cancel_cp())]
+        #[transactional]
+        pub fn cancel_swap_cp(
+            origin: OriginFor<T>,
+            id: u64,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let intent = CpSwaps::<T>::take(id).ok_or(Error::<T>::UnknownSwap)?;
+            ensure!(intent.proposer == who, Error::<T>::NotProposer);
+            Self::refund_cp(&intent, Some(refund_proof))?;
+            Self::deposit_event(Event::CpCanceled { id, proposer: who });
+            Ok(())
+        }
+
+        /// Remove an expired C↔P intent. Anyone may call this: a public maker leg goes
+        /// straight back to the maker, a confidential one through its expired lock.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::prune_cp())]
+        #[transactional]
+        pub fn prune_expired_cp(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let intent = CpSwaps::<T>::take(id).ok_or(Error::<T>::UnknownSwap)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= intent.expires_at,
+                Error::<T>::NotExpired
+            );
+            Self::refund_cp(&intent, None)?;
+            Self::deposit_event(Event::CpExpired {
+                id,
+                proposer: intent.proposer,
+                pruned_by: who,
+            });
+            Ok(())
+        }
     }
 
-    impl<T: Config> ConfidentialSwapIntents<T::AccountId, T::AssetId> for Pallet<T> {
+    impl<T: Config> ConfidentialSwapIntents<T::AccountId, T::AssetId, BlockNumberFor<T>> for Pallet<T> {
         type SwapId = u64;
+        type Fee = NativeBalanceOf<T>;
 
+        #[transactional]
         fn open_intent_cc(
             maker: &T::AccountId,
-            counterparty: &T::AccountId,
+            counterparty: Option<&T::AccountId>,
             asset_a: T::AssetId,
             asset_b: T::AssetId,
             a_to_b_ct: EncryptedAmount,
+            lock_proof: InputProof,
             a_to_b_proof: InputProof,
-            terms_hash: Option<[u8; 32]>,
+            terms: SwapTerms,
+            expires_at: BlockNumberFor<T>,
         ) -> Result<Self::SwapId, DispatchError> {
-            let id = NextCcId::<T>::mutate(|n| {
-                let cur = *n;
-                *n = n.saturating_add(1);
-                cur
-            });
-            let th = TermsHash(terms_hash.unwrap_or([0u8; 32]));
-            CcSwaps::<T>::insert(
-                id,
-                SwapIntentCc {
-                    proposer: maker.clone(),
-                    counterparty: counterparty.clone(),
-                    asset_a,
-                    asset_b,
-                    a_to_b_ct,
-                    a_to_b_proof,
-                    terms_hash: th,
-                },
-            );
-            <Pallet<T>>::deposit_event(Event::CcOpened {
-                id,
-                proposer: maker.clone(),
-                counterparty: counterparty.clone(),
+            Self::open_cc(
+                maker.clone(),
+                counterparty.cloned(),
                 asset_a,
                 asset_b,
-            });
-            Ok(id)
+                a_to_b_ct,
+                lock_proof,
+                a_to_b_proof,
+                terms,
+                expires_at,
+            )
         }
 
         /// Accept a C↔C intent on behalf of `who`.
@@ -281,8 +1024,9 @@ pub mod pallet {
             id: Self::SwapId,
             b_to_a_ct: EncryptedAmount,
             b_to_a_proof: InputProof,
+            terms_proof: InputProof,
         ) -> Result<(Self::SwapId, EncryptedAmount), DispatchError> {
-            let intent = Self::exec_cc_inner(id, who, b_to_a_ct, b_to_a_proof)?;
+            let intent = Self::exec_cc_inner(id, who, b_to_a_ct, b_to_a_proof, terms_proof)?;
             <Pallet<T>>::deposit_event(Event::CcExecuted {
                 id,
                 proposer: intent.proposer.clone(),
@@ -291,14 +1035,22 @@ pub mod pallet {
             Ok((id, intent.a_to_b_ct))
         }
 
-        fn cancel_intent_cc(maker: &T::AccountId, id: Self::SwapId) -> DispatchResult {
-            let intent = CcSwaps::<T>::take(id).ok_or(Error::<T>::UnknownSwap)?;
-            ensure!(intent.proposer == *maker, Error::<T>::NotProposer);
-            <Pallet<T>>::deposit_event(Event::CcCanceled {
-                id,
-                proposer: maker.clone(),
-            });
-            Ok(())
+        #[transactional]
+        fn cancel_intent_cc(
+            maker: &T::AccountId,
+            id: Self::SwapId,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
+            Self::cancel_cc(maker, id, refund_proof)
+        }
+
+        fn prune_intent_cc(who: &T::AccountId, id: Self::SwapId) -> DispatchResult {
+            Self::prune_cc(who, id)
+        }
+
+        fn execution_fee(id: Self::SwapId) -> Result<NativeBalanceOf<T>, DispatchError> {
+            let intent = CcSwaps::<T>::get(id).ok_or(Error::<T>::UnknownSwap)?;
+            Ok(Self::fee_for(intent.asset_a).flat)
         }
     }
 }
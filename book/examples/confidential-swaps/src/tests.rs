@@ -0,0 +1,193 @@
+use crate::{
+    SWAP_PURPOSE,
+    mock::*,
+    pallet::{CcSwaps, Error, Event, SwapIntentCc},
+};
+use confidential_assets_primitives::{
+    Commitment, ConfidentialBackend, EncryptedAmount, InputProof, SwapTerms,
+};
+use frame_support::{assert_noop, assert_ok};
+
+// helpers
+fn commit(v: u64) -> Commitment {
+    Commitment::new(ct(v).nonce_point())
+}
+fn pending(asset: AssetId, who: AccountId) -> Vec<(u64, EncryptedAmount)> {
+    <Zkhe as ConfidentialBackend<AccountId, AssetId, Balance>>::pending_transfers(asset, &who)
+}
+fn has_event(event: Event<Runtime>) -> bool {
+    System::events()
+        .iter()
+        .any(|record| record.event == RuntimeEvent::Swaps(event.clone()))
+}
+// ALICE offers `amount` of ASSET_A for any ASSET_B leg until block 10
+fn open(amount: u64) -> u64 {
+    let id = Swaps::next_cc_id();
+    assert_ok!(Swaps::open_swap_cc(
+        RuntimeOrigin::signed(ALICE),
+        None,
+        ASSET_A,
+        ASSET_B,
+        ct(amount),
+        proof(&[0]),
+        proof(&[1]),
+        SwapTerms::Any,
+        10,
+    ));
+    id
+}
+
+#[test]
+fn partial_fill_relists_the_remainder_in_the_same_lock() {
+    new_test_ext().execute_with(|| {
+        let id = open(3);
+        let intent = CcSwaps::<Runtime>::get(id).expect("open");
+        let lock = ConfidentialEscrow::escrow(intent.escrow_id).expect("locked");
+        assert_eq!(lock.remaining, commit(3));
+        assert_eq!(lock.purpose, SWAP_PURPOSE);
+
+        assert_ok!(Swaps::fill_swap_cc(
+            RuntimeOrigin::signed(BOB),
+            id,
+            ct(1),
+            proof(&[2]),
+            proof(&[3]),
+            ct(4),
+            proof(&[4]),
+            InputProof::default(),
+        ));
+        let remainder_id = id + 1;
+        assert!(has_event(Event::CcPartiallyFilled {
+            id,
+            proposer: ALICE,
+            counterparty: BOB,
+            remainder_id,
+        }));
+        assert!(has_event(Event::FeeCharged {
+            id,
+            payer: BOB,
+            amount: FEE,
+        }));
+        assert_eq!(Balances::free_balance(FEE_COLLECTOR), ENDOWMENT + FEE);
+
+        // Both legs moved: one unit of A to the taker, its B leg to the maker
+        assert_eq!(pending(ASSET_A, BOB), vec![(0, ct(1))]);
+        assert_eq!(pending(ASSET_B, ALICE), vec![(0, ct(4))]);
+
+        // The rest is listed anew, on the same lock and terms, without a release proof
+        assert!(CcSwaps::<Runtime>::get(id).is_none());
+        assert_eq!(
+            CcSwaps::<Runtime>::get(remainder_id),
+            Some(SwapIntentCc {
+                a_to_b_ct: ct(2),
+                a_to_b_proof: InputProof::default(),
+                ..intent.clone()
+            })
+        );
+        let lock = ConfidentialEscrow::escrow(intent.escrow_id).expect("still locked");
+        assert_eq!(lock.encrypted_amount, ct(2));
+        assert_eq!(lock.remaining, commit(2));
+
+        // So it cannot be accepted whole, only refunded or filled again
+        assert_noop!(
+            Swaps::accept_swap_cc(
+                RuntimeOrigin::signed(CHARLIE),
+                remainder_id,
+                ct(5),
+                proof(&[6]),
+                InputProof::default(),
+            ),
+            Error::<Runtime>::EscrowError
+        );
+        assert_ok!(Swaps::cancel_swap_cc(
+            RuntimeOrigin::signed(ALICE),
+            remainder_id,
+            proof(&[7]),
+        ));
+        assert!(has_event(Event::CcCanceled {
+            id: remainder_id,
+            proposer: ALICE,
+        }));
+        assert_eq!(pending(ASSET_A, ALICE), vec![(0, ct(2))]);
+        assert!(ConfidentialEscrow::escrow(intent.escrow_id).is_none());
+    });
+}
+
+#[test]
+fn remainder_can_be_filled_again() {
+    new_test_ext().execute_with(|| {
+        let id = open(3);
+        assert_ok!(Swaps::fill_swap_cc(
+            RuntimeOrigin::signed(BOB),
+            id,
+            ct(1),
+            proof(&[2]),
+            proof(&[3]),
+            ct(4),
+            proof(&[4]),
+            InputProof::default(),
+        ));
+        assert_ok!(Swaps::fill_swap_cc(
+            RuntimeOrigin::signed(CHARLIE),
+            id + 1,
+            ct(1),
+            proof(&[5]),
+            proof(&[6]),
+            ct(4),
+            proof(&[7]),
+            InputProof::default(),
+        ));
+        assert_eq!(pending(ASSET_A, CHARLIE), vec![(0, ct(1))]);
+        assert_eq!(
+            CcSwaps::<Runtime>::get(id + 2).expect("relisted").a_to_b_ct,
+            ct(1)
+        );
+        let escrow_id = CcSwaps::<Runtime>::get(id + 2).unwrap().escrow_id;
+        assert_eq!(
+            ConfidentialEscrow::escrow(escrow_id).unwrap().remaining,
+            commit(1)
+        );
+    });
+}
+
+#[test]
+fn partial_fill_checks_the_intent_first() {
+    new_test_ext().execute_with(|| {
+        let fill = |who, id| {
+            Swaps::fill_swap_cc(
+                RuntimeOrigin::signed(who),
+                id,
+                ct(1),
+                proof(&[2]),
+                proof(&[3]),
+                ct(4),
+                proof(&[4]),
+                InputProof::default(),
+            )
+        };
+        assert_noop!(fill(BOB, 0), Error::<Runtime>::UnknownSwap);
+
+        assert_ok!(Swaps::open_swap_cc(
+            RuntimeOrigin::signed(ALICE),
+            Some(CHARLIE),
+            ASSET_A,
+            ASSET_B,
+            ct(3),
+            proof(&[0]),
+            proof(&[1]),
+            SwapTerms::Any,
+            10,
+        ));
+        assert_noop!(fill(BOB, 0), Error::<Runtime>::NotCounterparty);
+
+        // A taker leg the terms do not allow
+        let id = open(3);
+        CcSwaps::<Runtime>::mutate(id, |intent| {
+            intent.as_mut().unwrap().terms = SwapTerms::Exact([0u8; 32]);
+        });
+        assert_noop!(fill(BOB, id), Error::<Runtime>::TermsMismatch);
+
+        System::set_block_number(10);
+        assert_noop!(fill(CHARLIE, 0), Error::<Runtime>::Expired);
+    });
+}
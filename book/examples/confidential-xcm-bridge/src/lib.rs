@@ -1,14 +1,24 @@
 // pallets/confidential-xcm-bridge/src/lib.rs
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 extern crate alloc;
 
+#[cfg(feature = "xcm-router")]
+pub mod router;
+#[cfg(feature = "xcm-router")]
+pub use router::XcmRouterViaPalletXcm;
+
 #[frame_support::pallet]
 pub mod pallet {
     use confidential_assets_primitives::*;
     use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
     use frame_system::pallet_prelude::*;
-    use parity_scale_codec::{Decode, Encode};
+    use parity_scale_codec::{Decode, DecodeLimit, Encode};
     use scale_info::TypeInfo;
     use sp_std::prelude::*;
 
@@ -16,7 +26,7 @@ pub mod pallet {
     pub trait XcmRouter {
         type ParaId: Parameter + Copy + MaxEncodedLen + TypeInfo;
         type Weight: Parameter + Copy + MaxEncodedLen + TypeInfo + Default;
-        type FeeAssetId: Parameter + Copy + MaxEncodedLen + TypeInfo;
+        type FeeAssetId: Parameter + MaxEncodedLen + TypeInfo;
         type FeeBalance: Parameter + Copy + MaxEncodedLen + TypeInfo;
 
         /// Send a SCALE-encoded payload to `dest` via XCM::Transact.
@@ -27,6 +37,39 @@ pub mod pallet {
             fee: Self::FeeBalance,
             weight_limit: Self::Weight,
         ) -> Result<(), DispatchError>;
+
+        /// Send an encoded [`RemoteResult`] back to `dest`. Unlike `send_transact`, this
+        /// must land on the source's `handle_remote_result`.
+        fn send_result(
+            dest: Self::ParaId,
+            payload: Vec<u8>,
+            fee_asset: Self::FeeAssetId,
+            fee: Self::FeeBalance,
+            weight_limit: Self::Weight,
+        ) -> Result<(), DispatchError>;
+    }
+
+    /// Which `RemoteCall`s a para may have executed here.
+    #[derive(
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Default,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        MaxEncodedLen,
+        RuntimeDebug,
+    )]
+    pub struct ParaPermissions {
+        /// `ReceiveConfidentialTransfer`: mint what the para burned.
+        pub transfers: bool,
+        /// HTLC redemption and linking.
+        pub htlc: bool,
+        /// `Forward`: relay calls on to other paras, as a hub like Asset Hub does.
+        pub relay: bool,
     }
 
     // === Escrow param used by the confidential HTLC path ===
@@ -39,10 +82,17 @@ pub mod pallet {
         EscrowParam,
     >>::Secret;
 
+    // Internal helper alias; the hashlock also travels as SCALE bytes.
+    pub type HtlcHashLockOf<T> = <<T as Config>::ConfidentialHtlc as BridgeHtlc<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::AssetId,
+        EscrowParam,
+    >>::HashLock;
+
     // === SCALE payloads carried by XCM::Transact ===
     // IMPORTANT: Do not leak `Secret` in public metadata; use Vec<u8> on the wire.
     #[derive(Encode, Decode, TypeInfo, Clone)]
-    pub enum RemoteCall<AccountId, AssetId> {
+    pub enum RemoteCall<AccountId, AssetId, ParaId> {
         /// Credit a confidential transfer on the destination chain.
         ReceiveConfidentialTransfer {
             sender_on_src: [u8; 32],
@@ -66,6 +116,62 @@ pub mod pallet {
             htlc_id: u64,
             final_sig: Vec<u8>,
         },
+        /// Record that the destination's HTLC `htlc_id` is the counterpart of the
+        /// source's `src_htlc_id`.
+        LinkHtlc {
+            src_para: ParaId,
+            src_htlc_id: u64,
+            htlc_id: u64,
+        },
+        /// Pass `call` on towards `dest`, through at most `hops` more intermediate paras.
+        /// `LinkHtlc` cannot be relayed, as its source must be its sender.
+        Forward {
+            dest: ParaId,
+            hops: u8,
+            call: Box<RemoteCall<AccountId, AssetId, ParaId>>,
+        },
+    }
+
+    /// A `RemoteCall` on the wire, tagged with the source's nonce for its result.
+    #[derive(Encode, Decode, TypeInfo, Clone)]
+    pub struct RemoteMessage<AccountId, AssetId, ParaId> {
+        pub nonce: u64,
+        pub call: RemoteCall<AccountId, AssetId, ParaId>,
+    }
+
+    /// Outcome of the message `nonce`, reported by its destination.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct RemoteResult {
+        pub nonce: u64,
+        pub success: bool,
+    }
+
+    /// What the source does with the result of a message from its destination.
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    pub enum Undo<ParaId> {
+        /// On failure, forget the link of local HTLC `htlc_id`; the HTLC itself refunds
+        /// on expiry.
+        Unlink { htlc_id: u64 },
+        /// Report the result on to `to` as that of its message `nonce`, which this chain
+        /// relayed.
+        Relay { to: ParaId, nonce: u64 },
+    }
+
+    /// Purpose tag of the escrow locks outbound transfers open.
+    pub const XCM_TRANSFER_PURPOSE: EscrowPurpose = *b"xcm-xfer";
+
+    /// An outbound transfer escrowed until its destination reports the mint.
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    pub struct OutboundTransfer<AccountId, AssetId, ParaId, BlockNumber> {
+        pub from: AccountId,
+        pub dest: ParaId,
+        pub asset: AssetId,
+        pub delta_ciphertext: EncryptedAmount,
+        pub escrow_id: EscrowId,
+        /// Block from which the sender may refund without a result.
+        pub deadline: BlockNumber,
+        /// The destination reported the mint failed; the sender may refund at once.
+        pub failed: bool,
     }
 
     // === Config ===
@@ -79,9 +185,16 @@ pub mod pallet {
         /// Balance value type (unused directly here, kept for symmetry with Backend)
         type Balance: Parameter + Member + Copy + Ord + MaxEncodedLen + TypeInfo + Default;
 
-        /// Backend for encrypted balances (used for debit on source, mint on destination).
+        /// Backend for encrypted balances (used for mint on destination).
         type Backend: ConfidentialBackend<Self::AccountId, Self::AssetId, Self::Balance>;
 
+        /// Escrow holding outbound transfers until the destination reports the mint.
+        type Escrow: ConfidentialEscrow<Self::AccountId, Self::AssetId, BlockNumberFor<Self>>;
+
+        /// Blocks after which the sender of a transfer without a result may refund it.
+        #[pallet::constant]
+        type TransferTimeout: Get<BlockNumberFor<Self>>;
+
         /// Plug-in ramp (kept for symmetry; unused by this pallet’s calls right now).
         type Ramp: Ramp<Self::AccountId, Self::AssetId, Self::Balance>;
 
@@ -100,9 +213,29 @@ pub mod pallet {
         /// Concrete types for the router.
         type ParaId: Parameter + Copy + MaxEncodedLen + TypeInfo;
         type XcmWeight: Parameter + Copy + MaxEncodedLen + TypeInfo + Default;
-        type FeeAssetId: Parameter + Copy + MaxEncodedLen + TypeInfo;
+        type FeeAssetId: Parameter + MaxEncodedLen + TypeInfo;
         type FeeBalance: Parameter + Copy + MaxEncodedLen + TypeInfo;
 
+        /// This chain's para id, sent along when linking HTLCs.
+        type SelfParaId: Get<Self::ParaId>;
+
+        /// Origin of inbound `xcm_handle` calls, yielding the sending para, e.g.
+        /// `EnsureXcm<IsSiblingPara>` mapped from the sibling's location to its id.
+        type XcmOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::ParaId>;
+
+        /// Governance origin granting paras their `ParaPermissions`.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Fee asset, amount and weight limit this chain pays to report a result back.
+        type ResultFee: Get<(Self::FeeAssetId, Self::FeeBalance, Self::XcmWeight)>;
+
+        /// Fee asset, amount and weight limit this chain pays to relay a `Forward`.
+        type ForwardFee: Get<(Self::FeeAssetId, Self::FeeBalance, Self::XcmWeight)>;
+
+        /// Intermediate paras a message may pass through on its way.
+        #[pallet::constant]
+        type MaxHops: Get<u8>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -112,6 +245,11 @@ pub mod pallet {
         fn send_htlc_redeem_with_secret() -> Weight;
         fn send_htlc_redeem_with_adaptor_sig() -> Weight;
         fn xcm_handle() -> Weight;
+        fn open_linked_htlc() -> Weight;
+        fn set_para_permissions() -> Weight;
+        fn handle_remote_result() -> Weight;
+        fn refund_transfer() -> Weight;
+        fn set_route() -> Weight;
     }
     impl WeightInfo for () {
         fn send_confidential_transfer() -> Weight {
@@ -126,6 +264,21 @@ pub mod pallet {
         fn xcm_handle() -> Weight {
             Weight::from_parts(30_000, 0)
         }
+        fn open_linked_htlc() -> Weight {
+            Weight::from_parts(45_000, 0)
+        }
+        fn set_para_permissions() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn handle_remote_result() -> Weight {
+            Weight::from_parts(30_000, 0)
+        }
+        fn refund_transfer() -> Weight {
+            Weight::from_parts(30_000, 0)
+        }
+        fn set_route() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
     }
 
     // === Storage ===
@@ -133,6 +286,40 @@ pub mod pallet {
     #[pallet::getter(fn next_nonce)]
     pub type NextNonce<T> = StorageValue<_, u64, ValueQuery>;
 
+    /// Local HTLC id -> (para, HTLC id) of its counterpart leg.
+    #[pallet::storage]
+    pub type HtlcLinks<T: Config> = StorageMap<_, Twox64Concat, u64, (T::ParaId, u64), OptionQuery>;
+
+    /// (para, remote HTLC id) -> the local HTLC linked to it.
+    #[pallet::storage]
+    pub type RemoteHtlcLinks<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::ParaId, Twox64Concat, u64, u64, OptionQuery>;
+
+    /// Nonce -> para a sent message went to without a result yet, and what to do with
+    /// its result.
+    #[pallet::storage]
+    pub type AwaitingResult<T: Config> =
+        StorageMap<_, Twox64Concat, u64, (T::ParaId, Option<Undo<T::ParaId>>), OptionQuery>;
+
+    /// Nonce -> outbound transfer still escrowed.
+    #[pallet::storage]
+    pub type Transfers<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        OutboundTransfer<T::AccountId, T::AssetId, T::ParaId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Para without a direct channel -> the para messages to it are sent through.
+    #[pallet::storage]
+    pub type Routes<T: Config> = StorageMap<_, Twox64Concat, T::ParaId, T::ParaId, OptionQuery>;
+
+    /// What each para may have executed here; paras without an entry may do nothing.
+    #[pallet::storage]
+    pub type Permissions<T: Config> =
+        StorageMap<_, Twox64Concat, T::ParaId, ParaPermissions, ValueQuery>;
+
     // === Events / Errors ===
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -143,14 +330,44 @@ pub mod pallet {
             payload_hash: [u8; 32],
         },
         XcmConfTransferApplied {
+            source: T::ParaId,
             from_tag: [u8; 32],
             to: T::AccountId,
             asset: T::AssetId,
         },
         XcmHtlcExecuted {
+            source: T::ParaId,
             who: T::AccountId,
             htlc_id: u64,
         },
+        /// Local HTLC `htlc_id` and `remote_htlc_id` on `remote_para` are the two legs of
+        /// one swap. Emitted on both chains.
+        HtlcLinked {
+            htlc_id: u64,
+            remote_para: T::ParaId,
+            remote_htlc_id: u64,
+        },
+        /// Message `nonce` from `source` failed here; its effects were discarded and the
+        /// failure reported back.
+        RemoteCallFailed { source: T::ParaId, nonce: u64 },
+        /// `dest` reported the outcome of message `nonce`; a failure was undone here.
+        RemoteResultReceived {
+            nonce: u64,
+            dest: T::ParaId,
+            success: bool,
+        },
+        /// Transfer `nonce` was refunded out of escrow to `who`.
+        TransferRefunded { nonce: u64, who: T::AccountId },
+        /// Messages to `dest` now go through `via`, or straight to it when `None`.
+        RouteSet {
+            dest: T::ParaId,
+            via: Option<T::ParaId>,
+        },
+        /// What `para` may have executed here changed.
+        ParaPermissionsSet {
+            para: T::ParaId,
+            permissions: ParaPermissions,
+        },
     }
 
     #[pallet::error]
@@ -158,8 +375,24 @@ pub mod pallet {
         RouterError,
         BackendError,
         HtlcFailed,
-        BadOriginForXcm, // replace with EnsureXcm/AuthorizedXcm origin in runtime
+        /// A `LinkHtlc` named a source para other than its sender.
+        BadOriginForXcm,
         DecodeError,
+        AssetPaused, // the backend has paused the asset
+        /// One of the HTLCs is already linked to a counterpart.
+        AlreadyLinked,
+        /// The sending para may not have this call executed here.
+        NotPermitted,
+        /// No message with that nonce awaits a result from the sending para.
+        UnexpectedResult,
+        /// No transfer with that nonce is escrowed.
+        UnknownTransfer,
+        /// Only the sender may refund a transfer.
+        NotSender,
+        /// The transfer neither failed on its destination nor passed its deadline.
+        NotRefundable,
+        /// The route to the destination passes through more than `MaxHops` paras.
+        TooManyHops,
     }
 
     #[pallet::pallet]
@@ -171,14 +404,16 @@ pub mod pallet {
     impl<T: Config> Pallet<T>
     where
         HtlcSecretOf<T>: Decode,
+        HtlcHashLockOf<T>: Decode,
     {
         /// Source-chain: confidential cross-chain send **with local debit**.
         ///
-        /// This mirrors `withdraw` in `confidential-assets`:
-        /// 1) Locally **burn** (debit) the encrypted balance using `burn_proof` (fails if insufficient).
+        /// 1) Locally **escrow** the encrypted delta using `lock_proof` (fails if insufficient).
         /// 2) XCM a payload that the destination uses to **mint** via `mint_proof`.
+        /// 3) Burn the escrow once the destination reports the mint, or let the sender
+        ///    `refund_transfer` if it reports a failure or none comes by the deadline.
         ///
-        /// We split proofs so the destination mint witness is not consumed by the source burn.
+        /// We split proofs so the destination mint witness is not consumed by the source lock.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::send_confidential_transfer())]
         pub fn send_confidential_transfer(
@@ -189,8 +424,8 @@ pub mod pallet {
             asset: T::AssetId,
             // encrypted delta being moved cross-chain
             delta_ciphertext: EncryptedAmount,
-            // proof for local **burn** (debit on SOURCE)
-            burn_proof: InputProof,
+            // proof for local **escrow lock** (debit on SOURCE)
+            lock_proof: InputProof,
             // proof to use on DESTINATION to **mint**
             mint_proof: InputProof,
             fee_asset: T::FeeAssetId,
@@ -198,38 +433,42 @@ pub mod pallet {
             weight_limit: T::XcmWeight,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            ensure!(!T::Backend::is_paused(asset), Error::<T>::AssetPaused);
 
-            // 1) Locally debit confidential balance. Fails if insufficient.
-            //    We ignore the returned plaintext `amount` to preserve privacy here.
-            let _amount =
-                T::Backend::burn_encrypted(asset, &who, delta_ciphertext.clone(), burn_proof)
-                    .map_err(|_| Error::<T>::BackendError)?;
-
-            // 2) Ship the DEST mint proof to the destination chain.
-            let call = RemoteCall::<T::AccountId, T::AssetId>::ReceiveConfidentialTransfer {
-                sender_on_src: sender_tag,
-                dest_account: beneficiary,
+            // 1) Lock the delta in escrow. Fails if insufficient. Nothing is burned until
+            //    the destination has minted, so a lost message costs no funds.
+            let escrow_id = T::Escrow::escrow_lock(
                 asset,
-                delta_ciphertext, // kept for auditability
-                mint_proof,
-            };
-            let payload = Encode::encode(&call);
-            let payload_hash = sp_io::hashing::blake2_256(&payload);
-
-            T::Xcm::send_transact(dest, payload, fee_asset, fee, weight_limit)
-                .map_err(|_| Error::<T>::RouterError)?;
-
-            let nonce = NextNonce::<T>::mutate(|n| {
-                let cur = *n;
-                *n = n.saturating_add(1);
-                cur
-            });
+                &who,
+                delta_ciphertext,
+                XCM_TRANSFER_PURPOSE,
+                lock_proof,
+            )
+            .map_err(|_| Error::<T>::BackendError)?;
 
-            Self::deposit_event(Event::XcmSent {
+            // 2) Ship the DEST mint proof to the destination chain.
+            let call =
+                RemoteCall::<T::AccountId, T::AssetId, T::ParaId>::ReceiveConfidentialTransfer {
+                    sender_on_src: sender_tag,
+                    dest_account: beneficiary,
+                    asset,
+                    delta_ciphertext, // kept for auditability
+                    mint_proof,
+                };
+            let nonce = Self::send(dest, call, None, fee_asset, fee, weight_limit)?;
+            let deadline = frame_system::Pallet::<T>::block_number() + T::TransferTimeout::get();
+            Transfers::<T>::insert(
                 nonce,
-                dest,
-                payload_hash,
-            });
+                OutboundTransfer {
+                    from: who,
+                    dest,
+                    asset,
+                    delta_ciphertext,
+                    escrow_id,
+                    deadline,
+                    failed: false,
+                },
+            );
             Ok(())
         }
 
@@ -249,28 +488,12 @@ pub mod pallet {
         ) -> DispatchResult {
             let _caller = ensure_signed(origin)?;
 
-            let call = RemoteCall::<T::AccountId, T::AssetId>::HtlcRedeemWithSecret {
+            let call = RemoteCall::<T::AccountId, T::AssetId, T::ParaId>::HtlcRedeemWithSecret {
                 who,
                 htlc_id,
                 secret_bytes,
             };
-            let payload = Encode::encode(&call);
-            let payload_hash = sp_io::hashing::blake2_256(&payload);
-
-            T::Xcm::send_transact(dest, payload, fee_asset, fee, weight_limit)
-                .map_err(|_| Error::<T>::RouterError)?;
-
-            let nonce = NextNonce::<T>::mutate(|n| {
-                let cur = *n;
-                *n = n.saturating_add(1);
-                cur
-            });
-
-            Self::deposit_event(Event::XcmSent {
-                nonce,
-                dest,
-                payload_hash,
-            });
+            Self::send(dest, call, None, fee_asset, fee, weight_limit)?;
             Ok(())
         }
 
@@ -290,42 +513,230 @@ pub mod pallet {
         ) -> DispatchResult {
             let _caller = ensure_signed(origin)?;
 
-            let call = RemoteCall::<T::AccountId, T::AssetId>::HtlcRedeemWithAdaptorSig {
-                who,
-                htlc_id,
-                final_sig,
-            };
-            let payload = Encode::encode(&call);
-            let payload_hash = sp_io::hashing::blake2_256(&payload);
-
-            T::Xcm::send_transact(dest, payload, fee_asset, fee, weight_limit)
-                .map_err(|_| Error::<T>::RouterError)?;
+            let call =
+                RemoteCall::<T::AccountId, T::AssetId, T::ParaId>::HtlcRedeemWithAdaptorSig {
+                    who,
+                    htlc_id,
+                    final_sig,
+                };
+            Self::send(dest, call, None, fee_asset, fee, weight_limit)?;
+            Ok(())
+        }
 
-            let nonce = NextNonce::<T>::mutate(|n| {
-                let cur = *n;
-                *n = n.saturating_add(1);
-                cur
-            });
+        /// Source-chain: open the local leg of a swap whose counterpart is HTLC
+        /// `remote_htlc_id` on `dest`, and link the two on both chains so watchers can
+        /// follow them. The caller is the maker; `hashlock_bytes` is the SCALE-encoded
+        /// hashlock, opaque like `secret_bytes`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::open_linked_htlc())]
+        pub fn open_linked_htlc(
+            origin: OriginFor<T>,
+            dest: T::ParaId,
+            remote_htlc_id: u64,
+            taker: Option<T::AccountId>,
+            asset: T::AssetId,
+            delta_ciphertext: EncryptedAmount,
+            lock_proof: InputProof,
+            hashlock_bytes: Vec<u8>,
+            expiry: Expiry<u32>,
+            adaptor_partial: Option<Vec<u8>>,
+            fee_asset: T::FeeAssetId,
+            fee: T::FeeBalance,
+            weight_limit: T::XcmWeight,
+        ) -> DispatchResult {
+            let maker = ensure_signed(origin)?;
+            ensure!(
+                !RemoteHtlcLinks::<T>::contains_key(dest, remote_htlc_id),
+                Error::<T>::AlreadyLinked
+            );
+            let hashlock: HtlcHashLockOf<T> = {
+                let mut cur = &hashlock_bytes[..];
+                Decode::decode(&mut cur).map_err(|_| Error::<T>::DecodeError)?
+            };
 
-            Self::deposit_event(Event::XcmSent {
-                nonce,
+            let htlc_id = <T::ConfidentialHtlc as BridgeHtlc<
+                T::AccountId,
+                T::AssetId,
+                EscrowParam,
+            >>::open_htlc(
+                &maker,
+                taker,
+                asset,
+                (delta_ciphertext, lock_proof),
+                hashlock,
+                expiry,
+                adaptor_partial,
+            )
+            .map_err(|_| Error::<T>::HtlcFailed)?;
+            Self::link(htlc_id, dest, remote_htlc_id)?;
+
+            let call = RemoteCall::<T::AccountId, T::AssetId, T::ParaId>::LinkHtlc {
+                src_para: T::SelfParaId::get(),
+                src_htlc_id: htlc_id,
+                htlc_id: remote_htlc_id,
+            };
+            Self::send(
                 dest,
-                payload_hash,
-            });
+                call,
+                Some(Undo::Unlink { htlc_id }),
+                fee_asset,
+                fee,
+                weight_limit,
+            )?;
             Ok(())
         }
 
-        // -------- Inbound handler (gate with EnsureXcm in runtime) --------
+        // -------- Inbound handler --------
 
-        /// Destination-chain: handle inbound XCM payloads.
-        /// NOTE: Use a proper XCM origin (EnsureXcm/AuthorizedXcm) in your runtime; Root here is a placeholder.
+        /// Destination-chain: handle inbound XCM payloads from the para `XcmOrigin`
+        /// yields, if its `Permissions` allow the call.
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::xcm_handle())]
         pub fn xcm_handle(origin: OriginFor<T>, payload: Vec<u8>) -> DispatchResult {
-            ensure_root(origin).map_err(|_| Error::<T>::BadOriginForXcm)?;
+            let source = T::XcmOrigin::ensure_origin(origin)?;
+
+            // `Forward` nests calls, so bound how deep the payload may go
+            let RemoteMessage { nonce, call } =
+                RemoteMessage::<T::AccountId, T::AssetId, T::ParaId>::decode_with_depth_limit(
+                    frame_support::MAX_EXTRINSIC_DEPTH,
+                    &payload[..],
+                )
+                .map_err(|_| Error::<T>::DecodeError)?;
+            let forward = matches!(call, RemoteCall::Forward { .. });
+
+            // A failed call leaves nothing behind here and is reported, so the source
+            // can undo its side. A relayed one is reported once its next hop reports it.
+            let executed =
+                frame_support::storage::with_storage_layer(|| Self::execute(source, nonce, call));
+            if !(forward && executed.is_ok()) {
+                Self::report(source, nonce, executed.is_ok())?;
+            }
+            if executed.is_err() {
+                Self::deposit_event(Event::RemoteCallFailed { source, nonce });
+            }
+            Ok(())
+        }
 
-            let call: RemoteCall<T::AccountId, T::AssetId> =
+        /// Source-chain: apply a destination's result for message `nonce`, undoing what
+        /// the source did for it if the message failed there.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::handle_remote_result())]
+        pub fn handle_remote_result(origin: OriginFor<T>, payload: Vec<u8>) -> DispatchResult {
+            let source = T::XcmOrigin::ensure_origin(origin)?;
+            let RemoteResult { nonce, success } =
                 Decode::decode(&mut &payload[..]).map_err(|_| Error::<T>::DecodeError)?;
+            let (dest, undo) =
+                AwaitingResult::<T>::get(nonce).ok_or(Error::<T>::UnexpectedResult)?;
+            ensure!(dest == source, Error::<T>::UnexpectedResult);
+            AwaitingResult::<T>::remove(nonce);
+
+            match undo {
+                Some(Undo::Unlink { htlc_id }) if !success => {
+                    if let Some((para, remote_htlc_id)) = HtlcLinks::<T>::take(htlc_id) {
+                        RemoteHtlcLinks::<T>::remove(para, remote_htlc_id);
+                    }
+                }
+                Some(Undo::Relay { to, nonce }) => Self::report(to, nonce, success)?,
+                _ => {}
+            }
+            // A minted transfer's escrow is burned; a failed one waits for its sender's refund
+            if let Some(mut transfer) = Transfers::<T>::get(nonce) {
+                if success {
+                    T::Escrow::escrow_burn(transfer.escrow_id)
+                        .map_err(|_| Error::<T>::BackendError)?;
+                    Transfers::<T>::remove(nonce);
+                } else {
+                    transfer.failed = true;
+                    Transfers::<T>::insert(nonce, transfer);
+                }
+            }
+            Self::deposit_event(Event::RemoteResultReceived {
+                nonce,
+                dest,
+                success,
+            });
+            Ok(())
+        }
+
+        /// Source-chain: return transfer `nonce` out of escrow to its sender, once its
+        /// destination reported a failure or `TransferTimeout` passed without a result.
+        /// A result arriving after the refund is rejected, so set the timeout well above
+        /// the round trip.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::refund_transfer())]
+        pub fn refund_transfer(
+            origin: OriginFor<T>,
+            nonce: u64,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let transfer = Transfers::<T>::get(nonce).ok_or(Error::<T>::UnknownTransfer)?;
+            ensure!(transfer.from == who, Error::<T>::NotSender);
+            ensure!(
+                transfer.failed || frame_system::Pallet::<T>::block_number() >= transfer.deadline,
+                Error::<T>::NotRefundable
+            );
+            ensure!(
+                !T::Backend::is_paused(transfer.asset),
+                Error::<T>::AssetPaused
+            );
+
+            T::Escrow::escrow_refund(transfer.escrow_id, transfer.delta_ciphertext, refund_proof)
+                .map_err(|_| Error::<T>::BackendError)?;
+            Transfers::<T>::remove(nonce);
+            AwaitingResult::<T>::remove(nonce);
+            Self::deposit_event(Event::TransferRefunded { nonce, who });
+            Ok(())
+        }
+
+        /// Send messages to `dest` through `via`, e.g. Asset Hub for paras without a
+        /// direct channel, or straight to `dest` again when `None`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::set_route())]
+        pub fn set_route(
+            origin: OriginFor<T>,
+            dest: T::ParaId,
+            via: Option<T::ParaId>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Routes::<T>::set(dest, via);
+            Self::deposit_event(Event::RouteSet { dest, via });
+            Ok(())
+        }
+
+        /// Set which `RemoteCall`s `para` may have executed here.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_para_permissions())]
+        pub fn set_para_permissions(
+            origin: OriginFor<T>,
+            para: T::ParaId,
+            permissions: ParaPermissions,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Permissions::<T>::insert(para, permissions);
+            Self::deposit_event(Event::ParaPermissionsSet { para, permissions });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T>
+    where
+        HtlcSecretOf<T>: Decode,
+        HtlcHashLockOf<T>: Decode,
+    {
+        /// Run message `nonce`'s `RemoteCall` from `source`, if its `Permissions` allow it.
+        fn execute(
+            source: T::ParaId,
+            nonce: u64,
+            call: RemoteCall<T::AccountId, T::AssetId, T::ParaId>,
+        ) -> DispatchResult {
+            let permissions = Permissions::<T>::get(source);
+            let permitted = match call {
+                RemoteCall::ReceiveConfidentialTransfer { .. } => permissions.transfers,
+                RemoteCall::Forward { .. } => permissions.relay,
+                _ => permissions.htlc,
+            };
+            ensure!(permitted, Error::<T>::NotPermitted);
 
             match call {
                 RemoteCall::ReceiveConfidentialTransfer {
@@ -336,9 +747,11 @@ pub mod pallet {
                     mint_proof,
                 } => {
                     // Mint on destination using the mint witness provided by the source user.
+                    ensure!(!T::Backend::is_paused(asset), Error::<T>::AssetPaused);
                     T::Backend::mint_encrypted(asset, &dest_account, mint_proof)
                         .map_err(|_| Error::<T>::BackendError)?;
                     Self::deposit_event(Event::XcmConfTransferApplied {
+                        source,
                         from_tag: sender_on_src,
                         to: dest_account,
                         asset,
@@ -361,7 +774,11 @@ pub mod pallet {
                         EscrowParam,
                     >>::redeem_with_secret(&who, htlc_id, secret)
                         .map_err(|_| Error::<T>::HtlcFailed)?;
-                    Self::deposit_event(Event::XcmHtlcExecuted { who, htlc_id });
+                    Self::deposit_event(Event::XcmHtlcExecuted {
+                        source,
+                        who,
+                        htlc_id,
+                    });
                 }
                 RemoteCall::HtlcRedeemWithAdaptorSig {
                     who,
@@ -374,11 +791,134 @@ pub mod pallet {
                         EscrowParam,
                     >>::redeem_with_adaptor_sig(&who, htlc_id, final_sig)
                         .map_err(|_| Error::<T>::HtlcFailed)?;
-                    Self::deposit_event(Event::XcmHtlcExecuted { who, htlc_id });
+                    Self::deposit_event(Event::XcmHtlcExecuted {
+                        source,
+                        who,
+                        htlc_id,
+                    });
+                }
+                RemoteCall::LinkHtlc {
+                    src_para,
+                    src_htlc_id,
+                    htlc_id,
+                } => {
+                    ensure!(src_para == source, Error::<T>::BadOriginForXcm);
+                    Self::link(htlc_id, src_para, src_htlc_id)?;
+                }
+                RemoteCall::Forward { dest, hops, call } => {
+                    // Its result goes back to `source` once `dest` (or a later hop) reports
+                    let (fee_asset, fee, weight_limit) = T::ForwardFee::get();
+                    let relay = Undo::Relay { to: source, nonce };
+                    Self::send_via(dest, hops, *call, Some(relay), fee_asset, fee, weight_limit)?;
                 }
             }
 
             Ok(())
         }
     }
+
+    impl<T: Config> Pallet<T> {
+        /// Send `call` towards `dest` under the next nonce, remembering `undo` until the
+        /// result comes back. Returns the nonce.
+        fn send(
+            dest: T::ParaId,
+            call: RemoteCall<T::AccountId, T::AssetId, T::ParaId>,
+            undo: Option<Undo<T::ParaId>>,
+            fee_asset: T::FeeAssetId,
+            fee: T::FeeBalance,
+            weight_limit: T::XcmWeight,
+        ) -> Result<u64, DispatchError> {
+            Self::send_via(
+                dest,
+                T::MaxHops::get(),
+                call,
+                undo,
+                fee_asset,
+                fee,
+                weight_limit,
+            )
+        }
+
+        /// [`Self::send`] with `hops` intermediate paras left: straight to `dest`, or
+        /// wrapped in a `Forward` to the para its route goes through.
+        fn send_via(
+            dest: T::ParaId,
+            hops: u8,
+            call: RemoteCall<T::AccountId, T::AssetId, T::ParaId>,
+            undo: Option<Undo<T::ParaId>>,
+            fee_asset: T::FeeAssetId,
+            fee: T::FeeBalance,
+            weight_limit: T::XcmWeight,
+        ) -> Result<u64, DispatchError> {
+            let (dest, call) = match Routes::<T>::get(dest) {
+                Some(via) => {
+                    ensure!(hops > 0, Error::<T>::TooManyHops);
+                    let call = RemoteCall::Forward {
+                        dest,
+                        hops: hops - 1,
+                        call: Box::new(call),
+                    };
+                    (via, call)
+                }
+                None => (dest, call),
+            };
+            let nonce = NextNonce::<T>::mutate(|n| {
+                let cur = *n;
+                *n = n.saturating_add(1);
+                cur
+            });
+            let payload = Encode::encode(&RemoteMessage { nonce, call });
+            let payload_hash = sp_io::hashing::blake2_256(&payload);
+
+            T::Xcm::send_transact(dest, payload, fee_asset, fee, weight_limit)
+                .map_err(|_| Error::<T>::RouterError)?;
+            AwaitingResult::<T>::insert(nonce, (dest, undo));
+
+            Self::deposit_event(Event::XcmSent {
+                nonce,
+                dest,
+                payload_hash,
+            });
+            Ok(nonce)
+        }
+
+        /// Report the result of message `nonce` back to `dest`, which sent it.
+        fn report(dest: T::ParaId, nonce: u64, success: bool) -> DispatchResult {
+            let (fee_asset, fee, weight_limit) = T::ResultFee::get();
+            let result = RemoteResult { nonce, success };
+            T::Xcm::send_result(dest, result.encode(), fee_asset, fee, weight_limit)
+                .map_err(|_| Error::<T>::RouterError.into())
+        }
+
+        /// Record local HTLC `htlc_id` and `remote_htlc_id` on `remote_para` as the two
+        /// legs of one swap.
+        fn link(htlc_id: u64, remote_para: T::ParaId, remote_htlc_id: u64) -> DispatchResult {
+            ensure!(
+                !HtlcLinks::<T>::contains_key(htlc_id)
+                    && !RemoteHtlcLinks::<T>::contains_key(remote_para, remote_htlc_id),
+                Error::<T>::AlreadyLinked
+            );
+            HtlcLinks::<T>::insert(htlc_id, (remote_para, remote_htlc_id));
+            RemoteHtlcLinks::<T>::insert(remote_para, remote_htlc_id, htlc_id);
+            Self::deposit_event(Event::HtlcLinked {
+                htlc_id,
+                remote_para,
+                remote_htlc_id,
+            });
+            Ok(())
+        }
+    }
+
+    #[pallet::view_functions]
+    impl<T: Config> Pallet<T> {
+        /// The (para, HTLC id) of the counterpart leg of local HTLC `htlc_id`.
+        pub fn htlc_link(htlc_id: u64) -> Option<(T::ParaId, u64)> {
+            HtlcLinks::<T>::get(htlc_id)
+        }
+
+        /// The local HTLC whose counterpart is `remote_htlc_id` on `remote_para`.
+        pub fn local_htlc(remote_para: T::ParaId, remote_htlc_id: u64) -> Option<u64> {
+            RemoteHtlcLinks::<T>::get(remote_para, remote_htlc_id)
+        }
+    }
 }
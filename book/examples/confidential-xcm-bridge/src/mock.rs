@@ -0,0 +1,408 @@
+use crate::pallet::{self as pallet_confidential_xcm_bridge, EscrowParam, RemoteResult, XcmRouter};
+use confidential_assets_primitives::{
+    BridgeHtlc, Commitment, ConfidentialBackend, ConfidentialEscrow, EncryptedAmount, EscrowId,
+    EscrowPurpose, Expiry, InputProof, PublicKeyBytes,
+};
+use frame_support::{
+    construct_runtime, derive_impl, parameter_types,
+    traits::{ConstU8, ConstU64},
+};
+use parity_scale_codec::Decode;
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult};
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+pub type Balance = u64;
+/// Paras dispatch inbound calls as signed origins of their para id.
+pub type ParaId = u64;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const SELF_PARA: ParaId = 1_000;
+pub const PARA_B: ParaId = 2_000;
+pub const PARA_C: ParaId = 3_000;
+pub const ASSET: AssetId = 7;
+pub const TIMEOUT: u64 = 20;
+pub const SECRET: [u8; 32] = [9u8; 32];
+
+// --- Router ------------------------------------------------------------------
+// Records what the pallet sends instead of sending it.
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Sent {
+    Transact(ParaId, Vec<u8>),
+    Result(ParaId, RemoteResult),
+}
+
+thread_local! {
+    /// Every message sent, in order.
+    pub static SENT: core::cell::RefCell<Vec<Sent>> = const { core::cell::RefCell::new(Vec::new()) };
+}
+
+pub fn sent() -> Vec<Sent> {
+    SENT.with(|sent| sent.borrow().clone())
+}
+
+pub struct MockRouter;
+impl XcmRouter for MockRouter {
+    type ParaId = ParaId;
+    type Weight = u64;
+    type FeeAssetId = u32;
+    type FeeBalance = u64;
+
+    fn send_transact(
+        dest: ParaId,
+        payload: Vec<u8>,
+        _fee_asset: u32,
+        _fee: u64,
+        _weight_limit: u64,
+    ) -> Result<(), DispatchError> {
+        SENT.with(|sent| sent.borrow_mut().push(Sent::Transact(dest, payload)));
+        Ok(())
+    }
+
+    fn send_result(
+        dest: ParaId,
+        payload: Vec<u8>,
+        _fee_asset: u32,
+        _fee: u64,
+        _weight_limit: u64,
+    ) -> Result<(), DispatchError> {
+        let result = RemoteResult::decode(&mut &payload[..]).expect("a RemoteResult");
+        SENT.with(|sent| sent.borrow_mut().push(Sent::Result(dest, result)));
+        Ok(())
+    }
+}
+
+// --- Escrow ------------------------------------------------------------------
+// Records what the pallet moves instead of moving it. A lock's id is its index in
+// the log.
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EscrowCall {
+    Lock(AccountId, EncryptedAmount, InputProof),
+    Burn(EscrowId),
+    Refund(EscrowId, EncryptedAmount, InputProof),
+}
+
+thread_local! {
+    /// Every escrow call made, in order.
+    pub static ESCROW_CALLS: core::cell::RefCell<Vec<EscrowCall>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+}
+
+pub fn escrow_calls() -> Vec<EscrowCall> {
+    ESCROW_CALLS.with(|calls| calls.borrow().clone())
+}
+
+fn record(call: EscrowCall) -> EscrowId {
+    ESCROW_CALLS.with(|calls| {
+        let mut calls = calls.borrow_mut();
+        calls.push(call);
+        calls.len() as EscrowId - 1
+    })
+}
+
+pub struct MockEscrow;
+impl ConfidentialEscrow<AccountId, AssetId, u64> for MockEscrow {
+    fn escrow_lock(
+        _asset: AssetId,
+        who: &AccountId,
+        encrypted_amount: EncryptedAmount,
+        _purpose: EscrowPurpose,
+        proof: InputProof,
+    ) -> Result<EscrowId, DispatchError> {
+        Ok(record(EscrowCall::Lock(*who, encrypted_amount, proof)))
+    }
+    fn escrow_lock_until(
+        _asset: AssetId,
+        _who: &AccountId,
+        _encrypted_amount: EncryptedAmount,
+        _purpose: EscrowPurpose,
+        _expires_at: u64,
+        _proof: InputProof,
+    ) -> Result<EscrowId, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn escrow_expires_at(_id: EscrowId) -> Option<u64> {
+        None
+    }
+    fn escrow_release(
+        _id: EscrowId,
+        _to: &AccountId,
+        _encrypted_amount: EncryptedAmount,
+        _proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn escrow_refund(
+        id: EscrowId,
+        encrypted_amount: EncryptedAmount,
+        proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        record(EscrowCall::Refund(id, encrypted_amount, proof));
+        Ok(())
+    }
+    fn escrow_burn(id: EscrowId) -> Result<(), DispatchError> {
+        record(EscrowCall::Burn(id));
+        Ok(())
+    }
+    fn escrow_set_release_policy(
+        _id: EscrowId,
+        _approvers: Vec<AccountId>,
+        _threshold: u32,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn escrow_release_partial(
+        _id: EscrowId,
+        _to: &AccountId,
+        _partial: EncryptedAmount,
+        _proof_release: InputProof,
+        _proof_remainder: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+}
+
+// --- HTLC --------------------------------------------------------------------
+// Opens HTLCs with consecutive ids; only `SECRET` redeems them.
+
+thread_local! {
+    /// Id the next opened HTLC gets.
+    pub static NEXT_HTLC: core::cell::Cell<u64> = const { core::cell::Cell::new(0) };
+}
+
+pub struct MockHtlc;
+impl BridgeHtlc<AccountId, AssetId, EscrowParam> for MockHtlc {
+    type HashLock = [u8; 32];
+    type Secret = [u8; 32];
+
+    fn open_htlc(
+        _maker: &AccountId,
+        _taker: Option<AccountId>,
+        _asset: AssetId,
+        _amount: EscrowParam,
+        _hashlock: [u8; 32],
+        _expiry: Expiry<u32>,
+        _adaptor_partial: Option<Vec<u8>>,
+    ) -> Result<u64, DispatchError> {
+        Ok(NEXT_HTLC.with(|next| next.replace(next.get() + 1)))
+    }
+    fn redeem_with_secret(
+        _who: &AccountId,
+        _htlc_id: u64,
+        secret: [u8; 32],
+    ) -> Result<[u8; 32], DispatchError> {
+        if secret != SECRET {
+            return Err(DispatchError::Other("bad secret"));
+        }
+        Ok(secret)
+    }
+    fn redeem_with_adaptor_sig(
+        _who: &AccountId,
+        _htlc_id: u64,
+        _final_sig: Vec<u8>,
+    ) -> Result<[u8; 32], DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn refund(_who: &AccountId, _htlc_id: u64) -> DispatchResult {
+        Err(DispatchError::Unavailable)
+    }
+}
+
+// --- Backend -----------------------------------------------------------------
+// Records mints; an empty mint proof stands in for one that fails verification.
+
+thread_local! {
+    /// Every successful mint, in order.
+    pub static MINTS: core::cell::RefCell<Vec<(AccountId, InputProof)>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+}
+
+pub fn mints() -> Vec<(AccountId, InputProof)> {
+    MINTS.with(|mints| mints.borrow().clone())
+}
+
+pub struct MockBackend;
+impl ConfidentialBackend<AccountId, AssetId, Balance> for MockBackend {
+    fn set_public_key(
+        _who: &AccountId,
+        _elgamal_pk: &PublicKeyBytes,
+        _proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn total_supply(_asset: AssetId) -> Commitment {
+        Commitment::identity()
+    }
+    fn balance_of(_asset: AssetId, _who: &AccountId) -> Commitment {
+        Commitment::identity()
+    }
+    fn pending_balance_of(_asset: AssetId, _who: &AccountId) -> Commitment {
+        Commitment::identity()
+    }
+    fn pending_transfers(_asset: AssetId, _who: &AccountId) -> Vec<(u64, EncryptedAmount)> {
+        Vec::new()
+    }
+    fn last_pending_id(_asset: AssetId, _who: &AccountId) -> Option<u64> {
+        None
+    }
+    fn disclose_amount(
+        _asset: AssetId,
+        _encrypted_amount: &EncryptedAmount,
+        _who: &AccountId,
+        _proof: InputProof,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn is_audited(_asset: AssetId) -> bool {
+        false
+    }
+    fn disclose_audited(
+        _asset: AssetId,
+        _who: &AccountId,
+        _proof: InputProof,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn transfer_encrypted(
+        _asset: AssetId,
+        _from: &AccountId,
+        _to: &AccountId,
+        _encrypted_amount: EncryptedAmount,
+        _input_proof: InputProof,
+    ) -> Result<EncryptedAmount, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn transfer_split(
+        _asset: AssetId,
+        _from: &AccountId,
+        _total: EncryptedAmount,
+        _outputs: &[(AccountId, EncryptedAmount)],
+        _input_proof: InputProof,
+    ) -> Result<Vec<u64>, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn claim_encrypted(
+        _asset: AssetId,
+        _from: &AccountId,
+        _input_proof: InputProof,
+    ) -> Result<EncryptedAmount, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn mint_encrypted(
+        _asset: AssetId,
+        to: &AccountId,
+        input_proof: InputProof,
+    ) -> Result<EncryptedAmount, DispatchError> {
+        if input_proof.is_empty() {
+            return Err(DispatchError::Other("bad mint proof"));
+        }
+        MINTS.with(|mints| mints.borrow_mut().push((*to, input_proof)));
+        Ok(EncryptedAmount::ZERO)
+    }
+    fn burn_encrypted(
+        _asset: AssetId,
+        _from: &AccountId,
+        _amount: EncryptedAmount,
+        _input_proof: InputProof,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn burn_confidential(
+        _asset: AssetId,
+        _from: &AccountId,
+        _amount: EncryptedAmount,
+        _input_proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn burn_held(
+        _asset: AssetId,
+        _from: &AccountId,
+        _amount: Commitment,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn close_account(
+        _asset: AssetId,
+        _who: &AccountId,
+        _proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+    fn is_paused(_asset: AssetId) -> bool {
+        false
+    }
+    fn seize_balance(
+        _asset: AssetId,
+        _who: &AccountId,
+        _encrypted_balance: EncryptedAmount,
+        _proof: InputProof,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+}
+
+parameter_types! {
+    pub const SelfParaId: ParaId = SELF_PARA;
+    pub const XcmFee: (u32, u64, u64) = (0, 1, 1_000);
+}
+impl pallet_confidential_xcm_bridge::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = AssetId;
+    type Balance = Balance;
+    type Backend = MockBackend;
+    type Escrow = MockEscrow;
+    type TransferTimeout = ConstU64<TIMEOUT>;
+    type Ramp = ();
+    type Xcm = MockRouter;
+    type ConfidentialHtlc = MockHtlc;
+    type ParaId = ParaId;
+    type XcmWeight = u64;
+    type FeeAssetId = u32;
+    type FeeBalance = u64;
+    type SelfParaId = SelfParaId;
+    type XcmOrigin = frame_system::EnsureSigned<AccountId>;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type ResultFee = XcmFee;
+    type ForwardFee = XcmFee;
+    type MaxHops = ConstU8<2>;
+    type WeightInfo = ();
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Bridge: pallet_confidential_xcm_bridge,
+    }
+);
+
+// Build a fresh externalities for each test.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    SENT.with(|sent| sent.borrow_mut().clear());
+    ESCROW_CALLS.with(|calls| calls.borrow_mut().clear());
+    MINTS.with(|mints| mints.borrow_mut().clear());
+    NEXT_HTLC.with(|next| next.set(0));
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Construct InputProof from raw bytes
+pub fn proof(bytes: &[u8]) -> InputProof {
+    bytes.to_vec().try_into().expect("bounded vec")
+}
+
+// A ciphertext standing for `v`; the mocks never decrypt it
+pub fn ct(v: u8) -> EncryptedAmount {
+    EncryptedAmount::from_parts([v; 32], [v; 32])
+}
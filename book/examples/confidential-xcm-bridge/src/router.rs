@@ -0,0 +1,102 @@
+//! Reference [`XcmRouter`] over pallet-xcm.
+
+use crate::pallet::{Call, Config, Error, HtlcHashLockOf, HtlcSecretOf, XcmRouter};
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::*, weights::Weight};
+use parity_scale_codec::{Decode, Encode};
+use sp_std::prelude::*;
+use xcm::latest::prelude::*;
+
+/// [`XcmRouter`] sending through `pallet_xcm` from this chain itself, so messages reach
+/// sibling paras with an `OriginKind::Xcm` origin that `EnsureXcm` accepts there.
+///
+/// Each message withdraws `fee` of `fee_asset` from this chain's sovereign account on the
+/// destination, buys up to `weight_limit` with it and deposits what is left back. The
+/// destination must run this pallet at the same index as `Runtime`.
+pub struct XcmRouterViaPalletXcm<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> XcmRouterViaPalletXcm<Runtime>
+where
+    Runtime: Config<ParaId = u32> + pallet_xcm::Config,
+    <Runtime as frame_system::Config>::RuntimeCall: From<Call<Runtime>>,
+    HtlcSecretOf<Runtime>: Decode,
+    HtlcHashLockOf<Runtime>: Decode,
+{
+    fn send(
+        dest: u32,
+        call: Call<Runtime>,
+        fee_asset: Location,
+        fee: u128,
+        weight_limit: Weight,
+    ) -> Result<(), DispatchError> {
+        let call: <Runtime as frame_system::Config>::RuntimeCall = call.into();
+        let fees: Asset = (fee_asset, fee).into();
+        let sovereign = Location::new(1, [Parachain(<Runtime as Config>::SelfParaId::get())]);
+        let message = Xcm(vec![
+            WithdrawAsset(fees.clone().into()),
+            BuyExecution {
+                fees,
+                weight_limit: Limited(weight_limit),
+            },
+            Transact {
+                origin_kind: OriginKind::Xcm,
+                fallback_max_weight: None,
+                call: call.encode().into(),
+            },
+            RefundSurplus,
+            DepositAsset {
+                assets: Wild(AllCounted(1)),
+                beneficiary: sovereign,
+            },
+        ]);
+
+        pallet_xcm::Pallet::<Runtime>::send_xcm(Here, Location::new(1, [Parachain(dest)]), message)
+            .map(|_| ())
+            .map_err(|_| Error::<Runtime>::RouterError.into())
+    }
+}
+
+impl<Runtime> XcmRouter for XcmRouterViaPalletXcm<Runtime>
+where
+    Runtime: Config<ParaId = u32> + pallet_xcm::Config,
+    <Runtime as frame_system::Config>::RuntimeCall: From<Call<Runtime>>,
+    HtlcSecretOf<Runtime>: Decode,
+    HtlcHashLockOf<Runtime>: Decode,
+{
+    type ParaId = u32;
+    type Weight = Weight;
+    type FeeAssetId = Location;
+    type FeeBalance = u128;
+
+    fn send_transact(
+        dest: u32,
+        payload: Vec<u8>,
+        fee_asset: Location,
+        fee: u128,
+        weight_limit: Weight,
+    ) -> Result<(), DispatchError> {
+        Self::send(
+            dest,
+            Call::<Runtime>::xcm_handle { payload },
+            fee_asset,
+            fee,
+            weight_limit,
+        )
+    }
+
+    fn send_result(
+        dest: u32,
+        payload: Vec<u8>,
+        fee_asset: Location,
+        fee: u128,
+        weight_limit: Weight,
+    ) -> Result<(), DispatchError> {
+        Self::send(
+            dest,
+            Call::<Runtime>::handle_remote_result { payload },
+            fee_asset,
+            fee,
+            weight_limit,
+        )
+    }
+}
@@ -0,0 +1,366 @@
+use crate::{
+    mock::*,
+    pallet::{
+        AwaitingResult, Error, Event, ParaPermissions, RemoteCall, RemoteMessage, RemoteResult,
+        Transfers, Undo,
+    },
+};
+use confidential_assets_primitives::{Expiry, InputProof};
+use frame_support::{assert_noop, assert_ok};
+use parity_scale_codec::Encode;
+
+// helpers
+const TAG: [u8; 32] = [7u8; 32];
+fn has_event(event: Event<Runtime>) -> bool {
+    System::events()
+        .iter()
+        .any(|record| record.event == RuntimeEvent::Bridge(event.clone()))
+}
+fn receive(mint_proof: InputProof) -> RemoteCall<AccountId, AssetId, ParaId> {
+    RemoteCall::ReceiveConfidentialTransfer {
+        sender_on_src: TAG,
+        dest_account: BOB,
+        asset: ASSET,
+        delta_ciphertext: ct(3),
+        mint_proof,
+    }
+}
+fn message(nonce: u64, call: RemoteCall<AccountId, AssetId, ParaId>) -> Vec<u8> {
+    RemoteMessage { nonce, call }.encode()
+}
+fn result(nonce: u64, success: bool) -> Vec<u8> {
+    RemoteResult { nonce, success }.encode()
+}
+// ALICE sends 3 of ASSET to BOB on `dest`
+fn transfer(dest: ParaId) -> u64 {
+    let nonce = Bridge::next_nonce();
+    assert_ok!(Bridge::send_confidential_transfer(
+        RuntimeOrigin::signed(ALICE),
+        dest,
+        TAG,
+        BOB,
+        ASSET,
+        ct(3),
+        proof(&[0]),
+        proof(&[1]),
+        0,
+        1,
+        1_000,
+    ));
+    nonce
+}
+
+#[test]
+fn transfer_escrow_is_burned_once_the_destination_reports_the_mint() {
+    new_test_ext().execute_with(|| {
+        let nonce = transfer(PARA_B);
+        assert_eq!(
+            escrow_calls(),
+            vec![EscrowCall::Lock(ALICE, ct(3), proof(&[0]))]
+        );
+        assert_eq!(
+            sent(),
+            vec![Sent::Transact(PARA_B, message(nonce, receive(proof(&[1]))))]
+        );
+        let escrowed = Transfers::<Runtime>::get(nonce).expect("escrowed");
+        assert_eq!(escrowed.deadline, 1 + TIMEOUT);
+        assert!(!escrowed.failed);
+
+        // Only the destination may report on it
+        assert_noop!(
+            Bridge::handle_remote_result(RuntimeOrigin::signed(PARA_C), result(nonce, true)),
+            Error::<Runtime>::UnexpectedResult
+        );
+        assert_ok!(Bridge::handle_remote_result(
+            RuntimeOrigin::signed(PARA_B),
+            result(nonce, true)
+        ));
+        assert!(has_event(Event::RemoteResultReceived {
+            nonce,
+            dest: PARA_B,
+            success: true,
+        }));
+        assert_eq!(
+            escrow_calls().last(),
+            Some(&EscrowCall::Burn(escrowed.escrow_id))
+        );
+        assert!(Transfers::<Runtime>::get(nonce).is_none());
+        assert!(AwaitingResult::<Runtime>::get(nonce).is_none());
+        assert_noop!(
+            Bridge::refund_transfer(RuntimeOrigin::signed(ALICE), nonce, proof(&[5])),
+            Error::<Runtime>::UnknownTransfer
+        );
+    });
+}
+
+#[test]
+fn failed_or_timed_out_transfers_are_refunded_to_the_sender() {
+    new_test_ext().execute_with(|| {
+        let failed = transfer(PARA_B);
+        assert_noop!(
+            Bridge::refund_transfer(RuntimeOrigin::signed(ALICE), failed, proof(&[5])),
+            Error::<Runtime>::NotRefundable
+        );
+        assert_ok!(Bridge::handle_remote_result(
+            RuntimeOrigin::signed(PARA_B),
+            result(failed, false)
+        ));
+        let escrowed = Transfers::<Runtime>::get(failed).expect("kept for the refund");
+        assert!(escrowed.failed);
+
+        assert_noop!(
+            Bridge::refund_transfer(RuntimeOrigin::signed(BOB), failed, proof(&[5])),
+            Error::<Runtime>::NotSender
+        );
+        assert_ok!(Bridge::refund_transfer(
+            RuntimeOrigin::signed(ALICE),
+            failed,
+            proof(&[5])
+        ));
+        assert!(has_event(Event::TransferRefunded {
+            nonce: failed,
+            who: ALICE,
+        }));
+        assert_eq!(
+            escrow_calls().last(),
+            Some(&EscrowCall::Refund(escrowed.escrow_id, ct(3), proof(&[5])))
+        );
+        assert!(Transfers::<Runtime>::get(failed).is_none());
+
+        // Without a result, the sender may refund once the timeout passes
+        let lost = transfer(PARA_B);
+        let escrow_id = Transfers::<Runtime>::get(lost).unwrap().escrow_id;
+        System::set_block_number(TIMEOUT);
+        assert_noop!(
+            Bridge::refund_transfer(RuntimeOrigin::signed(ALICE), lost, proof(&[6])),
+            Error::<Runtime>::NotRefundable
+        );
+        System::set_block_number(1 + TIMEOUT);
+        assert_ok!(Bridge::refund_transfer(
+            RuntimeOrigin::signed(ALICE),
+            lost,
+            proof(&[6])
+        ));
+        assert_eq!(
+            escrow_calls().last(),
+            Some(&EscrowCall::Refund(escrow_id, ct(3), proof(&[6])))
+        );
+        // And a result arriving late cannot burn what was refunded
+        assert_noop!(
+            Bridge::handle_remote_result(RuntimeOrigin::signed(PARA_B), result(lost, true)),
+            Error::<Runtime>::UnexpectedResult
+        );
+    });
+}
+
+#[test]
+fn inbound_transfers_mint_for_permitted_paras_and_report_back() {
+    new_test_ext().execute_with(|| {
+        // Paras without permissions may do nothing; the failure is reported, not raised
+        assert_ok!(Bridge::xcm_handle(
+            RuntimeOrigin::signed(PARA_B),
+            message(0, receive(proof(&[1])))
+        ));
+        assert!(has_event(Event::RemoteCallFailed {
+            source: PARA_B,
+            nonce: 0,
+        }));
+        assert_eq!(
+            sent(),
+            vec![Sent::Result(
+                PARA_B,
+                RemoteResult {
+                    nonce: 0,
+                    success: false
+                }
+            )]
+        );
+        assert!(mints().is_empty());
+
+        assert_ok!(Bridge::set_para_permissions(
+            RuntimeOrigin::root(),
+            PARA_B,
+            ParaPermissions {
+                transfers: true,
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bridge::xcm_handle(
+            RuntimeOrigin::signed(PARA_B),
+            message(1, receive(proof(&[1])))
+        ));
+        assert!(has_event(Event::XcmConfTransferApplied {
+            source: PARA_B,
+            from_tag: TAG,
+            to: BOB,
+            asset: ASSET,
+        }));
+        assert_eq!(mints(), vec![(BOB, proof(&[1]))]);
+        assert_eq!(
+            sent().last(),
+            Some(&Sent::Result(
+                PARA_B,
+                RemoteResult {
+                    nonce: 1,
+                    success: true
+                }
+            ))
+        );
+
+        // A mint the backend rejects fails the same way
+        assert_ok!(Bridge::xcm_handle(
+            RuntimeOrigin::signed(PARA_B),
+            message(2, receive(proof(&[])))
+        ));
+        assert!(has_event(Event::RemoteCallFailed {
+            source: PARA_B,
+            nonce: 2,
+        }));
+        assert_eq!(mints().len(), 1);
+
+        // Transfer permission does not extend to HTLC calls
+        let redeem = RemoteCall::HtlcRedeemWithSecret {
+            who: BOB,
+            htlc_id: 0,
+            secret_bytes: SECRET.encode(),
+        };
+        assert_ok!(Bridge::xcm_handle(
+            RuntimeOrigin::signed(PARA_B),
+            message(3, redeem)
+        ));
+        assert!(has_event(Event::RemoteCallFailed {
+            source: PARA_B,
+            nonce: 3,
+        }));
+    });
+}
+
+#[test]
+fn a_link_the_destination_rejects_is_forgotten() {
+    new_test_ext().execute_with(|| {
+        let open = |remote_htlc_id| {
+            Bridge::open_linked_htlc(
+                RuntimeOrigin::signed(ALICE),
+                PARA_B,
+                remote_htlc_id,
+                Some(BOB),
+                ASSET,
+                ct(3),
+                proof(&[0]),
+                [1u8; 32].encode(),
+                Expiry::AtBlock(100),
+                None,
+                0,
+                1,
+                1_000,
+            )
+        };
+        assert_ok!(open(5));
+        assert!(has_event(Event::HtlcLinked {
+            htlc_id: 0,
+            remote_para: PARA_B,
+            remote_htlc_id: 5,
+        }));
+        assert_eq!(Bridge::htlc_link(0), Some((PARA_B, 5)));
+        assert_eq!(Bridge::local_htlc(PARA_B, 5), Some(0));
+        assert_eq!(
+            sent(),
+            vec![Sent::Transact(
+                PARA_B,
+                message(
+                    0,
+                    RemoteCall::LinkHtlc {
+                        src_para: SELF_PARA,
+                        src_htlc_id: 0,
+                        htlc_id: 5,
+                    }
+                )
+            )]
+        );
+        assert_eq!(
+            AwaitingResult::<Runtime>::get(0),
+            Some((PARA_B, Some(Undo::Unlink { htlc_id: 0 })))
+        );
+        assert_noop!(open(5), Error::<Runtime>::AlreadyLinked);
+
+        assert_ok!(Bridge::handle_remote_result(
+            RuntimeOrigin::signed(PARA_B),
+            result(0, false)
+        ));
+        assert_eq!(Bridge::htlc_link(0), None);
+        assert_eq!(Bridge::local_htlc(PARA_B, 5), None);
+        assert_ok!(open(5));
+    });
+}
+
+#[test]
+fn relayed_results_travel_back_to_the_source() {
+    new_test_ext().execute_with(|| {
+        // The source routes PARA_C through PARA_B, one of its MaxHops
+        assert_ok!(Bridge::set_route(
+            RuntimeOrigin::root(),
+            PARA_C,
+            Some(PARA_B)
+        ));
+        let nonce = transfer(PARA_C);
+        let forward = RemoteCall::Forward {
+            dest: PARA_C,
+            hops: 1,
+            call: Box::new(receive(proof(&[1]))),
+        };
+        assert_eq!(
+            sent(),
+            vec![Sent::Transact(PARA_B, message(nonce, forward.clone()))]
+        );
+        assert_eq!(AwaitingResult::<Runtime>::get(nonce), Some((PARA_B, None)));
+
+        // As the hub, which reaches PARA_C directly, relaying PARA_B's message on
+        assert_ok!(Bridge::set_route(RuntimeOrigin::root(), PARA_C, None));
+        assert_ok!(Bridge::set_para_permissions(
+            RuntimeOrigin::root(),
+            PARA_B,
+            ParaPermissions {
+                relay: true,
+                ..Default::default()
+            },
+        ));
+        let relayed = Bridge::next_nonce();
+        assert_ok!(Bridge::xcm_handle(
+            RuntimeOrigin::signed(PARA_B),
+            message(7, forward)
+        ));
+        assert_eq!(
+            sent().last(),
+            Some(&Sent::Transact(
+                PARA_C,
+                message(relayed, receive(proof(&[1])))
+            ))
+        );
+        assert_eq!(
+            AwaitingResult::<Runtime>::get(relayed),
+            Some((
+                PARA_C,
+                Some(Undo::Relay {
+                    to: PARA_B,
+                    nonce: 7
+                })
+            ))
+        );
+
+        // PARA_C's result goes back to PARA_B under PARA_B's own nonce
+        assert_ok!(Bridge::handle_remote_result(
+            RuntimeOrigin::signed(PARA_C),
+            result(relayed, true)
+        ));
+        assert_eq!(
+            sent().last(),
+            Some(&Sent::Result(
+                PARA_B,
+                RemoteResult {
+                    nonce: 7,
+                    success: true
+                }
+            ))
+        );
+    });
+}
@@ -6,10 +6,10 @@ extern crate alloc;
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
-use sp_runtime::traits::CheckedSub;
+use sp_runtime::traits::{CheckedSub, Saturating};
 use sp_std::prelude::*;
 
-use confidential_assets_primitives::{AdaptorSigBackend, BridgeHtlc, EscrowTrust};
+use confidential_assets_primitives::{AdaptorSigBackend, BridgeHtlc, EscrowTrust, Expiry};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -133,6 +133,8 @@ pub mod pallet {
         BadSignature,
         Arithmetic,
         MalformedSignature,
+        /// HTLCs here expire at a block; wall-clock expiries are not supported.
+        UnsupportedExpiry,
     }
 
     impl<T: Config> Pallet<T> {
@@ -341,9 +343,17 @@ pub mod pallet {
             asset: T::AssetId,
             amount: T::Balance,
             hashlock: Self::HashLock,
-            expiry_abs: u32,
+            expiry: Expiry<u32>,
             adaptor_partial: Option<Vec<u8>>,
         ) -> Result<u64, DispatchError> {
+            let expiry_bn: BlockNumberFor<T> = match expiry {
+                Expiry::AtBlock(block) => block.into(),
+                Expiry::AfterBlocks(blocks) => {
+                    frame_system::Pallet::<T>::block_number().saturating_add(blocks.into())
+                }
+                Expiry::AtTime(_) => return Err(Error::<T>::UnsupportedExpiry.into()),
+            };
+
             // Lock into escrow
             T::Escrow::escrow_lock(asset, maker, amount)?;
 
@@ -352,8 +362,6 @@ pub mod pallet {
                 *x = x.saturating_add(1);
                 id
             });
-            let expiry_bn: BlockNumberFor<T> = expiry_abs.into();
-
             let adaptor_bounded: Option<BoundedVec<_, ConstU32<64>>> = match adaptor_partial {
                 Some(bytes) => Some(bytes.try_into().map_err(|_| Error::<T>::Arithmetic)?),
                 None => None,
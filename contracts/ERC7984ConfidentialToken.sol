@@ -78,7 +78,7 @@ import {IConfidentialAssets} from "./interfaces/IConfidentialAssets.sol";
  * ## For Users (Using the Token)
  * ```solidity
  * // 1. Setup: Set your public key (one-time)
- * token.setPublicKey(myElGamalPublicKey);
+ * token.setPublicKey(myElGamalPublicKey, ownershipProof);
  *
  * // 2. Deposit: Shield public tokens
  * publicToken.approve(address(token), 1000e18);
@@ -485,11 +485,13 @@ contract ERC7984ConfidentialToken is IERC7984 {
      *      **Reverts:**
      *      - If pubkey length != 64 bytes
      *      - If pubkey is not a valid curve point
+     *      - If proof does not show knowledge of the private key
      *
      * @param pubkey The ElGamal public key (exactly 64 bytes)
+     * @param proof Proof of knowledge of the private key
      */
-    function setPublicKey(bytes calldata pubkey) external {
-        PRECOMPILE.setPublicKey(pubkey);
+    function setPublicKey(bytes calldata pubkey, bytes calldata proof) external {
+        PRECOMPILE.setPublicKey(pubkey, proof);
     }
 
     /**
@@ -21,6 +21,8 @@ pragma solidity ^0.8.20;
 /// - **Balance Commitments**: Balances are stored as 32-byte Pedersen commitments
 /// - **Encrypted Amounts**: Transfer amounts are ElGamal encrypted (64 bytes)
 /// - **ZK Proofs**: All operations require validity proofs to prevent double-spending
+/// - **Proof Envelopes**: Every `proof` argument starts with a 1-byte proof-system id
+///   (0 = ZK-ElGamal/Bulletproofs); proofs for another system revert with "unknown proof system"
 ///
 /// # Security Model
 /// - **Privacy**: Balances and amounts are encrypted; only commitments are stored on-chain
@@ -30,7 +32,8 @@ pragma solidity ^0.8.20;
 ///
 /// # Integration Steps
 /// 1. Deploy or identify the asset ID to work with
-/// 2. Users call `setPublicKey()` with their ElGamal public key (64 bytes)
+/// 2. Users call `setPublicKey()` with their ElGamal public key (64 bytes) and a proof of
+///    knowledge of its private key
 /// 3. Users can `deposit()` public tokens to get confidential balance
 /// 4. Users can perform `confidentialTransfer()` to send encrypted amounts
 /// 5. Recipients can `confidentialClaim()` to claim pending transfers
@@ -50,7 +53,8 @@ pragma solidity ^0.8.20;
 ///
 /// // Setup: Set public key (one-time)
 /// bytes memory myPublicKey = ...; // 64 bytes from ElGamal key generation
-/// precompile.setPublicKey(myPublicKey);
+/// bytes memory ownershipProof = ...; // zkhe_prover::prove_pubkey_ownership
+/// precompile.setPublicKey(myPublicKey, ownershipProof);
 ///
 /// // Shield tokens: Deposit 100 tokens (assumes approval given)
 /// bytes memory depositProof = ...; // Generated off-chain
@@ -168,9 +172,14 @@ interface IConfidentialAssets {
     ///      - Public key can be updated, but old encrypted amounts won't be decryptable with new key
     ///      - Consider using deterministic key derivation (BIP-32/44) for key recovery
     ///
+    ///      **Ownership Proof:**
+    ///      `proof` is a Schnorr proof of knowledge of the private key, bound to the caller's
+    ///      mapped account and the chain's network id. Without it anyone could register
+    ///      another user's key and leave transfers sent to them unclaimable.
+    ///
     ///      **Integration Flow:**
     ///      1. Generate ElGamal key pair off-chain (private key stays client-side)
-    ///      2. Call this function with the public key (one-time)
+    ///      2. Call this function with the public key and ownership proof (one-time)
     ///      3. Store private key securely (needed to decrypt received amounts)
     ///      4. Now able to receive confidential transfers
     ///
@@ -179,12 +188,14 @@ interface IConfidentialAssets {
     ///      **Reverts:**
     ///      - If pubkey length != 64 bytes
     ///      - If pubkey is not a valid curve point
+    ///      - If proof does not show knowledge of the private key
     ///
     /// @param pubkey The ElGamal public key (exactly 64 bytes: 32-byte X + 32-byte Y coordinate)
+    /// @param proof Proof of knowledge of the private key (64 bytes)
     ///
-    /// @custom:selector a91d58b4
+    /// @custom:selector fd240caf
     /// @custom:security CRITICAL - Keep the corresponding private key secure and backed up
-    function setPublicKey(bytes calldata pubkey) external;
+    function setPublicKey(bytes calldata pubkey, bytes calldata proof) external;
 
     /// @notice Deposits (shields) public assets into confidential balance
     /// @dev Converts public tokens into confidential balance. This operation:
@@ -0,0 +1,154 @@
+use super::*;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+const MSG: &[u8] = b"claim htlc 42";
+
+/// Secret key with an even-y public key, and that key's x-coordinate.
+fn keypair(seed: u64) -> (Scalar, [u8; 32]) {
+    let mut x = Scalar::from(seed);
+    let p = (ProjectivePoint::GENERATOR * x).to_affine();
+    if bool::from(p.y_is_odd()) {
+        x = -x;
+    }
+    (x, p.x().into())
+}
+
+fn adaptor_point(t: &Scalar) -> Vec<u8> {
+    let t = (ProjectivePoint::GENERATOR * t).to_affine();
+    t.to_encoded_point(true).as_bytes().to_vec()
+}
+
+fn signed_message(t: &Scalar) -> Vec<u8> {
+    let mut msg = MSG.to_vec();
+    msg.extend_from_slice(&adaptor_point(t));
+    msg
+}
+
+/// Pre-sign `MSG` for adaptor secret `t`, picking a nonce whose `R = R' + T` has even y.
+fn pre_sign(x: &Scalar, p_x: &[u8; 32], t: &Scalar) -> [u8; 64] {
+    let big_t = ProjectivePoint::GENERATOR * t;
+    let mut seed = 7u64;
+    loop {
+        let k = Scalar::from(seed);
+        let r = (ProjectivePoint::GENERATOR * k + big_t).to_affine();
+        if !bool::from(r.y_is_odd()) {
+            let r_x: [u8; 32] = r.x().into();
+            let s = k + challenge(&r_x, p_x, MSG) * x;
+            let mut sig = [0u8; 64];
+            sig[..32].copy_from_slice(&r_x);
+            sig[32..].copy_from_slice(&s.to_repr());
+            return sig;
+        }
+        seed += 1;
+    }
+}
+
+fn complete(partial: &[u8; 64], t: &Scalar) -> [u8; 64] {
+    let (_, s) = split_sig(partial).unwrap();
+    let mut sig = *partial;
+    sig[32..].copy_from_slice(&(s + t).to_repr());
+    sig
+}
+
+/// Plain BIP-340 verification: `s·G == R + e·P`.
+fn bip340_verify(msg: &[u8], p_x: &[u8; 32], sig: &[u8; 64]) -> bool {
+    let (r_x, s) = split_sig(sig).unwrap();
+    let r = lift_x(&r_x).unwrap();
+    let p = lift_x(p_x).unwrap();
+    ProjectivePoint::GENERATOR * s
+        == ProjectivePoint::from(r) + ProjectivePoint::from(p) * challenge(&r_x, p_x, msg)
+}
+
+#[test]
+fn hash_secret_is_sha256() {
+    let secret = [0x11u8; 32];
+    let expected: [u8; 32] = Sha256::digest(secret).into();
+    assert_eq!(Secp256k1Adaptor::hash_secret(&secret), expected);
+}
+
+#[test]
+fn tagged_hash_matches_the_bip340_construction() {
+    let tag = Sha256::digest(b"BIP0340/challenge");
+    let mut hasher = Sha256::new();
+    hasher.update(tag);
+    hasher.update(tag);
+    hasher.update(b"abc");
+    let expected: [u8; 32] = hasher.finalize().into();
+    assert_eq!(
+        tagged_hash(b"BIP0340/challenge", &[b"a".as_slice(), b"bc".as_slice()]),
+        expected
+    );
+}
+
+#[test]
+fn adaptor_round_trip_recovers_the_secret() {
+    let (x, p_x) = keypair(0xA11CE);
+    let t = Scalar::from(0xB0Bu64);
+
+    let partial = pre_sign(&x, &p_x, &t);
+    assert!(Secp256k1Adaptor::verify_adaptor_sig(&signed_message(&t), &p_x, &partial).is_ok());
+    // A pre-signature is not yet a valid signature.
+    assert!(!bip340_verify(MSG, &p_x, &partial));
+
+    let final_sig = complete(&partial, &t);
+    assert!(bip340_verify(MSG, &p_x, &final_sig));
+
+    let secret = Secp256k1Adaptor::recover_secret_from_sigs(&partial, &final_sig).unwrap();
+    assert_eq!(secret, <[u8; 32]>::from(t.to_repr()));
+    assert_eq!(
+        Secp256k1Adaptor::hash_secret(&secret),
+        <[u8; 32]>::from(Sha256::digest(t.to_repr()))
+    );
+}
+
+#[test]
+fn verification_rejects_the_wrong_adaptor_key_or_message() {
+    let (x, p_x) = keypair(0xA11CE);
+    let (_, other_x) = keypair(0xCAFE);
+    let t = Scalar::from(0xB0Bu64);
+    let partial = pre_sign(&x, &p_x, &t);
+
+    // Wrong adaptor point.
+    let other_t = Scalar::from(0xB0Cu64);
+    assert!(
+        Secp256k1Adaptor::verify_adaptor_sig(&signed_message(&other_t), &p_x, &partial).is_err()
+    );
+    // Wrong signer.
+    assert!(Secp256k1Adaptor::verify_adaptor_sig(&signed_message(&t), &other_x, &partial).is_err());
+    // Wrong message.
+    let mut msg = signed_message(&t);
+    msg[0] ^= 1;
+    assert!(Secp256k1Adaptor::verify_adaptor_sig(&msg, &p_x, &partial).is_err());
+    // No adaptor point at all.
+    assert!(Secp256k1Adaptor::verify_adaptor_sig(MSG, &p_x, &partial).is_err());
+}
+
+#[test]
+fn malformed_signatures_are_rejected() {
+    let (x, p_x) = keypair(0xA11CE);
+    let t = Scalar::from(0xB0Bu64);
+    let partial = pre_sign(&x, &p_x, &t);
+
+    // `s` at or above the group order.
+    let mut overflow = partial;
+    overflow[32..].copy_from_slice(&[0xFF; 32]);
+    assert!(Secp256k1Adaptor::verify_adaptor_sig(&signed_message(&t), &p_x, &overflow).is_err());
+    assert!(Secp256k1Adaptor::recover_secret_from_sigs(&overflow, &partial).is_err());
+
+    // Signatures over different nonces say nothing about `t`.
+    let mut other_nonce = complete(&partial, &t);
+    other_nonce[..32].copy_from_slice(&p_x);
+    assert!(Secp256k1Adaptor::recover_secret_from_sigs(&partial, &other_nonce).is_err());
+}
+
+#[test]
+fn uncompressed_adaptor_points_are_rejected() {
+    let (x, p_x) = keypair(0xA11CE);
+    let t = Scalar::from(0xB0Bu64);
+    let partial = pre_sign(&x, &p_x, &t);
+
+    let mut msg = MSG.to_vec();
+    let point = (ProjectivePoint::GENERATOR * t).to_affine();
+    msg.extend_from_slice(point.to_encoded_point(false).as_bytes());
+    assert!(Secp256k1Adaptor::verify_adaptor_sig(&msg, &p_x, &partial).is_err());
+}
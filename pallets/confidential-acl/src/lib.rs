@@ -0,0 +1,331 @@
+//! ACL Pallet
+//!
+//! Reference `AclProvider` for pallet-confidential-assets. Per asset, `AdminOrigin` can:
+//! - pause state-changing ops,
+//! - cap the disclosed amount of a single op,
+//! - deny accounts outright,
+//! - restrict the asset to an allowlist,
+//! - require the caller of an op to hold a role (e.g. only an `Issuer` mints, only
+//!   `Kyc`'d accounts shield).
+//!
+//! Every account an op names (`caller`, `owner`, `counterparty`) must pass the denylist
+//! and, when enabled, the allowlist; the role requirement applies to `caller` only.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+extern crate alloc;
+
+use confidential_assets_primitives::{AclCtx, AclProvider, Op};
+use frame_support::{Blake2_128Concat, pallet_prelude::*};
+use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
+
+pub use pallet::*;
+
+/// Role an account holds for an asset.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    RuntimeDebug,
+)]
+pub enum Role {
+    /// Issues the asset, e.g. the only account allowed to `Op::Mint`.
+    Issuer,
+    /// Passed identity checks, e.g. to `Op::Shield`.
+    Kyc,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type AssetId: Parameter + Member + Copy + Ord + MaxEncodedLen + scale_info::TypeInfo;
+        type Balance: Parameter
+            + Member
+            + Copy
+            + sp_runtime::traits::AtLeast32BitUnsigned
+            + Default
+            + MaxEncodedLen;
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Origin that manages the lists and roles, e.g. root or a compliance collective.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        type WeightInfo: WeightData;
+    }
+
+    pub trait WeightData {
+        fn set_paused() -> Weight;
+        fn set_max_per_tx() -> Weight;
+        fn set_allowlist_enabled() -> Weight;
+        fn set_allowed() -> Weight;
+        fn set_denied() -> Weight;
+        fn set_role() -> Weight;
+        fn set_op_role() -> Weight;
+    }
+    impl WeightData for () {
+        fn set_paused() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_max_per_tx() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_allowlist_enabled() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_allowed() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_denied() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_role() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_op_role() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+    }
+
+    #[pallet::storage]
+    pub type Paused<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, bool, ValueQuery>;
+
+    #[pallet::storage]
+    pub type MaxPerTx<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, T::Balance, OptionQuery>;
+
+    /// Whether only accounts in [`Allowed`] may use the asset.
+    #[pallet::storage]
+    pub type AllowlistEnabled<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, bool, ValueQuery>;
+
+    #[pallet::storage]
+    pub type Allowed<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    pub type Denied<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    pub type Roles<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AssetId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Blake2_128Concat, Role>,
+        ),
+        bool,
+        ValueQuery,
+    >;
+
+    /// Role the caller of an op on an asset must hold, if any.
+    #[pallet::storage]
+    pub type OpRoles<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, Op, Role, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        Paused(T::AssetId, bool),
+        MaxPerTxSet(T::AssetId, T::Balance),
+        AllowlistEnabled(T::AssetId, bool),
+        AllowedSet(T::AssetId, T::AccountId, bool),
+        DeniedSet(T::AssetId, T::AccountId, bool),
+        RoleSet(T::AssetId, T::AccountId, Role, bool),
+        OpRoleSet(T::AssetId, Op, Option<Role>),
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    impl<T: Config> AclProvider<T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
+        fn authorize(
+            op: Op,
+            ctx: &AclCtx<T::Balance, T::AccountId, T::AssetId>,
+        ) -> Result<(), DispatchError> {
+            // Pause gate for state-changing ops
+            match op {
+                Op::Mint
+                | Op::Burn
+                | Op::Transfer
+                | Op::TransferFrom
+                | Op::Shield
+                | Op::Unshield => {
+                    if Paused::<T>::get(ctx.asset) {
+                        return Err(DispatchError::Other("ACL: paused"));
+                    }
+                }
+                _ => {}
+            }
+
+            // Every named account must be clear of the denylist and, if enabled, on
+            // the allowlist
+            let allowlist = AllowlistEnabled::<T>::get(ctx.asset);
+            let parties = [
+                Some(&ctx.caller),
+                ctx.owner.as_ref(),
+                ctx.counterparty.as_ref(),
+            ];
+            for who in parties.into_iter().flatten() {
+                if Denied::<T>::get(ctx.asset, who) {
+                    return Err(DispatchError::Other("ACL: denied"));
+                }
+                if allowlist && !Allowed::<T>::get(ctx.asset, who) {
+                    return Err(DispatchError::Other("ACL: not allowed"));
+                }
+            }
+
+            // Role the op requires of its caller
+            if let Some(role) = OpRoles::<T>::get(ctx.asset, op) {
+                if !Roles::<T>::get((ctx.asset, &ctx.caller, role)) {
+                    return Err(DispatchError::Other("ACL: missing role"));
+                }
+            }
+
+            // Max-per-tx (only applies where amount matters)
+            if let Some(max) = MaxPerTx::<T>::get(ctx.asset) {
+                if ctx.amount > max {
+                    return Err(DispatchError::Other("ACL: over per-tx limit"));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_paused())]
+        pub fn set_paused(origin: OriginFor<T>, asset: T::AssetId, paused: bool) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Paused::<T>::insert(asset, paused);
+            Self::deposit_event(Event::Paused(asset, paused));
+            Ok(())
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_max_per_tx())]
+        pub fn set_max_per_tx(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            max: T::Balance,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            MaxPerTx::<T>::insert(asset, max);
+            Self::deposit_event(Event::MaxPerTxSet(asset, max));
+            Ok(())
+        }
+
+        /// Restrict `asset` to the accounts in `Allowed`, or lift the restriction.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_allowlist_enabled())]
+        pub fn set_allowlist_enabled(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            AllowlistEnabled::<T>::insert(asset, enabled);
+            Self::deposit_event(Event::AllowlistEnabled(asset, enabled));
+            Ok(())
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_allowed())]
+        pub fn set_allowed(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            who: T::AccountId,
+            allowed: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if allowed {
+                Allowed::<T>::insert(asset, &who, true);
+            } else {
+                Allowed::<T>::remove(asset, &who);
+            }
+            Self::deposit_event(Event::AllowedSet(asset, who, allowed));
+            Ok(())
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_denied())]
+        pub fn set_denied(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            who: T::AccountId,
+            denied: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if denied {
+                Denied::<T>::insert(asset, &who, true);
+            } else {
+                Denied::<T>::remove(asset, &who);
+            }
+            Self::deposit_event(Event::DeniedSet(asset, who, denied));
+            Ok(())
+        }
+
+        /// Grant or revoke `role` for `who` on `asset`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_role())]
+        pub fn set_role(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            who: T::AccountId,
+            role: Role,
+            granted: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if granted {
+                Roles::<T>::insert((asset, &who, role), true);
+            } else {
+                Roles::<T>::remove((asset, &who, role));
+            }
+            Self::deposit_event(Event::RoleSet(asset, who, role, granted));
+            Ok(())
+        }
+
+        /// Require the caller of `op` on `asset` to hold `role`; `None` drops the
+        /// requirement.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_op_role())]
+        pub fn set_op_role(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            op: Op,
+            role: Option<Role>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            OpRoles::<T>::set(asset, op, role);
+            Self::deposit_event(Event::OpRoleSet(asset, op, role));
+            Ok(())
+        }
+    }
+}
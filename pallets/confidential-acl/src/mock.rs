@@ -0,0 +1,41 @@
+use crate::pallet as pallet_confidential_acl;
+use frame_support::{construct_runtime, derive_impl};
+use sp_runtime::BuildStorage;
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+pub type Balance = u64;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const ASSET: AssetId = 7;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+}
+
+impl pallet_confidential_acl::Config for Runtime {
+    type AssetId = AssetId;
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Acl: pallet_confidential_acl,
+    }
+);
+
+// Build a fresh externalities for each test.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
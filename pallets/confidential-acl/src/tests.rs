@@ -0,0 +1,242 @@
+use crate::{Event, Role, mock::*};
+use confidential_assets_primitives::{AclCtx, AclProvider, Op};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+// helpers
+fn ctx(
+    caller: AccountId,
+    owner: Option<AccountId>,
+    counterparty: Option<AccountId>,
+    amount: Balance,
+) -> AclCtx<Balance, AccountId, AssetId> {
+    AclCtx {
+        amount,
+        asset: ASSET,
+        caller,
+        owner,
+        counterparty,
+        ..Default::default()
+    }
+}
+fn authorize(op: Op, ctx: &AclCtx<Balance, AccountId, AssetId>) -> Result<(), DispatchError> {
+    <Acl as AclProvider<AccountId, AssetId, Balance>>::authorize(op, ctx)
+}
+fn transfer(from: AccountId, to: AccountId) -> Result<(), DispatchError> {
+    authorize(Op::Transfer, &ctx(from, None, Some(to), 0))
+}
+fn root() -> RuntimeOrigin {
+    RuntimeOrigin::root()
+}
+const ALL_OPS: [Op; 8] = [
+    Op::Mint,
+    Op::Burn,
+    Op::Transfer,
+    Op::TransferFrom,
+    Op::Shield,
+    Op::Unshield,
+    Op::AcceptPending,
+    Op::SetOperator,
+];
+
+#[test]
+fn unconfigured_asset_authorizes_every_op() {
+    new_test_ext().execute_with(|| {
+        let c = ctx(ALICE, Some(BOB), Some(CHARLIE), u64::MAX);
+        for op in ALL_OPS {
+            assert_ok!(authorize(op, &c));
+        }
+    });
+}
+
+#[test]
+fn denylist_applies_to_caller_owner_and_counterparty() {
+    new_test_ext().execute_with(|| {
+        let c = ctx(ALICE, Some(BOB), Some(CHARLIE), 0);
+        for who in [ALICE, BOB, CHARLIE] {
+            assert_ok!(Acl::set_denied(root(), ASSET, who, true));
+            assert_eq!(
+                authorize(Op::TransferFrom, &c),
+                Err(DispatchError::Other("ACL: denied"))
+            );
+            assert_ok!(Acl::set_denied(root(), ASSET, who, false));
+            assert_ok!(authorize(Op::TransferFrom, &c));
+        }
+        assert_eq!(
+            System::events().last().expect("event").event,
+            RuntimeEvent::Acl(Event::DeniedSet(ASSET, CHARLIE, false))
+        );
+
+        // The denylist is per asset
+        assert_ok!(Acl::set_denied(root(), ASSET + 1, ALICE, true));
+        assert_ok!(authorize(Op::TransferFrom, &c));
+    });
+}
+
+#[test]
+fn allowlist_admits_only_listed_parties_while_enabled() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Acl::set_allowed(root(), ASSET, ALICE, true));
+        // Listing accounts restricts nothing until the allowlist is enabled
+        assert_ok!(transfer(ALICE, BOB));
+
+        assert_ok!(Acl::set_allowlist_enabled(root(), ASSET, true));
+        assert_eq!(
+            transfer(ALICE, BOB),
+            Err(DispatchError::Other("ACL: not allowed"))
+        );
+        assert_eq!(
+            transfer(BOB, ALICE),
+            Err(DispatchError::Other("ACL: not allowed"))
+        );
+        assert_eq!(
+            authorize(Op::TransferFrom, &ctx(ALICE, Some(CHARLIE), None, 0)),
+            Err(DispatchError::Other("ACL: not allowed"))
+        );
+
+        assert_ok!(Acl::set_allowed(root(), ASSET, BOB, true));
+        assert_ok!(transfer(ALICE, BOB));
+        assert_ok!(transfer(BOB, ALICE));
+
+        // Removal takes effect at once
+        assert_ok!(Acl::set_allowed(root(), ASSET, BOB, false));
+        assert_eq!(
+            transfer(ALICE, BOB),
+            Err(DispatchError::Other("ACL: not allowed"))
+        );
+
+        // A denied account stays out even when listed
+        assert_ok!(Acl::set_allowed(root(), ASSET, BOB, true));
+        assert_ok!(Acl::set_denied(root(), ASSET, BOB, true));
+        assert_eq!(
+            transfer(ALICE, BOB),
+            Err(DispatchError::Other("ACL: denied"))
+        );
+        assert_ok!(Acl::set_denied(root(), ASSET, BOB, false));
+
+        assert_ok!(Acl::set_allowlist_enabled(root(), ASSET, false));
+        assert_ok!(transfer(ALICE, CHARLIE));
+    });
+}
+
+#[test]
+fn op_role_is_required_of_the_caller_only() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Acl::set_op_role(
+            root(),
+            ASSET,
+            Op::Mint,
+            Some(Role::Issuer)
+        ));
+        let mint = |caller| authorize(Op::Mint, &ctx(caller, None, Some(BOB), 0));
+        assert_eq!(mint(ALICE), Err(DispatchError::Other("ACL: missing role")));
+
+        // Some other role, or the role on another asset, does not count
+        assert_ok!(Acl::set_role(root(), ASSET, ALICE, Role::Kyc, true));
+        assert_ok!(Acl::set_role(root(), ASSET + 1, ALICE, Role::Issuer, true));
+        assert_eq!(mint(ALICE), Err(DispatchError::Other("ACL: missing role")));
+
+        assert_ok!(Acl::set_role(root(), ASSET, ALICE, Role::Issuer, true));
+        assert_ok!(mint(ALICE));
+        // Only the op the role was set for is gated
+        assert_ok!(transfer(BOB, CHARLIE));
+
+        assert_ok!(Acl::set_role(root(), ASSET, ALICE, Role::Issuer, false));
+        assert_eq!(mint(ALICE), Err(DispatchError::Other("ACL: missing role")));
+
+        assert_ok!(Acl::set_op_role(root(), ASSET, Op::Mint, None));
+        assert_ok!(mint(ALICE));
+        assert_eq!(
+            System::events().last().expect("event").event,
+            RuntimeEvent::Acl(Event::OpRoleSet(ASSET, Op::Mint, None))
+        );
+    });
+}
+
+#[test]
+fn max_per_tx_caps_the_disclosed_amount() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Acl::set_max_per_tx(root(), ASSET, 100));
+        let shield = |amount| authorize(Op::Shield, &ctx(ALICE, None, None, amount));
+        assert_ok!(shield(0));
+        assert_ok!(shield(100));
+        assert_eq!(
+            shield(101),
+            Err(DispatchError::Other("ACL: over per-tx limit"))
+        );
+        assert_ok!(authorize(
+            Op::Shield,
+            &AclCtx {
+                asset: ASSET + 1,
+                ..ctx(ALICE, None, None, 101)
+            }
+        ));
+    });
+}
+
+#[test]
+fn pause_blocks_state_changing_ops_only() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Acl::set_paused(root(), ASSET, true));
+        let c = ctx(ALICE, None, Some(BOB), 0);
+        for op in ALL_OPS {
+            let res = authorize(op, &c);
+            match op {
+                Op::AcceptPending | Op::SetOperator => assert_ok!(res),
+                _ => assert_eq!(res, Err(DispatchError::Other("ACL: paused")), "{op:?}"),
+            }
+        }
+        // Other assets are unaffected
+        assert_ok!(authorize(
+            Op::Mint,
+            &AclCtx {
+                asset: ASSET + 1,
+                ..c.clone()
+            }
+        ));
+
+        assert_ok!(Acl::set_paused(root(), ASSET, false));
+        for op in ALL_OPS {
+            assert_ok!(authorize(op, &c));
+        }
+    });
+}
+
+#[test]
+fn admin_calls_reject_non_admin_origins() {
+    new_test_ext().execute_with(|| {
+        let signed = RuntimeOrigin::signed(ALICE);
+        assert_noop!(
+            Acl::set_paused(signed.clone(), ASSET, true),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Acl::set_max_per_tx(signed.clone(), ASSET, 1),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Acl::set_allowlist_enabled(signed.clone(), ASSET, true),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Acl::set_allowed(signed.clone(), ASSET, ALICE, true),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Acl::set_denied(signed.clone(), ASSET, BOB, true),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Acl::set_role(signed.clone(), ASSET, ALICE, Role::Issuer, true),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Acl::set_op_role(signed, ASSET, Op::Mint, Some(Role::Issuer)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Acl::set_paused(RuntimeOrigin::none(), ASSET, true),
+            DispatchError::BadOrigin
+        );
+    });
+}
@@ -0,0 +1,194 @@
+//! Node RPC for confidential assets, backed by `ConfidentialAssetsApi`.
+//!
+//! Each method returns in one call what a wallet needs to build a proof: the account's
+//! current commitments and pending deposits, or the asset's policy and the network id.
+//! Everything stays encrypted; the wallet decrypts with its own key.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use confidential_assets_primitives::EncryptedAmount;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::Codec;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_confidential_assets_runtime_api::ConfidentialAssetsApi as ConfidentialAssetsRuntimeApi;
+
+/// One unclaimed deposit, by the id `confidential_claim` takes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingTransfer {
+    pub id: u64,
+    /// 64-byte ElGamal ciphertext `C || D`.
+    pub amount: Bytes,
+}
+
+/// An account's state for one asset, as the prover takes it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountState {
+    pub public_key: Option<Bytes>,
+    /// Available balance commitment, the old commitment of a send, burn or claim.
+    pub available: H256,
+    /// Pending balance commitment, the old pending commitment of a claim.
+    pub pending: H256,
+    pub pending_transfers: Vec<PendingTransfer>,
+    pub network_id: H256,
+}
+
+/// What a proof for `asset` is bound to besides the account's state.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofParameters<AssetConfig> {
+    pub network_id: H256,
+    pub total_supply: H256,
+    pub asset_config: AssetConfig,
+}
+
+#[rpc(client, server)]
+pub trait ConfidentialAssetsApi<BlockHash, AccountId, AssetId, AssetConfig> {
+    /// `who`'s unclaimed deposits of `asset`, in id order.
+    #[method(name = "confidential_getPendingTransfers")]
+    fn pending_transfers(
+        &self,
+        asset: AssetId,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PendingTransfer>>;
+
+    /// `who`'s key, commitments and pending deposits of `asset`, read at one block.
+    #[method(name = "confidential_getAccountState")]
+    fn account_state(
+        &self,
+        asset: AssetId,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AccountState>;
+
+    /// `asset`'s policy, total supply and the chain's network id.
+    #[method(name = "confidential_getProofParameters")]
+    fn proof_parameters(
+        &self,
+        asset: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ProofParameters<AssetConfig>>;
+}
+
+/// Error code of a failed runtime API call.
+pub const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(e: ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query confidential asset state.",
+        Some(e.to_string()),
+    )
+}
+
+fn to_pending_transfers(deposits: Vec<(u64, EncryptedAmount)>) -> Vec<PendingTransfer> {
+    deposits
+        .into_iter()
+        .map(|(id, amount)| PendingTransfer {
+            id,
+            amount: amount.to_bytes().to_vec().into(),
+        })
+        .collect()
+}
+
+/// Implements [`ConfidentialAssetsApiServer`] over a client's runtime API.
+pub struct ConfidentialAssets<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> ConfidentialAssets<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Block, AccountId, AssetId, AssetConfig>
+    ConfidentialAssetsApiServer<<Block as BlockT>::Hash, AccountId, AssetId, AssetConfig>
+    for ConfidentialAssets<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ConfidentialAssetsRuntimeApi<Block, AccountId, AssetId, AssetConfig>,
+    AccountId: Codec + Clone + DeserializeOwned + Send + Sync + 'static,
+    AssetId: Codec + Copy + DeserializeOwned + Send + Sync + 'static,
+    AssetConfig: Codec + Serialize + Send + Sync + 'static,
+{
+    fn pending_transfers(
+        &self,
+        asset: AssetId,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<PendingTransfer>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let deposits = self
+            .client
+            .runtime_api()
+            .pending_transfers(at, asset, who)
+            .map_err(runtime_error)?;
+        Ok(to_pending_transfers(deposits))
+    }
+
+    fn account_state(
+        &self,
+        asset: AssetId,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<AccountState> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+        Ok(AccountState {
+            public_key: api
+                .public_key(at, who.clone())
+                .map_err(runtime_error)?
+                .map(|pk| pk.into_inner().into()),
+            available: H256(
+                api.balance_of(at, asset, who.clone())
+                    .map_err(runtime_error)?
+                    .to_bytes(),
+            ),
+            pending: H256(
+                api.pending_balance(at, asset, who.clone())
+                    .map_err(runtime_error)?
+                    .to_bytes(),
+            ),
+            pending_transfers: to_pending_transfers(
+                api.pending_transfers(at, asset, who)
+                    .map_err(runtime_error)?,
+            ),
+            network_id: H256(api.network_id(at).map_err(runtime_error)?),
+        })
+    }
+
+    fn proof_parameters(
+        &self,
+        asset: AssetId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<ProofParameters<AssetConfig>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+        Ok(ProofParameters {
+            network_id: H256(api.network_id(at).map_err(runtime_error)?),
+            total_supply: H256(
+                api.total_supply(at, asset)
+                    .map_err(runtime_error)?
+                    .to_bytes(),
+            ),
+            asset_config: api.asset_config(at, asset).map_err(runtime_error)?,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+//! Runtime API for reading confidential asset state.
+//!
+//! Wallets use it instead of decoding the backend's storage keys: every query returns
+//! the same commitments and ciphertexts the pallets hold, still encrypted.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use confidential_assets_primitives::{Commitment, EncryptedAmount, PublicKeyBytes};
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+    /// Encrypted balances, pending transfers, keys and per-asset policy.
+    ///
+    /// `AssetConfig` is the backend's policy type, e.g. `pallet_zkhe::AssetConfig`.
+    pub trait ConfidentialAssetsApi<AccountId, AssetId, AssetConfig>
+    where
+        AccountId: Codec,
+        AssetId: Codec,
+        AssetConfig: Codec,
+    {
+        /// `who`'s available balance commitment; the identity if they hold none.
+        fn balance_of(asset: AssetId, who: AccountId) -> Commitment;
+
+        /// `who`'s pending balance commitment, the sum of their unclaimed deposits; the
+        /// identity if they have none.
+        fn pending_balance(asset: AssetId, who: AccountId) -> Commitment;
+
+        /// `who`'s unclaimed deposits of `asset` as `(id, ciphertext)`, in id order.
+        /// The ids are the ones `confidential_claim` takes.
+        fn pending_transfers(asset: AssetId, who: AccountId) -> Vec<(u64, EncryptedAmount)>;
+
+        /// The ElGamal public key `who` registered, if any.
+        fn public_key(who: AccountId) -> Option<PublicKeyBytes>;
+
+        /// `asset`'s total supply commitment.
+        fn total_supply(asset: AssetId) -> Commitment;
+
+        /// `asset`'s policy, falling back to the default for unconfigured assets.
+        fn asset_config(asset: AssetId) -> AssetConfig;
+
+        /// The network id proofs for this chain must be bound to.
+        fn network_id() -> [u8; 32];
+    }
+}
@@ -1,7 +1,8 @@
 //! Benchmarking for `pallet-confidential-assets`.
 //!
-//! Note: Most operations delegate to the Backend (pallet_zkhe), so weights should
-//! be similar to the backend weights plus some overhead for the wrapper logic.
+//! Proofs come from the zkhe-vectors crate, so verification runs for real;
+//! `Config::BenchmarkHelper` writes the backend state each vector starts from.
+//! Calls that map one-to-one onto a pallet_zkhe extrinsic reuse its weights.
 
 use crate::*;
 use confidential_assets_primitives::*;
@@ -22,31 +23,111 @@ mod benchmarks {
             .expect("32 bytes fits in BoundedVec<64>")
     }
 
-    // set_public_key(who, elgamal_pk)
+    #[inline]
+    fn receiver_pk() -> PublicKeyBytes {
+        RECEIVER_PK32
+            .to_vec()
+            .try_into()
+            .expect("32 bytes fits in BoundedVec<64>")
+    }
+
+    // set_public_key(who, elgamal_pk, proof)
     #[benchmark]
     fn set_public_key() {
         let who: T::AccountId = whitelisted_caller();
+        // Bound to PK_OWNER_ACCOUNT, the whitelisted caller as an AccountId32
+        let proof: InputProof = seal_proof(PROOF_SYSTEM_ID, SENDER_PK_OWNERSHIP_PROOF)
+            .try_into()
+            .expect("proof fits");
 
         #[extrinsic_call]
-        set_public_key(RawOrigin::Signed(who), sender_pk());
+        set_public_key(RawOrigin::Signed(who), sender_pk(), proof);
+    }
+
+    /// Public funds for `who` to shield; above any existential deposit the ramp keeps
+    fn fund<T: Config>(asset: T::AssetId, who: &T::AccountId) -> T::Balance
+    where
+        T::Balance: From<u32>,
+    {
+        let amount: T::Balance = u32::MAX.into();
+        assert!(T::Ramp::mint(who, &asset, amount).is_ok(), "ramp mints");
+        amount
+    }
+
+    #[benchmark]
+    fn deposit() {
+        let who: T::AccountId = whitelisted_caller();
+        let asset = T::AssetId::default();
+        // The mint vector credits the receiver key, starting from an empty balance and supply
+        T::BenchmarkHelper::enable_asset(asset);
+        T::BenchmarkHelper::set_public_key(&who, receiver_pk());
+        let amount = fund::<T>(asset, &who);
+        let proof: InputProof = seal_proof(PROOF_SYSTEM_ID, MINT_PROOF)
+            .try_into()
+            .expect("proof fits");
+
+        #[extrinsic_call]
+        deposit(RawOrigin::Signed(who.clone()), asset, amount, proof);
+
+        assert!(!T::Backend::total_supply(asset).is_identity());
     }
 
-    // NOTE: deposit and withdraw benchmarks are omitted because they require:
-    // 1. A working Ramp implementation with sufficient public token balance
-    // 2. Proper mint/burn proof generation that matches the Ramp's internal accounting
-    //
-    // The main cryptographic weight comes from verify_mint/verify_burn which is
-    // already captured in the backend pallet benchmarks.
-
-    // NOTE: confidential_transfer, confidential_claim, confidential_transfer_from
-    // and disclose_amount benchmarks are omitted because they all delegate to the
-    // backend (pallet_zkhe) which has its own benchmarks.
-    //
-    // The weight for these operations should be:
-    //   confidential_assets_weight = backend_weight + small_overhead
-    //
-    // where backend_weight is from pallet_zkhe benchmarks and small_overhead
-    // accounts for the wrapper logic (event emission, etc.)
+    #[benchmark]
+    fn withdraw() {
+        let who: T::AccountId = whitelisted_caller();
+        let asset = T::AssetId::default();
+        T::BenchmarkHelper::enable_asset(asset);
+        T::BenchmarkHelper::set_public_key(&who, sender_pk());
+        T::BenchmarkHelper::set_available(asset, &who, Commitment::new(BURN_FROM_OLD_COMM_32));
+        T::BenchmarkHelper::set_total_supply(asset, Commitment::new(BURN_TOTAL_OLD_COMM_32));
+        let proof: InputProof = seal_proof(PROOF_SYSTEM_ID, BURN_PROOF)
+            .try_into()
+            .expect("proof fits");
+
+        #[extrinsic_call]
+        withdraw(
+            RawOrigin::Signed(who.clone()),
+            asset,
+            EncryptedAmount::new(BURN_AMOUNT_CT_64),
+            proof,
+        );
+
+        assert_ne!(
+            T::Backend::balance_of(asset, &who),
+            Commitment::new(BURN_FROM_OLD_COMM_32)
+        );
+    }
+
+    // Bounded by the smallest `MaxClaimUtxos` a zkhe backend is configured with
+    #[benchmark]
+    fn confidential_claim(n: Linear<1, 16>) {
+        let who: T::AccountId = whitelisted_caller();
+        let asset = T::AssetId::default();
+        T::BenchmarkHelper::set_public_key(&who, receiver_pk());
+
+        // The accept vector consumes a pending balance of TRANSFER_DELTA_COMM_32: queue
+        // `n - 1` arbitrary deposits and a last one that makes up the difference
+        let filler = Commitment::new(MINT_TO_NEW_COMM_32);
+        let ct = EncryptedAmount::new(TRANSFER_DELTA_CT_64);
+        let mut last = Commitment::new(TRANSFER_DELTA_COMM_32);
+        let mut claim = (n as u16).to_le_bytes().to_vec();
+        for _ in 1..n {
+            let id = T::BenchmarkHelper::add_pending(asset, &who, ct, filler);
+            claim.extend_from_slice(&id.to_le_bytes());
+            last = last.sub(&filler).expect("vector points decompress");
+        }
+        let id = T::BenchmarkHelper::add_pending(asset, &who, ct, last);
+        claim.extend_from_slice(&id.to_le_bytes());
+        claim.extend_from_slice(ACCEPT_ENVELOPE);
+        let proof: InputProof = seal_proof(PROOF_SYSTEM_ID, &claim)
+            .try_into()
+            .expect("proof fits");
+
+        #[extrinsic_call]
+        confidential_claim(RawOrigin::Signed(who.clone()), asset, proof);
+
+        assert!(!T::Backend::balance_of(asset, &who).is_identity());
+    }
 
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -0,0 +1,227 @@
+//! Transaction extension that lets a signer pay the transaction fee from a confidential
+//! balance instead of a public one.
+
+use super::*;
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    storage::{TransactionOutcome, with_transaction},
+};
+use pallet_transaction_payment::BalanceOf;
+use sp_runtime::{
+    DispatchError,
+    traits::{
+        AsSystemOriginSigner, DispatchInfoOf, DispatchOriginOf, Dispatchable, Implication,
+        PostDispatchInfoOf, TransactionExtension, TransactionExtensionMetadata, ValidateResult,
+        Zero,
+    },
+    transaction_validity::{
+        InvalidTransaction, TransactionSource, TransactionValidityError, ValidTransaction,
+    },
+};
+
+/// A fee paid from the signer's confidential balance of [`Config::FeeAsset`]:
+/// `encrypted_amount` is burned with `proof`, a burn proof that discloses it.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo, RuntimeDebug)]
+pub struct ConfidentialFee {
+    pub encrypted_amount: EncryptedAmount,
+    pub proof: InputProof,
+}
+
+/// Where a transaction's fee comes from.
+pub enum FeeSource<Inner, AccountId, Balance> {
+    /// The wrapped extension charges the fee publicly.
+    Public(Inner),
+    /// The signer burns this much of their confidential balance.
+    Confidential(AccountId, Balance),
+}
+
+/// Wraps the runtime's fee extension (normally `ChargeTransactionPayment`) so a signer
+/// with only shielded funds can still transact.
+///
+/// Without a [`ConfidentialFee`] the wrapped extension runs unchanged. With one, it is
+/// skipped and the signer instead burns `encrypted_amount` from their confidential
+/// balance of [`Config::FeeAsset`], as `withdraw` would but without releasing anything
+/// publicly, so the fee leaves the supply. The disclosed amount must cover the full
+/// fee for the transaction's declared weight and length, without tip; nothing is
+/// refunded when the call uses less.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeConfidentialFee<T, S> {
+    inner: S,
+    fee: Option<ConfidentialFee>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T, S> ChargeConfidentialFee<T, S> {
+    /// Charges the fee through `inner`.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            fee: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Pays the fee with `fee` instead of through `inner`.
+    pub fn with_fee(inner: S, fee: ConfidentialFee) -> Self {
+        Self {
+            inner,
+            fee: Some(fee),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S> From<S> for ChargeConfidentialFee<T, S> {
+    fn from(inner: S) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T, S: core::fmt::Debug> core::fmt::Debug for ChargeConfidentialFee<T, S> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.fee {
+            Some(_) => write!(f, "ChargeConfidentialFee<confidential>"),
+            None => write!(f, "ChargeConfidentialFee<{:?}>", self.inner),
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config, S> ChargeConfidentialFee<T, S> {
+    /// Burns `fee` from `who` and checks the disclosed amount covers `due`.
+    fn burn(
+        who: &T::AccountId,
+        fee: &ConfidentialFee,
+        due: T::Balance,
+    ) -> Result<T::Balance, DispatchError> {
+        let paid = T::Backend::burn_encrypted(
+            T::FeeAsset::get(),
+            who,
+            fee.encrypted_amount,
+            fee.proof.clone(),
+        )?;
+        ensure!(paid >= due, Error::<T>::FeeTooLow);
+        Ok(paid)
+    }
+}
+
+impl<T, S> TransactionExtension<T::RuntimeCall> for ChargeConfidentialFee<T, S>
+where
+    T: Config + pallet_transaction_payment::Config + Send + Sync,
+    T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    DispatchOriginOf<T::RuntimeCall>: AsSystemOriginSigner<T::AccountId>,
+    T::Balance: From<BalanceOf<T>>,
+    S: TransactionExtension<T::RuntimeCall>,
+{
+    const IDENTIFIER: &'static str = "ChargeConfidentialFee";
+    type Implicit = S::Implicit;
+    type Val = FeeSource<S::Val, T::AccountId, T::Balance>;
+    type Pre = FeeSource<S::Pre, T::AccountId, T::Balance>;
+
+    fn metadata() -> Vec<TransactionExtensionMetadata> {
+        let mut metadata = S::metadata();
+        metadata.push(TransactionExtensionMetadata {
+            identifier: Self::IDENTIFIER,
+            ty: scale_info::meta_type::<Option<ConfidentialFee>>(),
+            implicit: scale_info::meta_type::<()>(),
+        });
+        metadata
+    }
+
+    fn implicit(&self) -> Result<Self::Implicit, TransactionValidityError> {
+        self.inner.implicit()
+    }
+
+    fn weight(&self, call: &T::RuntimeCall) -> Weight {
+        match self.fee {
+            Some(_) => <T as Config>::WeightInfo::withdraw(),
+            None => self.inner.weight(call),
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        self_implicit: Self::Implicit,
+        inherited_implication: &impl Implication,
+        source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let Some(fee) = &self.fee else {
+            let (valid, val, origin) = self.inner.validate(
+                origin,
+                call,
+                info,
+                len,
+                self_implicit,
+                inherited_implication,
+                source,
+            )?;
+            return Ok((valid, FeeSource::Public(val), origin));
+        };
+        let who = origin
+            .as_system_origin_signer()
+            .ok_or(InvalidTransaction::BadSigner)?
+            .clone();
+        let due: T::Balance =
+            pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, Zero::zero())
+                .into();
+        // Dry-run the burn; `prepare` burns for real.
+        with_transaction(|| TransactionOutcome::Rollback(Self::burn(&who, fee, due)))
+            .map_err(|_| InvalidTransaction::Payment)?;
+        Ok((
+            ValidTransaction::default(),
+            FeeSource::Confidential(who, due),
+            origin,
+        ))
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        origin: &DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        match (val, self.fee) {
+            (FeeSource::Public(val), _) => self
+                .inner
+                .prepare(val, origin, call, info, len)
+                .map(FeeSource::Public),
+            (FeeSource::Confidential(who, due), Some(fee)) => {
+                let paid = Self::burn(&who, &fee, due).map_err(|_| InvalidTransaction::Payment)?;
+                Ok(FeeSource::Confidential(who, paid))
+            }
+            (FeeSource::Confidential(..), None) => Err(InvalidTransaction::Payment.into()),
+        }
+    }
+
+    fn post_dispatch_details(
+        pre: Self::Pre,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<Weight, TransactionValidityError> {
+        match pre {
+            FeeSource::Public(pre) => S::post_dispatch_details(pre, info, post_info, len, result),
+            FeeSource::Confidential(who, amount) => {
+                Pallet::<T>::deposit_event(Event::ConfidentialFeePaid {
+                    who,
+                    asset: T::FeeAsset::get(),
+                    amount,
+                });
+                Ok(Weight::zero())
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 // pallets/confidential-assets/src/lib.rs
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod fee;
+pub mod offchain;
+pub mod ramp;
 pub mod weights;
 
 #[cfg(test)]
@@ -14,12 +17,114 @@ mod benchmarking;
 extern crate alloc;
 
 use confidential_assets_primitives::*;
-use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+use frame_support::{
+    dispatch::DispatchResult,
+    pallet_prelude::*,
+    traits::{
+        OffchainWorker,
+        fungible::{self, MutateHold},
+        tokens::Precision,
+    },
+    transactional,
+};
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
+use sp_runtime::traits::{BlakeTwo256, Hash, UniqueSaturatedInto, Zero};
 use sp_std::prelude::*;
 
+pub use fee::{ChargeConfidentialFee, ConfidentialFee};
+pub use offchain::AutoClaim;
 pub use pallet::*;
+pub use ramp::FungiblesRamp;
+
+/// Balance of the currency asset deposits are held in.
+pub type DepositBalanceOf<T> =
+    <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Owner, admin and held deposit of an asset registered with `create_confidential_asset`.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct AssetDetails<AccountId, DepositBalance> {
+    /// Created the asset, holds its deposit and appoints the admin.
+    pub owner: AccountId,
+    /// Manages the asset's metadata and policy.
+    pub admin: AccountId,
+    pub deposit: DepositBalance,
+}
+
+/// Metadata stored for an asset registered with `create_confidential_asset`.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct ConfidentialAssetMetadata<BoundedString> {
+    pub name: BoundedString,
+    pub symbol: BoundedString,
+    pub decimals: u8,
+    /// ERC-7984 `contractURI`, e.g. a link to JSON describing the asset.
+    pub contract_uri: BoundedString,
+}
+
+/// Dust limits of a registered asset, set by its admin with `set_asset_policy`.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub struct AssetPolicy<Balance> {
+    /// Least amount `deposit` shields.
+    pub min_shield_amount: Balance,
+    /// Least amount a confidential transfer's commitment may hide. Transfers prove
+    /// it with a range proof; see [`WithTransferFloor`].
+    pub min_transfer_commitment: Balance,
+}
+
+/// [`RangeBitsProvider`] that takes each asset's range-proof width from `B` and its
+/// transfer floor from the asset's [`AssetPolicy`]. Give it to the verifier in place
+/// of `B` to enforce `min_transfer_commitment`.
+pub struct WithTransferFloor<T, B>(PhantomData<(T, B)>);
+
+impl<T, B> RangeBitsProvider for WithTransferFloor<T, B>
+where
+    T: Config,
+    T::Balance: UniqueSaturatedInto<u64>,
+    B: RangeBitsProvider,
+{
+    fn range_bits(asset: &[u8]) -> u8 {
+        B::range_bits(asset)
+    }
+
+    fn transfer_floor(asset: &[u8]) -> u64 {
+        T::AssetId::decode(&mut &asset[..])
+            .map(|asset| {
+                AssetPolicies::<T>::get(asset)
+                    .min_transfer_commitment
+                    .unique_saturated_into()
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// A confidential transfer held in escrow until `execute_at`.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct ScheduledTransfer<AccountId, AssetId, BlockNumber, DepositBalance> {
+    pub asset: AssetId,
+    pub from: AccountId,
+    pub to: AccountId,
+    /// Amount as received by the escrow.
+    pub encrypted_amount: EncryptedAmount,
+    pub execute_at: BlockNumber,
+    /// Held from `from` until the transfer executes or is cancelled.
+    pub deposit: DepositBalance,
+    /// `T::Escrow` lock holding the amount.
+    pub escrow_id: EscrowId,
+}
+
+/// Purpose tag of the escrow locks scheduled transfers open.
+pub const SCHEDULED_TRANSFER_PURPOSE: EscrowPurpose = *b"schedule";
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -48,26 +153,104 @@ pub mod pallet {
         /// Operator layer. Defaults to always returning false when assigned ().
         type Operators: OperatorRegistry<Self::AccountId, Self::AssetId, BlockNumberFor<Self>>;
 
+        /// Custody of scheduled transfers until they execute. Use () to disable
+        /// `confidential_transfer_scheduled`.
+        type Escrow: ConfidentialEscrow<Self::AccountId, Self::AssetId, BlockNumberFor<Self>>;
+
+        /// Held from the sender of each scheduled transfer until it executes or is
+        /// cancelled.
+        #[pallet::constant]
+        type ScheduledTransferDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Most transfers one `confidential_transfer_many` call may carry.
+        #[pallet::constant]
+        type MaxTransfersPerBatch: Get<u32>;
+
+        /// Metadata of assets not registered with `create_confidential_asset`.
         type AssetMetadata: AssetMetadataProvider<Self::AssetId>;
 
+        /// Currency asset deposits are held in.
+        type Currency: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + fungible::Mutate<Self::AccountId>;
+
+        /// Overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Held from the owner of each asset registered with `create_confidential_asset`.
+        #[pallet::constant]
+        type AssetDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Longest name, symbol or contract URI of a registered asset.
+        #[pallet::constant]
+        type StringLimit: Get<u32>;
+
+        /// Runs after deposits, withdrawals, transfers and claims succeed (default = ()).
+        type Callbacks: ConfidentialCallbacks<Self::AccountId, Self::AssetId, Self::Balance>;
+
+        /// Notified by `confidential_transfer_and_call`; may reject the transfer (default = ()).
+        type OnTransferReceived: OnConfidentialTransferReceived<Self::AccountId, Self::AssetId>;
+
+        /// Asset whose confidential balance can pay transaction fees through
+        /// [`ChargeConfidentialFee`]; the asset fees are charged in.
+        type FeeAsset: Get<Self::AssetId>;
+
+        /// Submits claims from the offchain worker, e.g. [`AutoClaim`]; `()` disables it.
+        type AutoClaim: OffchainWorker<BlockNumberFor<Self>>;
+
+        /// Origin that may seize balances with `force_unshield`.
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type WeightInfo: WeightInfo;
+
+        /// Seeds `Backend` state for benchmarks; `pallet_zkhe::Pallet` implements it.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BackendBenchmarkHelper<Self::AccountId, Self::AssetId>;
     }
 
     pub trait WeightInfo {
         fn set_public_key() -> Weight;
+        fn deposit() -> Weight;
+        fn withdraw() -> Weight;
+        fn confidential_claim(n: u32) -> Weight;
         fn confidential_transfer() -> Weight;
+        fn confidential_transfer_many(n: u32) -> Weight;
         fn confidential_transfer_from() -> Weight;
         fn confidential_transfer_and_call() -> Weight;
         fn confidential_transfer_from_and_call() -> Weight;
         fn disclose_amount() -> Weight;
+        fn confidential_burn() -> Weight;
+        fn close_confidential_account() -> Weight;
+        fn create_confidential_asset() -> Weight;
+        fn set_asset_metadata() -> Weight;
+        fn set_asset_admin() -> Weight;
+        fn set_asset_policy() -> Weight;
+        fn confidential_transfer_scheduled() -> Weight;
+        fn execute_scheduled_transfer() -> Weight;
+        fn cancel_scheduled_transfer() -> Weight;
+        fn confidential_mint() -> Weight;
+        fn force_unshield() -> Weight;
+        fn confidential_transfer_split(n: u32) -> Weight;
     }
     impl WeightInfo for () {
         fn set_public_key() -> Weight {
             Weight::from_parts(10_000, 0)
         }
+        fn deposit() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn withdraw() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn confidential_claim(n: u32) -> Weight {
+            Weight::from_parts(20_000, 0)
+                .saturating_add(Weight::from_parts(1_000, 0).saturating_mul(n.into()))
+        }
         fn confidential_transfer() -> Weight {
             Weight::from_parts(20_000, 0)
         }
+        fn confidential_transfer_many(n: u32) -> Weight {
+            Weight::from_parts(20_000, 0).saturating_mul(n.into())
+        }
         fn confidential_transfer_from() -> Weight {
             Weight::from_parts(22_000, 0)
         }
@@ -80,38 +263,115 @@ pub mod pallet {
         fn disclose_amount() -> Weight {
             Weight::from_parts(5_000, 0)
         }
+        fn confidential_burn() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn close_confidential_account() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn create_confidential_asset() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_asset_metadata() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_asset_admin() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_asset_policy() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn confidential_transfer_scheduled() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn execute_scheduled_transfer() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn cancel_scheduled_transfer() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn confidential_mint() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn force_unshield() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn confidential_transfer_split(n: u32) -> Weight {
+            Weight::from_parts(20_000, 0)
+                .saturating_add(Weight::from_parts(5_000, 0).saturating_mul(n.into()))
+        }
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         // On/Off Ramp Events (v0 without privacy)
+        /// `amount` was shielded as pending transfer `pending_id`, taking `who`'s
+        /// pending balance from `old_pending` to `new_pending`.
         Deposited {
             who: T::AccountId,
             asset: T::AssetId,
             amount: T::Balance,
             encrypted_amount: EncryptedAmount,
+            pending_id: Option<u64>,
+            old_pending: Commitment,
+            new_pending: Commitment,
+            /// blake2-256 of the mint proof.
+            proof_hash: [u8; 32],
         },
         Withdrawn {
             who: T::AccountId,
             asset: T::AssetId,
             encrypted_amount: EncryptedAmount,
             amount: T::Balance,
+            old_available: Commitment,
+            new_available: Commitment,
+            /// blake2-256 of the burn proof.
+            proof_hash: [u8; 32],
         },
         // User calls
         PublicKeySet {
             who: T::AccountId,
         },
+        /// `encrypted_amount` was queued for `to` as pending transfer `pending_id`.
+        /// Carries the balance commitments the transfer changed, so indexers can follow
+        /// both accounts without reading state.
         ConfidentialTransfer {
             asset: T::AssetId,
             from: T::AccountId,
             to: T::AccountId,
             encrypted_amount: EncryptedAmount,
+            pending_id: Option<u64>,
+            from_old_available: Commitment,
+            from_new_available: Commitment,
+            to_old_pending: Commitment,
+            to_new_pending: Commitment,
+            /// blake2-256 of the transfer proof.
+            proof_hash: [u8; 32],
         },
+        /// `total` left `from`'s available balance under one proof, split across
+        /// `recipients` outputs. One `ConfidentialTransfer` per output follows, each
+        /// carrying the sender commitments of the whole split.
+        ConfidentialTransferSplit {
+            asset: T::AssetId,
+            from: T::AccountId,
+            total: EncryptedAmount,
+            recipients: u32,
+            /// blake2-256 of the split proof.
+            proof_hash: [u8; 32],
+        },
+        /// `who` claimed pending transfers into their available balance. The backend's
+        /// own event lists the claimed ids.
         ConfidentialClaimed {
             asset: T::AssetId,
             who: T::AccountId,
             encrypted_amount: EncryptedAmount,
+            old_available: Commitment,
+            new_available: Commitment,
+            old_pending: Commitment,
+            new_pending: Commitment,
+            /// blake2-256 of the claim proof.
+            proof_hash: [u8; 32],
         },
         AmountDisclosed {
             asset: T::AssetId,
@@ -119,6 +379,66 @@ pub mod pallet {
             amount: T::Balance,
             discloser: T::AccountId,
         },
+        ConfidentialBurned {
+            asset: T::AssetId,
+            who: T::AccountId,
+            encrypted_amount: EncryptedAmount,
+        },
+        ConfidentialMinted {
+            asset: T::AssetId,
+            to: T::AccountId,
+            encrypted_amount: EncryptedAmount,
+        },
+        ConfidentialAccountClosed {
+            asset: T::AssetId,
+            who: T::AccountId,
+        },
+        /// `who`'s whole available balance was seized and `amount` paid out publicly to
+        /// `beneficiary`. `encrypted_balance` is the seized balance under the auditor key.
+        ForceUnshielded {
+            asset: T::AssetId,
+            who: T::AccountId,
+            beneficiary: T::AccountId,
+            encrypted_balance: EncryptedAmount,
+            amount: T::Balance,
+        },
+        ConfidentialFeePaid {
+            who: T::AccountId,
+            asset: T::AssetId,
+            amount: T::Balance,
+        },
+        // Asset registry
+        AssetCreated {
+            asset: T::AssetId,
+            owner: T::AccountId,
+            admin: T::AccountId,
+        },
+        AssetMetadataSet {
+            asset: T::AssetId,
+        },
+        AssetAdminSet {
+            asset: T::AssetId,
+            admin: T::AccountId,
+        },
+        AssetPolicySet {
+            asset: T::AssetId,
+            policy: AssetPolicy<T::Balance>,
+        },
+        // Scheduled transfers
+        TransferScheduled {
+            id: u64,
+            asset: T::AssetId,
+            from: T::AccountId,
+            to: T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            execute_at: BlockNumberFor<T>,
+        },
+        ScheduledTransferExecuted {
+            id: u64,
+        },
+        ScheduledTransferCancelled {
+            id: u64,
+        },
     }
 
     #[pallet::error]
@@ -128,11 +448,80 @@ pub mod pallet {
         BackendError,
         RampFailed,
         InsufficientConfidential, // if your debit fails
+        AssetPaused,              // the backend has paused the asset
+        FeeTooLow,
+        AssetExists,
+        UnknownAsset,
+        /// Deposit below the asset's `min_shield_amount`.
+        BelowMinimum,
+        /// `execute_at` is not after the current block.
+        ScheduleInPast,
+        UnknownScheduledTransfer,
+        /// The scheduled transfer's `execute_at` has not been reached.
+        NotDue,
+        /// The scheduled transfer is due and can no longer be cancelled.
+        AlreadyDue,
+        /// A split transfer lists no outputs.
+        EmptySplit,
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit for an asset registered with `create_confidential_asset`.
+        AssetDeposit,
+        /// Deposit for a transfer scheduled with `confidential_transfer_scheduled`.
+        ScheduledTransfer,
+    }
+
+    /// Assets registered with `create_confidential_asset`.
+    #[pallet::storage]
+    pub type Asset<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        AssetDetails<T::AccountId, DepositBalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Dust limits of registered assets; none by default.
+    #[pallet::storage]
+    pub type AssetPolicies<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, AssetPolicy<T::Balance>, ValueQuery>;
+
+    /// Metadata of registered assets; takes precedence over `Config::AssetMetadata`.
+    #[pallet::storage]
+    pub type Metadata<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        ConfidentialAssetMetadata<BoundedVec<u8, T::StringLimit>>,
+        OptionQuery,
+    >;
+
+    /// Transfers waiting in escrow, by id.
+    #[pallet::storage]
+    pub type ScheduledTransfers<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        ScheduledTransfer<T::AccountId, T::AssetId, BlockNumberFor<T>, DepositBalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Id of the next scheduled transfer.
+    #[pallet::storage]
+    pub type NextScheduledTransferId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn offchain_worker(n: BlockNumberFor<T>) {
+            T::AutoClaim::offchain_worker(n);
+        }
+    }
+
     // ---------- Read helpers ----------
     impl<T: Config> Pallet<T> {
         pub fn confidential_total_supply(asset: T::AssetId) -> Commitment {
@@ -141,14 +530,38 @@ pub mod pallet {
         pub fn confidential_balance_of(asset: T::AssetId, who: &T::AccountId) -> Commitment {
             T::Backend::balance_of(asset, who)
         }
+        pub fn confidential_pending_balance_of(
+            asset: T::AssetId,
+            who: &T::AccountId,
+        ) -> Commitment {
+            T::Backend::pending_balance_of(asset, who)
+        }
+        pub fn last_pending_id(asset: T::AssetId, who: &T::AccountId) -> Option<u64> {
+            T::Backend::last_pending_id(asset, who)
+        }
         pub fn asset_name(asset: T::AssetId) -> Vec<u8> {
-            T::AssetMetadata::name(asset)
+            match Metadata::<T>::get(asset) {
+                Some(metadata) => metadata.name.into_inner(),
+                None => T::AssetMetadata::name(asset),
+            }
         }
         pub fn asset_symbol(asset: T::AssetId) -> Vec<u8> {
-            T::AssetMetadata::symbol(asset)
+            match Metadata::<T>::get(asset) {
+                Some(metadata) => metadata.symbol.into_inner(),
+                None => T::AssetMetadata::symbol(asset),
+            }
         }
         pub fn asset_decimals(asset: T::AssetId) -> u8 {
-            T::AssetMetadata::decimals(asset)
+            match Metadata::<T>::get(asset) {
+                Some(metadata) => metadata.decimals,
+                None => T::AssetMetadata::decimals(asset),
+            }
+        }
+        /// Contract URI of a registered asset; empty otherwise.
+        pub fn asset_contract_uri(asset: T::AssetId) -> Vec<u8> {
+            Metadata::<T>::get(asset)
+                .map(|metadata| metadata.contract_uri.into_inner())
+                .unwrap_or_default()
         }
     }
 
@@ -157,7 +570,8 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// User converts public -> confidential to shield assets
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::set_public_key())] //TODO
+        #[pallet::weight(T::WeightInfo::deposit())]
+        #[transactional]
         pub fn deposit(
             origin: OriginFor<T>,
             asset: T::AssetId,
@@ -165,14 +579,28 @@ pub mod pallet {
             proof: InputProof,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
+            ensure!(
+                amount >= AssetPolicies::<T>::get(asset).min_shield_amount,
+                Error::<T>::BelowMinimum
+            );
+            let mut ctx = Self::acl_ctx(asset, &who, None, Some(&who), None, &proof);
+            ctx.amount = amount;
+            T::Acl::authorize(Op::Shield, &ctx)?;
 
             // pull public funds into pallet custody
             T::Ramp::burn(&who, &asset, amount).map_err(|_| Error::<T>::RampFailed)?;
 
             // credit confidential balance
+            let old_pending = T::Backend::pending_balance_of(asset, &who);
             let encrypted_amount = T::Backend::mint_encrypted(asset, &who, proof)?;
+            T::Callbacks::on_shield(asset, &who, amount, &encrypted_amount);
 
             Self::deposit_event(Event::Deposited {
+                pending_id: T::Backend::last_pending_id(asset, &who),
+                old_pending,
+                new_pending: T::Backend::pending_balance_of(asset, &who),
+                proof_hash: ctx.proof_hash.unwrap_or_default(),
                 who,
                 asset,
                 amount,
@@ -183,7 +611,8 @@ pub mod pallet {
 
         /// User converts confidential -> public to unshield assets
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::set_public_key())] //TODO
+        #[pallet::weight(T::WeightInfo::withdraw())]
+        #[transactional]
         pub fn withdraw(
             origin: OriginFor<T>,
             asset: T::AssetId,
@@ -191,15 +620,33 @@ pub mod pallet {
             proof: InputProof,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
+            let mut ctx = Self::acl_ctx(
+                asset,
+                &who,
+                Some(&who),
+                None,
+                Some(encrypted_amount),
+                &proof,
+            );
 
             // debit confidential (fail if insufficient)
+            let old_available = ctx.from_commitment.unwrap_or_default();
             let amount = T::Backend::burn_encrypted(asset, &who, encrypted_amount, proof)
                 .map_err(|_| Error::<T>::InsufficientConfidential)?;
+            // The amount is only known once the burn proof discloses it; the call is
+            // transactional, so a rejection still reverts the burn.
+            ctx.amount = amount;
+            T::Acl::authorize(Op::Unshield, &ctx)?;
 
             // push public funds out of pallet custody
             T::Ramp::mint(&who, &asset, amount).map_err(|_| Error::<T>::RampFailed)?;
+            T::Callbacks::on_unshield(asset, &who, amount, &encrypted_amount);
 
             Self::deposit_event(Event::Withdrawn {
+                old_available,
+                new_available: T::Backend::balance_of(asset, &who),
+                proof_hash: ctx.proof_hash.unwrap_or_default(),
                 who,
                 asset,
                 encrypted_amount,
@@ -208,11 +655,73 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Destroy confidential balance without revealing the amount.
+        ///
+        /// Reduces the caller's available balance and the asset's total supply by the
+        /// value `encrypted_amount` encrypts; nothing is released publicly, so this is
+        /// for supply reduction (e.g. buy-back and burn), not unshielding.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::confidential_burn())]
+        pub fn confidential_burn(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            encrypted_amount: EncryptedAmount,
+            proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
+            T::Acl::authorize(
+                Op::Burn,
+                &Self::acl_ctx(
+                    asset,
+                    &who,
+                    Some(&who),
+                    None,
+                    Some(encrypted_amount),
+                    &proof,
+                ),
+            )?;
+            T::Backend::burn_confidential(asset, &who, encrypted_amount, proof)?;
+            Self::deposit_event(Event::ConfidentialBurned {
+                asset,
+                who,
+                encrypted_amount,
+            });
+            Ok(())
+        }
+
+        /// Close the caller's confidential account for `asset`.
+        ///
+        /// `proof` shows the available and pending balances are both zero (see
+        /// `zkhe_prover::prove_zero_balance`); the backend then deletes the account's
+        /// commitments and pending deposits. The registered public key is kept, since it
+        /// is shared across assets.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::close_confidential_account())]
+        pub fn close_confidential_account(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            T::Backend::close_account(asset, &who, proof)?;
+            Self::deposit_event(Event::ConfidentialAccountClosed { asset, who });
+            Ok(())
+        }
+
+        /// Register `elgamal_pk` for the caller. `proof` shows knowledge of its secret key
+        /// (see `zkhe_prover::prove_pubkey_ownership`). Callers that already have a key
+        /// rotate it through the backend instead (`pallet_zkhe::rotate_public_key`).
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::set_public_key())]
-        pub fn set_public_key(origin: OriginFor<T>, elgamal_pk: PublicKeyBytes) -> DispatchResult {
+        pub fn set_public_key(
+            origin: OriginFor<T>,
+            elgamal_pk: PublicKeyBytes,
+            proof: InputProof,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            T::Backend::set_public_key(&who, &elgamal_pk).map_err(|_| Error::<T>::BackendError)?;
+            T::Backend::set_public_key(&who, &elgamal_pk, proof)
+                .map_err(|_| Error::<T>::BackendError)?;
             Self::deposit_event(Event::PublicKeySet { who });
             Ok(())
         }
@@ -227,28 +736,499 @@ pub mod pallet {
             input_proof: InputProof,
         ) -> DispatchResult {
             let from = ensure_signed(origin)?;
-            let transferred =
-                T::Backend::transfer_encrypted(asset, &from, &to, encrypted_amount, input_proof)
-                    .map_err(|_| Error::<T>::BackendError)?;
-            Self::deposit_event(Event::ConfidentialTransfer {
+            Self::ensure_not_paused(&asset)?;
+            T::Acl::authorize(
+                Op::Transfer,
+                &Self::acl_ctx(
+                    asset,
+                    &from,
+                    Some(&from),
+                    Some(&to),
+                    Some(encrypted_amount),
+                    &input_proof,
+                ),
+            )?;
+            Self::do_transfer(asset, &from, &to, encrypted_amount, input_proof, None)
+        }
+
+        /// Confidential transfer that then hands `data` to the receiver through
+        /// `OnTransferReceived` (ERC-7984 `confidentialTransferAndCall`). The whole
+        /// transfer is reverted if the receiver rejects it.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::confidential_transfer_and_call())]
+        #[transactional]
+        pub fn confidential_transfer_and_call(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            to: T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            input_proof: InputProof,
+            data: CallbackData,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
+            let mut ctx = Self::acl_ctx(
+                asset,
+                &from,
+                Some(&from),
+                Some(&to),
+                Some(encrypted_amount),
+                &input_proof,
+            );
+            ctx.data = AclOpData::Call {
+                data_hash: BlakeTwo256::hash(&data).into(),
+            };
+            T::Acl::authorize(Op::Transfer, &ctx)?;
+            Self::do_transfer(
+                asset,
+                &from,
+                &to,
+                encrypted_amount,
+                input_proof,
+                Some(&data),
+            )
+        }
+
+        /// Several confidential transfers from the caller in one transaction, e.g. for
+        /// payroll or airdrops. Transfers run in order, so each proof must start from
+        /// the caller's available balance after the one before; if any fails, none apply.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::confidential_transfer_many(transfers.len() as u32))]
+        #[transactional]
+        pub fn confidential_transfer_many(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            transfers: BoundedVec<
+                (T::AccountId, EncryptedAmount, InputProof),
+                T::MaxTransfersPerBatch,
+            >,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
+            let len = transfers.len() as u32;
+            for (index, (to, encrypted_amount, input_proof)) in transfers.into_iter().enumerate() {
+                let mut ctx = Self::acl_ctx(
+                    asset,
+                    &from,
+                    Some(&from),
+                    Some(&to),
+                    Some(encrypted_amount),
+                    &input_proof,
+                );
+                ctx.data = AclOpData::Batch {
+                    index: index as u32,
+                    len,
+                };
+                T::Acl::authorize(Op::Transfer, &ctx)?;
+                Self::do_transfer(asset, &from, &to, encrypted_amount, input_proof, None)?;
+            }
+            Ok(())
+        }
+
+        /// Pay `total` out of the caller's available balance to several recipients under
+        /// a single proof, e.g. a DAO paying contributors. Each output is encrypted under
+        /// its recipient's key and the proof shows they add up to `total`, so the split
+        /// is never revealed. Every output is queued as a pending transfer, and every
+        /// recipient must pass the ACL.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::confidential_transfer_split(outputs.len() as u32))]
+        #[transactional]
+        pub fn confidential_transfer_split(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            total: EncryptedAmount,
+            outputs: BoundedVec<(T::AccountId, EncryptedAmount), T::MaxTransfersPerBatch>,
+            input_proof: InputProof,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
+            ensure!(!outputs.is_empty(), Error::<T>::EmptySplit);
+
+            let len = outputs.len() as u32;
+            for (index, (to, encrypted_amount)) in outputs.iter().enumerate() {
+                let mut ctx = Self::acl_ctx(
+                    asset,
+                    &from,
+                    Some(&from),
+                    Some(to),
+                    Some(*encrypted_amount),
+                    &input_proof,
+                );
+                ctx.data = AclOpData::Batch {
+                    index: index as u32,
+                    len,
+                };
+                T::Acl::authorize(Op::Transfer, &ctx)?;
+            }
+
+            // Pending balance of each recipient before the split; a recipient paid more
+            // than once reports the balance before and after all of its outputs
+            let mut pending: Vec<(T::AccountId, Commitment)> = Vec::new();
+            for (to, _) in outputs.iter() {
+                if !pending.iter().any(|(who, _)| who == to) {
+                    pending.push((to.clone(), T::Backend::pending_balance_of(asset, to)));
+                }
+            }
+
+            let proof_hash = BlakeTwo256::hash(&input_proof).into();
+            let from_old_available = T::Backend::balance_of(asset, &from);
+            let ids = T::Backend::transfer_split(asset, &from, total, &outputs, input_proof)?;
+            let from_new_available = T::Backend::balance_of(asset, &from);
+
+            Self::deposit_event(Event::ConfidentialTransferSplit {
                 asset,
                 from: from.clone(),
-                to: to.clone(),
-                encrypted_amount: transferred.clone(),
+                total,
+                recipients: len,
+                proof_hash,
+            });
+            for ((to, encrypted_amount), id) in outputs.iter().zip(ids) {
+                T::Callbacks::on_transfer(asset, &from, to, encrypted_amount);
+                let to_old_pending = pending
+                    .iter()
+                    .find(|(who, _)| who == to)
+                    .map(|(_, balance)| *balance)
+                    .unwrap_or_default();
+                Self::deposit_event(Event::ConfidentialTransfer {
+                    asset,
+                    from: from.clone(),
+                    to: to.clone(),
+                    encrypted_amount: *encrypted_amount,
+                    pending_id: Some(id),
+                    from_old_available,
+                    from_new_available,
+                    to_old_pending,
+                    to_new_pending: T::Backend::pending_balance_of(asset, to),
+                    proof_hash,
+                });
+            }
+            Ok(())
+        }
+
+        /// Register `asset` as a confidential asset owned by the caller, with `admin`
+        /// managing its metadata and policy, instead of mirroring one from pallet-assets.
+        /// Holds `AssetDeposit` from the caller until the asset is removed.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::create_confidential_asset())]
+        pub fn create_confidential_asset(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            admin: T::AccountId,
+            name: BoundedVec<u8, T::StringLimit>,
+            symbol: BoundedVec<u8, T::StringLimit>,
+            decimals: u8,
+            contract_uri: BoundedVec<u8, T::StringLimit>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            ensure!(!Asset::<T>::contains_key(asset), Error::<T>::AssetExists);
+            let deposit = T::AssetDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::hold(&HoldReason::AssetDeposit.into(), &owner, deposit)?;
+            }
+            Asset::<T>::insert(
+                asset,
+                AssetDetails {
+                    owner: owner.clone(),
+                    admin: admin.clone(),
+                    deposit,
+                },
+            );
+            Metadata::<T>::insert(
+                asset,
+                ConfidentialAssetMetadata {
+                    name,
+                    symbol,
+                    decimals,
+                    contract_uri,
+                },
+            );
+            Self::deposit_event(Event::AssetCreated {
+                asset,
+                owner,
+                admin,
+            });
+            Ok(())
+        }
+
+        /// Replace a registered asset's metadata. Owner or admin only.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_asset_metadata())]
+        pub fn set_asset_metadata(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            name: BoundedVec<u8, T::StringLimit>,
+            symbol: BoundedVec<u8, T::StringLimit>,
+            decimals: u8,
+            contract_uri: BoundedVec<u8, T::StringLimit>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_asset_admin(asset, &who)?;
+            Metadata::<T>::insert(
+                asset,
+                ConfidentialAssetMetadata {
+                    name,
+                    symbol,
+                    decimals,
+                    contract_uri,
+                },
+            );
+            Self::deposit_event(Event::AssetMetadataSet { asset });
+            Ok(())
+        }
+
+        /// Hand a registered asset's admin role to `admin`. Owner only.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_asset_admin())]
+        pub fn set_asset_admin(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            admin: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Asset::<T>::try_mutate(asset, |details| -> DispatchResult {
+                let details = details.as_mut().ok_or(Error::<T>::UnknownAsset)?;
+                ensure!(details.owner == who, Error::<T>::NotAuthorized);
+                details.admin = admin.clone();
+                Ok(())
+            })?;
+            Self::deposit_event(Event::AssetAdminSet { asset, admin });
+            Ok(())
+        }
+
+        /// Set a registered asset's dust limits. Owner or admin only.
+        ///
+        /// `min_transfer_commitment` is only enforced if the runtime's verifier reads
+        /// it through [`WithTransferFloor`]; transfers must then carry a floor proof.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::set_asset_policy())]
+        pub fn set_asset_policy(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            policy: AssetPolicy<T::Balance>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_asset_admin(asset, &who)?;
+            AssetPolicies::<T>::insert(asset, policy.clone());
+            Self::deposit_event(Event::AssetPolicySet { asset, policy });
+            Ok(())
+        }
+
+        /// Transfer to `to` at block `execute_at`. The amount moves into escrow now
+        /// and `ScheduledTransferDeposit` is held from the caller until the transfer
+        /// executes or is cancelled.
+        ///
+        /// At or after `execute_at` anyone may release it with
+        /// `execute_scheduled_transfer`; before then the caller may take it back with
+        /// `cancel_scheduled_transfer`. Both take an escrow transfer proof, as the
+        /// escrow account's balance changes between scheduling and release.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::confidential_transfer_scheduled())]
+        #[transactional]
+        pub fn confidential_transfer_scheduled(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            to: T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            input_proof: InputProof,
+            execute_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
+            ensure!(
+                execute_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ScheduleInPast
+            );
+            let mut ctx = Self::acl_ctx(
+                asset,
+                &from,
+                Some(&from),
+                Some(&to),
+                Some(encrypted_amount),
+                &input_proof,
+            );
+            ctx.data = AclOpData::Scheduled {
+                execute_at: execute_at.unique_saturated_into(),
+            };
+            T::Acl::authorize(Op::Transfer, &ctx)?;
+            let escrow_id = T::Escrow::escrow_lock(
+                asset,
+                &from,
+                encrypted_amount,
+                SCHEDULED_TRANSFER_PURPOSE,
+                input_proof,
+            )?;
+            let deposit = T::ScheduledTransferDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::hold(&HoldReason::ScheduledTransfer.into(), &from, deposit)?;
+            }
+
+            let id = NextScheduledTransferId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            ScheduledTransfers::<T>::insert(
+                id,
+                ScheduledTransfer {
+                    asset,
+                    from: from.clone(),
+                    to: to.clone(),
+                    encrypted_amount,
+                    execute_at,
+                    deposit,
+                    escrow_id,
+                },
+            );
+            Self::deposit_event(Event::TransferScheduled {
+                id,
+                asset,
+                from,
+                to,
+                encrypted_amount,
+                execute_at,
+            });
+            Ok(())
+        }
+
+        /// Release a due scheduled transfer from escrow into the receiver's pending
+        /// balance. Callable by anyone with the escrow's transfer proof.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::execute_scheduled_transfer())]
+        #[transactional]
+        pub fn execute_scheduled_transfer(
+            origin: OriginFor<T>,
+            id: u64,
+            release_proof: InputProof,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let scheduled =
+                ScheduledTransfers::<T>::take(id).ok_or(Error::<T>::UnknownScheduledTransfer)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= scheduled.execute_at,
+                Error::<T>::NotDue
+            );
+            T::Escrow::escrow_release(
+                scheduled.escrow_id,
+                &scheduled.to,
+                scheduled.encrypted_amount,
+                release_proof,
+            )?;
+            Self::release_scheduled_deposit(&scheduled);
+            T::Callbacks::on_transfer(
+                scheduled.asset,
+                &scheduled.from,
+                &scheduled.to,
+                &scheduled.encrypted_amount,
+            );
+            Self::deposit_event(Event::ScheduledTransferExecuted { id });
+            Ok(())
+        }
+
+        /// Refund a scheduled transfer to its sender before it is due. Sender only.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_transfer())]
+        #[transactional]
+        pub fn cancel_scheduled_transfer(
+            origin: OriginFor<T>,
+            id: u64,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let scheduled =
+                ScheduledTransfers::<T>::take(id).ok_or(Error::<T>::UnknownScheduledTransfer)?;
+            ensure!(scheduled.from == who, Error::<T>::NotAuthorized);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < scheduled.execute_at,
+                Error::<T>::AlreadyDue
+            );
+            T::Escrow::escrow_refund(
+                scheduled.escrow_id,
+                scheduled.encrypted_amount,
+                refund_proof,
+            )?;
+            Self::release_scheduled_deposit(&scheduled);
+            Self::deposit_event(Event::ScheduledTransferCancelled { id });
+            Ok(())
+        }
+
+        /// Mint confidential supply of a registered asset into `to`'s pending balance,
+        /// with no public counterpart burned. Owner or admin only, checked with the ACL
+        /// as `Op::Mint`.
+        ///
+        /// For natively confidential assets; `deposit` is the way in for assets that
+        /// also exist publicly.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::confidential_mint())]
+        pub fn confidential_mint(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            to: T::AccountId,
+            mint_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
+            Self::ensure_asset_admin(asset, &who)?;
+            T::Acl::authorize(
+                Op::Mint,
+                &Self::acl_ctx(asset, &who, None, Some(&to), None, &mint_proof),
+            )?;
+            let encrypted_amount = T::Backend::mint_encrypted(asset, &to, mint_proof)?;
+            Self::deposit_event(Event::ConfidentialMinted {
+                asset,
+                to,
+                encrypted_amount,
+            });
+            Ok(())
+        }
+
+        /// Seize `who`'s whole available balance of an audited asset and pay it out
+        /// publicly to `beneficiary`. `ForceOrigin` only, e.g. to execute a court order.
+        ///
+        /// `encrypted_balance` is the balance under the asset's auditor key, which the
+        /// auditor reconstructs from the audit log, and `proof`, made with the auditor's
+        /// secret key and on-chain data alone, shows it hides the same amount as the
+        /// stored balance and discloses that amount. The backend must have followed the
+        /// balance's blinding since the auditor key was set. Pending transfers are
+        /// not seized; freeze the holder first so they stay unclaimed.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::force_unshield())]
+        #[transactional]
+        pub fn force_unshield(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            who: T::AccountId,
+            beneficiary: T::AccountId,
+            encrypted_balance: EncryptedAmount,
+            proof: InputProof,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let amount = T::Backend::seize_balance(asset, &who, encrypted_balance, proof)?;
+            T::Ramp::mint(&beneficiary, &asset, amount).map_err(|_| Error::<T>::RampFailed)?;
+
+            Self::deposit_event(Event::ForceUnshielded {
+                asset,
+                who,
+                beneficiary,
+                encrypted_balance,
+                amount,
             });
             Ok(())
         }
 
+        /// Publicly reveal the plaintext of a ciphertext under the caller's key.
+        ///
+        /// `proof` shows the ciphertext decrypts to the disclosed value (see the
+        /// backend's verifier for its layout); no secret key material is revealed.
         #[pallet::call_index(4)]
         #[pallet::weight(T::WeightInfo::disclose_amount())]
         pub fn disclose_amount(
             origin: OriginFor<T>,
             asset: T::AssetId,
             encrypted_amount: EncryptedAmount,
+            proof: InputProof,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let amount = T::Backend::disclose_amount(asset, &encrypted_amount, &who)
-                .map_err(|_| Error::<T>::BackendError)?;
+            let amount = T::Backend::disclose_amount(asset, &encrypted_amount, &who, proof)?;
             Self::deposit_event(Event::AmountDisclosed {
                 asset,
                 encrypted_amount,
@@ -262,26 +1242,41 @@ pub mod pallet {
         /// balances available to transfer. TODO: link to longer explanation
         // TODO: consider exposing confidential_claim_and_transfer aka pallet_zkhe::accept_pending_and_transfer
         #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::confidential_transfer())] // TODO
+        #[pallet::weight(T::WeightInfo::confidential_claim(claimed_utxos(input_proof)))]
         pub fn confidential_claim(
             origin: OriginFor<T>,
             asset: T::AssetId,
             input_proof: InputProof,
         ) -> DispatchResult {
             let from = ensure_signed(origin)?;
-            let claimed = T::Backend::claim_encrypted(asset, &from, input_proof)
-                .map_err(|_| Error::<T>::BackendError)?;
+            Self::ensure_not_paused(&asset)?;
+            let mut ctx = Self::acl_ctx(asset, &from, None, Some(&from), None, &input_proof);
+            ctx.data = AclOpData::Claim {
+                count: claimed_utxos(&input_proof),
+            };
+            T::Acl::authorize(Op::AcceptPending, &ctx)?;
+            let old_available = T::Backend::balance_of(asset, &from);
+            let old_pending = T::Backend::pending_balance_of(asset, &from);
+            let claimed = T::Backend::claim_encrypted(asset, &from, input_proof)?;
+            T::Callbacks::on_claim(asset, &from, &claimed);
             Self::deposit_event(Event::ConfidentialClaimed {
                 asset,
-                who: from,
                 encrypted_amount: claimed,
+                old_available,
+                new_available: T::Backend::balance_of(asset, &from),
+                old_pending,
+                new_pending: T::Backend::pending_balance_of(asset, &from),
+                proof_hash: ctx.proof_hash.unwrap_or_default(),
+                who: from,
             });
             Ok(())
         }
 
         // ---------- Operator + ACL permutations for all extrinsics ----------
 
-        /// Operator and/or ACL-driven confidential transfer
+        /// Confidential transfer out of `from`'s balance by `from` itself or by an
+        /// operator `from` approved for `asset` (see `Config::Operators`), checked with
+        /// the ACL as `Op::TransferFrom`.
         // TODO: impl for deposit, withraw, claim(accept_pending), etc
         #[pallet::call_index(6)]
         #[pallet::weight(T::WeightInfo::confidential_transfer_from())]
@@ -294,28 +1289,19 @@ pub mod pallet {
             input_proof: InputProof,
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
+            Self::ensure_not_paused(&asset)?;
             Self::ensure_is_self_or_operator(&from, &asset, &caller)?;
-            T::Acl::authorize(
-                Op::Transfer,
-                &AclCtx {
-                    amount: Default::default(),
-                    asset,
-                    caller: caller.clone(),
-                    owner: Some(from.clone()),
-                    counterparty: Some(to.clone()),
-                    opaque: sp_std::vec![],
-                },
-            )?;
-            let transferred =
-                T::Backend::transfer_encrypted(asset, &from, &to, encrypted_amount, input_proof)
-                    .map_err(|_| Error::<T>::BackendError)?;
-            Self::deposit_event(Event::ConfidentialTransfer {
+            let mut ctx = Self::acl_ctx(
                 asset,
-                from: from.clone(),
-                to: to.clone(),
-                encrypted_amount: transferred.clone(),
-            });
-            Ok(())
+                &caller,
+                Some(&from),
+                Some(&to),
+                Some(encrypted_amount),
+                &input_proof,
+            );
+            ctx.owner = Some(from.clone());
+            T::Acl::authorize(Op::TransferFrom, &ctx)?;
+            Self::do_transfer(asset, &from, &to, encrypted_amount, input_proof, None)
         }
 
         /// ACL-driven confidential transfer
@@ -331,31 +1317,124 @@ pub mod pallet {
             input_proof: InputProof,
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
-            T::Acl::authorize(
-                Op::Transfer,
-                &AclCtx {
-                    amount: Default::default(),
-                    asset,
-                    caller: caller.clone(),
-                    owner: Some(from.clone()),
-                    counterparty: Some(to.clone()),
-                    opaque: sp_std::vec![],
-                },
-            )?;
+            Self::ensure_not_paused(&asset)?;
+            let mut ctx = Self::acl_ctx(
+                asset,
+                &caller,
+                Some(&from),
+                Some(&to),
+                Some(encrypted_amount),
+                &input_proof,
+            );
+            ctx.owner = Some(from.clone());
+            T::Acl::authorize(Op::Transfer, &ctx)?;
+            Self::do_transfer(asset, &from, &to, encrypted_amount, input_proof, None)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Move `encrypted_amount` of `asset` from `from` to `to` through the backend,
+        /// hand `data` to the receiver if given, and emit `ConfidentialTransfer`.
+        pub(crate) fn do_transfer(
+            asset: T::AssetId,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            input_proof: InputProof,
+            data: Option<&CallbackData>,
+        ) -> DispatchResult {
+            let proof_hash = BlakeTwo256::hash(&input_proof).into();
+            let from_old_available = T::Backend::balance_of(asset, from);
+            let to_old_pending = T::Backend::pending_balance_of(asset, to);
             let transferred =
-                T::Backend::transfer_encrypted(asset, &from, &to, encrypted_amount, input_proof)
-                    .map_err(|_| Error::<T>::BackendError)?;
+                T::Backend::transfer_encrypted(asset, from, to, encrypted_amount, input_proof)?;
+            if let Some(data) = data {
+                ensure!(
+                    T::OnTransferReceived::on_confidential_transfer_received(
+                        asset,
+                        from,
+                        from,
+                        to,
+                        &transferred,
+                        data
+                    ),
+                    Error::<T>::ReceiverRejected
+                );
+            }
+            T::Callbacks::on_transfer(asset, from, to, &transferred);
             Self::deposit_event(Event::ConfidentialTransfer {
                 asset,
                 from: from.clone(),
                 to: to.clone(),
-                encrypted_amount: transferred.clone(),
+                encrypted_amount: transferred,
+                pending_id: T::Backend::last_pending_id(asset, to),
+                from_old_available,
+                from_new_available: T::Backend::balance_of(asset, from),
+                to_old_pending,
+                to_new_pending: T::Backend::pending_balance_of(asset, to),
+                proof_hash,
             });
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
+        /// ACL context for `caller` moving `delta` of `asset` from `from` to `to` with
+        /// `proof`, carrying both accounts' available balance commitments before the
+        /// move. Callers fill in `amount`, `owner` and `data` where they apply.
+        pub(crate) fn acl_ctx(
+            asset: T::AssetId,
+            caller: &T::AccountId,
+            from: Option<&T::AccountId>,
+            to: Option<&T::AccountId>,
+            delta: Option<EncryptedAmount>,
+            proof: &[u8],
+        ) -> AclCtx<T::Balance, T::AccountId, T::AssetId> {
+            AclCtx {
+                amount: Default::default(),
+                asset,
+                caller: caller.clone(),
+                owner: None,
+                counterparty: to.filter(|to| *to != caller).cloned(),
+                proof_hash: Some(BlakeTwo256::hash(proof).into()),
+                delta,
+                from_commitment: from.map(|who| T::Backend::balance_of(asset, who)),
+                to_commitment: to.map(|who| T::Backend::balance_of(asset, who)),
+                data: AclOpData::None,
+                opaque: sp_std::vec![],
+            }
+        }
+
+        /// Details of registered `asset` if `who` is its owner or admin.
+        pub(crate) fn ensure_asset_admin(
+            asset: T::AssetId,
+            who: &T::AccountId,
+        ) -> Result<AssetDetails<T::AccountId, DepositBalanceOf<T>>, DispatchError> {
+            let details = Asset::<T>::get(asset).ok_or(Error::<T>::UnknownAsset)?;
+            ensure!(
+                *who == details.owner || *who == details.admin,
+                Error::<T>::NotAuthorized
+            );
+            Ok(details)
+        }
+
+        fn release_scheduled_deposit(
+            scheduled: &ScheduledTransfer<
+                T::AccountId,
+                T::AssetId,
+                BlockNumberFor<T>,
+                DepositBalanceOf<T>,
+            >,
+        ) {
+            if !scheduled.deposit.is_zero() {
+                // Never blocks the transfer: whatever is still held is returned
+                let _ = T::Currency::release(
+                    &HoldReason::ScheduledTransfer.into(),
+                    &scheduled.from,
+                    scheduled.deposit,
+                    Precision::BestEffort,
+                );
+            }
+        }
+
         #[inline]
         fn ensure_is_self_or_operator(
             holder: &T::AccountId,
@@ -372,5 +1451,71 @@ pub mod pallet {
                 Err(Error::<T>::NotAuthorized)
             }
         }
+
+        #[inline]
+        fn ensure_not_paused(asset: &T::AssetId) -> Result<(), Error<T>> {
+            ensure!(!T::Backend::is_paused(*asset), Error::<T>::AssetPaused);
+            Ok(())
+        }
+    }
+}
+
+/// Number of UTXOs a claim proof (`id || count:u16 || ids || envelope`) consumes, read
+/// from its header so the declared weight scales with it; zero when too short.
+fn claimed_utxos(input_proof: &InputProof) -> u32 {
+    match input_proof.get(1..3) {
+        Some(&[lo, hi]) => u16::from_le_bytes([lo, hi]).into(),
+        _ => 0,
+    }
+}
+
+impl<T: Config> ProofCarrier for Call<T> {
+    fn proofs(&self) -> Vec<(ProofKind, &[u8])> {
+        match self {
+            Call::deposit { proof, .. } => sp_std::vec![(ProofKind::Mint, &proof[..])],
+            Call::confidential_mint { mint_proof, .. } => {
+                sp_std::vec![(ProofKind::Mint, &mint_proof[..])]
+            }
+            Call::withdraw { proof, .. } => sp_std::vec![(ProofKind::Burn, &proof[..])],
+            Call::confidential_burn { proof, .. } => {
+                sp_std::vec![(ProofKind::ConfidentialBurn, &proof[..])]
+            }
+            Call::close_confidential_account { proof, .. } => {
+                sp_std::vec![(ProofKind::ZeroBalance, &proof[..])]
+            }
+            Call::set_public_key { proof, .. } => {
+                sp_std::vec![(ProofKind::PubkeyOwnership, &proof[..])]
+            }
+            Call::confidential_transfer { input_proof, .. }
+            | Call::confidential_transfer_and_call { input_proof, .. }
+            | Call::confidential_transfer_from { input_proof, .. }
+            | Call::confidential_transfer_acl { input_proof, .. }
+            | Call::confidential_transfer_scheduled { input_proof, .. } => {
+                sp_std::vec![(ProofKind::Transfer, &input_proof[..])]
+            }
+            Call::execute_scheduled_transfer { release_proof, .. } => {
+                sp_std::vec![(ProofKind::Transfer, &release_proof[..])]
+            }
+            Call::cancel_scheduled_transfer { refund_proof, .. } => {
+                sp_std::vec![(ProofKind::Transfer, &refund_proof[..])]
+            }
+            Call::confidential_transfer_split { input_proof, .. } => {
+                sp_std::vec![(ProofKind::TransferSplit, &input_proof[..])]
+            }
+            Call::confidential_transfer_many { transfers, .. } => transfers
+                .iter()
+                .map(|(_, _, proof)| (ProofKind::Transfer, &proof[..]))
+                .collect(),
+            Call::disclose_amount { proof, .. } => {
+                sp_std::vec![(ProofKind::Disclosure, &proof[..])]
+            }
+            Call::force_unshield { proof, .. } => {
+                sp_std::vec![(ProofKind::Seizure, &proof[..])]
+            }
+            Call::confidential_claim { input_proof, .. } => {
+                sp_std::vec![(ProofKind::Claim, &input_proof[..])]
+            }
+            _ => Vec::new(),
+        }
     }
 }
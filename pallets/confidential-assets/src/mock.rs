@@ -1,9 +1,14 @@
 use crate::pallet as pallet_confidential_assets;
 use confidential_assets_primitives::{
-    ConfidentialBackend, EncryptedAmount, InputProof, NetworkIdProvider, PublicKeyBytes, Ramp,
-    ZkVerifier,
+    AclCtx, AclProvider, CallbackData, ConfidentialBackend, ConfidentialCallbacks, EncryptedAmount,
+    InputProof, NetworkIdProvider, OnConfidentialTransferReceived, Op, ProofKind, ProofSystem,
+    ProofSystemId, PublicKeyBytes, Ramp, VerifyError, ZkVerifier, seal_proof,
+};
+use frame_support::{
+    PalletId, construct_runtime, derive_impl, parameter_types,
+    traits::{ConstBool, ConstU32, ConstU64},
+    weights::{FixedFee, IdentityFee},
 };
-use frame_support::{construct_runtime, derive_impl};
 use sp_runtime::BuildStorage;
 
 pub type AccountId = u64;
@@ -32,10 +37,36 @@ impl NetworkIdProvider for MockNetworkId {
 pub struct AlwaysOkVerifier;
 
 impl ZkVerifier for AlwaysOkVerifier {
-    type Error = ();
     type NetworkIdProvider = MockNetworkId;
+
+    // Any key is accepted as owned
+    fn verify_pubkey_ownership(
+        _account: &[u8],
+        _pk: &[u8],
+        _proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        Ok(())
+    }
+
     // Disclose encrypted amount -> constant u64 (e.g., 123)
-    fn disclose(_asset: &[u8], _pk: &[u8], _cipher: &[u8]) -> Result<u64, ()> {
+    fn disclose(
+        _asset: &[u8],
+        _pk: &[u8],
+        _cipher: &[u8],
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
+        Ok(123)
+    }
+
+    // Seize -> the same constant
+    fn verify_seizure(
+        _asset: &[u8],
+        _auditor_pk: &[u8],
+        _avail: &[u8],
+        _blinding: &EncryptedAmount,
+        _ct: &EncryptedAmount,
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
         Ok(123)
     }
 
@@ -48,7 +79,7 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _delta_ct: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         Ok((vec![1u8; 32], vec![2u8; 32]))
     }
 
@@ -60,7 +91,7 @@ impl ZkVerifier for AlwaysOkVerifier {
         _pending_old: &[u8],
         _commits: &[[u8; 32]],
         _envelope: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         // Make pending_new zero so pallet removes PendingBalanceCommit on accept
         Ok((vec![3u8; 32], vec![0u8; 32]))
     }
@@ -72,8 +103,13 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _total_old: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), ()> {
-        Ok((vec![10u8; 32], vec![11u8; 32], [5u8; 64]))
+    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), VerifyError> {
+        // A real point: the pallet records what the mint added to the pending balance
+        Ok((
+            vec![14u8; 32],
+            vec![11u8; 32],
+            EncryptedAmount::new([5u8; 64]),
+        ))
     }
 
     // from_new_available, total_new, disclosed_u64
@@ -84,9 +120,54 @@ impl ZkVerifier for AlwaysOkVerifier {
         _total_old: &[u8],
         _amount_ct: &EncryptedAmount,
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, u64), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>, u64), VerifyError> {
         Ok((vec![20u8; 32], vec![21u8; 32], 42))
     }
+
+    fn verify_confidential_burn(
+        _asset: &[u8],
+        _from_pk: &PublicKeyBytes,
+        _from_old_avail: &[u8],
+        _total_old: &[u8],
+        _amount_ct: &EncryptedAmount,
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        Ok((vec![22u8; 32], vec![23u8; 32]))
+    }
+
+    // from_new_available, and each output's masked half standing in for its commitment
+    fn verify_transfer_split(
+        _asset: &[u8],
+        _from_pk: &[u8],
+        _from_old_avail: &[u8],
+        _total: &EncryptedAmount,
+        outputs: &[(PublicKeyBytes, EncryptedAmount)],
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<[u8; 32]>), VerifyError> {
+        Ok((
+            vec![24u8; 32],
+            outputs.iter().map(|(_, ct)| ct.masked()).collect(),
+        ))
+    }
+
+    fn verify_zero_balance(
+        _asset: &[u8],
+        _who_pk: &[u8],
+        _avail: &[u8],
+        _pending: &[u8],
+        _proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        Ok(())
+    }
+}
+
+// Stands in for the ZK-ElGamal proof system and accepts any body length
+impl ProofSystem for AlwaysOkVerifier {
+    const ID: ProofSystemId = 0;
+
+    fn proof_len(_kind: ProofKind) -> Option<(usize, usize)> {
+        Some((0, usize::MAX))
+    }
 }
 
 pub struct NoRamp;
@@ -109,9 +190,117 @@ impl Ramp<AccountId, AssetId, Balance> for NoRamp {
     }
 }
 
+// --- Callbacks that record what fired -----------------------------------------
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Callback {
+    Shield(AssetId, AccountId, Balance, EncryptedAmount),
+    Unshield(AssetId, AccountId, Balance, EncryptedAmount),
+    Transfer(AssetId, AccountId, AccountId, EncryptedAmount),
+    Claim(AssetId, AccountId, EncryptedAmount),
+    Received(AssetId, AccountId, AccountId, EncryptedAmount, Vec<u8>),
+}
+
+thread_local! {
+    pub static CALLBACKS: core::cell::RefCell<Vec<Callback>> = const { core::cell::RefCell::new(Vec::new()) };
+}
+
+pub struct RecordingCallbacks;
+impl ConfidentialCallbacks<AccountId, AssetId, Balance> for RecordingCallbacks {
+    fn on_shield(asset: AssetId, who: &AccountId, amount: Balance, ct: &EncryptedAmount) {
+        CALLBACKS.with(|c| {
+            c.borrow_mut()
+                .push(Callback::Shield(asset, *who, amount, *ct))
+        });
+    }
+    fn on_unshield(asset: AssetId, who: &AccountId, amount: Balance, ct: &EncryptedAmount) {
+        CALLBACKS.with(|c| {
+            c.borrow_mut()
+                .push(Callback::Unshield(asset, *who, amount, *ct))
+        });
+    }
+    fn on_transfer(asset: AssetId, from: &AccountId, to: &AccountId, ct: &EncryptedAmount) {
+        CALLBACKS.with(|c| {
+            c.borrow_mut()
+                .push(Callback::Transfer(asset, *from, *to, *ct))
+        });
+    }
+    fn on_claim(asset: AssetId, who: &AccountId, ct: &EncryptedAmount) {
+        CALLBACKS.with(|c| c.borrow_mut().push(Callback::Claim(asset, *who, *ct)));
+    }
+}
+
+// Rejects transfers whose data is `b"reject"`.
+impl OnConfidentialTransferReceived<AccountId, AssetId> for RecordingCallbacks {
+    fn on_confidential_transfer_received(
+        asset: AssetId,
+        _operator: &AccountId,
+        from: &AccountId,
+        to: &AccountId,
+        ct: &EncryptedAmount,
+        data: &CallbackData,
+    ) -> bool {
+        CALLBACKS.with(|c| {
+            c.borrow_mut()
+                .push(Callback::Received(asset, *from, *to, *ct, data.to_vec()))
+        });
+        data.as_slice() != b"reject"
+    }
+}
+
+// --- ACL that records what it was asked -----------------------------------------
+
+thread_local! {
+    pub static ACL_CHECKS: core::cell::RefCell<Vec<(Op, AclCtx<Balance, AccountId, AssetId>)>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+}
+
+/// Allows everything, recording each check.
+pub struct RecordingAcl;
+impl AclProvider<AccountId, AssetId, Balance> for RecordingAcl {
+    fn authorize(
+        op: Op,
+        ctx: &AclCtx<Balance, AccountId, AssetId>,
+    ) -> Result<(), sp_runtime::DispatchError> {
+        ACL_CHECKS.with(|c| c.borrow_mut().push((op, ctx.clone())));
+        Ok(())
+    }
+}
+
+/// ACL checks made since the last call, oldest first.
+pub fn take_acl_checks() -> Vec<(Op, AclCtx<Balance, AccountId, AssetId>)> {
+    ACL_CHECKS.with(|c| core::mem::take(&mut *c.borrow_mut()))
+}
+
+/// Callbacks fired since the last call, oldest first.
+pub fn take_callbacks() -> Vec<Callback> {
+    CALLBACKS.with(|c| core::mem::take(&mut *c.borrow_mut()))
+}
+
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Runtime {
     type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
+}
+
+// Fee = 2 + length: one unit for the base weight, one for the call weight.
+#[derive_impl(pallet_transaction_payment::config_preludes::TestDefaultConfig)]
+impl pallet_transaction_payment::Config for Runtime {
+    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
+    type WeightToFee = FixedFee<1, Balance>;
+    type LengthToFee = IdentityFee<Balance>;
+}
+
+impl pallet_operators::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = AssetId;
+    type MaxOperatorsPerAsset = ConstU32<16>;
+    type WeightInfo = ();
 }
 
 impl pallet_zkhe::Config for Runtime {
@@ -119,6 +308,31 @@ impl pallet_zkhe::Config for Runtime {
     type AssetId = AssetId;
     type Balance = Balance;
     type Verifier = AlwaysOkVerifier;
+    type MaxClaimUtxos = ConstU32<16>;
+    type PendingTtl = ConstU64<100>;
+    type MaxPendingPerAccount = ConstU32<16>;
+    type PendingDeposit = ConstU64<0>;
+    type AccountDeposit = ConstU64<0>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RequireAssetConfig = ConstBool<false>;
+    type MaxAuditHistory = ConstU32<4>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const EscrowPalletId: PalletId = PalletId(*b"CaEscrow");
+}
+impl pallet_confidential_escrow::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = AssetId;
+    type Balance = Balance;
+    type Backend = Zkhe;
+    type Ramp = NoRamp;
+    type PalletId = EscrowPalletId;
+    type MaxApprovers = ConstU32<4>;
+    type ApprovalTtl = ConstU64<100>;
+    type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 impl pallet_confidential_assets::Config for Runtime {
@@ -128,21 +342,40 @@ impl pallet_confidential_assets::Config for Runtime {
     type Backend = Zkhe;
     type Ramp = NoRamp;
     type AssetMetadata = ();
-    type Acl = ();
-    type Operators = ();
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetDeposit = ConstU64<10>;
+    type StringLimit = ConstU32<64>;
+    type Callbacks = RecordingCallbacks;
+    type OnTransferReceived = RecordingCallbacks;
+    type FeeAsset = ConstU32<ASSET>;
+    type AutoClaim = ();
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type Acl = RecordingAcl;
+    type Operators = Operators;
+    type Escrow = ConfidentialEscrow;
+    type ScheduledTransferDeposit = ConstU64<5>;
+    type MaxTransfersPerBatch = ConstU32<4>;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = Zkhe;
 }
 
 construct_runtime!(
     pub enum Runtime {
         System: frame_system,
+        Balances: pallet_balances,
+        TransactionPayment: pallet_transaction_payment,
+        Operators: pallet_operators,
         Zkhe: pallet_zkhe,
+        ConfidentialEscrow: pallet_confidential_escrow,
         ConfidentialAssets: pallet_confidential_assets,
     }
 );
 
 // Build a fresh externalities for each test.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    take_callbacks();
     let t = frame_system::GenesisConfig::<Runtime>::default()
         .build_storage()
         .unwrap();
@@ -155,12 +388,19 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 // Handy helpers
 pub fn set_pk(who: AccountId) {
     // Non-empty fake pk
-    Zkhe::set_public_key(&who, &[7u8; 64].to_vec().try_into().expect("bounded vec")).unwrap();
+    Zkhe::set_public_key(
+        &who,
+        &[7u8; 64].to_vec().try_into().expect("bounded vec"),
+        proof(&[]),
+    )
+    .unwrap();
 }
 
-// Construct InputProof from raw bytes using TryFrom<Vec<u8>>
+// Construct InputProof from raw bytes, enveloped for the mock proof system
 pub fn proof(bytes: &[u8]) -> InputProof {
-    bytes.to_vec().try_into().expect("bounded vec")
+    seal_proof(AlwaysOkVerifier::ID, bytes)
+        .try_into()
+        .expect("bounded vec")
 }
 
 // Accept envelope encoding: u16 count || ids (u64 LE) * count || rest (opaque)
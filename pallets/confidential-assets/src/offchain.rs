@@ -0,0 +1,92 @@
+//! Offchain worker that claims pending transfers for accounts whose keys this node holds.
+//!
+//! The node operator lists `(signing key, asset)` pairs under [`AUTO_CLAIM_KEY`] in
+//! persistent offchain storage (e.g. with `offchain_localStorageSet`) and inserts each
+//! signing key into the keystore under [`KEY_TYPE`]. Every block, [`AutoClaim`] asks the
+//! runtime's [`ClaimProver`] for a claim of each account's pending transfers and submits
+//! it as `confidential_claim`, signed by that key. The prover holds the accounts' ElGamal
+//! secret keys; the pallet never sees them.
+//!
+//! No prover ships with this crate. Proving needs the secret key and fresh randomness,
+//! which the runtime cannot hold, so the runtime must reach the node's prover through a
+//! host function the integrator adds to their node. With the `()` prover, [`AutoClaim`]
+//! finds nothing to prove and submits nothing.
+
+use super::*;
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+use sp_runtime::{KeyTypeId, offchain::storage::StorageValueRef, traits::IdentifyAccount};
+
+/// Key type of the keys that sign auto-claims.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"ccla");
+
+/// Persistent offchain storage key holding the SCALE-encoded `Vec<(Public, AssetId)>`
+/// of accounts to claim for.
+pub const AUTO_CLAIM_KEY: &[u8] = b"confidential-assets::auto-claim";
+
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_runtime::{
+        MultiSignature, MultiSigner,
+        app_crypto::{app_crypto, sr25519},
+    };
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Signs auto-claims with an sr25519 key of [`KEY_TYPE`].
+    pub struct ClaimAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for ClaimAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sr25519::Signature;
+        type GenericPublic = sr25519::Public;
+    }
+}
+
+/// Claims the pending transfers of every account listed under [`AUTO_CLAIM_KEY`] with a
+/// claim from `P`, signed with crypto `C`. Set it as `Config::AutoClaim`.
+///
+/// `P` must be a real prover supplied by the runtime; see the module docs.
+///
+/// A claim is resubmitted every block until it lands; the pool keeps one per nonce.
+pub struct AutoClaim<T, P, C>(PhantomData<(T, P, C)>);
+
+impl<T, P, C> OffchainWorker<BlockNumberFor<T>> for AutoClaim<T, P, C>
+where
+    T: Config + CreateSignedTransaction<Call<T>>,
+    P: ClaimProver<T::AccountId, T::AssetId>,
+    C: AppCrypto<T::Public, T::Signature>,
+{
+    fn offchain_worker(_n: BlockNumberFor<T>) {
+        let Ok(Some(accounts)) =
+            StorageValueRef::persistent(AUTO_CLAIM_KEY).get::<Vec<(T::Public, T::AssetId)>>()
+        else {
+            return;
+        };
+        for (public, asset) in accounts {
+            let who = public.clone().into_account();
+            let Some(input_proof) = Pallet::<T>::prepare_claim::<P>(asset, &who) else {
+                continue;
+            };
+            let _ = Signer::<T, C>::all_accounts()
+                .with_filter(sp_std::vec![public])
+                .send_signed_transaction(|_| Call::confidential_claim {
+                    asset,
+                    input_proof: input_proof.clone(),
+                });
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// A claim of `who`'s pending transfers of `asset` from `P`, or `None` if nothing
+    /// is pending or `P` cannot prove for `who`.
+    pub fn prepare_claim<P: ClaimProver<T::AccountId, T::AssetId>>(
+        asset: T::AssetId,
+        who: &T::AccountId,
+    ) -> Option<InputProof> {
+        let pending = T::Backend::pending_transfers(asset, who);
+        if pending.is_empty() {
+            return None;
+        }
+        P::prove_claim(asset, who, &pending)
+    }
+}
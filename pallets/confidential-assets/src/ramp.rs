@@ -0,0 +1,69 @@
+//! [`Ramp`] adapters for public balances held outside `pallet-balances`.
+
+use core::marker::PhantomData;
+
+use confidential_assets_primitives::Ramp;
+use frame_support::traits::{
+    fungibles,
+    tokens::{Fortitude, Precision, Preservation},
+};
+use sp_runtime::{DispatchError, TokenError, traits::MaybeEquivalence};
+
+/// [`Ramp`] over a `fungibles` pallet whose asset ids differ from the confidential ones,
+/// e.g. a `ForeignAssets` instance keyed by XCM `Location`, so assets reserve-transferred
+/// in can be shielded as soon as they arrive.
+///
+/// `Ids` maps the pallet's asset id to the confidential one; confidential ids it cannot
+/// map back fail with [`TokenError::UnknownAsset`]. Runtimes with several public asset
+/// pallets route the ids `Ids` maps here and the rest elsewhere.
+pub struct FungiblesRamp<Assets, Ids>(PhantomData<(Assets, Ids)>);
+
+impl<Assets, Ids> FungiblesRamp<Assets, Ids> {
+    fn id<AccountId, AssetId>(asset: &AssetId) -> Result<Assets::AssetId, DispatchError>
+    where
+        Assets: fungibles::Inspect<AccountId>,
+        Ids: MaybeEquivalence<Assets::AssetId, AssetId>,
+    {
+        Ids::convert_back(asset).ok_or(TokenError::UnknownAsset.into())
+    }
+}
+
+impl<AccountId, AssetId, Assets, Ids> Ramp<AccountId, AssetId, Assets::Balance>
+    for FungiblesRamp<Assets, Ids>
+where
+    Assets: fungibles::Mutate<AccountId>,
+    Ids: MaybeEquivalence<Assets::AssetId, AssetId>,
+{
+    type Error = DispatchError;
+
+    fn transfer_from(
+        from: &AccountId,
+        to: &AccountId,
+        asset: AssetId,
+        amount: Assets::Balance,
+    ) -> Result<(), Self::Error> {
+        let id = Self::id::<AccountId, _>(&asset)?;
+        Assets::transfer(id, from, to, amount, Preservation::Expendable)?;
+        Ok(())
+    }
+
+    fn burn(from: &AccountId, asset: &AssetId, amount: Assets::Balance) -> Result<(), Self::Error> {
+        let id = Self::id::<AccountId, _>(asset)?;
+        // Exact, so a short balance cannot be shielded for more than it holds
+        Assets::burn_from(
+            id,
+            from,
+            amount,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        )?;
+        Ok(())
+    }
+
+    fn mint(to: &AccountId, asset: &AssetId, amount: Assets::Balance) -> Result<(), Self::Error> {
+        let id = Self::id::<AccountId, _>(asset)?;
+        Assets::mint_into(id, to, amount)?;
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use proptest::prelude::*;
 
 // Small helpers
 fn ct(x: u8) -> EncryptedAmount {
-    [x; 64]
+    EncryptedAmount::new([x; 64])
 }
 fn last_event() -> RuntimeEvent {
     frame_system::Pallet::<Runtime>::events()
@@ -20,7 +20,8 @@ fn set_public_key_emits_event() {
         let pk: PublicKeyBytes = vec![9u8; 32].try_into().unwrap();
         assert_ok!(ConfidentialAssets::set_public_key(
             RuntimeOrigin::signed(ALICE),
-            pk
+            pk,
+            proof(&[1u8; 64])
         ));
 
         match last_event() {
@@ -55,11 +56,12 @@ fn deposit_calls_ramp_then_backend_and_emits_deposited() {
                 asset,
                 amount: ev_amount,
                 encrypted_amount,
+                ..
             }) => {
                 assert_eq!(who, ALICE);
                 assert_eq!(asset, ASSET);
                 assert_eq!(ev_amount, amount);
-                assert_eq!(encrypted_amount, [5u8; 64]);
+                assert_eq!(encrypted_amount, ct(5));
             }
             e => panic!("unexpected event: {e:?}"),
         }
@@ -67,7 +69,7 @@ fn deposit_calls_ramp_then_backend_and_emits_deposited() {
         // Read helpers surface backend state (mock returns constants).
         assert_eq!(
             ConfidentialAssets::confidential_total_supply(ASSET),
-            [11u8; 32]
+            Commitment::new([11u8; 32])
         );
     });
 }
@@ -94,6 +96,7 @@ fn withdraw_debits_confidential_then_mints_public_and_emits_withdrawn() {
                 asset,
                 encrypted_amount,
                 amount,
+                ..
             }) => {
                 assert_eq!(who, ALICE);
                 assert_eq!(asset, ASSET);
@@ -106,7 +109,80 @@ fn withdraw_debits_confidential_then_mints_public_and_emits_withdrawn() {
         // Helper reflects total supply commit from mock burn path.
         assert_eq!(
             ConfidentialAssets::confidential_total_supply(ASSET),
-            [21u8; 32]
+            Commitment::new([21u8; 32])
+        );
+    });
+}
+
+#[test]
+fn close_confidential_account_clears_balance_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        pallet_zkhe::AvailableBalanceCommit::<Runtime>::insert(
+            ASSET,
+            ALICE,
+            Commitment::new([9u8; 32]),
+        );
+
+        assert_ok!(ConfidentialAssets::close_confidential_account(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            proof(&[0u8; 128])
+        ));
+
+        match last_event() {
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ConfidentialAccountClosed {
+                asset,
+                who,
+            }) => {
+                assert_eq!(asset, ASSET);
+                assert_eq!(who, ALICE);
+            }
+            e => panic!("unexpected event: {e:?}"),
+        }
+
+        assert_eq!(
+            ConfidentialAssets::confidential_balance_of(ASSET, &ALICE),
+            Commitment::default()
+        );
+    });
+}
+
+#[test]
+fn confidential_burn_reduces_supply_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+
+        let enc = ct(77);
+
+        assert_ok!(ConfidentialAssets::confidential_burn(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            enc,
+            proof(&[9, 9])
+        ));
+
+        // Mock backend returns commits [22;32]/[23;32]; no amount is disclosed
+        match last_event() {
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ConfidentialBurned {
+                asset,
+                who,
+                encrypted_amount,
+            }) => {
+                assert_eq!(asset, ASSET);
+                assert_eq!(who, ALICE);
+                assert_eq!(encrypted_amount, enc);
+            }
+            e => panic!("unexpected event: {e:?}"),
+        }
+
+        assert_eq!(
+            ConfidentialAssets::confidential_balance_of(ASSET, &ALICE),
+            Commitment::new([22u8; 32])
+        );
+        assert_eq!(
+            ConfidentialAssets::confidential_total_supply(ASSET),
+            Commitment::new([23u8; 32])
         );
     });
 }
@@ -134,6 +210,7 @@ fn confidential_transfer_updates_via_backend_and_emits() {
                 from,
                 to,
                 encrypted_amount,
+                ..
             }) => {
                 assert_eq!(asset, ASSET);
                 assert_eq!(from, ALICE);
@@ -146,6 +223,24 @@ fn confidential_transfer_updates_via_backend_and_emits() {
     });
 }
 
+#[test]
+fn confidential_transfer_propagates_backend_error() {
+    new_test_ext().execute_with(|| {
+        // Receiver has no key: the backend's own error reaches the caller
+        // instead of being flattened into `BackendError`.
+        set_pk(ALICE);
+        let err = ConfidentialAssets::confidential_transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[7]),
+        )
+        .unwrap_err();
+        assert_eq!(err, pallet_zkhe::Error::<Runtime>::NoPublicKey.into());
+    });
+}
+
 #[test]
 fn disclose_amount_emits_event_with_mock_amount() {
     new_test_ext().execute_with(|| {
@@ -154,7 +249,8 @@ fn disclose_amount_emits_event_with_mock_amount() {
         assert_ok!(ConfidentialAssets::disclose_amount(
             RuntimeOrigin::signed(ALICE),
             ASSET,
-            ct(9)
+            ct(9),
+            proof(&[1, 2, 3])
         ));
 
         match last_event() {
@@ -178,13 +274,15 @@ fn disclose_amount_emits_event_with_mock_amount() {
 fn confidential_claim_consumes_backend_utxos_and_emits() {
     new_test_ext().execute_with(|| {
         use pallet_zkhe::{
-            AvailableBalanceCommit, NextPendingDepositId, PendingBalanceCommit, PendingDeposits,
+            AvailableBalanceCommit, NextPendingDepositId, PendingBalanceCommit,
+            PendingDepositCommit, PendingDeposits,
         };
 
         set_pk(ALICE);
 
         // Seed a pending UTXO for the caller in the ZkHE backend.
         PendingDeposits::<Runtime>::insert((ALICE, ASSET, 0), ct(55));
+        PendingDepositCommit::<Runtime>::insert((ALICE, ASSET, 0), Commitment::new([55u8; 32]));
         NextPendingDepositId::<Runtime>::insert(ALICE, ASSET, 1);
 
         // IDs=[0], opaque rest empty; mock verify_transfer_received => avail=[3;32], pending=[0;32]
@@ -199,7 +297,7 @@ fn confidential_claim_consumes_backend_utxos_and_emits() {
         // Storage effects in backend:
         assert_eq!(
             AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap(),
-            [3u8; 32]
+            Commitment::new([3u8; 32])
         );
         // pending_new == zero => pallet_zkhe removes PendingBalanceCommit
         assert!(PendingBalanceCommit::<Runtime>::get(ASSET, ALICE).is_none());
@@ -211,11 +309,12 @@ fn confidential_claim_consumes_backend_utxos_and_emits() {
                 asset,
                 who,
                 encrypted_amount,
+                ..
             }) => {
                 assert_eq!(asset, ASSET);
                 assert_eq!(who, ALICE);
                 // ZkHE::claim_encrypted returns [0;64] "no new UTXO" marker
-                assert_eq!(encrypted_amount, [0u8; 64]);
+                assert_eq!(encrypted_amount, EncryptedAmount::zero());
             }
             e => panic!("unexpected event: {e:?}"),
         }
@@ -230,7 +329,7 @@ fn confidential_transfer_from_succeeds_when_caller_is_owner() {
 
         let delta = ct(3);
 
-        // Caller == from => allowed even with Operators = ()
+        // Caller == from => allowed without any operator
         assert_ok!(ConfidentialAssets::confidential_transfer_from(
             RuntimeOrigin::signed(ALICE),
             ASSET,
@@ -246,6 +345,7 @@ fn confidential_transfer_from_succeeds_when_caller_is_owner() {
                 from,
                 to,
                 encrypted_amount,
+                ..
             }) => {
                 assert_eq!(asset, ASSET);
                 assert_eq!(from, ALICE);
@@ -263,7 +363,7 @@ fn confidential_transfer_from_fails_when_caller_not_owner_and_not_operator() {
         set_pk(ALICE);
         set_pk(BOB);
 
-        // CHARLIE is not ALICE and Operators=() returns false
+        // CHARLIE is not ALICE and ALICE approved no operator
         let err = ConfidentialAssets::confidential_transfer_from(
             RuntimeOrigin::signed(CHARLIE),
             ASSET,
@@ -279,12 +379,65 @@ fn confidential_transfer_from_fails_when_caller_not_owner_and_not_operator() {
 }
 
 #[test]
-fn confidential_transfer_acl_allows_any_caller_when_acl_is_unit() {
+fn confidential_transfer_from_accepts_an_operator_until_its_expiry() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        let transfer_from = |caller, asset| {
+            ConfidentialAssets::confidential_transfer_from(
+                RuntimeOrigin::signed(caller),
+                asset,
+                ALICE,
+                BOB,
+                ct(1),
+                proof(&[]),
+            )
+        };
+
+        assert_ok!(Operators::set_operator(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            CHARLIE,
+            5
+        ));
+        assert_ok!(transfer_from(CHARLIE, ASSET));
+        // The approval is per asset.
+        assert_eq!(
+            transfer_from(CHARLIE, ASSET + 1),
+            Err(pallet::Error::<Runtime>::NotAuthorized.into())
+        );
+
+        System::set_block_number(6);
+        assert_eq!(
+            transfer_from(CHARLIE, ASSET),
+            Err(pallet::Error::<Runtime>::NotAuthorized.into())
+        );
+
+        assert_ok!(Operators::set_operator(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            CHARLIE,
+            10
+        ));
+        assert_ok!(Operators::revoke_operator(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            CHARLIE
+        ));
+        assert_eq!(
+            transfer_from(CHARLIE, ASSET),
+            Err(pallet::Error::<Runtime>::NotAuthorized.into())
+        );
+    });
+}
+
+#[test]
+fn confidential_transfer_acl_allows_any_caller_the_acl_allows() {
     new_test_ext().execute_with(|| {
         set_pk(ALICE);
         set_pk(BOB);
 
-        // Acl = () authorizes all Ops; caller can be unrelated.
+        // The mock ACL authorizes all Ops; caller can be unrelated.
         assert_ok!(ConfidentialAssets::confidential_transfer_acl(
             RuntimeOrigin::signed(CHARLIE),
             ASSET,
@@ -300,6 +453,7 @@ fn confidential_transfer_acl_allows_any_caller_when_acl_is_unit() {
                 from,
                 to,
                 encrypted_amount,
+                ..
             }) => {
                 assert_eq!(asset, ASSET);
                 assert_eq!(from, ALICE);
@@ -311,6 +465,72 @@ fn confidential_transfer_acl_allows_any_caller_when_acl_is_unit() {
     });
 }
 
+#[test]
+fn paused_asset_is_refused_before_reaching_the_backend() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        assert_ok!(Zkhe::pause_asset(RuntimeOrigin::root(), ASSET));
+
+        let paused = pallet::Error::<Runtime>::AssetPaused.into();
+        assert_eq!(
+            ConfidentialAssets::deposit(RuntimeOrigin::signed(ALICE), ASSET, 1, proof(&[]))
+                .unwrap_err(),
+            paused
+        );
+        assert_eq!(
+            ConfidentialAssets::withdraw(RuntimeOrigin::signed(ALICE), ASSET, ct(1), proof(&[]))
+                .unwrap_err(),
+            paused
+        );
+        assert_eq!(
+            ConfidentialAssets::confidential_transfer(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(1),
+                proof(&[])
+            )
+            .unwrap_err(),
+            paused
+        );
+        assert_eq!(
+            ConfidentialAssets::confidential_claim(RuntimeOrigin::signed(BOB), ASSET, proof(&[]))
+                .unwrap_err(),
+            paused
+        );
+        assert_eq!(
+            ConfidentialAssets::confidential_transfer_many(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                vec![(BOB, ct(1), proof(&[]))].try_into().unwrap()
+            )
+            .unwrap_err(),
+            paused
+        );
+        assert_eq!(
+            ConfidentialAssets::confidential_transfer_split(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                ct(1),
+                vec![(BOB, ct(1))].try_into().unwrap(),
+                proof(&[])
+            )
+            .unwrap_err(),
+            paused
+        );
+
+        assert_ok!(Zkhe::unpause_asset(RuntimeOrigin::root(), ASSET));
+        assert_ok!(ConfidentialAssets::confidential_transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+    });
+}
+
 // ===================== PROPERTY TESTS =====================
 
 prop_compose! {
@@ -334,6 +554,920 @@ prop_compose! {
     }
 }
 
+#[test]
+fn confidential_fee_burns_the_disclosed_amount_instead_of_charging_publicly() {
+    use frame_support::{dispatch::DispatchInfo, traits::fungible::Mutate};
+    use pallet_transaction_payment::ChargeTransactionPayment;
+    use sp_runtime::{
+        traits::TransactionExtension,
+        transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+    };
+
+    type Ext = ChargeConfidentialFee<Runtime, ChargeTransactionPayment<Runtime>>;
+    let payment_err = Err(TransactionValidityError::Invalid(
+        InvalidTransaction::Payment,
+    ));
+
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let info = DispatchInfo::default();
+        let fee = ConfidentialFee {
+            encrypted_amount: ct(9),
+            proof: proof(&[]),
+        };
+        let before = ConfidentialAssets::confidential_balance_of(ASSET, &ALICE);
+
+        // The mock burn discloses 42, short of the fee for 100 bytes (2 + 100).
+        let too_long = Ext::with_fee(0.into(), fee.clone()).validate_only(
+            RuntimeOrigin::signed(ALICE),
+            &call,
+            &info,
+            100,
+            TransactionSource::External,
+            0,
+        );
+        assert_eq!(too_long.map(|_| ()), payment_err);
+        assert_eq!(
+            ConfidentialAssets::confidential_balance_of(ASSET, &ALICE),
+            before
+        );
+
+        // 42 covers the fee for 10 bytes; ALICE holds nothing publicly.
+        Ext::with_fee(0.into(), fee)
+            .test_run(RuntimeOrigin::signed(ALICE), &call, &info, 10, 0, |_| {
+                Ok(().into())
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            ConfidentialAssets::confidential_balance_of(ASSET, &ALICE).to_bytes(),
+            [20u8; 32]
+        );
+        assert_eq!(Balances::free_balance(ALICE), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ConfidentialFeePaid {
+                who: ALICE,
+                asset: ASSET,
+                amount: 42,
+            })
+        );
+
+        // Without a confidential fee the public charge applies as before.
+        let public = Ext::new(0.into()).validate_only(
+            RuntimeOrigin::signed(BOB),
+            &call,
+            &info,
+            10,
+            TransactionSource::External,
+            0,
+        );
+        assert_eq!(public.map(|_| ()), payment_err);
+        Balances::set_balance(&BOB, 100);
+        Ext::new(0.into())
+            .test_run(RuntimeOrigin::signed(BOB), &call, &info, 10, 0, |_| {
+                Ok(().into())
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(Balances::free_balance(BOB), 100 - 12);
+    });
+}
+
+#[test]
+fn prepare_claim_asks_the_prover_only_when_transfers_are_pending() {
+    // Claims exactly the pending ids, as a node holding the secret key would.
+    struct IdsProver;
+    impl ClaimProver<AccountId, AssetId> for IdsProver {
+        fn prove_claim(
+            _asset: AssetId,
+            _who: &AccountId,
+            pending: &[(u64, EncryptedAmount)],
+        ) -> Option<InputProof> {
+            let ids: Vec<u64> = pending.iter().map(|(id, _)| *id).collect();
+            Some(accept_input(&ids, &[]))
+        }
+    }
+
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        assert_eq!(
+            ConfidentialAssets::prepare_claim::<IdsProver>(ASSET, &BOB),
+            None
+        );
+
+        assert_ok!(ConfidentialAssets::confidential_transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+        let ids: Vec<u64> = Zkhe::pending_deposits(&BOB, ASSET)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ConfidentialAssets::prepare_claim::<()>(ASSET, &BOB), None);
+        let claim = ConfidentialAssets::prepare_claim::<IdsProver>(ASSET, &BOB).unwrap();
+        assert_eq!(claim, accept_input(&ids, &[]));
+
+        assert_ok!(ConfidentialAssets::confidential_claim(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            claim
+        ));
+        assert_eq!(
+            ConfidentialAssets::prepare_claim::<IdsProver>(ASSET, &BOB),
+            None
+        );
+    });
+}
+
+#[test]
+fn callbacks_fire_after_each_successful_call() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+
+        assert_ok!(ConfidentialAssets::deposit(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            10,
+            proof(&[])
+        ));
+        assert_ok!(ConfidentialAssets::confidential_transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+        let (id, _) = Zkhe::pending_deposits(&BOB, ASSET)[0];
+        assert_ok!(ConfidentialAssets::confidential_claim(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            accept_input(&[id], &[])
+        ));
+        assert_ok!(ConfidentialAssets::withdraw(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            ct(2),
+            proof(&[])
+        ));
+        // CHARLIE has no public key, so the transfer fails and nothing fires.
+        assert!(
+            ConfidentialAssets::confidential_transfer(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                CHARLIE,
+                ct(3),
+                proof(&[])
+            )
+            .is_err()
+        );
+
+        assert_eq!(
+            take_callbacks(),
+            vec![
+                Callback::Shield(ASSET, ALICE, 10, EncryptedAmount::new([5u8; 64])),
+                Callback::Transfer(ASSET, ALICE, BOB, ct(1)),
+                Callback::Claim(ASSET, BOB, EncryptedAmount::zero()),
+                Callback::Unshield(ASSET, BOB, 42, ct(2)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn confidential_transfer_and_call_notifies_the_receiver_and_reverts_on_rejection() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        let data = |d: &[u8]| CallbackData::try_from(d.to_vec()).unwrap();
+
+        assert_ok!(ConfidentialAssets::confidential_transfer_and_call(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[]),
+            data(b"hello")
+        ));
+        assert_eq!(
+            take_callbacks(),
+            vec![
+                Callback::Received(ASSET, ALICE, BOB, ct(1), b"hello".to_vec()),
+                Callback::Transfer(ASSET, ALICE, BOB, ct(1)),
+            ]
+        );
+        assert_eq!(Zkhe::pending_deposits(&BOB, ASSET).len(), 1);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ConfidentialTransfer {
+                asset: ASSET,
+                from: ALICE,
+                to: BOB,
+                encrypted_amount: ct(1),
+                pending_id: Some(0),
+                from_old_available: Commitment::default(),
+                from_new_available: Commitment::new([1u8; 32]),
+                to_old_pending: Commitment::default(),
+                to_new_pending: Commitment::new([2u8; 32]),
+                proof_hash: BlakeTwo256::hash(&proof(&[])).into(),
+            })
+        );
+
+        assert_eq!(
+            ConfidentialAssets::confidential_transfer_and_call(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(2),
+                proof(&[]),
+                data(b"reject")
+            ),
+            Err(pallet::Error::<Runtime>::ReceiverRejected.into())
+        );
+        // The rejected transfer left no pending deposit behind.
+        assert_eq!(Zkhe::pending_deposits(&BOB, ASSET).len(), 1);
+    });
+}
+
+#[test]
+fn confidential_transfer_many_sends_every_leg_or_none() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        set_pk(CHARLIE);
+        let batch = |legs: Vec<(AccountId, EncryptedAmount)>| {
+            legs.into_iter()
+                .map(|(to, amount)| (to, amount, proof(&[])))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap()
+        };
+
+        assert_ok!(ConfidentialAssets::confidential_transfer_many(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            batch(vec![(BOB, ct(1)), (CHARLIE, ct(2))])
+        ));
+        assert_eq!(
+            take_callbacks(),
+            vec![
+                Callback::Transfer(ASSET, ALICE, BOB, ct(1)),
+                Callback::Transfer(ASSET, ALICE, CHARLIE, ct(2)),
+            ]
+        );
+        assert_eq!(Zkhe::pending_deposits(&BOB, ASSET).len(), 1);
+        assert_eq!(Zkhe::pending_deposits(&CHARLIE, ASSET).len(), 1);
+
+        // The last leg's receiver has no key, so the first leg is rolled back too.
+        assert!(
+            ConfidentialAssets::confidential_transfer_many(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                batch(vec![(BOB, ct(3)), (99, ct(4))])
+            )
+            .is_err()
+        );
+        assert_eq!(Zkhe::pending_deposits(&BOB, ASSET).len(), 1);
+    });
+}
+
+#[test]
+fn confidential_transfer_split_queues_every_output() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        set_pk(CHARLIE);
+        // The mock verifier returns each output's masked half as its commitment, which
+        // the backend adds to the pending balance, so they must be valid points
+        const BASEPOINT: [u8; 32] = [
+            0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71, 0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00,
+            0x51, 0x5f, 0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d, 0xb6, 0xa6, 0x59, 0x45,
+            0xe0, 0x8d, 0x2d, 0x76,
+        ];
+        let one = Commitment::new(BASEPOINT);
+        let two = one.add(&one).unwrap();
+        let to_bob = EncryptedAmount::from_parts([8u8; 32], BASEPOINT);
+        let to_charlie = EncryptedAmount::from_parts([9u8; 32], BASEPOINT);
+        let outputs = |legs: Vec<(AccountId, EncryptedAmount)>| legs.try_into().unwrap();
+        let total = EncryptedAmount::new([1u8; 64]);
+
+        assert_eq!(
+            ConfidentialAssets::confidential_transfer_split(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                total,
+                outputs(vec![]),
+                proof(&[]),
+            ),
+            Err(pallet::Error::<Runtime>::EmptySplit.into())
+        );
+
+        // BOB is paid twice and CHARLIE once
+        assert_ok!(ConfidentialAssets::confidential_transfer_split(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            total,
+            outputs(vec![(BOB, to_bob), (CHARLIE, to_charlie), (BOB, to_bob)]),
+            proof(&[5]),
+        ));
+        assert_eq!(
+            take_callbacks(),
+            vec![
+                Callback::Transfer(ASSET, ALICE, BOB, to_bob),
+                Callback::Transfer(ASSET, ALICE, CHARLIE, to_charlie),
+                Callback::Transfer(ASSET, ALICE, BOB, to_bob),
+            ]
+        );
+        assert_eq!(
+            Zkhe::pending_deposits(&BOB, ASSET),
+            vec![(0, to_bob), (1, to_bob)]
+        );
+        assert_eq!(
+            Zkhe::pending_deposits(&CHARLIE, ASSET),
+            vec![(0, to_charlie)]
+        );
+        // Mock verifier debits the sender to [24;32]
+        assert_eq!(
+            ConfidentialAssets::confidential_balance_of(ASSET, &ALICE),
+            Commitment::new([24u8; 32])
+        );
+        // Events report the pending balances the backend stored
+        assert_eq!(
+            ConfidentialAssets::confidential_pending_balance_of(ASSET, &BOB),
+            two
+        );
+        assert_eq!(
+            ConfidentialAssets::confidential_pending_balance_of(ASSET, &CHARLIE),
+            one
+        );
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ConfidentialTransfer {
+                asset: ASSET,
+                from: ALICE,
+                to: BOB,
+                encrypted_amount: to_bob,
+                pending_id: Some(1),
+                from_old_available: Commitment::default(),
+                from_new_available: Commitment::new([24u8; 32]),
+                to_old_pending: Commitment::default(),
+                to_new_pending: two,
+                proof_hash: BlakeTwo256::hash(&proof(&[5])).into(),
+            })
+        );
+    });
+}
+
+#[test]
+fn created_asset_holds_deposit_and_is_managed_by_owner_and_admin() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::{Inspect, InspectHold, Mutate};
+        let text = |s: &[u8]| -> BoundedVec<u8, ConstU32<64>> { s.to_vec().try_into().unwrap() };
+        Balances::set_balance(&ALICE, 100);
+
+        assert_ok!(ConfidentialAssets::create_confidential_asset(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            text(b"Confidential Dot"),
+            text(b"cDOT"),
+            10,
+            text(b"ipfs://cdot")
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::AssetCreated {
+                asset: ASSET,
+                owner: ALICE,
+                admin: BOB,
+            })
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AssetDeposit.into(), &ALICE),
+            10
+        );
+        assert_eq!(Balances::balance(&ALICE), 90);
+        assert_eq!(ConfidentialAssets::asset_symbol(ASSET), b"cDOT".to_vec());
+        assert_eq!(ConfidentialAssets::asset_decimals(ASSET), 10);
+        assert_eq!(
+            ConfidentialAssets::asset_contract_uri(ASSET),
+            b"ipfs://cdot".to_vec()
+        );
+
+        assert_eq!(
+            ConfidentialAssets::create_confidential_asset(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                ALICE,
+                text(b""),
+                text(b""),
+                0,
+                text(b"")
+            ),
+            Err(pallet::Error::<Runtime>::AssetExists.into())
+        );
+
+        // The admin may change metadata, a stranger may not.
+        assert_ok!(ConfidentialAssets::set_asset_metadata(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            text(b"Confidential Dot"),
+            text(b"cDOT2"),
+            12,
+            text(b"")
+        ));
+        assert_eq!(ConfidentialAssets::asset_symbol(ASSET), b"cDOT2".to_vec());
+        assert_eq!(
+            ConfidentialAssets::set_asset_metadata(
+                RuntimeOrigin::signed(CHARLIE),
+                ASSET,
+                text(b""),
+                text(b""),
+                0,
+                text(b"")
+            ),
+            Err(pallet::Error::<Runtime>::NotAuthorized.into())
+        );
+
+        // Only the owner appoints the admin.
+        assert_eq!(
+            ConfidentialAssets::set_asset_admin(RuntimeOrigin::signed(BOB), ASSET, CHARLIE),
+            Err(pallet::Error::<Runtime>::NotAuthorized.into())
+        );
+        assert_ok!(ConfidentialAssets::set_asset_admin(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            CHARLIE
+        ));
+        assert_eq!(Asset::<Runtime>::get(ASSET).unwrap().admin, CHARLIE);
+        assert_eq!(
+            ConfidentialAssets::set_asset_admin(RuntimeOrigin::signed(ALICE), ASSET + 1, BOB),
+            Err(pallet::Error::<Runtime>::UnknownAsset.into())
+        );
+    });
+}
+
+#[test]
+fn asset_policy_sets_dust_limits_for_deposits_and_transfers() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::Mutate;
+        let text = |s: &[u8]| -> BoundedVec<u8, ConstU32<64>> { s.to_vec().try_into().unwrap() };
+        type Floor = WithTransferFloor<Runtime, DefaultRangeBits>;
+        let policy = AssetPolicy {
+            min_shield_amount: 100,
+            min_transfer_commitment: 25,
+        };
+        set_pk(ALICE);
+        Balances::set_balance(&ALICE, 100);
+        assert_ok!(ConfidentialAssets::create_confidential_asset(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            text(b""),
+            text(b""),
+            0,
+            text(b"")
+        ));
+        assert_eq!(Floor::transfer_floor(&ASSET.encode()), 0);
+
+        assert_eq!(
+            ConfidentialAssets::set_asset_policy(
+                RuntimeOrigin::signed(CHARLIE),
+                ASSET,
+                policy.clone()
+            ),
+            Err(pallet::Error::<Runtime>::NotAuthorized.into())
+        );
+        assert_ok!(ConfidentialAssets::set_asset_policy(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            policy.clone()
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::AssetPolicySet {
+                asset: ASSET,
+                policy,
+            })
+        );
+
+        assert_eq!(
+            ConfidentialAssets::deposit(RuntimeOrigin::signed(ALICE), ASSET, 99, proof(&[])),
+            Err(pallet::Error::<Runtime>::BelowMinimum.into())
+        );
+        assert_ok!(ConfidentialAssets::deposit(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            100,
+            proof(&[])
+        ));
+
+        // The verifier sees the floor through `WithTransferFloor`, other assets have none.
+        assert_eq!(Floor::transfer_floor(&ASSET.encode()), 25);
+        assert_eq!(Floor::transfer_floor(&(ASSET + 1).encode()), 0);
+        assert_eq!(Floor::range_bits(&ASSET.encode()), DEFAULT_RANGE_BITS);
+    });
+}
+
+#[test]
+fn scheduled_transfer_waits_in_escrow_until_due() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::{InspectHold, Mutate};
+        let escrow = pallet_confidential_escrow::Pallet::<Runtime>::escrow_account();
+        let held = || Balances::balance_on_hold(&HoldReason::ScheduledTransfer.into(), &ALICE);
+        set_pk(ALICE);
+        set_pk(BOB);
+        set_pk(escrow);
+        Balances::set_balance(&ALICE, 100);
+
+        assert_eq!(
+            ConfidentialAssets::confidential_transfer_scheduled(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(1),
+                proof(&[]),
+                1
+            ),
+            Err(pallet::Error::<Runtime>::ScheduleInPast.into())
+        );
+        assert_ok!(ConfidentialAssets::confidential_transfer_scheduled(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[]),
+            10
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::TransferScheduled {
+                id: 0,
+                asset: ASSET,
+                from: ALICE,
+                to: BOB,
+                encrypted_amount: ct(1),
+                execute_at: 10,
+            })
+        );
+        assert_eq!(held(), 5);
+        // The amount sits with the escrow, not the receiver, under its own lock.
+        assert_eq!(Zkhe::pending_deposits(&escrow, ASSET).len(), 1);
+        let escrow_id = ScheduledTransfers::<Runtime>::get(0).unwrap().escrow_id;
+        assert_eq!(
+            pallet_confidential_escrow::Escrows::<Runtime>::get(escrow_id)
+                .unwrap()
+                .purpose,
+            SCHEDULED_TRANSFER_PURPOSE
+        );
+        assert!(Zkhe::pending_deposits(&BOB, ASSET).is_empty());
+
+        assert_eq!(
+            ConfidentialAssets::execute_scheduled_transfer(
+                RuntimeOrigin::signed(CHARLIE),
+                0,
+                proof(&[])
+            ),
+            Err(pallet::Error::<Runtime>::NotDue.into())
+        );
+        System::set_block_number(10);
+        assert_eq!(
+            ConfidentialAssets::cancel_scheduled_transfer(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                proof(&[])
+            ),
+            Err(pallet::Error::<Runtime>::AlreadyDue.into())
+        );
+        take_callbacks();
+        assert_ok!(ConfidentialAssets::execute_scheduled_transfer(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            proof(&[])
+        ));
+        assert_eq!(Zkhe::pending_deposits(&BOB, ASSET).len(), 1);
+        assert_eq!(
+            take_callbacks(),
+            vec![Callback::Transfer(ASSET, ALICE, BOB, ct(1))]
+        );
+        assert_eq!(held(), 0);
+        assert!(ScheduledTransfers::<Runtime>::get(0).is_none());
+        assert!(pallet_confidential_escrow::Escrows::<Runtime>::get(escrow_id).is_none());
+
+        // Before it is due, only the sender may take a transfer back.
+        assert_ok!(ConfidentialAssets::confidential_transfer_scheduled(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(2),
+            proof(&[]),
+            20
+        ));
+        assert_eq!(
+            ConfidentialAssets::cancel_scheduled_transfer(
+                RuntimeOrigin::signed(BOB),
+                1,
+                proof(&[])
+            ),
+            Err(pallet::Error::<Runtime>::NotAuthorized.into())
+        );
+        assert_ok!(ConfidentialAssets::cancel_scheduled_transfer(
+            RuntimeOrigin::signed(ALICE),
+            1,
+            proof(&[])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ScheduledTransferCancelled { id: 1 })
+        );
+        assert_eq!(held(), 0);
+        assert_eq!(Zkhe::pending_deposits(&BOB, ASSET).len(), 1);
+    });
+}
+
+#[test]
+fn acl_sees_proof_ciphertext_commitments_and_op_data() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        set_pk(CHARLIE);
+        let hash = |p: &InputProof| -> [u8; 32] { BlakeTwo256::hash(p).into() };
+
+        let shield = proof(&[1]);
+        assert_ok!(ConfidentialAssets::deposit(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            100,
+            shield.clone()
+        ));
+        let [(op, ctx)] = &take_acl_checks()[..] else {
+            panic!("one check expected")
+        };
+        assert_eq!(*op, Op::Shield);
+        assert_eq!(ctx.amount, 100);
+        assert_eq!(ctx.proof_hash, Some(hash(&shield)));
+        assert_eq!(ctx.counterparty, None);
+
+        let alice_before = ConfidentialAssets::confidential_balance_of(ASSET, &ALICE);
+        let bob_before = ConfidentialAssets::confidential_balance_of(ASSET, &BOB);
+        let transfer = proof(&[2]);
+        assert_ok!(ConfidentialAssets::confidential_transfer_from(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            ALICE,
+            BOB,
+            ct(5),
+            transfer.clone()
+        ));
+        let [(op, ctx)] = &take_acl_checks()[..] else {
+            panic!("one check expected")
+        };
+        assert_eq!(*op, Op::TransferFrom);
+        assert_eq!(ctx.owner, Some(ALICE));
+        assert_eq!(ctx.counterparty, Some(BOB));
+        assert_eq!(ctx.delta, Some(ct(5)));
+        assert_eq!(ctx.proof_hash, Some(hash(&transfer)));
+        assert_eq!(ctx.from_commitment, Some(alice_before));
+        assert_eq!(ctx.to_commitment, Some(bob_before));
+        assert_eq!(ctx.data, AclOpData::None);
+
+        let legs = vec![(BOB, ct(1), proof(&[])), (CHARLIE, ct(2), proof(&[]))];
+        assert_ok!(ConfidentialAssets::confidential_transfer_many(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            legs.try_into().unwrap()
+        ));
+        let checks = take_acl_checks();
+        assert_eq!(
+            checks
+                .iter()
+                .map(|(op, ctx)| (*op, ctx.counterparty, ctx.data.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    Op::Transfer,
+                    Some(BOB),
+                    AclOpData::Batch { index: 0, len: 2 }
+                ),
+                (
+                    Op::Transfer,
+                    Some(CHARLIE),
+                    AclOpData::Batch { index: 1, len: 2 }
+                ),
+            ]
+        );
+    });
+}
+
+#[test]
+fn confidential_mint_is_for_the_asset_owner_or_admin() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::Mutate;
+        let text = |s: &[u8]| -> BoundedVec<u8, ConstU32<64>> { s.to_vec().try_into().unwrap() };
+        set_pk(CHARLIE);
+        Balances::set_balance(&ALICE, 100);
+
+        // Unregistered assets have no issuer.
+        assert_eq!(
+            ConfidentialAssets::confidential_mint(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                CHARLIE,
+                proof(&[])
+            ),
+            Err(pallet::Error::<Runtime>::UnknownAsset.into())
+        );
+        assert_ok!(ConfidentialAssets::create_confidential_asset(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            text(b"Confidential Dot"),
+            text(b"cDOT"),
+            10,
+            text(b"")
+        ));
+        assert_eq!(
+            ConfidentialAssets::confidential_mint(
+                RuntimeOrigin::signed(CHARLIE),
+                ASSET,
+                CHARLIE,
+                proof(&[])
+            ),
+            Err(pallet::Error::<Runtime>::NotAuthorized.into())
+        );
+
+        take_acl_checks();
+        assert_ok!(ConfidentialAssets::confidential_mint(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            CHARLIE,
+            proof(&[])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ConfidentialMinted {
+                asset: ASSET,
+                to: CHARLIE,
+                encrypted_amount: EncryptedAmount::new([5u8; 64]),
+            })
+        );
+        let [(op, ctx)] = &take_acl_checks()[..] else {
+            panic!("one check expected")
+        };
+        assert_eq!(*op, Op::Mint);
+        assert_eq!((ctx.caller, ctx.counterparty), (BOB, Some(CHARLIE)));
+        assert_eq!(Zkhe::pending_deposits(&CHARLIE, ASSET).len(), 1);
+    });
+}
+
+#[test]
+fn events_carry_commitments_pending_ids_and_proof_hashes() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        let hash = |p: &InputProof| -> [u8; 32] { BlakeTwo256::hash(p).into() };
+        let c = |b: u8| Commitment::new([b; 32]);
+
+        // The mock mints into pending [14; 32]
+        let p = proof(&[1]);
+        assert_ok!(ConfidentialAssets::deposit(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            100,
+            p.clone()
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::Deposited {
+                who: ALICE,
+                asset: ASSET,
+                amount: 100,
+                encrypted_amount: ct(5),
+                pending_id: Some(0),
+                old_pending: Commitment::default(),
+                new_pending: c(14),
+                proof_hash: hash(&p),
+            })
+        );
+
+        // ... and sends from available [1; 32] into pending [2; 32]
+        let p = proof(&[2]);
+        assert_ok!(ConfidentialAssets::confidential_transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            p.clone()
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ConfidentialTransfer {
+                asset: ASSET,
+                from: ALICE,
+                to: BOB,
+                encrypted_amount: ct(1),
+                pending_id: Some(0),
+                from_old_available: Commitment::default(),
+                from_new_available: c(1),
+                to_old_pending: Commitment::default(),
+                to_new_pending: c(2),
+                proof_hash: hash(&p),
+            })
+        );
+        assert_eq!(ConfidentialAssets::last_pending_id(ASSET, &BOB), Some(0));
+
+        // ... claims into available [3; 32], emptying pending
+        let p = accept_input(&[0], &[]);
+        assert_ok!(ConfidentialAssets::confidential_claim(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            p.clone()
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ConfidentialClaimed {
+                asset: ASSET,
+                who: BOB,
+                encrypted_amount: EncryptedAmount::zero(),
+                old_available: Commitment::default(),
+                new_available: c(3),
+                old_pending: c(2),
+                new_pending: Commitment::default(),
+                proof_hash: hash(&p),
+            })
+        );
+
+        // ... and burns available down to [20; 32]
+        let p = proof(&[3]);
+        assert_ok!(ConfidentialAssets::withdraw(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            ct(7),
+            p.clone()
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::Withdrawn {
+                who: ALICE,
+                asset: ASSET,
+                encrypted_amount: ct(7),
+                amount: 42,
+                old_available: c(1),
+                new_available: c(20),
+                proof_hash: hash(&p),
+            })
+        );
+    });
+}
+
+#[test]
+fn force_unshield_seizes_an_audited_balance_for_force_origin() {
+    new_test_ext().execute_with(|| {
+        let auditor: PublicKeyBytes = [9u8; 32].to_vec().try_into().unwrap();
+        assert_ok!(Zkhe::set_asset_config(
+            RuntimeOrigin::root(),
+            ASSET,
+            pallet_zkhe::AssetConfig {
+                auditor: Some(auditor),
+                ..Default::default()
+            }
+        ));
+        // The backend subtracts the balance from the supply, so seize the identity
+        // balance an empty account has
+        let balance = EncryptedAmount::from_parts([0u8; 32], [4u8; 32]);
+        let seize = |origin| {
+            ConfidentialAssets::force_unshield(origin, ASSET, ALICE, BOB, balance, proof(&[]))
+        };
+
+        assert_eq!(
+            seize(RuntimeOrigin::signed(ALICE)),
+            Err(sp_runtime::DispatchError::BadOrigin)
+        );
+        assert_ok!(seize(RuntimeOrigin::root()));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialAssets(pallet::Event::ForceUnshielded {
+                asset: ASSET,
+                who: ALICE,
+                beneficiary: BOB,
+                encrypted_balance: balance,
+                amount: 123,
+            })
+        );
+        assert_eq!(
+            Zkhe::audit_log(ASSET, 0, 10)[0].1.op,
+            pallet_zkhe::AuditOp::Seizure
+        );
+    });
+}
+
 proptest! {
     #![proptest_config(ProptestConfig::with_cases(50))]
 
@@ -348,7 +1482,8 @@ proptest! {
 
             let result = ConfidentialAssets::set_public_key(
                 RuntimeOrigin::signed(who),
-                pk
+                pk,
+                proof(&[1u8; 64])
             );
 
             prop_assert!(result.is_ok(), "set_public_key should always succeed");
@@ -501,7 +1636,8 @@ proptest! {
             let result = ConfidentialAssets::disclose_amount(
                 RuntimeOrigin::signed(who),
                 asset,
-                ct(ct_fill)
+                ct(ct_fill),
+                proof(&[])
             );
 
             prop_assert!(result.is_ok(), "disclose_amount should succeed: {:?}", result);
@@ -542,7 +1678,7 @@ proptest! {
             set_pk(attacker);
             set_pk(recipient);
 
-            // Attacker tries to transfer from owner (not authorized since Operators = ())
+            // Attacker tries to transfer from owner (not authorized, no operator set)
             let result = ConfidentialAssets::confidential_transfer_from(
                 RuntimeOrigin::signed(attacker),
                 asset,
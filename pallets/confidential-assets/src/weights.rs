@@ -1,7 +1,11 @@
 
-//! Autogenerated weights for `pallet_confidential_assets`
+//! Weights for `pallet_confidential_assets`: partly benchmark output, partly hand estimates.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
+//! Only `set_public_key` is benchmark output from the run below. Every function after
+//! the "Everything below is a hand estimate" marker is estimated by hand, not measured.
+//! Rerun the command to replace the estimates with measured weights.
+//!
+//! Benchmark run: SUBSTRATE BENCHMARK CLI VERSION 47.0.0
 //! DATE: 2025-11-28, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `Amars-MacBook-Pro.local`, CPU: `<UNKNOWN>`
@@ -47,6 +51,46 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+
+	// Everything below is a hand estimate, not benchmark output. Regenerate this file
+	// with frame-omni-bencher to replace them.
+
+	/// Estimated: a mint proof, about a backend transfer, plus the ramp transfer.
+	/// Storage: `System::Account` (r:1 w:1), `Zkhe::PublicKey` (r:1),
+	/// `Zkhe::ConfidentialAssetConfig` (r:1), `Zkhe::PendingDepositCount` (r:1 w:1),
+	/// `Zkhe::PendingBalanceCommit` (r:1 w:1), `Zkhe::TotalSupplyCommit` (r:1 w:1),
+	/// `Zkhe::NextPendingDepositId` (r:1 w:1), `Zkhe::PendingDeposits` (w:1)
+	fn deposit() -> Weight {
+		Weight::from_parts(6_245_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Estimated: a burn proof, about a backend transfer, plus the ramp mint.
+	/// Storage: `Zkhe::FrozenAccounts` (r:1), `Zkhe::ConfidentialAssetConfig` (r:1),
+	/// `Zkhe::PublicKey` (r:1), `Zkhe::AvailableBalanceCommit` (r:1 w:1),
+	/// `Zkhe::TotalSupplyCommit` (r:1 w:1), `System::Account` (r:1 w:1)
+	fn withdraw() -> Weight {
+		Weight::from_parts(6_442_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Estimated as pallet_zkhe::accept_pending plus one deposit read and delete per
+	/// claimed UTXO. Storage: `Zkhe::PublicKey` (r:1), `Zkhe::AvailableBalanceCommit`
+	/// (r:1 w:1), `Zkhe::PendingBalanceCommit` (r:1 w:1), `Zkhe::PendingDepositCount`
+	/// (r:1 w:1), and per UTXO `Zkhe::PendingDeposits` and `Zkhe::PendingDepositSource`
+	/// (r:1 w:1 each). `n` is in `[1, 16]`.
+	fn confidential_claim(n: u32, ) -> Weight {
+		Weight::from_parts(12_104_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3579))
+			.saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2647).saturating_mul(n.into()))
+	}
 	/// Delegates to pallet_zkhe::transfer - use backend weight + small overhead
 	fn confidential_transfer() -> Weight {
 		// Backend transfer (~6.5s) + overhead
@@ -55,6 +99,15 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// `n` backend transfers from one sender (estimated)
+	/// The range of component `n` is `[1, MaxTransfersPerBatch]`.
+	fn confidential_transfer_many(n: u32, ) -> Weight {
+		Weight::from_parts(6_600_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6168))
+			.saturating_mul(n.into())
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
 	/// Delegates to pallet_zkhe::transfer - use backend weight + small overhead
 	fn confidential_transfer_from() -> Weight {
 		// Backend transfer (~6.5s) + overhead + ACL checks
@@ -83,4 +136,106 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 2589))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Delegates to pallet_zkhe::burn_confidential - use backend weight + small overhead
+	fn confidential_burn() -> Weight {
+		// Backend burn (~6.5s) + overhead
+		Weight::from_parts(6_600_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6168))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Delegates to pallet_zkhe::close_account - two Schnorr checks + storage removal,
+	/// folding the deleted commitments into `Zkhe::ClosedBalanceCommit`
+	fn close_confidential_account() -> Weight {
+		Weight::from_parts(80_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6168))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `ConfidentialAssets::Asset` (r:1 w:1), `Balances::Holds` (r:1 w:1),
+	/// `ConfidentialAssets::Metadata` (r:0 w:1) (estimated)
+	fn create_confidential_asset() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `ConfidentialAssets::Asset` (r:1 w:0), `ConfidentialAssets::Metadata` (r:0 w:1) (estimated)
+	fn set_asset_metadata() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ConfidentialAssets::Asset` (r:1 w:1) (estimated)
+	fn set_asset_admin() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ConfidentialAssets::Asset` (r:1 w:0), `ConfidentialAssets::AssetPolicies` (r:0 w:1) (estimated)
+	fn set_asset_policy() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Escrow lock (a backend transfer) + deposit hold + schedule record (estimated)
+	fn confidential_transfer_scheduled() -> Weight {
+		Weight::from_parts(6_650_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6168))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Escrow release (a backend transfer) + deposit release (estimated)
+	fn execute_scheduled_transfer() -> Weight {
+		Weight::from_parts(6_650_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6168))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Escrow refund (a backend transfer) + deposit release (estimated)
+	fn cancel_scheduled_transfer() -> Weight {
+		Weight::from_parts(6_650_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6168))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Delegates to pallet_zkhe::mint_encrypted, as `deposit` does without the ramp,
+	/// plus `ConfidentialAssets::Asset` (r:1 w:0) (estimated)
+	fn confidential_mint() -> Weight {
+		Weight::from_parts(6_245_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Estimated, not benchmarked (110 ms):
+	/// - seizure proof, 75 ms: a DLEQ over the auditor key with three two-point
+	///   equations and no range proof; `disclose_amount` checks two in 50 ms
+	/// - ramp mint to the beneficiary, 25 ms, as a `fungibles` mint
+	/// - call and event overhead, 10 ms
+	/// Storage: `Zkhe::ConfidentialAssetConfig` (r:1), `Zkhe::AuditorEpoch` (r:1),
+	/// `Zkhe::AvailableBalanceCommit` (r:1 w:1), `Zkhe::AvailableBlinding` (r:1 w:1),
+	/// `Zkhe::TotalSupplyCommit` (r:1 w:1), `Zkhe::NextAuditId` (r:1 w:1),
+	/// `Zkhe::AuditLog` (w:2), and the ramp's asset, account and `System::Account`
+	/// (r:3 w:3)
+	fn force_unshield() -> Weight {
+		Weight::from_parts(110_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// One split proof debiting the sender, plus key, cap, pending balance, deposit
+	/// and expiry bookkeeping per output (estimated)
+	/// The range of component `n` is `[1, MaxTransfersPerBatch]`.
+	fn confidential_transfer_split(n: u32, ) -> Weight {
+		Weight::from_parts(6_600_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6168))
+			.saturating_add(Weight::from_parts(1_500_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
 }
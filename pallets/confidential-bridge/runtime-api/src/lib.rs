@@ -0,0 +1,15 @@
+//! Runtime API for tracking confidential bridge transfers.
+//!
+//! Explorers and tests use it instead of reading `Pending` and the settlement records
+//! of `pallet-confidential-bridge` on the source chain.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use confidential_assets_primitives::{BridgeTransferStatus, TransferId};
+
+sp_api::decl_runtime_apis! {
+    /// Status of outbound bridge transfers.
+    pub trait ConfidentialBridgeApi {
+        /// Where outbound transfer `id` stands; `None` if it was never sent from here.
+        fn bridge_transfer_status(id: TransferId) -> Option<BridgeTransferStatus>;
+    }
+}
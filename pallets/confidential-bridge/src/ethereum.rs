@@ -0,0 +1,97 @@
+//! Wire format of transfers to and from Ethereum.
+//!
+//! Packets are ABI-encoded so the ERC-7984 contract on the other side can read them
+//! with `abi.decode(payload, (uint64, bytes32, address, bytes32, bytes32, bytes))`:
+//! the nonce, the account credited, the token contract, the two halves of the
+//! ciphertext and the proof the receiving side mints with.
+
+use confidential_assets_primitives::EncryptedAmount;
+use sp_std::prelude::*;
+
+/// ABI word size.
+const WORD: usize = 32;
+/// Static head: five value words and the offset of `proof`.
+const HEAD: usize = 6 * WORD;
+
+/// An Ethereum address.
+pub type EthAddress = [u8; 20];
+
+/// A confidential transfer to or from Ethereum.
+#[derive(Clone, PartialEq, Eq, sp_runtime::RuntimeDebug)]
+pub struct EthereumPacket {
+    /// Sender-side nonce; each is minted at most once.
+    pub nonce: u64,
+    /// Account credited: an address left-padded with zeros towards Ethereum, a 32-byte
+    /// account id from it.
+    pub account: [u8; 32],
+    /// ERC-7984 contract of the asset.
+    pub token: EthAddress,
+    /// Ciphertext of the amount, burned on the sending side.
+    pub amount: EncryptedAmount,
+    /// Proof the receiving side mints `amount` with.
+    pub proof: Vec<u8>,
+}
+
+impl EthereumPacket {
+    /// Left-pad `address` to a 32-byte account.
+    pub fn account_of(address: EthAddress) -> [u8; 32] {
+        let mut account = [0u8; 32];
+        account[12..].copy_from_slice(&address);
+        account
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        let padded = self.proof.len().div_ceil(WORD) * WORD;
+        let mut out = Vec::with_capacity(HEAD + WORD + padded);
+        out.extend_from_slice(&uint(self.nonce));
+        out.extend_from_slice(&self.account);
+        out.extend_from_slice(&Self::account_of(self.token));
+        out.extend_from_slice(self.amount.as_ref());
+        out.extend_from_slice(&uint(HEAD as u64));
+        out.extend_from_slice(&uint(self.proof.len() as u64));
+        out.extend_from_slice(&self.proof);
+        out.resize(HEAD + WORD + padded, 0);
+        out
+    }
+
+    /// Decode a packet as `abi_encode` lays it out. Anything else, including words with
+    /// bits set above their type, is rejected.
+    pub fn abi_decode(data: &[u8]) -> Option<Self> {
+        let word = |i: usize| -> Option<&[u8]> { data.get(i * WORD..(i + 1) * WORD) };
+        let nonce = read_uint(word(0)?)?;
+        let account: [u8; 32] = word(1)?.try_into().ok()?;
+        let token = word(2)?;
+        if token[..12].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let amount = EncryptedAmount::try_from(data.get(3 * WORD..5 * WORD)?).ok()?;
+        if read_uint(word(5)?)? != HEAD as u64 {
+            return None;
+        }
+        let len = usize::try_from(read_uint(word(6)?)?).ok()?;
+        let start = HEAD + WORD;
+        let proof = data.get(start..start.checked_add(len)?)?.to_vec();
+        Some(Self {
+            nonce,
+            account,
+            token: token[12..].try_into().ok()?,
+            amount,
+            proof,
+        })
+    }
+}
+
+/// `value` as a big-endian ABI word.
+fn uint(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// A `uint64` ABI word.
+fn read_uint(word: &[u8]) -> Option<u64> {
+    if word[..24].iter().any(|b| *b != 0) {
+        return None;
+    }
+    Some(u64::from_be_bytes(word[24..].try_into().ok()?))
+}
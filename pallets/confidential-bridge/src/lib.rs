@@ -5,8 +5,8 @@
 //! transfers *between* parachains using HRMP. On the source chain we
 //! **escrow** a confidential ciphertext, send an HRMP packet to the
 //! destination, and later **finalize** by either:
-//! - success → move the escrowed ciphertext to this pallet’s burn account
-//!   and **burn** it (supply conservation), or
+//! - success → **burn** the escrowed ciphertext straight from escrow (supply
+//!   conservation), or
 //! - timeout/cancel → **refund** the ciphertext back to the sender.
 //!
 //! Notes:
@@ -16,12 +16,43 @@
 //!   adapter. The message payload is SCALE-encoded and opaque to this pallet
 //!   once sent.
 //! - We use `ConfidentialEscrow` and `ConfidentialBackend`:
-//!   * escrow_lock / escrow_release / escrow_refund for custody flow,
-//!   * burn_encrypted for post-success supply adjustment.
+//!   * escrow_lock / escrow_refund for custody flow,
+//!   * escrow_burn for post-success supply adjustment. It needs no proof: the
+//!     lock's commitment was verified when it was taken, so nobody has to prove
+//!     anything about an account whose key they do not hold.
 //! - The destination chain is expected to credit/mint the ciphertext (its own
-//!   backend/pallet) and then send an HRMP response that eventually calls
-//!   `confirm_success`. For simplicity, we also expose a `cancel_and_refund`
+//!   backend/pallet) and then acknowledge it, after which `confirm_success` burns
+//!   the escrow. For simplicity, we also expose a `cancel_and_refund`
 //!   path callable by the original sender after a deadline.
+//! - `receive_confidential` answers every packet with a `BridgeAck` sent through
+//!   `HrmpMessenger::send_ack`; the source's `handle_ack` records the outcome, after
+//!   which anyone may finalize (`confirm_success`) and the sender may refund
+//!   (`cancel_and_refund`) without waiting for the deadline or a privileged origin.
+//!   Relayers may also `submit_ack` with evidence `AckVerifier` accepts. Both pay
+//!   `RelayerReward` out of the fee pot (`FeeCollector`), so settlement does not hang
+//!   on `XcmOrigin` alone.
+//! - Chains number their assets independently. `AdminOrigin` maps each local asset
+//!   to its id on every para it may cross to (`map_asset`); `send_confidential`
+//!   translates the asset into the destination's id and refuses unmapped ones, and
+//!   `receive_confidential` only mints assets mapped for the packet's source.
+//! - `Channels` holds per-para overrides: a smaller payload limit, a native fee per
+//!   send, the execution each message buys on the para, and a pause switch (`pause_channel` / `resume_channel`) that stops sends
+//!   to and packets from a misbehaving para without a runtime upgrade. Paras without
+//!   an entry are open, fee-free, ask for unpaid execution and are limited by
+//!   `MaxBridgePayload` alone.
+//! - Packets carry a sequence number per (source, destination), assigned when the
+//!   messenger takes them. The destination rejects packets behind the next one it
+//!   expects, so reordered deliveries cannot mint; a channel in `DeliveryOrder::Strict`
+//!   also rejects packets ahead of it, stalling on a gap until the channel is made
+//!   lenient.
+//! - Transfers to Ethereum leave through `Config::Exporter` (e.g. Snowbridge) as an
+//!   ABI-encoded [`ethereum::EthereumPacket`] for an ERC-7984 contract mapped to the
+//!   asset. No ACK comes back, so the escrow is burned at once; the contract mints
+//!   with the packet's proof. Transfers back arrive on `receive_from_ethereum`.
+//! - A packet the messenger refuses (e.g. a congested HRMP channel) is kept in
+//!   `Outbox` rather than failing the send: `on_idle` retries it up to
+//!   `MaxSendAttempts` times and anyone may `resend` it. An abandoned packet counts
+//!   as a failed delivery, so the sender can refund at once.
 //!
 //! This is intentionally minimal and should compile with standard Substrate
 //! pallets in scope. Integrators can extend weights, origins, and message
@@ -29,25 +60,155 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod ethereum;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
-use frame_support::{PalletId, pallet_prelude::*, traits::Get, transactional};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        Get,
+        fungible::{Inspect, Mutate},
+        tokens::Preservation,
+    },
+    transactional,
+};
 use frame_system::pallet_prelude::*;
-use parity_scale_codec::{Encode, MaxEncodedLen};
+use parity_scale_codec::{DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::traits::{AtLeast32BitUnsigned, BlakeTwo256, Hash, Saturating, Zero};
 use sp_std::prelude::*;
 
 use confidential_assets_primitives::{
-    BridgePacket, ConfidentialBackend, ConfidentialEscrow, EncryptedAmount, HrmpMessenger,
-    InputProof, PendingTransfer, TransferId,
+    AckVerifier, BridgeAck, BridgePacket, BridgeTransferStatus, ConfidentialBackend,
+    ConfidentialEscrow, EncryptedAmount, EscrowPurpose, ExecutionFee, HrmpMessenger, InputProof,
+    MessageExporter, PendingTransfer, TransferId,
 };
+use ethereum::{EthAddress, EthereumPacket};
 
 pub use pallet::*;
 
+pub type NativeBalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+pub type ExecutionFeeOf<T> = ExecutionFee<<<T as Config>::Messenger as HrmpMessenger>::FeeAsset>;
+pub type ChannelConfigOf<T> =
+    ChannelConfig<NativeBalanceOf<T>, <<T as Config>::Messenger as HrmpMessenger>::FeeAsset>;
+
+const LOG_TARGET: &str = "runtime::confidential-bridge";
+
+/// Purpose tag of the escrow locks outbound transfers open.
+pub const BRIDGE_PURPOSE: EscrowPurpose = *b"bridging";
+
+/// Whether packets flow over a channel.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub enum ChannelStatus {
+    #[default]
+    Open,
+    /// No sends to the para and no packets accepted from it. Transfers in flight
+    /// still settle.
+    Paused,
+}
+
+/// How strictly packets from a para must follow their sequence numbers.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub enum DeliveryOrder {
+    /// Packets may skip sequence numbers, but never go back.
+    #[default]
+    Lenient,
+    /// Each packet must carry exactly the next sequence number.
+    Strict,
+}
+
+/// Settings of the channel to one para.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub struct ChannelConfig<Balance, FeeAsset> {
+    pub status: ChannelStatus,
+    /// Largest packet sent or accepted, at most `MaxBridgePayload`.
+    pub max_payload: u32,
+    /// Native fee each send pays to `FeeCollector`. Set it to cover `execution`, which
+    /// the bridge's account on the para pays.
+    pub fee: Balance,
+    /// Execution bought on the para for each message sent there, packets and ACKs
+    /// alike; `None` asks for unpaid execution.
+    pub execution: Option<ExecutionFee<FeeAsset>>,
+    /// Ordering enforced on packets from the para.
+    pub ordering: DeliveryOrder,
+}
+
+/// An outbound packet waiting for the messenger to take it.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct QueuedPacket<Payload> {
+    pub dest_para: u32,
+    pub payload: Payload,
+    /// Sends tried so far.
+    pub attempts: u32,
+}
+
+/// Limits on one asset's outbound transfers per `RateLimitEpoch`.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub struct OutboundCap<Balance> {
+    pub max_transfers: u32,
+    /// Cap on the amounts senders disclose under the auditor handles of their transfers,
+    /// so only for assets with an auditor key; `None` leaves amounts undisclosed and
+    /// unlimited.
+    pub max_amount: Option<Balance>,
+}
+
+/// What one asset's outbound transfers used of its cap in `epoch`.
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct OutboundUsage<BlockNumber, Balance> {
+    pub epoch: BlockNumber,
+    pub transfers: u32,
+    pub amount: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -61,39 +222,78 @@ pub mod pallet {
 
         /// Asset and balance types for the confidential backend.
         type AssetId: Parameter + Member + Copy + Ord + MaxEncodedLen + TypeInfo;
-        type Balance: Parameter + Member + Copy + Default + MaxEncodedLen + TypeInfo;
+        type Balance: Parameter
+            + Member
+            + AtLeast32BitUnsigned
+            + Copy
+            + Default
+            + MaxEncodedLen
+            + TypeInfo;
 
         /// Confidential state/backend (read/verify/burn/mint/transfer).
         type Backend: ConfidentialBackend<Self::AccountId, Self::AssetId, Self::Balance>;
 
+        /// Native currency channel fees are paid in.
+        type Currency: Mutate<Self::AccountId>;
+
+        /// Receives channel fees, and pays relayer rewards out of them.
+        type FeeCollector: Get<Self::AccountId>;
+
+        /// Paid from `FeeCollector` to whoever settles another's transfer, while the pot
+        /// lasts.
+        #[pallet::constant]
+        type RelayerReward: Get<NativeBalanceOf<Self>>;
+
+        /// Checks the evidence relayers submit ACKs with; `()` accepts none.
+        type AckVerifier: AckVerifier;
+
         /// Confidential escrow adapter (lock, release, refund).
-        type Escrow: ConfidentialEscrow<Self::AccountId, Self::AssetId>;
+        type Escrow: ConfidentialEscrow<Self::AccountId, Self::AssetId, BlockNumberFor<Self>>;
 
         // ---------------------------- XCM Types and Traits ----------------------------
 
-        /// Origin allowed to confirm/cancel on behalf of destination responses.
-        /// In production wire this to an XCM origin filter (e.g., EnsureXcm<…>).
-        type XcmOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin managing the asset mappings (governance).
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin of packets and ACKs from sibling paras, yielding the sending para,
+        /// e.g. `EnsureXcm<Everything>` mapped from the sibling's location to its id.
+        /// A para may also confirm or cancel the transfers sent to it.
+        type XcmOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = u32>;
 
         /// HRMP messenger adapter (runtime supplies an implementation).
         type Messenger: HrmpMessenger;
 
+        /// Exporter of transfers to Ethereum; `()` if the chain has no Ethereum bridge.
+        type Exporter: MessageExporter;
+
+        /// Origin of messages from the Ethereum-side contract, e.g. `EnsureXcm` for the
+        /// contract's location behind Snowbridge.
+        type EthereumOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Maximum size in bytes for a bridge HRMP payload.
         type MaxBridgePayload: Get<u32>;
 
         #[pallet::constant]
         type SelfParaId: Get<u32>; // in prod use compact encoded u32: polkadot_parachain_primitives::Id
 
-        /// PalletId used to derive the *burn account* for finalization.
-        /// We first escrow-release to this account (with a transfer proof),
-        /// then burn from it (with a burn proof).
+        /// Length in blocks of the epochs `OutboundCaps` apply to. Must be non-zero.
         #[pallet::constant]
-        type BurnPalletId: Get<PalletId>;
+        type RateLimitEpoch: Get<BlockNumberFor<Self>>;
 
         /// Default timeout in blocks for pending transfers.
         #[pallet::constant]
         type DefaultTimeout: Get<BlockNumberFor<Self>>;
 
+        /// Sends tried for a queued packet before it is abandoned.
+        #[pallet::constant]
+        type MaxSendAttempts: Get<u32>;
+
+        /// Blocks an inbound packet is remembered for replay protection. Once pruned,
+        /// transfer ids from its source up to it are rejected outright, so keep this
+        /// longer than any source's timeout.
+        #[pallet::constant]
+        type InboundRetention: Get<BlockNumberFor<Self>>;
+
         /// Weight info (minimal defaults provided below).
         type WeightInfo: WeightData;
     }
@@ -104,6 +304,22 @@ pub mod pallet {
         fn confirm_success() -> Weight;
         fn cancel_and_refund() -> Weight;
         fn receive() -> Weight;
+        fn handle_ack() -> Weight;
+        fn map_asset() -> Weight;
+        fn unmap_asset() -> Weight;
+        fn set_outbound_cap() -> Weight;
+        /// Checking a sender's disclosure against an asset's amount cap in `send`.
+        fn disclose() -> Weight;
+        fn set_channel() -> Weight;
+        fn set_channel_status() -> Weight;
+        /// Resending one queued packet, by `resend` or in `on_idle`.
+        fn resend() -> Weight;
+        /// Pruning one processed inbound packet in `on_idle`.
+        fn prune_inbound() -> Weight;
+        fn export_to_ethereum() -> Weight;
+        fn receive_from_ethereum() -> Weight;
+        fn map_ethereum_token() -> Weight;
+        fn submit_ack() -> Weight;
     }
     impl WeightData for () {
         fn send() -> Weight {
@@ -118,6 +334,45 @@ pub mod pallet {
         fn receive() -> Weight {
             Weight::from_parts(100_000, 0)
         }
+        fn handle_ack() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn map_asset() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn unmap_asset() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_outbound_cap() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn disclose() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn set_channel() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn set_channel_status() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn resend() -> Weight {
+            Weight::from_parts(40_000, 0)
+        }
+        fn prune_inbound() -> Weight {
+            Weight::from_parts(5_000, 0)
+        }
+        fn export_to_ethereum() -> Weight {
+            Weight::from_parts(80_000, 0)
+        }
+        fn receive_from_ethereum() -> Weight {
+            Weight::from_parts(100_000, 0)
+        }
+        fn map_ethereum_token() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn submit_ack() -> Weight {
+            Weight::from_parts(100_000, 0)
+        }
     }
 
     #[pallet::pallet]
@@ -137,17 +392,160 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// How each settled outbound transfer ended, `Confirmed` or `Refunded`, for
+    /// `transfer_status` once its `Pending` record is gone.
+    #[pallet::storage]
+    pub type Settled<T: Config> =
+        StorageMap<_, Blake2_128Concat, TransferId, BridgeTransferStatus, OptionQuery>;
+
+    /// Id each local asset has on a given para; only mapped assets cross to or arrive
+    /// from it.
+    #[pallet::storage]
+    pub type AssetMappings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Twox64Concat,
+        u32,
+        T::AssetId,
+        OptionQuery,
+    >;
+
+    /// Reverse of `AssetMappings`: the local asset a para's asset id stands for.
+    #[pallet::storage]
+    pub type RemoteAssets<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AssetId,
+        T::AssetId,
+        OptionQuery,
+    >;
+
+    /// Outbound packets the messenger refused, awaiting a retry.
+    #[pallet::storage]
+    pub type Outbox<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        TransferId,
+        QueuedPacket<BridgePacket<T::AccountId, T::AssetId>>,
+        OptionQuery,
+    >;
+
+    /// Sequence number the next packet handed to the messenger for each para carries.
+    #[pallet::storage]
+    pub type OutboundSequence<T: Config> = StorageMap<_, Twox64Concat, u32, u64, ValueQuery>;
+
+    /// Lowest sequence number still accepted from each para.
+    #[pallet::storage]
+    pub type InboundSequence<T: Config> = StorageMap<_, Twox64Concat, u32, u64, ValueQuery>;
+
+    /// Settings of the channel to each para; open, fee-free and limited by
+    /// `MaxBridgePayload` if absent.
+    #[pallet::storage]
+    pub type Channels<T: Config> =
+        StorageMap<_, Twox64Concat, u32, ChannelConfigOf<T>, OptionQuery>;
+
+    /// Per-epoch limits on each asset's outbound transfers; uncapped if absent.
+    #[pallet::storage]
+    pub type OutboundCaps<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, OutboundCap<T::Balance>, OptionQuery>;
+
+    /// Each capped asset's use of its cap in the latest epoch it was sent in.
+    #[pallet::storage]
+    pub type OutboundUsages<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        OutboundUsage<BlockNumberFor<T>, T::Balance>,
+        ValueQuery,
+    >;
+
+    /// Inbound packets already minted, by source para and transfer id, with the block
+    /// they were processed at.
+    #[pallet::storage]
+    pub type InboundProcessed<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Twox64Concat,
+        TransferId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Per source para, the transfer id below which every packet is rejected: all up to
+    /// the highest pruned from `InboundProcessed`.
+    #[pallet::storage]
+    pub type InboundFloor<T: Config> = StorageMap<_, Twox64Concat, u32, TransferId, ValueQuery>;
+
+    /// Processed inbound packets by the block their record is pruned at.
+    #[pallet::storage]
+    pub type InboundExpiry<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Twox64Concat,
+        (u32, TransferId),
+        (),
+        OptionQuery,
+    >;
+
+    /// First block whose `InboundExpiry` has not been pruned yet.
+    #[pallet::storage]
+    pub type NextInboundPrune<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// ERC-7984 contract on Ethereum each local asset crosses to and from.
+    #[pallet::storage]
+    pub type EthereumTokens<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, EthAddress, OptionQuery>;
+
+    /// Reverse of `EthereumTokens`.
+    #[pallet::storage]
+    pub type EthereumAssets<T: Config> =
+        StorageMap<_, Identity, EthAddress, T::AssetId, OptionQuery>;
+
+    /// Nonce of the next transfer exported to Ethereum.
+    #[pallet::storage]
+    pub type NextEthereumNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Nonces of the transfers from Ethereum already minted.
+    #[pallet::storage]
+    pub type EthereumProcessed<T: Config> = StorageMap<_, Twox64Concat, u64, (), OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = Self::prune_inbound(now, remaining_weight);
+            used.saturating_add(Self::retry_outbound(remaining_weight.saturating_sub(used)))
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::RateLimitEpoch::get().is_zero(),
+                "`RateLimitEpoch` must be non-zero: outbound caps divide the block number by it"
+            );
+        }
+    }
+
     // --------------------------- Events / Errors --------------------------------------
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// Outbound transfer was initiated and escrowed locally.
+        /// Outbound transfer was initiated and escrowed locally. The sender may refund
+        /// it from `deadline` on without a success ACK.
         OutboundTransferInitiated {
             id: TransferId,
             from: T::AccountId,
             dest_para: u32,
             asset: T::AssetId,
+            /// blake2-256 of the encoded destination account.
+            dest_account_hash: [u8; 32],
+            deadline: BlockNumberFor<T>,
+            /// The packet's `packet_hash`, also emitted by the destination.
+            payload_hash: [u8; 32],
         },
         /// Destination reported success; local escrow burned (supply reduced).
         OutboundTransferConfirmed { id: TransferId, asset: T::AssetId },
@@ -156,8 +554,95 @@ pub mod pallet {
         /// Incoming Transfer Executed
         InboundTransferExecuted {
             id: TransferId,
+            source_para: u32,
             asset: T::AssetId,
             minted: EncryptedAmount,
+            /// blake2-256 of the encoded account credited.
+            dest_account_hash: [u8; 32],
+            /// The packet's `packet_hash`, as emitted by the source.
+            payload_hash: [u8; 32],
+        },
+        /// An inbound packet could not be minted; a failure ACK was sent back.
+        InboundTransferFailed {
+            id: TransferId,
+            source_para: u32,
+            payload_hash: [u8; 32],
+        },
+        /// The destination acknowledged an outbound transfer.
+        TransferAcknowledged { id: TransferId, success: bool },
+        /// `asset` is known as `remote` on `para`.
+        AssetMapped {
+            asset: T::AssetId,
+            para: u32,
+            remote: T::AssetId,
+        },
+        /// `asset` no longer crosses to or from `para`.
+        AssetUnmapped { asset: T::AssetId, para: u32 },
+        /// Outbound limits of `asset` set, or lifted when `None`.
+        OutboundCapSet {
+            asset: T::AssetId,
+            cap: Option<OutboundCap<T::Balance>>,
+        },
+        /// The channel to `para` was configured.
+        ChannelSet {
+            para: u32,
+            config: ChannelConfigOf<T>,
+        },
+        /// Sends to and packets from `para` are refused.
+        ChannelPaused { para: u32 },
+        /// The channel to `para` carries packets again.
+        ChannelResumed { para: u32 },
+        /// The messenger refused transfer `id`'s packet; it is queued for a retry.
+        OutboundQueued { id: TransferId },
+        /// Queued transfer `id`'s packet was sent.
+        OutboundSent { id: TransferId },
+        /// Transfer `id`'s packet was refused `MaxSendAttempts` times and dropped; the
+        /// sender may refund it.
+        OutboundAbandoned { id: TransferId },
+        /// A packet from `source_para` skipped the sequence numbers from `expected` up to
+        /// `received`; those packets will be rejected if they arrive.
+        InboundSequenceSkipped {
+            source_para: u32,
+            expected: u64,
+            received: u64,
+        },
+        /// `asset` crosses to and from `token` on Ethereum, or no longer crosses when
+        /// `None`.
+        EthereumTokenMapped {
+            asset: T::AssetId,
+            token: Option<EthAddress>,
+        },
+        /// `amount` of `asset` was burned here and exported to `recipient` on Ethereum.
+        ExportedToEthereum {
+            nonce: u64,
+            from: T::AccountId,
+            asset: T::AssetId,
+            recipient: EthAddress,
+            amount: EncryptedAmount,
+        },
+        /// Transfer `nonce` from Ethereum was minted to `to`.
+        ReceivedFromEthereum {
+            nonce: u64,
+            to: T::AccountId,
+            asset: T::AssetId,
+            minted: EncryptedAmount,
+        },
+        /// `relayer` was paid `amount` from the fee pot for settling a transfer.
+        RelayerRewarded {
+            relayer: T::AccountId,
+            amount: NativeBalanceOf<T>,
+        },
+        /// The fee pot could not pay `relayer` its `amount`; the settlement stands.
+        RelayerRewardUnpaid {
+            relayer: T::AccountId,
+            amount: NativeBalanceOf<T>,
+        },
+        /// A retry of transfer `id`'s queued packet was refused again.
+        OutboundRetryFailed { id: TransferId, attempts: u32 },
+        /// `asset` used up its outbound cap; further sends fail until `epoch` ends.
+        OutboundLimitReached {
+            asset: T::AssetId,
+            epoch: BlockNumberFor<T>,
         },
     }
 
@@ -170,14 +655,86 @@ pub mod pallet {
         AlreadyCompleted,
         MessengerFailed,
         BackendError,
+        /// The backend has paused the asset.
+        AssetPaused,
+        /// The payload is not a well-formed packet or ACK.
+        MalformedPayload,
+        /// The escrow refused to lock the amount, e.g. for an invalid proof or too low a
+        /// balance.
+        EscrowLockFailed,
+        /// The escrow refused to return a transfer's lock to its sender.
+        EscrowReleaseFailed,
+        /// The escrow refused to burn a transfer's lock.
+        BurnFailed,
+        /// The inbound packet was already processed, or is older than what is remembered.
+        AlreadyProcessed,
+        /// The ACK does not match the pending transfer's destination, or one was already
+        /// recorded.
+        UnexpectedAck,
+        /// The packet, ACK or transfer names a para other than the one `XcmOrigin`
+        /// yielded.
+        ParaMismatch,
+        /// The destination has not acknowledged a successful mint.
+        NotDelivered,
+        /// The asset has no mapping for the other para.
+        UnmappedAsset,
+        /// The remote asset id is already mapped to another local asset.
+        RemoteAssetTaken,
+        /// The asset's outbound cap for this epoch would be exceeded.
+        OutboundCapExceeded,
+        /// The asset caps disclosed amounts, but no disclosure proof was given.
+        DisclosureRequired,
+        /// Amounts can only be capped for assets with an auditor key.
+        NotAudited,
+        /// The channel to or from that para is paused.
+        ChannelPaused,
+        /// The packet exceeds the channel's payload limit.
+        PayloadTooLarge,
+        /// A channel's payload limit cannot exceed `MaxBridgePayload`.
+        InvalidChannel,
+        /// No packet of that transfer is queued.
+        NotQueued,
+        /// The packet's sequence number is behind the next one expected from its source,
+        /// or ahead of it on a strict channel.
+        OutOfOrder,
+        /// The asset or token has no Ethereum mapping.
+        UnmappedToken,
+        /// The token contract is already mapped to another asset.
+        TokenTaken,
+        /// The exporter refused the packet.
+        ExportFailed,
+        /// The payload is not a well-formed packet from Ethereum.
+        InvalidEthereumPacket,
+        /// `AckVerifier` rejected the evidence for the ACK.
+        InvalidEvidence,
     }
 
     // --------------------------- Helpers ----------------------------------------------
 
     impl<T: Config> Pallet<T> {
-        #[inline]
-        pub fn burn_account() -> T::AccountId {
-            T::BurnPalletId::get().into_account_truncating()
+        /// Where outbound transfer `id` stands; `None` if it was never sent from here.
+        pub fn transfer_status(id: TransferId) -> Option<BridgeTransferStatus> {
+            if let Some(status) = Settled::<T>::get(id) {
+                return Some(status);
+            }
+            let rec = Pending::<T>::get(id)?;
+            let refundable = match rec.delivered {
+                Some(delivered) => !delivered,
+                None => frame_system::Pallet::<T>::block_number() >= rec.deadline,
+            };
+            Some(if refundable {
+                BridgeTransferStatus::Expired
+            } else {
+                BridgeTransferStatus::Pending
+            })
+        }
+
+        /// blake2-256 of `packet` with its `sequence` zeroed, so source and destination
+        /// hash the same bytes however late the packet left.
+        pub fn packet_hash(packet: &BridgePacket<T::AccountId, T::AssetId>) -> [u8; 32] {
+            let mut packet = packet.clone();
+            packet.sequence = 0;
+            BlakeTwo256::hash_of(&packet).into()
         }
 
         #[inline]
@@ -186,6 +743,261 @@ pub mod pallet {
             NextTransferId::<T>::put(id.wrapping_add(1));
             id
         }
+
+        #[inline]
+        fn ensure_not_paused(asset: T::AssetId) -> Result<(), Error<T>> {
+            ensure!(!T::Backend::is_paused(asset), Error::<T>::AssetPaused);
+            Ok(())
+        }
+
+        /// Check `payload` may cross the channel to or from `para`, returning the fee
+        /// a send pays and the execution it buys there.
+        fn open_channel(
+            para: u32,
+            payload: &[u8],
+        ) -> Result<(NativeBalanceOf<T>, Option<ExecutionFeeOf<T>>), DispatchError> {
+            let Some(channel) = Channels::<T>::get(para) else {
+                return Ok((Default::default(), None));
+            };
+            ensure!(
+                channel.status == ChannelStatus::Open,
+                Error::<T>::ChannelPaused
+            );
+            ensure!(
+                payload.len() <= channel.max_payload as usize,
+                Error::<T>::PayloadTooLarge
+            );
+            Ok((channel.fee, channel.execution))
+        }
+
+        fn set_status(para: u32, status: ChannelStatus) {
+            Channels::<T>::mutate(para, |channel| {
+                channel
+                    .get_or_insert(ChannelConfig {
+                        status,
+                        max_payload: T::MaxBridgePayload::get(),
+                        fee: Default::default(),
+                        execution: None,
+                        ordering: Default::default(),
+                    })
+                    .status = status
+            });
+        }
+
+        /// Hand `packet` to the messenger under the next sequence number for `dest_para`,
+        /// which only advances if the messenger takes it.
+        fn send_packet(
+            dest_para: u32,
+            packet: &mut BridgePacket<T::AccountId, T::AssetId>,
+            execution: Option<ExecutionFeeOf<T>>,
+        ) -> bool {
+            packet.sequence = OutboundSequence::<T>::get(dest_para);
+            if T::Messenger::send(dest_para, packet.encode(), execution).is_err() {
+                return false;
+            }
+            OutboundSequence::<T>::insert(dest_para, packet.sequence.saturating_add(1));
+            true
+        }
+
+        /// Record the destination's `ack` on its pending transfer.
+        fn apply_ack(ack: &BridgeAck) -> DispatchResult {
+            Pending::<T>::try_mutate(ack.transfer_id, |rec| -> DispatchResult {
+                let rec = rec.as_mut().ok_or(Error::<T>::NotFound)?;
+                ensure!(!rec.completed, Error::<T>::AlreadyCompleted);
+                ensure!(
+                    rec.dest_para == ack.dest_para && rec.delivered.is_none(),
+                    Error::<T>::UnexpectedAck
+                );
+                rec.delivered = Some(ack.success);
+                Ok(())
+            })?;
+            Self::deposit_event(Event::TransferAcknowledged {
+                id: ack.transfer_id,
+                success: ack.success,
+            });
+            Ok(())
+        }
+
+        /// Burn transfer `id`'s escrow once the destination minted it. Returns the
+        /// sender.
+        fn finalize(id: TransferId) -> Result<T::AccountId, DispatchError> {
+            let rec = Pending::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+            ensure!(!rec.completed, Error::<T>::AlreadyCompleted);
+            Self::ensure_not_paused(rec.asset)?;
+            T::Escrow::escrow_burn(rec.escrow_id).map_err(|e| {
+                log::error!(
+                    target: LOG_TARGET,
+                    "burning escrow {} of transfer {id} failed: {e:?}",
+                    rec.escrow_id,
+                );
+                Error::<T>::BurnFailed
+            })?;
+            Pending::<T>::remove(id);
+            Settled::<T>::insert(id, BridgeTransferStatus::Confirmed);
+            Self::deposit_event(Event::OutboundTransferConfirmed {
+                id,
+                asset: rec.asset,
+            });
+            Ok(rec.from)
+        }
+
+        /// Pay `relayer` the `RelayerReward`, if the fee pot still holds it. An empty pot
+        /// does not fail the settlement.
+        fn reward(relayer: T::AccountId) {
+            let amount = T::RelayerReward::get();
+            if amount.is_zero() {
+                return;
+            }
+            let paid = T::Currency::transfer(
+                &T::FeeCollector::get(),
+                &relayer,
+                amount,
+                Preservation::Preserve,
+            );
+            match paid {
+                Ok(_) => Self::deposit_event(Event::RelayerRewarded { relayer, amount }),
+                Err(_) => Self::deposit_event(Event::RelayerRewardUnpaid { relayer, amount }),
+            }
+        }
+
+        /// Record that queued transfer `id`'s packet left. Its deadline runs from now,
+        /// as the destination only sees it from here on.
+        fn mark_sent(id: TransferId) {
+            Outbox::<T>::remove(id);
+            let deadline = frame_system::Pallet::<T>::block_number() + T::DefaultTimeout::get();
+            Pending::<T>::mutate(id, |rec| {
+                if let Some(rec) = rec {
+                    rec.deadline = deadline;
+                }
+            });
+            Self::deposit_event(Event::OutboundSent { id });
+        }
+
+        /// Retry queued packets as far as `remaining_weight` allows. Packets of paused
+        /// channels wait without using up attempts.
+        pub(crate) fn retry_outbound(remaining_weight: Weight) -> Weight {
+            let per_packet = T::WeightInfo::resend();
+            let budget: usize = remaining_weight
+                .checked_div_per_component(&per_packet)
+                .unwrap_or(u64::MAX)
+                .try_into()
+                .unwrap_or(usize::MAX);
+            let queued: Vec<_> = Outbox::<T>::iter().take(budget).collect();
+            let used = per_packet.saturating_mul(queued.len() as u64);
+
+            for (id, mut packet) in queued {
+                let Ok((_, execution)) =
+                    Self::open_channel(packet.dest_para, &packet.payload.encode())
+                else {
+                    continue;
+                };
+                if Self::send_packet(packet.dest_para, &mut packet.payload, execution) {
+                    Self::mark_sent(id);
+                    continue;
+                }
+                packet.attempts.saturating_inc();
+                if packet.attempts < T::MaxSendAttempts::get() {
+                    Self::deposit_event(Event::OutboundRetryFailed {
+                        id,
+                        attempts: packet.attempts,
+                    });
+                    Outbox::<T>::insert(id, packet);
+                } else {
+                    Outbox::<T>::remove(id);
+                    Pending::<T>::mutate(id, |rec| {
+                        if let Some(rec) = rec {
+                            rec.delivered = Some(false);
+                        }
+                    });
+                    Self::deposit_event(Event::OutboundAbandoned { id });
+                }
+            }
+            used
+        }
+
+        /// Count an outbound transfer of `asset` against its cap, if it has one, once
+        /// its lock is in. `disclosure` proves the amount `who` sent under the lock's
+        /// auditor handle when the cap limits amounts, which it only does while the
+        /// asset has an auditor key.
+        fn use_outbound_cap(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            disclosure: Option<InputProof>,
+        ) -> DispatchResult {
+            let Some(cap) = OutboundCaps::<T>::get(asset) else {
+                return Ok(());
+            };
+            let epoch = frame_system::Pallet::<T>::block_number() / T::RateLimitEpoch::get();
+            let mut usage = OutboundUsages::<T>::get(asset);
+            if usage.epoch != epoch {
+                usage = OutboundUsage {
+                    epoch,
+                    ..Default::default()
+                };
+            }
+
+            usage.transfers = usage.transfers.saturating_add(1);
+            ensure!(
+                usage.transfers <= cap.max_transfers,
+                Error::<T>::OutboundCapExceeded
+            );
+            let mut filled = usage.transfers == cap.max_transfers;
+            if let Some(max_amount) = cap.max_amount.filter(|_| T::Backend::is_audited(asset)) {
+                let proof = disclosure.ok_or(Error::<T>::DisclosureRequired)?;
+                let amount = T::Backend::disclose_audited(asset, who, proof)?;
+                usage.amount = usage.amount.saturating_add(amount);
+                ensure!(usage.amount <= max_amount, Error::<T>::OutboundCapExceeded);
+                filled |= usage.amount == max_amount;
+            }
+            OutboundUsages::<T>::insert(asset, usage);
+            // A send refused for the cap rolls its events back, so announce the limit
+            // on the send that fills it
+            if filled {
+                Self::deposit_event(Event::OutboundLimitReached { asset, epoch });
+            }
+            Ok(())
+        }
+
+        /// Forget the inbound packets whose retention has passed, as far as
+        /// `remaining_weight` allows, raising each source's floor past them.
+        pub(crate) fn prune_inbound(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let per_block = db.reads(1);
+            let per_packet = T::WeightInfo::prune_inbound();
+            let mut used = db.reads_writes(1, 1);
+            if remaining_weight.any_lt(used.saturating_add(per_block)) {
+                return Weight::zero();
+            }
+
+            let mut block = NextInboundPrune::<T>::get();
+            while block <= now && used.saturating_add(per_block).all_lte(remaining_weight) {
+                used.saturating_accrue(per_block);
+                let budget: usize = remaining_weight
+                    .saturating_sub(used)
+                    .checked_div_per_component(&per_packet)
+                    .unwrap_or(u64::MAX)
+                    .try_into()
+                    .unwrap_or(usize::MAX);
+                let due: Vec<_> = InboundExpiry::<T>::iter_key_prefix(block)
+                    .take(budget.saturating_add(1))
+                    .collect();
+                let finished = due.len() <= budget;
+                for (para, id) in due.into_iter().take(budget) {
+                    used.saturating_accrue(per_packet);
+                    InboundExpiry::<T>::remove(block, (para, id));
+                    InboundProcessed::<T>::remove(para, id);
+                    InboundFloor::<T>::mutate(para, |floor| {
+                        *floor = (*floor).max(id.saturating_add(1))
+                    });
+                }
+                if !finished {
+                    break;
+                }
+                block.saturating_inc();
+            }
+            NextInboundPrune::<T>::put(block);
+            used
+        }
     }
 
     // --------------------------- Calls -------------------------------------------------
@@ -200,11 +1012,10 @@ pub mod pallet {
         ///    to accept/mint/credit the ciphertext (`accept_envelope` is opaque).
         ///
         /// Later:
-        /// - Destination responds (via HRMP → runtime origin) calling `confirm_success`
-        ///   with proofs to move escrow → burn account and then burn.
+        /// - Destination acknowledges the packet, and `confirm_success` burns the escrow.
         /// - Or the sender cancels after the deadline with `cancel_and_refund`.
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::send())]
+        #[pallet::weight(T::WeightInfo::send().saturating_add(T::WeightInfo::disclose()))]
         #[transactional]
         pub fn send_confidential(
             origin: T::RuntimeOrigin,
@@ -216,26 +1027,59 @@ pub mod pallet {
             lock_proof: InputProof,
             // Opaque envelope/proof bytes for the **destination** chain to accept/credit.
             accept_envelope: InputProof,
+            // The sender's disclosure of `encrypted_amount` under the auditor handle
+            // `lock_proof` carries, for assets whose `OutboundCap` limits amounts.
+            disclosure: Option<InputProof>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(T::SelfParaId::get() != dest_para, Error::<T>::NoSelfBridge);
+            Self::ensure_not_paused(asset)?;
+            let remote_asset =
+                AssetMappings::<T>::get(asset, dest_para).ok_or(Error::<T>::UnmappedAsset)?;
             let id = Self::new_transfer_id();
-            let packet = BridgePacket::<T::AccountId, T::AssetId> {
+            let mut packet = BridgePacket::<T::AccountId, T::AssetId> {
                 transfer_id: id,
                 dest_account: dest_account.clone(),
-                asset,
+                asset: remote_asset,
                 encrypted_amount,
                 accept_envelope,
+                source_para: T::SelfParaId::get(),
+                // Assigned when the messenger takes the packet
+                sequence: 0,
             };
+            // Lock before sending: a message already handed to the messenger cannot be
+            // recalled if the lock then fails
+            let escrow_id =
+                T::Escrow::escrow_lock(asset, &who, encrypted_amount, BRIDGE_PURPOSE, lock_proof)
+                    .map_err(|_| Error::<T>::EscrowLockFailed)?;
+            // Disclosed against the auditor handle the lock just logged
+            Self::use_outbound_cap(asset, &who, disclosure)?;
             let payload = packet.encode();
             ensure!(
-                T::Messenger::send(dest_para, payload).is_ok(),
-                Error::<T>::MessengerFailed
+                payload.len() <= T::MaxBridgePayload::get() as usize,
+                Error::<T>::PayloadTooLarge
             );
-            T::Escrow::escrow_lock(asset, &who, encrypted_amount, lock_proof)
-                .map_err(|_| Error::<T>::BackendError)?;
+            let (fee, execution) = Self::open_channel(dest_para, &payload)?;
+            let payload_hash = Self::packet_hash(&packet);
+            if !fee.is_zero() {
+                T::Currency::transfer(&who, &T::FeeCollector::get(), fee, Preservation::Preserve)?;
+            }
+            // Funds are escrowed either way: a refused packet waits for a retry instead
+            // of making the sender re-sign and re-prove
+            let sent = Self::send_packet(dest_para, &mut packet, execution);
+            if !sent {
+                Outbox::<T>::insert(
+                    id,
+                    QueuedPacket {
+                        dest_para,
+                        payload: packet,
+                        attempts: 1,
+                    },
+                );
+            }
             // Insert Pending Transfer Into Storage
             let deadline = <frame_system::Pallet<T>>::block_number() + T::DefaultTimeout::get();
+            let dest_account_hash = BlakeTwo256::hash_of(&dest_account).into();
             Pending::<T>::insert(
                 id,
                 PendingTransfer::<T::AccountId, T::AssetId, BlockNumberFor<T>> {
@@ -246,6 +1090,8 @@ pub mod pallet {
                     encrypted_amount,
                     deadline,
                     completed: false,
+                    escrow_id,
+                    delivered: None,
                 },
             );
             Self::deposit_event(Event::OutboundTransferInitiated {
@@ -253,60 +1099,50 @@ pub mod pallet {
                 from: who,
                 dest_para,
                 asset,
+                dest_account_hash,
+                deadline,
+                payload_hash,
             });
+            if !sent {
+                Self::deposit_event(Event::OutboundQueued { id });
+            }
             Ok(())
         }
 
         /// Finalize a successful outbound transfer.
         ///
-        /// Expected to be called from an XCM/HRMP verified origin on the source chain
-        /// after the destination has credited/minted the ciphertext.
+        /// Expected to be called by the destination para, through `XcmOrigin`, after it
+        /// has credited/minted the ciphertext, or by anyone once
+        /// `handle_ack` recorded a successful delivery. A caller other than the sender
+        /// earns the `RelayerReward`.
         ///
-        /// Steps (source chain):
-        /// 1) Move escrowed ciphertext to this pallet’s **burn account** using the provided
-        ///    `release_proof` (escrow_release → underlying backend transfer).
-        /// 2) Burn from the burn account using `burn_proof`.
-        ///
-        /// If both succeed, the pending record is cleared.
+        /// Burns the escrowed ciphertext straight from escrow (`Escrow::escrow_burn`), so
+        /// no proof is needed, and clears the pending record.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::confirm_success())]
         #[transactional]
-        pub fn confirm_success(
-            origin: T::RuntimeOrigin,
-            id: TransferId,
-            // Proof to release escrow → burn account (a standard confidential transfer proof).
-            release_proof: InputProof,
-            // Proof to burn from burn account (backend burn proof).
-            burn_proof: InputProof,
-        ) -> DispatchResult {
-            T::XcmOrigin::ensure_origin(origin)?;
-
-            let rec = Pending::<T>::get(id).ok_or(Error::<T>::NotFound)?;
-            ensure!(!rec.completed, Error::<T>::AlreadyCompleted);
-
-            let burn_acc = <Pallet<T>>::burn_account();
+        pub fn confirm_success(origin: T::RuntimeOrigin, id: TransferId) -> DispatchResult {
+            let signer = match ensure_signed(origin.clone()) {
+                Ok(who) => Some(who),
+                Err(_) => {
+                    let para = T::XcmOrigin::ensure_origin(origin)?;
+                    let rec = Pending::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+                    ensure!(rec.dest_para == para, Error::<T>::ParaMismatch);
+                    None
+                }
+            };
 
-            let res1 = T::Escrow::escrow_release(
-                rec.asset,
-                &burn_acc,
-                rec.encrypted_amount,
-                release_proof,
-            );
-            if res1.is_err() {
-                return Err(Error::<T>::AlreadyCompleted.into());
+            if signer.is_some() {
+                let rec = Pending::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+                ensure!(rec.delivered == Some(true), Error::<T>::NotDelivered);
             }
 
-            let res2 =
-                T::Backend::burn_encrypted(rec.asset, &burn_acc, rec.encrypted_amount, burn_proof);
-            if res2.is_err() {
-                return Err(Error::<T>::NotFound.into());
+            let sender = Self::finalize(id)?;
+            if let Some(who) = signer {
+                if who != sender {
+                    Self::reward(who);
+                }
             }
-            Pending::<T>::remove(id);
-
-            Self::deposit_event(Event::OutboundTransferConfirmed {
-                id,
-                asset: rec.asset,
-            });
             Ok(())
         }
 
@@ -314,13 +1150,17 @@ pub mod pallet {
         /// origin (runtime choice).
         ///
         /// Steps:
-        /// - If called by the original sender and the deadline has passed, refund escrow → sender.
-        /// - If called by `XcmOrigin` at any time, refund escrow → sender.
+        /// - If called by the original sender once the destination acknowledged a failed
+        ///   mint, or after the deadline unless it acknowledged a successful one, refund
+        ///   escrow → sender.
+        /// - If called by `XcmOrigin` for the destination para, at any time, refund
+        ///   escrow → sender.
         ///
         /// Requires a transfer proof (`refund_proof`) to move ciphertext from escrow
         /// back to the original `from`.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::cancel_and_refund())]
+        #[transactional]
         pub fn cancel_and_refund(
             origin: T::RuntimeOrigin,
             id: TransferId,
@@ -332,21 +1172,41 @@ pub mod pallet {
             ensure!(!rec.completed, Error::<T>::AlreadyCompleted);
 
             // Two options for authority:
-            // 1) Original sender *after* deadline.
-            // 2) Privileged confirm origin (e.g., an XCM admin) at any time.
+            // 1) Original sender after a failure ACK, or *after* deadline without a
+            //    success ACK (the funds were minted on the destination).
+            // 2) The destination para, through `XcmOrigin`, at any time.
             if let Ok(who) = ensure_signed(caller.clone()) {
                 ensure!(who == rec.from, Error::<T>::NotSender);
-                let now = <frame_system::Pallet<T>>::block_number();
-                ensure!(now >= rec.deadline, Error::<T>::NotExpired);
+                match rec.delivered {
+                    Some(false) => {}
+                    Some(true) => return Err(Error::<T>::NotExpired.into()),
+                    None => {
+                        let now = <frame_system::Pallet<T>>::block_number();
+                        ensure!(now >= rec.deadline, Error::<T>::NotExpired);
+                    }
+                }
             } else {
-                // If not signed, require the confirm origin.
-                T::XcmOrigin::ensure_origin(caller)?;
+                // If not signed, require the destination para itself.
+                let para = T::XcmOrigin::ensure_origin(caller)?;
+                ensure!(para == rec.dest_para, Error::<T>::ParaMismatch);
             }
 
-            // Refund escrow → original sender.
-            T::Escrow::escrow_refund(rec.asset, &rec.from, rec.encrypted_amount, refund_proof)
-                .map_err(|_| Error::<T>::BackendError)?;
+            Self::ensure_not_paused(rec.asset)?;
+            // Refund escrow → original sender. A packet still queued must never leave.
+            // Usually a bad refund proof, but the lock may also be gone
+            T::Escrow::escrow_refund(rec.escrow_id, rec.encrypted_amount, refund_proof).map_err(
+                |e| {
+                    log::error!(
+                        target: LOG_TARGET,
+                        "refunding escrow {} of transfer {id} failed: {e:?}",
+                        rec.escrow_id,
+                    );
+                    Error::<T>::EscrowReleaseFailed
+                },
+            )?;
             Pending::<T>::remove(id);
+            Outbox::<T>::remove(id);
+            Settled::<T>::insert(id, BridgeTransferStatus::Refunded);
 
             Self::deposit_event(Event::OutboundTransferRefunded {
                 id,
@@ -359,31 +1219,391 @@ pub mod pallet {
         /// THIS IS INTENTIONALLY UNSAFE FOR DEMO PURPOSES DO NOT USE IN PRODUCTION
         /// Called automatically when an XCM Transact arrives with
         /// `RuntimeCall::ConfidentialBridge::on_incoming_packet`.
+        ///
+        /// The packet's `source_para` must be the para `XcmOrigin` yields, or it fails
+        /// with `ParaMismatch`.
+        ///
+        /// Each `(source_para, transfer_id)` is minted at most once; a replayed packet
+        /// fails with `AlreadyProcessed`, and one out of sequence with `OutOfOrder`. Every other packet is answered with a
+        /// `BridgeAck`: a packet that cannot be minted is still recorded as processed and
+        /// acknowledged as failed, so the source can refund it.
         #[pallet::call_index(3)] // just ensure unique index
         #[pallet::weight(T::WeightInfo::cancel_and_refund())]
         pub fn receive_confidential(
             origin: T::RuntimeOrigin,
             payload: BoundedVec<u8, T::MaxBridgePayload>, //make constant MAX_BRIDGE_PAYLOAD = 1024
         ) -> DispatchResult {
-            T::XcmOrigin::ensure_origin(origin)?;
+            let source = T::XcmOrigin::ensure_origin(origin)?;
 
             // Decode the BridgePacket
             let packet: BridgePacket<T::AccountId, T::AssetId> =
                 parity_scale_codec::Decode::decode(&mut &payload[..])
-                    .map_err(|_| Error::<T>::BackendError)?;
-            // Mint encrypted balance locally
-            let minted = T::Backend::mint_encrypted(
-                packet.asset,
-                &packet.dest_account,
-                packet.accept_envelope,
-            )?;
+                    .map_err(|_| Error::<T>::MalformedPayload)?;
+            // Replay protection, sequencing and asset mappings are all per source
+            ensure!(packet.source_para == source, Error::<T>::ParaMismatch);
+            Self::ensure_not_paused(packet.asset)?;
+            let (para, id) = (packet.source_para, packet.transfer_id);
+            // The ACK travels back over the same channel and buys the same execution
+            let (_, execution) = Self::open_channel(para, &payload)?;
+            ensure!(
+                id >= InboundFloor::<T>::get(para)
+                    && !InboundProcessed::<T>::contains_key(para, id),
+                Error::<T>::AlreadyProcessed
+            );
+            // A packet behind the expected one was overtaken by a later packet, whose
+            // ACK may already have settled supply on the source
+            let expected = InboundSequence::<T>::get(para);
+            let in_order = match Channels::<T>::get(para).map(|c| c.ordering) {
+                Some(DeliveryOrder::Strict) => packet.sequence == expected,
+                _ => packet.sequence >= expected,
+            };
+            ensure!(in_order, Error::<T>::OutOfOrder);
+            // The source translated the asset into our id; we must accept it from there
+            ensure!(
+                AssetMappings::<T>::contains_key(packet.asset, para),
+                Error::<T>::UnmappedAsset
+            );
+            let payload_hash = Self::packet_hash(&packet);
+            let dest_account_hash = BlakeTwo256::hash_of(&packet.dest_account).into();
+            // Mint encrypted balance locally, discarding a failed mint's partial writes
+            let minted = frame_support::storage::with_storage_layer(|| {
+                T::Backend::mint_encrypted(
+                    packet.asset,
+                    &packet.dest_account,
+                    packet.accept_envelope,
+                )
+            });
+
+            let now = frame_system::Pallet::<T>::block_number();
+            InboundSequence::<T>::insert(para, packet.sequence.saturating_add(1));
+            InboundProcessed::<T>::insert(para, id, now);
+            InboundExpiry::<T>::insert(
+                now.saturating_add(T::InboundRetention::get()),
+                (para, id),
+                (),
+            );
+
+            let ack = BridgeAck {
+                transfer_id: id,
+                dest_para: T::SelfParaId::get(),
+                success: minted.is_ok(),
+            };
+            // An unsent ACK would leave the source waiting for its deadline, so revert
+            // the mint instead and let the packet be retried
+            ensure!(
+                T::Messenger::send_ack(para, ack.encode(), execution).is_ok(),
+                Error::<T>::MessengerFailed
+            );
+
+            if packet.sequence > expected {
+                Self::deposit_event(Event::InboundSequenceSkipped {
+                    source_para: para,
+                    expected,
+                    received: packet.sequence,
+                });
+            }
+            match minted {
+                Ok(minted) => Self::deposit_event(Event::InboundTransferExecuted {
+                    id,
+                    source_para: para,
+                    asset: packet.asset,
+                    minted,
+                    dest_account_hash,
+                    payload_hash,
+                }),
+                Err(_) => Self::deposit_event(Event::InboundTransferFailed {
+                    id,
+                    source_para: para,
+                    payload_hash,
+                }),
+            }
+
+            Ok(())
+        }
+
+        /// Record the destination's acknowledgment of an outbound transfer.
+        ///
+        /// Sent back by the destination's `receive_confidential` (via
+        /// `HrmpMessenger::send_ack`). A success ACK lets the sender `confirm_success`
+        /// and stops them refunding; a failure ACK lets them `cancel_and_refund` at once.
+        /// Only the para `XcmOrigin` yields may acknowledge, as the ACK's `dest_para`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::handle_ack())]
+        pub fn handle_ack(
+            origin: T::RuntimeOrigin,
+            payload: BoundedVec<u8, T::MaxBridgePayload>,
+        ) -> DispatchResult {
+            let source = T::XcmOrigin::ensure_origin(origin)?;
+
+            let ack: BridgeAck = parity_scale_codec::Decode::decode(&mut &payload[..])
+                .map_err(|_| Error::<T>::MalformedPayload)?;
+            ensure!(ack.dest_para == source, Error::<T>::ParaMismatch);
+            Self::apply_ack(&ack)
+        }
+
+        /// Map local `asset` to its id `remote` on `para`, replacing any earlier mapping
+        /// of `asset` there. `remote` may stand for one local asset only.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::map_asset())]
+        pub fn map_asset(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            para: u32,
+            remote: T::AssetId,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                RemoteAssets::<T>::get(para, remote).is_none_or(|local| local == asset),
+                Error::<T>::RemoteAssetTaken
+            );
+            if let Some(old) = AssetMappings::<T>::get(asset, para) {
+                RemoteAssets::<T>::remove(para, old);
+            }
+            AssetMappings::<T>::insert(asset, para, remote);
+            RemoteAssets::<T>::insert(para, remote, asset);
+            Self::deposit_event(Event::AssetMapped {
+                asset,
+                para,
+                remote,
+            });
+            Ok(())
+        }
+
+        /// Stop `asset` crossing to or from `para`. Transfers already in flight still
+        /// settle: refunds and confirmations do not consult the mapping.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::unmap_asset())]
+        pub fn unmap_asset(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            para: u32,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let remote = AssetMappings::<T>::take(asset, para).ok_or(Error::<T>::UnmappedAsset)?;
+            RemoteAssets::<T>::remove(para, remote);
+            Self::deposit_event(Event::AssetUnmapped { asset, para });
+            Ok(())
+        }
+
+        /// Cap `asset`'s outbound transfers per `RateLimitEpoch`, or lift the cap with
+        /// `None`. Bounds what a compromised destination can pull off the shared supply.
+        /// Only assets with an auditor key can have their amounts capped.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_outbound_cap())]
+        pub fn set_outbound_cap(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            cap: Option<OutboundCap<T::Balance>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                cap.is_none_or(|cap| cap.max_amount.is_none() || T::Backend::is_audited(asset)),
+                Error::<T>::NotAudited
+            );
+            OutboundCaps::<T>::set(asset, cap);
+            Self::deposit_event(Event::OutboundCapSet { asset, cap });
+            Ok(())
+        }
+
+        /// Configure the channel to `para`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::set_channel())]
+        pub fn set_channel(
+            origin: T::RuntimeOrigin,
+            para: u32,
+            config: ChannelConfigOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                config.max_payload <= T::MaxBridgePayload::get(),
+                Error::<T>::InvalidChannel
+            );
+            Channels::<T>::insert(para, config.clone());
+            Self::deposit_event(Event::ChannelSet { para, config });
+            Ok(())
+        }
 
-            Self::deposit_event(Event::InboundTransferExecuted {
-                id: packet.transfer_id,
-                asset: packet.asset,
+        /// Refuse sends to and packets from `para`, e.g. when it misbehaves. ACKs and
+        /// the settling calls stay open, so transfers in flight still complete.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_channel_status())]
+        pub fn pause_channel(origin: T::RuntimeOrigin, para: u32) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::set_status(para, ChannelStatus::Paused);
+            Self::deposit_event(Event::ChannelPaused { para });
+            Ok(())
+        }
+
+        /// Let packets flow to and from `para` again.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_channel_status())]
+        pub fn resume_channel(origin: T::RuntimeOrigin, para: u32) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::set_status(para, ChannelStatus::Open);
+            Self::deposit_event(Event::ChannelResumed { para });
+            Ok(())
+        }
+
+        /// Send queued transfer `id`'s packet now. Anyone may call this; it fails, and
+        /// leaves the packet queued, if the messenger still refuses it.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::resend())]
+        pub fn resend(origin: T::RuntimeOrigin, id: TransferId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let mut packet = Outbox::<T>::get(id).ok_or(Error::<T>::NotQueued)?;
+            let (_, execution) = Self::open_channel(packet.dest_para, &packet.payload.encode())?;
+            ensure!(
+                Self::send_packet(packet.dest_para, &mut packet.payload, execution),
+                Error::<T>::MessengerFailed
+            );
+            Self::mark_sent(id);
+            Ok(())
+        }
+
+        /// Map local `asset` to the ERC-7984 contract `token` on Ethereum, or remove its
+        /// mapping with `None`. `token` may stand for one asset only.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::map_ethereum_token())]
+        pub fn map_ethereum_token(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            token: Option<EthAddress>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if let Some(token) = token {
+                ensure!(
+                    EthereumAssets::<T>::get(token).is_none_or(|local| local == asset),
+                    Error::<T>::TokenTaken
+                );
+            }
+            if let Some(old) = EthereumTokens::<T>::take(asset) {
+                EthereumAssets::<T>::remove(old);
+            }
+            if let Some(token) = token {
+                EthereumTokens::<T>::insert(asset, token);
+                EthereumAssets::<T>::insert(token, asset);
+            }
+            Self::deposit_event(Event::EthereumTokenMapped { asset, token });
+            Ok(())
+        }
+
+        /// Send `encrypted_amount` of `asset` to `recipient` on Ethereum.
+        ///
+        /// The amount is locked with `lock_proof` and burned from escrow straight away,
+        /// so it stays undisclosed; the packet carries `mint_proof`, which the asset's
+        /// ERC-7984 contract mints with. Nothing comes back to refund against, so the
+        /// send fails as a whole if the exporter refuses the packet.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::export_to_ethereum())]
+        #[transactional]
+        pub fn export_to_ethereum(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            recipient: EthAddress,
+            encrypted_amount: EncryptedAmount,
+            lock_proof: InputProof,
+            mint_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let token = EthereumTokens::<T>::get(asset).ok_or(Error::<T>::UnmappedToken)?;
+            Self::ensure_not_paused(asset)?;
+
+            let escrow_id =
+                T::Escrow::escrow_lock(asset, &who, encrypted_amount, BRIDGE_PURPOSE, lock_proof)
+                    .map_err(|_| Error::<T>::EscrowLockFailed)?;
+            T::Escrow::escrow_burn(escrow_id).map_err(|e| {
+                log::error!(
+                    target: LOG_TARGET,
+                    "burning escrow {escrow_id} of an Ethereum export failed: {e:?}",
+                );
+                Error::<T>::BurnFailed
+            })?;
+
+            let nonce = NextEthereumNonce::<T>::mutate(|n| {
+                let cur = *n;
+                *n = n.wrapping_add(1);
+                cur
+            });
+            let packet = EthereumPacket {
+                nonce,
+                account: EthereumPacket::account_of(recipient),
+                token,
+                amount: encrypted_amount,
+                proof: mint_proof.into_inner(),
+            };
+            T::Exporter::export(packet.abi_encode()).map_err(|_| Error::<T>::ExportFailed)?;
+
+            Self::deposit_event(Event::ExportedToEthereum {
+                nonce,
+                from: who,
+                asset,
+                recipient,
+                amount: encrypted_amount,
+            });
+            Ok(())
+        }
+
+        /// Mint a transfer the Ethereum-side contract burned, delivered by
+        /// `EthereumOrigin` as an ABI-encoded [`EthereumPacket`]. Each nonce is minted
+        /// at most once.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::receive_from_ethereum())]
+        pub fn receive_from_ethereum(
+            origin: T::RuntimeOrigin,
+            payload: BoundedVec<u8, T::MaxBridgePayload>,
+        ) -> DispatchResult {
+            T::EthereumOrigin::ensure_origin(origin)?;
+
+            let packet =
+                EthereumPacket::abi_decode(&payload).ok_or(Error::<T>::InvalidEthereumPacket)?;
+            ensure!(
+                !EthereumProcessed::<T>::contains_key(packet.nonce),
+                Error::<T>::AlreadyProcessed
+            );
+            let asset = EthereumAssets::<T>::get(packet.token).ok_or(Error::<T>::UnmappedToken)?;
+            Self::ensure_not_paused(asset)?;
+            let to = T::AccountId::decode(&mut &packet.account[..])
+                .map_err(|_| Error::<T>::InvalidEthereumPacket)?;
+            let proof: InputProof = packet
+                .proof
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidEthereumPacket)?;
+
+            let minted = T::Backend::mint_encrypted(asset, &to, proof)?;
+            EthereumProcessed::<T>::insert(packet.nonce, ());
+            Self::deposit_event(Event::ReceivedFromEthereum {
+                nonce: packet.nonce,
+                to,
+                asset,
                 minted,
             });
+            Ok(())
+        }
+
+        /// Relay the destination's ACK of an outbound transfer with `evidence` that it
+        /// processed the packet, for `AckVerifier` to check. A success ACK settles the
+        /// transfer at once and earns a caller other than the sender the
+        /// `RelayerReward`; a failure ACK lets the sender refund, as through `handle_ack`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::submit_ack())]
+        #[transactional]
+        pub fn submit_ack(
+            origin: T::RuntimeOrigin,
+            payload: BoundedVec<u8, T::MaxBridgePayload>,
+            evidence: BoundedVec<u8, T::MaxBridgePayload>,
+        ) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+            let ack: BridgeAck = parity_scale_codec::Decode::decode(&mut &payload[..])
+                .map_err(|_| Error::<T>::MalformedPayload)?;
+            ensure!(
+                T::AckVerifier::verify(&ack, &evidence),
+                Error::<T>::InvalidEvidence
+            );
 
+            Self::apply_ack(&ack)?;
+            if ack.success && Self::finalize(ack.transfer_id)? != relayer {
+                Self::reward(relayer);
+            }
             Ok(())
         }
     }
@@ -1,11 +1,12 @@
 use crate::pallet as pallet_confidential_bridge;
 use confidential_assets_primitives::{
-    ConfidentialBackend, EncryptedAmount, HrmpMessenger, InputProof, NetworkIdProvider,
-    PublicKeyBytes, ZkVerifier,
+    AckVerifier, BridgeAck, ConfidentialBackend, EncryptedAmount, ExecutionFee, HrmpMessenger,
+    InputProof, MessageExporter, NetworkIdProvider, ProofKind, ProofSystem, ProofSystemId,
+    PublicKeyBytes, VerifyError, ZkVerifier, seal_proof,
 };
 use frame_support::{
     PalletId, construct_runtime, derive_impl, parameter_types,
-    traits::{ConstU32, ConstU64},
+    traits::{ConstBool, ConstU32, ConstU64},
 };
 use sp_runtime::BuildStorage;
 
@@ -14,7 +15,13 @@ pub type AssetId = u32;
 pub type Balance = u64;
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 pub const ASSET: AssetId = 7;
+/// Compressed Ristretto basepoint, a valid point for mock auditor handles.
+pub const BASEPOINT: [u8; 32] = [
+    0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71, 0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00, 0x51, 0x5f,
+    0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d, 0xb6, 0xa6, 0x59, 0x45, 0xe0, 0x8d, 0x2d, 0x76,
+];
 
 // --- Mock Network ID Provider -----------------------------------------------
 pub struct MockNetworkId;
@@ -32,10 +39,24 @@ impl NetworkIdProvider for MockNetworkId {
 pub struct AlwaysOkVerifier;
 
 impl ZkVerifier for AlwaysOkVerifier {
-    type Error = ();
     type NetworkIdProvider = MockNetworkId;
+
+    // Any key is accepted as owned
+    fn verify_pubkey_ownership(
+        _account: &[u8],
+        _pk: &[u8],
+        _proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        Ok(())
+    }
+
     // Disclose encrypted amount -> constant u64 (e.g., 123)
-    fn disclose(_asset: &[u8], _pk: &[u8], _cipher: &[u8]) -> Result<u64, ()> {
+    fn disclose(
+        _asset: &[u8],
+        _pk: &[u8],
+        _cipher: &[u8],
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
         Ok(123)
     }
 
@@ -48,7 +69,7 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _delta_ct: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         Ok((vec![1u8; 32], vec![2u8; 32]))
     }
 
@@ -60,7 +81,7 @@ impl ZkVerifier for AlwaysOkVerifier {
         _pending_old: &[u8],
         _commits: &[[u8; 32]],
         _envelope: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         // Make pending_new zero so pallet removes PendingBalanceCommit on accept
         Ok((vec![3u8; 32], vec![0u8; 32]))
     }
@@ -72,8 +93,13 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _total_old: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), ()> {
-        Ok((vec![10u8; 32], vec![11u8; 32], [5u8; 64]))
+    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), VerifyError> {
+        // A real point: the pallet records what the mint added to the pending balance
+        Ok((
+            vec![14u8; 32],
+            vec![11u8; 32],
+            EncryptedAmount::new([5u8; 64]),
+        ))
     }
 
     // from_new_available, total_new, disclosed_u64
@@ -84,22 +110,138 @@ impl ZkVerifier for AlwaysOkVerifier {
         _total_old: &[u8],
         _amount_ct: &EncryptedAmount,
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, u64), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>, u64), VerifyError> {
         Ok((vec![20u8; 32], vec![21u8; 32], 42))
     }
+
+    // Auditor handle `(k·G, [b; 32])`, reusing the transfer's nonce point, for a body
+    // whose first byte `b` is non-zero; every blinding ciphertext is (G, G)
+    fn verify_auditor_handle(
+        _asset: &[u8],
+        _pk: &[u8],
+        _auditor_pk: &[u8],
+        ct: &EncryptedAmount,
+        _delta: (&[u8], &[u8]),
+        proof: &[u8],
+    ) -> Result<(EncryptedAmount, EncryptedAmount), VerifyError> {
+        match proof.first() {
+            Some(&b) if b != 0 => Ok((
+                EncryptedAmount::from_parts(ct.nonce_point(), [b; 32]),
+                EncryptedAmount::from_parts(BASEPOINT, BASEPOINT),
+            )),
+            _ => Err(VerifyError::LinkProofInvalid),
+        }
+    }
+}
+
+/// Length of a mock auditor handle body.
+pub const AUDITOR_PROOF_LEN: usize = 4;
+
+// Stands in for the ZK-ElGamal proof system and accepts any body length, except for
+// auditor handles, which must be fixed-length
+impl ProofSystem for AlwaysOkVerifier {
+    const ID: ProofSystemId = 0;
+
+    fn proof_len(kind: ProofKind) -> Option<(usize, usize)> {
+        match kind {
+            ProofKind::AuditorHandle => Some((AUDITOR_PROOF_LEN, AUDITOR_PROOF_LEN)),
+            _ => Some((0, usize::MAX)),
+        }
+    }
+}
+
+thread_local! {
+    /// Packets handed to `MockMessenger::send`, as `(dest_para, payload)`.
+    pub static SENT: core::cell::RefCell<Vec<(u32, Vec<u8>)>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+    /// ACKs handed to `MockMessenger::send_ack`, as `(dest_para, payload)`.
+    pub static SENT_ACKS: core::cell::RefCell<Vec<(u32, Vec<u8>)>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+    /// Execution bought by each packet or ACK sent, in sending order.
+    pub static SENT_FEES: core::cell::RefCell<Vec<Option<ExecutionFee<u32>>>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+    /// Whether `MockMessenger::send` refuses packets, as a congested channel would.
+    pub static SEND_FAILS: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+    /// Payloads handed to `MockExporter::export`.
+    pub static EXPORTED: core::cell::RefCell<Vec<Vec<u8>>> =
+        const { core::cell::RefCell::new(Vec::new()) };
 }
 
 pub struct MockMessenger;
 impl HrmpMessenger for MockMessenger {
+    type FeeAsset = u32;
+
     /// Send an opaque SCALE-encoded payload to `dest_para`.
-    fn send(_dest_para: u32, _payload: Vec<u8>) -> Result<(), ()> {
+    fn send(dest_para: u32, payload: Vec<u8>, fee: Option<ExecutionFee<u32>>) -> Result<(), ()> {
+        if SEND_FAILS.with(|fails| fails.get()) {
+            return Err(());
+        }
+        SENT_FEES.with(|fees| fees.borrow_mut().push(fee));
+        SENT.with(|sent| sent.borrow_mut().push((dest_para, payload)));
+        Ok(())
+    }
+
+    fn send_ack(
+        dest_para: u32,
+        payload: Vec<u8>,
+        fee: Option<ExecutionFee<u32>>,
+    ) -> Result<(), ()> {
+        SENT_FEES.with(|fees| fees.borrow_mut().push(fee));
+        SENT_ACKS.with(|acks| acks.borrow_mut().push((dest_para, payload)));
         Ok(())
     }
 }
 
+pub struct MockExporter;
+impl MessageExporter for MockExporter {
+    fn export(payload: Vec<u8>) -> Result<(), ()> {
+        EXPORTED.with(|exported| exported.borrow_mut().push(payload));
+        Ok(())
+    }
+}
+
+/// Drain the payloads exported so far.
+pub fn take_exported() -> Vec<Vec<u8>> {
+    EXPORTED.with(|exported| exported.take())
+}
+
+/// Accepts the evidence `b"final"` for any ACK.
+pub struct MockAckVerifier;
+impl AckVerifier for MockAckVerifier {
+    fn verify(_ack: &BridgeAck, evidence: &[u8]) -> bool {
+        evidence == b"final"
+    }
+}
+
+/// Make `MockMessenger::send` refuse (`true`) or take (`false`) packets.
+pub fn set_send_fails(fails: bool) {
+    SEND_FAILS.with(|f| f.set(fails));
+}
+
+/// Drain the packets sent so far.
+pub fn take_sent() -> Vec<(u32, Vec<u8>)> {
+    SENT.with(|sent| sent.take())
+}
+
+/// Drain the execution bought by the packets and ACKs sent so far.
+pub fn take_fees() -> Vec<Option<ExecutionFee<u32>>> {
+    SENT_FEES.with(|fees| fees.take())
+}
+
+/// Drain the ACKs sent so far.
+pub fn take_acks() -> Vec<(u32, Vec<u8>)> {
+    SENT_ACKS.with(|acks| acks.take())
+}
+
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Runtime {
     type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
 }
 
 impl pallet_zkhe::Config for Runtime {
@@ -107,37 +249,65 @@ impl pallet_zkhe::Config for Runtime {
     type AssetId = AssetId;
     type Balance = Balance;
     type Verifier = AlwaysOkVerifier;
+    type MaxClaimUtxos = ConstU32<16>;
+    type PendingTtl = ConstU64<100>;
+    type MaxPendingPerAccount = ConstU32<16>;
+    type PendingDeposit = ConstU64<0>;
+    type AccountDeposit = ConstU64<0>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RequireAssetConfig = ConstBool<false>;
+    type MaxAuditHistory = ConstU32<4>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 parameter_types! {
     pub const EscrowPalletId: PalletId = PalletId(*b"CaEscrow");
-    pub const BridgePalletId: PalletId = PalletId(*b"CaBridge");
 }
 impl pallet_confidential_escrow::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type Balance = Balance;
     type Backend = Zkhe;
+    type Ramp = ();
     type PalletId = EscrowPalletId;
+    type MaxApprovers = ConstU32<4>;
+    type ApprovalTtl = ConstU64<100>;
+    type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+parameter_types! {
+    /// Para that root stands in for as `XcmOrigin`.
+    pub static XcmPara: u32 = 2;
 }
 impl pallet_confidential_bridge::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type Balance = Balance;
     type Backend = Zkhe;
+    type Currency = Balances;
+    type FeeCollector = ConstU64<99>;
+    type RelayerReward = ConstU64<5>;
+    type AckVerifier = MockAckVerifier;
     type Escrow = ConfidentialEscrow;
     type Messenger = MockMessenger;
+    type Exporter = MockExporter;
+    type EthereumOrigin = frame_system::EnsureRoot<AccountId>;
     type MaxBridgePayload = ConstU32<1024>;
-    type BurnPalletId = BridgePalletId;
+    type MaxSendAttempts = ConstU32<3>;
+    type RateLimitEpoch = ConstU64<10>;
     type DefaultTimeout = ConstU64<10>;
+    type InboundRetention = ConstU64<20>;
     type SelfParaId = ConstU32<1>;
-    type XcmOrigin = frame_system::EnsureRoot<AccountId>;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type XcmOrigin = frame_system::EnsureRootWithSuccess<AccountId, XcmPara>;
     type WeightInfo = ();
 }
 
 construct_runtime!(
     pub enum Runtime {
         System: frame_system,
+        Balances: pallet_balances,
         Zkhe: pallet_zkhe,
         ConfidentialEscrow: pallet_confidential_escrow,
         ConfidentialBridge: pallet_confidential_bridge,
@@ -151,17 +321,41 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
         .unwrap();
     // nothing else needed in genesis
     let mut ext = sp_io::TestExternalities::new(t);
-    ext.execute_with(|| System::set_block_number(1));
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        // ASSET crosses to and from paras 2 and 3 under the same id
+        for para in [2, 3] {
+            crate::AssetMappings::<Runtime>::insert(ASSET, para, ASSET);
+            crate::RemoteAssets::<Runtime>::insert(para, ASSET, ASSET);
+        }
+    });
     ext
 }
 
 // Handy helpers
 pub fn set_pk(who: AccountId) {
     // Non-empty fake pk
-    Zkhe::set_public_key(&who, &[7u8; 64].to_vec().try_into().expect("bounded vec")).unwrap();
+    Zkhe::set_public_key(
+        &who,
+        &[7u8; 64].to_vec().try_into().expect("bounded vec"),
+        proof(&[]),
+    )
+    .unwrap();
 }
 
-// Construct InputProof from raw bytes using TryFrom<Vec<u8>>
+// Construct InputProof from raw bytes, enveloped for the mock proof system
 pub fn proof(bytes: &[u8]) -> InputProof {
-    bytes.to_vec().try_into().expect("bounded vec")
+    seal_proof(AlwaysOkVerifier::ID, bytes)
+        .try_into()
+        .expect("bounded vec")
+}
+
+// Transfer proof `bytes` followed by an auditor handle whose masked half is `[handle; 32]`
+pub fn audited_proof(bytes: &[u8], handle: u8) -> InputProof {
+    let mut v = seal_proof(AlwaysOkVerifier::ID, bytes);
+    v.extend(seal_proof(
+        AlwaysOkVerifier::ID,
+        &[handle; AUDITOR_PROOF_LEN],
+    ));
+    v.try_into().expect("bounded vec")
 }
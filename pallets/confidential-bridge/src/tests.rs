@@ -1,10 +1,18 @@
-use crate::{Error, Event, mock::*};
-use confidential_assets_primitives::EncryptedAmount;
-use frame_support::assert_ok;
+use crate::{
+    ChannelConfig, ChannelStatus, DeliveryOrder, Error, Event, InboundSequence, OutboundCap,
+    OutboundSequence, Outbox,
+    ethereum::{EthAddress, EthereumPacket},
+    mock::*,
+};
+use confidential_assets_primitives::{
+    BridgeAck, BridgePacket, Commitment, EncryptedAmount, ExecutionFee, InputProof,
+};
+use frame_support::{assert_ok, traits::ConstU32};
+use parity_scale_codec::{Decode, Encode};
 
 // helpers
 fn ct(b: u8) -> EncryptedAmount {
-    [b; 64]
+    EncryptedAmount::new([b; 64])
 }
 fn last_event() -> RuntimeEvent {
     frame_system::Pallet::<Runtime>::events()
@@ -36,6 +44,7 @@ fn send_confidential_initiates_and_records_pending() {
             amount,
             lock_proof,
             accept_envelope.clone(),
+            None,
         ));
 
         // Event: OutboundTransferInitiated with id 0 (first transfer), asset.
@@ -45,11 +54,18 @@ fn send_confidential_initiates_and_records_pending() {
                 from,
                 dest_para: dp,
                 asset: ev_asset,
+                dest_account_hash,
+                deadline,
+                payload_hash,
             }) => {
                 assert_eq!(id, 0);
                 assert_eq!(from, ALICE);
                 assert_eq!(dp, dest_para);
                 assert_eq!(ev_asset, asset);
+                assert_eq!(dest_account_hash, sp_io::hashing::blake2_256(&BOB.encode()));
+                assert_eq!(deadline, 11);
+                // The first packet to para 2 has sequence 0: the hash is of what was sent.
+                assert_eq!(payload_hash, sp_io::hashing::blake2_256(&take_sent()[0].1));
             }
             other => panic!("unexpected event: {other:?}"),
         }
@@ -64,6 +80,7 @@ fn send_confidential_initiates_and_records_pending() {
         // Deadline = block 1 + DefaultTimeout(10) = 11
         assert_eq!(rec.deadline, 11);
         assert!(!rec.completed);
+        assert_eq!(rec.escrow_id, 0);
     });
 }
 
@@ -83,6 +100,7 @@ fn send_confidential_rejects_self_bridge() {
             ct(1),
             proof(&[]),
             proof(&[]),
+            None,
         )
         .unwrap_err();
 
@@ -91,36 +109,65 @@ fn send_confidential_rejects_self_bridge() {
 }
 
 #[test]
-fn confirm_success_releases_to_burn_and_burns_then_clears_pending() {
+fn send_and_receive_refuse_paused_assets() {
     new_test_ext().execute_with(|| {
-        use pallet_zkhe::{NextPendingDepositId, PendingBalanceCommit, PendingDeposits};
+        set_pk(ALICE);
+        set_pk(BOB);
+        let escrow_acc = ConfidentialEscrow::escrow_account();
+        set_pk(escrow_acc);
+        assert_ok!(Zkhe::pause_asset(RuntimeOrigin::root(), ASSET));
 
-        // Setup: keys for ALICE (sender), escrow account (to hold), and burn account (to receive & burn).
+        let err = ConfidentialBridge::send_confidential(
+            RuntimeOrigin::signed(ALICE),
+            2,
+            BOB,
+            ASSET,
+            ct(1),
+            proof(&[]),
+            proof(&[]),
+        )
+        .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::AssetPaused.into());
+        assert!(ConfidentialBridge::pending(0).is_none());
+
+        let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound(0))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::AssetPaused.into());
+        assert!(take_acks().is_empty());
+    });
+}
+
+#[test]
+fn confirm_success_burns_from_escrow_then_clears_pending() {
+    new_test_ext().execute_with(|| {
+        use pallet_zkhe::{AvailableBalanceCommit, TotalSupplyCommit};
+
+        // Setup: keys for ALICE (sender) and the escrow account (to hold).
         set_pk(ALICE);
         let escrow_acc = ConfidentialEscrow::escrow_account();
         set_pk(escrow_acc);
-        let burn_acc = ConfidentialBridge::burn_account();
-        set_pk(burn_acc);
 
         // First, create a pending transfer via send_confidential (id = 0).
+        let amount = EncryptedAmount::from_parts(BASEPOINT, [3u8; 32]);
         assert_ok!(ConfidentialBridge::send_confidential(
             RuntimeOrigin::signed(ALICE),
             2,
             BOB,
             ASSET,
-            ct(7),
+            amount,
             proof(&[1]),
             proof(&[2, 2]),
+            None,
         ));
         // Sanity
         assert!(ConfidentialBridge::pending(0).is_some());
+        // What the lock credited the escrow account, which the burn subtracts
+        let locked = ConfidentialEscrow::escrow(0).expect("locked").remaining;
 
-        // Now confirm success as Root (XcmOrigin in mock is EnsureRoot)
+        // Now confirm success as the destination (root is para 2 in the mock); no proofs.
         assert_ok!(ConfidentialBridge::confirm_success(
             RuntimeOrigin::root(),
-            0,
-            proof(&[9, 9]), // release_proof
-            proof(&[8, 8]), // burn_proof
+            0
         ));
 
         // Event emitted
@@ -132,24 +179,17 @@ fn confirm_success_releases_to_burn_and_burns_then_clears_pending() {
             other => panic!("unexpected event: {other:?}"),
         }
 
-        // Pending cleared
+        // Pending cleared, and the escrow lock with it
         assert!(ConfidentialBridge::pending(0).is_none());
+        assert_eq!(ConfidentialEscrow::escrow(0), None);
 
-        // Effects visible in the backend:
-        // - escrow_release moved ciphertext to burn account pending list, then
-        // - burn_encrypted consumed it; with our AlwaysOkVerifier we can at least
-        //   observe a pending commit created on release before burn.
-        // Because burn does not clear pending in the mock, we assert that the burn
-        // receiver saw a pending update and a UTXO got recorded at some point.
-        assert_eq!(
-            PendingBalanceCommit::<Runtime>::get(ASSET, burn_acc).unwrap(),
-            [2u8; 32]
-        );
-        assert_eq!(
-            PendingDeposits::<Runtime>::get((burn_acc, ASSET, 0)).unwrap(),
-            ct(7)
-        );
-        assert_eq!(NextPendingDepositId::<Runtime>::get(burn_acc, ASSET), 1);
+        // The escrowed commitment left both the escrow account and the total supply.
+        let burned =
+            |c: Option<Commitment>| c.unwrap_or_default().add(&locked).unwrap().is_identity();
+        assert!(burned(AvailableBalanceCommit::<Runtime>::get(
+            ASSET, escrow_acc
+        )));
+        assert!(burned(TotalSupplyCommit::<Runtime>::get(ASSET)));
     });
 }
 
@@ -157,9 +197,7 @@ fn confirm_success_releases_to_burn_and_burns_then_clears_pending() {
 fn confirm_success_errors_when_not_found() {
     new_test_ext().execute_with(|| {
         // No pending transfer with id 99
-        let err =
-            ConfidentialBridge::confirm_success(RuntimeOrigin::root(), 99, proof(&[]), proof(&[]))
-                .unwrap_err();
+        let err = ConfidentialBridge::confirm_success(RuntimeOrigin::root(), 99).unwrap_err();
 
         assert_eq!(err, Error::<Runtime>::NotFound.into());
     });
@@ -184,6 +222,7 @@ fn cancel_and_refund_by_sender_after_deadline() {
             ct(44),
             proof(&[1]),
             proof(&[2]),
+            None,
         ));
         let rec = ConfidentialBridge::pending(0).unwrap();
         assert_eq!(rec.deadline, 11);
@@ -212,7 +251,7 @@ fn cancel_and_refund_by_sender_after_deadline() {
         // Backend effects: refund to ALICE pending + UTXO(0).
         assert_eq!(
             PendingBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap(),
-            [2u8; 32]
+            Commitment::new([2u8; 32])
         );
         assert_eq!(
             PendingDeposits::<Runtime>::get((ALICE, ASSET, 0)).unwrap(),
@@ -237,6 +276,7 @@ fn cancel_and_refund_by_root_before_deadline() {
             ct(10),
             proof(&[1]),
             proof(&[2]),
+            None,
         ));
 
         // Before deadline, but root is allowed to cancel.
@@ -272,6 +312,7 @@ fn cancel_and_refund_errors_when_not_sender_or_not_expired() {
             ct(3),
             proof(&[1]),
             proof(&[2]),
+            None,
         ));
 
         // Wrong caller (BOB), not privileged → NotSender
@@ -290,14 +331,12 @@ fn cancel_and_refund_errors_when_not_sender_or_not_expired() {
 #[test]
 fn receive_confidential_mints_on_incoming_packet() {
     new_test_ext().execute_with(|| {
-        use parity_scale_codec::Encode;
-
         // Destination will mint for BOB; need BOB's PK for backend mint.
         set_pk(BOB);
 
         // Build payload without importing BridgePacket:
         // SCALE for struct = ordered fields, same as tuple encoding.
-        let payload = (0u64, BOB, ASSET, ct(55), proof(&[1, 2, 3])).encode();
+        let payload = (0u64, BOB, ASSET, ct(55), proof(&[1, 2, 3]), 2u32, 0u64).encode();
         let bounded: sp_runtime::BoundedVec<u8, sp_runtime::traits::ConstU32<1024>> =
             payload.clone().try_into().expect("fits");
 
@@ -309,14 +348,1085 @@ fn receive_confidential_mints_on_incoming_packet() {
         match last_event() {
             RuntimeEvent::ConfidentialBridge(Event::InboundTransferExecuted {
                 id,
+                source_para,
                 asset,
                 minted,
+                dest_account_hash,
+                payload_hash,
             }) => {
                 assert_eq!(id, 0);
+                assert_eq!(source_para, 2);
                 assert_eq!(asset, ASSET);
-                assert_eq!(minted, [5u8; 64]); // AlwaysOkVerifier::verify_mint
+                assert_eq!(minted, ct(5)); // AlwaysOkVerifier::verify_mint
+                assert_eq!(dest_account_hash, sp_io::hashing::blake2_256(&BOB.encode()));
+                assert_eq!(payload_hash, sp_io::hashing::blake2_256(&payload));
             }
             other => panic!("unexpected event: {other:?}"),
         }
     });
 }
+
+/// Packet `id` from para 2, sequenced like its id.
+fn inbound(id: u64) -> sp_runtime::BoundedVec<u8, sp_runtime::traits::ConstU32<1024>> {
+    inbound_at(id, id)
+}
+
+fn inbound_at(
+    id: u64,
+    sequence: u64,
+) -> sp_runtime::BoundedVec<u8, sp_runtime::traits::ConstU32<1024>> {
+    (id, BOB, ASSET, ct(55), proof(&[1, 2, 3]), 2u32, sequence)
+        .encode()
+        .try_into()
+        .expect("fits")
+}
+
+#[test]
+fn receive_confidential_rejects_replayed_packets() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound(0)
+        ));
+        assert_eq!(crate::InboundProcessed::<Runtime>::get(2, 0), Some(1));
+
+        let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound(0))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::AlreadyProcessed.into());
+
+        let garbage: sp_runtime::BoundedVec<u8, sp_runtime::traits::ConstU32<1024>> =
+            vec![1, 2, 3].try_into().expect("fits");
+        let err =
+            ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), garbage).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::MalformedPayload.into());
+
+        // The same id from another source is a different packet.
+        let other_source: sp_runtime::BoundedVec<u8, sp_runtime::traits::ConstU32<1024>> =
+            (0u64, BOB, ASSET, ct(55), proof(&[1, 2, 3]), 3u32, 0u64)
+                .encode()
+                .try_into()
+                .expect("fits");
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            other_source
+        ));
+    });
+}
+
+#[test]
+fn pruned_inbound_packets_stay_rejected_through_the_floor() {
+    use frame_support::weights::Weight;
+
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound(4)
+        ));
+
+        // Retention is 20 blocks: nothing is pruned before then.
+        ConfidentialBridge::prune_inbound(20, Weight::MAX);
+        assert!(crate::InboundProcessed::<Runtime>::contains_key(2, 4));
+
+        ConfidentialBridge::prune_inbound(21, Weight::MAX);
+        assert!(!crate::InboundProcessed::<Runtime>::contains_key(2, 4));
+        assert_eq!(crate::InboundFloor::<Runtime>::get(2), 5);
+
+        // Neither the pruned packet nor an older one can be minted.
+        for id in [4, 3] {
+            let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound(id))
+                .unwrap_err();
+            assert_eq!(err, Error::<Runtime>::AlreadyProcessed.into());
+        }
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound(5)
+        ));
+    });
+}
+
+fn ack(id: u64, dest_para: u32, success: bool) -> sp_runtime::BoundedVec<u8, ConstU32<1024>> {
+    BridgeAck {
+        transfer_id: id,
+        dest_para,
+        success,
+    }
+    .encode()
+    .try_into()
+    .expect("fits")
+}
+
+fn open_transfer() {
+    set_pk(ALICE);
+    set_pk(ConfidentialEscrow::escrow_account());
+    assert_ok!(ConfidentialBridge::send_confidential(
+        RuntimeOrigin::signed(ALICE),
+        2,
+        BOB,
+        ASSET,
+        // Any amount: `confirm_success` burns what the lock credited the escrow account
+        EncryptedAmount::from_parts(BASEPOINT, [3u8; 32]),
+        proof(&[1]),
+        proof(&[2]),
+        None,
+    ));
+}
+
+#[test]
+fn receive_confidential_acknowledges_to_the_source() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound(3)
+        ));
+        // Acked to the packet's source para, from SelfParaId (1).
+        assert_eq!(take_acks(), vec![(2, ack(3, 1, true).into_inner())]);
+    });
+}
+
+#[test]
+fn receive_confidential_acknowledges_a_failed_mint() {
+    new_test_ext().execute_with(|| {
+        // BOB has no public key, so the mint fails.
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound(3)
+        ));
+        assert_eq!(take_acks(), vec![(2, ack(3, 1, false).into_inner())]);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::InboundTransferFailed {
+                id: 3,
+                source_para: 2,
+                // Hashed with the sequence zeroed, as the source hashed it
+                payload_hash: sp_io::hashing::blake2_256(&inbound_at(3, 0)),
+            })
+        );
+
+        // Failed packets are processed too: a retry cannot mint later.
+        set_pk(BOB);
+        let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound(3))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::AlreadyProcessed.into());
+    });
+}
+
+#[test]
+fn success_ack_lets_the_sender_confirm_but_not_refund() {
+    new_test_ext().execute_with(|| {
+        open_transfer();
+
+        // Without an ACK only the privileged origin confirms.
+        let err = ConfidentialBridge::confirm_success(RuntimeOrigin::signed(ALICE), 0).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::NotDelivered.into());
+
+        assert_ok!(ConfidentialBridge::handle_ack(
+            RuntimeOrigin::root(),
+            ack(0, 2, true)
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::TransferAcknowledged {
+                id: 0,
+                success: true
+            })
+        );
+        assert_eq!(
+            ConfidentialBridge::pending(0).unwrap().delivered,
+            Some(true)
+        );
+
+        // The funds were minted on the destination: no refund, even after the deadline.
+        frame_system::Pallet::<Runtime>::set_block_number(12);
+        let err =
+            ConfidentialBridge::cancel_and_refund(RuntimeOrigin::signed(ALICE), 0, proof(&[7]))
+                .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::NotExpired.into());
+
+        assert_ok!(ConfidentialBridge::confirm_success(
+            RuntimeOrigin::signed(ALICE),
+            0
+        ));
+        assert!(ConfidentialBridge::pending(0).is_none());
+        // The sender settles its own transfer without a reward.
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::ConfidentialBridge(Event::RelayerRewarded { .. })
+        )));
+    });
+}
+
+#[test]
+fn failure_ack_lets_the_sender_refund_before_the_deadline() {
+    new_test_ext().execute_with(|| {
+        open_transfer();
+        assert_ok!(ConfidentialBridge::handle_ack(
+            RuntimeOrigin::root(),
+            ack(0, 2, false)
+        ));
+
+        assert_ok!(ConfidentialBridge::cancel_and_refund(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            proof(&[7]),
+        ));
+        assert!(ConfidentialBridge::pending(0).is_none());
+    });
+}
+
+#[test]
+fn handle_ack_rejects_mismatched_or_repeated_acks() {
+    new_test_ext().execute_with(|| {
+        open_transfer();
+
+        let err = ConfidentialBridge::handle_ack(RuntimeOrigin::signed(ALICE), ack(0, 2, true))
+            .unwrap_err();
+        assert_eq!(err, sp_runtime::DispatchError::BadOrigin);
+        let err =
+            ConfidentialBridge::handle_ack(RuntimeOrigin::root(), ack(1, 2, true)).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::NotFound.into());
+        // Only the destination para acknowledges, and only as itself.
+        let err =
+            ConfidentialBridge::handle_ack(RuntimeOrigin::root(), ack(0, 3, true)).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::ParaMismatch.into());
+        XcmPara::set(3);
+        let err =
+            ConfidentialBridge::handle_ack(RuntimeOrigin::root(), ack(0, 3, true)).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::UnexpectedAck.into());
+        XcmPara::set(2);
+
+        assert_ok!(ConfidentialBridge::handle_ack(
+            RuntimeOrigin::root(),
+            ack(0, 2, false)
+        ));
+        let err =
+            ConfidentialBridge::handle_ack(RuntimeOrigin::root(), ack(0, 2, true)).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::UnexpectedAck.into());
+    });
+}
+
+#[test]
+fn paras_speak_only_for_themselves() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        open_transfer();
+        XcmPara::set(3);
+
+        // Para 3 cannot deliver a packet claiming to come from para 2
+        let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound(0))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::ParaMismatch.into());
+        assert!(crate::InboundProcessed::<Runtime>::get(2, 0).is_none());
+        assert!(take_acks().is_empty());
+
+        // Nor settle a transfer that was sent to para 2
+        let err = ConfidentialBridge::confirm_success(RuntimeOrigin::root(), 0).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::ParaMismatch.into());
+        let err = ConfidentialBridge::cancel_and_refund(RuntimeOrigin::root(), 0, proof(&[7]))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::ParaMismatch.into());
+        assert!(ConfidentialBridge::pending(0).is_some());
+
+        XcmPara::set(2);
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound(0)
+        ));
+        assert_ok!(ConfidentialBridge::confirm_success(
+            RuntimeOrigin::root(),
+            0
+        ));
+    });
+}
+
+#[test]
+fn send_confidential_translates_the_asset_for_the_destination() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(ConfidentialEscrow::escrow_account());
+        let send = |dest_para| {
+            ConfidentialBridge::send_confidential(
+                RuntimeOrigin::signed(ALICE),
+                dest_para,
+                BOB,
+                ASSET,
+                ct(12),
+                proof(&[1]),
+                proof(&[2]),
+                None,
+            )
+        };
+
+        assert_eq!(send(4), Err(Error::<Runtime>::UnmappedAsset.into()));
+
+        assert_ok!(ConfidentialBridge::map_asset(
+            RuntimeOrigin::root(),
+            ASSET,
+            4,
+            1984
+        ));
+        assert_ok!(send(4));
+        // The packet names the destination's id; the pending record keeps ours.
+        let sent = take_sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, 4);
+        let packet = BridgePacket::<AccountId, AssetId>::decode(&mut &sent[0].1[..]).unwrap();
+        assert_eq!(packet.asset, 1984);
+        assert_eq!(packet.source_para, 1);
+        assert_eq!(ConfidentialBridge::pending(0).unwrap().asset, ASSET);
+    });
+}
+
+#[test]
+fn receive_confidential_rejects_assets_unmapped_for_the_source() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        // Para 5 may not send ASSET here.
+        let from_five: sp_runtime::BoundedVec<u8, ConstU32<1024>> =
+            (0u64, BOB, ASSET, ct(55), proof(&[1, 2, 3]), 5u32, 0u64)
+                .encode()
+                .try_into()
+                .expect("fits");
+        XcmPara::set(5);
+        let err =
+            ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), from_five).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::UnmappedAsset.into());
+        XcmPara::set(2);
+
+        assert_ok!(ConfidentialBridge::unmap_asset(
+            RuntimeOrigin::root(),
+            ASSET,
+            2
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::AssetUnmapped {
+                asset: ASSET,
+                para: 2
+            })
+        );
+        let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound(0))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::UnmappedAsset.into());
+    });
+}
+
+#[test]
+fn asset_mappings_are_admin_only_and_one_to_one() {
+    new_test_ext().execute_with(|| {
+        let err =
+            ConfidentialBridge::map_asset(RuntimeOrigin::signed(ALICE), ASSET, 4, 1).unwrap_err();
+        assert_eq!(err, sp_runtime::DispatchError::BadOrigin);
+
+        // Para 2 already knows its ASSET as our ASSET.
+        let err = ConfidentialBridge::map_asset(RuntimeOrigin::root(), 8, 2, ASSET).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::RemoteAssetTaken.into());
+
+        // Remapping frees the old remote id.
+        assert_ok!(ConfidentialBridge::map_asset(
+            RuntimeOrigin::root(),
+            ASSET,
+            2,
+            9
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::AssetMapped {
+                asset: ASSET,
+                para: 2,
+                remote: 9
+            })
+        );
+        assert_eq!(crate::RemoteAssets::<Runtime>::get(2, ASSET), None);
+        assert_ok!(ConfidentialBridge::map_asset(
+            RuntimeOrigin::root(),
+            8,
+            2,
+            ASSET
+        ));
+
+        let err = ConfidentialBridge::unmap_asset(RuntimeOrigin::root(), 8, 4).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::UnmappedAsset.into());
+    });
+}
+
+fn send_capped(disclosure: Option<InputProof>) -> sp_runtime::DispatchResult {
+    send_locked(proof(&[2]), disclosure)
+}
+
+fn send_locked(
+    lock_proof: InputProof,
+    disclosure: Option<InputProof>,
+) -> sp_runtime::DispatchResult {
+    ConfidentialBridge::send_confidential(
+        RuntimeOrigin::signed(ALICE),
+        2,
+        BOB,
+        ASSET,
+        ct(12),
+        proof(&[1]),
+        lock_proof,
+        disclosure,
+    )
+}
+
+fn audit(asset: AssetId) {
+    assert_ok!(Zkhe::set_asset_config(
+        RuntimeOrigin::root(),
+        asset,
+        pallet_zkhe::AssetConfig {
+            auditor: Some([9u8; 32].to_vec().try_into().expect("bounded vec")),
+            ..Default::default()
+        }
+    ));
+}
+
+fn limit_reached(epoch: u64) -> bool {
+    frame_system::Pallet::<Runtime>::events()
+        .iter()
+        .any(|record| {
+            record.event
+                == RuntimeEvent::ConfidentialBridge(Event::OutboundLimitReached {
+                    asset: ASSET,
+                    epoch,
+                })
+        })
+}
+
+#[test]
+fn outbound_cap_limits_transfers_per_epoch() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(ConfidentialEscrow::escrow_account());
+        let cap = OutboundCap {
+            max_transfers: 2,
+            max_amount: None,
+        };
+        let err =
+            ConfidentialBridge::set_outbound_cap(RuntimeOrigin::signed(ALICE), ASSET, Some(cap))
+                .unwrap_err();
+        assert_eq!(err, sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(ConfidentialBridge::set_outbound_cap(
+            RuntimeOrigin::root(),
+            ASSET,
+            Some(cap)
+        ));
+
+        assert_ok!(send_capped(None));
+        assert!(!limit_reached(0));
+        assert_ok!(send_capped(None));
+        assert!(limit_reached(0));
+        assert_eq!(
+            send_capped(None),
+            Err(Error::<Runtime>::OutboundCapExceeded.into())
+        );
+
+        // Epochs are 10 blocks: the count starts over at block 10.
+        System::set_block_number(10);
+        assert_ok!(send_capped(None));
+        assert_eq!(crate::OutboundUsages::<Runtime>::get(ASSET).transfers, 1);
+
+        // Lifting the cap lifts the limit.
+        assert_ok!(ConfidentialBridge::set_outbound_cap(
+            RuntimeOrigin::root(),
+            ASSET,
+            None
+        ));
+        for _ in 0..3 {
+            assert_ok!(send_capped(None));
+        }
+    });
+}
+
+#[test]
+fn outbound_cap_limits_disclosed_amounts() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(ConfidentialEscrow::escrow_account());
+        let cap = OutboundCap {
+            max_transfers: 10,
+            max_amount: Some(246),
+        };
+        // Amounts are only disclosed under auditor handles.
+        assert_eq!(
+            ConfidentialBridge::set_outbound_cap(RuntimeOrigin::root(), ASSET, Some(cap)),
+            Err(Error::<Runtime>::NotAudited.into())
+        );
+        audit(ASSET);
+        assert_ok!(ConfidentialBridge::set_outbound_cap(
+            RuntimeOrigin::root(),
+            ASSET,
+            Some(cap)
+        ));
+
+        // The mock discloses every handle as 123.
+        assert_eq!(
+            send_locked(audited_proof(&[2], 4), None),
+            Err(Error::<Runtime>::DisclosureRequired.into())
+        );
+        assert_ok!(send_locked(audited_proof(&[2], 4), Some(proof(&[5]))));
+        assert!(!limit_reached(0));
+        assert_eq!(crate::OutboundUsages::<Runtime>::get(ASSET).amount, 123);
+        assert_ok!(send_locked(audited_proof(&[2], 4), Some(proof(&[5]))));
+        assert!(limit_reached(0));
+        assert_eq!(crate::OutboundUsages::<Runtime>::get(ASSET).amount, 246);
+        assert_eq!(
+            send_locked(audited_proof(&[2], 4), Some(proof(&[5]))),
+            Err(Error::<Runtime>::OutboundCapExceeded.into())
+        );
+
+        // Dropping the auditor key leaves only the transfer count capped.
+        assert_ok!(Zkhe::set_asset_config(
+            RuntimeOrigin::root(),
+            ASSET,
+            Default::default()
+        ));
+        assert_ok!(send_capped(None));
+        assert_eq!(crate::OutboundUsages::<Runtime>::get(ASSET).transfers, 3);
+    });
+}
+
+#[test]
+fn paused_channels_refuse_sends_and_packets() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        set_pk(ConfidentialEscrow::escrow_account());
+
+        let err = ConfidentialBridge::pause_channel(RuntimeOrigin::signed(ALICE), 2).unwrap_err();
+        assert_eq!(err, sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(ConfidentialBridge::pause_channel(RuntimeOrigin::root(), 2));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::ChannelPaused { para: 2 })
+        );
+
+        assert_eq!(
+            send_capped(None),
+            Err(Error::<Runtime>::ChannelPaused.into())
+        );
+        let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound(0))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::ChannelPaused.into());
+
+        assert_ok!(ConfidentialBridge::resume_channel(RuntimeOrigin::root(), 2));
+        assert_ok!(send_capped(None));
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound(0)
+        ));
+    });
+}
+
+#[test]
+fn channels_limit_payloads_and_charge_fees() {
+    use frame_support::traits::fungible::{Inspect, Mutate};
+
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(ConfidentialEscrow::escrow_account());
+        let channel = |max_payload, fee| ChannelConfig {
+            status: ChannelStatus::Open,
+            max_payload,
+            fee,
+            execution: None,
+            ordering: DeliveryOrder::Lenient,
+        };
+
+        let err = ConfidentialBridge::set_channel(RuntimeOrigin::root(), 2, channel(1025, 0))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::InvalidChannel.into());
+
+        // Too small for any packet.
+        assert_ok!(ConfidentialBridge::set_channel(
+            RuntimeOrigin::root(),
+            2,
+            channel(8, 0)
+        ));
+        assert_eq!(
+            send_capped(None),
+            Err(Error::<Runtime>::PayloadTooLarge.into())
+        );
+
+        assert_ok!(ConfidentialBridge::set_channel(
+            RuntimeOrigin::root(),
+            2,
+            channel(1024, 10)
+        ));
+        assert!(send_capped(None).is_err());
+        Balances::set_balance(&ALICE, 100);
+        assert_ok!(send_capped(None));
+        assert_eq!(Balances::balance(&ALICE), 90);
+        assert_eq!(Balances::balance(&99), 10);
+    });
+}
+
+#[test]
+fn refused_packets_are_queued_and_resent() {
+    use frame_support::weights::Weight;
+
+    new_test_ext().execute_with(|| {
+        set_send_fails(true);
+        open_transfer();
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::OutboundQueued { id: 0 })
+        );
+        assert_eq!(Outbox::<Runtime>::get(0).expect("queued").attempts, 1);
+        assert!(take_sent().is_empty());
+
+        let err = ConfidentialBridge::resend(RuntimeOrigin::signed(BOB), 0).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::MessengerFailed.into());
+        let err = ConfidentialBridge::resend(RuntimeOrigin::signed(BOB), 1).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::NotQueued.into());
+
+        // A paused channel holds the packet without using up attempts.
+        assert_ok!(ConfidentialBridge::pause_channel(RuntimeOrigin::root(), 2));
+        ConfidentialBridge::retry_outbound(Weight::MAX);
+        assert_eq!(Outbox::<Runtime>::get(0).expect("queued").attempts, 1);
+        assert_ok!(ConfidentialBridge::resume_channel(RuntimeOrigin::root(), 2));
+
+        // The deadline runs from the actual send.
+        System::set_block_number(5);
+        set_send_fails(false);
+        assert_ok!(ConfidentialBridge::resend(RuntimeOrigin::signed(BOB), 0));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::OutboundSent { id: 0 })
+        );
+        assert!(Outbox::<Runtime>::get(0).is_none());
+        assert_eq!(take_sent().len(), 1);
+        assert_eq!(
+            ConfidentialBridge::pending(0).expect("pending").deadline,
+            15
+        );
+    });
+}
+
+#[test]
+fn on_idle_abandons_packets_after_max_attempts() {
+    use frame_support::weights::Weight;
+
+    new_test_ext().execute_with(|| {
+        set_send_fails(true);
+        open_transfer();
+
+        // MaxSendAttempts is 3, counting the first send.
+        ConfidentialBridge::retry_outbound(Weight::MAX);
+        assert_eq!(Outbox::<Runtime>::get(0).expect("queued").attempts, 2);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::OutboundRetryFailed { id: 0, attempts: 2 })
+        );
+        ConfidentialBridge::retry_outbound(Weight::MAX);
+        assert!(Outbox::<Runtime>::get(0).is_none());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::OutboundAbandoned { id: 0 })
+        );
+        assert_eq!(
+            ConfidentialBridge::pending(0).expect("pending").delivered,
+            Some(false)
+        );
+
+        // Abandoned counts as failed delivery: refundable before the deadline.
+        assert_ok!(ConfidentialBridge::cancel_and_refund(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            proof(&[7]),
+        ));
+    });
+}
+
+#[test]
+fn refunds_drop_queued_packets() {
+    new_test_ext().execute_with(|| {
+        set_send_fails(true);
+        open_transfer();
+
+        assert_ok!(ConfidentialBridge::cancel_and_refund(
+            RuntimeOrigin::root(),
+            0,
+            proof(&[7]),
+        ));
+        assert!(Outbox::<Runtime>::get(0).is_none());
+        let err = ConfidentialBridge::resend(RuntimeOrigin::signed(BOB), 0).unwrap_err();
+        assert_eq!(err, Error::<Runtime>::NotQueued.into());
+    });
+}
+
+#[test]
+fn channels_buy_execution_for_packets_and_acks() {
+    use frame_support::weights::Weight;
+
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        let execution = ExecutionFee {
+            asset: 5,
+            amount: 1_000,
+            weight_limit: Weight::from_parts(1_000_000, 0),
+        };
+        assert_ok!(ConfidentialBridge::set_channel(
+            RuntimeOrigin::root(),
+            2,
+            ChannelConfig {
+                status: ChannelStatus::Open,
+                max_payload: 1024,
+                fee: 0,
+                execution: Some(execution.clone()),
+                ordering: DeliveryOrder::Lenient,
+            }
+        ));
+
+        open_transfer();
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound(0)
+        ));
+        assert_eq!(take_fees(), vec![Some(execution.clone()), Some(execution)]);
+
+        // Paras without a channel entry ask for unpaid execution.
+        assert_ok!(ConfidentialBridge::send_confidential(
+            RuntimeOrigin::signed(ALICE),
+            3,
+            BOB,
+            ASSET,
+            ct(12),
+            proof(&[1]),
+            proof(&[2]),
+            None,
+        ));
+        assert_eq!(take_fees(), vec![None]);
+    });
+}
+
+#[test]
+fn packets_are_sequenced_per_destination_as_they_leave() {
+    new_test_ext().execute_with(|| {
+        let sequences = || -> Vec<(u32, u64, u64)> {
+            take_sent()
+                .into_iter()
+                .map(|(para, payload)| {
+                    let packet =
+                        BridgePacket::<AccountId, AssetId>::decode(&mut &payload[..]).unwrap();
+                    (para, packet.transfer_id, packet.sequence)
+                })
+                .collect()
+        };
+
+        // A refused packet takes its number only once it leaves.
+        set_send_fails(true);
+        open_transfer();
+        set_send_fails(false);
+        open_transfer();
+        assert_ok!(ConfidentialBridge::resend(RuntimeOrigin::signed(BOB), 0));
+        assert_eq!(sequences(), vec![(2, 1, 0), (2, 0, 1)]);
+
+        // Each destination counts on its own.
+        assert_ok!(ConfidentialBridge::send_confidential(
+            RuntimeOrigin::signed(ALICE),
+            3,
+            BOB,
+            ASSET,
+            ct(12),
+            proof(&[1]),
+            proof(&[2]),
+            None,
+        ));
+        assert_eq!(sequences(), vec![(3, 2, 0)]);
+        assert_eq!(OutboundSequence::<Runtime>::get(2), 2);
+    });
+}
+
+#[test]
+fn inbound_packets_must_follow_their_sequence() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+
+        // Lenient: a gap is skipped, and what was skipped is rejected.
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound_at(0, 2)
+        ));
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::ConfidentialBridge(Event::InboundSequenceSkipped {
+                source_para: 2,
+                expected: 0,
+                received: 2,
+            })));
+        let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound_at(1, 1))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::OutOfOrder.into());
+
+        // Strict: only the next number passes.
+        assert_ok!(ConfidentialBridge::set_channel(
+            RuntimeOrigin::root(),
+            2,
+            ChannelConfig {
+                status: ChannelStatus::Open,
+                max_payload: 1024,
+                fee: 0,
+                execution: None,
+                ordering: DeliveryOrder::Strict,
+            }
+        ));
+        let err = ConfidentialBridge::receive_confidential(RuntimeOrigin::root(), inbound_at(1, 4))
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::OutOfOrder.into());
+        assert_ok!(ConfidentialBridge::receive_confidential(
+            RuntimeOrigin::root(),
+            inbound_at(1, 3)
+        ));
+        assert_eq!(InboundSequence::<Runtime>::get(2), 4);
+    });
+}
+
+const TOKEN: EthAddress = [0xee; 20];
+
+#[test]
+fn ethereum_packets_are_abi_encoded() {
+    let packet = EthereumPacket {
+        nonce: 7,
+        account: EthereumPacket::account_of([0xaa; 20]),
+        token: TOKEN,
+        amount: ct(3),
+        proof: vec![1; 33],
+    };
+    let data = packet.abi_encode();
+    // Six head words, the proof's length and two words of padded proof
+    assert_eq!(data.len(), 9 * 32);
+    assert_eq!(data[31], 7);
+    assert_eq!(&data[64 + 12..96], &TOKEN);
+    assert_eq!(data[5 * 32 + 31], 192);
+    assert_eq!(data[6 * 32 + 31], 33);
+    assert_eq!(EthereumPacket::abi_decode(&data), Some(packet));
+
+    // Bits above a uint64 or an address are not ours to drop.
+    let mut dirty = data.clone();
+    dirty[0] = 1;
+    assert_eq!(EthereumPacket::abi_decode(&dirty), None);
+    let mut dirty = data.clone();
+    dirty[64] = 1;
+    assert_eq!(EthereumPacket::abi_decode(&dirty), None);
+    assert_eq!(EthereumPacket::abi_decode(&data[..7 * 32 + 32]), None);
+}
+
+#[test]
+fn export_to_ethereum_burns_and_hands_the_packet_over() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(ConfidentialEscrow::escrow_account());
+        let amount = EncryptedAmount::from_parts(BASEPOINT, [3u8; 32]);
+        let export = || {
+            ConfidentialBridge::export_to_ethereum(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                [0xaa; 20],
+                amount,
+                proof(&[1]),
+                proof(&[2]),
+            )
+        };
+        assert_eq!(export(), Err(Error::<Runtime>::UnmappedToken.into()));
+
+        let err = ConfidentialBridge::map_ethereum_token(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            Some(TOKEN),
+        )
+        .unwrap_err();
+        assert_eq!(err, sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(ConfidentialBridge::map_ethereum_token(
+            RuntimeOrigin::root(),
+            ASSET,
+            Some(TOKEN)
+        ));
+        let err =
+            ConfidentialBridge::map_ethereum_token(RuntimeOrigin::root(), ASSET + 1, Some(TOKEN))
+                .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::TokenTaken.into());
+
+        assert_ok!(export());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::ExportedToEthereum {
+                nonce: 0,
+                from: ALICE,
+                asset: ASSET,
+                recipient: [0xaa; 20],
+                amount,
+            })
+        );
+        let exported = take_exported();
+        assert_eq!(exported.len(), 1);
+        let packet = EthereumPacket::abi_decode(&exported[0]).expect("abi packet");
+        assert_eq!(packet.nonce, 0);
+        assert_eq!(packet.account, EthereumPacket::account_of([0xaa; 20]));
+        assert_eq!(packet.token, TOKEN);
+        assert_eq!(packet.amount, amount);
+        assert_eq!(packet.proof, proof(&[2]).into_inner());
+        // Nothing waits for an answer.
+        assert!(ConfidentialBridge::pending(0).is_none());
+    });
+}
+
+#[test]
+fn receive_from_ethereum_mints_each_nonce_once() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        let mut account = [0u8; 32];
+        account[..8].copy_from_slice(&BOB.encode());
+        let payload = |nonce, token| -> sp_runtime::BoundedVec<u8, ConstU32<1024>> {
+            EthereumPacket {
+                nonce,
+                account,
+                token,
+                amount: ct(55),
+                proof: proof(&[1, 2, 3]).into_inner(),
+            }
+            .abi_encode()
+            .try_into()
+            .expect("fits")
+        };
+        assert_ok!(ConfidentialBridge::map_ethereum_token(
+            RuntimeOrigin::root(),
+            ASSET,
+            Some(TOKEN)
+        ));
+
+        let err = ConfidentialBridge::receive_from_ethereum(
+            RuntimeOrigin::signed(BOB),
+            payload(0, TOKEN),
+        )
+        .unwrap_err();
+        assert_eq!(err, sp_runtime::DispatchError::BadOrigin);
+        let err =
+            ConfidentialBridge::receive_from_ethereum(RuntimeOrigin::root(), payload(0, [1; 20]))
+                .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::UnmappedToken.into());
+        let err = ConfidentialBridge::receive_from_ethereum(
+            RuntimeOrigin::root(),
+            vec![0u8; 64].try_into().expect("fits"),
+        )
+        .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::InvalidEthereumPacket.into());
+
+        assert_ok!(ConfidentialBridge::receive_from_ethereum(
+            RuntimeOrigin::root(),
+            payload(0, TOKEN)
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::ReceivedFromEthereum {
+                nonce: 0,
+                to: BOB,
+                asset: ASSET,
+                minted: ct(5),
+            })
+        );
+        let err =
+            ConfidentialBridge::receive_from_ethereum(RuntimeOrigin::root(), payload(0, TOKEN))
+                .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::AlreadyProcessed.into());
+    });
+}
+
+#[test]
+fn relayers_settle_transfers_for_a_reward() {
+    use frame_support::traits::fungible::{Inspect, Mutate};
+
+    new_test_ext().execute_with(|| {
+        Balances::set_balance(&99, 100);
+        open_transfer();
+        open_transfer();
+        assert_ok!(ConfidentialBridge::handle_ack(
+            RuntimeOrigin::root(),
+            ack(0, 2, true)
+        ));
+
+        // Anyone confirms an acknowledged transfer, and is paid from the fee pot.
+        assert_ok!(ConfidentialBridge::confirm_success(
+            RuntimeOrigin::signed(CHARLIE),
+            0
+        ));
+        assert!(ConfidentialBridge::pending(0).is_none());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialBridge(Event::RelayerRewarded {
+                relayer: CHARLIE,
+                amount: 5
+            })
+        );
+        assert_eq!(Balances::balance(&CHARLIE), 5);
+
+        // ACKs can be relayed with evidence the verifier accepts.
+        let err = ConfidentialBridge::submit_ack(
+            RuntimeOrigin::signed(CHARLIE),
+            ack(1, 2, true),
+            b"forged".to_vec().try_into().expect("fits"),
+        )
+        .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::InvalidEvidence.into());
+        assert_ok!(ConfidentialBridge::submit_ack(
+            RuntimeOrigin::signed(CHARLIE),
+            ack(1, 2, true),
+            b"final".to_vec().try_into().expect("fits"),
+        ));
+        assert!(ConfidentialBridge::pending(1).is_none());
+        assert_eq!(Balances::balance(&CHARLIE), 10);
+
+        // An empty pot stops the rewards, not the settlement.
+        Balances::set_balance(&99, 0);
+        open_transfer();
+        assert_ok!(ConfidentialBridge::submit_ack(
+            RuntimeOrigin::signed(CHARLIE),
+            ack(2, 2, true),
+            b"final".to_vec().try_into().expect("fits"),
+        ));
+        assert!(ConfidentialBridge::pending(2).is_none());
+        assert_eq!(Balances::balance(&CHARLIE), 10);
+    });
+}
+
+#[test]
+fn transfer_status_follows_a_transfer_to_its_end() {
+    use confidential_assets_primitives::BridgeTransferStatus::*;
+
+    new_test_ext().execute_with(|| {
+        assert_eq!(ConfidentialBridge::transfer_status(0), None);
+        open_transfer();
+        open_transfer();
+        open_transfer();
+        assert_eq!(ConfidentialBridge::transfer_status(0), Some(Pending));
+
+        // A success ACK keeps it pending past the deadline, until it is confirmed.
+        assert_ok!(ConfidentialBridge::handle_ack(
+            RuntimeOrigin::root(),
+            ack(0, 2, true)
+        ));
+        // A failure ACK makes it refundable at once.
+        assert_ok!(ConfidentialBridge::handle_ack(
+            RuntimeOrigin::root(),
+            ack(1, 2, false)
+        ));
+        assert_eq!(ConfidentialBridge::transfer_status(1), Some(Expired));
+
+        System::set_block_number(11);
+        assert_eq!(ConfidentialBridge::transfer_status(0), Some(Pending));
+        assert_eq!(ConfidentialBridge::transfer_status(2), Some(Expired));
+
+        assert_ok!(ConfidentialBridge::confirm_success(
+            RuntimeOrigin::signed(ALICE),
+            0
+        ));
+        assert_ok!(ConfidentialBridge::cancel_and_refund(
+            RuntimeOrigin::signed(ALICE),
+            1,
+            proof(&[7]),
+        ));
+        assert_eq!(ConfidentialBridge::transfer_status(0), Some(Confirmed));
+        assert_eq!(ConfidentialBridge::transfer_status(1), Some(Refunded));
+    });
+}
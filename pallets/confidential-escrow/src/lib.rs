@@ -1,5 +1,34 @@
 //! pallet-confidential-escrow — escrow adapter that escrows encrypted balances
 //! using a derived pallet account and ConfidentialBackend.
+//!
+//! It also implements [`EscrowTrust`] for plaintext amounts, moved through `Config::Ramp`
+//! into the same account, so one pallet can back HTLCs over both public and confidential
+//! assets. [`AssetTotals`] keeps both sides' accounting per asset.
+//!
+//! Every `escrow_lock` opens a record under a fresh [`EscrowId`] holding its owner,
+//! asset, ciphertext, purpose tag and creation block, and the Pedersen commitment the
+//! transfer added to the escrow account's balance. Releases and refunds name the lock
+//! and draw that commitment down by what they pay out; the record is deleted once
+//! nothing is left, so a lock can never pay out more than was put into it.
+//! `escrow_release_partial` settles part of a lock and re-escrows the homomorphic
+//! remainder of its ciphertext under a proof of its own, for swaps and milestone
+//! payments.
+//!
+//! The pallet that opened a lock may give it a release policy with
+//! `escrow_set_release_policy`: releases from it then need `threshold` of the named
+//! approvers (e.g. buyer and arbiter) to `approve_release` the same recipient and amount
+//! within `ApprovalTtl` blocks, after which anyone can carry it out with
+//! `release_approved`.
+//!
+//! Users can also escrow directly: `lock` opens a lock from the caller, which its owner
+//! or `ArbiterOrigin` may `release_to` a recipient and only `ArbiterOrigin` may `refund`
+//! before it expires. These calls only act on locks opened with `lock`, never on those
+//! other pallets hold through the trait.
+//!
+//! Locks opened with `escrow_lock_until` expire at a given block. `on_idle` emits
+//! `EscrowExpired` for them as their block passes, and from then on anyone may submit a
+//! refund proof with `refund_expired` to return what is left to the owner, so consumers
+//! need no timeout logic of their own.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -10,17 +39,80 @@ mod tests;
 
 extern crate alloc;
 
-use frame_support::pallet_prelude::*;
-use sp_runtime::traits::AccountIdConversion;
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, Saturating};
 use sp_std::prelude::*;
 
 use confidential_assets_primitives::{
-    ConfidentialBackend, ConfidentialEscrow, EncryptedAmount, InputProof,
+    Commitment, ConfidentialBackend, ConfidentialEscrow, EncryptedAmount, EscrowId, EscrowPurpose,
+    EscrowTrust, InputProof, Ramp,
 };
 use frame_support::PalletId;
 
 pub use pallet::*;
 
+/// A lock held by the escrow account.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct EscrowRecord<AccountId, AssetId, BlockNumber> {
+    /// Account the value was locked from; refunds go back to it.
+    pub owner: AccountId,
+    pub asset: AssetId,
+    /// Ciphertext `escrow_lock` moved into escrow, or the remainder
+    /// `escrow_release_partial` last re-escrowed.
+    pub encrypted_amount: EncryptedAmount,
+    /// Pedersen commitment to the part not yet released or refunded.
+    pub remaining: Commitment,
+    pub purpose: EscrowPurpose,
+    pub created_at: BlockNumber,
+    /// Block from which anyone may refund the lock to `owner`.
+    pub expires_at: Option<BlockNumber>,
+}
+
+/// What the escrow account holds of one asset.
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct AssetEscrow<Balance> {
+    /// Plaintext amount held for [`EscrowTrust`] users.
+    pub public: Balance,
+    /// Open confidential locks.
+    pub locks: u32,
+}
+
+/// The escrow account's balance of one asset next to what its locks record, for
+/// operators to spot drift, e.g. from a half-applied release.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct EscrowReconciliation {
+    /// Escrow account's available balance commitment.
+    pub available: Commitment,
+    /// Escrow account's pending balance commitment, deposits it has not claimed yet.
+    pub pending: Commitment,
+    /// Sum of the remaining Pedersen commitments of the asset's open locks, which
+    /// equals `available` plus `pending` while locks are all the account holds; `None`
+    /// if one of them is not a valid point.
+    pub locked: Option<Commitment>,
+    /// Open locks of the asset.
+    pub locks: u32,
+}
+
+/// Who must approve releases from a lock.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct ReleasePolicy<Approvers> {
+    pub approvers: Approvers,
+    /// Approvals each release needs.
+    pub threshold: u32,
+}
+
+/// A release from a lock gathering approvals.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct PendingRelease<AccountId, BlockNumber, Approvers> {
+    pub to: AccountId,
+    pub encrypted_amount: EncryptedAmount,
+    /// Approvers who approved it so far.
+    pub approvals: Approvers,
+    /// Block after which the approvals lapse and a new release may be proposed.
+    pub expires_at: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -30,40 +122,458 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         type AssetId: Parameter + Member + Copy + Ord + MaxEncodedLen;
-        type Balance: Parameter + Member + Copy + Default + MaxEncodedLen;
+        type Balance: Parameter + Member + Copy + Default + MaxEncodedLen + AtLeast32BitUnsigned;
 
         type Backend: ConfidentialBackend<Self::AccountId, Self::AssetId, Self::Balance>;
 
+        /// Public ledger [`EscrowTrust`] moves plaintext amounts through, e.g. the runtime's
+        /// confidential-assets ramp.
+        type Ramp: Ramp<Self::AccountId, Self::AssetId, Self::Balance>;
+
         #[pallet::constant]
         type PalletId: Get<PalletId>;
+
+        /// Maximum number of approvers in a release policy.
+        #[pallet::constant]
+        type MaxApprovers: Get<u32>;
+
+        /// Blocks a proposed release has to gather its approvals.
+        #[pallet::constant]
+        type ApprovalTtl: Get<BlockNumberFor<Self>>;
+
+        /// Origin that may release or refund locks opened with `lock` on their owner's
+        /// behalf.
+        type ArbiterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    pub trait WeightInfo {
+        fn lock() -> Weight;
+        fn release_to() -> Weight;
+        fn refund() -> Weight;
+        fn refund_expired() -> Weight;
+        fn approve_release() -> Weight;
+        fn release_approved() -> Weight;
+        /// Sweeping one expired lock in `on_idle`.
+        fn expire() -> Weight;
+    }
+    impl WeightInfo for () {
+        fn lock() -> Weight {
+            Weight::from_parts(60_000, 0)
+        }
+        fn release_to() -> Weight {
+            Weight::from_parts(60_000, 0)
+        }
+        fn refund() -> Weight {
+            Weight::from_parts(60_000, 0)
+        }
+        fn refund_expired() -> Weight {
+            Weight::from_parts(60_000, 0)
+        }
+        fn approve_release() -> Weight {
+            Weight::from_parts(10_000, 0)
+        }
+        fn release_approved() -> Weight {
+            Weight::from_parts(60_000, 0)
+        }
+        fn expire() -> Weight {
+            Weight::from_parts(1_000, 0)
+        }
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    #[pallet::storage]
+    pub type NextEscrowId<T: Config> = StorageValue<_, EscrowId, ValueQuery>;
+
+    /// Open locks by id.
+    #[pallet::storage]
+    pub type Escrows<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        EscrowId,
+        EscrowRecord<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Public and confidential escrow of each asset.
+    #[pallet::storage]
+    pub type AssetTotals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, AssetEscrow<T::Balance>, ValueQuery>;
+
+    /// Ids of each owner's open locks.
+    #[pallet::storage]
+    pub type EscrowsByOwner<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        EscrowId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Locks opened with the `lock` call, which the user-facing calls may act on.
+    #[pallet::storage]
+    pub type UserLocks<T: Config> = StorageMap<_, Twox64Concat, EscrowId, (), OptionQuery>;
+
+    /// Locks by the block they expire at, for the `on_idle` sweep. Entries of locks
+    /// closed meanwhile are skipped.
+    #[pallet::storage]
+    pub type ExpiringEscrows<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Twox64Concat,
+        EscrowId,
+        (),
+        OptionQuery,
+    >;
+
+    /// First block whose `ExpiringEscrows` have not been swept yet.
+    #[pallet::storage]
+    pub type NextExpirySweep<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Release policies of the locks that have one.
+    #[pallet::storage]
+    pub type ReleasePolicies<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        EscrowId,
+        ReleasePolicy<BoundedVec<T::AccountId, T::MaxApprovers>>,
+        OptionQuery,
+    >;
+
+    /// The release each policy-bound lock is gathering approvals for.
+    #[pallet::storage]
+    pub type PendingReleases<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        EscrowId,
+        PendingRelease<T::AccountId, BlockNumberFor<T>, BoundedVec<T::AccountId, T::MaxApprovers>>,
+        OptionQuery,
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_expired(now, remaining_weight)
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         EscrowLocked {
+            id: EscrowId,
             asset: T::AssetId,
             from: T::AccountId,
+            purpose: EscrowPurpose,
             encrypted_amount: EncryptedAmount,
         },
         EscrowReleased {
+            id: EscrowId,
             asset: T::AssetId,
             to: T::AccountId,
             encrypted_amount: EncryptedAmount,
         },
         EscrowRefunded {
+            id: EscrowId,
             asset: T::AssetId,
             to: T::AccountId,
             encrypted_amount: EncryptedAmount,
         },
+        /// What was left of lock `id` was burned from escrow.
+        EscrowBurned {
+            id: EscrowId,
+            asset: T::AssetId,
+            burned: Commitment,
+        },
+        /// Plaintext `amount` moved into escrow.
+        PublicLocked {
+            asset: T::AssetId,
+            from: T::AccountId,
+            amount: T::Balance,
+        },
+        PublicReleased {
+            asset: T::AssetId,
+            to: T::AccountId,
+            amount: T::Balance,
+        },
+        PublicRefunded {
+            asset: T::AssetId,
+            to: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Lock `id` reached its expiry; anyone may now refund it to `owner`.
+        EscrowExpired {
+            id: EscrowId,
+            asset: T::AssetId,
+            owner: T::AccountId,
+        },
+        /// Releases from lock `id` now need `threshold` of `approvers`.
+        ReleasePolicySet {
+            id: EscrowId,
+            approvers: Vec<T::AccountId>,
+            threshold: u32,
+        },
+        /// `who` approved releasing `encrypted_amount` of lock `id` to `to`; it has
+        /// `approvals` of the approvals it needs.
+        ReleaseApproved {
+            id: EscrowId,
+            who: T::AccountId,
+            to: T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            approvals: u32,
+        },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         BackendError,
+        /// The backend has paused the asset; nothing can be locked or paid out.
+        AssetPaused,
+        /// No open lock has this id.
+        UnknownEscrow,
+        /// The amount is not a valid ciphertext to draw down the lock with.
+        InvalidCiphertext,
+        /// The lock has no expiry or has not reached it.
+        NotExpired,
+        /// The lock already has a release policy.
+        PolicyExists,
+        /// The threshold is zero or above the number of approvers, or an approver is
+        /// listed twice.
+        InvalidPolicy,
+        TooManyApprovers,
+        /// The lock has no release policy naming the caller.
+        NotApprover,
+        /// The caller already approved the pending release.
+        AlreadyApproved,
+        /// Another release of the lock is gathering approvals.
+        ReleaseMismatch,
+        /// The lock has a release policy and this release lacks its approvals.
+        NotApproved,
+        /// Only the lock's owner or `ArbiterOrigin` may do this.
+        NotOwner,
+        /// The lock was opened by another pallet, not with `lock`.
+        NotUserLock,
+        /// Less plaintext of the asset is escrowed than the release or refund asks for.
+        InsufficientEscrow,
+        /// The public ledger refused the transfer.
+        RampFailed,
+        Overflow,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Refund `encrypted_amount` of expired lock `id` to its owner. Callable by
+        /// anyone; `refund_proof` is the transfer proof moving it out of escrow.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::refund_expired())]
+        #[transactional]
+        pub fn refund_expired(
+            origin: OriginFor<T>,
+            id: EscrowId,
+            encrypted_amount: EncryptedAmount,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let expires_at = Escrows::<T>::get(id)
+                .ok_or(Error::<T>::UnknownEscrow)?
+                .expires_at
+                .ok_or(Error::<T>::NotExpired)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= expires_at,
+                Error::<T>::NotExpired
+            );
+            <Self as ConfidentialEscrow<_, _, _>>::escrow_refund(id, encrypted_amount, refund_proof)
+        }
+
+        /// Approve releasing `encrypted_amount` of lock `id` to `to`. The first approval
+        /// proposes the release; later ones must name the same recipient and amount
+        /// until it is carried out or its approvals lapse.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::approve_release())]
+        pub fn approve_release(
+            origin: OriginFor<T>,
+            id: EscrowId,
+            to: T::AccountId,
+            encrypted_amount: EncryptedAmount,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let policy = ReleasePolicies::<T>::get(id).ok_or(Error::<T>::NotApprover)?;
+            ensure!(policy.approvers.contains(&who), Error::<T>::NotApprover);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut pending = match PendingReleases::<T>::get(id) {
+                Some(pending) if now <= pending.expires_at => {
+                    ensure!(
+                        pending.to == to && pending.encrypted_amount == encrypted_amount,
+                        Error::<T>::ReleaseMismatch
+                    );
+                    ensure!(
+                        !pending.approvals.contains(&who),
+                        Error::<T>::AlreadyApproved
+                    );
+                    pending
+                }
+                _ => PendingRelease {
+                    to,
+                    encrypted_amount,
+                    approvals: BoundedVec::new(),
+                    expires_at: now.saturating_add(T::ApprovalTtl::get()),
+                },
+            };
+            // Bounded by the policy's approvers, so this cannot overflow
+            pending
+                .approvals
+                .try_push(who.clone())
+                .map_err(|_| Error::<T>::TooManyApprovers)?;
+
+            Self::deposit_event(Event::ReleaseApproved {
+                id,
+                who,
+                to: pending.to.clone(),
+                encrypted_amount: pending.encrypted_amount,
+                approvals: pending.approvals.len() as u32,
+            });
+            PendingReleases::<T>::insert(id, pending);
+            Ok(())
+        }
+
+        /// Carry out the approved release of lock `id`. Callable by anyone;
+        /// `release_proof` is the transfer proof moving it out of escrow.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::release_approved())]
+        #[transactional]
+        pub fn release_approved(
+            origin: OriginFor<T>,
+            id: EscrowId,
+            release_proof: InputProof,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let pending = PendingReleases::<T>::get(id).ok_or(Error::<T>::NotApproved)?;
+            <Self as ConfidentialEscrow<_, _, _>>::escrow_release(
+                id,
+                &pending.to,
+                pending.encrypted_amount,
+                release_proof,
+            )
+        }
+
+        /// Lock `encrypted_amount` of the caller's `asset` in escrow, expiring at
+        /// `expires_at` if given. `input_proof` is the transfer proof moving it in; the
+        /// new id is in `EscrowLocked`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::lock())]
+        #[transactional]
+        pub fn lock(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            encrypted_amount: EncryptedAmount,
+            purpose: EscrowPurpose,
+            expires_at: Option<BlockNumberFor<T>>,
+            input_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let id = Self::do_lock(
+                asset,
+                &who,
+                encrypted_amount,
+                purpose,
+                expires_at,
+                input_proof,
+            )?;
+            UserLocks::<T>::insert(id, ());
+            Ok(())
+        }
+
+        /// Release `encrypted_amount` of lock `id` to `to`. Signed by the lock's owner or
+        /// sent by `ArbiterOrigin`; `release_proof` moves it out of escrow.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::release_to())]
+        #[transactional]
+        pub fn release_to(
+            origin: OriginFor<T>,
+            id: EscrowId,
+            to: T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            release_proof: InputProof,
+        ) -> DispatchResult {
+            let record = Self::user_lock(id)?;
+            if T::ArbiterOrigin::ensure_origin(origin.clone()).is_err() {
+                let who = ensure_signed(origin)?;
+                ensure!(who == record.owner, Error::<T>::NotOwner);
+            }
+            <Self as ConfidentialEscrow<_, _, _>>::escrow_release(
+                id,
+                &to,
+                encrypted_amount,
+                release_proof,
+            )
+        }
+
+        /// Refund `encrypted_amount` of lock `id` to its owner. Only `ArbiterOrigin` may,
+        /// so a buyer cannot take back what a seller is relying on; after the lock
+        /// expires anyone can with `refund_expired`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::refund())]
+        #[transactional]
+        pub fn refund(
+            origin: OriginFor<T>,
+            id: EscrowId,
+            encrypted_amount: EncryptedAmount,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
+            T::ArbiterOrigin::ensure_origin(origin)?;
+            Self::user_lock(id)?;
+            <Self as ConfidentialEscrow<_, _, _>>::escrow_refund(id, encrypted_amount, refund_proof)
+        }
+    }
+
+    #[pallet::view_functions]
+    impl<T: Config> Pallet<T> {
+        /// Public and confidential escrow of `asset`.
+        pub fn asset_totals(asset: T::AssetId) -> AssetEscrow<T::Balance> {
+            AssetTotals::<T>::get(asset)
+        }
+
+        /// The escrow account's `asset` balance next to the sum of its open locks. Walks
+        /// every open lock, so it is meant for off-chain checks.
+        pub fn reconciliation(asset: T::AssetId) -> EscrowReconciliation {
+            let escrow = Self::escrow_account();
+            let remaining: Vec<Commitment> = Escrows::<T>::iter_values()
+                .filter(|record| record.asset == asset)
+                .map(|record| record.remaining)
+                .collect();
+            EscrowReconciliation {
+                available: T::Backend::balance_of(asset, &escrow),
+                pending: T::Backend::pending_balance_of(asset, &escrow),
+                locked: remaining
+                    .iter()
+                    .try_fold(Commitment::identity(), |sum, part| sum.add(part).ok()),
+                locks: remaining.len() as u32,
+            }
+        }
+
+        /// Approvers and threshold releases from lock `id` need, if it has a policy.
+        pub fn release_policy(id: EscrowId) -> Option<(Vec<T::AccountId>, u32)> {
+            ReleasePolicies::<T>::get(id).map(|policy| (policy.approvers.into(), policy.threshold))
+        }
+
+        /// Open lock `id`, if any.
+        pub fn escrow(
+            id: EscrowId,
+        ) -> Option<EscrowRecord<T::AccountId, T::AssetId, BlockNumberFor<T>>> {
+            Escrows::<T>::get(id)
+        }
+
+        /// Ids of `owner`'s open locks.
+        pub fn escrows_of(owner: T::AccountId) -> Vec<EscrowId> {
+            EscrowsByOwner::<T>::iter_key_prefix(owner).collect()
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -71,61 +581,429 @@ pub mod pallet {
         pub fn escrow_account() -> T::AccountId {
             T::PalletId::get().into_account_truncating()
         }
-    }
 
-    impl<T: Config> ConfidentialEscrow<T::AccountId, T::AssetId> for Pallet<T> {
-        fn escrow_lock(
+        #[inline]
+        fn ensure_not_paused(asset: T::AssetId) -> Result<(), Error<T>> {
+            ensure!(!T::Backend::is_paused(asset), Error::<T>::AssetPaused);
+            Ok(())
+        }
+
+        fn do_lock(
             asset: T::AssetId,
             who: &T::AccountId,
             encrypted_amount: EncryptedAmount,
+            purpose: EscrowPurpose,
+            expires_at: Option<BlockNumberFor<T>>,
             proof: InputProof,
-        ) -> Result<(), DispatchError> {
+        ) -> Result<EscrowId, DispatchError> {
+            Self::ensure_not_paused(asset)?;
             let escrow = Self::escrow_account();
-            let encrypted =
+            let (encrypted, locked) = Self::credited(asset, &escrow, || {
                 T::Backend::transfer_encrypted(asset, who, &escrow, encrypted_amount, proof)
-                    .map_err(|_| Error::<T>::BackendError)?;
+            })?;
+            let id = NextEscrowId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.wrapping_add(1);
+                id
+            });
+            Escrows::<T>::insert(
+                id,
+                EscrowRecord {
+                    owner: who.clone(),
+                    asset,
+                    encrypted_amount: encrypted,
+                    remaining: locked,
+                    purpose,
+                    created_at: frame_system::Pallet::<T>::block_number(),
+                    expires_at,
+                },
+            );
+            EscrowsByOwner::<T>::insert(who, id, ());
+            AssetTotals::<T>::mutate(asset, |totals| totals.locks.saturating_inc());
+            if let Some(block) = expires_at {
+                ExpiringEscrows::<T>::insert(block, id, ());
+            }
             Self::deposit_event(Event::EscrowLocked {
+                id,
                 asset,
                 from: who.clone(),
+                purpose,
                 encrypted_amount: encrypted,
             });
+            Ok(id)
+        }
+
+        /// Emit `EscrowExpired` for the locks whose expiry block has passed, as far as
+        /// `remaining_weight` allows, resuming where the last sweep stopped.
+        pub(crate) fn sweep_expired(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let per_block = db.reads(1);
+            let per_lock = T::WeightInfo::expire();
+            let mut used = db.reads_writes(1, 1);
+            if remaining_weight.any_lt(used.saturating_add(per_block)) {
+                return Weight::zero();
+            }
+
+            let mut block = NextExpirySweep::<T>::get();
+            while block <= now && used.saturating_add(per_block).all_lte(remaining_weight) {
+                used.saturating_accrue(per_block);
+                let budget: usize = remaining_weight
+                    .saturating_sub(used)
+                    .checked_div_per_component(&per_lock)
+                    .unwrap_or(u64::MAX)
+                    .try_into()
+                    .unwrap_or(usize::MAX);
+                let due: Vec<_> = ExpiringEscrows::<T>::iter_key_prefix(block)
+                    .take(budget.saturating_add(1))
+                    .collect();
+                let finished = due.len() <= budget;
+                for id in due.into_iter().take(budget) {
+                    used.saturating_accrue(per_lock);
+                    ExpiringEscrows::<T>::remove(block, id);
+                    if let Some(record) = Escrows::<T>::get(id) {
+                        Self::deposit_event(Event::EscrowExpired {
+                            id,
+                            asset: record.asset,
+                            owner: record.owner,
+                        });
+                    }
+                }
+                if !finished {
+                    break;
+                }
+                block.saturating_inc();
+            }
+            NextExpirySweep::<T>::put(block);
+            used
+        }
+
+        /// Move plaintext `amount` of `asset` out of escrow to `to`.
+        fn pay_out_public(
+            asset: T::AssetId,
+            to: &T::AccountId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            AssetTotals::<T>::try_mutate(asset, |totals| -> DispatchResult {
+                totals.public = totals
+                    .public
+                    .checked_sub(&amount)
+                    .ok_or(Error::<T>::InsufficientEscrow)?;
+                Ok(())
+            })?;
+            T::Ramp::transfer_from(&Self::escrow_account(), to, asset, amount)
+                .map_err(|_| Error::<T>::RampFailed)?;
             Ok(())
         }
 
-        fn escrow_release(
+        /// Open lock `id`, if it was opened with the `lock` call.
+        fn user_lock(
+            id: EscrowId,
+        ) -> Result<EscrowRecord<T::AccountId, T::AssetId, BlockNumberFor<T>>, DispatchError>
+        {
+            let record = Escrows::<T>::get(id).ok_or(Error::<T>::UnknownEscrow)?;
+            ensure!(UserLocks::<T>::contains_key(id), Error::<T>::NotUserLock);
+            Ok(record)
+        }
+
+        /// If lock `id` has a release policy, check that releasing `encrypted_amount` to
+        /// `to` gathered its approvals in time and use them up.
+        fn take_approval(
+            id: EscrowId,
+            to: &T::AccountId,
+            encrypted_amount: &EncryptedAmount,
+        ) -> DispatchResult {
+            let Some(policy) = ReleasePolicies::<T>::get(id) else {
+                return Ok(());
+            };
+            let pending = PendingReleases::<T>::get(id).ok_or(Error::<T>::NotApproved)?;
+            ensure!(
+                &pending.to == to
+                    && &pending.encrypted_amount == encrypted_amount
+                    && pending.approvals.len() as u32 >= policy.threshold
+                    && frame_system::Pallet::<T>::block_number() <= pending.expires_at,
+                Error::<T>::NotApproved
+            );
+            PendingReleases::<T>::remove(id);
+            Ok(())
+        }
+
+        /// Move `encrypted_amount` of lock `id` from escrow to `to`, or to the lock's
+        /// owner, and draw the lock down by it, deleting the record once nothing remains.
+        fn pay_out(
+            id: EscrowId,
+            to: Option<&T::AccountId>,
+            encrypted_amount: EncryptedAmount,
+            proof: InputProof,
+        ) -> Result<(T::AssetId, T::AccountId, EncryptedAmount), DispatchError> {
+            let mut record = Escrows::<T>::get(id).ok_or(Error::<T>::UnknownEscrow)?;
+            Self::ensure_not_paused(record.asset)?;
+            let to = to.unwrap_or(&record.owner).clone();
+
+            let escrow = Self::escrow_account();
+            let (encrypted, part) = Self::credited(record.asset, &to, || {
+                T::Backend::transfer_encrypted(record.asset, &escrow, &to, encrypted_amount, proof)
+            })?;
+            let closed = part == record.remaining;
+            if !closed {
+                record.remaining = record
+                    .remaining
+                    .sub(&part)
+                    .map_err(|_| Error::<T>::InvalidCiphertext)?;
+            }
+            if closed {
+                Self::close_lock(id, &record);
+            } else {
+                Escrows::<T>::insert(id, &record);
+            }
+            Ok((record.asset, to, encrypted))
+        }
+
+        /// Run `transfer` to `to` and return its result with the Pedersen commitment it
+        /// moved, read off the change to `to`'s pending balance, where the backend adds
+        /// each transfer's commitment.
+        fn credited(
+            asset: T::AssetId,
+            to: &T::AccountId,
+            transfer: impl FnOnce() -> Result<EncryptedAmount, DispatchError>,
+        ) -> Result<(EncryptedAmount, Commitment), DispatchError> {
+            let before = T::Backend::pending_balance_of(asset, to);
+            let encrypted = transfer().map_err(|_| Error::<T>::BackendError)?;
+            let moved = T::Backend::pending_balance_of(asset, to)
+                .sub(&before)
+                .map_err(|_| Error::<T>::InvalidCiphertext)?;
+            Ok((encrypted, moved))
+        }
+
+        /// Forget lock `id` once nothing is left of it.
+        fn close_lock(
+            id: EscrowId,
+            record: &EscrowRecord<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+        ) {
+            Escrows::<T>::remove(id);
+            EscrowsByOwner::<T>::remove(&record.owner, id);
+            ReleasePolicies::<T>::remove(id);
+            PendingReleases::<T>::remove(id);
+            UserLocks::<T>::remove(id);
+            AssetTotals::<T>::mutate(record.asset, |totals| totals.locks.saturating_dec());
+        }
+    }
+
+    impl<T: Config> ConfidentialEscrow<T::AccountId, T::AssetId, BlockNumberFor<T>> for Pallet<T> {
+        fn escrow_lock(
             asset: T::AssetId,
+            who: &T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            purpose: EscrowPurpose,
+            proof: InputProof,
+        ) -> Result<EscrowId, DispatchError> {
+            Self::do_lock(asset, who, encrypted_amount, purpose, None, proof)
+        }
+
+        fn escrow_lock_until(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            purpose: EscrowPurpose,
+            expires_at: BlockNumberFor<T>,
+            proof: InputProof,
+        ) -> Result<EscrowId, DispatchError> {
+            Self::do_lock(
+                asset,
+                who,
+                encrypted_amount,
+                purpose,
+                Some(expires_at),
+                proof,
+            )
+        }
+
+        fn escrow_expires_at(id: EscrowId) -> Option<BlockNumberFor<T>> {
+            Escrows::<T>::get(id).and_then(|record| record.expires_at)
+        }
+
+        fn escrow_release(
+            id: EscrowId,
             to: &T::AccountId,
             encrypted_amount: EncryptedAmount,
             proof: InputProof,
         ) -> Result<(), DispatchError> {
-            let escrow = Self::escrow_account();
-            let encrypted =
-                T::Backend::transfer_encrypted(asset, &escrow, to, encrypted_amount, proof)
-                    .map_err(|_| Error::<T>::BackendError)?;
+            Self::take_approval(id, to, &encrypted_amount)?;
+            let (asset, to, encrypted) = Self::pay_out(id, Some(to), encrypted_amount, proof)?;
             Self::deposit_event(Event::EscrowReleased {
+                id,
                 asset,
-                to: to.clone(),
+                to,
                 encrypted_amount: encrypted,
             });
             Ok(())
         }
 
         fn escrow_refund(
-            asset: T::AssetId,
-            to: &T::AccountId,
+            id: EscrowId,
             encrypted_amount: EncryptedAmount,
             proof: InputProof,
         ) -> Result<(), DispatchError> {
-            let escrow = Self::escrow_account();
-            let encrypted =
-                T::Backend::transfer_encrypted(asset, &escrow, to, encrypted_amount, proof)
-                    .map_err(|_| Error::<T>::BackendError)?;
+            let (asset, to, encrypted) = Self::pay_out(id, None, encrypted_amount, proof)?;
             Self::deposit_event(Event::EscrowRefunded {
+                id,
                 asset,
-                to: to.clone(),
+                to,
                 encrypted_amount: encrypted,
             });
             Ok(())
         }
+
+        fn escrow_burn(id: EscrowId) -> Result<(), DispatchError> {
+            let record = Escrows::<T>::get(id).ok_or(Error::<T>::UnknownEscrow)?;
+            Self::ensure_not_paused(record.asset)?;
+            T::Backend::burn_held(record.asset, &Self::escrow_account(), record.remaining)
+                .map_err(|_| Error::<T>::BackendError)?;
+            Self::close_lock(id, &record);
+            Self::deposit_event(Event::EscrowBurned {
+                id,
+                asset: record.asset,
+                burned: record.remaining,
+            });
+            Ok(())
+        }
+
+        fn escrow_set_release_policy(
+            id: EscrowId,
+            approvers: Vec<T::AccountId>,
+            threshold: u32,
+        ) -> Result<(), DispatchError> {
+            ensure!(Escrows::<T>::contains_key(id), Error::<T>::UnknownEscrow);
+            ensure!(
+                !ReleasePolicies::<T>::contains_key(id),
+                Error::<T>::PolicyExists
+            );
+            let mut unique = approvers.clone();
+            unique.sort();
+            unique.dedup();
+            ensure!(
+                threshold > 0
+                    && threshold as usize <= approvers.len()
+                    && unique.len() == approvers.len(),
+                Error::<T>::InvalidPolicy
+            );
+            let bounded: BoundedVec<_, T::MaxApprovers> = approvers
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyApprovers)?;
+
+            ReleasePolicies::<T>::insert(
+                id,
+                ReleasePolicy {
+                    approvers: bounded,
+                    threshold,
+                },
+            );
+            Self::deposit_event(Event::ReleasePolicySet {
+                id,
+                approvers,
+                threshold,
+            });
+            Ok(())
+        }
+
+        // The remainder is only checked against the lock once both transfers are in
+        #[transactional]
+        fn escrow_release_partial(
+            id: EscrowId,
+            to: &T::AccountId,
+            partial: EncryptedAmount,
+            proof_release: InputProof,
+            proof_remainder: InputProof,
+        ) -> Result<(), DispatchError> {
+            let mut record = Escrows::<T>::get(id).ok_or(Error::<T>::UnknownEscrow)?;
+            Self::ensure_not_paused(record.asset)?;
+            Self::take_approval(id, to, &partial)?;
+            let remainder = record
+                .encrypted_amount
+                .sub(&partial)
+                .map_err(|_| Error::<T>::InvalidCiphertext)?;
+
+            let escrow = Self::escrow_account();
+            let (released, part) = Self::credited(record.asset, to, || {
+                T::Backend::transfer_encrypted(record.asset, &escrow, to, partial, proof_release)
+            })?;
+            // A transfer back to escrow range-checks the remainder against the lock
+            let (kept, rest) = Self::credited(record.asset, &escrow, || {
+                T::Backend::transfer_encrypted(
+                    record.asset,
+                    &escrow,
+                    &escrow,
+                    remainder,
+                    proof_remainder,
+                )
+            })?;
+            ensure!(
+                part.add(&rest).ok() == Some(record.remaining),
+                Error::<T>::InvalidCiphertext
+            );
+            record.encrypted_amount = kept;
+            record.remaining = rest;
+            Escrows::<T>::insert(id, &record);
+
+            Self::deposit_event(Event::EscrowReleased {
+                id,
+                asset: record.asset,
+                to: to.clone(),
+                encrypted_amount: released,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> EscrowTrust<T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
+        fn escrow_lock(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            amount: T::Balance,
+        ) -> Result<(), DispatchError> {
+            AssetTotals::<T>::try_mutate(asset, |totals| -> DispatchResult {
+                totals.public = totals
+                    .public
+                    .checked_add(&amount)
+                    .ok_or(Error::<T>::Overflow)?;
+                Ok(())
+            })?;
+            T::Ramp::transfer_from(who, &Self::escrow_account(), asset, amount)
+                .map_err(|_| Error::<T>::RampFailed)?;
+            Self::deposit_event(Event::PublicLocked {
+                asset,
+                from: who.clone(),
+                amount,
+            });
+            Ok(())
+        }
+
+        fn escrow_release(
+            asset: T::AssetId,
+            to: &T::AccountId,
+            amount: T::Balance,
+        ) -> Result<(), DispatchError> {
+            Self::pay_out_public(asset, to, amount)?;
+            Self::deposit_event(Event::PublicReleased {
+                asset,
+                to: to.clone(),
+                amount,
+            });
+            Ok(())
+        }
+
+        fn escrow_refund(
+            asset: T::AssetId,
+            to: &T::AccountId,
+            amount: T::Balance,
+        ) -> Result<(), DispatchError> {
+            Self::pay_out_public(asset, to, amount)?;
+            Self::deposit_event(Event::PublicRefunded {
+                asset,
+                to: to.clone(),
+                amount,
+            });
+            Ok(())
+        }
     }
 }
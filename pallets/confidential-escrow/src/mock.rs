@@ -1,9 +1,13 @@
 use crate::pallet as pallet_confidential_escrow;
 use confidential_assets_primitives::{
-    ConfidentialBackend, EncryptedAmount, InputProof, NetworkIdProvider, PublicKeyBytes, ZkVerifier,
+    Commitment, ConfidentialBackend, EncryptedAmount, InputProof, NetworkIdProvider, ProofKind,
+    ProofSystem, ProofSystemId, PublicKeyBytes, Ramp, VerifyError, ZkVerifier, seal_proof,
 };
-use frame_support::{PalletId, construct_runtime, derive_impl, parameter_types};
-use sp_runtime::BuildStorage;
+use frame_support::{
+    PalletId, construct_runtime, derive_impl, parameter_types,
+    traits::{ConstBool, ConstU32, ConstU64, fungible, tokens::Preservation},
+};
+use sp_runtime::{BuildStorage, DispatchError};
 
 pub type AccountId = u64;
 pub type AssetId = u32;
@@ -28,24 +32,40 @@ impl NetworkIdProvider for MockNetworkId {
 pub struct AlwaysOkVerifier;
 
 impl ZkVerifier for AlwaysOkVerifier {
-    type Error = ();
     type NetworkIdProvider = MockNetworkId;
+
+    // Any key is accepted as owned
+    fn verify_pubkey_ownership(
+        _account: &[u8],
+        _pk: &[u8],
+        _proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        Ok(())
+    }
+
     // Disclose encrypted amount -> constant u64 (e.g., 123)
-    fn disclose(_asset: &[u8], _pk: &[u8], _cipher: &[u8]) -> Result<u64, ()> {
+    fn disclose(
+        _asset: &[u8],
+        _pk: &[u8],
+        _cipher: &[u8],
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
         Ok(123)
     }
 
-    // from_new_available, to_new_pending
+    // from_new_available, to_new_pending: the amount moves between the balances when
+    // every point is valid, so escrow locks are drawn down as on chain; else constants
     fn verify_transfer_sent(
         _asset: &[u8],
         _from_pk: &[u8],
         _to_pk: &[u8],
-        _from_old_avail: &[u8],
-        _to_old_pending: &[u8],
-        _delta_ct: &[u8],
+        from_old_avail: &[u8],
+        to_old_pending: &[u8],
+        delta_ct: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
-        Ok((vec![1u8; 32], vec![2u8; 32]))
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        Ok(moved(from_old_avail, to_old_pending, delta_ct)
+            .unwrap_or_else(|| (vec![1u8; 32], vec![2u8; 32])))
     }
 
     // avail_new, pending_new
@@ -56,7 +76,7 @@ impl ZkVerifier for AlwaysOkVerifier {
         _pending_old: &[u8],
         _commits: &[[u8; 32]],
         _envelope: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         // Make pending_new zero so pallet removes PendingBalanceCommit on accept
         Ok((vec![3u8; 32], vec![0u8; 32]))
     }
@@ -68,8 +88,13 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _total_old: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), ()> {
-        Ok((vec![10u8; 32], vec![11u8; 32], [5u8; 64]))
+    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), VerifyError> {
+        // A real point: the pallet records what the mint added to the pending balance
+        Ok((
+            vec![14u8; 32],
+            vec![11u8; 32],
+            EncryptedAmount::new([5u8; 64]),
+        ))
     }
 
     // from_new_available, total_new, disclosed_u64
@@ -80,14 +105,70 @@ impl ZkVerifier for AlwaysOkVerifier {
         _total_old: &[u8],
         _amount_ct: &EncryptedAmount,
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, u64), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>, u64), VerifyError> {
         Ok((vec![20u8; 32], vec![21u8; 32], 42))
     }
 }
 
+// Balances after moving an amount from `from` to `to`. The mock takes the nonce point
+// of `delta_ct` as the amount's Pedersen commitment, which in a real ciphertext it is
+// not; tests build their ciphertexts to match.
+fn moved(from: &[u8], to: &[u8], delta_ct: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let commit = |bytes: &[u8]| match bytes {
+        [] => Some(Commitment::identity()),
+        _ => <[u8; 32]>::try_from(bytes).ok().map(Commitment::new),
+    };
+    let delta = commit(delta_ct.get(..32)?)?;
+    let from = commit(from)?.sub(&delta).ok()?;
+    let to = commit(to)?.add(&delta).ok()?;
+    Some((from.to_bytes().to_vec(), to.to_bytes().to_vec()))
+}
+
+// Stands in for the ZK-ElGamal proof system and accepts any body length
+impl ProofSystem for AlwaysOkVerifier {
+    const ID: ProofSystemId = 0;
+
+    fn proof_len(_kind: ProofKind) -> Option<(usize, usize)> {
+        Some((0, usize::MAX))
+    }
+}
+
+// Public ledger for `EscrowTrust`: every asset id moves native balance
+pub struct BalancesRamp;
+impl Ramp<AccountId, AssetId, Balance> for BalancesRamp {
+    type Error = DispatchError;
+
+    fn transfer_from(
+        from: &AccountId,
+        to: &AccountId,
+        _asset: AssetId,
+        amount: Balance,
+    ) -> Result<(), Self::Error> {
+        <Balances as fungible::Mutate<AccountId>>::transfer(
+            from,
+            to,
+            amount,
+            Preservation::Expendable,
+        )?;
+        Ok(())
+    }
+    fn burn(_from: &AccountId, _asset: &AssetId, _amount: Balance) -> Result<(), Self::Error> {
+        Err(DispatchError::Unavailable)
+    }
+    fn mint(_to: &AccountId, _asset: &AssetId, _amount: Balance) -> Result<(), Self::Error> {
+        Err(DispatchError::Unavailable)
+    }
+}
+
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Runtime {
     type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
 }
 
 impl pallet_zkhe::Config for Runtime {
@@ -95,6 +176,16 @@ impl pallet_zkhe::Config for Runtime {
     type AssetId = AssetId;
     type Balance = Balance;
     type Verifier = AlwaysOkVerifier;
+    type MaxClaimUtxos = ConstU32<16>;
+    type PendingTtl = ConstU64<100>;
+    type MaxPendingPerAccount = ConstU32<16>;
+    type PendingDeposit = ConstU64<0>;
+    type AccountDeposit = ConstU64<0>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RequireAssetConfig = ConstBool<false>;
+    type MaxAuditHistory = ConstU32<4>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 parameter_types! {
@@ -105,12 +196,18 @@ impl pallet_confidential_escrow::Config for Runtime {
     type AssetId = AssetId;
     type Balance = Balance;
     type Backend = Zkhe;
+    type Ramp = BalancesRamp;
     type PalletId = EscrowPalletId;
+    type MaxApprovers = ConstU32<4>;
+    type ApprovalTtl = ConstU64<100>;
+    type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = ();
 }
 
 construct_runtime!(
     pub enum Runtime {
         System: frame_system,
+        Balances: pallet_balances,
         Zkhe: pallet_zkhe,
         ConfidentialEscrow: pallet_confidential_escrow,
     }
@@ -130,10 +227,17 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 // Handy helpers
 pub fn set_pk(who: AccountId) {
     // Non-empty fake pk
-    Zkhe::set_public_key(&who, &[7u8; 64].to_vec().try_into().expect("bounded vec")).unwrap();
+    Zkhe::set_public_key(
+        &who,
+        &[7u8; 64].to_vec().try_into().expect("bounded vec"),
+        proof(&[]),
+    )
+    .unwrap();
 }
 
-// Construct InputProof from raw bytes using TryFrom<Vec<u8>>
+// Construct InputProof from raw bytes, enveloped for the mock proof system
 pub fn proof(bytes: &[u8]) -> InputProof {
-    bytes.to_vec().try_into().expect("bounded vec")
+    seal_proof(AlwaysOkVerifier::ID, bytes)
+        .try_into()
+        .expect("bounded vec")
 }
@@ -1,5 +1,7 @@
-use crate::{Error, Event, mock::*};
-use confidential_assets_primitives::EncryptedAmount;
+use crate::{
+    AssetEscrow, Error, EscrowReconciliation, EscrowRecord, Event, ExpiringEscrows, Pallet, mock::*,
+};
+use confidential_assets_primitives::{Commitment, EncryptedAmount, EscrowPurpose};
 use frame_support::{assert_err, assert_ok};
 use sp_runtime::traits::Zero;
 // Avoid name clash: pallet alias = `ConfidentialEscrow`, trait aliased as CE.
@@ -7,7 +9,23 @@ use confidential_assets_primitives::ConfidentialEscrow as CE;
 
 // helpers
 fn ct(b: u8) -> EncryptedAmount {
-    [b; 64]
+    EncryptedAmount::new([b; 64])
+}
+const PURPOSE: EscrowPurpose = *b"testlock";
+// Compressed Ristretto basepoint, a valid commitment to draw locks down with
+const BASEPOINT: [u8; 32] = [
+    0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71, 0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00, 0x51, 0x5f,
+    0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d, 0xb6, 0xa6, 0x59, 0x45, 0xe0, 0x8d, 0x2d, 0x76,
+];
+fn lock(encrypted_amount: EncryptedAmount) -> u64 {
+    <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_lock(
+        ASSET,
+        &ALICE,
+        encrypted_amount,
+        PURPOSE,
+        proof(&[]),
+    )
+    .expect("locked")
 }
 fn last_event() -> RuntimeEvent {
     frame_system::Pallet::<Runtime>::events()
@@ -39,14 +57,16 @@ fn escrow_lock_moves_funds_to_escrow_and_emits_event() {
         let delta = ct(11);
         let proof = proof(&[1, 2, 3]);
 
-        assert_ok!(<ConfidentialEscrow as CE<AccountId, AssetId>>::escrow_lock(
-            ASSET, &ALICE, delta, proof
-        ));
+        let id = <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_lock(
+            ASSET, &ALICE, delta, PURPOSE, proof,
+        )
+        .expect("locked");
+        assert_eq!(id, 0);
 
         // Backend effects on ZkHE storage (receiver = escrow).
         assert_eq!(
             PendingBalanceCommit::<Runtime>::get(ASSET, escrow).unwrap(),
-            [2u8; 32]
+            Commitment::new([2u8; 32])
         );
         assert_eq!(
             PendingDeposits::<Runtime>::get((escrow, ASSET, 0)).unwrap(),
@@ -54,15 +74,35 @@ fn escrow_lock_moves_funds_to_escrow_and_emits_event() {
         );
         assert_eq!(NextPendingDepositId::<Runtime>::get(escrow, ASSET), 1);
 
+        // The lock is on record under its id, with the commitment the transfer added to
+        // escrow's pending balance (fixed in the mock, as ct(11) is not a valid point).
+        assert_eq!(
+            ConfidentialEscrow::escrow(id),
+            Some(EscrowRecord {
+                owner: ALICE,
+                asset: ASSET,
+                encrypted_amount: delta,
+                remaining: Commitment::new([2u8; 32]),
+                purpose: PURPOSE,
+                created_at: 1,
+                expires_at: None,
+            })
+        );
+        assert_eq!(ConfidentialEscrow::escrows_of(ALICE), vec![id]);
+
         // Event surfaced by this pallet.
         match last_event() {
             RuntimeEvent::ConfidentialEscrow(Event::EscrowLocked {
+                id: locked,
                 asset,
                 from,
+                purpose,
                 encrypted_amount,
             }) => {
+                assert_eq!(locked, id);
                 assert_eq!(asset, ASSET);
                 assert_eq!(from, ALICE);
+                assert_eq!(purpose, PURPOSE);
                 assert_eq!(encrypted_amount, delta);
             }
             other => panic!("unexpected event: {other:?}"),
@@ -77,13 +117,15 @@ fn escrow_release_moves_funds_from_escrow_to_beneficiary_and_emits_event() {
 
         let escrow = ConfidentialEscrow::escrow_account();
         set_pk(escrow);
+        set_pk(ALICE);
         set_pk(BOB);
 
         let delta = ct(22);
+        let id = lock(delta);
 
         assert_ok!(
-            <ConfidentialEscrow as CE<AccountId, AssetId>>::escrow_release(
-                ASSET,
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release(
+                id,
                 &BOB,
                 delta,
                 proof(&[9])
@@ -93,7 +135,7 @@ fn escrow_release_moves_funds_from_escrow_to_beneficiary_and_emits_event() {
         // Backend effects on receiver (BOB).
         assert_eq!(
             PendingBalanceCommit::<Runtime>::get(ASSET, BOB).unwrap(),
-            [2u8; 32]
+            Commitment::new([2u8; 32])
         );
         assert_eq!(
             PendingDeposits::<Runtime>::get((BOB, ASSET, 0)).unwrap(),
@@ -101,12 +143,18 @@ fn escrow_release_moves_funds_from_escrow_to_beneficiary_and_emits_event() {
         );
         assert_eq!(NextPendingDepositId::<Runtime>::get(BOB, ASSET), 1);
 
+        // Released in full, so the lock is closed.
+        assert_eq!(ConfidentialEscrow::escrow(id), None);
+        assert!(ConfidentialEscrow::escrows_of(ALICE).is_empty());
+
         match last_event() {
             RuntimeEvent::ConfidentialEscrow(Event::EscrowReleased {
+                id: released,
                 asset,
                 to,
                 encrypted_amount,
             }) => {
+                assert_eq!(released, id);
                 assert_eq!(asset, ASSET);
                 assert_eq!(to, BOB);
                 assert_eq!(encrypted_amount, delta);
@@ -126,11 +174,11 @@ fn escrow_refund_moves_funds_from_escrow_back_to_owner_and_emits_event() {
         set_pk(ALICE);
 
         let delta = ct(33);
+        let id = lock(delta);
 
         assert_ok!(
-            <ConfidentialEscrow as CE<AccountId, AssetId>>::escrow_refund(
-                ASSET,
-                &ALICE,
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_refund(
+                id,
                 delta,
                 proof(&[4, 4])
             )
@@ -139,20 +187,23 @@ fn escrow_refund_moves_funds_from_escrow_back_to_owner_and_emits_event() {
         // Backend effects on receiver (ALICE).
         assert_eq!(
             PendingBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap(),
-            [2u8; 32]
+            Commitment::new([2u8; 32])
         );
         assert_eq!(
             PendingDeposits::<Runtime>::get((ALICE, ASSET, 0)).unwrap(),
             delta
         );
         assert_eq!(NextPendingDepositId::<Runtime>::get(ALICE, ASSET), 1);
+        assert_eq!(ConfidentialEscrow::escrow(id), None);
 
         match last_event() {
             RuntimeEvent::ConfidentialEscrow(Event::EscrowRefunded {
+                id: refunded,
                 asset,
                 to,
                 encrypted_amount,
             }) => {
+                assert_eq!(refunded, id);
                 assert_eq!(asset, ASSET);
                 assert_eq!(to, ALICE);
                 assert_eq!(encrypted_amount, delta);
@@ -162,6 +213,200 @@ fn escrow_refund_moves_funds_from_escrow_back_to_owner_and_emits_event() {
     });
 }
 
+#[test]
+fn partial_releases_draw_the_lock_down_until_it_closes() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        set_pk(BOB);
+
+        let one = Commitment::new(BASEPOINT);
+        let two = one.add(&one).unwrap();
+        let id = lock(EncryptedAmount::from_parts(two.to_bytes(), [5u8; 32]));
+        let part = EncryptedAmount::from_parts(one.to_bytes(), [6u8; 32]);
+
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release(
+                id,
+                &BOB,
+                part,
+                proof(&[])
+            )
+        );
+        assert_eq!(ConfidentialEscrow::escrow(id).unwrap().remaining, one);
+
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_refund(
+                id,
+                part,
+                proof(&[])
+            )
+        );
+        assert_eq!(ConfidentialEscrow::escrow(id), None);
+    });
+}
+
+#[test]
+fn partial_release_keeps_the_homomorphic_remainder_escrowed() {
+    new_test_ext().execute_with(|| {
+        use pallet_zkhe::PendingDeposits;
+
+        let escrow = ConfidentialEscrow::escrow_account();
+        set_pk(escrow);
+        set_pk(ALICE);
+        set_pk(BOB);
+
+        let one = Commitment::new(BASEPOINT);
+        let two = one.add(&one).unwrap();
+        let three = two.add(&one).unwrap();
+        let amount = |c: Commitment| EncryptedAmount::from_parts(c.to_bytes(), c.to_bytes());
+        let id = lock(amount(three));
+
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release_partial(
+                id,
+                &BOB,
+                amount(one),
+                proof(&[1]),
+                proof(&[2])
+            )
+        );
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::EscrowReleased {
+                id,
+                asset: ASSET,
+                to: BOB,
+                encrypted_amount: amount(one),
+            })
+        );
+        let record = ConfidentialEscrow::escrow(id).unwrap();
+        assert_eq!(record.encrypted_amount, amount(two));
+        assert_eq!(record.remaining, two);
+        // The remainder went back into escrow after the lock's own deposit
+        assert_eq!(
+            PendingDeposits::<Runtime>::get((escrow, ASSET, 1)),
+            Some(amount(two))
+        );
+
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release(
+                id,
+                &BOB,
+                amount(two),
+                proof(&[])
+            )
+        );
+        assert_eq!(ConfidentialEscrow::escrow(id), None);
+    });
+}
+
+#[test]
+fn partial_release_must_match_the_lock() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        set_pk(BOB);
+
+        let one = Commitment::new(BASEPOINT);
+        let two = one.add(&one).unwrap();
+        let three = two.add(&one).unwrap();
+        let amount = |c: Commitment| EncryptedAmount::from_parts(c.to_bytes(), c.to_bytes());
+        let id = lock(amount(three));
+
+        // A plain partial release draws the commitment down but not the ciphertext
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release(
+                id,
+                &BOB,
+                amount(one),
+                proof(&[])
+            )
+        );
+        assert_err!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release_partial(
+                id,
+                &BOB,
+                amount(one),
+                proof(&[]),
+                proof(&[])
+            ),
+            Error::<Runtime>::InvalidCiphertext
+        );
+        assert_err!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release_partial(
+                9,
+                &BOB,
+                amount(one),
+                proof(&[]),
+                proof(&[])
+            ),
+            Error::<Runtime>::UnknownEscrow
+        );
+        assert_eq!(ConfidentialEscrow::escrow(id).unwrap().remaining, two);
+    });
+}
+
+#[test]
+fn escrow_burn_takes_the_lock_out_of_supply_and_closes_it() {
+    new_test_ext().execute_with(|| {
+        use pallet_zkhe::{AvailableBalanceCommit, TotalSupplyCommit};
+
+        let escrow = ConfidentialEscrow::escrow_account();
+        set_pk(escrow);
+        set_pk(ALICE);
+
+        let one = Commitment::new(BASEPOINT);
+        let id = lock(EncryptedAmount::from_parts(one.to_bytes(), [5u8; 32]));
+
+        assert_ok!(<ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_burn(id));
+        assert_eq!(ConfidentialEscrow::escrow(id), None);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::EscrowBurned {
+                id,
+                asset: ASSET,
+                burned: one,
+            })
+        );
+        // No proof involved: the commitment is subtracted from escrow and supply
+        let burned = |c: Option<Commitment>| c.unwrap_or_default().add(&one).unwrap();
+        assert!(burned(AvailableBalanceCommit::<Runtime>::get(ASSET, escrow)).is_identity());
+        assert!(burned(TotalSupplyCommit::<Runtime>::get(ASSET)).is_identity());
+
+        assert_err!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_burn(id),
+            Error::<Runtime>::UnknownEscrow
+        );
+    });
+}
+
+#[test]
+fn release_and_refund_need_an_open_lock() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(BOB);
+
+        assert_err!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release(
+                0,
+                &BOB,
+                ct(22),
+                proof(&[])
+            ),
+            Error::<Runtime>::UnknownEscrow
+        );
+        assert_err!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_refund(
+                0,
+                ct(22),
+                proof(&[])
+            ),
+            Error::<Runtime>::UnknownEscrow
+        );
+    });
+}
+
 #[test]
 fn escrow_lock_fails_with_backend_error_when_missing_public_key() {
     new_test_ext().execute_with(|| {
@@ -169,13 +414,517 @@ fn escrow_lock_fails_with_backend_error_when_missing_public_key() {
         set_pk(ALICE);
         let delta = ct(7);
 
-        let res = <ConfidentialEscrow as CE<AccountId, AssetId>>::escrow_lock(
+        let res = <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_lock(
             ASSET,
             &ALICE,
             delta,
+            PURPOSE,
             proof(&[]),
         );
 
         assert_err!(res, Error::<Runtime>::BackendError);
+        assert_eq!(ConfidentialEscrow::escrow(0), None);
+    });
+}
+
+#[test]
+fn paused_asset_cannot_be_locked_released_or_refunded() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        let escrow = ConfidentialEscrow::escrow_account();
+        set_pk(escrow);
+        let id = lock(ct(1));
+        assert_ok!(Zkhe::pause_asset(RuntimeOrigin::root(), ASSET));
+
+        type Escrow = ConfidentialEscrow;
+        assert_err!(
+            <Escrow as CE<AccountId, AssetId>>::escrow_lock(
+                ASSET,
+                &ALICE,
+                ct(1),
+                PURPOSE,
+                proof(&[])
+            ),
+            Error::<Runtime>::AssetPaused
+        );
+        assert_err!(
+            <Escrow as CE<AccountId, AssetId>>::escrow_release(id, &ALICE, ct(1), proof(&[])),
+            Error::<Runtime>::AssetPaused
+        );
+        assert_err!(
+            <Escrow as CE<AccountId, AssetId>>::escrow_refund(id, ct(1), proof(&[])),
+            Error::<Runtime>::AssetPaused
+        );
+        assert!(ConfidentialEscrow::escrow(id).is_some());
+
+        assert_ok!(Zkhe::unpause_asset(RuntimeOrigin::root(), ASSET));
+        assert_ok!(<Escrow as CE<AccountId, AssetId>>::escrow_refund(
+            id,
+            ct(1),
+            proof(&[])
+        ));
+    });
+}
+
+#[test]
+fn expired_locks_are_announced_and_refundable_by_anyone() {
+    new_test_ext().execute_with(|| {
+        use frame_support::{traits::Hooks, weights::Weight};
+
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        let delta = ct(44);
+        let id = <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_lock_until(
+            ASSET,
+            &ALICE,
+            delta,
+            PURPOSE,
+            5,
+            proof(&[]),
+        )
+        .expect("locked");
+        assert_eq!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_expires_at(id),
+            Some(5)
+        );
+
+        // Not refundable by others before its expiry
+        assert_err!(
+            ConfidentialEscrow::refund_expired(RuntimeOrigin::signed(BOB), id, delta, proof(&[])),
+            Error::<Runtime>::NotExpired
+        );
+
+        System::set_block_number(5);
+        Pallet::<Runtime>::on_idle(5, Weight::MAX);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::EscrowExpired {
+                id,
+                asset: ASSET,
+                owner: ALICE,
+            })
+        );
+        assert!(!ExpiringEscrows::<Runtime>::contains_key(5, id));
+
+        // Anyone may now send it back to the owner
+        assert_ok!(ConfidentialEscrow::refund_expired(
+            RuntimeOrigin::signed(BOB),
+            id,
+            delta,
+            proof(&[])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::EscrowRefunded {
+                id,
+                asset: ASSET,
+                to: ALICE,
+                encrypted_amount: delta,
+            })
+        );
+        assert_eq!(ConfidentialEscrow::escrow(id), None);
+    });
+}
+
+#[test]
+fn locks_without_expiry_cannot_be_refunded_by_others() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        let id = lock(ct(45));
+
+        System::set_block_number(1_000);
+        assert_err!(
+            ConfidentialEscrow::refund_expired(RuntimeOrigin::signed(BOB), id, ct(45), proof(&[])),
+            Error::<Runtime>::NotExpired
+        );
+        assert_err!(
+            ConfidentialEscrow::refund_expired(RuntimeOrigin::signed(BOB), 9, ct(45), proof(&[])),
+            Error::<Runtime>::UnknownEscrow
+        );
+    });
+}
+
+#[test]
+fn policy_bound_releases_need_their_approvals() {
+    new_test_ext().execute_with(|| {
+        const ARBITER: AccountId = 3;
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        set_pk(BOB);
+        let delta = ct(50);
+        let id = lock(delta);
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_set_release_policy(
+                id,
+                vec![BOB, ARBITER],
+                2
+            )
+        );
+        assert_eq!(
+            ConfidentialEscrow::release_policy(id),
+            Some((vec![BOB, ARBITER], 2))
+        );
+
+        assert_err!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_release(
+                id,
+                &BOB,
+                delta,
+                proof(&[])
+            ),
+            Error::<Runtime>::NotApproved
+        );
+        assert_err!(
+            ConfidentialEscrow::approve_release(RuntimeOrigin::signed(ALICE), id, BOB, delta),
+            Error::<Runtime>::NotApprover
+        );
+
+        assert_ok!(ConfidentialEscrow::approve_release(
+            RuntimeOrigin::signed(BOB),
+            id,
+            BOB,
+            delta
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::ReleaseApproved {
+                id,
+                who: BOB,
+                to: BOB,
+                encrypted_amount: delta,
+                approvals: 1,
+            })
+        );
+        assert_err!(
+            ConfidentialEscrow::approve_release(RuntimeOrigin::signed(BOB), id, BOB, delta),
+            Error::<Runtime>::AlreadyApproved
+        );
+        assert_err!(
+            ConfidentialEscrow::release_approved(RuntimeOrigin::signed(ALICE), id, proof(&[])),
+            Error::<Runtime>::NotApproved
+        );
+        assert_err!(
+            ConfidentialEscrow::approve_release(RuntimeOrigin::signed(ARBITER), id, ALICE, delta),
+            Error::<Runtime>::ReleaseMismatch
+        );
+
+        assert_ok!(ConfidentialEscrow::approve_release(
+            RuntimeOrigin::signed(ARBITER),
+            id,
+            BOB,
+            delta
+        ));
+        assert_ok!(ConfidentialEscrow::release_approved(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            proof(&[])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::EscrowReleased {
+                id,
+                asset: ASSET,
+                to: BOB,
+                encrypted_amount: delta,
+            })
+        );
+        assert_eq!(ConfidentialEscrow::escrow(id), None);
+        assert_eq!(ConfidentialEscrow::release_policy(id), None);
+    });
+}
+
+#[test]
+fn pending_approvals_lapse_after_the_ttl() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        set_pk(BOB);
+        let id = lock(ct(51));
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_set_release_policy(
+                id,
+                vec![ALICE, BOB],
+                1
+            )
+        );
+        assert_ok!(ConfidentialEscrow::approve_release(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            BOB,
+            ct(51)
+        ));
+
+        // Approved at block 1 with a TTL of 100
+        System::set_block_number(102);
+        assert_err!(
+            ConfidentialEscrow::release_approved(RuntimeOrigin::signed(BOB), id, proof(&[])),
+            Error::<Runtime>::NotApproved
+        );
+        // Once lapsed, a different release may be proposed
+        assert_ok!(ConfidentialEscrow::approve_release(
+            RuntimeOrigin::signed(BOB),
+            id,
+            ALICE,
+            ct(51)
+        ));
+        assert_ok!(ConfidentialEscrow::release_approved(
+            RuntimeOrigin::signed(BOB),
+            id,
+            proof(&[])
+        ));
+        assert_eq!(ConfidentialEscrow::escrow(id), None);
+    });
+}
+
+#[test]
+fn release_policies_are_validated() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        let id = lock(ct(52));
+        let set = |id, approvers: Vec<AccountId>, threshold| {
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_set_release_policy(
+                id, approvers, threshold,
+            )
+        };
+
+        assert_err!(set(9, vec![BOB], 1), Error::<Runtime>::UnknownEscrow);
+        assert_err!(set(id, vec![BOB], 0), Error::<Runtime>::InvalidPolicy);
+        assert_err!(set(id, vec![BOB], 2), Error::<Runtime>::InvalidPolicy);
+        assert_err!(set(id, vec![BOB, BOB], 2), Error::<Runtime>::InvalidPolicy);
+        assert_err!(
+            set(id, vec![2, 3, 4, 5, 6], 2),
+            Error::<Runtime>::TooManyApprovers
+        );
+        assert_ok!(set(id, vec![BOB], 1));
+        assert_err!(set(id, vec![BOB], 1), Error::<Runtime>::PolicyExists);
+    });
+}
+
+#[test]
+fn user_locks_are_released_by_their_owner_or_the_arbiter() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        set_pk(BOB);
+
+        assert_ok!(ConfidentialEscrow::lock(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            ct(60),
+            PURPOSE,
+            None,
+            proof(&[])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::EscrowLocked {
+                id: 0,
+                asset: ASSET,
+                from: ALICE,
+                purpose: PURPOSE,
+                encrypted_amount: ct(60),
+            })
+        );
+        assert_err!(
+            ConfidentialEscrow::release_to(RuntimeOrigin::signed(BOB), 0, BOB, ct(60), proof(&[])),
+            Error::<Runtime>::NotOwner
+        );
+        assert_ok!(ConfidentialEscrow::release_to(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            BOB,
+            ct(60),
+            proof(&[])
+        ));
+        assert_eq!(ConfidentialEscrow::escrow(0), None);
+
+        assert_ok!(ConfidentialEscrow::lock(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            ct(61),
+            PURPOSE,
+            None,
+            proof(&[])
+        ));
+        assert_ok!(ConfidentialEscrow::release_to(
+            RuntimeOrigin::root(),
+            1,
+            BOB,
+            ct(61),
+            proof(&[])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::EscrowReleased {
+                id: 1,
+                asset: ASSET,
+                to: BOB,
+                encrypted_amount: ct(61),
+            })
+        );
+    });
+}
+
+#[test]
+fn only_the_arbiter_refunds_user_locks() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        assert_ok!(ConfidentialEscrow::lock(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            ct(62),
+            PURPOSE,
+            None,
+            proof(&[])
+        ));
+
+        assert_err!(
+            ConfidentialEscrow::refund(RuntimeOrigin::signed(ALICE), 0, ct(62), proof(&[])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(ConfidentialEscrow::refund(
+            RuntimeOrigin::root(),
+            0,
+            ct(62),
+            proof(&[])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::EscrowRefunded {
+                id: 0,
+                asset: ASSET,
+                to: ALICE,
+                encrypted_amount: ct(62),
+            })
+        );
+        assert_eq!(ConfidentialEscrow::escrow(0), None);
+    });
+}
+
+#[test]
+fn user_calls_leave_pallet_locks_alone() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        let id = lock(ct(63));
+
+        assert_err!(
+            ConfidentialEscrow::release_to(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                ALICE,
+                ct(63),
+                proof(&[])
+            ),
+            Error::<Runtime>::NotUserLock
+        );
+        assert_err!(
+            ConfidentialEscrow::refund(RuntimeOrigin::root(), id, ct(63), proof(&[])),
+            Error::<Runtime>::NotUserLock
+        );
+    });
+}
+
+#[test]
+fn public_and_confidential_escrow_share_one_account_and_ledger() {
+    new_test_ext().execute_with(|| {
+        use confidential_assets_primitives::EscrowTrust;
+        use frame_support::traits::fungible::{Inspect, Mutate};
+
+        let escrow = ConfidentialEscrow::escrow_account();
+        set_pk(escrow);
+        set_pk(ALICE);
+        assert_ok!(<Balances as Mutate<_>>::mint_into(&ALICE, 1_000));
+
+        assert_ok!(<ConfidentialEscrow as EscrowTrust<_, _, _>>::escrow_lock(
+            ASSET, &ALICE, 100
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialEscrow(Event::PublicLocked {
+                asset: ASSET,
+                from: ALICE,
+                amount: 100,
+            })
+        );
+        let id = lock(ct(70));
+        assert_eq!(
+            ConfidentialEscrow::asset_totals(ASSET),
+            AssetEscrow {
+                public: 100,
+                locks: 1
+            }
+        );
+        assert_eq!(<Balances as Inspect<_>>::balance(&escrow), 100);
+
+        assert_ok!(<ConfidentialEscrow as EscrowTrust<_, _, _>>::escrow_release(ASSET, &BOB, 60));
+        assert_err!(
+            <ConfidentialEscrow as EscrowTrust<_, _, _>>::escrow_refund(ASSET, &ALICE, 41),
+            Error::<Runtime>::InsufficientEscrow
+        );
+        assert_ok!(<ConfidentialEscrow as EscrowTrust<_, _, _>>::escrow_refund(
+            ASSET, &ALICE, 40
+        ));
+        assert_eq!(<Balances as Inspect<_>>::balance(&BOB), 60);
+        assert_eq!(<Balances as Inspect<_>>::balance(&ALICE), 940);
+
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_refund(
+                id,
+                ct(70),
+                proof(&[])
+            )
+        );
+        assert_eq!(
+            ConfidentialEscrow::asset_totals(ASSET),
+            AssetEscrow::default()
+        );
+    });
+}
+
+#[test]
+fn reconciliation_sums_the_open_locks_next_to_the_escrow_balance() {
+    new_test_ext().execute_with(|| {
+        set_pk(ConfidentialEscrow::escrow_account());
+        set_pk(ALICE);
+        let one = Commitment::new(BASEPOINT);
+        let two = one.add(&one).unwrap();
+        let amount = |c: Commitment| EncryptedAmount::from_parts(c.to_bytes(), c.to_bytes());
+
+        assert_eq!(
+            ConfidentialEscrow::reconciliation(ASSET),
+            EscrowReconciliation {
+                available: Commitment::default(),
+                pending: Commitment::default(),
+                locked: Some(Commitment::identity()),
+                locks: 0,
+            }
+        );
+
+        lock(amount(one));
+        let id = lock(amount(two));
+        let three = two.add(&one).unwrap();
+        let report = ConfidentialEscrow::reconciliation(ASSET);
+        assert_eq!(report.locked, Some(three));
+        assert_eq!(report.locks, 2);
+        // The locks hold exactly what was transferred into escrow
+        assert_eq!(report.pending, three);
+
+        // A partial refund draws the lock and the escrow balance down alike
+        assert_ok!(
+            <ConfidentialEscrow as CE<AccountId, AssetId, u64>>::escrow_refund(
+                id,
+                amount(one),
+                proof(&[])
+            )
+        );
+        let report = ConfidentialEscrow::reconciliation(ASSET);
+        assert_eq!(report.locked, Some(two));
+        assert_eq!(report.available.add(&report.pending).ok(), Some(two));
+        assert_eq!(report.locks, 2);
+        assert_eq!(ConfidentialEscrow::reconciliation(ASSET + 1).locks, 0);
     });
 }
@@ -0,0 +1,321 @@
+//! pallet-confidential-vesting — encrypted vesting grants paid out of confidential escrow.
+//!
+//! A grantor locks an encrypted total in escrow together with its split into per-period
+//! tranches. The pallet checks the split homomorphically: the tranche ciphertexts must
+//! add up to the locked total, so no amount is ever disclosed. Tranche `i` vests at
+//! `cliff + i * period`; the beneficiary claims vested tranches one at a time, in order,
+//! each with a proof releasing that tranche from escrow.
+//!
+//! The grantor may revoke a grant at any time. Tranches that have vested stay claimable
+//! by the beneficiary; the sum of the unvested ones is refunded to the grantor with a
+//! refund proof.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{Saturating, UniqueSaturatedInto, Zero};
+use sp_std::prelude::*;
+
+use confidential_assets_primitives::{
+    ConfidentialEscrow, EncryptedAmount, EscrowId, EscrowPurpose, InputProof,
+};
+
+pub use pallet::*;
+
+pub type GrantId = u64;
+
+/// Purpose tag of the escrow locks grants open.
+pub const GRANT_PURPOSE: EscrowPurpose = *b"vestings";
+
+/// A vesting grant. Its tranche ciphertexts are stored separately in [`Tranches`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct Grant<AccountId, AssetId, BlockNumber> {
+    pub asset: AssetId,
+    pub grantor: AccountId,
+    pub beneficiary: AccountId,
+    /// Block the first tranche vests at.
+    pub cliff: BlockNumber,
+    /// Blocks between consecutive tranches.
+    pub period: BlockNumber,
+    /// Tranches the beneficiary has claimed so far.
+    pub claimed: u32,
+    /// Escrow lock holding the unclaimed tranches.
+    pub escrow_id: EscrowId,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        type AssetId: Parameter + Member + Copy + Ord + MaxEncodedLen;
+
+        /// Escrow holding the unclaimed part of every grant.
+        type Escrow: ConfidentialEscrow<Self::AccountId, Self::AssetId, BlockNumberFor<Self>>;
+
+        /// Maximum number of tranches in one grant.
+        #[pallet::constant]
+        type MaxTranches: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    pub trait WeightInfo {
+        fn create_grant(tranches: u32) -> Weight;
+        fn claim() -> Weight;
+        fn revoke(tranches: u32) -> Weight;
+    }
+    impl WeightInfo for () {
+        fn create_grant(tranches: u32) -> Weight {
+            Weight::from_parts(60_000, 0)
+                .saturating_add(Weight::from_parts(5_000, 0).saturating_mul(tranches.into()))
+        }
+        fn claim() -> Weight {
+            Weight::from_parts(60_000, 0)
+        }
+        fn revoke(tranches: u32) -> Weight {
+            Weight::from_parts(60_000, 0)
+                .saturating_add(Weight::from_parts(5_000, 0).saturating_mul(tranches.into()))
+        }
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::storage]
+    pub type NextGrantId<T: Config> = StorageValue<_, GrantId, ValueQuery>;
+
+    #[pallet::storage]
+    pub type Grants<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        GrantId,
+        Grant<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Tranche ciphertexts of each grant, in vesting order. Revocation drops the
+    /// unvested ones.
+    #[pallet::storage]
+    pub type Tranches<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        GrantId,
+        BoundedVec<EncryptedAmount, T::MaxTranches>,
+        ValueQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        GrantCreated {
+            id: GrantId,
+            asset: T::AssetId,
+            grantor: T::AccountId,
+            beneficiary: T::AccountId,
+            total: EncryptedAmount,
+            tranches: u32,
+        },
+        TrancheClaimed {
+            id: GrantId,
+            index: u32,
+            encrypted_amount: EncryptedAmount,
+        },
+        /// The unvested remainder went back to the grantor; `vested` tranches stay
+        /// claimable.
+        GrantRevoked {
+            id: GrantId,
+            vested: u32,
+            remainder: EncryptedAmount,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NoTranches,
+        ZeroPeriod,
+        /// A tranche or the total is not a valid ciphertext.
+        InvalidCiphertext,
+        /// The tranches do not add up to the total.
+        SplitMismatch,
+        UnknownGrant,
+        NotBeneficiary,
+        NotGrantor,
+        /// The next tranche has not vested yet.
+        NotVested,
+        /// Every tranche has vested; nothing is left to refund.
+        FullyVested,
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Number of tranches of `grant` vested by `now`, out of `count`.
+        pub fn vested(
+            grant: &Grant<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+            count: u32,
+            now: BlockNumberFor<T>,
+        ) -> u32 {
+            if now < grant.cliff {
+                return 0;
+            }
+            let periods: u32 = ((now - grant.cliff) / grant.period).unique_saturated_into();
+            periods.saturating_add(1).min(count)
+        }
+
+        fn sum(tranches: &[EncryptedAmount]) -> Result<EncryptedAmount, DispatchError> {
+            tranches
+                .iter()
+                .try_fold(EncryptedAmount::zero(), |acc, t| acc.add(t))
+                .map_err(|_| Error::<T>::InvalidCiphertext.into())
+        }
+
+        fn new_grant_id() -> GrantId {
+            let id = NextGrantId::<T>::get();
+            NextGrantId::<T>::put(id.wrapping_add(1));
+            id
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Lock `total` in escrow and grant it to `beneficiary` in `tranches`, the first
+        /// vesting at `cliff` and the rest every `period` blocks after it.
+        ///
+        /// The tranches must add up to `total` homomorphically. `lock_proof` is the
+        /// transfer proof moving `total` from the grantor to the escrow.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_grant(tranches.len() as u32))]
+        #[transactional]
+        pub fn create_grant(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            beneficiary: T::AccountId,
+            total: EncryptedAmount,
+            lock_proof: InputProof,
+            cliff: BlockNumberFor<T>,
+            period: BlockNumberFor<T>,
+            tranches: BoundedVec<EncryptedAmount, T::MaxTranches>,
+        ) -> DispatchResult {
+            let grantor = ensure_signed(origin)?;
+            ensure!(!tranches.is_empty(), Error::<T>::NoTranches);
+            ensure!(!period.is_zero(), Error::<T>::ZeroPeriod);
+            ensure!(Self::sum(&tranches)? == total, Error::<T>::SplitMismatch);
+
+            let escrow_id =
+                T::Escrow::escrow_lock(asset, &grantor, total, GRANT_PURPOSE, lock_proof)?;
+
+            let id = Self::new_grant_id();
+            let count = tranches.len() as u32;
+            Grants::<T>::insert(
+                id,
+                Grant {
+                    asset,
+                    grantor: grantor.clone(),
+                    beneficiary: beneficiary.clone(),
+                    cliff,
+                    period,
+                    claimed: 0,
+                    escrow_id,
+                },
+            );
+            Tranches::<T>::insert(id, tranches);
+            Self::deposit_event(Event::GrantCreated {
+                id,
+                asset,
+                grantor,
+                beneficiary,
+                total,
+                tranches: count,
+            });
+            Ok(())
+        }
+
+        /// Claim the next vested tranche of grant `id`. `release_proof` is the transfer
+        /// proof moving that tranche from the escrow to the beneficiary.
+        ///
+        /// The grant is removed once its last tranche is claimed.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::claim())]
+        #[transactional]
+        pub fn claim(
+            origin: OriginFor<T>,
+            id: GrantId,
+            release_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut grant = Grants::<T>::get(id).ok_or(Error::<T>::UnknownGrant)?;
+            ensure!(who == grant.beneficiary, Error::<T>::NotBeneficiary);
+            let tranches = Tranches::<T>::get(id);
+            let count = tranches.len() as u32;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                grant.claimed < Self::vested(&grant, count, now),
+                Error::<T>::NotVested
+            );
+
+            let index = grant.claimed;
+            let encrypted_amount = tranches[index as usize];
+            T::Escrow::escrow_release(grant.escrow_id, &who, encrypted_amount, release_proof)?;
+
+            grant.claimed.saturating_inc();
+            if grant.claimed == count {
+                Grants::<T>::remove(id);
+                Tranches::<T>::remove(id);
+            } else {
+                Grants::<T>::insert(id, grant);
+            }
+            Self::deposit_event(Event::TrancheClaimed {
+                id,
+                index,
+                encrypted_amount,
+            });
+            Ok(())
+        }
+
+        /// Revoke grant `id`, refunding the sum of its unvested tranches to the grantor.
+        /// `refund_proof` is the transfer proof moving that sum from the escrow back.
+        ///
+        /// Vested tranches the beneficiary has not claimed yet remain claimable.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::revoke(T::MaxTranches::get()))]
+        #[transactional]
+        pub fn revoke(
+            origin: OriginFor<T>,
+            id: GrantId,
+            refund_proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let grant = Grants::<T>::get(id).ok_or(Error::<T>::UnknownGrant)?;
+            ensure!(who == grant.grantor, Error::<T>::NotGrantor);
+            let mut tranches = Tranches::<T>::get(id);
+            let now = frame_system::Pallet::<T>::block_number();
+            let vested = Self::vested(&grant, tranches.len() as u32, now);
+            ensure!((vested as usize) < tranches.len(), Error::<T>::FullyVested);
+
+            let remainder = Self::sum(&tranches[vested as usize..])?;
+            T::Escrow::escrow_refund(grant.escrow_id, remainder, refund_proof)?;
+
+            if grant.claimed == vested {
+                Grants::<T>::remove(id);
+                Tranches::<T>::remove(id);
+            } else {
+                tranches.truncate(vested as usize);
+                Tranches::<T>::insert(id, tranches);
+            }
+            Self::deposit_event(Event::GrantRevoked {
+                id,
+                vested,
+                remainder,
+            });
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Error, Event, Grants, Tranches, mock::*};
+use confidential_assets_primitives::{Commitment, EncryptedAmount};
+use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT, scalar::Scalar};
+use frame_support::{BoundedVec, assert_noop, assert_ok};
+
+// helpers
+// A well-formed ciphertext of `x`, so tranches add up homomorphically.
+fn ct(x: u64) -> EncryptedAmount {
+    let p = (RISTRETTO_BASEPOINT_POINT * Scalar::from(x)).compress();
+    EncryptedAmount::from_parts(p.to_bytes(), p.to_bytes())
+}
+fn tranches(xs: &[u64]) -> BoundedVec<EncryptedAmount, frame_support::traits::ConstU32<8>> {
+    xs.iter()
+        .map(|x| ct(*x))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("bounded vec")
+}
+fn last_event() -> RuntimeEvent {
+    frame_system::Pallet::<Runtime>::events()
+        .pop()
+        .expect("event")
+        .event
+}
+fn setup() {
+    set_pk(ALICE);
+    set_pk(BOB);
+    set_pk(pallet_confidential_escrow::Pallet::<Runtime>::escrow_account());
+}
+// Grant 6 to BOB in tranches 1, 2, 3 vesting at blocks 10, 15 and 20.
+fn grant() {
+    assert_ok!(ConfidentialVesting::create_grant(
+        RuntimeOrigin::signed(ALICE),
+        ASSET,
+        BOB,
+        ct(6),
+        proof(&[1]),
+        10,
+        5,
+        tranches(&[1, 2, 3]),
+    ));
+}
+
+#[test]
+fn create_grant_requires_tranches_to_add_up_to_the_total() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert_noop!(
+            ConfidentialVesting::create_grant(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(7),
+                proof(&[1]),
+                10,
+                5,
+                tranches(&[1, 2, 3]),
+            ),
+            Error::<Runtime>::SplitMismatch
+        );
+        assert_noop!(
+            ConfidentialVesting::create_grant(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(0),
+                proof(&[1]),
+                10,
+                5,
+                tranches(&[]),
+            ),
+            Error::<Runtime>::NoTranches
+        );
+
+        grant();
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialVesting(Event::GrantCreated {
+                id: 0,
+                asset: ASSET,
+                grantor: ALICE,
+                beneficiary: BOB,
+                total: ct(6),
+                tranches: 3,
+            })
+        );
+        assert_eq!(Tranches::<Runtime>::get(0).len(), 3);
+    });
+}
+
+#[test]
+fn beneficiary_claims_tranches_as_they_vest() {
+    new_test_ext().execute_with(|| {
+        setup();
+        grant();
+
+        // Before the cliff nothing is claimable, and only the beneficiary may claim.
+        assert_noop!(
+            ConfidentialVesting::claim(RuntimeOrigin::signed(BOB), 0, proof(&[2])),
+            Error::<Runtime>::NotVested
+        );
+        System::set_block_number(10);
+        assert_noop!(
+            ConfidentialVesting::claim(RuntimeOrigin::signed(CHARLIE), 0, proof(&[2])),
+            Error::<Runtime>::NotBeneficiary
+        );
+        assert_ok!(ConfidentialVesting::claim(
+            RuntimeOrigin::signed(BOB),
+            0,
+            proof(&[2])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialVesting(Event::TrancheClaimed {
+                id: 0,
+                index: 0,
+                encrypted_amount: ct(1),
+            })
+        );
+        assert_noop!(
+            ConfidentialVesting::claim(RuntimeOrigin::signed(BOB), 0, proof(&[3])),
+            Error::<Runtime>::NotVested
+        );
+
+        // Both remaining tranches have vested by block 20.
+        System::set_block_number(20);
+        assert_ok!(ConfidentialVesting::claim(
+            RuntimeOrigin::signed(BOB),
+            0,
+            proof(&[3])
+        ));
+        assert_ok!(ConfidentialVesting::claim(
+            RuntimeOrigin::signed(BOB),
+            0,
+            proof(&[4])
+        ));
+        assert!(Grants::<Runtime>::get(0).is_none());
+        assert!(Tranches::<Runtime>::get(0).is_empty());
+        // Every tranche has left escrow, closing the grant's lock
+        assert!(pallet_confidential_escrow::Escrows::<Runtime>::get(0).is_none());
+    });
+}
+
+#[test]
+fn revoke_refunds_the_unvested_remainder() {
+    new_test_ext().execute_with(|| {
+        setup();
+        grant();
+
+        System::set_block_number(15);
+        assert_noop!(
+            ConfidentialVesting::revoke(RuntimeOrigin::signed(BOB), 0, proof(&[2])),
+            Error::<Runtime>::NotGrantor
+        );
+        assert_ok!(ConfidentialVesting::revoke(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            proof(&[2])
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::ConfidentialVesting(Event::GrantRevoked {
+                id: 0,
+                vested: 2,
+                remainder: ct(3),
+            })
+        );
+
+        // The two vested tranches stay claimable; nothing more vests. The mock commits
+        // to amounts by their nonce point.
+        assert_eq!(
+            pallet_confidential_escrow::Escrows::<Runtime>::get(0)
+                .unwrap()
+                .remaining,
+            Commitment::new(ct(3).nonce_point())
+        );
+        System::set_block_number(100);
+        assert_noop!(
+            ConfidentialVesting::revoke(RuntimeOrigin::signed(ALICE), 0, proof(&[3])),
+            Error::<Runtime>::FullyVested
+        );
+        assert_ok!(ConfidentialVesting::claim(
+            RuntimeOrigin::signed(BOB),
+            0,
+            proof(&[4])
+        ));
+        assert_ok!(ConfidentialVesting::claim(
+            RuntimeOrigin::signed(BOB),
+            0,
+            proof(&[5])
+        ));
+        assert!(Grants::<Runtime>::get(0).is_none());
+        assert!(pallet_confidential_escrow::Escrows::<Runtime>::get(0).is_none());
+    });
+}
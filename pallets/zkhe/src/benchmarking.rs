@@ -5,7 +5,9 @@
 use super::*;
 use confidential_assets_primitives::*;
 use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Zero;
 use sp_std::vec::Vec;
 use zkhe_vectors::*;
 
@@ -28,7 +30,7 @@ fn setup_receiver_pk<T: Config>(who: &T::AccountId) {
 /// Setup sender's available balance commitment for transfer benchmarks
 fn setup_sender_available_balance<T: Config>(asset: T::AssetId, who: &T::AccountId) {
     // Use TRANSFER_FROM_OLD_COMM_32 as the initial available balance
-    AvailableBalanceCommit::<T>::insert(asset, who, TRANSFER_FROM_OLD_COMM_32);
+    AvailableBalanceCommit::<T>::insert(asset, who, Commitment::new(TRANSFER_FROM_OLD_COMM_32));
 }
 
 /// Setup receiver's pending balance commitment (starts at identity/zero for fresh receiver)
@@ -39,30 +41,90 @@ fn setup_receiver_pending_balance<T: Config>(asset: T::AssetId, who: &T::Account
     let _ = (asset, who); // silence unused warning
 }
 
+/// Enable `asset` with the default policy, as runtimes requiring a configuration need
+fn enable_asset<T: Config>(asset: T::AssetId) {
+    ConfidentialAssetConfig::<T>::insert(asset, AssetConfig::default());
+}
+
+/// Give `sender` just enough to cover one pending deposit's storage deposit
+fn fund_sender<T: Config>(sender: &T::AccountId) {
+    let funds = T::Currency::minimum_balance().saturating_add(T::PendingDeposit::get());
+    T::Currency::set_balance(sender, funds);
+}
+
+/// Record `sender` as the source of `who`'s pending deposit `id`, holding its storage
+/// deposit, so removing the deposit pays for the release
+fn setup_deposit_source<T: Config>(
+    asset: T::AssetId,
+    who: &T::AccountId,
+    id: u64,
+    sender: &T::AccountId,
+    deadline: BlockNumberFor<T>,
+) {
+    let deposit = T::PendingDeposit::get();
+    fund_sender::<T>(sender);
+    T::Currency::hold(&HoldReason::PendingDeposit.into(), sender, deposit)
+        .expect("sender is funded");
+    PendingDepositSource::<T>::insert(
+        (who.clone(), asset, id),
+        DepositSource {
+            sender: sender.clone(),
+            deadline,
+            deposit,
+        },
+    );
+    PendingDepositCount::<T>::mutate(who, asset, |count| *count += 1);
+}
+
 /// Setup pending deposits for accept_pending benchmarks
-/// The pending deposit ciphertext must have C = TRANSFER_DELTA_COMM_32 as its first 32 bytes
-fn setup_pending_deposit<T: Config>(asset: T::AssetId, who: &T::AccountId) {
-    // The pallet extracts the first 32 bytes (C part) from the ciphertext to use as commitment
-    // We need to construct a ciphertext where C = TRANSFER_DELTA_COMM_32
-    // D part (second 32 bytes) can be from the actual delta ciphertext
-    let mut fake_ct: [u8; 64] = [0u8; 64];
-    fake_ct[0..32].copy_from_slice(&TRANSFER_DELTA_COMM_32);
-    fake_ct[32..64].copy_from_slice(&TRANSFER_DELTA_CT_64[32..64]);
-
-    // Insert the pending deposit UTXO at id=0
-    PendingDeposits::<T>::insert((who.clone(), asset, 0u64), fake_ct);
+/// The pending deposit must be committed to by TRANSFER_DELTA_COMM_32
+fn setup_pending_deposit<T: Config>(asset: T::AssetId, who: &T::AccountId, sender: &T::AccountId) {
+    // Insert the pending deposit UTXO at id=0, sent by `sender`
+    PendingDeposits::<T>::insert(
+        (who.clone(), asset, 0u64),
+        EncryptedAmount::new(TRANSFER_DELTA_CT_64),
+    );
+    PendingDepositCommit::<T>::insert(
+        (who.clone(), asset, 0u64),
+        Commitment::new(TRANSFER_DELTA_COMM_32),
+    );
     NextPendingDepositId::<T>::insert(who, asset, 1u64);
+    setup_deposit_source::<T>(asset, who, 0, sender, Zero::zero());
 
     // The pending balance commitment should be TRANSFER_DELTA_COMM_32
     // (this is the ΔC that the receiver will accept)
     // The accept envelope proves: avail_new = avail_old + ΔC, pending_new = pending_old - ΔC
-    PendingBalanceCommit::<T>::insert(asset, who, TRANSFER_DELTA_COMM_32);
+    PendingBalanceCommit::<T>::insert(asset, who, Commitment::new(TRANSFER_DELTA_COMM_32));
+}
+
+/// Setup `n` pending deposits whose commitments sum to TRANSFER_DELTA_COMM_32, so the
+/// vector accept envelope claims all of them. The first `n - 1` reuse an arbitrary
+/// vector point and the last one makes up the difference.
+fn setup_pending_deposits<T: Config>(asset: T::AssetId, who: &T::AccountId, n: u32) -> Vec<u64> {
+    let filler = Commitment::new(MINT_TO_NEW_COMM_32);
+    let mut last = Commitment::new(TRANSFER_DELTA_COMM_32);
+    let ct = EncryptedAmount::new(TRANSFER_DELTA_CT_64);
+    let sender: T::AccountId = account("sender", 0, 0);
+    for id in 0..n.saturating_sub(1) as u64 {
+        PendingDeposits::<T>::insert((who.clone(), asset, id), ct);
+        PendingDepositCommit::<T>::insert((who.clone(), asset, id), filler);
+        setup_deposit_source::<T>(asset, who, id, &sender, Zero::zero());
+        last = last.sub(&filler).expect("vector points decompress");
+    }
+    let last_id = n.saturating_sub(1) as u64;
+    PendingDeposits::<T>::insert((who.clone(), asset, last_id), ct);
+    PendingDepositCommit::<T>::insert((who.clone(), asset, last_id), last);
+    setup_deposit_source::<T>(asset, who, last_id, &sender, Zero::zero());
+    NextPendingDepositId::<T>::insert(who, asset, last_id + 1);
+    PendingBalanceCommit::<T>::insert(asset, who, Commitment::new(TRANSFER_DELTA_COMM_32));
+    (0..=last_id).collect()
 }
 
 /// Build accept_input proof for accept_pending benchmark
-/// Layout: u16 count || ids (u64 LE) * count || accept_envelope
-fn build_accept_input(ids: &[u64], envelope: &[u8]) -> InputProof {
-    let mut v = Vec::with_capacity(2 + ids.len() * 8 + envelope.len());
+/// Layout: proof system id || u16 count || ids (u64 LE) * count || accept_envelope
+fn build_accept_input<T: Config>(ids: &[u64], envelope: &[u8]) -> InputProof {
+    let mut v = Vec::with_capacity(1 + 2 + ids.len() * 8 + envelope.len());
+    v.push(T::Verifier::ID);
     let count = ids.len() as u16;
     v.extend_from_slice(&count.to_le_bytes());
     for id in ids {
@@ -82,15 +144,18 @@ mod benchmarks {
         let recipient: T::AccountId = account("recipient", 0, 0);
         let asset = T::AssetId::default();
 
-        // Setup: both accounts need public keys, sender needs available balance
+        // Setup: both accounts need public keys, sender needs available balance and
+        // native funds for the storage deposit
+        enable_asset::<T>(asset);
         setup_sender_pk::<T>(&caller);
+        fund_sender::<T>(&caller);
         setup_receiver_pk::<T>(&recipient);
         setup_sender_available_balance::<T>(asset, &caller);
         setup_receiver_pending_balance::<T>(asset, &recipient);
 
         // Use real vectors
-        let encrypted_amount: EncryptedAmount = TRANSFER_DELTA_CT_64;
-        let proof: InputProof = Vec::from(TRANSFER_BUNDLE)
+        let encrypted_amount = EncryptedAmount::new(TRANSFER_DELTA_CT_64);
+        let proof: InputProof = seal_proof(T::Verifier::ID, TRANSFER_BUNDLE)
             .try_into()
             .expect("proof fits in BoundedVec<8192>");
 
@@ -116,10 +181,10 @@ mod benchmarks {
         // Setup: receiver needs public key and pending deposits
         // For accept_pending, we use receiver vectors since they're accepting incoming funds
         setup_receiver_pk::<T>(&caller);
-        setup_pending_deposit::<T>(asset, &caller);
+        setup_pending_deposit::<T>(asset, &caller, &account("sender", 0, 0));
 
         // Build accept envelope with deposit id=0 and the real accept envelope
-        let accept_envelope = build_accept_input(&[0u64], ACCEPT_ENVELOPE);
+        let accept_envelope = build_accept_input::<T>(&[0u64], ACCEPT_ENVELOPE);
 
         #[extrinsic_call]
         accept_pending(RawOrigin::Signed(caller.clone()), asset, accept_envelope);
@@ -132,6 +197,238 @@ mod benchmarks {
         )));
     }
 
+    #[benchmark]
+    fn claim_many(n: Linear<1, { T::MaxClaimUtxos::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let asset = T::AssetId::default();
+
+        setup_receiver_pk::<T>(&caller);
+        let ids = setup_pending_deposits::<T>(asset, &caller, n);
+        let deposit_ids: BoundedVec<u64, T::MaxClaimUtxos> =
+            ids.try_into().expect("n is within MaxClaimUtxos");
+        let accept_envelope: InputProof = seal_proof(T::Verifier::ID, ACCEPT_ENVELOPE)
+            .try_into()
+            .expect("proof fits in BoundedVec<8192>");
+
+        #[extrinsic_call]
+        claim_many(
+            RawOrigin::Signed(caller.clone()),
+            asset,
+            deposit_ids,
+            accept_envelope,
+        );
+
+        // Verify every pending deposit was consumed
+        assert_eq!(
+            PendingDeposits::<T>::iter_prefix_values((caller.clone(), asset)).count(),
+            0
+        );
+    }
+
+    #[benchmark]
+    fn reclaim_expired() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let asset = T::AssetId::default();
+
+        // The vector accept envelope takes an empty available balance and a pending
+        // balance of exactly ΔC, which is the sender's side of a reclaim.
+        setup_receiver_pk::<T>(&caller);
+        setup_pending_deposit::<T>(asset, &recipient, &caller);
+        let accept_envelope: InputProof = seal_proof(T::Verifier::ID, ACCEPT_ENVELOPE)
+            .try_into()
+            .expect("proof fits in BoundedVec<8192>");
+
+        #[extrinsic_call]
+        reclaim_expired(
+            RawOrigin::Signed(caller.clone()),
+            asset,
+            recipient.clone(),
+            0u64,
+            accept_envelope,
+        );
+
+        assert!(AvailableBalanceCommit::<T>::contains_key(asset, &caller));
+        assert!(!PendingDeposits::<T>::contains_key((
+            recipient, asset, 0u64
+        )));
+    }
+
+    #[benchmark]
+    fn expire_pending() {
+        let sender: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let asset = T::AssetId::default();
+        let now = frame_system::Pallet::<T>::block_number();
+
+        PendingDepositSource::<T>::insert(
+            (recipient.clone(), asset, 0u64),
+            DepositSource {
+                sender,
+                deadline: now,
+                deposit: T::PendingDeposit::get(),
+            },
+        );
+        ExpiringDeposits::<T>::insert(now, (recipient.clone(), asset, 0u64), ());
+        NextExpirySweep::<T>::put(now);
+
+        #[block]
+        {
+            Pallet::<T>::sweep_expired(now, Weight::MAX);
+        }
+
+        assert!(!ExpiringDeposits::<T>::contains_key(
+            now,
+            (recipient, asset, 0u64)
+        ));
+    }
+
+    #[benchmark]
+    fn rotate_public_key() {
+        let caller: T::AccountId = whitelisted_caller();
+        let asset = T::AssetId::default();
+
+        // REKEY_PROOF rotates the sender key of the whitelisted caller away from the
+        // transfer vector's starting balance
+        setup_sender_pk::<T>(&caller);
+        setup_sender_available_balance::<T>(asset, &caller);
+        let new_pk: PublicKeyBytes = REKEY_NEW_PK32.to_vec().try_into().expect("32 bytes fits");
+        let proof: InputProof = seal_proof(T::Verifier::ID, REKEY_PROOF)
+            .try_into()
+            .expect("proof fits in BoundedVec<8192>");
+
+        #[extrinsic_call]
+        rotate_public_key(
+            RawOrigin::Signed(caller.clone()),
+            asset,
+            new_pk.clone(),
+            proof,
+        );
+
+        assert_eq!(PublicKey::<T>::get(&caller), Some(new_pk));
+    }
+
+    #[benchmark]
+    fn audit() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let asset = T::AssetId::default();
+
+        // The log is full, so recording also prunes the oldest entry
+        let next = u64::from(T::MaxAuditHistory::get());
+        NextAuditId::<T>::insert(asset, next);
+        let from_pk = SENDER_PK32;
+        let auditor_pk: PublicKeyBytes = AUDITOR_PK32.to_vec().try_into().expect("32 bytes fits");
+        ConfidentialAssetConfig::<T>::insert(
+            asset,
+            AssetConfig {
+                auditor: Some(auditor_pk.clone()),
+                ..Default::default()
+            },
+        );
+        // The sender's blinding is tracked, so it is read, debited and written back
+        AvailableBlinding::<T>::insert(asset, &caller, (0, EncryptedAmount::zero()));
+        let from_old = Commitment::new(TRANSFER_FROM_OLD_COMM_32);
+        let encrypted_amount = EncryptedAmount::new(TRANSFER_DELTA_CT_64);
+
+        #[block]
+        {
+            let blinding = Pallet::<T>::record_audit(
+                asset,
+                Some((auditor_pk, TRANSFER_AUDITOR_HANDLE)),
+                &from_pk,
+                &encrypted_amount,
+                (&TRANSFER_FROM_OLD_COMM_32, &TRANSFER_FROM_NEW_COMM_32),
+                AuditOp::Transfer,
+                Some(&caller),
+                Some(&recipient),
+            )
+            .expect("vector handle verifies");
+            Pallet::<T>::track_blinding(asset, &caller, Some(&from_old), blinding, false);
+        }
+
+        assert!(AuditLog::<T>::contains_key(asset, next));
+        assert_eq!(NextAuditId::<T>::get(asset), next + 1);
+        assert!(AvailableBlinding::<T>::contains_key(asset, &caller));
+    }
+
+    #[benchmark]
+    fn set_range_bits() {
+        let asset = T::AssetId::default();
+
+        #[extrinsic_call]
+        set_range_bits(RawOrigin::Root, asset, 32);
+
+        assert_eq!(Pallet::<T>::asset_config(asset).range_bits, 32);
+    }
+
+    #[benchmark]
+    fn set_asset_config() {
+        let asset = T::AssetId::default();
+        let auditor: PublicKeyBytes = RECEIVER_PK32.to_vec().try_into().expect("32 bytes fits");
+        let config = AssetConfig {
+            enabled: true,
+            auditor: Some(auditor),
+            range_bits: 32,
+            max_pending: Some(T::MaxPendingPerAccount::get()),
+        };
+
+        #[extrinsic_call]
+        set_asset_config(RawOrigin::Root, asset, config.clone());
+
+        assert_eq!(ConfidentialAssetConfig::<T>::get(asset), Some(config));
+    }
+
+    #[benchmark]
+    fn pause_asset() {
+        let asset = T::AssetId::default();
+
+        #[extrinsic_call]
+        pause_asset(RawOrigin::Root, asset);
+
+        assert!(PausedAssets::<T>::contains_key(asset));
+    }
+
+    #[benchmark]
+    fn unpause_asset() {
+        let asset = T::AssetId::default();
+        PausedAssets::<T>::insert(asset, ());
+
+        #[extrinsic_call]
+        unpause_asset(RawOrigin::Root, asset);
+
+        assert!(!PausedAssets::<T>::contains_key(asset));
+    }
+
+    #[benchmark]
+    fn freeze_account() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let asset = T::AssetId::default();
+        let who: T::AccountId = account("holder", 0, 0);
+
+        #[extrinsic_call]
+        freeze_account(origin as T::RuntimeOrigin, asset, who.clone());
+
+        assert!(FrozenAccounts::<T>::contains_key(asset, &who));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn thaw_account() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let asset = T::AssetId::default();
+        let who: T::AccountId = account("holder", 0, 0);
+        FrozenAccounts::<T>::insert(asset, &who, ());
+
+        #[extrinsic_call]
+        thaw_account(origin as T::RuntimeOrigin, asset, who.clone());
+
+        assert!(!FrozenAccounts::<T>::contains_key(asset, &who));
+        Ok(())
+    }
+
     // NOTE: accept_pending_and_transfer benchmark is not included because it requires
     // chained proofs where the accept result feeds into the transfer input.
     // The current vectors don't support this chaining.
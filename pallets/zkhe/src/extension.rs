@@ -0,0 +1,135 @@
+//! Transaction extension that checks confidential proofs when a transaction enters the pool.
+
+use super::*;
+use sp_runtime::{
+    impl_tx_ext_default,
+    traits::{
+        BlakeTwo256, DispatchInfoOf, DispatchOriginOf, Dispatchable, Hash, Implication,
+        TransactionExtension, ValidateResult,
+    },
+    transaction_validity::{InvalidTransaction, TransactionSource, ValidTransaction},
+};
+
+/// Prefix of the tag a checked proof `provides`, followed by the proof's hash.
+pub const PROOF_TAG_PREFIX: &[u8] = b"zkhe/proof";
+
+/// Checks the structure of every proof a call carries (see [`ProofCarrier`]) before
+/// the transaction enters the pool: the proof-system id, the length bounds and the
+/// verifier's `validate_shape`, which for ZK-ElGamal also decompresses the leading
+/// points. Nothing is verified and no storage is read.
+///
+/// Each proof is tagged with its hash, so the pool keeps only one transaction carrying
+/// a given proof; a proof can be used once anyway.
+///
+/// Transfer, mint and burn proofs of audited assets carry a trailing auditor handle.
+/// The asset's policy is not read here, so those kinds pass in either layout and
+/// dispatch checks the one the asset needs.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckConfidentialProof<T>(PhantomData<T>);
+
+impl<T> CheckConfidentialProof<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for CheckConfidentialProof<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::fmt::Debug for CheckConfidentialProof<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckConfidentialProof")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config> CheckConfidentialProof<T> {
+    fn check(kind: ProofKind, proof: &[u8]) -> Result<(), VerifyError> {
+        match T::Verifier::open(kind, proof) {
+            Ok(_) => Ok(()),
+            Err(_)
+                if matches!(
+                    kind,
+                    ProofKind::Transfer
+                        | ProofKind::Mint
+                        | ProofKind::Burn
+                        | ProofKind::ConfidentialBurn
+                ) =>
+            {
+                T::Verifier::open_audited(kind, proof).map(|_| ())
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for CheckConfidentialProof<T>
+where
+    T::RuntimeCall: Dispatchable + ProofCarrier,
+{
+    const IDENTIFIER: &'static str = "CheckConfidentialProof";
+    type Implicit = ();
+    type Val = ();
+    type Pre = ();
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Implication,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let mut valid = ValidTransaction::default();
+        for (kind, proof) in call.proofs() {
+            Self::check(kind, proof).map_err(|_| InvalidTransaction::BadProof)?;
+            valid
+                .provides
+                .push((PROOF_TAG_PREFIX, BlakeTwo256::hash(proof)).encode());
+        }
+        Ok((valid, (), origin))
+    }
+
+    // The same checks run again in dispatch, whose weight covers them.
+    impl_tx_ext_default!(T::RuntimeCall; weight prepare);
+}
+
+impl<T: Config> ProofCarrier for Call<T> {
+    fn proofs(&self) -> Vec<(ProofKind, &[u8])> {
+        match self {
+            Call::transfer { proof, .. } => sp_std::vec![(ProofKind::Transfer, &proof[..])],
+            Call::accept_pending {
+                accept_envelope, ..
+            } => sp_std::vec![(ProofKind::Claim, &accept_envelope[..])],
+            Call::accept_pending_and_transfer {
+                accept_envelope,
+                transfer_proof,
+                ..
+            } => sp_std::vec![
+                (ProofKind::Claim, &accept_envelope[..]),
+                (ProofKind::Transfer, &transfer_proof[..]),
+            ],
+            Call::claim_many {
+                accept_envelope, ..
+            }
+            | Call::reclaim_expired {
+                accept_envelope, ..
+            } => sp_std::vec![(ProofKind::Accept, &accept_envelope[..])],
+            Call::rotate_public_key { proof, .. } => {
+                sp_std::vec![(ProofKind::Rekey, &proof[..])]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
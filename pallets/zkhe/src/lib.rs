@@ -7,14 +7,57 @@
 //! - per-(asset,account) available commitment (32B)
 //! - per-(asset,account) pending commitment (32B)
 //! - per-asset total supply commitment (32B)
+//! - per-asset configuration: enabled flag, auditor key, range-proof bit width (32 or 64,
+//!   default 64) and pending-deposit cap
 //! - per-(account,asset,id) pending deposits as 64B ElGamal ciphertexts (UTXO-like)
+//! - per-(account,asset,id) sender, expiry block and storage deposit of transferred deposits
+//! - per-(account,asset) number of pending deposits, capped at `MaxPendingPerAccount`
+//! - per-(asset,account) freeze flag set by `ForceOrigin`
+//! - per-(asset,id) audit records of audited assets, the last `MaxAuditHistory` kept
+//! - per-asset pause flag set by governance
 //!
 //! Dispatchables:
 //! - `accept_pending`: consume selected UTXOs, prove ΔC, move pending → available
+//! - `claim_many`: the same for up to `MaxClaimUtxos` UTXOs listed in the call, weighed per UTXO
+//! - `set_range_bits` (root): choose an asset's range-proof width before it is minted
+//! - `set_asset_config` (root): enable an asset and set its auditor key, width and pending cap
+//! - `reclaim_expired`: the sender takes back a transfer left unclaimed for `PendingTtl` blocks
+//! - `rotate_public_key`: replace the caller's key, re-encrypting an available balance under it
+//! - `pause_asset` / `unpause_asset` (root): stop and resume every transfer, mint, burn and
+//!   claim of one asset
+//! - `freeze_account` / `thaw_account` (`ForceOrigin`): stop and resume a holder's outgoing
+//!   transfers, burns and closure of one asset
 //!
 //! Notes:
 //! - All cryptographic checks live in `Config::Verifier`.
+//! - Proofs arrive as `proof_system_id(1) || body`; the id must match `Config::Verifier`.
 //! - Sender transfer updates: available(from) ↓, pending(to) ↑.
+//! - `Pallet` is a `RangeBitsProvider`; give it to the verifier so proofs are checked at
+//!   each asset's width. Weights assume 64-bit proofs and narrower ones are refunded.
+//! - `on_idle` emits `PendingExpired` for transfers whose TTL ran out. They stay claimable
+//!   by the receiver until the sender reclaims them. Minted deposits never expire.
+//! - Each transfer holds `PendingDeposit` from the sender until its UTXO is claimed,
+//!   reclaimed or closed, so flooding a receiver with dust transfers costs the sender.
+//!   Mints count towards `MaxPendingPerAccount` but hold nothing.
+//! - Transfers and mints need the asset enabled. With `RequireAssetConfig` an asset is
+//!   disabled until governance configures it; otherwise unconfigured assets are enabled.
+//!   Claims, burns and closures work on disabled assets so holders can always exit.
+//! - A frozen holder still receives transfers and may claim and reclaim them, but cannot
+//!   send, burn or close its account of that asset until thawed.
+//! - Proofs for an asset with an auditor key end with an `AuditorHandle` envelope that
+//!   re-encrypts the amount for the auditor. `audit_log` (a view function) reads the log.
+//! - A paused asset is frozen for everyone: transfers, mints, burns, claims and reclaims
+//!   fail with `AssetPaused`. Pallets built on the backend check `is_paused` up front.
+//! - Storage is versioned; `migrations::v1::MigrateV0ToV1` upgrades chains that ran the
+//!   unversioned layout.
+//! - `close_account` deletes an (asset,account)'s commitments and UTXOs after a
+//!   zero-balance proof and returns the senders' storage deposits.
+//! - The first transfer or mint that creates an (asset,account)'s state holds
+//!   `AccountDeposit` until `close_account` deletes it: from the sender of a transfer and
+//!   from the receiver of a mint.
+//! - `CheckConfidentialProof` is a transaction extension that rejects malformed proofs
+//!   in the pool and keeps one transaction per proof; the runtime implements
+//!   `ProofCarrier` for `RuntimeCall` to hand it the proofs.
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -23,16 +66,117 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod extension;
+pub mod migrations;
 pub mod weights;
 
 use confidential_assets_primitives::*;
-use frame_support::{Blake2_128Concat, pallet_prelude::*, transactional};
+use frame_support::{
+    Blake2_128Concat,
+    pallet_prelude::*,
+    traits::{
+        fungible::{self, MutateHold},
+        tokens::Precision,
+    },
+    transactional,
+};
 use frame_system::pallet_prelude::*;
+use parity_scale_codec::DecodeWithMemTracking;
 use scale_info::TypeInfo;
+use sp_runtime::traits::{Saturating, Zero};
 use sp_std::prelude::*;
 
+pub use extension::CheckConfidentialProof;
 pub use pallet::*;
 
+/// Balance of the currency storage deposits are held in.
+pub type DepositBalanceOf<T> =
+    <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Who made a transferred pending deposit, when the sender may reclaim it and what it
+/// holds from the sender meanwhile.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct DepositSource<AccountId, BlockNumber, Balance> {
+    pub sender: AccountId,
+    /// Block from which the sender may reclaim the deposit.
+    pub deadline: BlockNumber,
+    /// Storage deposit held from the sender, returned when the entry is removed.
+    pub deposit: Balance,
+}
+
+/// Governance policy for one asset, set with `set_asset_config`.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AssetConfig {
+    /// Whether the asset may be minted into or transferred confidentially.
+    pub enabled: bool,
+    /// Auditor key of an audited asset. Its transfers, mints and burns must carry an
+    /// auditor handle, and the amount under this key is kept in `AuditLog`.
+    pub auditor: Option<PublicKeyBytes>,
+    /// Range-proof bit width, 32 or 64. Fixed once the asset is minted.
+    pub range_bits: u8,
+    /// Most pending deposits an account may hold of the asset; `None` means
+    /// `MaxPendingPerAccount`, which also bounds any value set here.
+    pub max_pending: Option<u32>,
+}
+
+impl Default for AssetConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            auditor: None,
+            range_bits: DEFAULT_RANGE_BITS,
+            max_pending: None,
+        }
+    }
+}
+
+/// State transition an `AuditRecord` was made for.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub enum AuditOp {
+    Transfer,
+    Mint,
+    Burn,
+    ConfidentialBurn,
+    /// A holder's whole available balance, seized under a seizure proof.
+    Seizure,
+}
+
+/// An audited asset's state transition, with its amount encrypted under the auditor key.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct AuditRecord<AccountId, BlockNumber> {
+    pub op: AuditOp,
+    /// Account whose available balance decreased; `None` for mints.
+    pub from: Option<AccountId>,
+    /// Account that received a pending deposit; `None` for burns.
+    pub to: Option<AccountId>,
+    pub block: BlockNumber,
+    /// The amount under the asset's auditor key at the time.
+    pub ciphertext: EncryptedAmount,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -44,11 +188,60 @@ pub mod pallet {
         type Balance: Parameter + Member + Copy + Ord + MaxEncodedLen + TypeInfo + From<u64>;
 
         /// Verifier boundary (no_std on-chain).
+        ///
+        /// Every proof argument is an envelope `ProofSystem::ID(1) || body`; proofs for
+        /// any other proof system are rejected and only `body` reaches the verifier.
         /// - `verify_transfer_sent(..) -> (from_new_commit, to_new_pending_commit)`
         /// - `verify_transfer_received(.., pending_commits: &[[u8;32]], accept_envelope: &[u8])`
+        /// - `verify_claim(.., utxos: &[(id, commit)], claim: &[u8]) -> (.., consumed_ids)`
         /// - `verify_mint(..) -> (to_new_pending_commit, total_new_commit, minted_ciphertext)`
         /// - `verify_burn(..) -> (from_new_available_commit, total_new_commit, disclosed_amount_u64)`
-        type Verifier: ZkVerifier;
+        /// - `verify_confidential_burn(..) -> (from_new_available_commit, total_new_commit)`
+        /// - `verify_zero_balance(..)` before an account's entries are deleted
+        /// - `verify_rekey(..) -> available_ciphertext_under_new_key`
+        type Verifier: ProofSystem;
+
+        /// Most pending deposits a single `claim_many` may consume.
+        #[pallet::constant]
+        type MaxClaimUtxos: Get<u32>;
+
+        /// Blocks a transferred deposit stays claimable only by its receiver; after that
+        /// the sender may reclaim it.
+        #[pallet::constant]
+        type PendingTtl: Get<BlockNumberFor<Self>>;
+
+        /// Most pending deposits an account may have per asset. Transfers and mints to a
+        /// full queue fail until the receiver claims.
+        #[pallet::constant]
+        type MaxPendingPerAccount: Get<u32>;
+
+        /// Held from the sender for each pending deposit a transfer creates.
+        #[pallet::constant]
+        type PendingDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Held once per (asset, account) when its confidential state is created, until
+        /// `close_account` deletes it.
+        #[pallet::constant]
+        type AccountDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Currency the storage deposits are held in.
+        type Currency: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + fungible::Mutate<Self::AccountId>;
+
+        /// Overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// If true, assets are disabled until `set_asset_config` enables them. If false,
+        /// assets without a configuration are enabled with the default policy.
+        #[pallet::constant]
+        type RequireAssetConfig: Get<bool>;
+
+        /// Audit records kept per audited asset; older ones are pruned.
+        #[pallet::constant]
+        type MaxAuditHistory: Get<u32>;
+
+        /// Origin that may freeze and thaw holders.
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         type WeightInfo: WeightInfo;
     }
@@ -58,6 +251,23 @@ pub mod pallet {
         fn transfer() -> Weight;
         fn transfer_from_available() -> Weight;
         fn accept_pending() -> Weight;
+        /// Claiming `n` pending deposits with one accept envelope.
+        fn claim_many(n: u32) -> Weight;
+        fn set_range_bits() -> Weight;
+        fn set_asset_config() -> Weight;
+        fn reclaim_expired() -> Weight;
+        /// Sweeping one expired deposit in `on_idle`.
+        fn expire_pending() -> Weight;
+        fn rotate_public_key() -> Weight;
+        /// Verifying an auditor handle, logging it and tracking its blinding, on top of
+        /// the audited call.
+        fn audit() -> Weight;
+        fn pause_asset() -> Weight;
+        fn unpause_asset() -> Weight;
+        fn freeze_account() -> Weight;
+        fn thaw_account() -> Weight;
+        /// Verifying one range proof `b` bits wide.
+        fn range_proof(b: u32) -> Weight;
     }
     impl WeightInfo for () {
         fn transfer() -> Weight {
@@ -69,6 +279,42 @@ pub mod pallet {
         fn accept_pending() -> Weight {
             Weight::from_parts(25_000, 0)
         }
+        fn claim_many(n: u32) -> Weight {
+            Weight::from_parts(25_000 + 2_000 * n as u64, 0)
+        }
+        fn set_range_bits() -> Weight {
+            Weight::from_parts(5_000, 0)
+        }
+        fn set_asset_config() -> Weight {
+            Weight::from_parts(5_000, 0)
+        }
+        fn reclaim_expired() -> Weight {
+            Weight::from_parts(25_000, 0)
+        }
+        fn expire_pending() -> Weight {
+            Weight::from_parts(1_000, 0)
+        }
+        fn rotate_public_key() -> Weight {
+            Weight::from_parts(20_000, 0)
+        }
+        fn audit() -> Weight {
+            Weight::from_parts(8_000, 0)
+        }
+        fn pause_asset() -> Weight {
+            Weight::from_parts(5_000, 0)
+        }
+        fn unpause_asset() -> Weight {
+            Weight::from_parts(5_000, 0)
+        }
+        fn freeze_account() -> Weight {
+            Weight::from_parts(5_000, 0)
+        }
+        fn thaw_account() -> Weight {
+            Weight::from_parts(5_000, 0)
+        }
+        fn range_proof(b: u32) -> Weight {
+            Weight::from_parts(100 * b as u64, 0)
+        }
     }
 
     // -------------------- Storage --------------------
@@ -109,10 +355,67 @@ pub mod pallet {
             NMapKey<Blake2_128Concat, T::AssetId>,
             NMapKey<Blake2_128Concat, u64>,
         ),
-        EncryptedAmount, // [u8; 64]
+        EncryptedAmount,
+        OptionQuery,
+    >;
+
+    /// Pedersen commitment of each pending deposit, which claims and reclaims sum. The
+    /// deposit's ciphertext cannot stand in for it: its halves are the nonce point and
+    /// masked amount.
+    #[pallet::storage]
+    pub type PendingDepositCommit<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Blake2_128Concat, T::AssetId>,
+            NMapKey<Blake2_128Concat, u64>,
+        ),
+        Commitment,
+        OptionQuery,
+    >;
+
+    /// Sender and expiry block of each pending deposit created by a transfer.
+    #[pallet::storage]
+    pub type PendingDepositSource<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Blake2_128Concat, T::AssetId>,
+            NMapKey<Blake2_128Concat, u64>,
+        ),
+        DepositSource<T::AccountId, BlockNumberFor<T>, DepositBalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Number of pending deposits per (account, asset), at most `MaxPendingPerAccount`.
+    #[pallet::storage]
+    pub type PendingDepositCount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AssetId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Transferred deposits by the block their TTL runs out, for the `on_idle` sweep.
+    /// Entries of deposits claimed or reclaimed meanwhile are skipped.
+    #[pallet::storage]
+    pub type ExpiringDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (T::AccountId, T::AssetId, u64),
+        (),
         OptionQuery,
     >;
 
+    /// First block whose `ExpiringDeposits` have not been swept yet.
+    #[pallet::storage]
+    pub type NextExpirySweep<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     #[pallet::storage]
     pub type NextPendingDepositId<T: Config> = StorageDoubleMap<
         _,
@@ -128,9 +431,138 @@ pub mod pallet {
     pub type TotalSupplyCommit<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AssetId, Commitment, OptionQuery>;
 
+    /// Sum of the balance commitments `close_account` deleted. They open to zero but
+    /// keep their blinding, so `TotalSupplyCommit` equals the asset's remaining balance
+    /// commitments plus this; see [`Pallet::check_supply`].
+    #[pallet::storage]
+    pub type ClosedBalanceCommit<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, Commitment, OptionQuery>;
+
+    /// Who paid the `AccountDeposit` for an (asset, account)'s state, and how much.
+    #[pallet::storage]
+    pub type AccountDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::AccountId, DepositBalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// Per-asset policy; see [`Pallet::asset_config`] for assets without an entry.
+    #[pallet::storage]
+    pub type ConfidentialAssetConfig<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, AssetConfig, OptionQuery>;
+
+    /// Assets governance has stopped with `pause_asset`.
+    #[pallet::storage]
+    pub type PausedAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, (), OptionQuery>;
+
+    /// Holders whose outgoing operations on an asset are blocked by `freeze_account`.
+    #[pallet::storage]
+    pub type FrozenAccounts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// The last `MaxAuditHistory` state transitions of each audited asset, by audit id.
+    #[pallet::storage]
+    pub type AuditLog<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Twox64Concat,
+        u64,
+        AuditRecord<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Id the next `AuditLog` entry of an asset gets.
+    #[pallet::storage]
+    pub type NextAuditId<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, u64, ValueQuery>;
+
+    /// Bumped each time an asset's auditor key changes; blinding ciphertexts of an
+    /// older epoch are under another key and no longer count.
+    #[pallet::storage]
+    pub type AuditorEpoch<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, u32, ValueQuery>;
+
+    /// The blinding of each holder's available balance of an audited asset, encrypted
+    /// under the auditor key, with the `AuditorEpoch` it belongs to.
+    ///
+    /// Summed from the blinding ciphertexts of the auditor handles that moved the
+    /// balance, so `seize_balance` needs nothing from the holder. A balance that moved
+    /// before the key was set, or through a path without a handle, has no entry and
+    /// cannot be seized until it is emptied.
+    #[pallet::storage]
+    pub type AvailableBlinding<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        (u32, EncryptedAmount),
+        OptionQuery,
+    >;
+
+    /// The blinding ciphertext of each pending deposit made by an audited transfer or
+    /// mint, credited to `AvailableBlinding` of whoever claims or reclaims it.
+    #[pallet::storage]
+    pub type PendingDepositBlinding<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Blake2_128Concat, T::AssetId>,
+            NMapKey<Blake2_128Concat, u64>,
+        ),
+        (u32, EncryptedAmount),
+        OptionQuery,
+    >;
+
+    /// The in-code storage version; see [`crate::migrations`].
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Storage deposit for a pending transfer to another account.
+        PendingDeposit,
+        /// Storage deposit for an account's confidential state of an asset.
+        AccountDeposit,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_expired(now, remaining_weight)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()?;
+            let mut assets: Vec<T::AssetId> = Vec::new();
+            TotalSupplyCommit::<T>::iter_keys()
+                .chain(AvailableBalanceCommit::<T>::iter_keys().map(|(asset, _)| asset))
+                .chain(PendingBalanceCommit::<T>::iter_keys().map(|(asset, _)| asset))
+                .for_each(|asset| {
+                    if !assets.contains(&asset) {
+                        assets.push(asset);
+                    }
+                });
+            assets
+                .into_iter()
+                .try_for_each(|asset| Self::check_supply(asset).map_err(Into::into))
+        }
+    }
+
     // -------------------- Events / Errors --------------------
 
     #[pallet::event]
@@ -153,17 +585,101 @@ pub mod pallet {
             to: T::AccountId,
             encrypted_amount: EncryptedAmount,
         },
+        RangeBitsSet {
+            asset: T::AssetId,
+            bits: u8,
+        },
+        AssetConfigSet {
+            asset: T::AssetId,
+            config: AssetConfig,
+        },
+        PendingClaimed {
+            asset: T::AssetId,
+            who: T::AccountId,
+            deposit_ids: BoundedVec<u64, T::MaxClaimUtxos>,
+        },
+        /// A transfer to `who` went unclaimed for `PendingTtl`; `sender` may reclaim it.
+        PendingExpired {
+            asset: T::AssetId,
+            who: T::AccountId,
+            deposit_id: u64,
+            sender: T::AccountId,
+        },
+        PendingReclaimed {
+            asset: T::AssetId,
+            from: T::AccountId,
+            to: T::AccountId,
+            deposit_id: u64,
+            encrypted_amount: EncryptedAmount,
+        },
+        /// `who` now holds `asset` under a new key; `encrypted_balance` is its available
+        /// balance encrypted under that key.
+        PublicKeyRotated {
+            asset: T::AssetId,
+            who: T::AccountId,
+            encrypted_balance: EncryptedAmount,
+        },
+        /// Nothing of `asset` can move until it is unpaused.
+        Paused {
+            asset: T::AssetId,
+        },
+        Unpaused {
+            asset: T::AssetId,
+        },
+        /// `who` can no longer send, burn or close its `asset` account.
+        Frozen {
+            asset: T::AssetId,
+            who: T::AccountId,
+        },
+        Thawed {
+            asset: T::AssetId,
+            who: T::AccountId,
+        },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         NoPublicKey,
-        InvalidProof,
+        /// The verifier rejected the proof; the payload says why.
+        InvalidProof(VerifyError),
         BackendPolicy,
         BadCipher,
         NoPending,
         SupplyMismatch,
         MalformedEnvelope,
+        /// Range proofs can only be 32 or 64 bits wide.
+        UnsupportedRangeBits,
+        /// The asset has been minted, so its range-proof width is fixed.
+        AssetInUse,
+        /// A pending deposit id was listed more than once.
+        DuplicateDeposit,
+        /// A claim lists more deposits than an account can hold pending.
+        TooManyDeposits,
+        /// Only the account that made the transfer can reclaim it.
+        NotSender,
+        /// The deposit is still within its `PendingTtl`.
+        NotExpired,
+        /// The receiver already has as many pending deposits of the asset as it may hold.
+        TooManyPending,
+        /// The asset is not enabled for confidential mints and transfers.
+        AssetDisabled,
+        /// A per-asset pending cap may not exceed `MaxPendingPerAccount`.
+        PendingCapTooHigh,
+        /// The asset is paused; no transfer, mint, burn or claim of it is possible.
+        AssetPaused,
+        /// The holder is frozen for this asset and may only receive and claim.
+        Frozen,
+        /// The asset has no auditor key.
+        NotAudited,
+        /// The holder's balance has no blinding under the current auditor key, e.g. it
+        /// predates the key, so it cannot be seized.
+        NotSeizable,
+        /// Split transfers carry no auditor handle, so audited assets cannot use them.
+        AuditedSplit,
+        /// The account moved nothing in the asset's latest audited operation.
+        NothingAudited,
+        /// The account already has a public key; `rotate_public_key` replaces it.
+        PkAlreadySet,
     }
 
     // -------------------- Dispatchables --------------------
@@ -171,14 +687,15 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::transfer())]
+        #[pallet::weight(T::WeightInfo::transfer().saturating_add(T::WeightInfo::audit()))]
+        #[transactional]
         pub fn transfer(
             origin: T::RuntimeOrigin,
             asset: T::AssetId,
             to: T::AccountId,
             encrypted_amount: EncryptedAmount,
             proof: InputProof,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let from = ensure_signed(origin)?;
             let transferred = Self::transfer_encrypted(asset, &from, &to, encrypted_amount, proof)?;
             Self::deposit_event(Event::Transferred {
@@ -187,34 +704,46 @@ pub mod pallet {
                 to,
                 encrypted_amount: transferred,
             });
-            Ok(())
+            let weight = T::WeightInfo::transfer().saturating_add(Self::audit_weight(asset));
+            Ok(Some(Self::range_adjusted_weight(weight, asset, 1)).into())
         }
 
         /// Accept selected UTXO deposits; prove ΔC; update (avail, pending) for caller.
         ///
-        /// `accept_envelope` layout (Option A):
+        /// `accept_envelope` layout (Option A), prefixed with the proof-system id and the
+        /// UTXO ids it consumes:
+        ///   id(1) || count(2) || ids(8 LE × count) ||
         ///   delta_comm(32) || len1(2) || rp_avail_new || len2(2) || rp_pending_new
+        ///
+        /// Weighed by the ids listed, which are checked before any storage is read: at
+        /// most `MaxPendingPerAccount` of them, each once.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::accept_pending())]
+        #[pallet::weight(Pallet::<T>::accept_weight(claimed_utxos(accept_envelope)))]
+        #[transactional]
         pub fn accept_pending(
             origin: T::RuntimeOrigin,
             asset: T::AssetId,
             accept_envelope: InputProof,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let utxos = claimed_utxos(&accept_envelope);
             let claimed = Self::claim_encrypted(asset, &who, accept_envelope)?;
             Self::deposit_event(Event::PendingAccepted {
                 asset,
                 who,
                 encrypted_amount: claimed,
             });
-            Ok(())
+            let weight = Self::accept_weight(utxos);
+            Ok(Some(Self::range_adjusted_weight(weight, asset, 2)).into())
         }
 
         /// Accept pending then transfer from available.
         /// Enables spend of pending deposits in one transaction.
         #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::transfer_from_available())]
+        #[pallet::weight(
+            Pallet::<T>::accept_and_transfer_weight(claimed_utxos(accept_envelope))
+                .saturating_add(T::WeightInfo::audit())
+        )]
         #[transactional]
         pub fn accept_pending_and_transfer(
             origin: T::RuntimeOrigin,
@@ -222,8 +751,9 @@ pub mod pallet {
             to: T::AccountId,
             accept_envelope: InputProof,
             transfer_proof: InputProof,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let from = ensure_signed(origin)?;
+            let utxos = claimed_utxos(&accept_envelope);
             let claimed = Self::claim_encrypted(asset, &from, accept_envelope)?;
             let transferred = Self::transfer_encrypted(asset, &from, &to, claimed, transfer_proof)?;
             Self::deposit_event(Event::PendingAcceptedAndTransferred {
@@ -232,70 +762,366 @@ pub mod pallet {
                 to,
                 encrypted_amount: transferred,
             });
-            Ok(())
+            let weight =
+                Self::accept_and_transfer_weight(utxos).saturating_add(Self::audit_weight(asset));
+            Ok(Some(Self::range_adjusted_weight(weight, asset, 3)).into())
         }
-    }
 
-    impl<T: Config> ConfidentialBackend<T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
-        fn set_public_key(
-            who: &T::AccountId,
-            elgamal_pk: &PublicKeyBytes,
-        ) -> Result<(), DispatchError> {
-            ensure!(!elgamal_pk.is_empty(), Error::<T>::BadCipher);
-            PublicKey::<T>::insert(who, elgamal_pk.clone());
+        /// Set the bit width (32 or 64) of `asset`'s range proofs.
+        ///
+        /// Amounts of a 32-bit asset must stay below 2^32; its proofs are smaller and
+        /// cheaper to verify. Only possible before the asset is first minted.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_range_bits())]
+        pub fn set_range_bits(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            bits: u8,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                is_supported_range_bits(bits),
+                Error::<T>::UnsupportedRangeBits
+            );
+            ensure!(
+                !TotalSupplyCommit::<T>::contains_key(asset),
+                Error::<T>::AssetInUse
+            );
+            let config = AssetConfig {
+                range_bits: bits,
+                ..Self::asset_config(asset)
+            };
+            ConfidentialAssetConfig::<T>::insert(asset, config);
+            Self::deposit_event(Event::RangeBitsSet { asset, bits });
             Ok(())
         }
 
-        fn total_supply(asset: T::AssetId) -> [u8; 32] {
-            TotalSupplyCommit::<T>::get(asset).unwrap_or([0u8; 32])
+        /// Accept every deposit in `deposit_ids` at once; their commitments are summed
+        /// into ΔC and checked against a single accept envelope.
+        ///
+        /// `accept_envelope` carries no id list:
+        ///   id(1) || delta_comm(32) || len1(2) || rp_avail_new || len2(2) || rp_pending_new
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            T::WeightInfo::claim_many(deposit_ids.len() as u32)
+                .saturating_add(Pallet::<T>::blinding_weight(deposit_ids.len() as u32))
+        )]
+        #[transactional]
+        pub fn claim_many(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            deposit_ids: BoundedVec<u64, T::MaxClaimUtxos>,
+            accept_envelope: InputProof,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_claim_many(&who, asset, &deposit_ids, accept_envelope)?;
+            let weight = T::WeightInfo::claim_many(deposit_ids.len() as u32)
+                .saturating_add(Self::blinding_weight(deposit_ids.len() as u32));
+            Self::deposit_event(Event::PendingClaimed {
+                asset,
+                who,
+                deposit_ids,
+            });
+            Ok(Some(Self::range_adjusted_weight(weight, asset, 2)).into())
         }
 
-        fn balance_of(asset: T::AssetId, who: &T::AccountId) -> [u8; 32] {
-            AvailableBalanceCommit::<T>::get(asset, who).unwrap_or([0u8; 32])
+        /// Take back a transfer to `to` that went unclaimed for `PendingTtl` blocks.
+        ///
+        /// The sender proves ΔC into its own available balance with an accept envelope
+        /// made for its key, taking the deposit's commitment as both ΔC and pending_old.
+        /// The receiver's pending commitment drops by the same ΔC.
+        #[pallet::call_index(5)]
+        #[pallet::weight(
+            T::WeightInfo::reclaim_expired().saturating_add(Pallet::<T>::blinding_weight(1))
+        )]
+        #[transactional]
+        pub fn reclaim_expired(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            to: T::AccountId,
+            deposit_id: u64,
+            accept_envelope: InputProof,
+        ) -> DispatchResultWithPostInfo {
+            let from = ensure_signed(origin)?;
+            let reclaimed =
+                Self::do_reclaim_expired(&from, asset, &to, deposit_id, accept_envelope)?;
+            Self::deposit_event(Event::PendingReclaimed {
+                asset,
+                from,
+                to,
+                deposit_id,
+                encrypted_amount: reclaimed,
+            });
+            let weight = T::WeightInfo::reclaim_expired().saturating_add(Self::blinding_weight(1));
+            Ok(Some(Self::range_adjusted_weight(weight, asset, 2)).into())
         }
 
-        fn disclose_amount(
+        /// Replace the caller's public key with `new_pk`, re-encrypting its available
+        /// balance of `asset` under `new_pk`.
+        ///
+        /// `proof` shows the caller knows the secret keys of both its current key and
+        /// `new_pk`, and carries the re-encrypted balance. Keys are per account, so a
+        /// holder of several assets calls again with the same `new_pk` for each other
+        /// asset. Pending deposits stay encrypted under the old key.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::rotate_public_key())]
+        #[transactional]
+        pub fn rotate_public_key(
+            origin: T::RuntimeOrigin,
             asset: T::AssetId,
-            encrypted_amount: &EncryptedAmount,
-            who: &T::AccountId,
-        ) -> Result<T::Balance, DispatchError> {
-            let pk = PublicKey::<T>::get(who).ok_or(Error::<T>::NoPublicKey)?;
-            let amount = T::Verifier::disclose(
-                &asset.using_encoded(|b| b.to_vec()),
-                &pk,
-                &encrypted_amount[..],
-            )
-            .map_err(|_| Error::<T>::BackendPolicy)?;
-            Ok(amount.into())
+            new_pk: PublicKeyBytes,
+            proof: InputProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let rekeyed = Self::do_rotate_public_key(&who, asset, new_pk, proof)?;
+            Self::deposit_event(Event::PublicKeyRotated {
+                asset,
+                who,
+                encrypted_balance: rekeyed,
+            });
+            Ok(())
         }
 
-        fn transfer_encrypted(
+        /// Replace `asset`'s policy with `config`.
+        ///
+        /// `range_bits` follows the rules of `set_range_bits` when it changes, and
+        /// `max_pending` may not exceed `MaxPendingPerAccount`. Lowering the cap leaves
+        /// existing deposits in place; disabling an asset still lets holders claim,
+        /// burn and close.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_asset_config())]
+        pub fn set_asset_config(
+            origin: T::RuntimeOrigin,
             asset: T::AssetId,
-            from: &T::AccountId,
-            to: &T::AccountId,
-            encrypted_amount: EncryptedAmount,
-            input_proof: InputProof,
-        ) -> Result<EncryptedAmount, DispatchError> {
-            let from_pk = PublicKey::<T>::get(from).ok_or(Error::<T>::NoPublicKey)?;
-            let to_pk = PublicKey::<T>::get(to).ok_or(Error::<T>::NoPublicKey)?;
+            config: AssetConfig,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                is_supported_range_bits(config.range_bits),
+                Error::<T>::UnsupportedRangeBits
+            );
+            ensure!(
+                config.range_bits == Self::asset_config(asset).range_bits
+                    || !TotalSupplyCommit::<T>::contains_key(asset),
+                Error::<T>::AssetInUse
+            );
+            ensure!(
+                config
+                    .max_pending
+                    .is_none_or(|cap| cap <= T::MaxPendingPerAccount::get()),
+                Error::<T>::PendingCapTooHigh
+            );
+            if config.auditor != Self::asset_config(asset).auditor {
+                AuditorEpoch::<T>::mutate(asset, |epoch| *epoch = epoch.wrapping_add(1));
+            }
+            ConfidentialAssetConfig::<T>::insert(asset, config.clone());
+            Self::deposit_event(Event::AssetConfigSet { asset, config });
+            Ok(())
+        }
 
-            // lifetime-safe buffers
-            let from_old_avail_opt = AvailableBalanceCommit::<T>::get(asset, from);
-            let from_old_avail_buf;
-            let from_old_avail: &[u8] = match from_old_avail_opt {
-                Some(c) => {
-                    from_old_avail_buf = c;
-                    &from_old_avail_buf[..]
-                }
-                None => &[],
-            };
+        /// Stop every transfer, mint, burn and claim of `asset`, e.g. while a key or
+        /// verifier bug is investigated.
+        ///
+        /// Unlike disabling an asset, holders cannot exit either; commitments stay as
+        /// they are until `unpause_asset`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::pause_asset())]
+        pub fn pause_asset(origin: T::RuntimeOrigin, asset: T::AssetId) -> DispatchResult {
+            ensure_root(origin)?;
+            PausedAssets::<T>::insert(asset, ());
+            Self::deposit_event(Event::Paused { asset });
+            Ok(())
+        }
 
-            let to_old_pending_opt = PendingBalanceCommit::<T>::get(asset, to);
-            let to_old_pending_buf;
+        /// Lift a `pause_asset` of `asset`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::unpause_asset())]
+        pub fn unpause_asset(origin: T::RuntimeOrigin, asset: T::AssetId) -> DispatchResult {
+            ensure_root(origin)?;
+            PausedAssets::<T>::remove(asset);
+            Self::deposit_event(Event::Unpaused { asset });
+            Ok(())
+        }
+
+        /// Block `who`'s outgoing transfers, burns and closure of `asset`.
+        ///
+        /// Transfers to `who` keep arriving and it may still claim them, so its balance
+        /// can grow but not leave.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::freeze_account())]
+        pub fn freeze_account(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            FrozenAccounts::<T>::insert(asset, &who, ());
+            Self::deposit_event(Event::Frozen { asset, who });
+            Ok(())
+        }
+
+        /// Lift a `freeze_account` of `who` for `asset`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::thaw_account())]
+        pub fn thaw_account(
+            origin: T::RuntimeOrigin,
+            asset: T::AssetId,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            FrozenAccounts::<T>::remove(asset, &who);
+            Self::deposit_event(Event::Thawed { asset, who });
+            Ok(())
+        }
+    }
+
+    #[pallet::view_functions]
+    impl<T: Config> Pallet<T> {
+        /// Up to `limit` of `asset`'s audit records with ids from `start` on, oldest
+        /// first. Records older than `MaxAuditHistory` ids have been pruned.
+        pub fn audit_log(
+            asset: T::AssetId,
+            start: u64,
+            limit: u32,
+        ) -> Vec<(u64, AuditRecord<T::AccountId, BlockNumberFor<T>>)> {
+            let end = NextAuditId::<T>::get(asset);
+            let first = start.max(end.saturating_sub(T::MaxAuditHistory::get().into()));
+            (first..end)
+                .take(limit as usize)
+                .filter_map(|id| AuditLog::<T>::get(asset, id).map(|record| (id, record)))
+                .collect()
+        }
+    }
+
+    impl<T: Config> RangeBitsProvider for Pallet<T> {
+        fn range_bits(asset: &[u8]) -> u8 {
+            T::AssetId::decode(&mut &asset[..]).map_or(DEFAULT_RANGE_BITS, |asset| {
+                Self::asset_config(asset).range_bits
+            })
+        }
+    }
+
+    impl<T: Config> ConfidentialBackend<T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
+        fn set_public_key(
+            who: &T::AccountId,
+            elgamal_pk: &PublicKeyBytes,
+            proof: InputProof,
+        ) -> Result<(), DispatchError> {
+            ensure!(!elgamal_pk.is_empty(), Error::<T>::BadCipher);
+            ensure!(!PublicKey::<T>::contains_key(who), Error::<T>::PkAlreadySet);
+            let proof = T::Verifier::open(ProofKind::PubkeyOwnership, proof.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            T::Verifier::verify_pubkey_ownership(&who.encode(), elgamal_pk, proof)
+                .map_err(Error::<T>::InvalidProof)?;
+            PublicKey::<T>::insert(who, elgamal_pk.clone());
+            Ok(())
+        }
+
+        fn total_supply(asset: T::AssetId) -> Commitment {
+            TotalSupplyCommit::<T>::get(asset).unwrap_or_default()
+        }
+
+        fn balance_of(asset: T::AssetId, who: &T::AccountId) -> Commitment {
+            AvailableBalanceCommit::<T>::get(asset, who).unwrap_or_default()
+        }
+
+        fn pending_balance_of(asset: T::AssetId, who: &T::AccountId) -> Commitment {
+            PendingBalanceCommit::<T>::get(asset, who).unwrap_or_default()
+        }
+
+        fn pending_transfers(asset: T::AssetId, who: &T::AccountId) -> Vec<(u64, EncryptedAmount)> {
+            Self::pending_deposits(who, asset)
+        }
+
+        fn last_pending_id(asset: T::AssetId, who: &T::AccountId) -> Option<u64> {
+            NextPendingDepositId::<T>::get(who, asset).checked_sub(1)
+        }
+
+        fn disclose_amount(
+            asset: T::AssetId,
+            encrypted_amount: &EncryptedAmount,
+            who: &T::AccountId,
+            proof: InputProof,
+        ) -> Result<T::Balance, DispatchError> {
+            let proof = T::Verifier::open(ProofKind::Disclosure, proof.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            let pk = PublicKey::<T>::get(who).ok_or(Error::<T>::NoPublicKey)?;
+            let amount = T::Verifier::disclose(
+                &asset.using_encoded(|b| b.to_vec()),
+                &pk,
+                encrypted_amount.as_ref(),
+                proof,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+            Ok(amount.into())
+        }
+
+        fn is_audited(asset: T::AssetId) -> bool {
+            Self::asset_config(asset).auditor.is_some()
+        }
+
+        fn disclose_audited(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            proof: InputProof,
+        ) -> Result<T::Balance, DispatchError> {
+            let auditor_pk = Self::asset_config(asset)
+                .auditor
+                .ok_or(Error::<T>::NotAudited)?;
+            let proof = T::Verifier::open(ProofKind::Disclosure, proof.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            let record = NextAuditId::<T>::get(asset)
+                .checked_sub(1)
+                .and_then(|id| AuditLog::<T>::get(asset, id))
+                .filter(|record| record.from.as_ref() == Some(who))
+                .ok_or(Error::<T>::NothingAudited)?;
+            // The handle (k·G, v·G + k·auditor_pk) reads as a ciphertext under the key
+            // k·G with auditor_pk as its nonce point, so the sender, who chose k,
+            // discloses it with an ordinary disclosure proof keyed by k
+            let handle = [auditor_pk.as_slice(), &record.ciphertext.masked()].concat();
+            let amount = T::Verifier::disclose(
+                &asset.using_encoded(|b| b.to_vec()),
+                &record.ciphertext.nonce_point(),
+                &handle,
+                proof,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+            Ok(amount.into())
+        }
+
+        #[transactional]
+        fn transfer_encrypted(
+            asset: T::AssetId,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            encrypted_amount: EncryptedAmount,
+            input_proof: InputProof,
+        ) -> Result<EncryptedAmount, DispatchError> {
+            Self::ensure_not_paused(asset)?;
+            Self::ensure_not_frozen(asset, from)?;
+            let (input_proof, audit) =
+                Self::open_audited(asset, ProofKind::Transfer, input_proof.as_slice())?;
+            let from_pk = PublicKey::<T>::get(from).ok_or(Error::<T>::NoPublicKey)?;
+            let to_pk = PublicKey::<T>::get(to).ok_or(Error::<T>::NoPublicKey)?;
+            Self::ensure_can_deposit(to, asset)?;
+
+            // lifetime-safe buffers
+            let from_old_avail_opt = AvailableBalanceCommit::<T>::get(asset, from);
+            let from_old_avail_buf;
+            let from_old_avail: &[u8] = match from_old_avail_opt {
+                Some(c) => {
+                    from_old_avail_buf = c;
+                    from_old_avail_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let to_old_pending_opt = PendingBalanceCommit::<T>::get(asset, to);
+            let to_old_pending_buf;
             let to_old_pending: &[u8] = match to_old_pending_opt {
                 Some(c) => {
                     to_old_pending_buf = c;
-                    &to_old_pending_buf[..]
+                    to_old_pending_buf.as_ref()
                 }
                 None => &[],
             };
@@ -306,25 +1132,144 @@ pub mod pallet {
                 &to_pk,
                 from_old_avail,
                 to_old_pending,
-                &encrypted_amount, // Δciphertext bytes
-                input_proof.as_slice(),
+                encrypted_amount.as_ref(), // Δciphertext bytes
+                input_proof,
             )
-            .map_err(|_| Error::<T>::InvalidProof)?;
+            .map_err(Error::<T>::InvalidProof)?;
 
-            let from_new = vec32(from_new_raw).map_err(|_| Error::<T>::BadCipher)?;
-            let to_new_pending = vec32(to_new_pending_raw).map_err(|_| Error::<T>::BadCipher)?;
+            let from_new = commitment::<T>(from_new_raw)?;
+            let to_new_pending = commitment::<T>(to_new_pending_raw)?;
+            let blinding = Self::record_audit(
+                asset,
+                audit,
+                &from_pk,
+                &encrypted_amount,
+                (from_old_avail, from_new.as_ref()),
+                AuditOp::Transfer,
+                Some(from),
+                Some(to),
+            )?;
+            Self::track_blinding(asset, from, from_old_avail_opt.as_ref(), blinding, false);
+
+            let deposit = T::PendingDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::hold(&HoldReason::PendingDeposit.into(), from, deposit)?;
+            }
+            Self::touch(asset, to, from)?;
 
             AvailableBalanceCommit::<T>::insert(asset, from, from_new);
             PendingBalanceCommit::<T>::insert(asset, to, to_new_pending);
 
-            // record UTXO for receiver
-            let id = NextPendingDepositId::<T>::get(to, &asset);
-            PendingDeposits::<T>::insert((to, asset, id), encrypted_amount);
-            NextPendingDepositId::<T>::insert(to, asset, id + 1);
+            // record UTXO for receiver, reclaimable by the sender once it expires
+            let credited = Self::credited(to_new_pending, to_old_pending_opt)?;
+            let id = Self::push_pending(to, asset, encrypted_amount, credited);
+            Self::store_deposit_blinding(to, asset, id, blinding);
+            let deadline =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::PendingTtl::get());
+            PendingDepositSource::<T>::insert(
+                (to, asset, id),
+                DepositSource {
+                    sender: from.clone(),
+                    deadline,
+                    deposit,
+                },
+            );
+            ExpiringDeposits::<T>::insert(deadline, (to.clone(), asset, id), ());
+            Self::debug_check_supply(asset);
 
             Ok(encrypted_amount)
         }
 
+        #[transactional]
+        fn transfer_split(
+            asset: T::AssetId,
+            from: &T::AccountId,
+            total: EncryptedAmount,
+            outputs: &[(T::AccountId, EncryptedAmount)],
+            input_proof: InputProof,
+        ) -> Result<Vec<u64>, DispatchError> {
+            // One proof debits `total` from the sender and shows every output is a
+            // non-negative amount under its recipient's key, and that they add up to
+            // `total`; each output is then queued exactly like a single transfer.
+            Self::ensure_not_paused(asset)?;
+            Self::ensure_not_frozen(asset, from)?;
+            // The outputs carry no auditor handles, so the auditor could not follow them
+            ensure!(
+                Self::asset_config(asset).auditor.is_none(),
+                Error::<T>::AuditedSplit
+            );
+            let input_proof = T::Verifier::open(ProofKind::TransferSplit, input_proof.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            let from_pk = PublicKey::<T>::get(from).ok_or(Error::<T>::NoPublicKey)?;
+            let recipients = outputs
+                .iter()
+                .map(|(to, ct)| {
+                    let to_pk = PublicKey::<T>::get(to).ok_or(Error::<T>::NoPublicKey)?;
+                    Ok((to_pk, *ct))
+                })
+                .collect::<Result<Vec<_>, Error<T>>>()?;
+
+            let from_old_avail_opt = AvailableBalanceCommit::<T>::get(asset, from);
+            let from_old_avail_buf;
+            let from_old_avail: &[u8] = match from_old_avail_opt {
+                Some(c) => {
+                    from_old_avail_buf = c;
+                    from_old_avail_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let (from_new_raw, out_comms) = T::Verifier::verify_transfer_split(
+                &asset.using_encoded(|b| b.to_vec()),
+                &from_pk,
+                from_old_avail,
+                &total,
+                &recipients,
+                input_proof,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+
+            let from_new = commitment::<T>(from_new_raw)?;
+            ensure!(out_comms.len() == outputs.len(), Error::<T>::BadCipher);
+            AvailableBalanceCommit::<T>::insert(asset, from, from_new);
+
+            let deposit = T::PendingDeposit::get();
+            let deadline =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::PendingTtl::get());
+            let mut ids = Vec::with_capacity(outputs.len());
+            for ((to, ct), out_comm) in outputs.iter().zip(out_comms) {
+                // checked per output, so repeated recipients count against their cap
+                Self::ensure_can_deposit(to, asset)?;
+                if !deposit.is_zero() {
+                    T::Currency::hold(&HoldReason::PendingDeposit.into(), from, deposit)?;
+                }
+                Self::touch(asset, to, from)?;
+                // credit the output's commitment, as `transfer` credits ΔC
+                let out_comm = Commitment::new(out_comm);
+                let pending = match PendingBalanceCommit::<T>::get(asset, to) {
+                    Some(old) => old.add(&out_comm).map_err(|_| Error::<T>::BadCipher)?,
+                    None => out_comm,
+                };
+                PendingBalanceCommit::<T>::insert(asset, to, pending);
+
+                let id = Self::push_pending(to, asset, *ct, out_comm);
+                PendingDepositSource::<T>::insert(
+                    (to, asset, id),
+                    DepositSource {
+                        sender: from.clone(),
+                        deadline,
+                        deposit,
+                    },
+                );
+                ExpiringDeposits::<T>::insert(deadline, (to.clone(), asset, id), ());
+                ids.push(id);
+            }
+            Self::debug_check_supply(asset);
+
+            Ok(ids)
+        }
+
+        #[transactional]
         fn claim_encrypted(
             asset: T::AssetId,
             from: &T::AccountId,
@@ -332,18 +1277,23 @@ pub mod pallet {
         ) -> Result<EncryptedAmount, DispatchError> {
             // Thin wrapper around accept_pending:
             // input_proof is assumed to be:
-            //   count:u16 || ids[count]*u64 || accept_envelope:bytes
-            let (ids, accept_envelope) = Self::parse_ids_and_accept_envelope(&input_proof)
-                .map_err(|_| Error::<T>::MalformedEnvelope)?;
+            //   proof_system_id:u8 || count:u16 || ids[count]*u64 || accept_envelope:bytes
+            let claim = T::Verifier::open(ProofKind::Claim, input_proof.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            let (ids, _) = parse_claim(claim).map_err(|_| Error::<T>::MalformedEnvelope)?;
+            Self::ensure_claimable(&ids)?;
+            Self::ensure_not_paused(asset)?;
 
             // Perform the same logic as accept_pending for `from`.
-            Self::do_accept_pending(from.clone(), asset, ids, accept_envelope)?;
+            Self::do_accept_pending(from.clone(), asset, ids, claim)?;
+            Self::debug_check_supply(asset);
 
             // Interface returns an EncryptedAmount; for a pure "claim"/"unlock" there is no new
-            // ciphertext to return. Return 64 zero bytes to signal "no new UTXO created".
-            Ok([0u8; 64])
+            // ciphertext to return. Return the zero ciphertext to signal "no new UTXO created".
+            Ok(EncryptedAmount::zero())
         }
 
+        #[transactional]
         fn mint_encrypted(
             asset: T::AssetId,
             to: &T::AccountId,
@@ -351,14 +1301,18 @@ pub mod pallet {
         ) -> Result<EncryptedAmount, DispatchError> {
             // - verify_mint proves: pending(to) += v, total_supply(asset) += v
             // - it also returns the freshly minted ciphertext for the recipient UTXO list
+            Self::ensure_not_paused(asset)?;
+            let (input_proof, audit) =
+                Self::open_audited(asset, ProofKind::Mint, input_proof.as_slice())?;
             let to_pk = PublicKey::<T>::get(to).ok_or(Error::<T>::NoPublicKey)?;
+            Self::ensure_can_deposit(to, asset)?;
 
             let to_old_pending_opt = PendingBalanceCommit::<T>::get(asset, to);
             let to_old_pending_buf;
             let to_old_pending: &[u8] = match to_old_pending_opt {
                 Some(c) => {
                     to_old_pending_buf = c;
-                    &to_old_pending_buf[..]
+                    to_old_pending_buf.as_ref()
                 }
                 None => &[],
             };
@@ -368,7 +1322,7 @@ pub mod pallet {
             let total_old: &[u8] = match total_old_opt {
                 Some(c) => {
                     total_old_buf = c;
-                    &total_old_buf[..]
+                    total_old_buf.as_ref()
                 }
                 None => &[],
             };
@@ -378,25 +1332,38 @@ pub mod pallet {
                 &to_pk,
                 to_old_pending,
                 total_old,
-                input_proof.as_slice(),
+                input_proof,
             )
-            .map_err(|_| Error::<T>::InvalidProof)?;
+            .map_err(Error::<T>::InvalidProof)?;
 
-            let to_new_pending = vec32(to_new_pending_raw).map_err(|_| Error::<T>::BadCipher)?;
-            let total_new = vec32(total_new_raw).map_err(|_| Error::<T>::BadCipher)?;
+            let to_new_pending = commitment::<T>(to_new_pending_raw)?;
+            let total_new = commitment::<T>(total_new_raw)?;
+            let blinding = Self::record_audit(
+                asset,
+                audit,
+                &to_pk,
+                &minted_ct,
+                (to_new_pending.as_ref(), to_old_pending),
+                AuditOp::Mint,
+                None,
+                Some(to),
+            )?;
 
             // Update storage
+            Self::touch(asset, to, to)?;
             PendingBalanceCommit::<T>::insert(asset, to, to_new_pending);
             TotalSupplyCommit::<T>::insert(asset, total_new);
 
             // Record the minted UTXO for `to`
-            let id = NextPendingDepositId::<T>::get(to, &asset);
-            PendingDeposits::<T>::insert((to, asset, id), minted_ct);
-            NextPendingDepositId::<T>::insert(to, asset, id + 1);
+            let credited = Self::credited(to_new_pending, to_old_pending_opt)?;
+            let id = Self::push_pending(to, asset, minted_ct, credited);
+            Self::store_deposit_blinding(to, asset, id, blinding);
+            Self::debug_check_supply(asset);
 
             Ok(minted_ct)
         }
 
+        #[transactional]
         fn burn_encrypted(
             asset: T::AssetId,
             from: &T::AccountId,
@@ -407,6 +1374,10 @@ pub mod pallet {
             // - verify_burn proves: available(from) -= v, total_supply(asset) -= v,
             //   and that `amount_ciphertext` indeed encrypts v under `from`'s key (or policy key).
             // - it returns new commits and the disclosed v (u64 -> T::Balance).
+            Self::ensure_not_paused(asset)?;
+            Self::ensure_not_frozen(asset, from)?;
+            let (input_proof, audit) =
+                Self::open_audited(asset, ProofKind::Burn, input_proof.as_slice())?;
             let from_pk = PublicKey::<T>::get(from).ok_or(Error::<T>::NoPublicKey)?;
 
             let from_old_avail_opt = AvailableBalanceCommit::<T>::get(asset, from);
@@ -414,7 +1385,7 @@ pub mod pallet {
             let from_old_avail: &[u8] = match from_old_avail_opt {
                 Some(c) => {
                     from_old_avail_buf = c;
-                    &from_old_avail_buf[..]
+                    from_old_avail_buf.as_ref()
                 }
                 None => &[],
             };
@@ -424,7 +1395,7 @@ pub mod pallet {
             let total_old: &[u8] = match total_old_opt {
                 Some(c) => {
                     total_old_buf = c;
-                    &total_old_buf[..]
+                    total_old_buf.as_ref()
                 }
                 None => &[],
             };
@@ -435,75 +1406,329 @@ pub mod pallet {
                 from_old_avail,
                 total_old,
                 &amount_ciphertext,
-                input_proof.as_slice(),
+                input_proof,
             )
-            .map_err(|_| Error::<T>::InvalidProof)?;
+            .map_err(Error::<T>::InvalidProof)?;
 
-            let from_new = vec32(from_new_raw).map_err(|_| Error::<T>::BadCipher)?;
-            let total_new = vec32(total_new_raw).map_err(|_| Error::<T>::BadCipher)?;
+            let from_new = commitment::<T>(from_new_raw)?;
+            let total_new = commitment::<T>(total_new_raw)?;
+            let blinding = Self::record_audit(
+                asset,
+                audit,
+                &from_pk,
+                &amount_ciphertext,
+                (from_old_avail, from_new.as_ref()),
+                AuditOp::Burn,
+                Some(from),
+                None,
+            )?;
+            Self::track_blinding(asset, from, from_old_avail_opt.as_ref(), blinding, false);
 
             AvailableBalanceCommit::<T>::insert(asset, from, from_new);
             TotalSupplyCommit::<T>::insert(asset, total_new);
+            Self::debug_check_supply(asset);
 
             Ok(disclosed_u64.into())
         }
+
+        #[transactional]
+        fn burn_confidential(
+            asset: T::AssetId,
+            from: &T::AccountId,
+            amount_ciphertext: EncryptedAmount,
+            input_proof: InputProof,
+        ) -> Result<(), DispatchError> {
+            // Same state transition as `burn_encrypted`, but the amount never leaves
+            // the ciphertext: available(from) -= v, total_supply(asset) -= v.
+            Self::ensure_not_paused(asset)?;
+            Self::ensure_not_frozen(asset, from)?;
+            let (input_proof, audit) =
+                Self::open_audited(asset, ProofKind::ConfidentialBurn, input_proof.as_slice())?;
+            let from_pk = PublicKey::<T>::get(from).ok_or(Error::<T>::NoPublicKey)?;
+
+            let from_old_avail_opt = AvailableBalanceCommit::<T>::get(asset, from);
+            let from_old_avail_buf;
+            let from_old_avail: &[u8] = match from_old_avail_opt {
+                Some(c) => {
+                    from_old_avail_buf = c;
+                    from_old_avail_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let total_old_opt = TotalSupplyCommit::<T>::get(asset);
+            let total_old_buf;
+            let total_old: &[u8] = match total_old_opt {
+                Some(c) => {
+                    total_old_buf = c;
+                    total_old_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let (from_new_raw, total_new_raw) = T::Verifier::verify_confidential_burn(
+                &asset.using_encoded(|b| b.to_vec()),
+                &from_pk,
+                from_old_avail,
+                total_old,
+                &amount_ciphertext,
+                input_proof,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+
+            let from_new = commitment::<T>(from_new_raw)?;
+            let total_new = commitment::<T>(total_new_raw)?;
+            let blinding = Self::record_audit(
+                asset,
+                audit,
+                &from_pk,
+                &amount_ciphertext,
+                (from_old_avail, from_new.as_ref()),
+                AuditOp::ConfidentialBurn,
+                Some(from),
+                None,
+            )?;
+            Self::track_blinding(asset, from, from_old_avail_opt.as_ref(), blinding, false);
+
+            AvailableBalanceCommit::<T>::insert(asset, from, from_new);
+            TotalSupplyCommit::<T>::insert(asset, total_new);
+            Self::debug_check_supply(asset);
+
+            Ok(())
+        }
+
+        fn burn_held(
+            asset: T::AssetId,
+            from: &T::AccountId,
+            amount: Commitment,
+        ) -> Result<(), DispatchError> {
+            Self::ensure_not_paused(asset)?;
+            let avail_new = AvailableBalanceCommit::<T>::get(asset, from)
+                .unwrap_or_default()
+                .sub(&amount)
+                .map_err(|_| Error::<T>::BadCipher)?;
+            let total_new = TotalSupplyCommit::<T>::get(asset)
+                .unwrap_or_default()
+                .sub(&amount)
+                .map_err(|_| Error::<T>::BadCipher)?;
+
+            AvailableBalanceCommit::<T>::insert(asset, from, avail_new);
+            TotalSupplyCommit::<T>::insert(asset, total_new);
+            // `amount` comes with no auditor handle, so the blinding is lost
+            AvailableBlinding::<T>::remove(asset, from);
+            Self::debug_check_supply(asset);
+            Ok(())
+        }
+
+        #[transactional]
+        fn close_account(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            proof: InputProof,
+        ) -> Result<(), DispatchError> {
+            Self::ensure_not_frozen(asset, who)?;
+            let proof = T::Verifier::open(ProofKind::ZeroBalance, proof.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            let pk = PublicKey::<T>::get(who).ok_or(Error::<T>::NoPublicKey)?;
+
+            let avail_opt = AvailableBalanceCommit::<T>::get(asset, who);
+            let avail_buf;
+            let avail: &[u8] = match avail_opt {
+                Some(c) => {
+                    avail_buf = c;
+                    avail_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let pending_opt = PendingBalanceCommit::<T>::get(asset, who);
+            let pending_buf;
+            let pending: &[u8] = match pending_opt {
+                Some(c) => {
+                    pending_buf = c;
+                    pending_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            T::Verifier::verify_zero_balance(
+                &asset.using_encoded(|b| b.to_vec()),
+                &pk,
+                avail,
+                pending,
+                proof,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+
+            // Keep the deleted blinding so the supply still adds up. The zero-balance
+            // proof has decompressed both, so this only fails under a verifier that
+            // checks nothing, where there is no supply to keep consistent.
+            if let Ok(closed) = [avail_opt, pending_opt]
+                .into_iter()
+                .flatten()
+                .chain(ClosedBalanceCommit::<T>::get(asset))
+                .try_fold(Commitment::IDENTITY, |sum, c| sum.add(&c))
+            {
+                ClosedBalanceCommit::<T>::insert(asset, closed);
+            }
+
+            // Both balances are zero, so any pending UTXOs left behind carry nothing.
+            AvailableBalanceCommit::<T>::remove(asset, who);
+            AvailableBlinding::<T>::remove(asset, who);
+            PendingBalanceCommit::<T>::remove(asset, who);
+            let _ = PendingDeposits::<T>::clear_prefix((who.clone(), asset), u32::MAX, None);
+            let _ = PendingDepositCommit::<T>::clear_prefix((who.clone(), asset), u32::MAX, None);
+            let _ = PendingDepositBlinding::<T>::clear_prefix((who.clone(), asset), u32::MAX, None);
+            for (_, source) in PendingDepositSource::<T>::drain_prefix((who.clone(), asset)) {
+                Self::release_deposit(&source);
+            }
+            PendingDepositCount::<T>::remove(who, asset);
+            NextPendingDepositId::<T>::remove(who, asset);
+            if let Some((payer, deposit)) = AccountDeposits::<T>::take(asset, who) {
+                let _ = T::Currency::release(
+                    &HoldReason::AccountDeposit.into(),
+                    &payer,
+                    deposit,
+                    Precision::BestEffort,
+                );
+            }
+            Self::debug_check_supply(asset);
+
+            Ok(())
+        }
+
+        fn is_paused(asset: T::AssetId) -> bool {
+            PausedAssets::<T>::contains_key(asset)
+        }
+
+        #[transactional]
+        fn seize_balance(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            encrypted_balance: EncryptedAmount,
+            proof: InputProof,
+        ) -> Result<T::Balance, DispatchError> {
+            // Frozen holders can be seized; freezing usually comes first.
+            let auditor_pk = Self::asset_config(asset)
+                .auditor
+                .ok_or(Error::<T>::NotAudited)?;
+            let proof = T::Verifier::open(ProofKind::Seizure, proof.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            // The proof ties the disclosed amount to the stored balance, so a stale or
+            // foreign ciphertext fails here
+            let avail_opt = AvailableBalanceCommit::<T>::get(asset, who);
+            let blinding = Self::tracked_blinding(asset, who, avail_opt.as_ref())
+                .ok_or(Error::<T>::NotSeizable)?;
+            let avail = avail_opt.unwrap_or_default();
+            let amount = T::Verifier::verify_seizure(
+                &asset.using_encoded(|b| b.to_vec()),
+                &auditor_pk,
+                avail.as_ref(),
+                &blinding,
+                &encrypted_balance,
+                proof,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+
+            let total_new = TotalSupplyCommit::<T>::get(asset)
+                .unwrap_or_default()
+                .sub(&avail)
+                .map_err(|_| Error::<T>::BadCipher)?;
+            Self::log_audit(asset, AuditOp::Seizure, Some(who), None, encrypted_balance);
+
+            AvailableBalanceCommit::<T>::remove(asset, who);
+            AvailableBlinding::<T>::remove(asset, who);
+            TotalSupplyCommit::<T>::insert(asset, total_new);
+            Self::debug_check_supply(asset);
+
+            Ok(amount.into())
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl<T: Config> BackendBenchmarkHelper<T::AccountId, T::AssetId> for Pallet<T> {
+        fn enable_asset(asset: T::AssetId) {
+            ConfidentialAssetConfig::<T>::insert(asset, AssetConfig::default());
+        }
+
+        fn set_public_key(who: &T::AccountId, elgamal_pk: PublicKeyBytes) {
+            PublicKey::<T>::insert(who, elgamal_pk);
+        }
+
+        fn set_available(asset: T::AssetId, who: &T::AccountId, available: Commitment) {
+            AvailableBalanceCommit::<T>::insert(asset, who, available);
+        }
+
+        fn set_total_supply(asset: T::AssetId, total: Commitment) {
+            TotalSupplyCommit::<T>::insert(asset, total);
+        }
+
+        fn add_pending(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            amount: EncryptedAmount,
+            commitment: Commitment,
+        ) -> u64 {
+            let pending = match PendingBalanceCommit::<T>::get(asset, who) {
+                Some(old) => old.add(&commitment).expect("valid commitments"),
+                None => commitment,
+            };
+            PendingBalanceCommit::<T>::insert(asset, who, pending);
+            Self::push_pending(who, asset, amount, commitment)
+        }
     }
 
     // -------------------- Internal helpers --------------------
 
     impl<T: Config> Pallet<T> {
-        /// Build the list of 32B commitments (C) from selected UTXO deposits.
-        fn build_pending_commit_list(
+        /// `weight`, benchmarked with 64-bit range proofs, less what `range_proofs`
+        /// proofs at `asset`'s width save.
+        fn range_adjusted_weight(weight: Weight, asset: T::AssetId, range_proofs: u64) -> Weight {
+            let bits = Self::asset_config(asset).range_bits;
+            let saved = T::WeightInfo::range_proof(DEFAULT_RANGE_BITS.into())
+                .saturating_sub(T::WeightInfo::range_proof(bits.into()));
+            weight.saturating_sub(saved.saturating_mul(range_proofs))
+        }
+
+        /// Check a claim's deposit ids before any storage is read: at least one, no more
+        /// than an account can hold, and none twice, since a repeated id would count its
+        /// commitment twice in ΣC.
+        fn ensure_claimable(deposit_ids: &[u64]) -> Result<(), Error<T>> {
+            ensure!(!deposit_ids.is_empty(), Error::<T>::NoPending);
+            ensure!(
+                deposit_ids.len() <= T::MaxPendingPerAccount::get() as usize,
+                Error::<T>::TooManyDeposits
+            );
+            ensure!(
+                !deposit_ids
+                    .iter()
+                    .enumerate()
+                    .any(|(i, id)| deposit_ids[..i].contains(id)),
+                Error::<T>::DuplicateDeposit
+            );
+            Ok(())
+        }
+
+        /// Look up the Pedersen commitment of each selected UTXO deposit. Callers check
+        /// the ids with `ensure_claimable` first.
+        fn build_pending_utxo_list(
             who: &T::AccountId,
             asset: &T::AssetId,
             deposit_ids: &[u64],
-        ) -> Result<Vec<[u8; 32]>, Error<T>> {
-            ensure!(!deposit_ids.is_empty(), Error::<T>::NoPending);
-            let mut out = Vec::with_capacity(deposit_ids.len());
-            for &id in deposit_ids {
-                let dep = PendingDeposits::<T>::get((who.clone(), *asset, id))
-                    .ok_or(Error::<T>::NoPending)?;
-                let mut c = [0u8; 32];
-                c.copy_from_slice(&dep[0..32]); // C part of ElGamal
-                out.push(c);
-            }
-            Ok(out)
-        }
-
-        /// Parse `[ids]*` + envelope from a single `InputProof`.
-        /// Layout:
-        ///   - u16: count
-        ///   - count * u64: deposit ids (LE)
-        ///   - remaining bytes: accept envelope (opaque for verifier)
-        fn parse_ids_and_accept_envelope(input: &InputProof) -> Result<(Vec<u64>, InputProof), ()> {
-            let bytes = input.as_slice();
-            if bytes.len() < 2 {
-                return Err(());
-            }
-            let count = u16::from_le_bytes([bytes[0], bytes[1]]) as usize;
-            let need = 2 + count * 8;
-            if bytes.len() < need {
-                return Err(());
-            }
-            let mut ids = Vec::with_capacity(count);
-            let mut off = 2;
-            for _ in 0..count {
-                let mut le = [0u8; 8];
-                le.copy_from_slice(&bytes[off..off + 8]);
-                ids.push(u64::from_le_bytes(le));
-                off += 8;
-            }
-            let rest = &bytes[off..];
-            // Re-wrap remainder into InputProof
-            let env: InputProof = rest.to_vec().try_into().map_err(|_| ())?;
-            Ok((ids, env))
+        ) -> Result<Vec<(u64, [u8; 32])>, Error<T>> {
+            deposit_ids
+                .iter()
+                .map(|&id| Ok((id, Self::deposit_commitment(who, *asset, id)?.to_bytes())))
+                .collect()
         }
+
         fn do_accept_pending(
             who: T::AccountId,
             asset: T::AssetId,
             deposits: Vec<u64>,
-            accept_envelope: InputProof, // ΔC + 2 range proofs
+            claim: &[u8], // ids + (ΔC + 2 range proofs), already opened
         ) -> DispatchResult {
+            Self::ensure_not_paused(asset)?;
             let who_pk = PublicKey::<T>::get(&who).ok_or(Error::<T>::NoPublicKey)?;
 
             let avail_old_opt = AvailableBalanceCommit::<T>::get(asset, &who);
@@ -511,7 +1736,7 @@ pub mod pallet {
             let avail_old: &[u8] = match avail_old_opt {
                 Some(c) => {
                     avail_old_buf = c;
-                    &avail_old_buf[..]
+                    avail_old_buf.as_ref()
                 }
                 None => &[],
             };
@@ -521,12 +1746,62 @@ pub mod pallet {
             let pending_old: &[u8] = match pending_old_opt {
                 Some(c) => {
                     pending_old_buf = c;
-                    &pending_old_buf[..]
+                    pending_old_buf.as_ref()
                 }
                 None => &[],
             };
 
-            let commits = Self::build_pending_commit_list(&who, &asset, &deposits)?;
+            let utxos = Self::build_pending_utxo_list(&who, &asset, &deposits)?;
+
+            let (avail_new_raw, pending_new_raw, consumed) = T::Verifier::verify_claim(
+                &asset.using_encoded(|b| b.to_vec()),
+                &who_pk,
+                avail_old,
+                pending_old,
+                &utxos,
+                claim,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+
+            Self::settle_accept(&who, asset, &consumed, avail_new_raw, pending_new_raw)
+        }
+
+        fn do_claim_many(
+            who: &T::AccountId,
+            asset: T::AssetId,
+            deposit_ids: &[u64],
+            accept_envelope: InputProof,
+        ) -> DispatchResult {
+            let accept_envelope = T::Verifier::open(ProofKind::Accept, accept_envelope.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            Self::ensure_claimable(deposit_ids)?;
+            Self::ensure_not_paused(asset)?;
+            let who_pk = PublicKey::<T>::get(who).ok_or(Error::<T>::NoPublicKey)?;
+
+            let avail_old_opt = AvailableBalanceCommit::<T>::get(asset, who);
+            let avail_old_buf;
+            let avail_old: &[u8] = match avail_old_opt {
+                Some(c) => {
+                    avail_old_buf = c;
+                    avail_old_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let pending_old_opt = PendingBalanceCommit::<T>::get(asset, who);
+            let pending_old_buf;
+            let pending_old: &[u8] = match pending_old_opt {
+                Some(c) => {
+                    pending_old_buf = c;
+                    pending_old_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let commits: Vec<[u8; 32]> = Self::build_pending_utxo_list(who, &asset, deposit_ids)?
+                .into_iter()
+                .map(|(_, c)| c)
+                .collect();
 
             let (avail_new_raw, pending_new_raw) = T::Verifier::verify_transfer_received(
                 &asset.using_encoded(|b| b.to_vec()),
@@ -534,22 +1809,570 @@ pub mod pallet {
                 avail_old,
                 pending_old,
                 &commits,
-                accept_envelope.as_slice(),
+                accept_envelope,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+
+            Self::settle_accept(who, asset, deposit_ids, avail_new_raw, pending_new_raw)
+        }
+
+        fn do_reclaim_expired(
+            from: &T::AccountId,
+            asset: T::AssetId,
+            to: &T::AccountId,
+            deposit_id: u64,
+            accept_envelope: InputProof,
+        ) -> Result<EncryptedAmount, DispatchError> {
+            Self::ensure_not_paused(asset)?;
+            let accept_envelope = T::Verifier::open(ProofKind::Accept, accept_envelope.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            let key = (to.clone(), asset, deposit_id);
+            let source =
+                PendingDepositSource::<T>::get(key.clone()).ok_or(Error::<T>::NoPending)?;
+            ensure!(source.sender == *from, Error::<T>::NotSender);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= source.deadline,
+                Error::<T>::NotExpired
+            );
+            let deposit = PendingDeposits::<T>::get(key).ok_or(Error::<T>::NoPending)?;
+            let delta = Self::deposit_commitment(to, asset, deposit_id)?;
+            let from_pk = PublicKey::<T>::get(from).ok_or(Error::<T>::NoPublicKey)?;
+
+            let avail_old_opt = AvailableBalanceCommit::<T>::get(asset, from);
+            let avail_old_buf;
+            let avail_old: &[u8] = match avail_old_opt {
+                Some(c) => {
+                    avail_old_buf = c;
+                    avail_old_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let (avail_new_raw, _) = T::Verifier::verify_transfer_received(
+                &asset.using_encoded(|b| b.to_vec()),
+                &from_pk,
+                avail_old,
+                delta.as_ref(),
+                &[delta.to_bytes()],
+                accept_envelope,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+            let avail_new = commitment::<T>(avail_new_raw)?;
+
+            let to_pending = PendingBalanceCommit::<T>::get(asset, to)
+                .unwrap_or_default()
+                .sub(&delta)
+                .map_err(|_| Error::<T>::BadCipher)?;
+            if to_pending.is_identity() {
+                PendingBalanceCommit::<T>::remove(asset, to);
+            } else {
+                PendingBalanceCommit::<T>::insert(asset, to, to_pending);
+            }
+            let blinding = Self::deposit_blinding(to, asset, &[deposit_id]);
+            Self::track_blinding(asset, from, avail_old_opt.as_ref(), blinding, true);
+            AvailableBalanceCommit::<T>::insert(asset, from, avail_new);
+            Self::remove_pending(to, asset, deposit_id);
+
+            Ok(deposit)
+        }
+
+        fn do_rotate_public_key(
+            who: &T::AccountId,
+            asset: T::AssetId,
+            new_pk: PublicKeyBytes,
+            proof: InputProof,
+        ) -> Result<EncryptedAmount, DispatchError> {
+            ensure!(!new_pk.is_empty(), Error::<T>::BadCipher);
+            let proof = T::Verifier::open(ProofKind::Rekey, proof.as_slice())
+                .map_err(Error::<T>::InvalidProof)?;
+            let old_pk = PublicKey::<T>::get(who).ok_or(Error::<T>::NoPublicKey)?;
+
+            let avail_opt = AvailableBalanceCommit::<T>::get(asset, who);
+            let avail_buf;
+            let avail: &[u8] = match avail_opt {
+                Some(c) => {
+                    avail_buf = c;
+                    avail_buf.as_ref()
+                }
+                None => &[],
+            };
+
+            let rekeyed = T::Verifier::verify_rekey(
+                &asset.using_encoded(|b| b.to_vec()),
+                &who.encode(),
+                &old_pk,
+                &new_pk,
+                avail,
+                proof,
+            )
+            .map_err(Error::<T>::InvalidProof)?;
+            PublicKey::<T>::insert(who, new_pk);
+
+            Ok(rekeyed)
+        }
+
+        /// Emit `PendingExpired` for deposits whose TTL ran out by `now`, oldest first,
+        /// within `remaining_weight`. Returns the weight used.
+        pub(crate) fn sweep_expired(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let per_block = db.reads(1);
+            let per_deposit = T::WeightInfo::expire_pending();
+            let mut used = db.reads_writes(1, 1);
+            if remaining_weight.any_lt(used.saturating_add(per_block)) {
+                return Weight::zero();
+            }
+
+            let mut block = NextExpirySweep::<T>::get();
+            while block <= now && used.saturating_add(per_block).all_lte(remaining_weight) {
+                used.saturating_accrue(per_block);
+                let budget: usize = remaining_weight
+                    .saturating_sub(used)
+                    .checked_div_per_component(&per_deposit)
+                    .unwrap_or(u64::MAX)
+                    .try_into()
+                    .unwrap_or(usize::MAX);
+                let due: Vec<_> = ExpiringDeposits::<T>::iter_key_prefix(block)
+                    .take(budget.saturating_add(1))
+                    .collect();
+                let finished = due.len() <= budget;
+                for (who, asset, deposit_id) in due.into_iter().take(budget) {
+                    used.saturating_accrue(per_deposit);
+                    ExpiringDeposits::<T>::remove(block, (who.clone(), asset, deposit_id));
+                    if let Some(source) =
+                        PendingDepositSource::<T>::get((who.clone(), asset, deposit_id))
+                    {
+                        Self::deposit_event(Event::PendingExpired {
+                            asset,
+                            who,
+                            deposit_id,
+                            sender: source.sender,
+                        });
+                    }
+                }
+                if !finished {
+                    break;
+                }
+                block.saturating_inc();
+            }
+            NextExpirySweep::<T>::put(block);
+            used
+        }
+
+        /// Check that `asset`'s total supply commitment is the homomorphic sum of its
+        /// available and pending balance commitments and `ClosedBalanceCommit`.
+        ///
+        /// Every transition moves the same ΔC between the commitments it touches, so a
+        /// mismatch means a path created or destroyed supply. Reads every balance of the
+        /// asset; for `try-runtime` and tests.
+        pub fn check_supply(asset: T::AssetId) -> Result<(), &'static str> {
+            let total = TotalSupplyCommit::<T>::get(asset).unwrap_or_default();
+            let sum = AvailableBalanceCommit::<T>::iter_prefix_values(asset)
+                .chain(PendingBalanceCommit::<T>::iter_prefix_values(asset))
+                .chain(ClosedBalanceCommit::<T>::get(asset))
+                .try_fold(Commitment::IDENTITY, |sum, c| sum.add(&c))
+                .map_err(|_| "a balance commitment is not a valid point")?;
+            ensure!(sum == total, "total supply is not the sum of the balances");
+            Ok(())
+        }
+
+        /// With the `supply-invariant` feature, debug builds re-run [`Self::check_supply`]
+        /// after each backend call.
+        #[inline]
+        fn debug_check_supply(_asset: T::AssetId) {
+            #[cfg(feature = "supply-invariant")]
+            debug_assert_eq!(Self::check_supply(_asset), Ok(()));
+        }
+
+        /// Check that the pending-deposit bookkeeping agrees with the deposits and
+        /// that the audit log only holds ids in its kept window.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub(crate) fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            for (who, asset, count) in PendingDepositCount::<T>::iter() {
+                ensure!(count > 0, "a zero pending count is stored");
+                ensure!(
+                    PendingDeposits::<T>::iter_prefix_values((who.clone(), asset)).count()
+                        == count as usize,
+                    "pending count does not match the deposits"
+                );
+            }
+            for (who, asset, id) in PendingDeposits::<T>::iter_keys() {
+                ensure!(
+                    PendingDepositCount::<T>::contains_key(&who, asset),
+                    "pending deposit is not counted"
+                );
+                ensure!(
+                    PendingDepositCommit::<T>::contains_key((&who, asset, id)),
+                    "pending deposit has no commitment"
+                );
+                ensure!(
+                    id < NextPendingDepositId::<T>::get(&who, asset),
+                    "pending deposit id was never issued"
+                );
+            }
+            for key in PendingDepositSource::<T>::iter_keys() {
+                ensure!(
+                    PendingDeposits::<T>::contains_key(key),
+                    "deposit source without a deposit"
+                );
+            }
+            for key in PendingDepositCommit::<T>::iter_keys() {
+                ensure!(
+                    PendingDeposits::<T>::contains_key(key),
+                    "deposit commitment without a deposit"
+                );
+            }
+            for key in PendingDepositBlinding::<T>::iter_keys() {
+                ensure!(
+                    PendingDeposits::<T>::contains_key(key),
+                    "deposit blinding without a deposit"
+                );
+            }
+            for (asset, next) in NextAuditId::<T>::iter() {
+                let oldest = next.saturating_sub(T::MaxAuditHistory::get().into());
+                for id in AuditLog::<T>::iter_key_prefix(asset) {
+                    ensure!(
+                        (oldest..next).contains(&id),
+                        "audit record outside the kept window"
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        /// `who`'s pending deposits of `asset` as `(id, ciphertext)`, sorted by id.
+        pub fn pending_deposits(
+            who: &T::AccountId,
+            asset: T::AssetId,
+        ) -> Vec<(u64, EncryptedAmount)> {
+            let mut deposits: Vec<_> =
+                PendingDeposits::<T>::iter_prefix((who.clone(), asset)).collect();
+            deposits.sort_unstable_by_key(|(id, _)| *id);
+            deposits
+        }
+
+        /// `asset`'s stored policy, or the default one (enabled unless
+        /// `RequireAssetConfig`) if governance has not configured it.
+        pub fn asset_config(asset: T::AssetId) -> AssetConfig {
+            ConfidentialAssetConfig::<T>::get(asset).unwrap_or_else(|| AssetConfig {
+                enabled: !T::RequireAssetConfig::get(),
+                ..Default::default()
+            })
+        }
+
+        /// Open a `kind` proof, split into its body and, if `asset` is audited, the
+        /// auditor key with the body of the appended auditor handle.
+        fn open_audited(
+            asset: T::AssetId,
+            kind: ProofKind,
+            proof: &[u8],
+        ) -> Result<(&[u8], Option<(PublicKeyBytes, &[u8])>), Error<T>> {
+            match Self::asset_config(asset).auditor {
+                Some(auditor_pk) => {
+                    let (body, handle) =
+                        T::Verifier::open_audited(kind, proof).map_err(Error::<T>::InvalidProof)?;
+                    Ok((body, Some((auditor_pk, handle))))
+                }
+                None => Ok((
+                    T::Verifier::open(kind, proof).map_err(Error::<T>::InvalidProof)?,
+                    None,
+                )),
+            }
+        }
+
+        /// Verify the auditor handle `open_audited` returned for `ct` (under `pk`) and
+        /// log the auditor ciphertext, dropping the record `MaxAuditHistory` ids back.
+        /// `delta` is the pair of commitments whose difference the operation moves.
+        /// Returns the blinding ciphertext of that difference, or `None` for unaudited
+        /// assets, where this does nothing.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) fn record_audit(
+            asset: T::AssetId,
+            audit: Option<(PublicKeyBytes, &[u8])>,
+            pk: &[u8],
+            ct: &EncryptedAmount,
+            delta: (&[u8], &[u8]),
+            op: AuditOp,
+            from: Option<&T::AccountId>,
+            to: Option<&T::AccountId>,
+        ) -> Result<Option<EncryptedAmount>, DispatchError> {
+            let Some((auditor_pk, handle)) = audit else {
+                return Ok(None);
+            };
+            let (ciphertext, blinding) = T::Verifier::verify_auditor_handle(
+                &asset.using_encoded(|b| b.to_vec()),
+                pk,
+                &auditor_pk,
+                ct,
+                delta,
+                handle,
             )
-            .map_err(|_| Error::<T>::InvalidProof)?;
+            .map_err(Error::<T>::InvalidProof)?;
+            Self::log_audit(asset, op, from, to, ciphertext);
+            Ok(Some(blinding))
+        }
 
-            let avail_new = vec32(avail_new_raw).map_err(|_| Error::<T>::BadCipher)?;
-            let pending_new = vec32(pending_new_raw).map_err(|_| Error::<T>::BadCipher)?;
+        /// `who`'s available-balance blinding of `asset` under the current auditor
+        /// key, given its balance `avail`: the tracked one, zero for an empty balance,
+        /// else `None`.
+        fn tracked_blinding(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            avail: Option<&Commitment>,
+        ) -> Option<EncryptedAmount> {
+            match AvailableBlinding::<T>::get(asset, who) {
+                Some((epoch, blinding)) if epoch == AuditorEpoch::<T>::get(asset) => Some(blinding),
+                _ if avail.is_none_or(Commitment::is_identity) => Some(EncryptedAmount::zero()),
+                _ => None,
+            }
+        }
 
-            for id in deposits {
-                PendingDeposits::<T>::remove((who.clone(), asset, id));
+        /// Credit (or debit) the blinding ciphertext `change` to `who`'s tracked
+        /// blinding of an audited `asset`, whose available balance was `avail_old`.
+        /// A `None` change, or a balance with no tracked blinding, leaves it untracked.
+        pub(crate) fn track_blinding(
+            asset: T::AssetId,
+            who: &T::AccountId,
+            avail_old: Option<&Commitment>,
+            change: Option<EncryptedAmount>,
+            credit: bool,
+        ) {
+            if Self::asset_config(asset).auditor.is_none() {
+                return;
             }
+            let tracked = Self::tracked_blinding(asset, who, avail_old)
+                .zip(change)
+                .and_then(|(blinding, change)| {
+                    if credit {
+                        blinding.add(&change).ok()
+                    } else {
+                        blinding.sub(&change).ok()
+                    }
+                });
+            match tracked {
+                Some(blinding) => AvailableBlinding::<T>::insert(
+                    asset,
+                    who,
+                    (AuditorEpoch::<T>::get(asset), blinding),
+                ),
+                None => AvailableBlinding::<T>::remove(asset, who),
+            }
+        }
 
-            AvailableBalanceCommit::<T>::insert(asset, &who, avail_new);
-            if pending_new == [0u8; 32] {
-                PendingBalanceCommit::<T>::remove(asset, &who);
+        /// Keep the blinding ciphertext of pending deposit `id`, if it has one.
+        fn store_deposit_blinding(
+            who: &T::AccountId,
+            asset: T::AssetId,
+            id: u64,
+            blinding: Option<EncryptedAmount>,
+        ) {
+            if let Some(blinding) = blinding {
+                PendingDepositBlinding::<T>::insert(
+                    (who, asset, id),
+                    (AuditorEpoch::<T>::get(asset), blinding),
+                );
+            }
+        }
+
+        /// The summed blinding ciphertexts of `who`'s pending deposits `ids` under the
+        /// current auditor key, or `None` if any of them has none.
+        fn deposit_blinding(
+            who: &T::AccountId,
+            asset: T::AssetId,
+            ids: &[u64],
+        ) -> Option<EncryptedAmount> {
+            let epoch = AuditorEpoch::<T>::get(asset);
+            ids.iter().try_fold(EncryptedAmount::zero(), |sum, &id| {
+                match PendingDepositBlinding::<T>::get((who.clone(), asset, id)) {
+                    Some((e, blinding)) if e == epoch => sum.add(&blinding).ok(),
+                    _ => None,
+                }
+            })
+        }
+
+        /// Append `ciphertext`, already under the auditor key, to `asset`'s audit log,
+        /// dropping the record `MaxAuditHistory` ids back.
+        fn log_audit(
+            asset: T::AssetId,
+            op: AuditOp,
+            from: Option<&T::AccountId>,
+            to: Option<&T::AccountId>,
+            ciphertext: EncryptedAmount,
+        ) {
+            let id = NextAuditId::<T>::get(asset);
+            AuditLog::<T>::insert(
+                asset,
+                id,
+                AuditRecord {
+                    op,
+                    from: from.cloned(),
+                    to: to.cloned(),
+                    block: frame_system::Pallet::<T>::block_number(),
+                    ciphertext,
+                },
+            );
+            if let Some(stale) = id.checked_sub(T::MaxAuditHistory::get().into()) {
+                AuditLog::<T>::remove(asset, stale);
+            }
+            NextAuditId::<T>::insert(asset, id + 1);
+        }
+
+        /// A claim of the `n` deposits an accept envelope lists: `claim_many` prices one
+        /// per deposit, plus the blinding bookkeeping.
+        pub(crate) fn accept_weight(n: u32) -> Weight {
+            T::WeightInfo::claim_many(n).saturating_add(Self::blinding_weight(n))
+        }
+
+        /// `transfer_from_available`, priced for a single deposit, with the claim priced
+        /// for the `n` deposits its accept envelope lists instead.
+        pub(crate) fn accept_and_transfer_weight(n: u32) -> Weight {
+            T::WeightInfo::transfer_from_available()
+                .saturating_sub(T::WeightInfo::accept_pending())
+                .saturating_add(Self::accept_weight(n))
+        }
+
+        /// Storage work of crediting the auditor blinding of `n` claimed deposits: the
+        /// asset config, epoch and holder entry, and each deposit's blinding.
+        pub(crate) fn blinding_weight(n: u32) -> Weight {
+            T::DbWeight::get().reads_writes(3 + u64::from(n), 1 + u64::from(n))
+        }
+
+        /// `WeightInfo::audit` if `asset` is audited, zero otherwise.
+        fn audit_weight(asset: T::AssetId) -> Weight {
+            if Self::asset_config(asset).auditor.is_some() {
+                T::WeightInfo::audit()
+            } else {
+                Weight::zero()
+            }
+        }
+
+        fn ensure_not_paused(asset: T::AssetId) -> Result<(), Error<T>> {
+            ensure!(
+                !PausedAssets::<T>::contains_key(asset),
+                Error::<T>::AssetPaused
+            );
+            Ok(())
+        }
+
+        fn ensure_not_frozen(asset: T::AssetId, who: &T::AccountId) -> Result<(), Error<T>> {
+            ensure!(
+                !FrozenAccounts::<T>::contains_key(asset, who),
+                Error::<T>::Frozen
+            );
+            Ok(())
+        }
+
+        /// `asset` is enabled and `who` has room for another pending deposit of it.
+        fn ensure_can_deposit(who: &T::AccountId, asset: T::AssetId) -> Result<(), Error<T>> {
+            let config = Self::asset_config(asset);
+            ensure!(config.enabled, Error::<T>::AssetDisabled);
+            let cap = config
+                .max_pending
+                .unwrap_or(u32::MAX)
+                .min(T::MaxPendingPerAccount::get());
+            ensure!(
+                PendingDepositCount::<T>::get(who, asset) < cap,
+                Error::<T>::TooManyPending
+            );
+            Ok(())
+        }
+
+        /// Hold `AccountDeposit` from `payer` unless `who` already has state of `asset`
+        /// paid for. State created before the deposit existed is charged on its next
+        /// transfer or mint in.
+        fn touch(asset: T::AssetId, who: &T::AccountId, payer: &T::AccountId) -> DispatchResult {
+            let deposit = T::AccountDeposit::get();
+            if deposit.is_zero() || AccountDeposits::<T>::contains_key(asset, who) {
+                return Ok(());
+            }
+            T::Currency::hold(&HoldReason::AccountDeposit.into(), payer, deposit)?;
+            AccountDeposits::<T>::insert(asset, who, (payer.clone(), deposit));
+            Ok(())
+        }
+
+        /// Record a pending deposit of `ct`, which added `commitment` to `who`'s pending
+        /// balance, and return its id. Callers check `ensure_can_deposit` first.
+        fn push_pending(
+            who: &T::AccountId,
+            asset: T::AssetId,
+            ct: EncryptedAmount,
+            commitment: Commitment,
+        ) -> u64 {
+            let id = NextPendingDepositId::<T>::get(who, asset);
+            PendingDeposits::<T>::insert((who, asset, id), ct);
+            PendingDepositCommit::<T>::insert((who, asset, id), commitment);
+            NextPendingDepositId::<T>::insert(who, asset, id + 1);
+            PendingDepositCount::<T>::mutate(who, asset, |count| *count += 1);
+            id
+        }
+
+        /// The commitment a claim of pending deposit `id` consumes.
+        fn deposit_commitment(
+            who: &T::AccountId,
+            asset: T::AssetId,
+            id: u64,
+        ) -> Result<Commitment, Error<T>> {
+            PendingDepositCommit::<T>::get((who.clone(), asset, id)).ok_or(Error::<T>::NoPending)
+        }
+
+        /// What a deposit added to a pending balance that went from `old` to `new`.
+        fn credited(new: Commitment, old: Option<Commitment>) -> Result<Commitment, Error<T>> {
+            new.sub(&old.unwrap_or_default())
+                .map_err(|_| Error::<T>::BadCipher)
+        }
+
+        /// Delete a pending deposit and return its sender's storage deposit.
+        fn remove_pending(who: &T::AccountId, asset: T::AssetId, id: u64) {
+            PendingDeposits::<T>::remove((who.clone(), asset, id));
+            PendingDepositCommit::<T>::remove((who.clone(), asset, id));
+            PendingDepositBlinding::<T>::remove((who.clone(), asset, id));
+            if let Some(source) = PendingDepositSource::<T>::take((who.clone(), asset, id)) {
+                Self::release_deposit(&source);
+            }
+            let count = PendingDepositCount::<T>::get(who, asset).saturating_sub(1);
+            if count == 0 {
+                PendingDepositCount::<T>::remove(who, asset);
             } else {
-                PendingBalanceCommit::<T>::insert(asset, &who, pending_new);
+                PendingDepositCount::<T>::insert(who, asset, count);
+            }
+        }
+
+        fn release_deposit(
+            source: &DepositSource<T::AccountId, BlockNumberFor<T>, DepositBalanceOf<T>>,
+        ) {
+            if !source.deposit.is_zero() {
+                // Never blocks the claim: whatever is still held is returned
+                let _ = T::Currency::release(
+                    &HoldReason::PendingDeposit.into(),
+                    &source.sender,
+                    source.deposit,
+                    Precision::BestEffort,
+                );
+            }
+        }
+
+        /// Delete the consumed deposits and store the accepted (avail, pending) pair.
+        fn settle_accept(
+            who: &T::AccountId,
+            asset: T::AssetId,
+            consumed: &[u64],
+            avail_new_raw: Vec<u8>,
+            pending_new_raw: Vec<u8>,
+        ) -> DispatchResult {
+            let avail_new = commitment::<T>(avail_new_raw)?;
+            let pending_new = commitment::<T>(pending_new_raw)?;
+
+            let avail_old = AvailableBalanceCommit::<T>::get(asset, who);
+            let blinding = Self::deposit_blinding(who, asset, consumed);
+            Self::track_blinding(asset, who, avail_old.as_ref(), blinding, true);
+            for &id in consumed {
+                Self::remove_pending(who, asset, id);
+            }
+
+            AvailableBalanceCommit::<T>::insert(asset, who, avail_new);
+            if pending_new.is_identity() {
+                PendingBalanceCommit::<T>::remove(asset, who);
+            } else {
+                PendingBalanceCommit::<T>::insert(asset, who, pending_new);
             }
 
             Ok(())
@@ -558,12 +2381,16 @@ pub mod pallet {
 
     // -------------------- Tiny util --------------------
 
-    fn vec32(v: Vec<u8>) -> Result<[u8; 32], ()> {
-        if v.len() != 32 {
-            return Err(());
+    /// Number of deposit ids a sealed accept envelope lists, from its count field; the
+    /// claim fails later if the envelope is malformed.
+    fn claimed_utxos(accept_envelope: &InputProof) -> u32 {
+        match accept_envelope.get(1..3) {
+            Some(&[lo, hi]) => u16::from_le_bytes([lo, hi]).into(),
+            _ => 0,
         }
-        let mut out = [0u8; 32];
-        out.copy_from_slice(&v);
-        Ok(out)
+    }
+
+    fn commitment<T: Config>(v: Vec<u8>) -> Result<Commitment, Error<T>> {
+        Commitment::try_from(v).map_err(|_| Error::<T>::BadCipher)
     }
 }
@@ -0,0 +1,140 @@
+//! Storage migrations for pallet-zkhe.
+
+use super::*;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// From the unversioned layout (v0) to v1.
+///
+/// v0 kept commitments and ciphertexts as raw `[u8; 32]` / `[u8; 64]`, did not count
+/// pending deposits, and may hold range-proof widths in the separate `AssetRangeBits`
+/// map. This rewrites the raw arrays as `Commitment` / `EncryptedAmount` (same SCALE
+/// bytes), recounts every account's pending deposits and folds `AssetRangeBits` into
+/// `ConfidentialAssetConfig`. It runs in a single block.
+pub mod v1 {
+    use super::*;
+
+    /// v0 items that no longer exist in the pallet.
+    pub(crate) mod v0 {
+        use super::*;
+        use frame_support::storage_alias;
+
+        #[storage_alias]
+        pub type AssetRangeBits<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::AssetId, u8, OptionQuery>;
+    }
+
+    /// Entry counts checked by `post_upgrade`.
+    #[cfg(feature = "try-runtime")]
+    #[derive(Encode, Decode)]
+    struct Counts {
+        available: u32,
+        pending: u32,
+        supply: u32,
+        deposits: u32,
+    }
+
+    #[cfg(feature = "try-runtime")]
+    impl Counts {
+        fn take<T: Config>() -> Self {
+            Self {
+                available: AvailableBalanceCommit::<T>::iter_keys().count() as u32,
+                pending: PendingBalanceCommit::<T>::iter_keys().count() as u32,
+                supply: TotalSupplyCommit::<T>::iter_keys().count() as u32,
+                deposits: PendingDeposits::<T>::iter_keys().count() as u32,
+            }
+        }
+    }
+
+    /// The migration without its version check; use [`MigrateV0ToV1`].
+    pub struct VersionUncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+
+            AvailableBalanceCommit::<T>::translate::<[u8; 32], _>(|_, _, raw| {
+                reads.saturating_inc();
+                writes.saturating_inc();
+                Some(Commitment::new(raw))
+            });
+            PendingBalanceCommit::<T>::translate::<[u8; 32], _>(|_, _, raw| {
+                reads.saturating_inc();
+                writes.saturating_inc();
+                Some(Commitment::new(raw))
+            });
+            TotalSupplyCommit::<T>::translate::<[u8; 32], _>(|_, raw| {
+                reads.saturating_inc();
+                writes.saturating_inc();
+                Some(Commitment::new(raw))
+            });
+
+            // Counts written by an unversioned build that already had them are rebuilt too
+            let cleared = PendingDepositCount::<T>::clear(u32::MAX, None);
+            writes.saturating_accrue(cleared.backend.into());
+            PendingDeposits::<T>::translate::<[u8; 64], _>(|(who, asset, _), raw| {
+                reads.saturating_accrue(2);
+                writes.saturating_accrue(2);
+                PendingDepositCount::<T>::mutate(&who, asset, |count| count.saturating_inc());
+                Some(EncryptedAmount::new(raw))
+            });
+
+            for (asset, bits) in v0::AssetRangeBits::<T>::drain() {
+                reads.saturating_accrue(2);
+                writes.saturating_accrue(2);
+                let mut config = Pallet::<T>::asset_config(asset);
+                config.range_bits = bits;
+                ConfidentialAssetConfig::<T>::insert(asset, config);
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(Counts::take::<T>().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let before = Counts::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("pre_upgrade state does not decode"))?;
+            // `iter` decodes every value as its v1 type
+            let after = Counts {
+                available: AvailableBalanceCommit::<T>::iter().count() as u32,
+                pending: PendingBalanceCommit::<T>::iter().count() as u32,
+                supply: TotalSupplyCommit::<T>::iter().count() as u32,
+                deposits: PendingDeposits::<T>::iter().count() as u32,
+            };
+            ensure!(
+                before.available == after.available
+                    && before.pending == after.pending
+                    && before.supply == after.supply
+                    && before.deposits == after.deposits,
+                "entries were lost"
+            );
+            let counted = PendingDepositCount::<T>::iter_values()
+                .fold(0u32, |sum, count| sum.saturating_add(count));
+            ensure!(
+                counted == after.deposits,
+                "pending counts do not match the deposits"
+            );
+            ensure!(
+                v0::AssetRangeBits::<T>::iter_keys().next().is_none(),
+                "AssetRangeBits was not drained"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`VersionUncheckedMigrateV0ToV1`], run only while the on-chain version is 0.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        VersionUncheckedMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,8 +1,12 @@
 use crate::pallet as pallet_zkhe;
 use confidential_assets_primitives::{
-    ConfidentialBackend, EncryptedAmount, InputProof, NetworkIdProvider, PublicKeyBytes, ZkVerifier,
+    ConfidentialBackend, EncryptedAmount, InputProof, NetworkIdProvider, ProofCarrier, ProofKind,
+    ProofSystem, ProofSystemId, PublicKeyBytes, VerifyError, ZkVerifier, seal_proof,
+};
+use frame_support::{
+    construct_runtime, derive_impl,
+    traits::{ConstBool, ConstU32, ConstU64, fungible::Mutate},
 };
-use frame_support::{construct_runtime, derive_impl};
 use sp_runtime::BuildStorage;
 
 pub type AccountId = u64;
@@ -12,6 +16,10 @@ pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const CHARLIE: AccountId = 3;
 pub const ASSET: AssetId = 7;
+/// Held from the sender per pending transfer.
+pub const DEPOSIT: Balance = 5;
+/// Native balance of each funded test account.
+pub const ENDOWMENT: Balance = 1_000;
 
 // --- Mock Network ID Provider -----------------------------------------------
 // Returns zero network ID for testing (matches the default vector generation).
@@ -30,11 +38,102 @@ impl NetworkIdProvider for MockNetworkId {
 #[derive(Default)]
 pub struct AlwaysOkVerifier;
 
+thread_local! {
+    // When set, `verify_transfer_sent` fails with this reason instead.
+    static REJECT_TRANSFER: core::cell::Cell<Option<VerifyError>> =
+        const { core::cell::Cell::new(None) };
+}
+
+thread_local! {
+    // Pending commitments handed to the last `verify_transfer_received` call.
+    static ACCEPTED_COMMITS: core::cell::RefCell<Vec<[u8; 32]>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+}
+
+/// Pending commitments the mock verifier was last asked to sum.
+pub fn accepted_commits() -> Vec<[u8; 32]> {
+    ACCEPTED_COMMITS.with(|c| c.borrow().clone())
+}
+
+thread_local! {
+    // Available balance and blinding handed to the last `verify_seizure` call.
+    static SEIZED_AVAIL: core::cell::RefCell<(Vec<u8>, EncryptedAmount)> =
+        const { core::cell::RefCell::new((Vec::new(), EncryptedAmount::ZERO)) };
+}
+
+/// Available balance the mock verifier was last asked to tie a seizure to.
+pub fn seized_avail() -> Vec<u8> {
+    SEIZED_AVAIL.with(|a| a.borrow().0.clone())
+}
+
+/// Blinding ciphertext the mock verifier was last handed for a seizure.
+pub fn seized_blinding() -> EncryptedAmount {
+    SEIZED_AVAIL.with(|a| a.borrow().1)
+}
+
+/// The compressed Ristretto basepoint, a valid point for ciphertexts the pallet adds up.
+pub const BASEPOINT: [u8; 32] = [
+    0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71, 0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00, 0x51, 0x5f,
+    0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d, 0xb6, 0xa6, 0x59, 0x45, 0xe0, 0x8d, 0x2d, 0x76,
+];
+
+thread_local! {
+    static ACCOUNT_DEPOSIT: core::cell::Cell<Balance> = const { core::cell::Cell::new(0) };
+}
+
+/// `AccountDeposit`, zero unless a test sets it.
+pub struct AccountDeposit;
+impl frame_support::traits::Get<Balance> for AccountDeposit {
+    fn get() -> Balance {
+        ACCOUNT_DEPOSIT.with(|d| d.get())
+    }
+}
+
+/// Hold `amount` per (asset, account) state from now on.
+pub fn set_account_deposit(amount: Balance) {
+    ACCOUNT_DEPOSIT.with(|d| d.set(amount));
+}
+
+/// Make the mock verifier reject sender proofs with `reason`.
+pub fn reject_transfers_with(reason: Option<VerifyError>) {
+    REJECT_TRANSFER.with(|r| r.set(reason));
+}
+
 impl ZkVerifier for AlwaysOkVerifier {
-    type Error = ();
     type NetworkIdProvider = MockNetworkId;
+
+    // Any non-empty proof shows key ownership
+    fn verify_pubkey_ownership(
+        _account: &[u8],
+        _pk: &[u8],
+        proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        if proof.is_empty() {
+            return Err(VerifyError::LinkProofInvalid);
+        }
+        Ok(())
+    }
+
     // Disclose encrypted amount -> constant u64 (e.g., 123)
-    fn disclose(_asset: &[u8], _pk: &[u8], _cipher: &[u8]) -> Result<u64, ()> {
+    fn disclose(
+        _asset: &[u8],
+        _pk: &[u8],
+        _cipher: &[u8],
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
+        Ok(123)
+    }
+
+    // Seize -> constant u64, like `disclose`
+    fn verify_seizure(
+        _asset: &[u8],
+        _auditor_pk: &[u8],
+        avail: &[u8],
+        blinding: &EncryptedAmount,
+        _ct: &EncryptedAmount,
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
+        SEIZED_AVAIL.with(|a| *a.borrow_mut() = (avail.to_vec(), *blinding));
         Ok(123)
     }
 
@@ -47,7 +146,10 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _delta_ct: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        if let Some(reason) = REJECT_TRANSFER.with(|r| r.get()) {
+            return Err(reason);
+        }
         Ok((vec![1u8; 32], vec![2u8; 32]))
     }
 
@@ -57,9 +159,10 @@ impl ZkVerifier for AlwaysOkVerifier {
         _who_pk: &[u8],
         _avail_old: &[u8],
         _pending_old: &[u8],
-        _commits: &[[u8; 32]],
+        commits: &[[u8; 32]],
         _envelope: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        ACCEPTED_COMMITS.with(|c| *c.borrow_mut() = commits.to_vec());
         // Make pending_new zero so pallet removes PendingBalanceCommit on accept
         Ok((vec![3u8; 32], vec![0u8; 32]))
     }
@@ -71,8 +174,13 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _total_old: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), ()> {
-        Ok((vec![10u8; 32], vec![11u8; 32], [5u8; 64]))
+    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), VerifyError> {
+        // A real point: the pallet records what the mint added to the pending balance
+        Ok((
+            vec![14u8; 32],
+            vec![11u8; 32],
+            EncryptedAmount::new([5u8; 64]),
+        ))
     }
 
     // from_new_available, total_new, disclosed_u64
@@ -83,14 +191,110 @@ impl ZkVerifier for AlwaysOkVerifier {
         _total_old: &[u8],
         _amount_ct: &EncryptedAmount,
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, u64), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>, u64), VerifyError> {
         Ok((vec![20u8; 32], vec![21u8; 32], 42))
     }
+
+    fn verify_confidential_burn(
+        _asset: &[u8],
+        _from_pk: &PublicKeyBytes,
+        _from_old_avail: &[u8],
+        _total_old: &[u8],
+        _amount_ct: &EncryptedAmount,
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        Ok((vec![22u8; 32], vec![23u8; 32]))
+    }
+
+    // from_new_available, and each output's masked half standing in for its commitment
+    fn verify_transfer_split(
+        _asset: &[u8],
+        _from_pk: &[u8],
+        _from_old_avail: &[u8],
+        _total: &EncryptedAmount,
+        outputs: &[(PublicKeyBytes, EncryptedAmount)],
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<[u8; 32]>), VerifyError> {
+        if let Some(reason) = REJECT_TRANSFER.with(|r| r.get()) {
+            return Err(reason);
+        }
+        Ok((
+            vec![24u8; 32],
+            outputs.iter().map(|(_, ct)| ct.masked()).collect(),
+        ))
+    }
+
+    fn verify_zero_balance(
+        _asset: &[u8],
+        _who_pk: &[u8],
+        _avail: &[u8],
+        _pending: &[u8],
+        _proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        Ok(())
+    }
+
+    // Any non-empty proof rekeys the balance to a constant ciphertext
+    fn verify_rekey(
+        _asset: &[u8],
+        _account: &[u8],
+        _old_pk: &[u8],
+        _new_pk: &[u8],
+        _avail: &[u8],
+        proof: &[u8],
+    ) -> Result<EncryptedAmount, VerifyError> {
+        if proof.is_empty() {
+            return Err(VerifyError::LinkProofInvalid);
+        }
+        Ok(EncryptedAmount::new([6u8; 64]))
+    }
+
+    // The auditor ciphertext reuses the transfer's nonce point and takes its masked half
+    // from the proof's first byte; a zero byte is rejected. Every blinding ciphertext is
+    // (G, G).
+    fn verify_auditor_handle(
+        _asset: &[u8],
+        _pk: &[u8],
+        _auditor_pk: &[u8],
+        ct: &EncryptedAmount,
+        _delta: (&[u8], &[u8]),
+        proof: &[u8],
+    ) -> Result<(EncryptedAmount, EncryptedAmount), VerifyError> {
+        match proof.first() {
+            Some(&b) if b != 0 => Ok((
+                EncryptedAmount::from_parts(ct.nonce_point(), [b; 32]),
+                EncryptedAmount::from_parts(BASEPOINT, BASEPOINT),
+            )),
+            _ => Err(VerifyError::LinkProofInvalid),
+        }
+    }
+}
+
+/// Length of a mock auditor handle body.
+pub const AUDITOR_PROOF_LEN: usize = 4;
+
+// Stands in for the ZK-ElGamal proof system and accepts any body length, except for
+// auditor handles, which must be fixed-length
+impl ProofSystem for AlwaysOkVerifier {
+    const ID: ProofSystemId = 0;
+
+    fn proof_len(kind: ProofKind) -> Option<(usize, usize)> {
+        match kind {
+            ProofKind::AuditorHandle => Some((AUDITOR_PROOF_LEN, AUDITOR_PROOF_LEN)),
+            _ => Some((0, usize::MAX)),
+        }
+    }
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Runtime {
     type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
 }
 
 impl pallet_zkhe::Config for Runtime {
@@ -98,22 +302,47 @@ impl pallet_zkhe::Config for Runtime {
     type AssetId = AssetId;
     type Balance = Balance;
     type Verifier = AlwaysOkVerifier;
+    type MaxClaimUtxos = ConstU32<16>;
+    type PendingTtl = ConstU64<10>;
+    type MaxPendingPerAccount = ConstU32<8>;
+    type PendingDeposit = ConstU64<DEPOSIT>;
+    type AccountDeposit = AccountDeposit;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RequireAssetConfig = ConstBool<false>;
+    type MaxAuditHistory = ConstU32<4>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
 construct_runtime!(
     pub enum Runtime {
         System: frame_system,
+        Balances: pallet_balances,
         Zkhe: pallet_zkhe,
     }
 );
 
+impl ProofCarrier for RuntimeCall {
+    fn proofs(&self) -> Vec<(ProofKind, &[u8])> {
+        match self {
+            RuntimeCall::Zkhe(call) => call.proofs(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 // Build a fresh externalities for each test.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::<Runtime>::default()
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
         .build_storage()
         .unwrap();
-    // nothing else needed in genesis
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![(ALICE, ENDOWMENT), (BOB, ENDOWMENT), (CHARLIE, ENDOWMENT)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     let mut ext = sp_io::TestExternalities::new(t);
     ext.execute_with(|| System::set_block_number(1));
     ext
@@ -122,12 +351,35 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 // Handy helpers
 pub fn set_pk(who: AccountId) {
     // Non-empty fake pk
-    Zkhe::set_public_key(&who, &[7u8; 64].to_vec().try_into().expect("bounded vec")).unwrap();
+    Zkhe::set_public_key(
+        &who,
+        &[7u8; 64].to_vec().try_into().expect("bounded vec"),
+        proof(&[1u8; 64]),
+    )
+    .unwrap();
 }
 
-// Construct InputProof from raw bytes using TryFrom<Vec<u8>>
+// Give `who` native funds for the storage deposits of its transfers
+pub fn fund(who: AccountId) {
+    Balances::set_balance(&who, ENDOWMENT);
+}
+
+// Construct InputProof from raw bytes, enveloped for the mock proof system
 pub fn proof(bytes: &[u8]) -> InputProof {
-    bytes.to_vec().try_into().expect("bounded vec")
+    seal_proof(AlwaysOkVerifier::ID, bytes)
+        .try_into()
+        .expect("bounded vec")
+}
+
+// A proof for an audited asset: `bytes` enveloped, then an auditor handle whose
+// ciphertext gets `[handle; 32]` as its masked half
+pub fn audited_proof(bytes: &[u8], handle: u8) -> InputProof {
+    let mut v = seal_proof(AlwaysOkVerifier::ID, bytes);
+    v.extend(seal_proof(
+        AlwaysOkVerifier::ID,
+        &[handle; AUDITOR_PROOF_LEN],
+    ));
+    v.try_into().expect("bounded vec")
 }
 
 // Accept envelope encoding: u16 count || ids (u64 LE) * count || rest (opaque)
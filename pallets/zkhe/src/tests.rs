@@ -1,6 +1,10 @@
 use super::*;
 use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{
+    assert_ok,
+    dispatch::GetDispatchInfo,
+    traits::fungible::{InspectHold, Mutate},
+};
 use proptest::prelude::*;
 use sp_runtime::traits::BadOrigin;
 
@@ -13,7 +17,23 @@ fn last_event() -> RuntimeEvent {
 
 // A 64B “ciphertext” convenience
 fn ct(val: u8) -> EncryptedAmount {
-    [val; 64]
+    EncryptedAmount::new([val; 64])
+}
+
+// A 32B “commitment” convenience
+fn commit(val: u8) -> Commitment {
+    Commitment::new([val; 32])
+}
+
+// Queue `ct(val)` for `who` as pending deposit `id`, committed to by `commit(val)`
+fn queue(who: AccountId, id: u64, val: u8) {
+    PendingDeposits::<Runtime>::insert((who, ASSET, id), ct(val));
+    PendingDepositCommit::<Runtime>::insert((who, ASSET, id), commit(val));
+}
+
+// Native balance `who` has on hold for pending transfers
+fn held(who: AccountId) -> Balance {
+    Balances::balance_on_hold(&HoldReason::PendingDeposit.into(), &who)
 }
 
 #[test]
@@ -25,6 +45,7 @@ fn set_public_key_and_disclose_works() {
             ASSET,
             &ct(9),
             &ALICE,
+            proof(&[1, 2, 3]),
         )
         .expect("ok");
         // Mock verifier discloses 123
@@ -32,6 +53,44 @@ fn set_public_key_and_disclose_works() {
     });
 }
 
+#[test]
+fn set_public_key_requires_ownership_proof() {
+    new_test_ext().execute_with(|| {
+        let pk: PublicKeyBytes = [7u8; 32].to_vec().try_into().expect("bounded vec");
+        let err = <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::set_public_key(
+            &ALICE,
+            &pk,
+            proof(&[]),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            Error::<Runtime>::InvalidProof(VerifyError::LinkProofInvalid).into()
+        );
+        assert!(PublicKey::<Runtime>::get(ALICE).is_none());
+
+        assert_ok!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::set_public_key(
+                &ALICE,
+                &pk,
+                proof(&[1u8; 64]),
+            )
+        );
+        assert_eq!(PublicKey::<Runtime>::get(ALICE), Some(pk.clone()));
+
+        // Registered keys change only through `rotate_public_key`.
+        let other: PublicKeyBytes = [8u8; 32].to_vec().try_into().expect("bounded vec");
+        let err = <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::set_public_key(
+            &ALICE,
+            &other,
+            proof(&[1u8; 64]),
+        )
+        .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::PkAlreadySet.into());
+        assert_eq!(PublicKey::<Runtime>::get(ALICE), Some(pk));
+    });
+}
+
 #[test]
 fn transfer_sets_commits_records_utxo_and_emits() {
     new_test_ext().execute_with(|| {
@@ -51,11 +110,11 @@ fn transfer_sets_commits_records_utxo_and_emits() {
         // from_new_available = [1;32], to_new_pending = [2;32]
         assert_eq!(
             AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap(),
-            [1u8; 32]
+            commit(1)
         );
         assert_eq!(
             PendingBalanceCommit::<Runtime>::get(ASSET, BOB).unwrap(),
-            [2u8; 32]
+            commit(2)
         );
 
         // A UTXO is recorded for the receiver at id 0
@@ -89,7 +148,7 @@ fn accept_pending_consumes_utxos_updates_balances_and_emits() {
         set_pk(BOB);
 
         // Seed one pending deposit for BOB (id 0)
-        PendingDeposits::<Runtime>::insert((BOB, ASSET, 0), ct(7));
+        queue(BOB, 0, 7);
         NextPendingDepositId::<Runtime>::insert(BOB, ASSET, 1);
 
         // Build envelope with ids=[0] and dummy rest
@@ -104,7 +163,7 @@ fn accept_pending_consumes_utxos_updates_balances_and_emits() {
         // Verifier returns avail_new=[3;32], pending_new=[0;32] -> pallet removes pending commit
         assert_eq!(
             AvailableBalanceCommit::<Runtime>::get(ASSET, BOB).unwrap(),
-            [3u8; 32]
+            commit(3)
         );
         assert!(PendingBalanceCommit::<Runtime>::get(ASSET, BOB).is_none());
 
@@ -120,13 +179,280 @@ fn accept_pending_consumes_utxos_updates_balances_and_emits() {
             }) => {
                 assert_eq!(asset, ASSET);
                 assert_eq!(who, BOB);
-                assert_eq!(encrypted_amount, [0u8; 64]);
+                assert_eq!(encrypted_amount, EncryptedAmount::zero());
             }
             e => panic!("unexpected event: {e:?}"),
         }
     });
 }
 
+#[test]
+fn accept_pending_rejects_duplicate_utxo_ids() {
+    new_test_ext().execute_with(|| {
+        PendingDeposits::<Runtime>::insert((BOB, ASSET, 0), ct(7));
+        NextPendingDepositId::<Runtime>::insert(BOB, ASSET, 1);
+        let accept = |ids: &[u64]| {
+            Pallet::<Runtime>::accept_pending(
+                RuntimeOrigin::signed(BOB),
+                ASSET,
+                accept_input(ids, &[]),
+            )
+            .unwrap_err()
+            .error
+        };
+
+        // Listing id 0 twice would count its commitment twice in ΣC. BOB has no key,
+        // so failing with this error shows the ids are checked before storage is read.
+        assert_eq!(accept(&[0, 0]), Error::<Runtime>::DuplicateDeposit.into());
+        assert!(PendingDeposits::<Runtime>::get((BOB, ASSET, 0)).is_some());
+
+        // Nor can a claim list more deposits than MaxPendingPerAccount (8 in the mock)
+        let ids: Vec<u64> = (0..9).collect();
+        assert_eq!(accept(&ids), Error::<Runtime>::TooManyDeposits.into());
+        assert_eq!(accept(&ids[..8]), Error::<Runtime>::NoPublicKey.into());
+    });
+}
+
+#[test]
+fn accept_pending_is_weighed_by_the_listed_deposits() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        for id in 0..3 {
+            queue(BOB, id, 7);
+        }
+        NextPendingDepositId::<Runtime>::insert(BOB, ASSET, 3);
+        PendingDepositCount::<Runtime>::insert(BOB, ASSET, 3);
+
+        let call = Call::<Runtime>::accept_pending {
+            asset: ASSET,
+            accept_envelope: accept_input(&[0, 1, 2], &[]),
+        };
+        assert_eq!(
+            call.get_dispatch_info().call_weight,
+            <() as WeightInfo>::claim_many(3)
+        );
+        let post = Pallet::<Runtime>::accept_pending(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            accept_input(&[0, 1, 2], &[]),
+        )
+        .expect("claim of three deposits");
+        assert_eq!(post.actual_weight, Some(<() as WeightInfo>::claim_many(3)));
+    });
+}
+
+#[test]
+fn claim_many_consumes_all_listed_utxos_in_one_call() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        for id in 0..4u8 {
+            queue(BOB, id as u64, 10 + id);
+        }
+        NextPendingDepositId::<Runtime>::insert(BOB, ASSET, 4);
+
+        let ids: BoundedVec<u64, _> = vec![0, 2, 3].try_into().unwrap();
+        let post = Pallet::<Runtime>::claim_many(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            ids.clone(),
+            proof(&[9, 9, 9]),
+        )
+        .expect("claim_many");
+        assert_eq!(post.actual_weight, Some(<() as WeightInfo>::claim_many(3)));
+
+        // The verifier sums exactly the listed deposits' commitments
+        assert_eq!(accepted_commits(), vec![[10u8; 32], [12u8; 32], [13u8; 32]]);
+        assert_eq!(
+            AvailableBalanceCommit::<Runtime>::get(ASSET, BOB).unwrap(),
+            commit(3)
+        );
+        assert!(PendingBalanceCommit::<Runtime>::get(ASSET, BOB).is_none());
+
+        for id in [0, 2, 3] {
+            assert!(PendingDeposits::<Runtime>::get((BOB, ASSET, id)).is_none());
+        }
+        assert!(PendingDeposits::<Runtime>::get((BOB, ASSET, 1)).is_some());
+
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Zkhe(pallet::Event::PendingClaimed {
+                asset: ASSET,
+                who: BOB,
+                deposit_ids: ids,
+            })
+        );
+    });
+}
+
+#[test]
+fn claim_many_rejects_duplicate_missing_and_empty_ids() {
+    new_test_ext().execute_with(|| {
+        set_pk(BOB);
+        queue(BOB, 0, 7);
+        NextPendingDepositId::<Runtime>::insert(BOB, ASSET, 1);
+
+        let claim = |ids: Vec<u64>| {
+            Pallet::<Runtime>::claim_many(
+                RuntimeOrigin::signed(BOB),
+                ASSET,
+                ids.try_into().unwrap(),
+                proof(&[]),
+            )
+            .map_err(|e| e.error)
+        };
+
+        assert_eq!(
+            claim(vec![0, 0]),
+            Err(Error::<Runtime>::DuplicateDeposit.into())
+        );
+        assert_eq!(claim(vec![0, 1]), Err(Error::<Runtime>::NoPending.into()));
+        assert_eq!(claim(vec![]), Err(Error::<Runtime>::NoPending.into()));
+
+        // Nothing was consumed by the failed calls
+        assert!(PendingDeposits::<Runtime>::get((BOB, ASSET, 0)).is_some());
+        assert!(AvailableBalanceCommit::<Runtime>::get(ASSET, BOB).is_none());
+    });
+}
+
+// Ristretto basepoint, for tests where the pallet itself does point arithmetic
+#[test]
+fn reclaim_expired_returns_deposit_to_sender() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+
+        // Sent at block 1 with a TTL of 10
+        let deadline = 1 + <Runtime as Config>::PendingTtl::get();
+        assert_eq!(
+            PendingDepositSource::<Runtime>::get((BOB, ASSET, 0)),
+            Some(DepositSource {
+                sender: ALICE,
+                deadline,
+                deposit: DEPOSIT,
+            })
+        );
+        assert!(ExpiringDeposits::<Runtime>::contains_key(
+            deadline,
+            (BOB, ASSET, 0)
+        ));
+
+        // The deposit is committed to by what it added to BOB's pending balance
+        assert_eq!(
+            PendingDepositCommit::<Runtime>::get((BOB, ASSET, 0)),
+            Some(commit(2))
+        );
+        // Reclaiming subtracts that from BOB's pending, so use real points: G of 2G
+        let g = Commitment::new(BASEPOINT);
+        PendingDepositCommit::<Runtime>::insert((BOB, ASSET, 0), g);
+        PendingBalanceCommit::<Runtime>::insert(ASSET, BOB, g.add(&g).unwrap());
+
+        let reclaim = |who| {
+            Pallet::<Runtime>::reclaim_expired(
+                RuntimeOrigin::signed(who),
+                ASSET,
+                BOB,
+                0,
+                proof(&[]),
+            )
+            .map_err(|e| e.error)
+        };
+        assert_eq!(reclaim(CHARLIE), Err(Error::<Runtime>::NotSender.into()));
+        assert_eq!(reclaim(ALICE), Err(Error::<Runtime>::NotExpired.into()));
+
+        System::set_block_number(deadline);
+        assert_ok!(reclaim(ALICE));
+
+        // ALICE proved the deposit alone into her available balance
+        assert_eq!(accepted_commits(), vec![BASEPOINT]);
+        assert_eq!(
+            AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap(),
+            commit(3)
+        );
+        assert_eq!(PendingBalanceCommit::<Runtime>::get(ASSET, BOB).unwrap(), g);
+        assert!(PendingDeposits::<Runtime>::get((BOB, ASSET, 0)).is_none());
+        assert!(PendingDepositSource::<Runtime>::get((BOB, ASSET, 0)).is_none());
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Zkhe(pallet::Event::PendingReclaimed {
+                asset: ASSET,
+                from: ALICE,
+                to: BOB,
+                deposit_id: 0,
+                encrypted_amount: ct(1),
+            })
+        );
+
+        // Gone once reclaimed
+        assert_eq!(reclaim(ALICE), Err(Error::<Runtime>::NoPending.into()));
+    });
+}
+
+#[test]
+fn on_idle_reports_expired_transfers_once() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        for _ in 0..2 {
+            assert_ok!(Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(1),
+                proof(&[])
+            ));
+        }
+        // BOB claims id 1 in time, so only id 0 expires
+        assert_ok!(Pallet::<Runtime>::accept_pending(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            accept_input(&[1], &[])
+        ));
+        assert!(PendingDepositSource::<Runtime>::get((BOB, ASSET, 1)).is_none());
+
+        let expired = || {
+            System::events()
+                .into_iter()
+                .filter_map(|r| match r.event {
+                    RuntimeEvent::Zkhe(pallet::Event::PendingExpired {
+                        who,
+                        deposit_id,
+                        sender,
+                        ..
+                    }) => Some((who, deposit_id, sender)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        let deadline = 1 + <Runtime as Config>::PendingTtl::get();
+
+        // Not due yet
+        Zkhe::on_idle(deadline - 1, Weight::MAX);
+        assert!(expired().is_empty());
+
+        // No weight left, nothing swept
+        assert_eq!(Zkhe::on_idle(deadline, Weight::zero()), Weight::zero());
+        assert!(expired().is_empty());
+
+        Zkhe::on_idle(deadline, Weight::MAX);
+        assert_eq!(expired(), vec![(BOB, 0, ALICE)]);
+        assert_eq!(ExpiringDeposits::<Runtime>::iter().count(), 0);
+        assert_eq!(NextExpirySweep::<Runtime>::get(), deadline + 1);
+
+        // Still claimable by BOB and reported only once
+        assert!(PendingDeposits::<Runtime>::get((BOB, ASSET, 0)).is_some());
+        Zkhe::on_idle(deadline + 5, Weight::MAX);
+        assert_eq!(expired().len(), 1);
+    });
+}
+
 #[test]
 fn accept_pending_and_transfer_chains_both_paths() {
     new_test_ext().execute_with(|| {
@@ -134,7 +460,7 @@ fn accept_pending_and_transfer_chains_both_paths() {
         set_pk(CHARLIE);
 
         // Give BOB a pending UTXO id 0
-        PendingDeposits::<Runtime>::insert((BOB, ASSET, 0), ct(55));
+        queue(BOB, 0, 55);
         NextPendingDepositId::<Runtime>::insert(BOB, ASSET, 1);
 
         let accept_env = accept_input(&[0], &[]); // ids + empty rest
@@ -151,18 +477,18 @@ fn accept_pending_and_transfer_chains_both_paths() {
         // After accept: BOB avail set to [3;32]; after transfer: from_new_available overwrites to [1;32]
         assert_eq!(
             AvailableBalanceCommit::<Runtime>::get(ASSET, BOB).unwrap(),
-            [1u8; 32]
+            commit(1)
         );
         // CHARLIE pending updated to [2;32] and a UTXO with the transferred “amount”
         assert_eq!(
             PendingBalanceCommit::<Runtime>::get(ASSET, CHARLIE).unwrap(),
-            [2u8; 32]
+            commit(2)
         );
         assert_eq!(
             PendingDeposits::<Runtime>::get((CHARLIE, ASSET, 0)).unwrap(),
             // transfer_encrypted returns the same ciphertext it was passed; here it's the
             // “claimed” marker of 64 zeroes coming from claim_encrypted()
-            [0u8; 64]
+            EncryptedAmount::zero()
         );
         assert_eq!(NextPendingDepositId::<Runtime>::get(CHARLIE, ASSET), 1);
 
@@ -176,13 +502,123 @@ fn accept_pending_and_transfer_chains_both_paths() {
                 assert_eq!(asset, ASSET);
                 assert_eq!(from, BOB);
                 assert_eq!(to, CHARLIE);
-                assert_eq!(encrypted_amount, [0u8; 64]);
+                assert_eq!(encrypted_amount, EncryptedAmount::zero());
             }
             e => panic!("unexpected event: {e:?}"),
         }
     });
 }
 
+#[test]
+fn transfer_holds_sender_deposit_until_the_utxo_is_gone() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        let send = || {
+            Pallet::<Runtime>::transfer(RuntimeOrigin::signed(ALICE), ASSET, BOB, ct(1), proof(&[]))
+        };
+        assert_ok!(send());
+        assert_ok!(send());
+        assert_eq!(held(ALICE), 2 * DEPOSIT);
+        assert_eq!(PendingDepositCount::<Runtime>::get(BOB, ASSET), 2);
+
+        // Claiming returns the sender's deposit for each consumed UTXO
+        let ids: BoundedVec<u64, _> = vec![0].try_into().unwrap();
+        assert_ok!(Pallet::<Runtime>::claim_many(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            ids,
+            proof(&[])
+        ));
+        assert_eq!(held(ALICE), DEPOSIT);
+        assert_eq!(PendingDepositCount::<Runtime>::get(BOB, ASSET), 1);
+
+        // Closing the receiver's account returns the rest
+        assert_ok!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::close_account(
+                ASSET,
+                &BOB,
+                proof(&[0u8; 128]),
+            )
+        );
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), ENDOWMENT);
+        assert!(!PendingDepositCount::<Runtime>::contains_key(BOB, ASSET));
+
+        // A sender who cannot cover the deposit cannot transfer
+        set_pk(CHARLIE);
+        Balances::set_balance(&CHARLIE, DEPOSIT);
+        assert!(
+            Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(CHARLIE),
+                ASSET,
+                BOB,
+                ct(1),
+                proof(&[]),
+            )
+            .is_err()
+        );
+        assert!(PendingDeposits::<Runtime>::get((BOB, ASSET, 2)).is_none());
+    });
+}
+
+#[test]
+fn pending_deposits_lists_one_account_and_asset_by_id() {
+    new_test_ext().execute_with(|| {
+        for id in [5u64, 0, 12, 3] {
+            PendingDeposits::<Runtime>::insert((BOB, ASSET, id), ct(id as u8));
+        }
+        PendingDeposits::<Runtime>::insert((BOB, ASSET + 1, 1), ct(40));
+        PendingDeposits::<Runtime>::insert((ALICE, ASSET, 1), ct(41));
+
+        assert_eq!(
+            Pallet::<Runtime>::pending_deposits(&BOB, ASSET),
+            vec![(0, ct(0)), (3, ct(3)), (5, ct(5)), (12, ct(12))]
+        );
+        assert!(Pallet::<Runtime>::pending_deposits(&CHARLIE, ASSET).is_empty());
+    });
+}
+
+#[test]
+fn pending_queue_is_capped_per_account_and_asset() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        let max = <Runtime as Config>::MaxPendingPerAccount::get();
+        let send = |asset| {
+            Pallet::<Runtime>::transfer(RuntimeOrigin::signed(ALICE), asset, BOB, ct(1), proof(&[]))
+                .map_err(|e| e.error)
+        };
+        for _ in 0..max {
+            assert_ok!(send(ASSET));
+        }
+        assert_eq!(send(ASSET), Err(Error::<Runtime>::TooManyPending.into()));
+        assert_eq!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::mint_encrypted(
+                ASSET,
+                &BOB,
+                proof(&[])
+            ),
+            Err(Error::<Runtime>::TooManyPending.into())
+        );
+        assert_eq!(held(ALICE), max as Balance * DEPOSIT);
+
+        // Other assets have their own queue
+        assert_ok!(send(ASSET + 1));
+
+        // Claiming frees a slot
+        let ids: BoundedVec<u64, _> = vec![3].try_into().unwrap();
+        assert_ok!(Pallet::<Runtime>::claim_many(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            ids,
+            proof(&[])
+        ));
+        assert_ok!(send(ASSET));
+        assert_eq!(PendingDepositCount::<Runtime>::get(BOB, ASSET), max);
+    });
+}
+
 #[test]
 fn mint_encrypted_updates_pending_total_and_records_utxo() {
     new_test_ext().execute_with(|| {
@@ -193,19 +629,19 @@ fn mint_encrypted_updates_pending_total_and_records_utxo() {
             <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::mint_encrypted(ASSET, &BOB, proof)
                 .expect("ok");
 
-        assert_eq!(minted, [5u8; 64]);
+        assert_eq!(minted, ct(5));
         assert_eq!(
             PendingBalanceCommit::<Runtime>::get(ASSET, BOB).unwrap(),
-            [10u8; 32]
+            commit(14)
         );
         assert_eq!(
             TotalSupplyCommit::<Runtime>::get(ASSET).unwrap(),
-            [11u8; 32]
+            commit(11)
         );
 
         assert_eq!(
             PendingDeposits::<Runtime>::get((BOB, ASSET, 0)).unwrap(),
-            [5u8; 64]
+            ct(5)
         );
         assert_eq!(NextPendingDepositId::<Runtime>::get(BOB, ASSET), 1);
     });
@@ -217,8 +653,8 @@ fn burn_encrypted_updates_available_total_and_returns_amount() {
         set_pk(ALICE);
 
         // Seed some pre-state (optional; verifier ignores)
-        AvailableBalanceCommit::<Runtime>::insert(ASSET, ALICE, [9u8; 32]);
-        TotalSupplyCommit::<Runtime>::insert(ASSET, [8u8; 32]);
+        AvailableBalanceCommit::<Runtime>::insert(ASSET, ALICE, commit(9));
+        TotalSupplyCommit::<Runtime>::insert(ASSET, commit(8));
 
         let amt = <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::burn_encrypted(
             ASSET,
@@ -232,46 +668,1194 @@ fn burn_encrypted_updates_available_total_and_returns_amount() {
         assert_eq!(amt, 42u64);
         assert_eq!(
             AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap(),
-            [20u8; 32]
+            commit(20)
         );
         assert_eq!(
             TotalSupplyCommit::<Runtime>::get(ASSET).unwrap(),
-            [21u8; 32]
+            commit(21)
         );
     });
 }
 
 #[test]
-fn errors_no_public_key_and_malformed_envelope() {
+fn burn_confidential_updates_available_and_total() {
     new_test_ext().execute_with(|| {
-        // No PK for ALICE -> transfer should fail with NoPublicKey
-        set_pk(BOB); // only receiver has pk
-        let err = Pallet::<Runtime>::transfer(
-            RuntimeOrigin::signed(ALICE),
+        set_pk(ALICE);
+
+        AvailableBalanceCommit::<Runtime>::insert(ASSET, ALICE, commit(9));
+        TotalSupplyCommit::<Runtime>::insert(ASSET, commit(8));
+
+        assert_ok!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::burn_confidential(
+                ASSET,
+                &ALICE,
+                ct(77),
+                proof(&[4, 4, 4]),
+            )
+        );
+
+        // Mock returns new commits [22;32], [23;32]
+        assert_eq!(
+            AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap(),
+            commit(22)
+        );
+        assert_eq!(
+            TotalSupplyCommit::<Runtime>::get(ASSET).unwrap(),
+            commit(23)
+        );
+    });
+}
+
+#[test]
+fn transfer_split_debits_the_total_and_queues_every_output() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        set_pk(CHARLIE);
+        PendingBalanceCommit::<Runtime>::insert(ASSET, BOB, Commitment::new(BASEPOINT));
+
+        // The mock verifier returns each output's masked half as its commitment
+        let to_bob = EncryptedAmount::from_parts([8u8; 32], BASEPOINT);
+        let to_charlie = EncryptedAmount::from_parts([6u8; 32], [9u8; 32]);
+        let ids = <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::transfer_split(
             ASSET,
-            BOB,
-            ct(1),
-            proof(&[]),
+            &ALICE,
+            ct(7),
+            &[(BOB, to_bob), (CHARLIE, to_charlie)],
+            proof(&[1, 2, 3]),
         )
-        .unwrap_err();
-        assert_eq!(err, Error::<Runtime>::NoPublicKey.into());
+        .expect("split");
+        assert_eq!(ids, vec![0, 0]);
 
-        // accept_pending with malformed envelope: must be at least 2 bytes for count
+        // Mock returns from_new_available = [24;32]; pending balances grow by each
+        // output's commitment, which claims of the deposit consume
+        assert_eq!(
+            AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap(),
+            commit(24)
+        );
+        let doubled = Commitment::new(BASEPOINT)
+            .add(&Commitment::new(BASEPOINT))
+            .unwrap();
+        assert_eq!(
+            PendingBalanceCommit::<Runtime>::get(ASSET, BOB).unwrap(),
+            doubled
+        );
+        assert_eq!(
+            PendingBalanceCommit::<Runtime>::get(ASSET, CHARLIE).unwrap(),
+            commit(9)
+        );
+        assert_eq!(
+            PendingDepositCommit::<Runtime>::get((CHARLIE, ASSET, 0)).unwrap(),
+            commit(9)
+        );
+        assert_ok!(Pallet::<Runtime>::claim_many(
+            RuntimeOrigin::signed(CHARLIE),
+            ASSET,
+            vec![0].try_into().unwrap(),
+            proof(&[9]),
+        ));
+        assert_eq!(accepted_commits(), vec![[9u8; 32]]);
+        assert!(PendingDepositCommit::<Runtime>::get((CHARLIE, ASSET, 0)).is_none());
+        assert_eq!(
+            PendingDeposits::<Runtime>::get((BOB, ASSET, 0)).unwrap(),
+            to_bob
+        );
+        assert_eq!(
+            PendingDeposits::<Runtime>::get((CHARLIE, ASSET, 0)).unwrap(),
+            to_charlie
+        );
+        assert_eq!(
+            PendingDepositSource::<Runtime>::get((CHARLIE, ASSET, 0))
+                .unwrap()
+                .sender,
+            ALICE
+        );
+
+        // A recipient without a key fails the whole split
+        assert!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::transfer_split(
+                ASSET,
+                &ALICE,
+                ct(7),
+                &[(BOB, to_bob), (99, to_charlie)],
+                proof(&[1, 2, 3]),
+            )
+            .is_err()
+        );
+        assert_eq!(NextPendingDepositId::<Runtime>::get(BOB, ASSET), 1);
+    });
+}
+
+#[test]
+fn close_account_deletes_balances_and_pending_deposits() {
+    new_test_ext().execute_with(|| {
         set_pk(ALICE);
-        let bad = proof(&[1]); // too short
-        let err = Pallet::<Runtime>::accept_pending(RuntimeOrigin::signed(ALICE), ASSET, bad)
-            .unwrap_err();
-        assert_eq!(err, Error::<Runtime>::MalformedEnvelope.into());
+
+        AvailableBalanceCommit::<Runtime>::insert(ASSET, ALICE, commit(9));
+        PendingBalanceCommit::<Runtime>::insert(ASSET, ALICE, commit(8));
+        PendingDeposits::<Runtime>::insert((ALICE, ASSET, 0), ct(1));
+        PendingDeposits::<Runtime>::insert((ALICE, ASSET, 1), ct(2));
+        NextPendingDepositId::<Runtime>::insert(ALICE, ASSET, 2);
+        // Another asset is left alone
+        AvailableBalanceCommit::<Runtime>::insert(ASSET + 1, ALICE, commit(7));
+        PendingDeposits::<Runtime>::insert((ALICE, ASSET + 1, 0), ct(3));
+
+        assert_ok!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::close_account(
+                ASSET,
+                &ALICE,
+                proof(&[0u8; 128]),
+            )
+        );
+
+        assert!(AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE).is_none());
+        assert!(PendingBalanceCommit::<Runtime>::get(ASSET, ALICE).is_none());
+        assert!(PendingDeposits::<Runtime>::get((ALICE, ASSET, 0)).is_none());
+        assert!(PendingDeposits::<Runtime>::get((ALICE, ASSET, 1)).is_none());
+        assert_eq!(NextPendingDepositId::<Runtime>::get(ALICE, ASSET), 0);
+
+        assert_eq!(
+            AvailableBalanceCommit::<Runtime>::get(ASSET + 1, ALICE),
+            Some(commit(7))
+        );
+        assert_eq!(
+            PendingDeposits::<Runtime>::get((ALICE, ASSET + 1, 0)),
+            Some(ct(3))
+        );
+        assert!(PublicKey::<Runtime>::get(ALICE).is_some());
     });
 }
 
 #[test]
-fn origin_checks_on_dispatchables() {
+fn account_deposit_is_held_once_per_state_until_close() {
     new_test_ext().execute_with(|| {
-        // Unsigned should be BadOrigin on dispatchables
-        assert!(matches!(
-            Pallet::<Runtime>::transfer(
-                RuntimeOrigin::none(),
+        set_pk(ALICE);
+        set_pk(BOB);
+        set_account_deposit(20);
+        let account_held =
+            |who| Balances::balance_on_hold(&HoldReason::AccountDeposit.into(), &who);
+
+        // The sender pays for the receiver's state, once
+        for _ in 0..2 {
+            assert_ok!(Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(1),
+                proof(&[])
+            ));
+        }
+        assert_eq!(account_held(ALICE), 20);
+        assert_eq!(
+            AccountDeposits::<Runtime>::get(ASSET, BOB),
+            Some((ALICE, 20))
+        );
+
+        // A mint pays for its receiver's own state
+        set_pk(CHARLIE);
+        assert_ok!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::mint_encrypted(
+                ASSET,
+                &CHARLIE,
+                proof(&[])
+            )
+        );
+        assert_eq!(account_held(CHARLIE), 20);
+
+        // Closing returns it to whoever paid
+        assert_ok!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::close_account(
+                ASSET,
+                &BOB,
+                proof(&[0u8; 128]),
+            )
+        );
+        assert_eq!(account_held(ALICE), 0);
+        assert!(!AccountDeposits::<Runtime>::contains_key(ASSET, BOB));
+        assert_eq!(Balances::free_balance(ALICE), ENDOWMENT);
+    });
+}
+
+#[test]
+fn close_account_requires_public_key() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::close_account(
+                ASSET,
+                &ALICE,
+                proof(&[0u8; 128]),
+            ),
+            Err(Error::<Runtime>::NoPublicKey.into())
+        );
+    });
+}
+
+#[test]
+fn rotate_public_key_swaps_key_and_emits_rekeyed_balance() {
+    new_test_ext().execute_with(|| {
+        let new_pk: PublicKeyBytes = [8u8; 32].to_vec().try_into().expect("bounded vec");
+        assert_eq!(
+            Pallet::<Runtime>::rotate_public_key(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                new_pk.clone(),
+                proof(&[1]),
+            ),
+            Err(Error::<Runtime>::NoPublicKey.into())
+        );
+
+        set_pk(ALICE);
+        let old_pk = PublicKey::<Runtime>::get(ALICE);
+        AvailableBalanceCommit::<Runtime>::insert(ASSET, ALICE, commit(4));
+        assert_eq!(
+            Pallet::<Runtime>::rotate_public_key(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                Default::default(),
+                proof(&[1]),
+            ),
+            Err(Error::<Runtime>::BadCipher.into())
+        );
+        assert_eq!(
+            Pallet::<Runtime>::rotate_public_key(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                new_pk.clone(),
+                proof(&[]),
+            ),
+            Err(Error::<Runtime>::InvalidProof(VerifyError::LinkProofInvalid).into())
+        );
+        assert_eq!(PublicKey::<Runtime>::get(ALICE), old_pk);
+
+        assert_ok!(Pallet::<Runtime>::rotate_public_key(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            new_pk.clone(),
+            proof(&[1]),
+        ));
+        assert_eq!(PublicKey::<Runtime>::get(ALICE), Some(new_pk));
+        // The balance commitment does not depend on the key
+        assert_eq!(
+            AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE),
+            Some(commit(4))
+        );
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Zkhe(Event::PublicKeyRotated {
+                asset: ASSET,
+                who: ALICE,
+                encrypted_balance: ct(6),
+            })
+        );
+    });
+}
+
+#[test]
+fn set_range_bits_is_root_only_and_fixed_once_minted() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            Pallet::<Runtime>::set_range_bits(RuntimeOrigin::signed(ALICE), ASSET, 32),
+            Err(BadOrigin.into())
+        );
+        assert_eq!(
+            Pallet::<Runtime>::set_range_bits(RuntimeOrigin::root(), ASSET, 128),
+            Err(Error::<Runtime>::UnsupportedRangeBits.into())
+        );
+
+        assert_eq!(
+            Pallet::<Runtime>::asset_config(ASSET).range_bits,
+            DEFAULT_RANGE_BITS
+        );
+        assert_ok!(Pallet::<Runtime>::set_range_bits(
+            RuntimeOrigin::root(),
+            ASSET,
+            32
+        ));
+        assert_eq!(
+            <Pallet<Runtime> as RangeBitsProvider>::range_bits(&ASSET.encode()),
+            32
+        );
+        assert_eq!(
+            <Pallet<Runtime> as RangeBitsProvider>::range_bits(&(ASSET + 1).encode()),
+            64
+        );
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Zkhe(pallet::Event::RangeBitsSet {
+                asset: ASSET,
+                bits: 32
+            })
+        ));
+
+        // Existing commitments were proven at the old width
+        TotalSupplyCommit::<Runtime>::insert(ASSET, commit(1));
+        assert_eq!(
+            Pallet::<Runtime>::set_range_bits(RuntimeOrigin::root(), ASSET, 64),
+            Err(Error::<Runtime>::AssetInUse.into())
+        );
+    });
+}
+
+#[test]
+fn set_asset_config_is_root_only_and_validated() {
+    new_test_ext().execute_with(|| {
+        let config = AssetConfig {
+            enabled: true,
+            auditor: Some([7u8; 32].to_vec().try_into().expect("bounded vec")),
+            range_bits: 32,
+            max_pending: Some(2),
+        };
+        assert_eq!(
+            Pallet::<Runtime>::set_asset_config(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                config.clone()
+            ),
+            Err(BadOrigin.into())
+        );
+        assert_eq!(
+            Pallet::<Runtime>::set_asset_config(
+                RuntimeOrigin::root(),
+                ASSET,
+                AssetConfig {
+                    range_bits: 16,
+                    ..config.clone()
+                }
+            ),
+            Err(Error::<Runtime>::UnsupportedRangeBits.into())
+        );
+        let max = <Runtime as Config>::MaxPendingPerAccount::get();
+        assert_eq!(
+            Pallet::<Runtime>::set_asset_config(
+                RuntimeOrigin::root(),
+                ASSET,
+                AssetConfig {
+                    max_pending: Some(max + 1),
+                    ..config.clone()
+                }
+            ),
+            Err(Error::<Runtime>::PendingCapTooHigh.into())
+        );
+
+        // Unconfigured assets fall back to the default policy
+        assert_eq!(
+            Pallet::<Runtime>::asset_config(ASSET),
+            AssetConfig::default()
+        );
+        assert_ok!(Pallet::<Runtime>::set_asset_config(
+            RuntimeOrigin::root(),
+            ASSET,
+            config.clone()
+        ));
+        assert_eq!(Pallet::<Runtime>::asset_config(ASSET), config);
+        assert_eq!(
+            <Pallet<Runtime> as RangeBitsProvider>::range_bits(&ASSET.encode()),
+            32
+        );
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Zkhe(Event::AssetConfigSet {
+                asset: ASSET,
+                config: config.clone(),
+            })
+        );
+
+        // Once minted, the policy may change but the width may not
+        TotalSupplyCommit::<Runtime>::insert(ASSET, commit(1));
+        assert_ok!(Pallet::<Runtime>::set_asset_config(
+            RuntimeOrigin::root(),
+            ASSET,
+            AssetConfig {
+                max_pending: None,
+                ..config.clone()
+            }
+        ));
+        assert_eq!(
+            Pallet::<Runtime>::set_asset_config(
+                RuntimeOrigin::root(),
+                ASSET,
+                AssetConfig {
+                    range_bits: 64,
+                    ..config
+                }
+            ),
+            Err(Error::<Runtime>::AssetInUse.into())
+        );
+    });
+}
+
+#[test]
+fn disabled_asset_blocks_deposits_but_not_claims() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+
+        assert_ok!(Pallet::<Runtime>::set_asset_config(
+            RuntimeOrigin::root(),
+            ASSET,
+            AssetConfig {
+                enabled: false,
+                ..Default::default()
+            }
+        ));
+        assert_eq!(
+            Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(1),
+                proof(&[])
+            )
+            .map_err(|e| e.error),
+            Err(Error::<Runtime>::AssetDisabled.into())
+        );
+        assert_eq!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::mint_encrypted(
+                ASSET,
+                &BOB,
+                proof(&[])
+            ),
+            Err(Error::<Runtime>::AssetDisabled.into())
+        );
+
+        // Holders can still take what was already sent
+        let ids: BoundedVec<u64, _> = vec![0].try_into().unwrap();
+        assert_ok!(Pallet::<Runtime>::claim_many(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            ids,
+            proof(&[])
+        ));
+        assert!(!PendingDeposits::<Runtime>::contains_key((BOB, ASSET, 0)));
+
+        // Other assets are unaffected
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET + 1,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+    });
+}
+
+#[test]
+fn paused_asset_blocks_every_movement_until_unpaused() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+        assert_eq!(
+            Pallet::<Runtime>::pause_asset(RuntimeOrigin::signed(ALICE), ASSET),
+            Err(BadOrigin.into())
+        );
+        assert_ok!(Pallet::<Runtime>::pause_asset(RuntimeOrigin::root(), ASSET));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Zkhe(Event::Paused { asset: ASSET })
+        );
+        type Backend = Pallet<Runtime>;
+        assert!(<Backend as ConfidentialBackend<_, _, _>>::is_paused(ASSET));
+
+        let paused: DispatchResult = Err(Error::<Runtime>::AssetPaused.into());
+        assert_eq!(
+            Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(1),
+                proof(&[])
+            )
+            .map(|_| ())
+            .map_err(|e| e.error),
+            paused
+        );
+        assert_eq!(
+            <Backend as ConfidentialBackend<_, _, _>>::mint_encrypted(ASSET, &BOB, proof(&[]))
+                .map(|_| ()),
+            paused
+        );
+        assert_eq!(
+            <Backend as ConfidentialBackend<_, _, _>>::burn_encrypted(
+                ASSET,
+                &ALICE,
+                ct(1),
+                proof(&[])
+            )
+            .map(|_| ()),
+            paused
+        );
+        assert_eq!(
+            <Backend as ConfidentialBackend<_, _, _>>::burn_confidential(
+                ASSET,
+                &ALICE,
+                ct(1),
+                proof(&[])
+            ),
+            paused
+        );
+        let ids: BoundedVec<u64, _> = vec![0].try_into().unwrap();
+        assert_eq!(
+            Pallet::<Runtime>::claim_many(
+                RuntimeOrigin::signed(BOB),
+                ASSET,
+                ids.clone(),
+                proof(&[])
+            )
+            .map(|_| ())
+            .map_err(|e| e.error),
+            paused
+        );
+        assert_eq!(
+            Pallet::<Runtime>::accept_pending(
+                RuntimeOrigin::signed(BOB),
+                ASSET,
+                accept_input(&[0], &[])
+            )
+            .map(|_| ())
+            .map_err(|e| e.error),
+            paused
+        );
+
+        // Other assets keep moving, and unpausing lifts the block
+        assert!(!<Backend as ConfidentialBackend<_, _, _>>::is_paused(
+            ASSET + 1
+        ));
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET + 1,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+        assert_ok!(Pallet::<Runtime>::unpause_asset(
+            RuntimeOrigin::root(),
+            ASSET
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Zkhe(Event::Unpaused { asset: ASSET })
+        );
+        assert_ok!(Pallet::<Runtime>::claim_many(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            ids,
+            proof(&[])
+        ));
+    });
+}
+
+#[test]
+fn asset_pending_cap_tightens_the_global_cap() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        assert_ok!(Pallet::<Runtime>::set_asset_config(
+            RuntimeOrigin::root(),
+            ASSET,
+            AssetConfig {
+                max_pending: Some(2),
+                ..Default::default()
+            }
+        ));
+        let send = || {
+            Pallet::<Runtime>::transfer(RuntimeOrigin::signed(ALICE), ASSET, BOB, ct(1), proof(&[]))
+                .map_err(|e| e.error)
+        };
+        assert_ok!(send());
+        assert_ok!(send());
+        assert_eq!(send(), Err(Error::<Runtime>::TooManyPending.into()));
+        assert_eq!(PendingDepositCount::<Runtime>::get(BOB, ASSET), 2);
+    });
+}
+
+#[test]
+fn frozen_holder_can_receive_but_not_send() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        assert_eq!(
+            Pallet::<Runtime>::freeze_account(RuntimeOrigin::signed(ALICE), ASSET, BOB),
+            Err(BadOrigin.into())
+        );
+        assert_ok!(Pallet::<Runtime>::freeze_account(
+            RuntimeOrigin::root(),
+            ASSET,
+            BOB
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Zkhe(Event::Frozen {
+                asset: ASSET,
+                who: BOB
+            })
+        );
+
+        // Incoming transfers arrive and can be claimed
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[])
+        ));
+        assert_ok!(Pallet::<Runtime>::accept_pending(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            accept_input(&[0], &[])
+        ));
+
+        // Nothing leaves
+        type Backend = Pallet<Runtime>;
+        let frozen: DispatchResult = Err(Error::<Runtime>::Frozen.into());
+        assert_eq!(
+            Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(BOB),
+                ASSET,
+                ALICE,
+                ct(1),
+                proof(&[])
+            )
+            .map(|_| ())
+            .map_err(|e| e.error),
+            frozen
+        );
+        assert_eq!(
+            <Backend as ConfidentialBackend<_, _, _>>::burn_encrypted(
+                ASSET,
+                &BOB,
+                ct(1),
+                proof(&[])
+            )
+            .map(|_| ()),
+            frozen
+        );
+        assert_eq!(
+            <Backend as ConfidentialBackend<_, _, _>>::burn_confidential(
+                ASSET,
+                &BOB,
+                ct(1),
+                proof(&[])
+            ),
+            frozen
+        );
+        assert_eq!(
+            <Backend as ConfidentialBackend<_, _, _>>::close_account(
+                ASSET,
+                &BOB,
+                proof(&[0u8; 128])
+            ),
+            frozen
+        );
+
+        // The freeze is per asset, and thawing lifts it
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(BOB),
+            ASSET + 1,
+            ALICE,
+            ct(1),
+            proof(&[])
+        ));
+        assert_ok!(Pallet::<Runtime>::thaw_account(
+            RuntimeOrigin::root(),
+            ASSET,
+            BOB
+        ));
+        assert!(!FrozenAccounts::<Runtime>::contains_key(ASSET, BOB));
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            ALICE,
+            ct(1),
+            proof(&[])
+        ));
+    });
+}
+
+// Give `asset` an auditor key
+fn audit(asset: AssetId) {
+    let auditor: PublicKeyBytes = [9u8; 32].to_vec().try_into().expect("bounded vec");
+    assert_ok!(Pallet::<Runtime>::set_asset_config(
+        RuntimeOrigin::root(),
+        asset,
+        AssetConfig {
+            auditor: Some(auditor),
+            ..Default::default()
+        }
+    ));
+}
+
+#[test]
+fn senders_disclose_their_latest_audited_transfer() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        type Backend = Pallet<Runtime>;
+        let disclose = |who| {
+            <Backend as ConfidentialBackend<_, _, _>>::disclose_audited(ASSET, &who, proof(&[]))
+        };
+
+        assert!(!<Backend as ConfidentialBackend<_, _, _>>::is_audited(
+            ASSET
+        ));
+        assert_eq!(disclose(ALICE), Err(Error::<Runtime>::NotAudited.into()));
+        audit(ASSET);
+        assert!(<Backend as ConfidentialBackend<_, _, _>>::is_audited(ASSET));
+        assert_eq!(
+            disclose(ALICE),
+            Err(Error::<Runtime>::NothingAudited.into())
+        );
+
+        assert_ok!(Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            audited_proof(&[], 3),
+        ));
+        // The mock discloses every amount as 123
+        assert_eq!(disclose(ALICE), Ok(123));
+        // Only the sender of the latest audited operation can disclose it
+        assert_eq!(disclose(BOB), Err(Error::<Runtime>::NothingAudited.into()));
+    });
+}
+
+#[test]
+fn audited_asset_requires_auditor_handle_and_logs_transitions() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        audit(ASSET);
+        let transfer = |proof| {
+            Pallet::<Runtime>::transfer(RuntimeOrigin::signed(ALICE), ASSET, BOB, ct(1), proof)
+                .map_err(|e| e.error)
+        };
+
+        // A proof without the appended handle, or with one the verifier rejects, fails
+        assert_eq!(
+            transfer(proof(&[])),
+            Err(Error::<Runtime>::InvalidProof(VerifyError::MalformedProof).into())
+        );
+        assert_eq!(
+            transfer(audited_proof(&[], 0)),
+            Err(Error::<Runtime>::InvalidProof(VerifyError::LinkProofInvalid).into())
+        );
+        assert!(!AvailableBalanceCommit::<Runtime>::contains_key(
+            ASSET, ALICE
+        ));
+        assert_eq!(NextAuditId::<Runtime>::get(ASSET), 0);
+
+        let post = Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            audited_proof(&[], 3),
+        )
+        .expect("audited transfer");
+        assert_eq!(
+            post.actual_weight,
+            Some(<() as WeightInfo>::transfer() + <() as WeightInfo>::audit())
+        );
+        assert_eq!(
+            AuditLog::<Runtime>::get(ASSET, 0),
+            Some(AuditRecord {
+                op: AuditOp::Transfer,
+                from: Some(ALICE),
+                to: Some(BOB),
+                block: 1,
+                ciphertext: EncryptedAmount::from_parts([1u8; 32], [3u8; 32]),
+            })
+        );
+
+        // Mints and both burns carry a handle too
+        type Backend = Pallet<Runtime>;
+        assert_ok!(<Backend as ConfidentialBackend<_, _, _>>::mint_encrypted(
+            ASSET,
+            &BOB,
+            audited_proof(&[], 4)
+        ));
+        assert_ok!(<Backend as ConfidentialBackend<_, _, _>>::burn_encrypted(
+            ASSET,
+            &ALICE,
+            ct(2),
+            audited_proof(&[], 5)
+        ));
+        assert_ok!(
+            <Backend as ConfidentialBackend<_, _, _>>::burn_confidential(
+                ASSET,
+                &ALICE,
+                ct(2),
+                audited_proof(&[], 6)
+            )
+        );
+        assert_eq!(
+            <Backend as ConfidentialBackend<_, _, _>>::burn_encrypted(
+                ASSET,
+                &ALICE,
+                ct(2),
+                proof(&[])
+            ),
+            Err(Error::<Runtime>::InvalidProof(VerifyError::MalformedProof).into())
+        );
+        let logged: Vec<_> = Pallet::<Runtime>::audit_log(ASSET, 0, 10)
+            .into_iter()
+            .map(|(id, r)| (id, r.op, r.from, r.to, r.ciphertext))
+            .collect();
+        assert_eq!(
+            logged,
+            vec![
+                (
+                    0,
+                    AuditOp::Transfer,
+                    Some(ALICE),
+                    Some(BOB),
+                    EncryptedAmount::from_parts([1u8; 32], [3u8; 32])
+                ),
+                (
+                    1,
+                    AuditOp::Mint,
+                    None,
+                    Some(BOB),
+                    EncryptedAmount::from_parts([5u8; 32], [4u8; 32])
+                ),
+                (
+                    2,
+                    AuditOp::Burn,
+                    Some(ALICE),
+                    None,
+                    EncryptedAmount::from_parts([2u8; 32], [5u8; 32])
+                ),
+                (
+                    3,
+                    AuditOp::ConfidentialBurn,
+                    Some(ALICE),
+                    None,
+                    EncryptedAmount::from_parts([2u8; 32], [6u8; 32])
+                ),
+            ]
+        );
+
+        // Each handle's blinding ciphertext, (G, G) in the mock, follows the balance it
+        // moved: ALICE sent and burned twice, BOB's deposits carry theirs until claimed
+        let gg = EncryptedAmount::from_parts(BASEPOINT, BASEPOINT);
+        let times = |n: u8| (1..n).fold(gg, |sum, _| sum.add(&gg).unwrap());
+        assert_eq!(
+            AvailableBlinding::<Runtime>::get(ASSET, ALICE),
+            Some((1, EncryptedAmount::zero().sub(&times(3)).unwrap()))
+        );
+        assert_eq!(
+            PendingDepositBlinding::<Runtime>::get((BOB, ASSET, 0)),
+            Some((1, gg))
+        );
+        assert_eq!(
+            PendingDepositBlinding::<Runtime>::get((BOB, ASSET, 1)),
+            Some((1, gg))
+        );
+        assert_ok!(Pallet::<Runtime>::claim_many(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            vec![0, 1].try_into().unwrap(),
+            proof(&[]),
+        ));
+        assert_eq!(
+            AvailableBlinding::<Runtime>::get(ASSET, BOB),
+            Some((1, times(2)))
+        );
+        assert!(PendingDepositBlinding::<Runtime>::iter().next().is_none());
+
+        // Split outputs carry no handles, so audited assets cannot split
+        set_pk(CHARLIE);
+        assert_eq!(
+            <Backend as ConfidentialBackend<_, _, _>>::transfer_split(
+                ASSET,
+                &ALICE,
+                ct(7),
+                &[(BOB, ct(3)), (CHARLIE, ct(4))],
+                proof(&[1, 2, 3]),
+            ),
+            Err(Error::<Runtime>::AuditedSplit.into())
+        );
+
+        // Unaudited assets take plain proofs, log nothing and are not charged for it
+        let post = Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET + 1,
+            BOB,
+            ct(1),
+            proof(&[]),
+        )
+        .expect("unaudited transfer");
+        assert_eq!(post.actual_weight, Some(<() as WeightInfo>::transfer()));
+        assert_eq!(NextAuditId::<Runtime>::get(ASSET + 1), 0);
+    });
+}
+
+#[test]
+fn audit_log_keeps_the_last_max_audit_history_records() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        audit(ASSET);
+        for handle in 1..=6 {
+            assert_ok!(Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(1),
+                audited_proof(&[], handle)
+            ));
+        }
+
+        // MaxAuditHistory is 4 in the mock, so ids 0 and 1 are gone
+        assert_eq!(NextAuditId::<Runtime>::get(ASSET), 6);
+        assert!(!AuditLog::<Runtime>::contains_key(ASSET, 0));
+        assert!(!AuditLog::<Runtime>::contains_key(ASSET, 1));
+        let ids = |start, limit| -> Vec<u64> {
+            Pallet::<Runtime>::audit_log(ASSET, start, limit)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect()
+        };
+        assert_eq!(ids(0, 10), vec![2, 3, 4, 5]);
+        assert_eq!(ids(3, 2), vec![3, 4]);
+        assert_eq!(ids(6, 10), Vec::<u64>::new());
+        assert_eq!(
+            Pallet::<Runtime>::audit_log(ASSET, 5, 1)[0].1.ciphertext,
+            EncryptedAmount::from_parts([1u8; 32], [6u8; 32])
+        );
+    });
+}
+
+#[test]
+fn seize_balance_clears_the_holder_and_logs_the_seizure() {
+    new_test_ext().execute_with(|| {
+        type Backend = Pallet<Runtime>;
+        let seize = |ciphertext| {
+            <Backend as ConfidentialBackend<_, _, _>>::seize_balance(
+                ASSET,
+                &ALICE,
+                ciphertext,
+                proof(&[]),
+            )
+        };
+        // The supply shrinks by the seized commitment, so use real points: balance and
+        // supply both G
+        let g = Commitment::new(BASEPOINT);
+        AvailableBalanceCommit::<Runtime>::insert(ASSET, ALICE, g);
+        TotalSupplyCommit::<Runtime>::insert(ASSET, g);
+        let balance = EncryptedAmount::from_parts(BASEPOINT, [9u8; 32]);
+
+        assert_eq!(seize(balance), Err(Error::<Runtime>::NotAudited.into()));
+        audit(ASSET);
+
+        // The balance predates the auditor key, so no blinding is tracked for it
+        assert_eq!(seize(balance), Err(Error::<Runtime>::NotSeizable.into()));
+        let blinding = EncryptedAmount::from_parts(BASEPOINT, BASEPOINT);
+        let epoch = AuditorEpoch::<Runtime>::get(ASSET);
+        AvailableBlinding::<Runtime>::insert(ASSET, ALICE, (epoch - 1, blinding));
+        assert_eq!(seize(balance), Err(Error::<Runtime>::NotSeizable.into()));
+        AvailableBlinding::<Runtime>::insert(ASSET, ALICE, (epoch, blinding));
+
+        // The verifier ties the disclosure to the stored balance and its blinding
+        assert_eq!(seize(balance), Ok(123));
+        assert_eq!(seized_avail(), g.as_ref().to_vec());
+        assert_eq!(seized_blinding(), blinding);
+        assert!(!AvailableBalanceCommit::<Runtime>::contains_key(
+            ASSET, ALICE
+        ));
+        assert!(!AvailableBlinding::<Runtime>::contains_key(ASSET, ALICE));
+        assert!(
+            TotalSupplyCommit::<Runtime>::get(ASSET)
+                .unwrap()
+                .is_identity()
+        );
+        let record = AuditLog::<Runtime>::get(ASSET, 0).expect("logged");
+        assert_eq!(record.op, AuditOp::Seizure);
+        assert_eq!(record.from, Some(ALICE));
+        assert_eq!(record.to, None);
+        assert_eq!(record.ciphertext, balance);
+    });
+}
+
+#[test]
+fn burn_held_takes_the_commitment_out_of_balance_and_supply() {
+    new_test_ext().execute_with(|| {
+        type Backend = Pallet<Runtime>;
+        let burn =
+            |amount| <Backend as ConfidentialBackend<_, _, _>>::burn_held(ASSET, &ALICE, amount);
+        // Held as a pending deposit: supply G, pending G, nothing available yet
+        let g = Commitment::new(BASEPOINT);
+        PendingBalanceCommit::<Runtime>::insert(ASSET, ALICE, g);
+        TotalSupplyCommit::<Runtime>::insert(ASSET, g);
+
+        assert_eq!(burn(commit(1)), Err(Error::<Runtime>::BadCipher.into()));
+        assert_ok!(burn(g));
+        assert!(
+            TotalSupplyCommit::<Runtime>::get(ASSET)
+                .unwrap()
+                .is_identity()
+        );
+        // Available and pending together hold nothing
+        let avail = AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE).unwrap();
+        assert!(avail.add(&g).unwrap().is_identity());
+        assert_eq!(Backend::check_supply(ASSET), Ok(()));
+    });
+}
+
+#[test]
+fn narrow_range_proofs_are_refunded() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        let saved = <() as WeightInfo>::range_proof(64) - <() as WeightInfo>::range_proof(32);
+
+        let post = Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[]),
+        )
+        .expect("64-bit transfer");
+        assert_eq!(post.actual_weight, Some(<() as WeightInfo>::transfer()));
+
+        let narrow = ASSET + 1;
+        assert_ok!(Pallet::<Runtime>::set_range_bits(
+            RuntimeOrigin::root(),
+            narrow,
+            32
+        ));
+        let post = Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            narrow,
+            BOB,
+            ct(1),
+            proof(&[]),
+        )
+        .expect("32-bit transfer");
+        assert_eq!(
+            post.actual_weight,
+            Some(<() as WeightInfo>::transfer() - saved)
+        );
+
+        // Accepting checks two range proofs
+        let post = Pallet::<Runtime>::accept_pending(
+            RuntimeOrigin::signed(BOB),
+            narrow,
+            accept_input(&[0], &[]),
+        )
+        .expect("32-bit accept");
+        assert_eq!(
+            post.actual_weight,
+            Some(<() as WeightInfo>::claim_many(1) - saved.saturating_mul(2))
+        );
+    });
+}
+
+#[test]
+fn errors_no_public_key_and_malformed_envelope() {
+    new_test_ext().execute_with(|| {
+        // No PK for ALICE -> transfer should fail with NoPublicKey
+        set_pk(BOB); // only receiver has pk
+        let err = Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[]),
+        )
+        .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::NoPublicKey.into());
+
+        // accept_pending with malformed envelope: must be at least 2 bytes for count
+        set_pk(ALICE);
+        let bad = proof(&[1]); // too short
+        let err = Pallet::<Runtime>::accept_pending(RuntimeOrigin::signed(ALICE), ASSET, bad)
+            .unwrap_err();
+        assert_eq!(err, Error::<Runtime>::MalformedEnvelope.into());
+    });
+}
+
+#[test]
+fn verifier_rejection_reason_is_surfaced() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        reject_transfers_with(Some(VerifyError::RangeProofInvalid));
+
+        let err = Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            proof(&[]),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            Error::<Runtime>::InvalidProof(VerifyError::RangeProofInvalid).into()
+        );
+        assert!(PendingBalanceCommit::<Runtime>::get(ASSET, BOB).is_none());
+
+        reject_transfers_with(None);
+    });
+}
+
+#[test]
+fn proof_for_another_proof_system_is_rejected() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+
+        let foreign: InputProof = seal_proof(AlwaysOkVerifier::ID + 1, &[1u8; 64])
+            .try_into()
+            .expect("bounded vec");
+        let err =
+            Pallet::<Runtime>::transfer(RuntimeOrigin::signed(ALICE), ASSET, BOB, ct(1), foreign)
+                .unwrap_err();
+        assert_eq!(
+            err,
+            Error::<Runtime>::InvalidProof(VerifyError::UnknownProofSystem).into()
+        );
+
+        // Without an envelope there is no proof-system id to check
+        let err = Pallet::<Runtime>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            ASSET,
+            BOB,
+            ct(1),
+            InputProof::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            Error::<Runtime>::InvalidProof(VerifyError::MalformedProof).into()
+        );
+        assert!(PendingBalanceCommit::<Runtime>::get(ASSET, BOB).is_none());
+    });
+}
+
+#[test]
+fn origin_checks_on_dispatchables() {
+    new_test_ext().execute_with(|| {
+        // Unsigned should be BadOrigin on dispatchables
+        assert!(matches!(
+            Pallet::<Runtime>::transfer(
+                RuntimeOrigin::none(),
                 ASSET,
                 BOB,
                 ct(9),
@@ -283,6 +1867,228 @@ fn origin_checks_on_dispatchables() {
             Pallet::<Runtime>::accept_pending(RuntimeOrigin::none(), ASSET, accept_input(&[], &[])),
             Err(e) if e == BadOrigin.into()
         ));
+        assert!(matches!(
+            Pallet::<Runtime>::claim_many(RuntimeOrigin::none(), ASSET, Default::default(), proof(&[])),
+            Err(e) if e == BadOrigin.into()
+        ));
+        assert!(matches!(
+            Pallet::<Runtime>::rotate_public_key(RuntimeOrigin::none(), ASSET, Default::default(), proof(&[])),
+            Err(e) if e == BadOrigin.into()
+        ));
+    });
+}
+
+#[test]
+fn migration_to_v1_wraps_raw_values_and_rebuilds_counts() {
+    use frame_support::{
+        storage::unhashed,
+        traits::{OnRuntimeUpgrade, StorageVersion},
+    };
+    use migrations::v1::{MigrateV0ToV1, v0};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Runtime>>();
+        // v0 stored bare arrays
+        unhashed::put(
+            &AvailableBalanceCommit::<Runtime>::hashed_key_for(ASSET, ALICE),
+            &[1u8; 32],
+        );
+        unhashed::put(
+            &TotalSupplyCommit::<Runtime>::hashed_key_for(ASSET),
+            &[2u8; 32],
+        );
+        for id in 0..3u64 {
+            unhashed::put(
+                &PendingDeposits::<Runtime>::hashed_key_for((BOB, ASSET, id)),
+                &[3u8; 64],
+            );
+        }
+        PendingDepositCount::<Runtime>::insert(BOB, ASSET, 7);
+        v0::AssetRangeBits::<Runtime>::insert(ASSET, 32);
+
+        MigrateV0ToV1::<Runtime>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Pallet<Runtime>>(), 1);
+        assert_eq!(
+            AvailableBalanceCommit::<Runtime>::get(ASSET, ALICE),
+            Some(commit(1))
+        );
+        assert_eq!(TotalSupplyCommit::<Runtime>::get(ASSET), Some(commit(2)));
+        assert_eq!(
+            PendingDeposits::<Runtime>::get((BOB, ASSET, 2)),
+            Some(ct(3))
+        );
+        assert_eq!(PendingDepositCount::<Runtime>::get(BOB, ASSET), 3);
+        assert_eq!(Pallet::<Runtime>::asset_config(ASSET).range_bits, 32);
+        assert!(Pallet::<Runtime>::asset_config(ASSET).enabled);
+        assert!(!v0::AssetRangeBits::<Runtime>::contains_key(ASSET));
+
+        // Once at v1 it does not run again
+        PendingDepositCount::<Runtime>::insert(BOB, ASSET, 7);
+        MigrateV0ToV1::<Runtime>::on_runtime_upgrade();
+        assert_eq!(PendingDepositCount::<Runtime>::get(BOB, ASSET), 7);
+    });
+}
+
+#[test]
+fn check_confidential_proof_rejects_bad_envelopes_and_tags_proofs() {
+    use frame_support::dispatch::DispatchInfo;
+    use sp_runtime::{
+        traits::TransactionExtension,
+        transaction_validity::{InvalidTransaction, TransactionSource},
+    };
+
+    new_test_ext().execute_with(|| {
+        let ext = CheckConfidentialProof::<Runtime>::new();
+        let validate = |call: RuntimeCall| {
+            ext.validate_only(
+                Some(ALICE).into(),
+                &call,
+                &DispatchInfo::default(),
+                0,
+                TransactionSource::External,
+                0,
+            )
+            .map(|(valid, _, _)| valid.provides)
+        };
+        let transfer = |to, proof| {
+            RuntimeCall::Zkhe(Call::transfer {
+                asset: ASSET,
+                to,
+                encrypted_amount: ct(1),
+                proof,
+            })
+        };
+
+        // The same proof in two transactions claims the same tag
+        let tags = validate(transfer(BOB, proof(&[1, 2, 3]))).unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(
+            validate(transfer(CHARLIE, proof(&[1, 2, 3]))),
+            Ok(tags.clone())
+        );
+        assert_ne!(validate(transfer(BOB, proof(&[4]))), Ok(tags));
+
+        // Claim-and-transfer carries two proofs
+        let both = RuntimeCall::Zkhe(Call::accept_pending_and_transfer {
+            asset: ASSET,
+            to: BOB,
+            accept_envelope: proof(&[5]),
+            transfer_proof: proof(&[6]),
+        });
+        assert_eq!(validate(both).unwrap().len(), 2);
+
+        // Another proof system's envelope, or none at all, never enters the pool
+        let foreign: InputProof = seal_proof(AlwaysOkVerifier::ID + 1, &[1, 2, 3])
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            validate(transfer(BOB, foreign)),
+            Err(InvalidTransaction::BadProof.into())
+        );
+        assert_eq!(
+            validate(transfer(BOB, Default::default())),
+            Err(InvalidTransaction::BadProof.into())
+        );
+
+        // Calls without proofs pass untagged
+        let freeze = RuntimeCall::Zkhe(Call::freeze_account {
+            asset: ASSET,
+            who: BOB,
+        });
+        assert_eq!(validate(freeze), Ok(vec![]));
+    });
+}
+
+#[test]
+fn backend_calls_roll_back_when_a_later_step_fails() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        audit(ASSET);
+        // The audit record is written before the deposit hold, which ALICE cannot pay.
+        // Called outside a dispatch, nothing else would undo the record.
+        Balances::set_balance(&ALICE, DEPOSIT);
+        assert!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::transfer_encrypted(
+                ASSET,
+                &ALICE,
+                &BOB,
+                ct(1),
+                audited_proof(&[], 3),
+            )
+            .is_err()
+        );
+        assert_eq!(NextAuditId::<Runtime>::get(ASSET), 0);
+        assert!(!AuditLog::<Runtime>::contains_key(ASSET, 0));
+        assert!(!AvailableBalanceCommit::<Runtime>::contains_key(
+            ASSET, ALICE
+        ));
+    });
+}
+
+#[test]
+fn try_state_checks_pending_bookkeeping() {
+    new_test_ext().execute_with(|| {
+        set_pk(ALICE);
+        set_pk(BOB);
+        for _ in 0..3 {
+            assert_ok!(Pallet::<Runtime>::transfer(
+                RuntimeOrigin::signed(ALICE),
+                ASSET,
+                BOB,
+                ct(1),
+                proof(&[])
+            ));
+        }
+        let ids: BoundedVec<u64, _> = vec![1].try_into().unwrap();
+        assert_ok!(Pallet::<Runtime>::claim_many(
+            RuntimeOrigin::signed(BOB),
+            ASSET,
+            ids,
+            proof(&[])
+        ));
+        assert_ok!(Pallet::<Runtime>::do_try_state());
+
+        // A count that drifted from the deposits is caught
+        PendingDepositCount::<Runtime>::insert(BOB, ASSET, 3);
+        assert!(Pallet::<Runtime>::do_try_state().is_err());
+        PendingDepositCount::<Runtime>::insert(BOB, ASSET, 2);
+
+        // So is a deposit source left behind by its deposit
+        PendingDeposits::<Runtime>::remove((BOB, ASSET, 0u64));
+        PendingDepositCount::<Runtime>::insert(BOB, ASSET, 1);
+        assert!(Pallet::<Runtime>::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn supply_stays_the_sum_of_balances_through_close() {
+    new_test_ext().execute_with(|| {
+        // Real points, so the homomorphic sums mean something
+        let avail = Commitment::new(zkhe_vectors::MINT_TO_NEW_COMM_32);
+        let pending = Commitment::new(zkhe_vectors::TRANSFER_DELTA_COMM_32);
+        let both = avail.add(&pending).expect("vector points decompress");
+        AvailableBalanceCommit::<Runtime>::insert(ASSET, ALICE, avail);
+        PendingBalanceCommit::<Runtime>::insert(ASSET, BOB, pending);
+        TotalSupplyCommit::<Runtime>::insert(ASSET, both);
+        assert_ok!(Pallet::<Runtime>::check_supply(ASSET));
+
+        // Closing BOB moves its commitment into the residue
+        set_pk(BOB);
+        assert_ok!(
+            <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::close_account(
+                ASSET,
+                &BOB,
+                proof(&[0u8; 128]),
+            )
+        );
+        assert_eq!(ClosedBalanceCommit::<Runtime>::get(ASSET), Some(pending));
+        assert_ok!(Pallet::<Runtime>::check_supply(ASSET));
+
+        // Balance that appeared without a mint is caught
+        AvailableBalanceCommit::<Runtime>::insert(ASSET, ALICE, both);
+        assert!(Pallet::<Runtime>::check_supply(ASSET).is_err());
     });
 }
 
@@ -305,7 +2111,7 @@ prop_compose! {
 prop_compose! {
     /// Generate arbitrary ciphertext fill byte
     fn arb_ciphertext()(fill in any::<u8>()) -> EncryptedAmount {
-        [fill; 64]
+        ct(fill)
     }
 }
 
@@ -335,9 +2141,10 @@ proptest! {
         let receiver = if receiver == sender { receiver + 1 } else { receiver };
 
         new_test_ext().execute_with(|| {
-            // Setup: both parties need PKs
+            // Setup: both parties need PKs, the sender funds for the storage deposit
             set_pk(sender);
             set_pk(receiver);
+            fund(sender);
 
             let delta = ct(ct_val);
             let prf = proof(&[1, 2, 3]);
@@ -357,12 +2164,12 @@ proptest! {
             // Verify storage updates (mock returns [1;32] for from, [2;32] for to)
             prop_assert_eq!(
                 AvailableBalanceCommit::<Runtime>::get(asset, sender),
-                Some([1u8; 32]),
+                Some(commit(1)),
                 "Sender available commit should be updated"
             );
             prop_assert_eq!(
                 PendingBalanceCommit::<Runtime>::get(asset, receiver),
-                Some([2u8; 32]),
+                Some(commit(2)),
                 "Receiver pending commit should be updated"
             );
 
@@ -418,6 +2225,7 @@ proptest! {
         new_test_ext().execute_with(|| {
             set_pk(sender);
             set_pk(receiver);
+            fund(sender);
 
             for i in 0..num_transfers {
                 let ct_val = i as u8;
@@ -494,17 +2302,17 @@ proptest! {
             prop_assert!(result.is_ok(), "Mint should succeed: {:?}", result);
 
             // Mock returns [5;64] as minted ciphertext
-            prop_assert_eq!(result.unwrap(), [5u8; 64]);
+            prop_assert_eq!(result.unwrap(), ct(5));
 
             // Check storage updates
             prop_assert_eq!(
                 PendingBalanceCommit::<Runtime>::get(asset, recipient),
-                Some([10u8; 32]),
+                Some(commit(14)),
                 "Pending commit should be set"
             );
             prop_assert_eq!(
                 TotalSupplyCommit::<Runtime>::get(asset),
-                Some([11u8; 32]),
+                Some(commit(11)),
                 "Total supply commit should be set"
             );
 
@@ -530,8 +2338,8 @@ proptest! {
             set_pk(burner);
 
             // Seed initial state
-            AvailableBalanceCommit::<Runtime>::insert(asset, burner, [initial_avail; 32]);
-            TotalSupplyCommit::<Runtime>::insert(asset, [initial_total; 32]);
+            AvailableBalanceCommit::<Runtime>::insert(asset, burner, commit(initial_avail));
+            TotalSupplyCommit::<Runtime>::insert(asset, commit(initial_total));
 
             let result = <Pallet<Runtime> as ConfidentialBackend<_, _, _>>::burn_encrypted(
                 asset,
@@ -548,12 +2356,12 @@ proptest! {
             // Check storage updates (mock returns [20;32], [21;32])
             prop_assert_eq!(
                 AvailableBalanceCommit::<Runtime>::get(asset, burner),
-                Some([20u8; 32]),
+                Some(commit(20)),
                 "Available commit should be updated after burn"
             );
             prop_assert_eq!(
                 TotalSupplyCommit::<Runtime>::get(asset),
-                Some([21u8; 32]),
+                Some(commit(21)),
                 "Total supply commit should be updated after burn"
             );
 
@@ -1,7 +1,12 @@
 
-//! Autogenerated weights for `pallet_zkhe`
+//! Weights for `pallet_zkhe`: partly benchmark output, partly hand estimates.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
+//! `transfer` and `accept_pending` are benchmark output from the run below, plus
+//! hand-estimated storage added since the run, marked inline. Every function after the
+//! "Everything below is a hand estimate" marker is estimated by hand, not measured.
+//! Rerun the command to replace the estimates with measured weights.
+//!
+//! Benchmark run: SUBSTRATE BENCHMARK CLI VERSION 47.0.0
 //! DATE: 2025-11-28, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `Amars-MacBook-Pro.local`, CPU: `<UNKNOWN>`
@@ -55,6 +60,11 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 6168))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
+			// Hand estimate for storage added since the run: `FrozenAccounts` (r:1),
+			// `ConfidentialAssetConfig` (r:1), `PendingDepositCount` (r:1 w:1), the
+			// deposit hold (r:2 w:2), `PendingDepositSource` (w:1), `ExpiringDeposits` (w:1)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Zkhe::PublicKey` (r:1 w:0)
 	/// Proof: `Zkhe::PublicKey` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
@@ -73,13 +83,104 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3633))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
+			// Hand estimate for storage added since the run: `PendingDepositSource`
+			// (r:1 w:1), `PendingDepositCount` (r:1 w:1), the deposit release (r:2 w:2)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	// Everything below is a hand estimate, not benchmark output. Regenerate this file
+	// with frame-omni-bencher to replace them.
+
+	/// Estimated from accept_pending: the same fixed cost plus one deposit read, one
+	/// commitment decompression, one delete and one hold release per claimed deposit.
+	/// Storage: `PublicKey`, `AvailableBalanceCommit`, `PendingBalanceCommit`,
+	/// `PendingDepositCount`, and per deposit `PendingDeposits`, `PendingDepositSource`,
+	/// `Balances::Holds` and `System::Account`. `n` is in `[1, 64]`.
+	fn claim_many(n: u32, ) -> Weight {
+		Weight::from_parts(12_083_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			.saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2647).saturating_mul(n.into()))
 	}
 	/// Estimated as accept_pending + transfer (chained operations)
 	fn transfer_from_available() -> Weight {
 		// accept_pending weight + transfer weight
 		Weight::from_parts(12_083_000_000 + 6_535_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 6168 + 3633))
-			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+	/// Estimated: `TotalSupplyCommit` (r:1), `ConfidentialAssetConfig` (r:1 w:1)
+	fn set_range_bits() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3571))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated as set_range_bits
+	fn set_asset_config() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3571))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated as accept_pending (same verification) plus the source lookup
+	fn reclaim_expired() -> Weight {
+		Weight::from_parts(12_083_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3633))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
+	/// Estimated: two storage accesses and an event, no proof verification
+	fn expire_pending() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3637))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated: two Schnorr checks and a link proof, no range proof
+	fn rotate_public_key() -> Weight {
+		Weight::from_parts(700_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3579))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated: one Chaum-Pedersen check and the blinding Σ-proof (five multiscalar
+	/// checks in all), no range proof; the log plus the sender's and deposit's blinding
+	fn audit() -> Weight {
+		Weight::from_parts(550_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3648))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Estimated: a single storage write
+	fn pause_asset() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated as pause_asset
+	fn unpause_asset() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated: a single storage write
+	fn freeze_account() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated as freeze_account
+	fn thaw_account() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated: Bulletproof verification is linear in the bit width, and one
+	/// 64-bit proof is most of `transfer`
+	fn range_proof(b: u32, ) -> Weight {
+		Weight::from_parts(95_000_000, 0).saturating_mul(b.into())
+	}
 }
@@ -15,7 +15,9 @@ extern crate alloc;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
-use confidential_assets_primitives::{EncryptedAmount, InputProof, PublicKeyBytes};
+use confidential_assets_primitives::{
+    EncryptedAmount, InputProof, ProofKind, ProofSystem, PublicKeyBytes, VerifyError,
+};
 use fp_evm::PrecompileHandle;
 use frame_support::{
     BoundedVec,
@@ -26,7 +28,7 @@ use pallet_evm::AddressMapping;
 use precompile_utils::prelude::*;
 use precompile_utils::{
     evm::logs::{LogExt, log2, log3, log4},
-    keccak256,
+    keccak256, solidity,
 };
 use sp_core::{H160, H256, U256};
 use sp_runtime::traits::Dispatchable;
@@ -48,19 +50,83 @@ type GetEncryptedAmountSize = ConstU32<ENCRYPTED_AMOUNT_SIZE>;
 /// event PublicKeySet(address indexed account, bytes pubkey)
 pub const SELECTOR_LOG_PUBLIC_KEY_SET: [u8; 32] = keccak256!("PublicKeySet(address,bytes)");
 
-/// event Deposit(uint128 indexed asset, address indexed account, uint256 amount)
-pub const SELECTOR_LOG_DEPOSIT: [u8; 32] = keccak256!("Deposit(uint128,address,uint256)");
-
-/// event Withdraw(uint128 indexed asset, address indexed account)
-pub const SELECTOR_LOG_WITHDRAW: [u8; 32] = keccak256!("Withdraw(uint128,address)");
+/// Logs mirror the pallet events: the balance commitments an op changed, the pending
+/// transfer it queued and the blake2-256 hash of its proof. A `pendingId` of
+/// `type(uint64).max` means the backend queued none.
+///
+/// event Deposit(uint128 indexed asset, address indexed account, uint256 amount,
+///     uint64 pendingId, bytes32 oldPending, bytes32 newPending, bytes32 proofHash)
+pub const SELECTOR_LOG_DEPOSIT: [u8; 32] =
+    keccak256!("Deposit(uint128,address,uint256,uint64,bytes32,bytes32,bytes32)");
+
+/// event Withdraw(uint128 indexed asset, address indexed account,
+///     bytes32 oldAvailable, bytes32 newAvailable, bytes32 proofHash)
+pub const SELECTOR_LOG_WITHDRAW: [u8; 32] =
+    keccak256!("Withdraw(uint128,address,bytes32,bytes32,bytes32)");
+
+/// event ConfidentialTransfer(uint128 indexed asset, address indexed from, address indexed to,
+///     uint64 pendingId, bytes32 fromOldAvailable, bytes32 fromNewAvailable,
+///     bytes32 toOldPending, bytes32 toNewPending, bytes32 proofHash)
+pub const SELECTOR_LOG_CONFIDENTIAL_TRANSFER: [u8; 32] = keccak256!(
+    "ConfidentialTransfer(uint128,address,address,uint64,bytes32,bytes32,bytes32,bytes32,bytes32)"
+);
+
+/// event ConfidentialClaim(uint128 indexed asset, address indexed account,
+///     bytes32 oldAvailable, bytes32 newAvailable, bytes32 oldPending, bytes32 newPending,
+///     bytes32 proofHash)
+pub const SELECTOR_LOG_CONFIDENTIAL_CLAIM: [u8; 32] =
+    keccak256!("ConfidentialClaim(uint128,address,bytes32,bytes32,bytes32,bytes32,bytes32)");
+
+/// Reject a proof whose envelope or layout does not match `kind` before dispatching it.
+/// Proofs of audited assets carry a trailing auditor handle; the pallet decides which
+/// form the asset needs.
+fn ensure_proof_shape<Runtime: pallet_zkhe::Config>(kind: ProofKind, proof: &[u8]) -> EvmResult {
+    let opened = <Runtime as pallet_zkhe::Config>::Verifier::open(kind, proof)
+        .map(|_| ())
+        .or_else(|e| match kind {
+            ProofKind::Transfer | ProofKind::Mint | ProofKind::Burn => {
+                <Runtime as pallet_zkhe::Config>::Verifier::open_audited(kind, proof)
+                    .map(|_| ())
+                    .map_err(|_| e)
+            }
+            _ => Err(e),
+        });
+    match opened {
+        Ok(()) => Ok(()),
+        Err(VerifyError::UnknownProofSystem) => Err(revert("unknown proof system")),
+        Err(_) => Err(revert("malformed proof")),
+    }
+}
 
-/// event ConfidentialTransfer(uint128 indexed asset, address indexed from, address indexed to)
-pub const SELECTOR_LOG_CONFIDENTIAL_TRANSFER: [u8; 32] =
-    keccak256!("ConfidentialTransfer(uint128,address,address)");
+/// `who`'s available and pending balance commitments of `asset`, as log words.
+fn commitments<Runtime: pallet_confidential_assets::Config>(
+    handle: &mut impl PrecompileHandle,
+    asset: <Runtime as pallet_confidential_assets::Config>::AssetId,
+    who: &AccountIdOf<Runtime>,
+) -> EvmResult<(H256, H256)> {
+    handle.record_db_read::<Runtime>(128)?;
+    let available =
+        pallet_confidential_assets::Pallet::<Runtime>::confidential_balance_of(asset, who);
+    let pending =
+        pallet_confidential_assets::Pallet::<Runtime>::confidential_pending_balance_of(asset, who);
+    Ok((
+        H256::from(available.to_bytes()),
+        H256::from(pending.to_bytes()),
+    ))
+}
 
-/// event ConfidentialClaim(uint128 indexed asset, address indexed account)
-pub const SELECTOR_LOG_CONFIDENTIAL_CLAIM: [u8; 32] =
-    keccak256!("ConfidentialClaim(uint128,address)");
+/// Id of the pending transfer last queued for `who`, or `u64::MAX` if none.
+fn last_pending_id<Runtime: pallet_confidential_assets::Config>(
+    handle: &mut impl PrecompileHandle,
+    asset: <Runtime as pallet_confidential_assets::Config>::AssetId,
+    who: &AccountIdOf<Runtime>,
+) -> EvmResult<u64> {
+    handle.record_db_read::<Runtime>(8)?;
+    Ok(
+        pallet_confidential_assets::Pallet::<Runtime>::last_pending_id(asset, who)
+            .unwrap_or(u64::MAX),
+    )
+}
 
 /// Precompile exposing confidential assets functionality to EVM.
 pub struct ConfidentialAssetsPrecompile<Runtime>(PhantomData<Runtime>);
@@ -97,7 +163,7 @@ where
         let commitment =
             pallet_confidential_assets::Pallet::<Runtime>::confidential_balance_of(asset_id, &who);
 
-        Ok(H256::from_slice(&commitment))
+        Ok(H256::from(commitment.to_bytes()))
     }
 
     /// Returns the confidential total supply commitment for an asset.
@@ -116,7 +182,7 @@ where
         let commitment =
             pallet_confidential_assets::Pallet::<Runtime>::confidential_total_supply(asset_id);
 
-        Ok(H256::from_slice(&commitment))
+        Ok(H256::from(commitment.to_bytes()))
     }
 
     /// Returns the asset name.
@@ -160,11 +226,13 @@ where
     // ============ State-Changing Functions ============
 
     /// Sets the caller's public key for receiving confidential transfers.
-    /// Solidity: function setPublicKey(bytes pubkey) external
-    #[precompile::public("setPublicKey(bytes)")]
+    /// `proof` shows knowledge of the secret key, bound to the caller's mapped account.
+    /// Solidity: function setPublicKey(bytes pubkey, bytes proof) external
+    #[precompile::public("setPublicKey(bytes,bytes)")]
     fn set_public_key(
         handle: &mut impl PrecompileHandle,
         pubkey: BoundedBytes<GetMaxPubKeySize>,
+        proof: BoundedBytes<GetMaxProofSize>,
     ) -> EvmResult {
         let caller = handle.context().caller;
         let origin = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(caller);
@@ -172,6 +240,10 @@ where
         let pubkey_bytes = pubkey_vec.clone();
         let pubkey_bounded: PublicKeyBytes =
             BoundedVec::try_from(pubkey_vec).map_err(|_| revert("pubkey too large"))?;
+        let proof_vec: Vec<u8> = proof.into();
+        ensure_proof_shape::<Runtime>(ProofKind::PubkeyOwnership, &proof_vec)?;
+        let proof_bounded: InputProof =
+            BoundedVec::try_from(proof_vec).map_err(|_| revert("proof too large"))?;
 
         // Dispatch the call
         RuntimeHelper::<Runtime>::try_dispatch(
@@ -179,6 +251,7 @@ where
             Some(origin).into(),
             pallet_confidential_assets::Call::<Runtime>::set_public_key {
                 elgamal_pk: pubkey_bounded,
+                proof: proof_bounded,
             },
             0,
         )?;
@@ -214,12 +287,15 @@ where
             amount.try_into().map_err(|_| revert("amount overflow"))?;
 
         let proof_vec: Vec<u8> = proof.into();
+        ensure_proof_shape::<Runtime>(ProofKind::Mint, &proof_vec)?;
+        let proof_hash = H256::from(sp_core::hashing::blake2_256(&proof_vec));
         let proof_bounded: InputProof =
             BoundedVec::try_from(proof_vec).map_err(|_| revert("proof too large"))?;
+        let (_, old_pending) = commitments::<Runtime>(handle, asset_id, &origin)?;
 
         RuntimeHelper::<Runtime>::try_dispatch(
             handle,
-            Some(origin).into(),
+            Some(origin.clone()).into(),
             pallet_confidential_assets::Call::<Runtime>::deposit {
                 asset: asset_id,
                 amount: balance,
@@ -229,18 +305,20 @@ where
         )?;
 
         // Emit Deposit event
-        // event Deposit(uint128 indexed asset, address indexed account, uint256 amount)
+        // event Deposit(uint128 indexed asset, address indexed account, uint256 amount,
+        //     uint64 pendingId, bytes32 oldPending, bytes32 newPending, bytes32 proofHash)
         // Use validated asset_id to ensure event matches state in case AssetId conversion is non-identity
+        let pending_id = last_pending_id::<Runtime>(handle, asset_id, &origin)?;
+        let (_, new_pending) = commitments::<Runtime>(handle, asset_id, &origin)?;
         let asset_u128: u128 = asset_id.into();
         let mut asset_h256 = H256::zero();
         asset_h256.0[16..32].copy_from_slice(&asset_u128.to_be_bytes());
-        let amount_bytes: [u8; 32] = amount.to_big_endian();
         log3(
             handle.context().address,
             SELECTOR_LOG_DEPOSIT,
             asset_h256,
             H256::from(caller),
-            amount_bytes.to_vec(),
+            solidity::encode_event_data((amount, pending_id, old_pending, new_pending, proof_hash)),
         )
         .record(handle)?;
 
@@ -267,12 +345,15 @@ where
             .map_err(|_| revert("encrypted amount must be 64 bytes"))?;
 
         let proof_vec: Vec<u8> = proof.into();
+        ensure_proof_shape::<Runtime>(ProofKind::Burn, &proof_vec)?;
+        let proof_hash = H256::from(sp_core::hashing::blake2_256(&proof_vec));
         let proof_bounded: InputProof =
             BoundedVec::try_from(proof_vec).map_err(|_| revert("proof too large"))?;
+        let (old_available, _) = commitments::<Runtime>(handle, asset_id, &origin)?;
 
         RuntimeHelper::<Runtime>::try_dispatch(
             handle,
-            Some(origin).into(),
+            Some(origin.clone()).into(),
             pallet_confidential_assets::Call::<Runtime>::withdraw {
                 asset: asset_id,
                 encrypted_amount: encrypted_arr,
@@ -282,8 +363,10 @@ where
         )?;
 
         // Emit Withdraw event
-        // event Withdraw(uint128 indexed asset, address indexed account)
+        // event Withdraw(uint128 indexed asset, address indexed account,
+        //     bytes32 oldAvailable, bytes32 newAvailable, bytes32 proofHash)
         // Use validated asset_id to ensure event matches state in case AssetId conversion is non-identity
+        let (new_available, _) = commitments::<Runtime>(handle, asset_id, &origin)?;
         let asset_u128: u128 = asset_id.into();
         let mut asset_h256 = H256::zero();
         asset_h256.0[16..32].copy_from_slice(&asset_u128.to_be_bytes());
@@ -292,7 +375,7 @@ where
             SELECTOR_LOG_WITHDRAW,
             asset_h256,
             H256::from(caller),
-            Vec::new(),
+            solidity::encode_event_data((old_available, new_available, proof_hash)),
         )
         .record(handle)?;
 
@@ -323,15 +406,19 @@ where
             .map_err(|_| revert("encrypted amount must be 64 bytes"))?;
 
         let proof_vec: Vec<u8> = proof.into();
+        ensure_proof_shape::<Runtime>(ProofKind::Transfer, &proof_vec)?;
+        let proof_hash = H256::from(sp_core::hashing::blake2_256(&proof_vec));
         let proof_bounded: InputProof =
             BoundedVec::try_from(proof_vec).map_err(|_| revert("proof too large"))?;
+        let (from_old_available, _) = commitments::<Runtime>(handle, asset_id, &origin)?;
+        let (_, to_old_pending) = commitments::<Runtime>(handle, asset_id, &to_account)?;
 
         RuntimeHelper::<Runtime>::try_dispatch(
             handle,
-            Some(origin).into(),
+            Some(origin.clone()).into(),
             pallet_confidential_assets::Call::<Runtime>::confidential_transfer {
                 asset: asset_id,
-                to: to_account,
+                to: to_account.clone(),
                 encrypted_amount: encrypted_arr,
                 input_proof: proof_bounded,
             },
@@ -339,8 +426,13 @@ where
         )?;
 
         // Emit ConfidentialTransfer event
-        // event ConfidentialTransfer(uint128 indexed asset, address indexed from, address indexed to)
+        // event ConfidentialTransfer(uint128 indexed asset, address indexed from, address indexed to,
+        //     uint64 pendingId, bytes32 fromOldAvailable, bytes32 fromNewAvailable,
+        //     bytes32 toOldPending, bytes32 toNewPending, bytes32 proofHash)
         // Use validated asset_id to ensure event matches state in case AssetId conversion is non-identity
+        let pending_id = last_pending_id::<Runtime>(handle, asset_id, &to_account)?;
+        let (from_new_available, _) = commitments::<Runtime>(handle, asset_id, &origin)?;
+        let (_, to_new_pending) = commitments::<Runtime>(handle, asset_id, &to_account)?;
         let asset_u128: u128 = asset_id.into();
         let mut asset_h256 = H256::zero();
         asset_h256.0[16..32].copy_from_slice(&asset_u128.to_be_bytes());
@@ -350,7 +442,14 @@ where
             asset_h256,
             H256::from(caller),
             H256::from(to_h160),
-            Vec::new(),
+            solidity::encode_event_data((
+                pending_id,
+                from_old_available,
+                from_new_available,
+                to_old_pending,
+                to_new_pending,
+                proof_hash,
+            )),
         )
         .record(handle)?;
 
@@ -371,12 +470,15 @@ where
         let asset_id = asset.try_into().map_err(|_| revert("invalid asset id"))?;
 
         let proof_vec: Vec<u8> = proof.into();
+        ensure_proof_shape::<Runtime>(ProofKind::Claim, &proof_vec)?;
+        let proof_hash = H256::from(sp_core::hashing::blake2_256(&proof_vec));
         let proof_bounded: InputProof =
             BoundedVec::try_from(proof_vec).map_err(|_| revert("proof too large"))?;
+        let (old_available, old_pending) = commitments::<Runtime>(handle, asset_id, &origin)?;
 
         RuntimeHelper::<Runtime>::try_dispatch(
             handle,
-            Some(origin).into(),
+            Some(origin.clone()).into(),
             pallet_confidential_assets::Call::<Runtime>::confidential_claim {
                 asset: asset_id,
                 input_proof: proof_bounded,
@@ -385,8 +487,11 @@ where
         )?;
 
         // Emit ConfidentialClaim event
-        // event ConfidentialClaim(uint128 indexed asset, address indexed account)
+        // event ConfidentialClaim(uint128 indexed asset, address indexed account,
+        //     bytes32 oldAvailable, bytes32 newAvailable, bytes32 oldPending, bytes32 newPending,
+        //     bytes32 proofHash)
         // Use validated asset_id to ensure event matches state in case AssetId conversion is non-identity
+        let (new_available, new_pending) = commitments::<Runtime>(handle, asset_id, &origin)?;
         let asset_u128: u128 = asset_id.into();
         let mut asset_h256 = H256::zero();
         asset_h256.0[16..32].copy_from_slice(&asset_u128.to_be_bytes());
@@ -395,7 +500,13 @@ where
             SELECTOR_LOG_CONFIDENTIAL_CLAIM,
             asset_h256,
             H256::from(caller),
-            Vec::new(),
+            solidity::encode_event_data((
+                old_available,
+                new_available,
+                old_pending,
+                new_pending,
+                proof_hash,
+            )),
         )
         .record(handle)?;
 
@@ -3,10 +3,13 @@
 use super::*;
 
 use confidential_assets_primitives::{
-    ConfidentialBackend, EncryptedAmount, NetworkIdProvider, PublicKeyBytes, Ramp, ZkVerifier,
+    ConfidentialBackend, EncryptedAmount, NetworkIdProvider, ProofKind, ProofSystem, ProofSystemId,
+    PublicKeyBytes, Ramp, VerifyError, ZkVerifier, seal_proof,
 };
 use frame_support::{
-    construct_runtime, derive_impl, parameter_types, traits::Everything, weights::Weight,
+    construct_runtime, derive_impl, parameter_types,
+    traits::{ConstBool, ConstU32, ConstU128, Everything},
+    weights::Weight,
 };
 use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
 use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
@@ -37,10 +40,23 @@ impl NetworkIdProvider for MockNetworkId {
 pub struct AlwaysOkVerifier;
 
 impl ZkVerifier for AlwaysOkVerifier {
-    type Error = ();
     type NetworkIdProvider = MockNetworkId;
 
-    fn disclose(_asset: &[u8], _pk: &[u8], _cipher: &[u8]) -> Result<u64, ()> {
+    // Any key is accepted as owned
+    fn verify_pubkey_ownership(
+        _account: &[u8],
+        _pk: &[u8],
+        _proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        Ok(())
+    }
+
+    fn disclose(
+        _asset: &[u8],
+        _pk: &[u8],
+        _cipher: &[u8],
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
         Ok(123)
     }
 
@@ -52,7 +68,7 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _delta_ct: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         Ok((vec![1u8; 32], vec![2u8; 32]))
     }
 
@@ -63,7 +79,7 @@ impl ZkVerifier for AlwaysOkVerifier {
         _pending_old: &[u8],
         _commits: &[[u8; 32]],
         _envelope: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         Ok((vec![3u8; 32], vec![0u8; 32]))
     }
 
@@ -73,8 +89,12 @@ impl ZkVerifier for AlwaysOkVerifier {
         _to_old_pending: &[u8],
         _total_old: &[u8],
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), ()> {
-        Ok((vec![10u8; 32], vec![11u8; 32], [5u8; 64]))
+    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), VerifyError> {
+        Ok((
+            vec![10u8; 32],
+            vec![11u8; 32],
+            EncryptedAmount::new([5u8; 64]),
+        ))
     }
 
     fn verify_burn(
@@ -84,11 +104,20 @@ impl ZkVerifier for AlwaysOkVerifier {
         _total_old: &[u8],
         _amount_ct: &EncryptedAmount,
         _proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, u64), ()> {
+    ) -> Result<(Vec<u8>, Vec<u8>, u64), VerifyError> {
         Ok((vec![20u8; 32], vec![21u8; 32], 42))
     }
 }
 
+// Stands in for the ZK-ElGamal proof system and accepts any body length
+impl ProofSystem for AlwaysOkVerifier {
+    const ID: ProofSystemId = 0;
+
+    fn proof_len(_kind: ProofKind) -> Option<(usize, usize)> {
+        Some((0, usize::MAX))
+    }
+}
+
 // --- Mock ramp that always succeeds ---
 pub struct NoRamp;
 impl Ramp<AccountId, AssetId, Balance> for NoRamp {
@@ -162,7 +191,7 @@ impl pallet_balances::Config for Runtime {
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
     type RuntimeFreezeReason = ();
@@ -242,6 +271,16 @@ impl pallet_zkhe::Config for Runtime {
     type AssetId = AssetId;
     type Balance = Balance;
     type Verifier = AlwaysOkVerifier;
+    type MaxClaimUtxos = ConstU32<16>;
+    type PendingTtl = ConstU32<100>;
+    type MaxPendingPerAccount = ConstU32<16>;
+    type PendingDeposit = ConstU128<0>;
+    type AccountDeposit = ConstU128<0>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RequireAssetConfig = ConstBool<false>;
+    type MaxAuditHistory = ConstU32<4>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
@@ -252,8 +291,20 @@ impl pallet_confidential_assets::Config for Runtime {
     type Backend = Zkhe;
     type Ramp = NoRamp;
     type AssetMetadata = ();
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<64>;
+    type Callbacks = ();
+    type OnTransferReceived = ();
+    type FeeAsset = ConstU128<0>;
+    type AutoClaim = ();
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
     type Acl = ();
     type Operators = ();
+    type Escrow = ();
+    type ScheduledTransferDeposit = ConstU128<0>;
+    type MaxTransfersPerBatch = ConstU32<4>;
     type WeightInfo = ();
 }
 
@@ -297,11 +348,17 @@ pub fn precompiles() -> Precompiles<Runtime> {
     PrecompilesValue::get()
 }
 
+/// Helper to envelope raw proof bytes for the mock proof system
+pub fn sealed(bytes: &[u8]) -> Vec<u8> {
+    seal_proof(AlwaysOkVerifier::ID, bytes)
+}
+
 /// Helper to set a public key for an account
 pub fn set_pk(who: AccountId) {
     <Zkhe as ConfidentialBackend<AccountId, AssetId, Balance>>::set_public_key(
         &who,
         &[7u8; 64].to_vec().try_into().expect("bounded vec"),
+        sealed(&[]).try_into().expect("bounded vec"),
     )
     .unwrap();
 }
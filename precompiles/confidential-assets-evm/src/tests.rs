@@ -1,7 +1,7 @@
 //! Unit tests for the confidential assets EVM precompile.
 
 use crate::MAX_PROOF_SIZE;
-use crate::mock::{ConfidentialAssetsAddress, ExtBuilder, PCall, precompiles, set_pk};
+use crate::mock::{ConfidentialAssetsAddress, ExtBuilder, PCall, precompiles, sealed, set_pk};
 use precompile_utils::prelude::Address;
 use precompile_utils::testing::*;
 use sp_core::{H160, H256, U256};
@@ -68,7 +68,7 @@ fn selectors_match_solidity_interface() {
     // State-changing functions
     assert_eq!(
         PCall::set_public_key_selectors()[0],
-        compute_selector("setPublicKey(bytes)"),
+        compute_selector("setPublicKey(bytes,bytes)"),
         "setPublicKey selector mismatch"
     );
     assert_eq!(
@@ -231,6 +231,7 @@ fn test_set_public_key_succeeds() {
                     ConfidentialAssetsAddress,
                     PCall::set_public_key {
                         pubkey: pubkey.into(),
+                        proof: sealed(&[0x01u8; 64]).into(),
                     },
                 )
                 .execute_returns(());
@@ -251,6 +252,7 @@ fn test_set_public_key_rejects_oversized_key() {
                     ConfidentialAssetsAddress,
                     PCall::set_public_key {
                         pubkey: oversized_pubkey.into(),
+                        proof: sealed(&[0x01u8; 64]).into(),
                     },
                 )
                 .execute_reverts(|output| {
@@ -270,7 +272,7 @@ fn test_deposit_succeeds_with_valid_proof() {
             set_pk(Alice.into());
 
             // Deposit with a mock proof
-            let proof_data = vec![0x01u8; 100];
+            let proof_data = sealed(&[0x01u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -285,6 +287,48 @@ fn test_deposit_succeeds_with_valid_proof() {
         })
 }
 
+#[test]
+fn test_deposit_log_carries_pending_id_commitments_and_proof_hash() {
+    use crate::SELECTOR_LOG_DEPOSIT;
+    use precompile_utils::{evm::logs::log3, solidity};
+
+    ExtBuilder::default()
+        .with_balances(vec![(Alice.into(), 1_000_000)])
+        .build()
+        .execute_with(|| {
+            set_pk(Alice.into());
+
+            // The mock verifier mints into pending [10; 32]; it is Alice's first pending transfer
+            let proof_data = sealed(&[0x01u8; 100]);
+            let mut asset_topic = H256::zero();
+            asset_topic.0[16..32].copy_from_slice(&1u128.to_be_bytes());
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    ConfidentialAssetsAddress,
+                    PCall::deposit {
+                        asset: 1u128,
+                        amount: U256::from(1000u64),
+                        proof: proof_data.clone().into(),
+                    },
+                )
+                .expect_log(log3(
+                    ConfidentialAssetsAddress,
+                    SELECTOR_LOG_DEPOSIT,
+                    asset_topic,
+                    H256::from(H160::from(Alice)),
+                    solidity::encode_event_data((
+                        U256::from(1000u64),
+                        0u64,
+                        H256::zero(),
+                        H256::repeat_byte(10),
+                        H256::from(sp_core::hashing::blake2_256(&proof_data)),
+                    )),
+                ))
+                .execute_returns(());
+        })
+}
+
 #[test]
 fn test_deposit_rejects_oversized_proof() {
     ExtBuilder::default()
@@ -321,7 +365,7 @@ fn test_withdraw_succeeds_with_valid_inputs() {
             set_pk(Alice.into());
 
             // First deposit some funds
-            let deposit_proof = vec![0x01u8; 100];
+            let deposit_proof = sealed(&[0x01u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -336,7 +380,7 @@ fn test_withdraw_succeeds_with_valid_inputs() {
 
             // Then withdraw
             let encrypted_amount = vec![0x02u8; 64]; // 64 bytes exactly
-            let withdraw_proof = vec![0x03u8; 100];
+            let withdraw_proof = sealed(&[0x03u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -361,7 +405,7 @@ fn test_withdraw_rejects_wrong_size_encrypted_amount() {
 
             // Try with wrong size encrypted amount (not 64 bytes)
             let wrong_size_amount = vec![0x02u8; 32]; // Should be 64 bytes
-            let proof = vec![0x03u8; 100];
+            let proof = sealed(&[0x03u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -387,7 +431,7 @@ fn test_confidential_transfer_succeeds() {
             set_pk(Bob.into());
 
             // Deposit funds for Alice first
-            let deposit_proof = vec![0x01u8; 100];
+            let deposit_proof = sealed(&[0x01u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -402,7 +446,7 @@ fn test_confidential_transfer_succeeds() {
 
             // Transfer from Alice to Bob
             let encrypted_amount = vec![0x05u8; 64];
-            let transfer_proof = vec![0x06u8; 100];
+            let transfer_proof = sealed(&[0x06u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -428,7 +472,7 @@ fn test_confidential_claim_succeeds() {
             set_pk(Bob.into());
 
             // Deposit funds for Alice
-            let deposit_proof = vec![0x01u8; 100];
+            let deposit_proof = sealed(&[0x01u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -443,7 +487,7 @@ fn test_confidential_claim_succeeds() {
 
             // Transfer from Alice to Bob
             let encrypted_amount = vec![0x05u8; 64];
-            let transfer_proof = vec![0x06u8; 100];
+            let transfer_proof = sealed(&[0x06u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -459,7 +503,7 @@ fn test_confidential_claim_succeeds() {
 
             // Bob claims the pending transfer
             // The claim proof needs to encode the transfer IDs
-            let mut claim_proof = Vec::new();
+            let mut claim_proof = sealed(&[]);
             // count: 1 transfer
             claim_proof.extend_from_slice(&1u16.to_le_bytes());
             // transfer_id: 0 (first transfer)
@@ -523,6 +567,7 @@ fn test_state_changing_fails_in_static_context() {
                     ConfidentialAssetsAddress,
                     PCall::set_public_key {
                         pubkey: pubkey.into(),
+                        proof: sealed(&[0x01u8; 64]).into(),
                     },
                 )
                 .with_static_call(true)
@@ -761,6 +806,7 @@ fn erc7984_wrapper_integration_scenario() {
                     ConfidentialAssetsAddress,
                     PCall::set_public_key {
                         pubkey: alice_pk.into(),
+                        proof: sealed(&[0x01u8; 64]).into(),
                     },
                 )
                 .execute_returns(());
@@ -772,6 +818,7 @@ fn erc7984_wrapper_integration_scenario() {
                     ConfidentialAssetsAddress,
                     PCall::set_public_key {
                         pubkey: bob_pk.into(),
+                        proof: sealed(&[0x01u8; 64]).into(),
                     },
                 )
                 .execute_returns(());
@@ -780,7 +827,7 @@ fn erc7984_wrapper_integration_scenario() {
             // ERC-7984 consumer calls: wrapper.deposit(amount, proof)
             // Wrapper calls: PRECOMPILE.deposit(assetId, amount, proof)
             let asset_id = 1u128; // Wrapper binds to this at deployment
-            let deposit_proof = vec![0x01u8; 100];
+            let deposit_proof = sealed(&[0x01u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -813,7 +860,7 @@ fn erc7984_wrapper_integration_scenario() {
             // where data = abi.encode(encryptedAmount, proof)
             // Wrapper calls: PRECOMPILE.confidentialTransfer(assetId, to, encryptedAmount, proof)
             let encrypted_amount = vec![0x05u8; 64];
-            let transfer_proof = vec![0x06u8; 100];
+            let transfer_proof = sealed(&[0x06u8; 100]);
             precompiles()
                 .prepare_test(
                     Alice,
@@ -830,7 +877,7 @@ fn erc7984_wrapper_integration_scenario() {
             // Step 5: Bob claims (via wrapper's claim)
             // ERC-7984 consumer calls: wrapper.claim(proof)
             // Wrapper calls: PRECOMPILE.confidentialClaim(assetId, proof)
-            let mut claim_proof = Vec::new();
+            let mut claim_proof = sealed(&[]);
             claim_proof.extend_from_slice(&1u16.to_le_bytes()); // count
             claim_proof.extend_from_slice(&0u64.to_le_bytes()); // transfer_id
             claim_proof.extend_from_slice(&[0x07u8; 50]); // proof data
@@ -890,7 +937,7 @@ fn erc7984_wrapper_integration_scenario() {
 
 /// Helper function to generate mock proof data with a specific pattern
 fn mock_proof_with_pattern(size: usize, pattern: u8) -> Vec<u8> {
-    vec![pattern; size]
+    sealed(&vec![pattern; size])
 }
 
 /// Helper function to generate mock encrypted amount
@@ -905,7 +952,7 @@ fn mock_public_key(pattern: u8) -> Vec<u8> {
 
 /// Helper function to create a claim proof with transfer IDs
 fn mock_claim_proof(transfer_ids: &[u64], proof_data_pattern: u8) -> Vec<u8> {
-    let mut proof = Vec::new();
+    let mut proof = sealed(&[]);
     proof.extend_from_slice(&(transfer_ids.len() as u16).to_le_bytes());
     for &id in transfer_ids {
         proof.extend_from_slice(&id.to_le_bytes());
@@ -936,6 +983,7 @@ fn test_complete_flow_set_key_deposit_transfer_claim_withdraw() {
                     ConfidentialAssetsAddress,
                     PCall::set_public_key {
                         pubkey: alice_pk.into(),
+                        proof: sealed(&[0x01u8; 64]).into(),
                     },
                 )
                 .execute_returns(());
@@ -948,6 +996,7 @@ fn test_complete_flow_set_key_deposit_transfer_claim_withdraw() {
                     ConfidentialAssetsAddress,
                     PCall::set_public_key {
                         pubkey: bob_pk.into(),
+                        proof: sealed(&[0x01u8; 64]).into(),
                     },
                 )
                 .execute_returns(());
@@ -1166,14 +1215,14 @@ fn test_error_case_malformed_encrypted_amount() {
 
 #[test]
 fn test_error_case_empty_proof() {
-    // Test that empty proofs are handled (may succeed with mock verifier)
+    // An empty proof has no proof-system id, so it is rejected before dispatch
     ExtBuilder::default()
         .with_balances(vec![(Alice.into(), 1_000_000)])
         .build()
         .execute_with(|| {
             set_pk(Alice.into());
 
-            // Try deposit with empty proof - mock verifier accepts this
+            // Try deposit with empty proof
             let empty_proof = vec![];
             precompiles()
                 .prepare_test(
@@ -1185,7 +1234,22 @@ fn test_error_case_empty_proof() {
                         proof: empty_proof.into(),
                     },
                 )
-                .execute_returns(()); // Mock verifier always succeeds
+                .execute_reverts(|output| output == b"malformed proof");
+
+            // A proof for another proof system is rejected too
+            let mut foreign_proof = mock_proof_with_pattern(100, 0x01);
+            foreign_proof[0] = 0xFF;
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    ConfidentialAssetsAddress,
+                    PCall::deposit {
+                        asset: 1u128,
+                        amount: U256::from(1000u64),
+                        proof: foreign_proof.into(),
+                    },
+                )
+                .execute_reverts(|output| output == b"unknown proof system");
         })
 }
 
@@ -1554,7 +1618,8 @@ fn test_edge_case_max_values() {
             let max_amount = U256::from(u128::MAX);
 
             // Maximum valid proof size (at the limit)
-            let max_proof = vec![0xFFu8; MAX_PROOF_SIZE as usize];
+            let mut max_proof = sealed(&[]);
+            max_proof.resize(MAX_PROOF_SIZE as usize, 0xFF);
 
             // These should all succeed with proper values
             precompiles()
@@ -1601,6 +1666,7 @@ fn test_gas_consumption_operations_complete_successfully() {
                     ConfidentialAssetsAddress,
                     PCall::set_public_key {
                         pubkey: pubkey.into(),
+                        proof: sealed(&[0x01u8; 64]).into(),
                     },
                 )
                 .execute_returns(());
@@ -119,10 +119,7 @@ where
                 );
 
                 // Return the commitment as bytes32
-                let result: [u8; 32] = commitment
-                    .try_into()
-                    .map_err(|_| revert_error("Invalid commitment length"))?;
-                Ok(result.abi_encode())
+                Ok(commitment.to_bytes().abi_encode())
             }
             publicKey(call) => {
                 let account_bytes: [u8; 32] = call.account.into();
@@ -149,10 +146,7 @@ where
                     asset_id.into(),
                 );
 
-                let result: [u8; 32] = commitment
-                    .try_into()
-                    .map_err(|_| revert_error("Invalid commitment length"))?;
-                Ok(result.abi_encode())
+                Ok(commitment.to_bytes().abi_encode())
             }
         }
     }
@@ -0,0 +1,242 @@
+//! Byte-level ciphertext and commitment types.
+//!
+//! Both wrap compressed Ristretto points. They are stored and transmitted as raw bytes
+//! (SCALE-encoded exactly like the arrays they wrap) and only decompressed for the
+//! homomorphic helpers below.
+
+use crate::VerifyError;
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+use frame_support::pallet_prelude::*;
+use parity_scale_codec::DecodeWithMemTracking;
+use sp_std::prelude::*;
+
+/// Pedersen commitment (compressed Ristretto). All-zero bytes encode the identity.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub struct Commitment(pub [u8; 32]);
+
+impl Commitment {
+    /// Commitment to zero with zero blinding.
+    pub const IDENTITY: Self = Self([0u8; 32]);
+
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.0 == [0u8; 32]
+    }
+
+    pub const fn to_bytes(self) -> [u8; 32] {
+        self.0
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// `self + other`, homomorphically.
+    pub fn add(&self, other: &Self) -> Result<Self, VerifyError> {
+        Ok(Self::compress(self.point()? + other.point()?))
+    }
+
+    /// `self - other`, homomorphically.
+    pub fn sub(&self, other: &Self) -> Result<Self, VerifyError> {
+        Ok(Self::compress(self.point()? - other.point()?))
+    }
+
+    fn point(&self) -> Result<RistrettoPoint, VerifyError> {
+        CompressedRistretto(self.0)
+            .decompress()
+            .ok_or(VerifyError::InvalidPoint)
+    }
+
+    fn compress(point: RistrettoPoint) -> Self {
+        Self(point.compress().to_bytes())
+    }
+}
+
+impl From<[u8; 32]> for Commitment {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Commitment> for [u8; 32] {
+    fn from(c: Commitment) -> Self {
+        c.0
+    }
+}
+
+impl TryFrom<&[u8]> for Commitment {
+    type Error = VerifyError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes
+            .try_into()
+            .map(Self)
+            .map_err(|_| VerifyError::InvalidPoint)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Commitment {
+    type Error = VerifyError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl AsRef<[u8]> for Commitment {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// ElGamal ciphertext of an amount `v` under a key `pk`, with nonce `k`.
+/// bytes 0..32 = nonce point C = k·G
+/// bytes 32..64 = masked amount D = v·G + k·pk
+///
+/// Neither half is the Pedersen commitment to `v`; backends track those separately.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub struct EncryptedAmount(pub [u8; 64]);
+
+impl EncryptedAmount {
+    /// Encryption of zero with zero randomness: (identity, identity).
+    pub const ZERO: Self = Self([0u8; 64]);
+
+    pub const fn new(bytes: [u8; 64]) -> Self {
+        Self(bytes)
+    }
+
+    /// Join a nonce point `C` and masked amount `D`.
+    pub fn from_parts(nonce_point: [u8; 32], masked: [u8; 32]) -> Self {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&nonce_point);
+        out[32..].copy_from_slice(&masked);
+        Self(out)
+    }
+
+    pub const fn zero() -> Self {
+        Self::ZERO
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0u8; 64]
+    }
+
+    /// The nonce point `C = k·G`.
+    pub fn nonce_point(&self) -> [u8; 32] {
+        let mut c = [0u8; 32];
+        c.copy_from_slice(&self.0[..32]);
+        c
+    }
+
+    /// The masked amount `D = v·G + k·pk`.
+    pub fn masked(&self) -> [u8; 32] {
+        let mut d = [0u8; 32];
+        d.copy_from_slice(&self.0[32..]);
+        d
+    }
+
+    pub const fn to_bytes(self) -> [u8; 64] {
+        self.0
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        &self.0
+    }
+
+    /// `self + other`, component-wise; decrypts to the sum when both share a key.
+    pub fn add(&self, other: &Self) -> Result<Self, VerifyError> {
+        let (c1, d1) = self.points()?;
+        let (c2, d2) = other.points()?;
+        Ok(Self::compress(c1 + c2, d1 + d2))
+    }
+
+    /// `self - other`, component-wise; decrypts to the difference when both share a key.
+    pub fn sub(&self, other: &Self) -> Result<Self, VerifyError> {
+        let (c1, d1) = self.points()?;
+        let (c2, d2) = other.points()?;
+        Ok(Self::compress(c1 - c2, d1 - d2))
+    }
+
+    fn points(&self) -> Result<(RistrettoPoint, RistrettoPoint), VerifyError> {
+        let c = CompressedRistretto(self.nonce_point()).decompress();
+        let d = CompressedRistretto(self.masked()).decompress();
+        c.zip(d).ok_or(VerifyError::InvalidCiphertext)
+    }
+
+    fn compress(c: RistrettoPoint, d: RistrettoPoint) -> Self {
+        Self::from_parts(c.compress().to_bytes(), d.compress().to_bytes())
+    }
+}
+
+impl Default for EncryptedAmount {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<[u8; 64]> for EncryptedAmount {
+    fn from(bytes: [u8; 64]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<EncryptedAmount> for [u8; 64] {
+    fn from(ct: EncryptedAmount) -> Self {
+        ct.0
+    }
+}
+
+impl TryFrom<&[u8]> for EncryptedAmount {
+    type Error = VerifyError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes
+            .try_into()
+            .map(Self)
+            .map_err(|_| VerifyError::InvalidCiphertext)
+    }
+}
+
+impl TryFrom<Vec<u8>> for EncryptedAmount {
+    type Error = VerifyError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl AsRef<[u8]> for EncryptedAmount {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
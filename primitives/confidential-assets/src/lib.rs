@@ -1,15 +1,12 @@
 //! Types and traits for confidential assets crates
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{BoundedVec, pallet_prelude::*};
+use frame_support::{BoundedVec, PalletError, pallet_prelude::*};
+use parity_scale_codec::DecodeWithMemTracking;
 use sp_std::prelude::*;
 
-/// ZK El Gamal Ciphertext
-/// bytes 0..32 = Pederson commitment
-/// bytes 33..64 = El Gamal decrypt handle
-pub type EncryptedAmount = [u8; 64];
-/// Commitment type (compressed Ristretto)
-pub type Commitment = [u8; 32];
+mod ciphertext;
+pub use ciphertext::{Commitment, EncryptedAmount};
 
 /// Proof/aux data blob used by the backend to validate encrypted transfers.
 pub type MaxProofLen = ConstU32<8192>;
@@ -25,16 +22,45 @@ pub type PublicKeyBytes = BoundedVec<u8, MaxPubKeyLen>;
 
 /// Backend that holds the **truth** for totals, balances, public keys, and executes transfers.
 pub trait ConfidentialBackend<AccountId, AssetId, Balance> {
-    fn set_public_key(who: &AccountId, elgamal_pk: &PublicKeyBytes) -> Result<(), DispatchError>;
+    /// Register `elgamal_pk` for `who`. `proof` shows the caller knows the secret key.
+    /// Fails if `who` already has a key: balances are encrypted under it, so replacing
+    /// it needs the backend's own rotation, which re-encrypts them.
+    fn set_public_key(
+        who: &AccountId,
+        elgamal_pk: &PublicKeyBytes,
+        proof: InputProof,
+    ) -> Result<(), DispatchError>;
 
     // Read encrypted balances state
     fn total_supply(asset: AssetId) -> Commitment;
     fn balance_of(asset: AssetId, who: &AccountId) -> Commitment;
+    /// Commitment to the sum of `who`'s pending transfers of `asset`.
+    fn pending_balance_of(asset: AssetId, who: &AccountId) -> Commitment;
+    /// `who`'s pending transfers of `asset` as `(id, amount)`, sorted by id.
+    fn pending_transfers(asset: AssetId, who: &AccountId) -> Vec<(u64, EncryptedAmount)>;
+    /// Id of the pending transfer of `asset` last queued for `who`, even if since
+    /// claimed; `None` if there never was one.
+    fn last_pending_id(asset: AssetId, who: &AccountId) -> Option<u64>;
 
+    /// Verify `who`'s disclosure proof for `encrypted_amount` and return the plaintext.
     fn disclose_amount(
         asset: AssetId,
         encrypted_amount: &EncryptedAmount,
         who: &AccountId,
+        proof: InputProof,
+    ) -> Result<Balance, DispatchError>;
+
+    /// Whether `asset` has an auditor key, so its transfers carry auditor handles.
+    fn is_audited(asset: AssetId) -> bool;
+
+    /// Verify `who`'s disclosure of the amount it moved in the latest audited operation
+    /// on `asset`, made under the auditor handle that operation carried, and return it.
+    /// Lets a pallet learn what a transfer it just made for `who` moved, whoever the
+    /// recipient's key belongs to.
+    fn disclose_audited(
+        asset: AssetId,
+        who: &AccountId,
+        proof: InputProof,
     ) -> Result<Balance, DispatchError>;
 
     fn transfer_encrypted(
@@ -45,6 +71,18 @@ pub trait ConfidentialBackend<AccountId, AssetId, Balance> {
         input_proof: InputProof,
     ) -> Result<EncryptedAmount, DispatchError>;
 
+    /// Move `total` out of `from`'s available balance under one `input_proof` and queue
+    /// each of `outputs` as a pending transfer to its recipient. The proof shows the
+    /// outputs add up to `total`.
+    /// Returns the pending ids, in `outputs` order.
+    fn transfer_split(
+        asset: AssetId,
+        from: &AccountId,
+        total: EncryptedAmount,
+        outputs: &[(AccountId, EncryptedAmount)],
+        input_proof: InputProof,
+    ) -> Result<Vec<u64>, DispatchError>;
+
     fn claim_encrypted(
         asset: AssetId,
         from: &AccountId,
@@ -63,6 +101,71 @@ pub trait ConfidentialBackend<AccountId, AssetId, Balance> {
         amount: EncryptedAmount,
         input_proof: InputProof,
     ) -> Result<Balance, DispatchError>;
+
+    /// Destroy `amount` from `from`'s available balance and the total supply without
+    /// revealing it. Nothing is released on the public side.
+    fn burn_confidential(
+        asset: AssetId,
+        from: &AccountId,
+        amount: EncryptedAmount,
+        input_proof: InputProof,
+    ) -> Result<(), DispatchError>;
+
+    /// Destroy `amount` from `from`'s available balance and the total supply without a
+    /// proof, by subtracting the commitment. Only for pallet accounts known to hold
+    /// `amount` because every credit to them was verified, e.g. an escrow burning one of
+    /// its locks; `from` may not have claimed it out of pending yet, so only its
+    /// available and pending balances together stay exact.
+    fn burn_held(asset: AssetId, from: &AccountId, amount: Commitment)
+    -> Result<(), DispatchError>;
+
+    /// Delete `who`'s balances and pending deposits for `asset` once `proof` shows both
+    /// the available and pending balance are zero.
+    fn close_account(
+        asset: AssetId,
+        who: &AccountId,
+        proof: InputProof,
+    ) -> Result<(), DispatchError>;
+
+    /// Whether governance has paused `asset`. Paused assets cannot be transferred,
+    /// minted, burned or claimed; callers should refuse to start work on them.
+    /// `seize_balance` is governance's own tool and still works while paused.
+    fn is_paused(asset: AssetId) -> bool;
+
+    /// Seize `who`'s whole available balance of an audited `asset`, e.g. under a court
+    /// order. `encrypted_balance` is that balance under the asset's auditor key and
+    /// `proof`, made with the auditor's secret key alone, shows it hides the same amount
+    /// as the stored balance (see [`ZkVerifier::verify_seizure`]). Clears the balance,
+    /// takes it out of the total supply and returns the disclosed amount.
+    fn seize_balance(
+        asset: AssetId,
+        who: &AccountId,
+        encrypted_balance: EncryptedAmount,
+        proof: InputProof,
+    ) -> Result<Balance, DispatchError>;
+}
+
+/// Writes [`ConfidentialBackend`] state directly, so pallets wrapping a backend can
+/// benchmark calls against proofs generated for fixed starting commitments.
+///
+/// Nothing here is checked; implement it for benchmarks only.
+pub trait BackendBenchmarkHelper<AccountId, AssetId> {
+    /// Accept deposits of `asset`, for backends that gate assets.
+    fn enable_asset(asset: AssetId);
+    /// Register `elgamal_pk` for `who` without an ownership proof.
+    fn set_public_key(who: &AccountId, elgamal_pk: PublicKeyBytes);
+    /// Set `who`'s available balance of `asset`.
+    fn set_available(asset: AssetId, who: &AccountId, available: Commitment);
+    /// Set the total supply of `asset`.
+    fn set_total_supply(asset: AssetId, total: Commitment);
+    /// Queue `amount`, committed to by `commitment`, as a pending deposit for `who`,
+    /// adding `commitment` to their pending balance, and return its id.
+    fn add_pending(
+        asset: AssetId,
+        who: &AccountId,
+        amount: EncryptedAmount,
+        commitment: Commitment,
+    ) -> u64;
 }
 
 /// Adaptor signature functionality required for trustless cross chain atomic swaps
@@ -104,54 +207,249 @@ pub trait EscrowTrust<AccountId, AssetId, Balance> {
     fn escrow_refund(asset: AssetId, to: &AccountId, amount: Balance) -> Result<(), DispatchError>;
 }
 
+/// Id of a [`ConfidentialEscrow`] lock.
+pub type EscrowId = u64;
+
+/// Tag the locking pallet gives a lock so its purpose can be told apart on chain,
+/// e.g. `*b"bridging"`.
+pub type EscrowPurpose = [u8; 8];
+
 /// Confidential escrow
-pub trait ConfidentialEscrow<AccountId, AssetId> {
-    /// Move value from `who` into pallet escrow.
+pub trait ConfidentialEscrow<AccountId, AssetId, BlockNumber> {
+    /// Move value from `who` into pallet escrow, returning the id of the new lock.
     fn escrow_lock(
         asset: AssetId,
         who: &AccountId,
         encrypted_amount: EncryptedAmount,
+        purpose: EscrowPurpose,
         proof: InputProof,
-    ) -> Result<(), DispatchError>;
+    ) -> Result<EscrowId, DispatchError>;
 
-    /// Release escrowed value to `to` (on successful redeem).
-    fn escrow_release(
+    /// [`Self::escrow_lock`] with a lock that expires at block `expires_at`. From then on
+    /// anyone may refund what is left of it to `who` with a refund proof.
+    fn escrow_lock_until(
         asset: AssetId,
+        who: &AccountId,
+        encrypted_amount: EncryptedAmount,
+        purpose: EscrowPurpose,
+        expires_at: BlockNumber,
+        proof: InputProof,
+    ) -> Result<EscrowId, DispatchError>;
+
+    /// Block lock `id` expires at; `None` if it never does or is closed.
+    fn escrow_expires_at(id: EscrowId) -> Option<BlockNumber>;
+
+    /// Release `encrypted_amount` of lock `id` to `to` (on successful redeem). The lock
+    /// is closed once all of it has left.
+    fn escrow_release(
+        id: EscrowId,
         to: &AccountId,
         encrypted_amount: EncryptedAmount,
         proof: InputProof,
     ) -> Result<(), DispatchError>;
 
-    /// Refund escrowed value to `to` (after timeout).
+    /// Refund `encrypted_amount` of lock `id` to the account that locked it (after
+    /// timeout). The lock is closed once all of it has left.
     fn escrow_refund(
-        asset: AssetId,
-        to: &AccountId,
+        id: EscrowId,
         encrypted_amount: EncryptedAmount,
         proof: InputProof,
     ) -> Result<(), DispatchError>;
+
+    /// Burn what is left of lock `id` straight from escrow, taking it out of the total
+    /// supply without a proof, and close the lock. For the pallet that opened it, e.g. a
+    /// bridge finalizing a transfer already minted on another chain.
+    fn escrow_burn(id: EscrowId) -> Result<(), DispatchError>;
+
+    /// Require `threshold` of `approvers` to approve every release from lock `id`, e.g.
+    /// buyer and arbiter. Set once, by the pallet that opened the lock; refunds stay
+    /// ungated.
+    fn escrow_set_release_policy(
+        id: EscrowId,
+        approvers: Vec<AccountId>,
+        threshold: u32,
+    ) -> Result<(), DispatchError>;
+
+    /// Release `partial` of lock `id` to `to` under `proof_release` and keep the rest
+    /// escrowed. The rest is the lock's ciphertext minus `partial`, checked against its
+    /// remaining commitment and re-escrowed under `proof_remainder`, which proves it is
+    /// a valid amount so the release cannot take more than the lock holds.
+    fn escrow_release_partial(
+        id: EscrowId,
+        to: &AccountId,
+        partial: EncryptedAmount,
+        proof_release: InputProof,
+        proof_remainder: InputProof,
+    ) -> Result<(), DispatchError>;
+}
+
+/// No escrow: every operation fails with `DispatchError::Unavailable`.
+impl<AccountId, AssetId, BlockNumber> ConfidentialEscrow<AccountId, AssetId, BlockNumber> for () {
+    fn escrow_lock(
+        _asset: AssetId,
+        _who: &AccountId,
+        _encrypted_amount: EncryptedAmount,
+        _purpose: EscrowPurpose,
+        _proof: InputProof,
+    ) -> Result<EscrowId, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn escrow_lock_until(
+        _asset: AssetId,
+        _who: &AccountId,
+        _encrypted_amount: EncryptedAmount,
+        _purpose: EscrowPurpose,
+        _expires_at: BlockNumber,
+        _proof: InputProof,
+    ) -> Result<EscrowId, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn escrow_expires_at(_id: EscrowId) -> Option<BlockNumber> {
+        None
+    }
+
+    fn escrow_release(
+        _id: EscrowId,
+        _to: &AccountId,
+        _encrypted_amount: EncryptedAmount,
+        _proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn escrow_refund(
+        _id: EscrowId,
+        _encrypted_amount: EncryptedAmount,
+        _proof: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn escrow_burn(_id: EscrowId) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn escrow_set_release_policy(
+        _id: EscrowId,
+        _approvers: Vec<AccountId>,
+        _threshold: u32,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn escrow_release_partial(
+        _id: EscrowId,
+        _to: &AccountId,
+        _partial: EncryptedAmount,
+        _proof_release: InputProof,
+        _proof_remainder: InputProof,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+}
+
+/// What a swap intent's maker asks of the taker leg.
+#[derive(
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    RuntimeDebug,
+)]
+pub enum SwapTerms {
+    /// Any taker leg on the intent's asset.
+    #[default]
+    Any,
+    /// Exactly one taker leg: the blake2 hash of `(asset_b, b_to_a_ct)`.
+    Exact([u8; 32]),
+    /// Any taker leg worth between `min` and `max` units of the taker's asset per unit of
+    /// the maker's, shown by a ratio proof at execution.
+    Rate {
+        min: sp_runtime::FixedU128,
+        max: sp_runtime::FixedU128,
+    },
+}
+
+/// Checks ratio proofs for [`SwapTerms::Rate`].
+pub trait RatioVerifier {
+    /// Whether `proof` shows the amount under `taker` is between `min` and `max` times
+    /// the amount under `maker`.
+    fn verify_ratio(
+        maker: &EncryptedAmount,
+        taker: &EncryptedAmount,
+        min: sp_runtime::FixedU128,
+        max: sp_runtime::FixedU128,
+        proof: &[u8],
+    ) -> bool;
+}
+
+/// No ratio proofs: intents with [`SwapTerms::Rate`] cannot execute.
+impl RatioVerifier for () {
+    fn verify_ratio(
+        _maker: &EncryptedAmount,
+        _taker: &EncryptedAmount,
+        _min: sp_runtime::FixedU128,
+        _max: sp_runtime::FixedU128,
+        _proof: &[u8],
+    ) -> bool {
+        false
+    }
 }
 
 /// Trait so other pallets can open/cancel intents without extrinsics.
-pub trait ConfidentialSwapIntents<AccountId, AssetId> {
+pub trait ConfidentialSwapIntents<AccountId, AssetId, BlockNumber> {
     type SwapId;
+    /// Fee `execute_intent_cc` charges its taker.
+    type Fee: Encode;
+
+    /// Open an intent for `counterparty`, or for whoever executes it first if `None`.
+    /// It can no longer be executed from block `expires_at` on.
+    ///
+    /// `a_to_b_ct` is escrowed from `maker` under `lock_proof` until then, and released
+    /// to the taker under `a_to_b_proof`.
+    #[allow(clippy::too_many_arguments)]
     fn open_intent_cc(
         maker: &AccountId,
-        counterparty: &AccountId,
+        counterparty: Option<&AccountId>,
         asset_a: AssetId,
         asset_b: AssetId,
         a_to_b_ct: EncryptedAmount,
+        lock_proof: InputProof,
         a_to_b_proof: InputProof,
-        terms_hash: Option<[u8; 32]>,
+        terms: SwapTerms,
+        expires_at: BlockNumber,
     ) -> Result<Self::SwapId, DispatchError>;
 
+    /// Execute intent `id` for `who`; `terms_proof` is the ratio proof of
+    /// [`SwapTerms::Rate`] intents and ignored otherwise.
     fn execute_intent_cc(
         who: &AccountId,
         id: Self::SwapId,
         b_to_a_ct: EncryptedAmount,
         b_to_a_proof: InputProof,
+        terms_proof: InputProof,
     ) -> Result<(Self::SwapId, EncryptedAmount), DispatchError>;
 
-    fn cancel_intent_cc(maker: &AccountId, id: Self::SwapId) -> DispatchResult;
+    /// Cancel `maker`'s intent, refunding its escrowed leg under `refund_proof`.
+    fn cancel_intent_cc(
+        maker: &AccountId,
+        id: Self::SwapId,
+        refund_proof: InputProof,
+    ) -> DispatchResult;
+
+    /// Remove an expired intent on behalf of `who`. Its escrowed leg is refunded
+    /// through the escrow, whose lock expired with it.
+    fn prune_intent_cc(who: &AccountId, id: Self::SwapId) -> DispatchResult;
+
+    /// Fee executing intent `id` would charge its taker now.
+    fn execution_fee(id: Self::SwapId) -> Result<Self::Fee, DispatchError>;
 }
 
 /// Off/On-ramp for the public side of an asset.
@@ -174,6 +472,92 @@ pub trait Ramp<AccountId, AssetId, Amount> {
     fn mint(to: &AccountId, asset: &AssetId, amount: Amount) -> Result<(), Self::Error>;
 }
 
+/// No public ledger: every operation fails with `DispatchError::Unavailable`.
+impl<AccountId, AssetId, Amount> Ramp<AccountId, AssetId, Amount> for () {
+    type Error = DispatchError;
+
+    fn transfer_from(
+        _from: &AccountId,
+        _to: &AccountId,
+        _asset: AssetId,
+        _amount: Amount,
+    ) -> Result<(), Self::Error> {
+        Err(DispatchError::Unavailable)
+    }
+    fn burn(_from: &AccountId, _asset: &AssetId, _amount: Amount) -> Result<(), Self::Error> {
+        Err(DispatchError::Unavailable)
+    }
+    fn mint(_to: &AccountId, _asset: &AssetId, _amount: Amount) -> Result<(), Self::Error> {
+        Err(DispatchError::Unavailable)
+    }
+}
+
+/// Callbacks pallet-confidential-assets runs after a call succeeds, so other pallets can
+/// react on-chain instead of parsing events offchain. Every method does nothing by
+/// default, and `()` is the no-op implementation.
+pub trait ConfidentialCallbacks<AccountId, AssetId, Balance> {
+    /// `who` deposited `amount`, credited to them as `encrypted_amount`.
+    fn on_shield(
+        _asset: AssetId,
+        _who: &AccountId,
+        _amount: Balance,
+        _encrypted_amount: &EncryptedAmount,
+    ) {
+    }
+
+    /// `who` withdrew `encrypted_amount`, which disclosed `amount`.
+    fn on_unshield(
+        _asset: AssetId,
+        _who: &AccountId,
+        _amount: Balance,
+        _encrypted_amount: &EncryptedAmount,
+    ) {
+    }
+
+    /// `from` sent `encrypted_amount` to `to`'s pending balance.
+    fn on_transfer(
+        _asset: AssetId,
+        _from: &AccountId,
+        _to: &AccountId,
+        _encrypted_amount: &EncryptedAmount,
+    ) {
+    }
+
+    /// `who` claimed pending transfers into their available balance; `encrypted_amount`
+    /// is what the backend reports as claimed.
+    fn on_claim(_asset: AssetId, _who: &AccountId, _encrypted_amount: &EncryptedAmount) {}
+}
+
+impl<AccountId, AssetId, Balance> ConfidentialCallbacks<AccountId, AssetId, Balance> for () {}
+
+/// Receiver hook of `confidential_transfer_and_call`, the ERC-7984
+/// `onConfidentialTransferReceived`. `()` accepts every transfer.
+pub trait OnConfidentialTransferReceived<AccountId, AssetId> {
+    /// `to` received `encrypted_amount` of `asset` from `from`, sent by `operator`, with
+    /// `data`. Returning `false` rejects it and the transfer is reverted.
+    fn on_confidential_transfer_received(
+        asset: AssetId,
+        operator: &AccountId,
+        from: &AccountId,
+        to: &AccountId,
+        encrypted_amount: &EncryptedAmount,
+        data: &CallbackData,
+    ) -> bool;
+}
+
+impl<AccountId, AssetId> OnConfidentialTransferReceived<AccountId, AssetId> for () {
+    fn on_confidential_transfer_received(
+        _asset: AssetId,
+        _operator: &AccountId,
+        _from: &AccountId,
+        _to: &AccountId,
+        _encrypted_amount: &EncryptedAmount,
+        _data: &CallbackData,
+    ) -> bool {
+        true
+    }
+}
+
 /// Metadata provider per asset (names, symbols, etc.).
 pub trait AssetMetadataProvider<AssetId> {
     fn name(asset: AssetId) -> Vec<u8>;
@@ -193,6 +577,31 @@ impl<AssetId> AssetMetadataProvider<AssetId> for () {
     }
 }
 
+/// Proves claims off-chain for accounts whose secret key the node holds, so the
+/// offchain worker can accept their pending transfers.
+///
+/// Only `()` is provided, which never proves. A runtime implements this over a host
+/// function of its node that holds the keys and runs the backend's prover.
+pub trait ClaimProver<AccountId, AssetId> {
+    /// Claim envelope accepting `pending` into `who`'s available balance of `asset`,
+    /// or `None` if this node cannot prove for `who`.
+    fn prove_claim(
+        asset: AssetId,
+        who: &AccountId,
+        pending: &[(u64, EncryptedAmount)],
+    ) -> Option<InputProof>;
+}
+
+impl<AccountId, AssetId> ClaimProver<AccountId, AssetId> for () {
+    fn prove_claim(
+        _asset: AssetId,
+        _who: &AccountId,
+        _pending: &[(u64, EncryptedAmount)],
+    ) -> Option<InputProof> {
+        None
+    }
+}
+
 /// Provider for the network identifier used in ZK proof domain separation.
 /// Implement this trait and configure it via `ZkVerifier::NetworkIdProvider`.
 pub trait NetworkIdProvider {
@@ -201,8 +610,9 @@ pub trait NetworkIdProvider {
 }
 
 /// Default network ID provider that returns all zeros.
-/// **WARNING**: Only use this for testing. Production deployments should
-/// configure a unique network ID to prevent cross-chain replay attacks.
+/// **WARNING**: Only use this for testing. Every chain using it shares one domain
+/// separator, so proofs replay across them. Production runtimes should use
+/// `zkhe_primitives::GenesisNetworkId`.
 pub struct ZeroNetworkId;
 impl NetworkIdProvider for ZeroNetworkId {
     fn network_id() -> [u8; 32] {
@@ -210,6 +620,130 @@ impl NetworkIdProvider for ZeroNetworkId {
     }
 }
 
+/// Range-proof bit width used when an asset has not configured one.
+pub const DEFAULT_RANGE_BITS: u8 = 64;
+
+/// Range-proof bit widths a backend may accept. Amounts are `u64`, so wider
+/// widths would prove nothing more.
+pub const SUPPORTED_RANGE_BITS: [u8; 2] = [32, 64];
+
+/// Whether `bits` is one of [`SUPPORTED_RANGE_BITS`].
+pub fn is_supported_range_bits(bits: u8) -> bool {
+    SUPPORTED_RANGE_BITS.contains(&bits)
+}
+
+/// Provider for the range-proof bit width of an asset.
+///
+/// Amounts of an asset with width `n` are proven to lie in `[0, 2^n)`; narrower
+/// proofs are smaller and cheaper to verify.
+pub trait RangeBitsProvider {
+    /// Bit width for `asset` (SCALE-encoded). Must be one of [`SUPPORTED_RANGE_BITS`].
+    fn range_bits(asset: &[u8]) -> u8;
+
+    /// Least amount a transfer of `asset` (SCALE-encoded) may move. Transfers of an
+    /// asset with a non-zero floor must carry a range proof that the amount minus the
+    /// floor is non-negative.
+    fn transfer_floor(_asset: &[u8]) -> u64 {
+        0
+    }
+}
+
+/// Range-bits provider that uses [`DEFAULT_RANGE_BITS`] for every asset.
+pub struct DefaultRangeBits;
+impl RangeBitsProvider for DefaultRangeBits {
+    fn range_bits(_asset: &[u8]) -> u8 {
+        DEFAULT_RANGE_BITS
+    }
+}
+
+/// Reason a proof was rejected by a [`ZkVerifier`].
+///
+/// Encodes into a single byte so pallets can carry it inside their `Error`
+/// and `Event` types.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    PalletError,
+    RuntimeDebug,
+)]
+pub enum VerifyError {
+    /// Proof bytes do not follow the expected layout (bad length, prefix, etc.).
+    MalformedProof,
+    /// A public key or commitment is not a valid compressed Ristretto point.
+    InvalidPoint,
+    /// The 64-byte ciphertext could not be decoded.
+    InvalidCiphertext,
+    /// A scalar, point or proof is not in its unique canonical encoding (e.g. trailing bytes).
+    NonCanonicalEncoding,
+    /// The Σ-proof linking ciphertext and commitment failed.
+    LinkProofInvalid,
+    /// A Bulletproof range proof failed.
+    RangeProofInvalid,
+    /// Consumed pending commitments do not sum to the claimed delta.
+    PendingCommitMismatch,
+    /// The operation is not supported by this verifier (e.g. on-chain disclosure).
+    Unsupported,
+    /// The proof envelope names a proof system this verifier does not implement.
+    UnknownProofSystem,
+}
+
+/// Which proof a byte string is expected to be, for [`ZkVerifier::validate_shape`].
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ProofKind {
+    /// Sender bundle passed to [`ZkVerifier::verify_transfer_sent`].
+    Transfer,
+    /// Accept envelope passed to [`ZkVerifier::verify_transfer_received`].
+    Accept,
+    /// UTXO ids followed by an accept envelope (see [`parse_claim`]).
+    Claim,
+    /// Mint proof passed to [`ZkVerifier::verify_mint`].
+    Mint,
+    /// Burn proof passed to [`ZkVerifier::verify_burn`].
+    Burn,
+    /// Burn proof passed to [`ZkVerifier::verify_confidential_burn`].
+    ConfidentialBurn,
+    /// Disclosure proof passed to [`ZkVerifier::disclose`].
+    Disclosure,
+    /// Key ownership proof passed to [`ZkVerifier::verify_pubkey_ownership`].
+    PubkeyOwnership,
+    /// Zero-balance proof passed to [`ZkVerifier::verify_zero_balance`].
+    ZeroBalance,
+    /// Key rotation proof passed to [`ZkVerifier::verify_rekey`].
+    Rekey,
+    /// Auditor handle passed to [`ZkVerifier::verify_auditor_handle`].
+    AuditorHandle,
+    /// Split transfer proof passed to [`ZkVerifier::verify_transfer_split`].
+    TransferSplit,
+    /// Seizure proof passed to [`ZkVerifier::verify_seizure`].
+    Seizure,
+}
+
+/// Split a claim into the UTXO ids it consumes and the accept envelope.
+///
+/// Layout: count:u16 LE || ids[count]:u64 LE || accept_envelope
+pub fn parse_claim(claim: &[u8]) -> Result<(Vec<u64>, &[u8]), VerifyError> {
+    if claim.len() < 2 {
+        return Err(VerifyError::MalformedProof);
+    }
+    let count = u16::from_le_bytes([claim[0], claim[1]]) as usize;
+    let end = 2 + count * 8;
+    if claim.len() < end {
+        return Err(VerifyError::MalformedProof);
+    }
+    let ids = claim[2..end]
+        .chunks_exact(8)
+        .map(|c| u64::from_le_bytes(c.try_into().expect("chunk is 8 bytes")))
+        .collect();
+    Ok((ids, &claim[end..]))
+}
+
 /// Abstract verifier boundary. Implement in the runtime.
 ///
 /// The `NetworkIdProvider` type provides domain separation for proofs, preventing
@@ -219,13 +753,24 @@ impl NetworkIdProvider for ZeroNetworkId {
 // TODO:
 // - verify_{mint, burn}_{to_send, received}
 pub trait ZkVerifier {
-    type Error;
-
     /// Provider for the network identifier used in ZK proof domain separation.
     /// This should return a unique 32-byte value per chain to prevent cross-chain replay attacks.
     /// Common choices: `blake2_256(genesis_hash ++ pallet_name)` or a fixed chain ID.
     type NetworkIdProvider: NetworkIdProvider;
 
+    /// Cheap structural check of `proof` against the layout expected for `kind`.
+    ///
+    /// Runs before any storage reads or curve arithmetic so malformed input is rejected
+    /// early. It does not imply the proof verifies. The default accepts everything.
+    fn validate_shape(_kind: ProofKind, _proof: &[u8]) -> Result<(), VerifyError> {
+        Ok(())
+    }
+
+    /// Key registration: verify that whoever submits `pk` for `account` (SCALE-encoded)
+    /// knows its secret key. Without it anyone could register another user's key, or a
+    /// point nobody can decrypt under, and strand transfers sent to them.
+    fn verify_pubkey_ownership(account: &[u8], pk: &[u8], proof: &[u8]) -> Result<(), VerifyError>;
+
     /// Sender phase: verify link/range (as implemented) and compute new commitments.
     /// Inputs:
     /// - `from_old_avail_commit`, `to_old_pending_commit`: 0 or 32 bytes
@@ -242,7 +787,29 @@ pub trait ZkVerifier {
         to_old_pending_commit: &[u8], // empty => identity
         delta_ct: &[u8],              // 64B
         proof_bundle: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), Self::Error>;
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError>;
+
+    /// Split transfer: verify that `total` (64B, under `from_pk`) encrypts a value v with
+    /// `from_old_avail_commit - v ≥ 0`, that each output ciphertext encrypts a value
+    /// ≥ 0 under its recipient's key, and that the outputs add up to v.
+    /// Inputs:
+    /// - `from_old_avail_commit`: 0 or 32 bytes
+    /// - `outputs`: `(to_pk, ciphertext)` of each recipient
+    ///
+    /// Returns:
+    /// - (from_new_available_commit, output_commits), 32B each; one commitment per
+    ///   output, in `outputs` order
+    /// The default rejects every proof.
+    fn verify_transfer_split(
+        _asset: &[u8],
+        _from_pk: &[u8],
+        _from_old_avail_commit: &[u8],
+        _total: &EncryptedAmount,
+        _outputs: &[(PublicKeyBytes, EncryptedAmount)],
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<[u8; 32]>), VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
 
     /// Receiver phase (Option A): accept selected UTXO deposits.
     /// Inputs:
@@ -259,7 +826,48 @@ pub trait ZkVerifier {
         pending_old_commit: &[u8],    // empty => identity
         pending_commits: &[[u8; 32]], // UTXO C’s to sum
         accept_envelope: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), Self::Error>;
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError>;
+
+    /// Receiver phase with explicit UTXO selection.
+    /// Inputs:
+    /// - `avail_old_commit`, `pending_old_commit`: 0 or 32 bytes
+    /// - `utxos`: `(id, commitment)` of each UTXO the claim lists, in claim order
+    /// - `claim`: count(2) || ids(8 LE × count) || accept_envelope (see [`parse_claim`])
+    ///
+    /// The ids in `claim` must match `utxos` exactly and must not repeat; Σ of their
+    /// commitments must equal ΔC.
+    ///
+    /// Returns:
+    /// - (avail_new_commit, pending_new_commit, consumed_ids); the caller deletes `consumed_ids`
+    fn verify_claim(
+        asset: &[u8],
+        who_pk: &[u8],
+        avail_old_commit: &[u8],
+        pending_old_commit: &[u8],
+        utxos: &[(u64, [u8; 32])],
+        claim: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>, Vec<u64>), VerifyError> {
+        let (ids, accept_envelope) = parse_claim(claim)?;
+        if ids.is_empty() {
+            return Err(VerifyError::MalformedProof);
+        }
+        if ids.len() != utxos.len() || ids.iter().zip(utxos).any(|(id, (u, _))| id != u) {
+            return Err(VerifyError::PendingCommitMismatch);
+        }
+        if ids.iter().enumerate().any(|(i, id)| ids[..i].contains(id)) {
+            return Err(VerifyError::MalformedProof);
+        }
+        let commits: Vec<[u8; 32]> = utxos.iter().map(|(_, c)| *c).collect();
+        let (avail_new, pending_new) = Self::verify_transfer_received(
+            asset,
+            who_pk,
+            avail_old_commit,
+            pending_old_commit,
+            &commits,
+            accept_envelope,
+        )?;
+        Ok((avail_new, pending_new, ids))
+    }
 
     /// Mint: prove v ≥ 0, update pending(to) and total supply.
     /// The prover chooses a fresh ElGamal nonce for the minted ciphertext.
@@ -270,7 +878,7 @@ pub trait ZkVerifier {
         to_old_pending: &[u8],
         total_old: &[u8],
         proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), ()>;
+    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), VerifyError>;
 
     /// Burn: prove ciphertext encrypts v under `from_pk`, v ≥ 0,
     /// and update available(from) and total supply downward by v.
@@ -282,10 +890,174 @@ pub trait ZkVerifier {
         total_old: &[u8],
         amount_ciphertext: &EncryptedAmount,
         proof: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, u64), ()>;
+    ) -> Result<(Vec<u8>, Vec<u8>, u64), VerifyError>;
+
+    /// Confidential burn: as [`ZkVerifier::verify_burn`], but v stays hidden. Supply
+    /// shrinks homomorphically and nothing is released on the public side.
+    /// Returns (from_new_available_commit, total_new_commit).
+    /// The default rejects every proof.
+    fn verify_confidential_burn(
+        _asset: &[u8],
+        _from_pk: &PublicKeyBytes,
+        _from_old_available: &[u8],
+        _total_old: &[u8],
+        _amount_ciphertext: &EncryptedAmount,
+        _proof: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+
+    /// Account closure: verify that `avail` and `pending` (32B commitments; empty means
+    /// identity) both commit to zero for the holder of `who_pk`.
+    /// The default rejects every proof.
+    fn verify_zero_balance(
+        _asset: &[u8],
+        _who_pk: &[u8],
+        _avail: &[u8],
+        _pending: &[u8],
+        _proof: &[u8],
+    ) -> Result<(), VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+
+    /// Key rotation: verify that `account`, holder of `old_pk`, also holds `new_pk` and
+    /// that the ciphertext carried in `proof` encrypts the value committed in `avail`
+    /// (32B; empty means identity) under `new_pk`.
+    /// Returns that ciphertext, so the holder can recover the balance with the new key.
+    /// The default rejects every proof.
+    fn verify_rekey(
+        _asset: &[u8],
+        _account: &[u8],
+        _old_pk: &[u8],
+        _new_pk: &[u8],
+        _avail: &[u8],
+        _proof: &[u8],
+    ) -> Result<EncryptedAmount, VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+
+    /// Auditor handle: verify that `proof` carries a decrypt handle for `auditor_pk` that
+    /// shares the nonce of `ct` (64B, under `pk`), so the auditor can decrypt the same
+    /// value, and an encryption under `auditor_pk` of the blinding of the commitment the
+    /// operation moves, `delta.0 - delta.1` (32B each; empty means identity), e.g. the
+    /// sender's old and new available balance.
+    /// Returns `(ciphertext under auditor_pk, blinding ciphertext)`; the backend sums
+    /// the blinding ciphertexts so the auditor can open balances for
+    /// [`ZkVerifier::verify_seizure`].
+    /// The default rejects every proof.
+    fn verify_auditor_handle(
+        _asset: &[u8],
+        _pk: &[u8],
+        _auditor_pk: &[u8],
+        _ct: &EncryptedAmount,
+        _delta: (&[u8], &[u8]),
+        _proof: &[u8],
+    ) -> Result<(EncryptedAmount, EncryptedAmount), VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+
+    /// Seizure: verify that `ct` (64B, under `auditor_pk`) and the available balance
+    /// commitment `avail` (0 or 32 bytes) hide the same value, and return that value,
+    /// carried in `proof`. `blinding` is the backend's encryption of `avail`'s blinding
+    /// under `auditor_pk`, so the auditor's secret key is all the proof needs.
+    /// The default rejects every proof.
+    fn verify_seizure(
+        _asset: &[u8],
+        _auditor_pk: &[u8],
+        _avail: &[u8],
+        _blinding: &EncryptedAmount,
+        _ct: &EncryptedAmount,
+        _proof: &[u8],
+    ) -> Result<u64, VerifyError> {
+        Err(VerifyError::Unsupported)
+    }
+
+    /// Selective disclosure: verify that `cipher` (64B, under `who_pk`) decrypts to the
+    /// value carried in `proof`, and return that value.
+    /// The proof is produced by the key owner; its layout is verifier-defined.
+    fn disclose(
+        asset: &[u8],
+        who_pk: &[u8],
+        cipher: &[u8],
+        proof: &[u8],
+    ) -> Result<u64, VerifyError>;
+}
+
+/// Identifies the proof system a proof was produced for.
+///
+/// Proofs handed to a confidential backend are enveloped as `system_id(1) || body`, so
+/// a proof for one system is never parsed by another (see [`ProofSystem`]).
+pub type ProofSystemId = u8;
+
+/// Split a proof envelope into its proof-system id and body.
+pub fn open_proof(proof: &[u8]) -> Result<(ProofSystemId, &[u8]), VerifyError> {
+    let (id, body) = proof.split_first().ok_or(VerifyError::MalformedProof)?;
+    Ok((*id, body))
+}
 
-    /// Optional disclosure
-    fn disclose(asset: &[u8], who_pk: &[u8], cipher: &[u8]) -> Result<u64, Self::Error>;
+/// Envelope `body` for proof system `system`: `system(1) || body`.
+pub fn seal_proof(system: ProofSystemId, body: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(1 + body.len());
+    out.push(system);
+    out.extend_from_slice(body);
+    out
+}
+
+/// A [`ZkVerifier`] together with the id and proof sizes of the system it verifies.
+///
+/// ZK-ElGamal with Bulletproofs is one such system; another (e.g. Groth16 over BN254)
+/// implements this trait with its own [`ProofSystem::ID`] and is configured the same way.
+/// The backend checks the envelope id before any proof bytes reach the verifier.
+pub trait ProofSystem: ZkVerifier {
+    /// Envelope id of this proof system. Must be unique among systems a chain accepts.
+    const ID: ProofSystemId;
+
+    /// Inclusive `(min, max)` length of a `kind` proof body, or `None` if this system
+    /// has no such proof.
+    fn proof_len(kind: ProofKind) -> Option<(usize, usize)>;
+
+    /// Open an enveloped `kind` proof for this system and return its body.
+    ///
+    /// Checks the envelope id, [`ProofSystem::proof_len`] and
+    /// [`ZkVerifier::validate_shape`], in that order.
+    fn open(kind: ProofKind, proof: &[u8]) -> Result<&[u8], VerifyError> {
+        let (id, body) = open_proof(proof)?;
+        if id != Self::ID {
+            return Err(VerifyError::UnknownProofSystem);
+        }
+        let (min, max) = Self::proof_len(kind).ok_or(VerifyError::Unsupported)?;
+        if body.len() < min || body.len() > max {
+            return Err(VerifyError::MalformedProof);
+        }
+        Self::validate_shape(kind, body)?;
+        Ok(body)
+    }
+
+    /// Open an audited `kind` proof `main_envelope || auditor_envelope` and return both
+    /// bodies. The auditor envelope is the trailing `1 + len` bytes, where `len` is the
+    /// largest [`ProofKind::AuditorHandle`] body, so this system's auditor handles must
+    /// have a fixed length.
+    fn open_audited(kind: ProofKind, proof: &[u8]) -> Result<(&[u8], &[u8]), VerifyError> {
+        let (_, len) = Self::proof_len(ProofKind::AuditorHandle).ok_or(VerifyError::Unsupported)?;
+        let split = len
+            .checked_add(1)
+            .and_then(|n| proof.len().checked_sub(n))
+            .ok_or(VerifyError::MalformedProof)?;
+        let (main, auditor) = proof.split_at(split);
+        Ok((
+            Self::open(kind, main)?,
+            Self::open(ProofKind::AuditorHandle, auditor)?,
+        ))
+    }
+}
+
+/// A call that may carry confidential proofs, so they can be checked before dispatch,
+/// e.g. by a transaction extension when the call enters the pool.
+///
+/// Runtimes implement it for `RuntimeCall` by delegating to each pallet's `Call`.
+pub trait ProofCarrier {
+    /// The enveloped proofs the call carries, each with the kind the backend opens it as.
+    fn proofs(&self) -> Vec<(ProofKind, &[u8])>;
 }
 
 // Operator
@@ -313,7 +1085,18 @@ impl<AccountId, AssetId, BlockNumber> OperatorRegistry<AccountId, AssetId, Block
 
 // ACL
 
-#[derive(Clone, Copy, Encode, Decode, scale_info::TypeInfo)]
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    RuntimeDebug,
+)]
 pub enum Op {
     Mint,
     Burn,
@@ -325,14 +1108,38 @@ pub enum Op {
     SetOperator,
 }
 
-#[derive(Encode, Decode, scale_info::TypeInfo, Default)]
+/// What distinguishes one use of an [`Op`] from another.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo, RuntimeDebug, Default)]
+pub enum AclOpData {
+    #[default]
+    None,
+    /// Transfer `index` of a batch of `len` from the same sender.
+    Batch { index: u32, len: u32 },
+    /// Transfer handing the receiver call data with this blake2-256 hash.
+    Call { data_hash: [u8; 32] },
+    /// Transfer held in escrow until this block.
+    Scheduled { execute_at: u64 },
+    /// Claim of this many pending transfers.
+    Claim { count: u32 },
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo, RuntimeDebug, Default)]
 pub struct AclCtx<Balance, AccountId, AssetId> {
-    pub amount: Balance, // plaintext amount if relevant; 0 if not
+    pub amount: Balance, // plaintext amount if disclosed (shield/unshield); 0 if not
     pub asset: AssetId,
     pub caller: AccountId,               // origin who signed the extrinsic
     pub owner: Option<AccountId>,        // on-behalf-of (transfer_from etc.)
     pub counterparty: Option<AccountId>, // receiver/sender if applicable
-    pub opaque: sp_std::vec::Vec<u8>,    // future-proof (proof bytes, memo, etc.)
+    /// blake2-256 of the op's proof, if it carries one.
+    pub proof_hash: Option<[u8; 32]>,
+    /// Ciphertext of the amount the op moves, if known before it runs.
+    pub delta: Option<EncryptedAmount>,
+    /// Available balance commitment of the account debited, before the op.
+    pub from_commitment: Option<Commitment>,
+    /// Available balance commitment of the account credited, before the op.
+    pub to_commitment: Option<Commitment>,
+    pub data: AclOpData,
+    pub opaque: sp_std::vec::Vec<u8>, // future-proof (memo, etc.)
 }
 
 pub trait AclProvider<AccountId, AssetId, Balance> {
@@ -353,8 +1160,71 @@ impl<AccountId, AssetId, Balance> AclProvider<AccountId, AssetId, Balance> for (
 /// Minimal abstraction so runtimes can plug in pallet-xcm HRMP or any messenger.
 /// Implement this in the runtime using pallet-xcm's `SendXcm` or a custom adapter.
 pub trait HrmpMessenger {
-    /// Send an opaque SCALE-encoded payload to `dest_para`.
-    fn send(dest_para: u32, payload: Vec<u8>) -> Result<(), ()>;
+    /// How destinations name the asset their execution is paid in (e.g. an XCM
+    /// `Location`).
+    type FeeAsset: Parameter + MaxEncodedLen;
+
+    /// Send an opaque SCALE-encoded payload to `dest_para`, buying `fee`'s execution
+    /// there. `None` asks for unpaid execution.
+    fn send(
+        dest_para: u32,
+        payload: Vec<u8>,
+        fee: Option<ExecutionFee<Self::FeeAsset>>,
+    ) -> Result<(), ()>;
+    /// Send an encoded [`BridgeAck`] back to the `source_para` of a received packet.
+    /// Unlike `send`, this must land on the source's `handle_ack`.
+    fn send_ack(
+        dest_para: u32,
+        payload: Vec<u8>,
+        fee: Option<ExecutionFee<Self::FeeAsset>>,
+    ) -> Result<(), ()>;
+}
+
+/// Checks evidence that a destination processed a packet the way a [`BridgeAck`]
+/// says, e.g. a relay-chain state proof of its inbound record, so anyone can relay the
+/// ACK instead of waiting for the destination's message.
+pub trait AckVerifier {
+    fn verify(ack: &BridgeAck, evidence: &[u8]) -> bool;
+}
+
+/// No evidence is accepted: ACKs only arrive from the destination itself.
+impl AckVerifier for () {
+    fn verify(_ack: &BridgeAck, _evidence: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Exports bridge messages to Ethereum, e.g. through Snowbridge's outbound queue or a
+/// runtime's own `ExportXcm` route, so the bridge pallet needs no bridge types.
+pub trait MessageExporter {
+    /// Deliver an ABI-encoded payload to the bridge's Ethereum-side contract.
+    fn export(payload: Vec<u8>) -> Result<(), ()>;
+}
+
+/// No Ethereum bridge: every export fails.
+impl MessageExporter for () {
+    fn export(_payload: Vec<u8>) -> Result<(), ()> {
+        Err(())
+    }
+}
+
+/// Execution a bridge message buys on its destination: `amount` of `asset`, withdrawn
+/// from the sending chain's account there, for up to `weight_limit`.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    RuntimeDebug,
+)]
+pub struct ExecutionFee<FeeAsset> {
+    pub asset: FeeAsset,
+    pub amount: u128,
+    pub weight_limit: Weight,
 }
 
 /// Unique id for each outbound transfer.
@@ -375,6 +1245,11 @@ pub struct BridgePacket<AccountId, AssetId> {
     pub encrypted_amount: EncryptedAmount,
     /// Opaque "accept/credit" envelope/proof for the destination backend.
     pub accept_envelope: InputProof,
+    /// Para id of the source chain; with `transfer_id` it identifies the packet.
+    pub source_para: u32,
+    /// Position among the packets the source handed to its messenger for this
+    /// destination, counting from 0.
+    pub sequence: u64,
 }
 
 /// Internal ledger of a pending outbound transfer.
@@ -389,10 +1264,75 @@ pub struct PendingTransfer<AccountId, AssetId, BlockNumber> {
     pub deadline: BlockNumber,
     /// True once a finalize path (success or refund) executed.
     pub completed: bool,
+    /// Escrow lock holding `encrypted_amount` until then.
+    pub escrow_id: EscrowId,
+    /// `None` until the destination acknowledges the packet, then whether it minted.
+    pub delivered: Option<bool>,
+}
+
+/// Where an outbound bridge transfer stands, as the source chain sees it.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    RuntimeDebug,
+)]
+pub enum BridgeTransferStatus {
+    /// Escrowed, waiting for the destination's ACK or for the sender to settle it.
+    Pending,
+    /// The destination minted it and the escrow was burned.
+    Confirmed,
+    /// The escrow went back to the sender.
+    Refunded,
+    /// Refundable: the destination failed to mint it, or its deadline passed without
+    /// a success ACK.
+    Expired,
+}
+
+/// Acknowledgment the destination sends back for each [`BridgePacket`] it processes.
+#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct BridgeAck {
+    /// Transfer id of the acknowledged packet (source side).
+    pub transfer_id: TransferId,
+    /// Para id of the chain that processed the packet.
+    pub dest_para: u32,
+    /// Whether the destination minted the transfer.
+    pub success: bool,
 }
 
 // Confidential cross-chain atomic swaps (see examples/confidential-xcm-bridge)
 
+/// When an HTLC expires; refunds become valid from then on. Counterparty chains
+/// count timeouts in seconds, not in this chain's blocks, so a wall-clock deadline can
+/// be given directly.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    RuntimeDebug,
+)]
+pub enum Expiry<BlockNumber> {
+    /// At this block.
+    AtBlock(BlockNumber),
+    /// This many blocks after the HTLC opens; stored as the [`Expiry::AtBlock`] it
+    /// resolves to.
+    AfterBlocks(BlockNumber),
+    /// At this Unix time, in seconds.
+    AtTime(u64),
+}
+
 pub trait BridgeHtlc<AccountId, AssetId, Amount> {
     type HashLock;
     type Secret;
@@ -404,8 +1344,8 @@ pub trait BridgeHtlc<AccountId, AssetId, Amount> {
         asset: AssetId,
         amount: Amount,
         hashlock: Self::HashLock,
-        // absolute expiry block; refunds become valid at `>= expiry`
-        expiry: u32,
+        // refunds become valid once it passes
+        expiry: Expiry<u32>,
         // Optional partial/adaptor signature commitment (for adaptor flow).
         adaptor_partial: Option<Vec<u8>>,
     ) -> Result<u64, DispatchError>;
@@ -17,6 +17,11 @@ extern crate alloc;
 
 use core::fmt;
 
+use alloc::vec::Vec;
+use confidential_assets_primitives::NetworkIdProvider;
+pub use confidential_assets_primitives::{
+    DEFAULT_RANGE_BITS, ProofKind, ProofSystemId, SUPPORTED_RANGE_BITS, is_supported_range_bits,
+};
 use curve25519_dalek::{
     ristretto::{CompressedRistretto, RistrettoPoint},
     scalar::Scalar,
@@ -32,6 +37,18 @@ pub trait RangeProofVerifier {
         commit_compressed: &[u8; 32],
         proof_bytes: &[u8],
     ) -> Result<(), ()>;
+
+    /// Verify one aggregated Bulletproof covering every commitment in `commits`.
+    ///
+    /// Verifiers that do not support aggregation reject.
+    fn verify_aggregated_range_proof(
+        _transcript_label: &[u8],
+        _context: &[u8],
+        _commits: &[[u8; 32]],
+        _proof_bytes: &[u8],
+    ) -> Result<(), ()> {
+        Err(())
+    }
 }
 
 /// 32-byte compressed Ristretto encoding.
@@ -40,10 +57,18 @@ pub type CompressedPoint = [u8; 32];
 /// 32-byte scalar encoding (canonical preferred).
 pub type ScalarBytes = [u8; 32];
 
+/// Envelope id of ZK-ElGamal + Bulletproof proofs
+/// (see `confidential_assets_primitives::ProofSystem`).
+pub const PROOF_SYSTEM_ID: ProofSystemId = 0;
+
 /// Version/tag this protocol instance. Bump on any incompatible change.
 pub const SDK_VERSION: u32 = 1;
 
 /// Domain / label strings — KEEP STABLE.
+///
+/// Every transcript label used by the prover and verifier is defined here exactly
+/// once. [`labels::ALL`] lists them and a compile-time check rejects duplicates, so
+/// two proof elements can never share a label by accident.
 pub mod labels {
     pub const PROTOCOL: &[u8] = b"zk-elgamal-conf-xfer";
     pub const PROTOCOL_V: &[u8] = b"zk-elgamal-conf-xfer/v1";
@@ -56,6 +81,214 @@ pub mod labels {
     // challenge labels
     pub const CHAL_CVP: &[u8] = b"cvp_chal";
     pub const CHAL_EQ: &[u8] = b"eq_chal";
+
+    // network id derivation
+    pub const NETWORK_ID: &[u8] = b"zkhe/network-id/v1";
+
+    // selective disclosure
+    pub const DISCLOSE: &[u8] = b"zk-elgamal-disclose";
+    pub const CHAL_DISCLOSE: &[u8] = b"disclose_chal";
+
+    // public key registration
+    pub const PK_OWNERSHIP: &[u8] = b"zk-elgamal-pk-ownership";
+    pub const CHAL_PK_OWNERSHIP: &[u8] = b"pk_ownership_chal";
+
+    // burn without amount disclosure
+    pub const CONFIDENTIAL_BURN: &[u8] = b"zk-elgamal-confidential-burn";
+
+    // one debit split across several recipients
+    pub const TRANSFER_SPLIT: &[u8] = b"zk-elgamal-transfer-split";
+
+    // account closure
+    pub const ZERO_BALANCE: &[u8] = b"zk-elgamal-zero-balance";
+    pub const CHAL_ZERO_BALANCE: &[u8] = b"zero_balance_chal";
+
+    // key rotation
+    pub const REKEY: &[u8] = b"zk-elgamal-rekey";
+    pub const CHAL_REKEY: &[u8] = b"rekey_chal";
+
+    // seizure of an audited balance
+    pub const SEIZURE: &[u8] = b"zk-elgamal-seizure";
+    pub const CHAL_SEIZURE: &[u8] = b"seizure_chal";
+
+    // auditor decrypt handles
+    pub const AUDITOR: &[u8] = b"zk-elgamal-auditor";
+    pub const CHAL_AUDITOR: &[u8] = b"auditor_chal";
+
+    // Pedersen H generator (hash-to-point input)
+    pub const PEDERSEN_H: &[u8] = b"Zether/PedersenH";
+
+    // public context binding
+    pub const BIND_PROTO: &[u8] = b"proto";
+    pub const BIND_SDK_VERSION: &[u8] = b"sdk_version";
+    pub const BIND_NETWORK_ID: &[u8] = b"network_id";
+    pub const BIND_ASSET_ID: &[u8] = b"asset_id";
+    pub const BIND_SENDER_PK: &[u8] = b"sender_pk";
+    pub const BIND_RECEIVER_PK: &[u8] = b"receiver_pk";
+    pub const BIND_AUDITOR_PK: &[u8] = b"auditor_pk";
+    pub const BIND_FEE_C: &[u8] = b"fee_C";
+    pub const BIND_OUT_C: &[u8] = b"out_C";
+    pub const BIND_OUT_D: &[u8] = b"out_D";
+    pub const BIND_IN_C: &[u8] = b"in_C";
+    pub const BIND_IN_D: &[u8] = b"in_D";
+    pub const BIND_IN_CIPHERTEXT: &[u8] = b"in_ciphertext";
+    pub const BIND_AVAIL_OLD: &[u8] = b"avail_old";
+    pub const BIND_PENDING_OLD: &[u8] = b"pending_old";
+    pub const BIND_DELTA_COMM: &[u8] = b"delta_comm";
+    pub const BIND_PK: &[u8] = b"pk";
+    pub const BIND_C: &[u8] = b"C";
+    pub const BIND_D: &[u8] = b"D";
+    pub const BIND_VALUE: &[u8] = b"value";
+    pub const BIND_ACCOUNT: &[u8] = b"account";
+    pub const BIND_NEW_PK: &[u8] = b"new_pk";
+    pub const BIND_AUDITOR_D: &[u8] = b"auditor_D";
+    pub const BIND_BLINDING_C: &[u8] = b"blinding_C";
+    pub const BIND_BLINDING_D: &[u8] = b"blinding_D";
+
+    // Σ-proof commitments
+    pub const SIGMA_A1: &[u8] = b"a1";
+    pub const SIGMA_A2: &[u8] = b"a2";
+    pub const SIGMA_A3: &[u8] = b"a3";
+    pub const SIGMA_A4: &[u8] = b"a4";
+    pub const SIGMA_A5: &[u8] = b"a5";
+
+    /// Context bytes: challenged from the Σ transcript, then fed to the range transcript.
+    pub const CTX: &[u8] = b"ctx";
+
+    // Bulletproof transcript
+    pub const RANGE_TRANSCRIPT: &[u8] = b"bp64";
+    pub const RANGE_LABEL: &[u8] = b"label";
+    pub const RANGE_COMMIT: &[u8] = b"commit";
+    pub const WITNESS_VALUE: &[u8] = b"witness_value";
+    pub const WITNESS_BLIND: &[u8] = b"witness_blind";
+    /// Range-proof bit width, bound before `CTX` when it is not the default.
+    pub const RANGE_BITS: &[u8] = b"range_bits";
+
+    // range proof slots (one per committed value)
+    pub const RANGE_FROM_NEW: &[u8] = b"range_from_new";
+    pub const RANGE_TO_NEW: &[u8] = b"range_to_new";
+    pub const RANGE_AVAIL_NEW: &[u8] = b"range_avail_new";
+    pub const RANGE_PENDING_NEW: &[u8] = b"range_pending_new";
+    pub const RANGE_TO_PENDING_NEW: &[u8] = b"range_to_pending_new";
+    pub const RANGE_FROM_AVAIL_NEW: &[u8] = b"range_from_avail_new";
+    pub const RANGE_TOTAL_NEW: &[u8] = b"range_total_new";
+    pub const RANGE_ACCEPT_AGG: &[u8] = b"range_accept_agg";
+    pub const RANGE_MINT_AGG: &[u8] = b"range_mint_agg";
+    pub const RANGE_BURN_AGG: &[u8] = b"range_burn_agg";
+    pub const RANGE_CONFIDENTIAL_BURN_AGG: &[u8] = b"range_confidential_burn_agg";
+    /// Transfer amount minus the asset's transfer floor.
+    pub const RANGE_AMOUNT_FLOOR: &[u8] = b"range_amount_floor";
+    /// Split output amount minus the asset's transfer floor.
+    pub const RANGE_SPLIT_OUTPUT: &[u8] = b"range_split_output";
+
+    /// Registry of every label above.
+    pub const ALL: &[&[u8]] = &[
+        PROTOCOL,
+        PROTOCOL_V,
+        SECTION_CVP,
+        SECTION_EQ,
+        SECTION_RP,
+        CHAL_CVP,
+        CHAL_EQ,
+        NETWORK_ID,
+        DISCLOSE,
+        CHAL_DISCLOSE,
+        PK_OWNERSHIP,
+        CHAL_PK_OWNERSHIP,
+        CONFIDENTIAL_BURN,
+        TRANSFER_SPLIT,
+        ZERO_BALANCE,
+        CHAL_ZERO_BALANCE,
+        REKEY,
+        CHAL_REKEY,
+        SEIZURE,
+        CHAL_SEIZURE,
+        AUDITOR,
+        CHAL_AUDITOR,
+        PEDERSEN_H,
+        BIND_PROTO,
+        BIND_SDK_VERSION,
+        BIND_NETWORK_ID,
+        BIND_ASSET_ID,
+        BIND_SENDER_PK,
+        BIND_RECEIVER_PK,
+        BIND_AUDITOR_PK,
+        BIND_FEE_C,
+        BIND_OUT_C,
+        BIND_OUT_D,
+        BIND_IN_C,
+        BIND_IN_D,
+        BIND_IN_CIPHERTEXT,
+        BIND_AVAIL_OLD,
+        BIND_PENDING_OLD,
+        BIND_DELTA_COMM,
+        BIND_PK,
+        BIND_C,
+        BIND_D,
+        BIND_VALUE,
+        BIND_ACCOUNT,
+        BIND_NEW_PK,
+        BIND_AUDITOR_D,
+        BIND_BLINDING_C,
+        BIND_BLINDING_D,
+        SIGMA_A1,
+        SIGMA_A2,
+        SIGMA_A3,
+        SIGMA_A4,
+        SIGMA_A5,
+        CTX,
+        RANGE_TRANSCRIPT,
+        RANGE_LABEL,
+        RANGE_COMMIT,
+        WITNESS_VALUE,
+        WITNESS_BLIND,
+        RANGE_BITS,
+        RANGE_FROM_NEW,
+        RANGE_TO_NEW,
+        RANGE_AVAIL_NEW,
+        RANGE_PENDING_NEW,
+        RANGE_TO_PENDING_NEW,
+        RANGE_FROM_AVAIL_NEW,
+        RANGE_TOTAL_NEW,
+        RANGE_ACCEPT_AGG,
+        RANGE_MINT_AGG,
+        RANGE_BURN_AGG,
+        RANGE_CONFIDENTIAL_BURN_AGG,
+        RANGE_AMOUNT_FLOOR,
+        RANGE_SPLIT_OUTPUT,
+    ];
+
+    const _: () = assert!(all_distinct(ALL), "duplicate transcript label");
+
+    /// Whether no two entries of `labels` are byte-equal.
+    pub const fn all_distinct(labels: &[&[u8]]) -> bool {
+        let mut i = 0;
+        while i < labels.len() {
+            let mut j = i + 1;
+            while j < labels.len() {
+                if bytes_eq(labels[i], labels[j]) {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut k = 0;
+        while k < a.len() {
+            if a[k] != b[k] {
+                return false;
+            }
+            k += 1;
+        }
+        true
+    }
 }
 
 /// Minimal Pedersen parameter bag. You decide how to source these (deterministic hash-to-point, fixed constants, etc.).
@@ -138,27 +371,27 @@ impl PublicContext {
     /// Bind this context into a Merlin transcript in a stable, canonical order.
     pub fn bind_to_transcript(&self, t: &mut Transcript) {
         use crate::labels::*;
-        t.append_message(b"proto", PROTOCOL_V);
-        t.append_message(b"sdk_version", &self.sdk_version.to_le_bytes());
-        t.append_message(b"network_id", &self.network_id);
-        t.append_message(b"asset_id", &self.asset_id);
+        t.append_message(BIND_PROTO, PROTOCOL_V);
+        t.append_message(BIND_SDK_VERSION, &self.sdk_version.to_le_bytes());
+        t.append_message(BIND_NETWORK_ID, &self.network_id);
+        t.append_message(BIND_ASSET_ID, &self.asset_id);
 
-        append_point(t, b"sender_pk", &self.sender_pk);
-        append_point(t, b"receiver_pk", &self.receiver_pk);
+        append_point(t, BIND_SENDER_PK, &self.sender_pk);
+        append_point(t, BIND_RECEIVER_PK, &self.receiver_pk);
         match self.auditor_pk {
-            Some(pk) => append_point(t, b"auditor_pk", &pk),
-            None => t.append_message(b"auditor_pk", b"none"),
+            Some(pk) => append_point(t, BIND_AUDITOR_PK, &pk),
+            None => t.append_message(BIND_AUDITOR_PK, b"none"),
         }
 
-        append_point(t, b"fee_C", &self.fee_commitment);
-        append_point(t, b"out_C", &self.ciphertext_out.C);
-        append_point(t, b"out_D", &self.ciphertext_out.D);
+        append_point(t, BIND_FEE_C, &self.fee_commitment);
+        append_point(t, BIND_OUT_C, &self.ciphertext_out.C);
+        append_point(t, BIND_OUT_D, &self.ciphertext_out.D);
 
         if let Some(cin) = &self.ciphertext_in {
-            append_point(t, b"in_C", &cin.C);
-            append_point(t, b"in_D", &cin.D);
+            append_point(t, BIND_IN_C, &cin.C);
+            append_point(t, BIND_IN_D, &cin.D);
         } else {
-            t.append_message(b"in_ciphertext", b"absent");
+            t.append_message(BIND_IN_CIPHERTEXT, b"absent");
         }
     }
 }
@@ -189,6 +422,164 @@ pub fn new_transcript(ctx: &PublicContext) -> Transcript {
     t
 }
 
+/// Transcript for a disclosure proof that `ct` (under `pk`) decrypts to `value`.
+///
+/// The proof is a Chaum–Pedersen DLEQ: the same `sk` links `pk = sk·G` and
+/// `D - value·G = sk·C`.
+pub fn disclosure_transcript(
+    network_id: &[u8; 32],
+    asset_id: &[u8; 32],
+    pk: &RistrettoPoint,
+    ct: &Ciphertext,
+    value: u64,
+) -> Transcript {
+    let mut t = Transcript::new(labels::DISCLOSE);
+    t.append_message(labels::BIND_PROTO, labels::PROTOCOL_V);
+    t.append_message(labels::BIND_SDK_VERSION, &SDK_VERSION.to_le_bytes());
+    t.append_message(labels::BIND_NETWORK_ID, network_id);
+    t.append_message(labels::BIND_ASSET_ID, asset_id);
+    append_point(&mut t, labels::BIND_PK, pk);
+    append_point(&mut t, labels::BIND_C, &ct.C);
+    append_point(&mut t, labels::BIND_D, &ct.D);
+    t.append_message(labels::BIND_VALUE, &value.to_le_bytes());
+    t
+}
+
+/// Transcript for a Schnorr proof that the registrant of `pk` for `account` knows `sk`
+/// with `pk = sk·G`.
+///
+/// Binding `account` stops a proof observed on-chain from registering the same key for
+/// another account.
+pub fn pk_ownership_transcript(
+    network_id: &[u8; 32],
+    account: &[u8],
+    pk: &RistrettoPoint,
+) -> Transcript {
+    let mut t = Transcript::new(labels::PK_OWNERSHIP);
+    t.append_message(labels::BIND_PROTO, labels::PROTOCOL_V);
+    t.append_message(labels::BIND_SDK_VERSION, &SDK_VERSION.to_le_bytes());
+    t.append_message(labels::BIND_NETWORK_ID, network_id);
+    t.append_message(labels::BIND_ACCOUNT, account);
+    append_point(&mut t, labels::BIND_PK, pk);
+    t
+}
+
+/// Transcript for a proof that the `avail` and `pending` commitments of `pk`'s holder
+/// both open to zero, i.e. are multiples of `H` alone.
+pub fn zero_balance_transcript(
+    network_id: &[u8; 32],
+    asset_id: &[u8; 32],
+    pk: &RistrettoPoint,
+    avail: &RistrettoPoint,
+    pending: &RistrettoPoint,
+) -> Transcript {
+    let mut t = Transcript::new(labels::ZERO_BALANCE);
+    t.append_message(labels::BIND_PROTO, labels::PROTOCOL_V);
+    t.append_message(labels::BIND_SDK_VERSION, &SDK_VERSION.to_le_bytes());
+    t.append_message(labels::BIND_NETWORK_ID, network_id);
+    t.append_message(labels::BIND_ASSET_ID, asset_id);
+    append_point(&mut t, labels::BIND_PK, pk);
+    append_point(&mut t, labels::BIND_AVAIL_OLD, avail);
+    append_point(&mut t, labels::BIND_PENDING_OLD, pending);
+    t
+}
+
+/// Context bytes for the range proofs of a `bits`-wide asset, challenged from `t`.
+///
+/// The width is bound only when it differs from [`DEFAULT_RANGE_BITS`], so 64-bit
+/// proofs keep the transcript they had before widths were configurable.
+pub fn range_context_bytes(t: &Transcript, bits: u8) -> [u8; 32] {
+    let mut t = t.clone();
+    if bits != DEFAULT_RANGE_BITS {
+        t.append_message(labels::RANGE_BITS, &[bits]);
+    }
+    let mut out = [0u8; 32];
+    t.challenge_bytes(labels::CTX, &mut out);
+    out
+}
+
+/// Transcript for a key rotation by `account`: the holder of `old_pk` knows the secret
+/// of `new_pk`, and `ct` encrypts the value committed in `avail` under `new_pk`.
+pub fn rekey_transcript(
+    network_id: &[u8; 32],
+    account: &[u8],
+    asset_id: &[u8; 32],
+    old_pk: &RistrettoPoint,
+    new_pk: &RistrettoPoint,
+    avail: &RistrettoPoint,
+    ct: &Ciphertext,
+) -> Transcript {
+    let mut t = Transcript::new(labels::REKEY);
+    t.append_message(labels::BIND_PROTO, labels::PROTOCOL_V);
+    t.append_message(labels::BIND_SDK_VERSION, &SDK_VERSION.to_le_bytes());
+    t.append_message(labels::BIND_NETWORK_ID, network_id);
+    t.append_message(labels::BIND_ACCOUNT, account);
+    t.append_message(labels::BIND_ASSET_ID, asset_id);
+    append_point(&mut t, labels::BIND_PK, old_pk);
+    append_point(&mut t, labels::BIND_NEW_PK, new_pk);
+    append_point(&mut t, labels::BIND_AVAIL_OLD, avail);
+    append_point(&mut t, labels::BIND_C, &ct.C);
+    append_point(&mut t, labels::BIND_D, &ct.D);
+    t
+}
+
+/// Transcript for a proof that `auditor_d` is the decrypt handle of `ct` (under `pk`)
+/// for `auditor_pk`, both ciphertexts sharing `C` and its nonce, and that `blinding`
+/// encrypts the blinding of the commitment `delta` under `auditor_pk`.
+#[allow(clippy::too_many_arguments)]
+pub fn auditor_transcript(
+    network_id: &[u8; 32],
+    asset_id: &[u8; 32],
+    pk: &RistrettoPoint,
+    auditor_pk: &RistrettoPoint,
+    ct: &Ciphertext,
+    auditor_d: &RistrettoPoint,
+    delta: &RistrettoPoint,
+    blinding: &Ciphertext,
+) -> Transcript {
+    let mut t = Transcript::new(labels::AUDITOR);
+    t.append_message(labels::BIND_PROTO, labels::PROTOCOL_V);
+    t.append_message(labels::BIND_SDK_VERSION, &SDK_VERSION.to_le_bytes());
+    t.append_message(labels::BIND_NETWORK_ID, network_id);
+    t.append_message(labels::BIND_ASSET_ID, asset_id);
+    append_point(&mut t, labels::BIND_PK, pk);
+    append_point(&mut t, labels::BIND_AUDITOR_PK, auditor_pk);
+    append_point(&mut t, labels::BIND_C, &ct.C);
+    append_point(&mut t, labels::BIND_D, &ct.D);
+    append_point(&mut t, labels::BIND_AUDITOR_D, auditor_d);
+    append_point(&mut t, labels::BIND_DELTA_COMM, delta);
+    append_point(&mut t, labels::BIND_BLINDING_C, &blinding.C);
+    append_point(&mut t, labels::BIND_BLINDING_D, &blinding.D);
+    t
+}
+
+/// Transcript for a proof that `ct` hides `value` under `auditor_pk`, and that the
+/// available balance commitment `avail` does too, given `blinding`, the auditor's
+/// encryption of its blinding.
+pub fn seizure_transcript(
+    network_id: &[u8; 32],
+    asset_id: &[u8; 32],
+    auditor_pk: &RistrettoPoint,
+    avail: &RistrettoPoint,
+    blinding: &Ciphertext,
+    ct: &Ciphertext,
+    value: u64,
+) -> Transcript {
+    let mut t = Transcript::new(labels::SEIZURE);
+    t.append_message(labels::BIND_PROTO, labels::PROTOCOL_V);
+    t.append_message(labels::BIND_SDK_VERSION, &SDK_VERSION.to_le_bytes());
+    t.append_message(labels::BIND_NETWORK_ID, network_id);
+    t.append_message(labels::BIND_ASSET_ID, asset_id);
+    append_point(&mut t, labels::BIND_AUDITOR_PK, auditor_pk);
+    append_point(&mut t, labels::BIND_AVAIL_OLD, avail);
+    append_point(&mut t, labels::BIND_BLINDING_C, &blinding.C);
+    append_point(&mut t, labels::BIND_BLINDING_D, &blinding.D);
+    append_point(&mut t, labels::BIND_C, &ct.C);
+    append_point(&mut t, labels::BIND_D, &ct.D);
+    t.append_message(labels::BIND_VALUE, &value.to_le_bytes());
+    t
+}
+
 /// Append a compressed Ristretto point under a label.
 pub fn append_point(t: &mut Transcript, label: &'static [u8], p: &RistrettoPoint) {
     t.append_message(label, p.compress().as_bytes());
@@ -252,7 +643,46 @@ pub fn scalar_to_bytes(x: &Scalar) -> ScalarBytes {
 /// ```
 pub fn pedersen_h_generator() -> RistrettoPoint {
     use sha2::Sha512;
-    RistrettoPoint::hash_from_bytes::<Sha512>(b"Zether/PedersenH")
+    RistrettoPoint::hash_from_bytes::<Sha512>(labels::PEDERSEN_H)
+}
+
+/// Network identifier bound into every proof transcript.
+///
+/// `network_id = sha256(labels::NETWORK_ID || genesis_hash || para_id_le)`. Clients
+/// compute it once per chain and pass it as `network_id` to the prover; the runtime
+/// derives the same value via [`GenesisNetworkId`].
+pub fn derive_network_id(genesis_hash: &[u8; 32], para_id: u32) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+    let mut h = Sha256::new();
+    h.update(labels::NETWORK_ID);
+    h.update(genesis_hash);
+    h.update(para_id.to_le_bytes());
+    h.finalize().into()
+}
+
+/// [`NetworkIdProvider`] sourced from the chain's genesis hash and parachain id.
+///
+/// `P` is typically `ParachainInfo` with `Id = ParaId`. The genesis hash is read from
+/// `frame_system::BlockHash[0]`, which holds the real hash from block 1 onwards.
+pub struct GenesisNetworkId<T, P, Id = u32>(core::marker::PhantomData<(T, P, Id)>);
+
+impl<T, P, Id> NetworkIdProvider for GenesisNetworkId<T, P, Id>
+where
+    T: frame_system::Config,
+    P: frame_support::traits::Get<Id>,
+    Id: Into<u32>,
+{
+    fn network_id() -> [u8; 32] {
+        use sp_runtime::traits::Zero;
+        let genesis = frame_system::Pallet::<T>::block_hash(
+            frame_system::pallet_prelude::BlockNumberFor::<T>::zero(),
+        );
+        let mut genesis_hash = [0u8; 32];
+        let bytes = genesis.as_ref();
+        let n = bytes.len().min(32);
+        genesis_hash[..n].copy_from_slice(&bytes[..n]);
+        derive_network_id(&genesis_hash, P::get().into())
+    }
 }
 
 /// Concatenate two compressed points (e.g., for fixed-size proof parts).
@@ -277,6 +707,237 @@ pub const CVP_PROOF_LEN: usize = 128;
 /// R(32) || s_v(32) || s_r(32) => total 96 bytes.
 pub const EQ_PROOF_LEN: usize = 96;
 
+/// Disclosure proof bytes:
+/// value_le(8) || A1(32) || A2(32) || z(32) => total 104 bytes.
+pub const DISCLOSURE_PROOF_LEN: usize = 104;
+
+/// Public key ownership proof bytes:
+/// A(32) || z(32) => total 64 bytes.
+pub const PK_OWNERSHIP_PROOF_LEN: usize = 64;
+
+/// Zero-balance proof bytes:
+/// A_avail(32) || A_pending(32) || z_avail(32) || z_pending(32) => total 128 bytes.
+pub const ZERO_BALANCE_PROOF_LEN: usize = 128;
+
+/// Rekey proof bytes:
+/// A_old(32) || A_new(32) || z_old(32) || z_new(32) || rekeyed_ct(64) || link(192)
+/// => total 384 bytes.
+pub const REKEY_PROOF_LEN: usize = 128 + DELTA_CT_LEN + LINK_PROOF_LEN;
+
+/// Auditor handle proof bytes:
+/// D_auditor(32) || A1(32) || A2(32) || z(32) ||
+/// blinding_C(32) || blinding_D(32) || A3(32) || A4(32) || A5(32) || z_v(32) || z_r(32) ||
+/// z_b(32) => total 384 bytes.
+pub const AUDITOR_PROOF_LEN: usize = 384;
+
+/// Seizure proof bytes:
+/// value_le(8) || A1(32) || A2(32) || A3(32) || z(32) => total 136 bytes.
+pub const SEIZURE_PROOF_LEN: usize = 136;
+
+/// Compressed Ristretto point / Pedersen commitment.
+pub const COMMITMENT_LEN: usize = 32;
+
+/// ElGamal ciphertext bytes: C(32) || D(32).
+pub const DELTA_CT_LEN: usize = 64;
+
+/// Link proof bytes:
+/// A1(32) || A2(32) || A3(32) || z_k(32) || z_v(32) || z_r(32) => total 192 bytes.
+pub const LINK_PROOF_LEN: usize = 192;
+
+/// A single 64-bit Bulletproof.
+pub const RANGE_PROOF_LEN: usize = 672;
+
+/// Length of one Bulletproof over `values` commitments of `bits` bits each:
+/// `32 × (9 + 2·log2(bits × values))`.
+pub const fn range_proof_len(bits: u8, values: usize) -> usize {
+    32 * (9 + 2 * (bits as usize * values).ilog2() as usize)
+}
+
+/// Largest range section in any proof: a 2-value aggregated Bulletproof.
+pub const MAX_RANGE_PROOF_LEN: usize = 736;
+
+const _: () = assert!(RANGE_PROOF_LEN == range_proof_len(64, 1));
+const _: () = assert!(MAX_RANGE_PROOF_LEN == range_proof_len(64, 2));
+
+/// Each proof carries two `len(2) || range_proof` sections.
+const RANGE_SECTIONS_LEN: usize = 2 + 2;
+const RANGE_SECTIONS_MAX_LEN: usize = RANGE_SECTIONS_LEN + 2 * MAX_RANGE_PROOF_LEN;
+
+/// Sender bundle: delta_comm(32) || link(192) || len1(2) || rp_from || len2(2) || rp_to,
+/// optionally followed by len3(2) || rp_floor proving the amount is at least the
+/// asset's transfer floor.
+pub const TRANSFER_PROOF_MIN_LEN: usize = COMMITMENT_LEN + LINK_PROOF_LEN + RANGE_SECTIONS_LEN;
+pub const TRANSFER_PROOF_MAX_LEN: usize =
+    COMMITMENT_LEN + LINK_PROOF_LEN + RANGE_SECTIONS_MAX_LEN + 2 + RANGE_PROOF_LEN;
+
+/// Accept envelope: delta_comm(32) || len1(2) || rp_avail || len2(2) || rp_pending.
+pub const ACCEPT_ENVELOPE_MIN_LEN: usize = COMMITMENT_LEN + RANGE_SECTIONS_LEN;
+pub const ACCEPT_ENVELOPE_MAX_LEN: usize = COMMITMENT_LEN + RANGE_SECTIONS_MAX_LEN;
+
+/// Claim: count(2) || ids(8 × count, count ≥ 1) || accept envelope.
+pub const CLAIM_MIN_LEN: usize = 2 + 8 + ACCEPT_ENVELOPE_MIN_LEN;
+pub const CLAIM_MAX_LEN: usize = 2 + 8 * u16::MAX as usize + ACCEPT_ENVELOPE_MAX_LEN;
+
+/// Mint: minted_ct(64) || delta_comm(32) || link(192) || len1(2) || rp || len2(2) || rp.
+pub const MINT_PROOF_MIN_LEN: usize =
+    DELTA_CT_LEN + COMMITMENT_LEN + LINK_PROOF_LEN + RANGE_SECTIONS_LEN;
+pub const MINT_PROOF_MAX_LEN: usize =
+    DELTA_CT_LEN + COMMITMENT_LEN + LINK_PROOF_LEN + RANGE_SECTIONS_MAX_LEN;
+
+/// Burn: delta_comm(32) || link(192) || len1(2) || rp || len2(2) || rp || amount_le(8).
+pub const BURN_PROOF_MIN_LEN: usize = COMMITMENT_LEN + LINK_PROOF_LEN + RANGE_SECTIONS_LEN + 8;
+pub const BURN_PROOF_MAX_LEN: usize = COMMITMENT_LEN + LINK_PROOF_LEN + RANGE_SECTIONS_MAX_LEN + 8;
+
+/// Confidential burn: a burn proof without the trailing amount.
+pub const CONFIDENTIAL_BURN_PROOF_MIN_LEN: usize = BURN_PROOF_MIN_LEN - 8;
+pub const CONFIDENTIAL_BURN_PROOF_MAX_LEN: usize = BURN_PROOF_MAX_LEN - 8;
+
+/// One split part: a commitment and the link proof tying it to a ciphertext.
+pub const SPLIT_PART_LEN: usize = COMMITMENT_LEN + LINK_PROOF_LEN;
+
+/// Split transfer: count(2) || total_comm(32) || link(192) || count × (out_comm(32) ||
+/// link(192)) || len(2) || rp_from_new || count × (len(2) || rp_out), with count ≥ 1.
+pub const TRANSFER_SPLIT_PROOF_MIN_LEN: usize = 2 + 2 * (SPLIT_PART_LEN + 2);
+pub const TRANSFER_SPLIT_PROOF_MAX_LEN: usize =
+    2 + (1 + u16::MAX as usize) * (SPLIT_PART_LEN + 2 + RANGE_PROOF_LEN);
+
+/// Inclusive `(min, max)` length of a `kind` proof body, or `None` if this system has
+/// no such proof.
+pub fn proof_len(kind: ProofKind) -> Option<(usize, usize)> {
+    Some(match kind {
+        ProofKind::Transfer => (TRANSFER_PROOF_MIN_LEN, TRANSFER_PROOF_MAX_LEN),
+        ProofKind::Accept => (ACCEPT_ENVELOPE_MIN_LEN, ACCEPT_ENVELOPE_MAX_LEN),
+        ProofKind::Claim => (CLAIM_MIN_LEN, CLAIM_MAX_LEN),
+        ProofKind::Mint => (MINT_PROOF_MIN_LEN, MINT_PROOF_MAX_LEN),
+        ProofKind::Burn => (BURN_PROOF_MIN_LEN, BURN_PROOF_MAX_LEN),
+        ProofKind::ConfidentialBurn => (
+            CONFIDENTIAL_BURN_PROOF_MIN_LEN,
+            CONFIDENTIAL_BURN_PROOF_MAX_LEN,
+        ),
+        ProofKind::Disclosure => (DISCLOSURE_PROOF_LEN, DISCLOSURE_PROOF_LEN),
+        ProofKind::PubkeyOwnership => (PK_OWNERSHIP_PROOF_LEN, PK_OWNERSHIP_PROOF_LEN),
+        ProofKind::ZeroBalance => (ZERO_BALANCE_PROOF_LEN, ZERO_BALANCE_PROOF_LEN),
+        ProofKind::Rekey => (REKEY_PROOF_LEN, REKEY_PROOF_LEN),
+        ProofKind::AuditorHandle => (AUDITOR_PROOF_LEN, AUDITOR_PROOF_LEN),
+        ProofKind::TransferSplit => (TRANSFER_SPLIT_PROOF_MIN_LEN, TRANSFER_SPLIT_PROOF_MAX_LEN),
+        ProofKind::Seizure => (SEIZURE_PROOF_LEN, SEIZURE_PROOF_LEN),
+    })
+}
+
+/// Prefix `proof` with [`PROOF_SYSTEM_ID`], the envelope `pallet-zkhe` extrinsics take.
+pub fn seal(proof: &[u8]) -> Vec<u8> {
+    confidential_assets_primitives::seal_proof(PROOF_SYSTEM_ID, proof)
+}
+
+/// Check that `bytes` has the layout of a `kind` proof: fixed parts present, range
+/// sections no longer than [`MAX_RANGE_PROOF_LEN`], and no trailing bytes.
+///
+/// Does no curve arithmetic; a proof that passes may still fail verification.
+pub fn validate_shape(kind: ProofKind, bytes: &[u8]) -> Result<(), Error> {
+    match kind {
+        ProofKind::Transfer => {
+            let end = range_sections_end(bytes, COMMITMENT_LEN + LINK_PROOF_LEN, 2)?;
+            // optional floor section
+            if bytes.len() != end && range_sections_end(bytes, end, 1)? != bytes.len() {
+                return Err(Error::Malformed);
+            }
+            Ok(())
+        }
+        ProofKind::Accept => check_range_sections(bytes, COMMITMENT_LEN, 0),
+        ProofKind::Claim => {
+            if bytes.len() < CLAIM_MIN_LEN {
+                return Err(Error::Malformed);
+            }
+            let count = u16::from_le_bytes([bytes[0], bytes[1]]) as usize;
+            let head = 2 + count * 8;
+            if count == 0 || bytes.len() < head {
+                return Err(Error::Malformed);
+            }
+            check_range_sections(&bytes[head..], COMMITMENT_LEN, 0)
+        }
+        ProofKind::Mint => {
+            check_range_sections(bytes, DELTA_CT_LEN + COMMITMENT_LEN + LINK_PROOF_LEN, 0)
+        }
+        ProofKind::Burn => check_range_sections(bytes, COMMITMENT_LEN + LINK_PROOF_LEN, 8),
+        ProofKind::ConfidentialBurn => {
+            check_range_sections(bytes, COMMITMENT_LEN + LINK_PROOF_LEN, 0)
+        }
+        ProofKind::Disclosure => {
+            if bytes.len() != DISCLOSURE_PROOF_LEN {
+                return Err(Error::Malformed);
+            }
+            Ok(())
+        }
+        ProofKind::PubkeyOwnership => {
+            if bytes.len() != PK_OWNERSHIP_PROOF_LEN {
+                return Err(Error::Malformed);
+            }
+            Ok(())
+        }
+        ProofKind::ZeroBalance => {
+            if bytes.len() != ZERO_BALANCE_PROOF_LEN {
+                return Err(Error::Malformed);
+            }
+            Ok(())
+        }
+        ProofKind::Rekey => {
+            if bytes.len() != REKEY_PROOF_LEN {
+                return Err(Error::Malformed);
+            }
+            Ok(())
+        }
+        ProofKind::AuditorHandle => {
+            if bytes.len() != AUDITOR_PROOF_LEN {
+                return Err(Error::Malformed);
+            }
+            Ok(())
+        }
+        ProofKind::TransferSplit => {
+            if bytes.len() < TRANSFER_SPLIT_PROOF_MIN_LEN {
+                return Err(Error::Malformed);
+            }
+            let count = u16::from_le_bytes([bytes[0], bytes[1]]) as usize;
+            if count == 0 {
+                return Err(Error::Malformed);
+            }
+            // the total plus one part and one range section per output
+            let head = 2 + (count + 1) * SPLIT_PART_LEN;
+            if range_sections_end(bytes, head, count + 1)? != bytes.len() {
+                return Err(Error::Malformed);
+            }
+            Ok(())
+        }
+        ProofKind::Seizure => {
+            if bytes.len() != SEIZURE_PROOF_LEN {
+                return Err(Error::Malformed);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// `head || len1(2) || range || len2(2) || range || tail`, ending exactly after `tail`.
+fn check_range_sections(bytes: &[u8], head: usize, tail: usize) -> Result<(), Error> {
+    if bytes.len() != range_sections_end(bytes, head, 2)? + tail {
+        return Err(Error::Malformed);
+    }
+    Ok(())
+}
+
+/// Offset just past `count` `len(2) || range` sections starting at `start`.
+fn range_sections_end(bytes: &[u8], start: usize, count: usize) -> Result<usize, Error> {
+    let mut off = start;
+    for _ in 0..count {
+        let prefix = bytes.get(off..off + 2).ok_or(Error::Malformed)?;
+        let len = u16::from_le_bytes([prefix[0], prefix[1]]) as usize;
+        if len > MAX_RANGE_PROOF_LEN {
+            return Err(Error::Malformed);
+        }
+        off += 2 + len;
+    }
+    Ok(off)
+}
+
 /// Simple wrapper enforcing correct lengths at construction.
 pub struct FixedProof<const N: usize> {
     inner: [u8; N],
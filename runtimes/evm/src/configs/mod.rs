@@ -390,16 +390,52 @@ impl pallet_assets::Config for Runtime {
 }
 
 // Confidential Assets configuration
+
+/// Proof domain separator: genesis hash + para id, so proofs do not replay across chains.
+/// Benchmarks replay pre-generated vectors, which were made for network id zero.
+#[cfg(not(feature = "runtime-benchmarks"))]
+pub type RuntimeNetworkId = zkhe_verifier::GenesisNetworkId<Runtime, ParachainInfo, ParaId>;
+#[cfg(feature = "runtime-benchmarks")]
+pub type RuntimeNetworkId = confidential_assets_primitives::ZeroNetworkId;
+
+parameter_types! {
+    /// Unclaimed transfers become reclaimable by their sender after a week.
+    pub const PendingTransferTtl: BlockNumber = 7 * DAYS;
+    /// Held from the sender of each pending transfer until it is claimed or reclaimed.
+    pub const PendingTransferDeposit: Balance = 10 * MILLI_UNIT;
+    /// Held once per (asset, account) confidential state until the account is closed.
+    pub const ConfidentialAccountDeposit: Balance = 10 * MILLI_UNIT;
+    /// Held from the owner of each asset registered with `create_confidential_asset`.
+    pub const ConfidentialAssetDeposit: Balance = 10 * UNIT;
+}
+
 impl pallet_zkhe::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = u128;
     type Balance = Balance;
-    type Verifier = zkhe_verifier::ZkheVerifier<confidential_assets_primitives::ZeroNetworkId>;
-    type WeightInfo = ();
+    type Verifier = zkhe_verifier::ZkheVerifier<
+        RuntimeNetworkId,
+        zkhe_verifier::BulletproofRangeVerifier,
+        // Widths from `Zkhe`, transfer floors from `ConfidentialAssets` asset policies
+        pallet_confidential_assets::WithTransferFloor<Runtime, Zkhe>,
+    >;
+    type MaxClaimUtxos = ConstU32<64>;
+    type PendingTtl = PendingTransferTtl;
+    // One `claim_many` can drain a full queue
+    type MaxPendingPerAccount = ConstU32<64>;
+    type PendingDeposit = PendingTransferDeposit;
+    type AccountDeposit = ConfidentialAccountDeposit;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    // Governance enables each asset with `set_asset_config`
+    type RequireAssetConfig = ConstBool<true>;
+    type MaxAuditHistory = ConstU32<1024>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = pallet_zkhe::weights::WeightInfo<Runtime>;
 }
 
 // Minimal confidential assets config - using Ramp and Backend types
-use confidential_assets_primitives::Ramp;
+use confidential_assets_primitives::{ProofCarrier, ProofKind, Ramp};
 use frame_support::traits::{
     Currency, ExistenceRequirement, Get,
     tokens::fungibles::Mutate as MultiTransfer,
@@ -489,7 +525,30 @@ impl pallet_confidential_assets::Config for Runtime {
     type Backend = Zkhe;
     type Ramp = PublicRamp;
     type AssetMetadata = ();
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetDeposit = ConfidentialAssetDeposit;
+    type StringLimit = ConstU32<64>;
+    type Callbacks = ();
+    type OnTransferReceived = ();
+    type FeeAsset = NativeAssetId;
+    type AutoClaim = ();
+    type ForceOrigin = EnsureRoot<AccountId>;
     type Acl = ();
     type Operators = ();
-    type WeightInfo = ();
+    type Escrow = ();
+    type ScheduledTransferDeposit = PendingTransferDeposit;
+    type MaxTransfersPerBatch = ConstU32<32>;
+    type WeightInfo = pallet_confidential_assets::weights::WeightInfo<Runtime>;
+}
+
+/// Hands the proofs of confidential calls to `pallet_zkhe::CheckConfidentialProof`.
+impl ProofCarrier for RuntimeCall {
+    fn proofs(&self) -> Vec<(ProofKind, &[u8])> {
+        match self {
+            RuntimeCall::Zkhe(call) => call.proofs(),
+            RuntimeCall::ConfidentialAssets(call) => call.proofs(),
+            _ => Vec::new(),
+        }
+    }
 }
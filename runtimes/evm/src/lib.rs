@@ -77,13 +77,20 @@ pub type TxExtension = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    pallet_confidential_assets::ChargeConfidentialFee<
+        Runtime,
+        pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    >,
+    pallet_zkhe::CheckConfidentialProof<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
     fp_self_contained::UncheckedExtrinsic<Address, RuntimeCall, Signature, TxExtension>;
 
+/// Migrations to run on runtime upgrade, aside from the ones declared in the pallets.
+type Migrations = (pallet_zkhe::migrations::v1::MigrateV0ToV1<Runtime>,);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -91,6 +98,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
 impl_opaque_keys! {
@@ -232,8 +240,9 @@ mod runtime {
 // TaggedTransactionQueue, etc.) are provided by derive_impl macros from the
 // ParaChainDefaultConfig preset.
 //
-// Additional runtime APIs (e.g., for confidential assets queries) may be added in
-// future releases as needed for tooling integration.
+// Confidential asset queries are defined in `pallet-confidential-assets-runtime-api`
+// and implemented by the PolkaVM runtime; this runtime will expose them once it has
+// an `impl_runtime_apis!` block.
 sp_api::decl_runtime_apis! {}
 
 pub const RUNTIME_API_VERSIONS: sp_version::ApisVec = sp_version::create_apis_vec!([]);
@@ -20,9 +20,13 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SLOT_DURATION, SessionKeys, System,
-    TransactionPayment, VERSION,
+    AccountId, AssetId, Balance, Block, ConfidentialAssets, ConsensusHook, Executive,
+    InherentDataExt, Nonce, ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig,
+    SLOT_DURATION, SessionKeys, System, TransactionPayment, VERSION, Zkhe,
+    configs::RuntimeNetworkId,
+};
+use confidential_assets_primitives::{
+    Commitment, EncryptedAmount, NetworkIdProvider, PublicKeyBytes,
 };
 
 // we move some impls outside so we can easily use them with `docify`.
@@ -194,6 +198,32 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_confidential_assets_runtime_api::ConfidentialAssetsApi<Block, AccountId, AssetId, pallet_zkhe::AssetConfig>
+        for Runtime
+    {
+        fn balance_of(asset: AssetId, who: AccountId) -> Commitment {
+            ConfidentialAssets::confidential_balance_of(asset, &who)
+        }
+        fn pending_balance(asset: AssetId, who: AccountId) -> Commitment {
+            pallet_zkhe::PendingBalanceCommit::<Runtime>::get(asset, who).unwrap_or_default()
+        }
+        fn pending_transfers(asset: AssetId, who: AccountId) -> Vec<(u64, EncryptedAmount)> {
+            Zkhe::pending_deposits(&who, asset)
+        }
+        fn public_key(who: AccountId) -> Option<PublicKeyBytes> {
+            Zkhe::public_key(who)
+        }
+        fn total_supply(asset: AssetId) -> Commitment {
+            ConfidentialAssets::confidential_total_supply(asset)
+        }
+        fn asset_config(asset: AssetId) -> pallet_zkhe::AssetConfig {
+            Zkhe::asset_config(asset)
+        }
+        fn network_id() -> [u8; 32] {
+            <RuntimeNetworkId as NetworkIdProvider>::network_id()
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
@@ -1,34 +1,65 @@
 //! Confidential Pallets Configuration
 //!
-//! Optional: pallet-acl, pallet-operators
-use crate::{AccountId, AssetId, Balance, Runtime, RuntimeEvent, Zkhe};
-use confidential_assets_primitives::{NetworkIdProvider, Ramp};
+//! Optional: pallet-confidential-acl, pallet-operators
+use crate::{
+    AccountId, AssetId, Balance, BlockNumber, DAYS, MILLI_UNIT, ParachainInfo, Runtime,
+    RuntimeCall, RuntimeEvent, RuntimeHoldReason, UNIT, Zkhe,
+};
+use alloc::vec::Vec;
+use confidential_assets_primitives::{ProofCarrier, ProofKind, Ramp};
 use frame_support::traits::{
-    Currency, ExistenceRequirement, Get,
+    ConstBool, ConstU32, Currency, ExistenceRequirement, Get,
     tokens::fungibles::Mutate as MultiTransfer,
     tokens::{Fortitude, Precision, Preservation, WithdrawReasons},
 };
-use polkadot_sdk::{frame_support, pallet_assets, pallet_balances, sp_runtime};
+use polkadot_sdk::{
+    cumulus_primitives_core::ParaId, frame_support, frame_system::EnsureRoot, pallet_assets,
+    pallet_balances, sp_runtime,
+};
 use sp_runtime::DispatchError;
 
 /// Network ID provider for this runtime.
 ///
-/// In production, this should return a unique identifier for the network (e.g., genesis hash
-/// or a configured chain-specific ID) to provide domain separation for ZK proofs.
-pub struct RuntimeNetworkId;
-impl NetworkIdProvider for RuntimeNetworkId {
-    fn network_id() -> [u8; 32] {
-        // TODO: Replace with actual network identifier (e.g., from genesis config)
-        // For now returns zeros to maintain compatibility with test vectors
-        [0u8; 32]
-    }
+/// Derived from the genesis hash and para id, so proofs made for another chain never verify
+/// here. Benchmarks replay pre-generated vectors, which were made for network id zero.
+#[cfg(not(feature = "runtime-benchmarks"))]
+pub type RuntimeNetworkId = zkhe_verifier::GenesisNetworkId<Runtime, ParachainInfo, ParaId>;
+#[cfg(feature = "runtime-benchmarks")]
+pub type RuntimeNetworkId = confidential_assets_primitives::ZeroNetworkId;
+
+frame_support::parameter_types! {
+    /// Unclaimed transfers become reclaimable by their sender after a week.
+    pub const PendingTransferTtl: BlockNumber = 7 * DAYS;
+    /// Held from the sender of each pending transfer until it is claimed or reclaimed.
+    pub const PendingTransferDeposit: Balance = 10 * MILLI_UNIT;
+    /// Held once per (asset, account) confidential state until the account is closed.
+    pub const ConfidentialAccountDeposit: Balance = 10 * MILLI_UNIT;
+    /// Held from the owner of each asset registered with `create_confidential_asset`.
+    pub const ConfidentialAssetDeposit: Balance = 10 * UNIT;
 }
 
 impl pallet_zkhe::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type Balance = Balance;
-    type Verifier = zkhe_verifier::ZkheVerifier<RuntimeNetworkId>;
+    type Verifier = zkhe_verifier::ZkheVerifier<
+        RuntimeNetworkId,
+        zkhe_verifier::BulletproofRangeVerifier,
+        // Widths from `Zkhe`, transfer floors from `ConfidentialAssets` asset policies
+        pallet_confidential_assets::WithTransferFloor<Runtime, Zkhe>,
+    >;
+    type MaxClaimUtxos = ConstU32<64>;
+    type PendingTtl = PendingTransferTtl;
+    // One `claim_many` can drain a full queue
+    type MaxPendingPerAccount = ConstU32<64>;
+    type PendingDeposit = PendingTransferDeposit;
+    type AccountDeposit = ConfidentialAccountDeposit;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    // Governance enables each asset with `set_asset_config`
+    type RequireAssetConfig = ConstBool<true>;
+    type MaxAuditHistory = ConstU32<1024>;
+    type ForceOrigin = EnsureRoot<AccountId>;
     type WeightInfo = pallet_zkhe::weights::WeightInfo<Runtime>;
 }
 
@@ -39,9 +70,23 @@ impl pallet_confidential_assets::Config for Runtime {
     type Backend = Zkhe;
     type Ramp = PublicRamp;
     type AssetMetadata = ();
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetDeposit = ConfidentialAssetDeposit;
+    type StringLimit = ConstU32<64>;
+    type Callbacks = ();
+    type OnTransferReceived = ();
+    type FeeAsset = NativeAssetId;
+    type AutoClaim = ();
+    type ForceOrigin = EnsureRoot<AccountId>;
     type Acl = ();
     type Operators = ();
+    type Escrow = ();
+    type ScheduledTransferDeposit = PendingTransferDeposit;
+    type MaxTransfersPerBatch = ConstU32<32>;
     type WeightInfo = pallet_confidential_assets::weights::WeightInfo<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = Zkhe;
 }
 
 // ----------------- Confidential Assets Helpers -----------------
@@ -127,3 +172,14 @@ impl Ramp<AccountId, AssetId, Balance> for PublicRamp {
         Ok(())
     }
 }
+
+/// Hands the proofs of confidential calls to `pallet_zkhe::CheckConfidentialProof`.
+impl ProofCarrier for RuntimeCall {
+    fn proofs(&self) -> Vec<(ProofKind, &[u8])> {
+        match self {
+            RuntimeCall::Zkhe(call) => call.proofs(),
+            RuntimeCall::ConfidentialAssets(call) => call.proofs(),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -42,6 +42,7 @@ use super::{
     SLOT_DURATION, Session, SessionKeys, System, UNIT, VERSION, WeightToFee, XcmpQueue,
     weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
 };
+pub use confidential::RuntimeNetworkId;
 pub use xcm_config::LocationToAccountId;
 use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
 
@@ -85,8 +85,12 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
         frame_system::CheckEra<Runtime>,
         frame_system::CheckNonce<Runtime>,
         frame_system::CheckWeight<Runtime>,
-        pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+        pallet_confidential_assets::ChargeConfidentialFee<
+            Runtime,
+            pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+        >,
         frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
+        pallet_zkhe::CheckConfidentialProof<Runtime>,
     ),
 >;
 
@@ -98,7 +102,7 @@ pub type UncheckedExtrinsic =
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (pallet_zkhe::migrations::v1::MigrateV0ToV1<Runtime>);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
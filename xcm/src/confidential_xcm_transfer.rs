@@ -1,18 +1,18 @@
 // Confidential XCM Tests
 use crate::*;
 
-use confidential_assets_primitives::ConfidentialBackend;
+use confidential_assets_primitives::{ConfidentialBackend, InputProof};
 use curve25519_dalek::{
     constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar, traits::Identity,
 };
-use frame_support::{assert_ok, weights::Weight};
+use frame_support::assert_ok;
 use parity_scale_codec::Encode;
 use rand::{RngCore, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use xcm_simulator::TestExt;
 use zkhe_prover::{
-    BurnInput, MintInput, ReceiverAcceptInput, SenderInput, prove_burn, prove_mint,
-    prove_receiver_accept, prove_sender_transfer,
+    MintInput, PubkeyOwnershipInput, SenderInput, prove_mint, prove_pubkey_ownership,
+    prove_sender_transfer, seal,
 };
 
 fn asset_id_bytes_u128(id: u128) -> Vec<u8> {
@@ -24,6 +24,18 @@ fn h() -> curve25519_dalek::ristretto::RistrettoPoint {
     use sha2::Sha512;
     curve25519_dalek::ristretto::RistrettoPoint::hash_from_bytes::<Sha512>(b"Zether/PedersenH")
 }
+fn pk_proof(who: &parachain::AccountId, sk: Scalar) -> InputProof {
+    prove_pubkey_ownership(&PubkeyOwnershipInput {
+        network_id: [0u8; 32],
+        account: who.encode(),
+        sk,
+        rng_seed: [3u8; 32],
+    })
+    .map(|proof| seal(&proof))
+    .expect("ownership proof")
+    .try_into()
+    .unwrap()
+}
 fn p32(pt: &curve25519_dalek::ristretto::RistrettoPoint) -> [u8; 32] {
     pt.compress().to_bytes()
 }
@@ -82,7 +94,7 @@ fn show_pending_commit(label: &str, asset: u128, who: parachain::AccountId) {
 /// Demonstrates confidential xcm transfers via pallet-confidential-bridge:
 /// 1. Send confidential assets from source parachain to dest parachain + escrow local confidential assets
 /// 2. Claim confidential assets on dest parachain + send confirmation back to source parachain
-/// 3. Release escrow on source parachain once received confirmed execution on dest parachain
+/// 3. Burn escrow on source parachain once dest parachain acknowledged the mint
 #[test]
 fn confidential_xcm_transfer() {
     MockNet::reset();
@@ -120,9 +132,17 @@ fn confidential_xcm_transfer() {
         let pk_bob = pk_receiver.compress().to_bytes().to_vec();
         assert_ok!(parachain::Zkhe::set_public_key(
             &BOB,
-            &pk_bob.clone().try_into().unwrap()
+            &pk_bob.clone().try_into().unwrap(),
+            pk_proof(&BOB, sk_receiver)
         ));
         show_pk("ParaB/BOB", &BOB);
+        // Asset 0 arrives from ParaA under the same id
+        assert_ok!(parachain::ConfidentialBridge::map_asset(
+            parachain::RuntimeOrigin::root(),
+            asset_id_u128,
+            1,
+            asset_id_u128
+        ));
         print_events_para_b();
     });
 
@@ -132,25 +152,30 @@ fn confidential_xcm_transfer() {
         let pk_sender_bytes = pk_sender.compress().to_bytes().to_vec();
         assert_ok!(parachain::Zkhe::set_public_key(
             &ALICE,
-            &pk_sender_bytes.clone().try_into().unwrap()
+            &pk_sender_bytes.clone().try_into().unwrap(),
+            pk_proof(&ALICE, sk_sender)
+        ));
+        assert_ok!(parachain::ConfidentialBridge::map_asset(
+            parachain::RuntimeOrigin::root(),
+            asset_id_u128,
+            2,
+            asset_id_u128
         ));
         let escrow = parachain::ConfidentialEscrow::escrow_account();
-        let burn = parachain::ConfidentialBridge::burn_account();
         let dummy_pk = pk_receiver
             .compress()
             .to_bytes()
             .to_vec()
             .try_into()
             .unwrap();
-        let _ = parachain::Zkhe::set_public_key(&escrow, &dummy_pk);
-        let _ = parachain::Zkhe::set_public_key(&burn, &dummy_pk);
+        let _ = parachain::Zkhe::set_public_key(&escrow, &dummy_pk, pk_proof(&escrow, sk_receiver));
 
         // Seed ALICE available commitment (must match prover input)
         let from_old_c = Scalar::from(from_old_v) * G + Scalar::from(from_old_r) * h();
         pallet_zkhe::AvailableBalanceCommit::<parachain::Runtime>::insert(
             asset_id_u128,
             ALICE,
-            p32(&from_old_c),
+            p32(&from_old_c).into(),
         );
 
         // Diagnostics before proving
@@ -215,17 +240,14 @@ fn confidential_xcm_transfer() {
             2,
             BOB,
             asset_id_u128,
-            s_out.delta_ct_bytes,
-            s_out
-                .sender_bundle_bytes
-                .clone()
+            s_out.delta_ct_bytes.into(),
+            seal(&s_out.sender_bundle_bytes)
                 .try_into()
                 .expect("bundle→BoundedVec"),
-            m_out
-                .proof_bytes
-                .clone()
+            seal(&m_out.proof_bytes)
                 .try_into()
                 .expect("mint→BoundedVec"),
+            None,
         );
 
         if call_res.is_err() {
@@ -262,111 +284,213 @@ fn confidential_xcm_transfer() {
         assert!(ok, "expected InboundTransferExecuted on ParaB");
     });
 
-    // Prepare burn account PK
-    let burn_sk = Scalar::from(777u64);
-    let burn_pk = burn_sk * G;
-
+    // ============ Phase 3 (ParaA): ACK received, sender burns the escrow ============
     ParaA::execute_with(|| {
-        let burn_acc = parachain::ConfidentialBridge::burn_account();
-        let burn_pk_bytes: Vec<u8> = burn_pk.compress().to_bytes().to_vec();
-        assert_ok!(parachain::Zkhe::set_public_key(
-            &burn_acc,
-            &burn_pk_bytes.try_into().unwrap()
-        ));
         println!("=== Phase 3 pre-confirm (ParaA) ===");
-        show_pk("BURN", &burn_acc);
         print_events_para_a();
+        let acked = parachain::System::events().iter().any(|e| {
+            matches!(
+                e.event,
+                parachain::RuntimeEvent::ConfidentialBridge(
+                    pallet_confidential_bridge::Event::TransferAcknowledged {
+                        id: 0,
+                        success: true
+                    }
+                )
+            )
+        });
+        assert!(acked, "expected ParaB's success ACK on ParaA");
+
+        // No proofs: the escrowed ΔC is burned straight from escrow
+        assert_ok!(parachain::ConfidentialBridge::confirm_success(
+            parachain::RuntimeOrigin::signed(ALICE),
+            0
+        ));
+        assert!(parachain::ConfidentialBridge::pending(0).is_none());
+        let total = pallet_zkhe::TotalSupplyCommit::<parachain::Runtime>::get(asset_id_u128)
+            .unwrap_or_default();
+        assert!(total.add(&p32(&delta_comm).into()).unwrap().is_identity());
+    });
+}
+
+/// Asset reserve-transferred in from Asset Hub (ParaB here) is shielded straight from
+/// the `ForeignAssets` balance it lands in.
+#[test]
+fn reserve_transferred_foreign_asset_can_be_shielded() {
+    MockNet::reset();
+
+    let index = 1984u128;
+    let amount = 500u128;
+    let foreign = Location::new(1, [Parachain(2), PalletInstance(50), GeneralIndex(index)]);
+    let asset = parachain::FOREIGN_ASSET_FLAG | index;
+    let sk = Scalar::from(5u64);
+    let pk = sk * G;
+
+    ParaA::execute_with(|| {
+        assert_ok!(parachain::ForeignAssets::force_create(
+            parachain::RuntimeOrigin::root(),
+            foreign.clone(),
+            ALICE,
+            true,
+            1
+        ));
     });
 
-    // ============ Phase 3 (ParaB): build release+burn proofs, send confirm_success ============
+    // ParaB holds the reserve and tells ParaA the asset was deposited for ALICE
     ParaB::execute_with(|| {
-        // Re-derive Δρ and ΔC for receiver-accept/burn
-        let mut chacha = ChaCha20Rng::from_seed({
-            let mut s = [0u8; 32];
-            s[0] = 7;
-            s
-        });
-        let _k_ignore = chacha.next_u64();
-        let delta_rho = Scalar::from(chacha.next_u64());
-        let delta_comm = Scalar::from(dv) * G + delta_rho * h();
-
-        // Burn PK (on A)
-        let burn_pk = {
-            let sk = Scalar::from(777u64);
-            sk * G
-        };
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(1)),
+            Xcm(vec![
+                ReserveAssetDeposited((foreign.clone(), amount).into()),
+                ClearOrigin,
+                DepositAsset {
+                    assets: AllCounted(1).into(),
+                    beneficiary: AccountId32 {
+                        network: None,
+                        id: ALICE.into()
+                    }
+                    .into(),
+                },
+            ]),
+        ));
+    });
 
-        // Release proof for burn account’s accept (avail += Δ, pending -= Δ)
-        let a_release_in = ReceiverAcceptInput {
-            asset_id: asset_id.clone(),
-            network_id,
-            receiver_pk: burn_pk,
-            avail_old_c: curve25519_dalek::ristretto::RistrettoPoint::identity(),
-            avail_old_opening: (0u64, Scalar::from(0u64)),
-            pending_old_c: delta_comm,
-            pending_old_opening: (dv, delta_rho),
-            delta_comm,
-            delta_value: dv,
-            delta_rho,
-        };
-        let a_release_out = prove_receiver_accept(&a_release_in).expect("release prover");
-        println!(
-            "release.accept_envelope.len={}",
-            a_release_out.accept_envelope.len()
+    ParaA::execute_with(|| {
+        assert_eq!(
+            parachain::ForeignAssets::balance(foreign.clone(), &ALICE),
+            amount
         );
 
-        // Burn proof
-        let b_burn_in = BurnInput {
-            asset_id: asset_id.clone(),
-            network_id,
-            from_pk: burn_pk,
-            from_avail_old_c: delta_comm,
-            from_avail_old_opening: (dv, delta_rho),
-            total_old_c: delta_comm,
-            total_old_opening: (dv, delta_rho),
-            burn_value: dv,
-            rng_seed: {
-                let mut s = [0u8; 32];
-                s[1] = 0x5C;
-                s
-            },
-        };
-        let b_burn_out = prove_burn(&b_burn_in).expect("burn prover");
-        println!("burn.proof_bytes.len={}", b_burn_out.proof_bytes.len());
-
-        // Ship both proofs back to A *with an XCM origin* so EnsureXcmOrigin passes
-        let call = parachain::RuntimeCall::ConfidentialBridge(pallet_confidential_bridge::Call::<
-            parachain::Runtime,
-        >::confirm_success {
-            id: 0,
-            release_proof: a_release_out.accept_envelope.clone().try_into().unwrap(),
-            burn_proof: b_burn_out.proof_bytes.clone().try_into().unwrap(),
-        });
+        assert_ok!(parachain::Zkhe::set_public_key(
+            &ALICE,
+            &pk.compress().to_bytes().to_vec().try_into().unwrap(),
+            pk_proof(&ALICE, sk)
+        ));
+        let mint = prove_mint(&MintInput {
+            asset_id: asset_id_bytes_u128(asset),
+            network_id: [0u8; 32],
+            to_pk: pk,
+            to_pending_old_c: curve25519_dalek::ristretto::RistrettoPoint::identity(),
+            to_pending_old_opening: (0u64, Scalar::from(0u64)),
+            total_old_c: curve25519_dalek::ristretto::RistrettoPoint::identity(),
+            total_old_opening: (0u64, Scalar::from(0u64)),
+            mint_value: amount as u64,
+            rng_seed: [0xA5; 32],
+        })
+        .expect("mint prover");
 
-        // Destination is Parent -> Parachain(1) (ParaA)
-        let dest = Location::new(1, [Parachain(1u32.into())]);
-
-        // IMPORTANT: use OriginKind::Xcm instead of SovereignAccount
-        let msg = Xcm(vec![Transact {
-            origin_kind: OriginKind::Xcm,
-            fallback_max_weight: Some(Weight::from_parts(3_000_000_000, 0)),
-            call: call.encode().into(),
-        }]);
-
-        // Send with the same router you already use elsewhere
-        let origin =
-            parachain::RuntimeOrigin::signed(parachain::ConfidentialBridge::burn_account());
-        // Send via PolkadotXcm::send (same path as your XcmHrmpMessenger)
-        let res = parachain::PolkadotXcm::send(
-            origin,
-            Box::new(VersionedLocation::from(dest)),
-            Box::new(VersionedXcm::from(msg)),
+        let res = parachain::ConfidentialAssets::deposit(
+            parachain::RuntimeOrigin::signed(ALICE),
+            asset,
+            amount,
+            seal(&mint.proof_bytes).try_into().unwrap(),
         );
         if res.is_err() {
-            println!("confirm_success XCM send failed on ParaB: {res:?}");
-            print_events_para_b();
+            print_events_para_a();
         }
         assert_ok!(res);
-        print_events_para_b();
+
+        assert_eq!(parachain::ForeignAssets::balance(foreign, &ALICE), 0);
+        assert!(parachain::System::events().iter().any(|e| matches!(
+            &e.event,
+            parachain::RuntimeEvent::ConfidentialAssets(
+                pallet_confidential_assets::Event::Deposited { who, .. }
+            ) if *who == ALICE
+        )));
+    });
+}
+
+/// A split pays each recipient's pending balance the output's commitment, so with the
+/// real verifier a recipient can claim its output and the supply still adds up.
+#[test]
+fn split_outputs_can_be_claimed() {
+    use zkhe_prover::{ReceiverAcceptInput, SplitInput, prove_receiver_accept, prove_sender_split};
+
+    MockNet::reset();
+
+    let charlie = sp_runtime::AccountId32::new([3u8; 32]);
+    let asset = 0u128;
+    let sk_alice = Scalar::from(5u64);
+    let sk_bob = Scalar::from(9u64);
+    let sk_charlie = Scalar::from(13u64);
+    let from_old_r = Scalar::from(42u64);
+    let from_old_c = Scalar::from(1_000u64) * G + from_old_r * h();
+
+    ParaA::execute_with(|| {
+        for (who, sk) in [(&ALICE, sk_alice), (&BOB, sk_bob), (&charlie, sk_charlie)] {
+            assert_ok!(parachain::Zkhe::set_public_key(
+                who,
+                &(sk * G).compress().to_bytes().to_vec().try_into().unwrap(),
+                pk_proof(who, sk)
+            ));
+        }
+        pallet_zkhe::AvailableBalanceCommit::<parachain::Runtime>::insert(
+            asset,
+            ALICE,
+            p32(&from_old_c).into(),
+        );
+        pallet_zkhe::TotalSupplyCommit::<parachain::Runtime>::insert(
+            asset,
+            p32(&from_old_c).into(),
+        );
+
+        let split = prove_sender_split(&SplitInput {
+            asset_id: asset_id_bytes_u128(asset),
+            network_id: [0u8; 32],
+            sender_pk: sk_alice * G,
+            from_old_opening: (1_000, from_old_r),
+            outputs: vec![(sk_bob * G, 300), (sk_charlie * G, 200)],
+            rng_seed: [8u8; 32],
+        })
+        .expect("split prover");
+        assert_ok!(parachain::ConfidentialAssets::confidential_transfer_split(
+            parachain::RuntimeOrigin::signed(ALICE),
+            asset,
+            split.total_ct_bytes.into(),
+            vec![
+                (BOB, split.output_ct_bytes[0].into()),
+                (charlie.clone(), split.output_ct_bytes[1].into()),
+            ]
+            .try_into()
+            .unwrap(),
+            seal(&split.proof_bytes).try_into().unwrap(),
+        ));
+        assert_eq!(
+            pallet_zkhe::PendingBalanceCommit::<parachain::Runtime>::get(asset, BOB),
+            Some(split.output_comm_bytes[0].into())
+        );
+        assert_ok!(parachain::Zkhe::check_supply(asset));
+
+        // BOB claims its output with the opening ALICE hands over off chain
+        let bob_comm = curve25519_dalek::ristretto::CompressedRistretto(split.output_comm_bytes[0])
+            .decompress()
+            .expect("output commitment");
+        let accept = prove_receiver_accept(&ReceiverAcceptInput {
+            asset_id: asset_id_bytes_u128(asset),
+            network_id: [0u8; 32],
+            receiver_pk: sk_bob * G,
+            avail_old_c: curve25519_dalek::ristretto::RistrettoPoint::identity(),
+            avail_old_opening: (0, Scalar::from(0u64)),
+            pending_old_c: bob_comm,
+            pending_old_opening: (300, split.output_blindings[0]),
+            delta_comm: bob_comm,
+            delta_value: 300,
+            delta_rho: split.output_blindings[0],
+        })
+        .expect("accept prover");
+        assert_ok!(parachain::Zkhe::claim_many(
+            parachain::RuntimeOrigin::signed(BOB),
+            asset,
+            vec![0].try_into().unwrap(),
+            seal(&accept.accept_envelope).try_into().unwrap(),
+        ));
+        assert_eq!(
+            pallet_zkhe::AvailableBalanceCommit::<parachain::Runtime>::get(asset, BOB),
+            Some(p32(&bob_comm).into())
+        );
+        assert!(parachain::Zkhe::pending_deposits(&BOB, asset).is_empty());
+        assert_eq!(parachain::Zkhe::pending_deposits(&charlie, asset).len(), 1);
+        assert_ok!(parachain::Zkhe::check_supply(asset));
     });
 }
@@ -1,30 +1,34 @@
 //! Confidential Pallets Configuration
 //!
-//! Optional: pallet-acl, pallet-operators
+//! Optional: pallet-confidential-acl, pallet-operators
 use crate::parachain::{
-    AccountId, Balance, ConfidentialEscrow, MsgQueue, PolkadotXcm, Runtime, RuntimeCall,
-    RuntimeEvent, RuntimeOrigin, Zkhe,
+    AccountId, Balance, ConfidentialEscrow, ForeignAssets, ForeignCreators, MsgQueue, PolkadotXcm,
+    Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, Zkhe,
+    constants::RelayNetwork,
 };
-use confidential_assets_primitives::{HrmpMessenger, NetworkIdProvider, Ramp};
+use confidential_assets_primitives::{ExecutionFee, HrmpMessenger, NetworkIdProvider, Ramp};
 use frame_support::traits::{
-    AsEnsureOriginWithArg, Currency, ExistenceRequirement,
+    AsEnsureOriginWithArg, Currency, Everything, ExistenceRequirement, TryMapSuccess,
     tokens::fungibles::Mutate as MultiTransfer,
     tokens::{Fortitude, Precision, Preservation, WithdrawReasons},
 };
 use frame_support::{
     PalletId, parameter_types,
-    traits::{ConstU64, Get},
+    traits::{ConstBool, ConstU32, ConstU64, ConstU128, Get},
 };
 use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_xcm::EnsureXcm;
 use parity_scale_codec::Encode;
 use sp_runtime::{
     DispatchError,
-    {BoundedVec, traits::AccountIdConversion},
+    {
+        BoundedVec,
+        traits::{AccountIdConversion, MaybeEquivalence, TryMorph},
+    },
 };
 use std::{boxed::Box, vec, vec::Vec};
 use xcm::latest::prelude::*;
 use xcm::{VersionedLocation, VersionedXcm};
-use xcm_builder::EnsureXcmOrigin;
 
 // conflicts with cumulus AssetId struct used in xcm config when places in parachain root
 pub type AssetId = u128;
@@ -63,6 +67,42 @@ impl pallet_assets::Config for Runtime {
     type BenchmarkHelper = ();
 }
 
+/// Foreign assets reserve-transferred in, keyed by location as on Asset Hub.
+pub type ForeignAssetsInstance = pallet_assets::Instance2;
+
+impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = Location;
+    type AssetIdParameter = Location;
+    type Currency = Balances;
+    type CreateOrigin = ForeignCreators;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = AssetDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = AssetsStringLimit;
+    type Holder = ();
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    type CallbackHandle = ();
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ForeignAssetsBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ForeignAssetsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets::BenchmarkHelper<Location> for ForeignAssetsBenchmarkHelper {
+    fn create_asset_id_parameter(id: u32) -> Location {
+        Location::new(1, [Parachain(id)])
+    }
+}
+
 /// Test network ID provider - returns zero for XCM tests (matches vector generation).
 pub struct TestNetworkId;
 impl NetworkIdProvider for TestNetworkId {
@@ -76,6 +116,16 @@ impl pallet_zkhe::Config for Runtime {
     type AssetId = AssetId;
     type Balance = Balance;
     type Verifier = zkhe_verifier::ZkheVerifier<TestNetworkId>;
+    type MaxClaimUtxos = ConstU32<16>;
+    type PendingTtl = ConstU64<100>;
+    type MaxPendingPerAccount = ConstU32<16>;
+    type PendingDeposit = ConstU128<0>;
+    type AccountDeposit = ConstU128<0>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RequireAssetConfig = ConstBool<false>;
+    type MaxAuditHistory = ConstU32<4>;
+    type ForceOrigin = EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 impl pallet_confidential_assets::Config for Runtime {
@@ -85,16 +135,35 @@ impl pallet_confidential_assets::Config for Runtime {
     type Backend = Zkhe;
     type Ramp = PublicRamp;
     type AssetMetadata = ();
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<64>;
+    type Callbacks = ();
+    type OnTransferReceived = ();
+    type FeeAsset = NativeAssetId;
+    type AutoClaim = ();
+    type ForceOrigin = EnsureRoot<AccountId>;
     type Acl = ();
     type Operators = ();
+    type Escrow = ConfidentialEscrow;
+    type ScheduledTransferDeposit = ConstU128<0>;
+    type MaxTransfersPerBatch = ConstU32<32>;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = Zkhe;
 }
 impl pallet_confidential_escrow::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type Balance = Balance;
     type Backend = Zkhe;
+    type Ramp = PublicRamp;
     type PalletId = EscrowPalletId;
+    type MaxApprovers = ConstU32<4>;
+    type ApprovalTtl = ConstU64<100>;
+    type ArbiterOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
 }
 parameter_types! {
     pub const MaxBridgePayload: u32 = 16 * 1024; // 16 KiB is safe for two Bulletproofs, link proof, etc.
@@ -104,13 +173,23 @@ impl pallet_confidential_bridge::Config for Runtime {
     type AssetId = AssetId;
     type Balance = Balance;
     type Backend = Zkhe;
+    type Currency = Balances;
+    type FeeCollector = BridgeFeeCollector;
+    type RelayerReward = ConstU128<0>;
+    type AckVerifier = ();
     type Escrow = ConfidentialEscrow;
     type Messenger = XcmHrmpMessenger;
+    // No Ethereum bridge between the simulated paras
+    type Exporter = ();
+    type EthereumOrigin = frame_system::EnsureNever<()>;
     type MaxBridgePayload = MaxBridgePayload;
-    type BurnPalletId = BridgePalletId;
+    type MaxSendAttempts = ConstU32<5>;
+    type RateLimitEpoch = ConstU64<100>;
     type DefaultTimeout = ConstU64<10>;
+    type InboundRetention = ConstU64<100>;
     type SelfParaId = SelfParaId;
-    type XcmOrigin = EnsureXcmOrigin<RuntimeOrigin, super::LocalOriginToLocation>;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type XcmOrigin = TryMapSuccess<EnsureXcm<Everything>, SiblingBridge>;
     type WeightInfo = ();
 }
 
@@ -128,8 +207,56 @@ fn is_native(asset: &AssetId) -> bool {
     *asset == NativeAssetId::get()
 }
 
+/// Para id of the chain standing in for Asset Hub (ParaB in the tests).
+pub const ASSET_HUB_PARA_ID: u32 = 2;
+/// Index of `pallet-assets` on Asset Hub.
+pub const ASSET_HUB_ASSETS_PALLET: u8 = 50;
+/// Confidential ids with this bit set name foreign assets; the rest are local.
+pub const FOREIGN_ASSET_FLAG: AssetId = 1 << 127;
+
+/// Maps Asset Hub asset `n`, at `(Parent, Parachain(ASSET_HUB_PARA_ID),
+/// PalletInstance(ASSET_HUB_ASSETS_PALLET), GeneralIndex(n))`, to confidential id
+/// `FOREIGN_ASSET_FLAG | n`.
+pub struct ForeignAssetIds;
+impl MaybeEquivalence<Location, AssetId> for ForeignAssetIds {
+    fn convert(location: &Location) -> Option<AssetId> {
+        match location.unpack() {
+            (
+                1,
+                [
+                    Parachain(para),
+                    PalletInstance(ASSET_HUB_ASSETS_PALLET),
+                    GeneralIndex(n),
+                ],
+            ) if *para == ASSET_HUB_PARA_ID && n & FOREIGN_ASSET_FLAG == 0 => {
+                Some(n | FOREIGN_ASSET_FLAG)
+            }
+            _ => None,
+        }
+    }
+
+    fn convert_back(asset: &AssetId) -> Option<Location> {
+        (asset & FOREIGN_ASSET_FLAG != 0).then(|| {
+            Location::new(
+                1,
+                [
+                    Parachain(ASSET_HUB_PARA_ID),
+                    PalletInstance(ASSET_HUB_ASSETS_PALLET),
+                    GeneralIndex(asset & !FOREIGN_ASSET_FLAG),
+                ],
+            )
+        })
+    }
+}
+
+#[inline]
+fn is_foreign(asset: &AssetId) -> bool {
+    asset & FOREIGN_ASSET_FLAG != 0
+}
+
 type Balances = pallet_balances::Pallet<Runtime>;
 type Assets = pallet_assets::Pallet<Runtime>;
+type ForeignRamp = pallet_confidential_assets::FungiblesRamp<ForeignAssets, ForeignAssetIds>;
 
 pub struct PublicRamp;
 impl Ramp<AccountId, AssetId, Balance> for PublicRamp {
@@ -141,6 +268,9 @@ impl Ramp<AccountId, AssetId, Balance> for PublicRamp {
         asset: AssetId,
         amount: Balance,
     ) -> Result<(), Self::Error> {
+        if is_foreign(&asset) {
+            return ForeignRamp::transfer_from(from, to, asset, amount);
+        }
         if is_native(&asset) {
             // Native: via Currency
             <Balances as Currency<AccountId>>::transfer(
@@ -163,6 +293,9 @@ impl Ramp<AccountId, AssetId, Balance> for PublicRamp {
     }
 
     fn mint(to: &AccountId, asset: &AssetId, amount: Balance) -> Result<(), Self::Error> {
+        if is_foreign(asset) {
+            return ForeignRamp::mint(to, asset, amount);
+        }
         if is_native(asset) {
             // Native “mint”: deposit_creating increases issuance, returns a PositiveImbalance which
             // is burned when dropped if your Currency implements Balanced. Just ignore it here.
@@ -175,6 +308,9 @@ impl Ramp<AccountId, AssetId, Balance> for PublicRamp {
     }
 
     fn burn(from: &AccountId, asset: &AssetId, amount: Balance) -> Result<(), Self::Error> {
+        if is_foreign(asset) {
+            return ForeignRamp::burn(from, asset, amount);
+        }
         if is_native(asset) {
             // Native “burn”: withdraw with reasons; dropping the NegativeImbalance reduces issuance.
             let _imbalance = <Balances as Currency<AccountId>>::withdraw(
@@ -204,30 +340,81 @@ parameter_types! {
     pub const EscrowPalletId: PalletId = PalletId(*b"CaEscrow");
     pub const BridgePalletId: PalletId = PalletId(*b"CaBridge");
     pub SelfParaId: u32 = MsgQueue::get().into();
+    pub BridgeFeeCollector: AccountId = BridgePalletId::get().into_account_truncating();
 }
 fn bridge_account() -> AccountId {
     BridgePalletId::get().into_account_truncating()
 }
+/// The bridge account of a sibling para, as `XcmOrigin` sees it: `send` descends the
+/// `OriginKind::Xcm` origin into the account, so it arrives as `(Parent,
+/// Parachain(id), AccountId32)`. Yields the para id.
+pub struct SiblingBridge;
+impl TryMorph<Location> for SiblingBridge {
+    type Outcome = u32;
+    fn try_morph(location: Location) -> Result<u32, ()> {
+        let bridge: [u8; 32] = bridge_account().into();
+        match location.unpack() {
+            (1, [Parachain(id), AccountId32 { id: account, .. }]) if *account == bridge => Ok(*id),
+            _ => Err(()),
+        }
+    }
+}
+
 /// HRMP messenger implementation used by confidential-bridge pallet. Assumes open channel exists.
 pub struct XcmHrmpMessenger;
-impl HrmpMessenger for XcmHrmpMessenger {
-    fn send(dest_para: u32, payload: Vec<u8>) -> Result<(), ()> {
+impl XcmHrmpMessenger {
+    /// Transact `call` on `dest_para` as the bridge account's XCM origin, paying
+    /// for it with `fee` when given.
+    fn transact(
+        dest_para: u32,
+        payload: Vec<u8>,
+        fee: Option<ExecutionFee<Location>>,
+        call: impl FnOnce(BoundedVec<u8, MaxBridgePayload>) -> pallet_confidential_bridge::Call<Runtime>,
+    ) -> Result<(), ()> {
         // Use the SAME bound as the pallet call expects:
         let payload_bv: BoundedVec<u8, MaxBridgePayload> =
             BoundedVec::try_from(payload).map_err(|_| ())?;
 
         let dest = (Parent, Parachain(dest_para));
-        let call = RuntimeCall::ConfidentialBridge(
-            pallet_confidential_bridge::Call::<Runtime>::receive_confidential {
-                payload: payload_bv,
-            },
-        );
-
-        let msg = Xcm(vec![Transact {
-            origin_kind: OriginKind::SovereignAccount,
+        let call = RuntimeCall::ConfidentialBridge(call(payload_bv));
+        // Passed through as a location, so `XcmOrigin` can tell which para sent it
+        let transact = Transact {
+            origin_kind: OriginKind::Xcm,
             fallback_max_weight: None,
             call: call.encode().into(),
-        }]);
+        };
+
+        let msg = match fee {
+            None => Xcm(vec![transact]),
+            Some(fee) => {
+                let fees: Asset = (fee.asset, fee.amount).into();
+                // Leftover fees go back to the account they were withdrawn from, which
+                // `send` descended into
+                let payer = Location::new(
+                    1,
+                    [
+                        Parachain(SelfParaId::get()),
+                        AccountId32 {
+                            network: Some(RelayNetwork::get()),
+                            id: bridge_account().into(),
+                        },
+                    ],
+                );
+                Xcm(vec![
+                    WithdrawAsset(fees.clone().into()),
+                    BuyExecution {
+                        fees,
+                        weight_limit: Limited(fee.weight_limit),
+                    },
+                    transact,
+                    RefundSurplus,
+                    DepositAsset {
+                        assets: Wild(AllCounted(1)),
+                        beneficiary: payer,
+                    },
+                ])
+            }
+        };
 
         let origin = RuntimeOrigin::signed(bridge_account());
         PolkadotXcm::send(
@@ -239,3 +426,26 @@ impl HrmpMessenger for XcmHrmpMessenger {
         .map_err(|_| ())
     }
 }
+impl HrmpMessenger for XcmHrmpMessenger {
+    type FeeAsset = Location;
+
+    fn send(
+        dest_para: u32,
+        payload: Vec<u8>,
+        fee: Option<ExecutionFee<Location>>,
+    ) -> Result<(), ()> {
+        Self::transact(dest_para, payload, fee, |payload| {
+            pallet_confidential_bridge::Call::<Runtime>::receive_confidential { payload }
+        })
+    }
+
+    fn send_ack(
+        dest_para: u32,
+        payload: Vec<u8>,
+        fee: Option<ExecutionFee<Location>>,
+    ) -> Result<(), ()> {
+        Self::transact(dest_para, payload, fee, |payload| {
+            pallet_confidential_bridge::Call::<Runtime>::handle_ack { payload }
+        })
+    }
+}
@@ -18,6 +18,7 @@
 
 mod confidential;
 mod xcm_config;
+pub use confidential::FOREIGN_ASSET_FLAG;
 pub use xcm_config::*;
 
 use core::marker::PhantomData;
@@ -179,6 +180,7 @@ construct_runtime!(
         System: frame_system,
         Balances: pallet_balances,
         Assets: pallet_assets,
+        ForeignAssets: pallet_assets<Instance2>,
         MsgQueue: mock_message_queue,
         PolkadotXcm: pallet_xcm,
         ForeignUniques: pallet_uniques,
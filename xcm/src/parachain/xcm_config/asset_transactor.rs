@@ -15,12 +15,13 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::parachain::{
-    AccountId, Balances, ForeignUniques, constants::KsmLocation,
+    AccountId, Balance, Balances, ForeignAssets, ForeignUniques, constants::KsmLocation,
     location_converter::LocationConverter,
 };
 use xcm::latest::prelude::*;
 use xcm_builder::{
-    ConvertedConcreteId, FungibleAdapter, IsConcrete, NoChecking, NonFungiblesAdapter,
+    ConvertedConcreteId, FungibleAdapter, FungiblesAdapter, IsConcrete, NoChecking,
+    NonFungiblesAdapter,
 };
 use xcm_executor::traits::JustTry;
 
@@ -34,6 +35,15 @@ type LocalAssetTransactor = (
         NoChecking,
         (),
     >,
+    // Reserve-transferred fungibles, e.g. from Asset Hub; shielded via `ForeignRamp`
+    FungiblesAdapter<
+        ForeignAssets,
+        ConvertedConcreteId<Location, Balance, JustTry, JustTry>,
+        LocationConverter,
+        AccountId,
+        NoChecking,
+        (),
+    >,
 );
 
 pub type AssetTransactor = LocalAssetTransactor;
@@ -126,7 +126,11 @@ fn verify_mint_with_vectors() {
 
     let (to_new_bytes, total_new_bytes, minted_ct_bytes) = result.unwrap();
 
-    assert_eq!(minted_ct_bytes, MINTED_CT_64, "minted_ct mismatch");
+    assert_eq!(
+        minted_ct_bytes.to_bytes(),
+        MINTED_CT_64,
+        "minted_ct mismatch"
+    );
     assert_eq!(
         to_new_bytes.as_slice(),
         &MINT_TO_NEW_COMM_32,
@@ -0,0 +1,197 @@
+//! # zkhe-host-functions — Native Range-Proof Verification
+//!
+//! Bulletproof verification dominates the cost of every ZK-ElGamal proof and runs
+//! several times slower in WASM than natively. This crate exposes it as a host
+//! function so the runtime can call into the node's native implementation.
+//!
+//! ## Usage
+//!
+//! Runtime: plug [`HostRangeVerifier`] into the verifier.
+//!
+//! ```ignore
+//! impl pallet_zkhe::Config for Runtime {
+//!     type Verifier = zkhe_verifier::ZkheVerifier<
+//!         RuntimeNetworkId,
+//!         zkhe_host_functions::HostRangeVerifier,
+//!     >;
+//!     // ...
+//! }
+//! ```
+//!
+//! Node: register [`HostFunctions`] with the executor, e.g.
+//! `WasmExecutor<(sp_io::SubstrateHostFunctions, zkhe_host_functions::HostFunctions)>`.
+//!
+//! ## WASM Fallback
+//!
+//! Nodes that do not register the host function cannot execute a runtime that
+//! imports it. Runtimes that must run on such nodes keep the default
+//! `ZkheVerifier<N>` (i.e. `zkhe_verifier::BulletproofRangeVerifier`), which
+//! verifies in pure WASM with identical results.
+//!
+//! ## Caching
+//!
+//! The native side checks proofs through `zkhe_verifier::CachedRangeVerifier`, so a
+//! proof seen during tx-pool validation is not verified again at block import.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_runtime_interface::{
+    pass_by::{PassFatPointerAndRead, PassPointerAndReadCopy},
+    runtime_interface,
+};
+use zkhe_primitives::RangeProofVerifier;
+
+#[cfg(feature = "std")]
+type NativeVerifier = zkhe_verifier::CachedRangeVerifier<zkhe_verifier::BulletproofRangeVerifier>;
+
+/// Host functions for ZK-ElGamal verification.
+#[runtime_interface]
+pub trait ZkheVerify {
+    /// Verify a 64-bit Bulletproof range proof natively.
+    ///
+    /// Same inputs and semantics as [`RangeProofVerifier::verify_range_proof`].
+    fn verify_range_proof(
+        transcript_label: PassFatPointerAndRead<&[u8]>,
+        context: PassFatPointerAndRead<&[u8]>,
+        commit_compressed: PassPointerAndReadCopy<[u8; 32], 32>,
+        proof_bytes: PassFatPointerAndRead<&[u8]>,
+    ) -> bool {
+        NativeVerifier::verify_range_proof(
+            transcript_label,
+            context,
+            &commit_compressed,
+            proof_bytes,
+        )
+        .is_ok()
+    }
+
+    /// Verify an aggregated 64-bit Bulletproof natively.
+    ///
+    /// `commits` is the concatenation of the 32-byte compressed commitments, in
+    /// prover order. Same semantics as
+    /// [`RangeProofVerifier::verify_aggregated_range_proof`].
+    fn verify_aggregated_range_proof(
+        transcript_label: PassFatPointerAndRead<&[u8]>,
+        context: PassFatPointerAndRead<&[u8]>,
+        commits: PassFatPointerAndRead<&[u8]>,
+        proof_bytes: PassFatPointerAndRead<&[u8]>,
+    ) -> bool {
+        if commits.len() % 32 != 0 {
+            return false;
+        }
+        let commits: Vec<[u8; 32]> = commits
+            .chunks_exact(32)
+            .map(|c| c.try_into().expect("chunk is 32 bytes"))
+            .collect();
+        NativeVerifier::verify_aggregated_range_proof(
+            transcript_label,
+            context,
+            &commits,
+            proof_bytes,
+        )
+        .is_ok()
+    }
+}
+
+/// Host functions to register with the node's executor.
+#[cfg(feature = "std")]
+pub type HostFunctions = (zkhe_verify::HostFunctions,);
+
+/// Range verifier that dispatches to the [`zkhe_verify`] host function.
+pub struct HostRangeVerifier;
+
+impl RangeProofVerifier for HostRangeVerifier {
+    fn verify_range_proof(
+        transcript_label: &[u8],
+        context: &[u8],
+        commit_compressed: &[u8; 32],
+        proof_bytes: &[u8],
+    ) -> Result<(), ()> {
+        if zkhe_verify::verify_range_proof(
+            transcript_label,
+            context,
+            *commit_compressed,
+            proof_bytes,
+        ) {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    fn verify_aggregated_range_proof(
+        transcript_label: &[u8],
+        context: &[u8],
+        commits: &[[u8; 32]],
+        proof_bytes: &[u8],
+    ) -> Result<(), ()> {
+        if zkhe_verify::verify_aggregated_range_proof(
+            transcript_label,
+            context,
+            commits.as_flattened(),
+            proof_bytes,
+        ) {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use confidential_assets_primitives::{ZeroNetworkId, ZkVerifier};
+    use zkhe_primitives::labels;
+    use zkhe_vectors::*;
+
+    type HostVerifier = zkhe_verifier::ZkheVerifier<ZeroNetworkId, HostRangeVerifier>;
+    type WasmVerifier = zkhe_verifier::ZkheVerifier<ZeroNetworkId>;
+
+    #[test]
+    fn host_and_wasm_verifiers_agree_on_vectors() {
+        let host = HostVerifier::verify_transfer_sent(
+            &ASSET_ID_BYTES,
+            &SENDER_PK32,
+            &RECEIVER_PK32,
+            &TRANSFER_FROM_OLD_COMM_32,
+            &[],
+            &TRANSFER_DELTA_CT_64,
+            TRANSFER_BUNDLE,
+        )
+        .expect("host verify");
+        let wasm = WasmVerifier::verify_transfer_sent(
+            &ASSET_ID_BYTES,
+            &SENDER_PK32,
+            &RECEIVER_PK32,
+            &TRANSFER_FROM_OLD_COMM_32,
+            &[],
+            &TRANSFER_DELTA_CT_64,
+            TRANSFER_BUNDLE,
+        )
+        .expect("wasm verify");
+        assert_eq!(host, wasm);
+
+        let accepted = HostVerifier::verify_transfer_received(
+            &ASSET_ID_BYTES,
+            &RECEIVER_PK32,
+            &[],
+            &TRANSFER_DELTA_COMM_32,
+            &[TRANSFER_DELTA_COMM_32],
+            ACCEPT_ENVELOPE,
+        );
+        assert!(accepted.is_ok());
+    }
+
+    #[test]
+    fn host_rejects_garbage_proof() {
+        assert!(
+            HostRangeVerifier::verify_range_proof(
+                labels::RANGE_FROM_NEW,
+                &[0u8; 32],
+                &[0u8; 32],
+                &[1, 2, 3]
+            )
+            .is_err()
+        );
+    }
+}
@@ -1,6 +1,14 @@
+//! Deterministic prover/verifier vector generation.
+//!
+//! [`generate_vectors`] runs every prover path from a [`VectorConfig`] and returns the
+//! matching inputs and outputs, including multi-UTXO claims over [`CLAIM_UTXO_COUNTS`]. Downstream runtimes use it to regenerate weight-benchmark
+//! vectors for their own asset id and network id; [`Vectors::to_rust_source`] renders the
+//! `zkhe_vectors` constants.
+
 use crate::{
-    BurnInput, MintInput, ReceiverAcceptInput, SenderInput, prove_burn, prove_mint,
-    prove_receiver_accept, prove_sender_transfer,
+    AuditorHandleInput, BurnInput, MintInput, ProverError, PubkeyOwnershipInput,
+    ReceiverAcceptInput, RekeyInput, SenderInput, prove_auditor_handle, prove_burn, prove_mint,
+    prove_pubkey_ownership, prove_receiver_accept, prove_rekey, prove_sender_transfer,
 };
 use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::{
@@ -9,22 +17,181 @@ use curve25519_dalek::{
 use rand::{RngCore, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use sha2::Sha512;
+use zkhe_primitives::{SDK_VERSION, labels};
 
 fn pedersen_h() -> RistrettoPoint {
-    RistrettoPoint::hash_from_bytes::<Sha512>(b"Zether/PedersenH")
+    RistrettoPoint::hash_from_bytes::<Sha512>(labels::PEDERSEN_H)
 }
 fn to_bytes32(pt: &RistrettoPoint) -> [u8; 32] {
     pt.compress().to_bytes()
 }
 
-/// Generate deterministic vectors for transfer, accept, mint, and burn.
-/// Returned string is written to `zkhe_vectors/src/proofs.rs` or similar.
-pub fn some_valid_proofs() -> String {
-    // ---- common params ----
-    // Use SCALE-encoded u128 = 0 to match runtime's T::AssetId::default()
-    // u128 encodes as 16 bytes little-endian
-    let asset_id = vec![0u8; 16];
-    let network_id = [0u8; 32];
+/// `frame_benchmarking::whitelisted_caller::<AccountId32>()`, SCALE-encoded.
+const WHITELISTED_CALLER: [u8; 32] = [
+    216, 97, 234, 30, 191, 72, 0, 212, 184, 159, 79, 247, 135, 173, 121, 238, 150, 217, 167, 8,
+    200, 91, 87, 218, 126, 184, 249, 221, 237, 166, 18, 145,
+];
+
+/// Pending UTXO counts for which [`generate_vectors`] produces a claim vector.
+pub const CLAIM_UTXO_COUNTS: [usize; 3] = [1, 4, 16];
+
+/// Per-proof seed: `base` with one byte xor-ed, so the default base reproduces the
+/// historical fixed seeds.
+fn seed_at(base: [u8; 32], idx: usize, tag: u8) -> [u8; 32] {
+    let mut seed = base;
+    seed[idx] ^= tag;
+    seed
+}
+
+/// Parameters for [`generate_vectors`].
+#[derive(Clone, Debug)]
+pub struct VectorConfig {
+    /// Asset id bytes as the runtime passes them to the verifier (SCALE-encoded `AssetId`).
+    pub asset_id: Vec<u8>,
+    /// Network id bound into every proof; see [`crate::derive_network_id`].
+    pub network_id: [u8; 32],
+    /// Protocol version; must equal [`SDK_VERSION`].
+    pub sdk_version: u32,
+    /// Base RNG seed; each proof derives its own seed from it.
+    pub rng_seed: [u8; 32],
+    /// SCALE-encoded account the sender key's ownership and rotation proofs are bound to.
+    pub pk_owner_account: Vec<u8>,
+
+    /// Sender's available balance before the transfer.
+    pub sender_balance: u64,
+    /// Amount transferred (and accepted by the receiver).
+    pub transfer_amount: u64,
+    /// Amount minted into the receiver's pending balance.
+    pub mint_amount: u64,
+    /// Burner's available balance and the asset's total supply before the burn.
+    pub burn_balance: u64,
+    /// Amount burned.
+    pub burn_amount: u64,
+    /// Edge case: large mint amount.
+    pub large_mint_amount: u64,
+    /// Edge case: balance burned in full.
+    pub full_burn_amount: u64,
+}
+
+impl Default for VectorConfig {
+    fn default() -> Self {
+        Self {
+            // SCALE-encoded u128 = 0 to match runtime's T::AssetId::default()
+            asset_id: vec![0u8; 16],
+            network_id: [0u8; 32],
+            sdk_version: SDK_VERSION,
+            rng_seed: [0u8; 32],
+            // benchmarks register keys for the whitelisted caller
+            pk_owner_account: WHITELISTED_CALLER.to_vec(),
+            sender_balance: 1_234,
+            transfer_amount: 111,
+            mint_amount: 77,
+            burn_balance: 500,
+            burn_amount: 120,
+            large_mint_amount: 1_000_000_000,
+            full_burn_amount: 1_000,
+        }
+    }
+}
+
+/// A claim over `utxo_comms.len()` pending deposits (ids `0..len`), each of
+/// `transfer_amount`, into an empty available balance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimVector {
+    pub utxo_comms: Vec<[u8; 32]>,
+    /// Pending balance before the claim: the sum of `utxo_comms`.
+    pub pending_old_comm: [u8; 32],
+    /// `count:u16 LE || ids:u64 LE || accept_envelope`.
+    pub claim: Vec<u8>,
+    pub avail_new_comm: [u8; 32],
+    pub pending_new_comm: [u8; 32],
+}
+
+/// Matched prover outputs and verifier inputs produced by [`generate_vectors`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vectors {
+    pub asset_id: Vec<u8>,
+    pub network_id: [u8; 32],
+    pub sender_pk: [u8; 32],
+    pub receiver_pk: [u8; 32],
+
+    // key registration
+    pub pk_owner_account: Vec<u8>,
+    pub sender_pk_ownership_proof: Vec<u8>,
+
+    // key rotation of the sender's pre-transfer balance
+    pub rekey_new_pk: [u8; 32],
+    pub rekey_proof: Vec<u8>,
+
+    // transfer (sender)
+    pub transfer_from_old_comm: [u8; 32],
+    pub transfer_delta_ct: [u8; 64],
+    pub transfer_delta_comm: [u8; 32],
+    pub transfer_bundle: Vec<u8>,
+    pub transfer_from_new_comm: [u8; 32],
+    pub transfer_to_new_comm: [u8; 32],
+
+    // auditor handle for the transfer ciphertext
+    pub auditor_pk: [u8; 32],
+    pub transfer_auditor_handle: Vec<u8>,
+
+    // receiver accept
+    pub accept_envelope: Vec<u8>,
+    pub accept_avail_new_comm: [u8; 32],
+    pub accept_pending_new_comm: [u8; 32],
+
+    // mint
+    pub mint_proof: Vec<u8>,
+    pub minted_ct: [u8; 64],
+    pub mint_to_new_comm: [u8; 32],
+    pub mint_total_new_comm: [u8; 32],
+
+    // burn
+    pub burn_amount_ct: [u8; 64],
+    pub burn_proof: Vec<u8>,
+    pub burn_from_old_comm: [u8; 32],
+    pub burn_total_old_comm: [u8; 32],
+    pub burn_from_new_comm: [u8; 32],
+    pub burn_total_new_comm: [u8; 32],
+
+    // multi-UTXO claims, one per entry of CLAIM_UTXO_COUNTS
+    pub claims: Vec<ClaimVector>,
+
+    // edge case: large mint
+    pub large_mint_value: u64,
+    pub large_mint_proof: Vec<u8>,
+    pub large_mint_ct: [u8; 64],
+    pub large_mint_to_new_comm: [u8; 32],
+    pub large_mint_total_new_comm: [u8; 32],
+
+    // edge case: full burn
+    pub full_burn_value: u64,
+    pub full_burn_proof: Vec<u8>,
+    pub full_burn_ct: [u8; 64],
+    pub full_burn_from_old_comm: [u8; 32],
+    pub full_burn_from_new_comm: [u8; 32],
+    pub full_burn_total_new_comm: [u8; 32],
+
+    // negative vectors
+    pub malformed_truncated_bundle: Vec<u8>,
+    pub malformed_tampered_bundle: Vec<u8>,
+    pub malformed_invalid_point: [u8; 32],
+}
+
+/// Generate deterministic vectors for key registration and rotation, transfer (with an
+/// auditor handle), accept, claim, mint, and burn.
+///
+/// The same config always yields the same bytes.
+///
+/// # Errors
+/// * `ProverError::InvalidInput` - If `sdk_version` is not [`SDK_VERSION`]
+/// * Any prover error, e.g. when the amount schedule overdraws a balance
+pub fn generate_vectors(cfg: &VectorConfig) -> Result<Vectors, ProverError> {
+    if cfg.sdk_version != SDK_VERSION {
+        return Err(ProverError::InvalidInput("unsupported sdk version"));
+    }
+    let asset_id = cfg.asset_id.clone();
+    let network_id = cfg.network_id;
 
     // ---- keys ----
     let sk_sender = Scalar::from(5u64);
@@ -35,8 +202,7 @@ pub fn some_valid_proofs() -> String {
     // ---- commitments/openings ----
     let h = pedersen_h();
 
-    // Sender starts with 1_234 available
-    let from_old_v = 1_234u64;
+    let from_old_v = cfg.sender_balance;
     let from_old_r = Scalar::from(42u64);
     let from_old_c = Scalar::from(from_old_v) * G + from_old_r * h;
 
@@ -45,11 +211,32 @@ pub fn some_valid_proofs() -> String {
     let avail_old_r = Scalar::from(0u64);
     let avail_old_c = RistrettoPoint::identity();
 
-    let dv = 111u64;
+    let dv = cfg.transfer_amount;
+
+    // ===================== KEY REGISTRATION =====================
+    let pk_proof = prove_pubkey_ownership(&PubkeyOwnershipInput {
+        network_id,
+        account: cfg.pk_owner_account.clone(),
+        sk: sk_sender,
+        rng_seed: seed_at(cfg.rng_seed, 3, 0x3C),
+    })?;
+
+    // ===================== KEY ROTATION =====================
+    let sk_rotated = Scalar::from(13u64);
+    let rekey_proof = prove_rekey(&RekeyInput {
+        asset_id: asset_id.clone(),
+        network_id,
+        account: cfg.pk_owner_account.clone(),
+        old_sk: sk_sender,
+        new_sk: sk_rotated,
+        avail_c: from_old_c,
+        avail_value: from_old_v,
+        avail_blind: from_old_r,
+        rng_seed: seed_at(cfg.rng_seed, 4, 0x4D),
+    })?;
 
     // ===================== SENDER TRANSFER =====================
-    let mut seed = [0u8; 32];
-    seed[0] = 7;
+    let seed = seed_at(cfg.rng_seed, 0, 7);
 
     let s_in = SenderInput {
         asset_id: asset_id.clone(),
@@ -63,7 +250,20 @@ pub fn some_valid_proofs() -> String {
         rng_seed: seed,
         fee_c: None,
     };
-    let s_out = prove_sender_transfer(&s_in).expect("sender prover");
+    let s_out = prove_sender_transfer(&s_in)?;
+
+    // ===================== AUDITOR HANDLE =====================
+    let pk_auditor = Scalar::from(17u64) * G;
+    let auditor_handle = prove_auditor_handle(&AuditorHandleInput {
+        asset_id: asset_id.clone(),
+        network_id,
+        pk: pk_sender,
+        auditor_pk: pk_auditor,
+        ct: s_out.delta_ct_bytes,
+        nonce: s_out.nonce,
+        delta_opening: (dv, s_out.delta_rho),
+        rng_seed: seed_at(cfg.rng_seed, 5, 0x5E),
+    })?;
 
     // Re-derive rho used by accept from the same seed
     // Must match the prover's random_scalar usage (256-bit entropy)
@@ -73,21 +273,12 @@ pub fn some_valid_proofs() -> String {
     chacha.fill_bytes(&mut bytes); // second scalar = rho
     let delta_rho = Scalar::from_bytes_mod_order_wide(&bytes);
 
-    // Bytes from sender output
-    let delta_ct_bytes = s_out.delta_ct_bytes; // 64
-    let delta_comm_bytes = s_out.delta_comm_bytes; // 32
-    let sender_bundle = s_out.sender_bundle_bytes; // var
-    let from_new_bytes = s_out.from_new_c; // 32
-    let to_new_bytes = s_out.to_new_c; // 32
-
-    // For completeness compute from_new via algebra too (not exported)
-    // let delta_c = CompressedRistretto(delta_comm_bytes).decompress().unwrap();
-    // let from_new_check = (from_old_c - delta_c).compress().to_bytes();
-
     // ===================== RECEIVER ACCEPT =====================
     let delta_comm = {
         use curve25519_dalek::ristretto::CompressedRistretto;
-        CompressedRistretto(delta_comm_bytes).decompress().unwrap()
+        CompressedRistretto(s_out.delta_comm_bytes)
+            .decompress()
+            .ok_or(ProverError::Malformed("delta commitment"))?
     };
 
     let r_in = ReceiverAcceptInput {
@@ -102,13 +293,56 @@ pub fn some_valid_proofs() -> String {
         delta_value: dv,
         delta_rho,
     };
-    let r_out = prove_receiver_accept(&r_in).expect("receiver accept");
+    let r_out = prove_receiver_accept(&r_in)?;
+
+    // ===================== MULTI-UTXO CLAIMS =====================
+    let claims = CLAIM_UTXO_COUNTS
+        .iter()
+        .map(|&count| {
+            let openings: Vec<(u64, Scalar)> = (0..count)
+                .map(|i| (dv, Scalar::from(1_000 + i as u64)))
+                .collect();
+            let utxos: Vec<RistrettoPoint> = openings
+                .iter()
+                .map(|(v, r)| Scalar::from(*v) * G + r * h)
+                .collect();
+            let total_v = dv
+                .checked_mul(count as u64)
+                .ok_or(ProverError::InvalidInput("claim total overflows"))?;
+            let total_r: Scalar = openings.iter().map(|(_, r)| r).sum();
+            let total_c: RistrettoPoint = utxos.iter().sum();
+
+            let c_out = prove_receiver_accept(&ReceiverAcceptInput {
+                asset_id: asset_id.clone(),
+                network_id,
+                receiver_pk: pk_receiver,
+                avail_old_c,
+                avail_old_opening: (avail_old_v, avail_old_r),
+                pending_old_c: total_c,
+                pending_old_opening: (total_v, total_r),
+                delta_comm: total_c,
+                delta_value: total_v,
+                delta_rho: total_r,
+            })?;
+
+            let mut claim = (count as u16).to_le_bytes().to_vec();
+            for id in 0..count as u64 {
+                claim.extend_from_slice(&id.to_le_bytes());
+            }
+            claim.extend_from_slice(&c_out.accept_envelope);
+
+            Ok(ClaimVector {
+                utxo_comms: utxos.iter().map(to_bytes32).collect(),
+                pending_old_comm: to_bytes32(&total_c),
+                claim,
+                avail_new_comm: c_out.avail_new_c,
+                pending_new_comm: c_out.pending_new_c,
+            })
+        })
+        .collect::<Result<Vec<_>, ProverError>>()?;
 
     // ===================== MINT =====================
-    let mut seed_m = [0u8; 32];
-    seed_m[0] = 0xA5;
-
-    let min = MintInput {
+    let mint_in = |value: u64, rng_seed: [u8; 32]| MintInput {
         asset_id: asset_id.clone(),
         network_id,
         to_pk: pk_receiver,
@@ -116,20 +350,17 @@ pub fn some_valid_proofs() -> String {
         to_pending_old_opening: (0, Scalar::from(0u64)),
         total_old_c: RistrettoPoint::identity(),
         total_old_opening: (0, Scalar::from(0u64)),
-        mint_value: 77,
-        rng_seed: seed_m,
+        mint_value: value,
+        rng_seed,
     };
-    let mout = prove_mint(&min).expect("mint prover");
+    let mout = prove_mint(&mint_in(cfg.mint_amount, seed_at(cfg.rng_seed, 0, 0xA5)))?;
 
     // ===================== BURN =====================
-    let mut seed_b = [0u8; 32];
-    seed_b[1] = 0x5C;
-
-    let from_old_v_b = 500u64;
+    let from_old_v_b = cfg.burn_balance;
     let from_old_r_b = Scalar::from(333u64);
     let from_old_c_b = Scalar::from(from_old_v_b) * G + from_old_r_b * h;
 
-    let total_old_v_b = 500u64;
+    let total_old_v_b = cfg.burn_balance;
     let total_old_r_b = Scalar::from(111u64);
     let total_old_c_b = Scalar::from(total_old_v_b) * G + total_old_r_b * h;
 
@@ -141,36 +372,20 @@ pub fn some_valid_proofs() -> String {
         from_avail_old_opening: (from_old_v_b, from_old_r_b),
         total_old_c: total_old_c_b,
         total_old_opening: (total_old_v_b, total_old_r_b),
-        burn_value: 120,
-        rng_seed: seed_b,
+        burn_value: cfg.burn_amount,
+        rng_seed: seed_at(cfg.rng_seed, 1, 0x5C),
     };
-    let bout = prove_burn(&bin).expect("burn prover");
+    let bout = prove_burn(&bin)?;
 
     // ===================== EDGE CASE: LARGE VALUE MINT =====================
-    // Test near-maximum value (2^63 - 1 fits in i64; use smaller for range proof)
-    let mut seed_large = [0u8; 32];
-    seed_large[0] = 0xBB;
-    let large_value = 1_000_000_000u64; // 1 billion
-
-    let large_mint = MintInput {
-        asset_id: asset_id.clone(),
-        network_id,
-        to_pk: pk_receiver,
-        to_pending_old_c: RistrettoPoint::identity(),
-        to_pending_old_opening: (0, Scalar::from(0u64)),
-        total_old_c: RistrettoPoint::identity(),
-        total_old_opening: (0, Scalar::from(0u64)),
-        mint_value: large_value,
-        rng_seed: seed_large,
-    };
-    let large_mout = prove_mint(&large_mint).expect("large mint prover");
+    let large_mout = prove_mint(&mint_in(
+        cfg.large_mint_amount,
+        seed_at(cfg.rng_seed, 0, 0xBB),
+    ))?;
 
     // ===================== EDGE CASE: FULL BALANCE BURN =====================
     // Burn entire balance (from_new should be zero commitment)
-    let mut seed_full = [0u8; 32];
-    seed_full[2] = 0xFF;
-
-    let full_burn_v = 1000u64;
+    let full_burn_v = cfg.full_burn_amount;
     let full_burn_r = Scalar::from(777u64);
     let full_burn_c = Scalar::from(full_burn_v) * G + full_burn_r * h;
 
@@ -183,12 +398,13 @@ pub fn some_valid_proofs() -> String {
         total_old_c: full_burn_c,
         total_old_opening: (full_burn_v, full_burn_r),
         burn_value: full_burn_v, // burn entire balance
-        rng_seed: seed_full,
+        rng_seed: seed_at(cfg.rng_seed, 2, 0xFF),
     };
-    let full_bout = prove_burn(&full_burn).expect("full burn prover");
+    let full_bout = prove_burn(&full_burn)?;
 
     // ===================== MALFORMED PROOF VECTORS (for negative testing) =====================
     // These are intentionally malformed bytes that should cause verification to fail
+    let sender_bundle = s_out.sender_bundle_bytes;
 
     // Truncated bundle (too short)
     let truncated_bundle: Vec<u8> = sender_bundle[..100].to_vec();
@@ -198,19 +414,98 @@ pub fn some_valid_proofs() -> String {
     tampered_bundle[50] ^= 0xFF; // flip bits at position 50
     tampered_bundle[100] ^= 0xFF; // flip bits at position 100
 
-    // Invalid point (not on curve)
-    let invalid_point: [u8; 32] = [0xFF; 32]; // all 1s is unlikely to be a valid point
+    Ok(Vectors {
+        asset_id,
+        network_id,
+        sender_pk: to_bytes32(&pk_sender),
+        receiver_pk: to_bytes32(&pk_receiver),
+        pk_owner_account: cfg.pk_owner_account.clone(),
+        sender_pk_ownership_proof: pk_proof,
+        rekey_new_pk: to_bytes32(&(sk_rotated * G)),
+        rekey_proof,
+        transfer_from_old_comm: to_bytes32(&from_old_c),
+        transfer_delta_ct: s_out.delta_ct_bytes,
+        transfer_delta_comm: s_out.delta_comm_bytes,
+        transfer_bundle: sender_bundle,
+        transfer_from_new_comm: s_out.from_new_c,
+        transfer_to_new_comm: s_out.to_new_c,
+        auditor_pk: to_bytes32(&pk_auditor),
+        transfer_auditor_handle: auditor_handle,
+        accept_envelope: r_out.accept_envelope,
+        accept_avail_new_comm: r_out.avail_new_c,
+        accept_pending_new_comm: r_out.pending_new_c,
+        mint_proof: mout.proof_bytes,
+        minted_ct: mout.minted_ct_bytes,
+        mint_to_new_comm: mout.to_pending_new_c,
+        mint_total_new_comm: mout.total_new_c,
+        burn_amount_ct: bout.amount_ct_bytes,
+        burn_proof: bout.proof_bytes,
+        burn_from_old_comm: to_bytes32(&from_old_c_b),
+        burn_total_old_comm: to_bytes32(&total_old_c_b),
+        burn_from_new_comm: bout.from_avail_new_c,
+        burn_total_new_comm: bout.total_new_c,
+        claims,
+        large_mint_value: cfg.large_mint_amount,
+        large_mint_proof: large_mout.proof_bytes,
+        large_mint_ct: large_mout.minted_ct_bytes,
+        large_mint_to_new_comm: large_mout.to_pending_new_c,
+        large_mint_total_new_comm: large_mout.total_new_c,
+        full_burn_value: full_burn_v,
+        full_burn_proof: full_bout.proof_bytes,
+        full_burn_ct: full_bout.amount_ct_bytes,
+        full_burn_from_old_comm: to_bytes32(&full_burn_c),
+        full_burn_from_new_comm: full_bout.from_avail_new_c,
+        full_burn_total_new_comm: full_bout.total_new_c,
+        malformed_truncated_bundle: truncated_bundle,
+        malformed_tampered_bundle: tampered_bundle,
+        // Invalid point (not on curve)
+        malformed_invalid_point: [0xFF; 32],
+    })
+}
 
-    // ===================== EXPORT =====================
-    format!(
-        r#"// Auto-generated by bench_vector.rs.
+impl Vectors {
+    /// Render as the Rust constants exported by `zkhe_vectors`.
+    pub fn to_rust_source(&self) -> String {
+        let claims: String = self
+            .claims
+            .iter()
+            .map(|c| {
+                let n = c.utxo_comms.len();
+                format!(
+                    r#"
+// ----- Claim of {n} pending UTXO(s), ids 0..{n} -----
+pub const CLAIM_{n}_UTXO_COMMS:         &[[u8;32]] = &{utxos:?};
+pub const CLAIM_{n}_PENDING_OLD_COMM_32: [u8;32]   = {pending_old:?};
+pub const CLAIM_{n}:                    &[u8]      = &{claim:?};
+pub const CLAIM_{n}_AVAIL_NEW_COMM_32:   [u8;32]   = {avail_new:?};
+pub const CLAIM_{n}_PENDING_NEW_COMM_32: [u8;32]   = {pending_new:?};
+"#,
+                    utxos = c.utxo_comms,
+                    pending_old = c.pending_old_comm,
+                    claim = c.claim,
+                    avail_new = c.avail_new_comm,
+                    pending_new = c.pending_new_comm,
+                )
+            })
+            .collect();
+        format!(
+            r#"// Auto-generated by bench_vector.rs.
 // Deterministic vectors for verifier tests, runtime benches, and XCM tests.
 
-// Asset ID is SCALE-encoded u128 = 0 (16 bytes of zeros)
-pub const ASSET_ID_BYTES: [u8; 16] = [0u8; 16];
+pub const ASSET_ID_BYTES: &[u8] = &{asset_id:?};
+pub const NETWORK_ID: [u8;32] = {network_id:?};
+pub const PROOF_SYSTEM_ID: u8 = {proof_system_id};
 pub const SENDER_PK32:   [u8;32] = {sender_pk:?};
 pub const RECEIVER_PK32: [u8;32] = {receiver_pk:?};
 
+// ----- Key registration -----
+pub const PK_OWNER_ACCOUNT: &[u8] = &{pk_owner:?};
+pub const SENDER_PK_OWNERSHIP_PROOF: &[u8] = &{pk_proof:?};
+
+// ----- Key rotation (sender key, balance TRANSFER_FROM_OLD_COMM_32) -----
+pub const REKEY_NEW_PK32: [u8;32] = {rekey_new_pk:?};
+pub const REKEY_PROOF:    &[u8]   = &{rekey_proof:?};
+
 // ----- Transfer (sender) -----
 pub const TRANSFER_FROM_OLD_COMM_32: [u8;32] = {transfer_from_old:?};
 pub const TRANSFER_DELTA_CT_64:      [u8;64] = {delta_ct:?};
@@ -219,6 +514,10 @@ pub const TRANSFER_BUNDLE:           &[u8]    = &{bundle:?};
 pub const TRANSFER_FROM_NEW_COMM_32: [u8;32] = {transfer_from_new:?};
 pub const TRANSFER_TO_NEW_COMM_32:   [u8;32] = {transfer_to_new:?};
 
+// ----- Auditor handle for the transfer ciphertext (sender key) -----
+pub const AUDITOR_PK32:            [u8;32] = {auditor_pk:?};
+pub const TRANSFER_AUDITOR_HANDLE: &[u8]   = &{auditor_handle:?};
+
 // ----- Receiver accept -----
 pub const ACCEPT_ENVELOPE:              &[u8]    = &{accept_env:?};
 pub const ACCEPT_AVAIL_NEW_COMM_32:     [u8;32] = {accept_avail_new:?};
@@ -237,10 +536,10 @@ pub const BURN_FROM_OLD_COMM_32: [u8;32]  = {burn_from_old:?};
 pub const BURN_TOTAL_OLD_COMM_32:[u8;32]  = {burn_total_old:?};
 pub const BURN_FROM_NEW_COMM_32: [u8;32]  = {burn_from_new:?};
 pub const BURN_TOTAL_NEW_COMM_32:[u8;32]  = {burn_total_new:?};
-
+{claims}
 // ===== EDGE CASE VECTORS =====
 
-// ----- Large value mint (1 billion) -----
+// ----- Large value mint -----
 pub const LARGE_MINT_VALUE: u64 = {large_mint_value};
 pub const LARGE_MINT_PROOF: &[u8] = &{large_mint_proof:?};
 pub const LARGE_MINT_CT_64: [u8;64] = {large_mint_ct:?};
@@ -266,48 +565,70 @@ pub const MALFORMED_TAMPERED_BUNDLE: &[u8] = &{tampered:?};
 // ----- Invalid point (not on curve) -----
 pub const MALFORMED_INVALID_POINT: [u8;32] = {invalid_pt:?};
 "#,
-        // keys
-        sender_pk = to_bytes32(&pk_sender),
-        receiver_pk = to_bytes32(&pk_receiver),
-        // transfer
-        transfer_from_old = to_bytes32(&from_old_c),
-        delta_ct = delta_ct_bytes,
-        delta_comm = delta_comm_bytes,
-        bundle = sender_bundle,
-        transfer_from_new = from_new_bytes,
-        transfer_to_new = to_new_bytes,
-        // accept
-        accept_env = r_out.accept_envelope,
-        accept_avail_new = r_out.avail_new_c,
-        accept_pending_new = r_out.pending_new_c,
-        // mint
-        mint_proof = mout.proof_bytes,
-        minted_ct = mout.minted_ct_bytes,
-        mint_to_new = mout.to_pending_new_c,
-        mint_total_new = mout.total_new_c,
-        // burn
-        burn_ct = bout.amount_ct_bytes,
-        burn_proof = bout.proof_bytes,
-        burn_from_old = to_bytes32(&from_old_c_b),
-        burn_total_old = to_bytes32(&total_old_c_b),
-        burn_from_new = bout.from_avail_new_c,
-        burn_total_new = bout.total_new_c,
-        // edge case: large mint
-        large_mint_value = large_value,
-        large_mint_proof = large_mout.proof_bytes,
-        large_mint_ct = large_mout.minted_ct_bytes,
-        large_mint_to_new = large_mout.to_pending_new_c,
-        large_mint_total_new = large_mout.total_new_c,
-        // edge case: full burn
-        full_burn_value = full_burn_v,
-        full_burn_proof = full_bout.proof_bytes,
-        full_burn_ct = full_bout.amount_ct_bytes,
-        full_burn_from_old = to_bytes32(&full_burn_c),
-        full_burn_from_new = full_bout.from_avail_new_c,
-        full_burn_total_new = full_bout.total_new_c,
-        // negative test vectors
-        truncated = truncated_bundle,
-        tampered = tampered_bundle,
-        invalid_pt = invalid_point,
-    )
+            asset_id = self.asset_id,
+            network_id = self.network_id,
+            proof_system_id = zkhe_primitives::PROOF_SYSTEM_ID,
+            // keys
+            sender_pk = self.sender_pk,
+            receiver_pk = self.receiver_pk,
+            // key registration
+            pk_owner = self.pk_owner_account,
+            pk_proof = self.sender_pk_ownership_proof,
+            // key rotation
+            rekey_new_pk = self.rekey_new_pk,
+            rekey_proof = self.rekey_proof,
+            // transfer
+            transfer_from_old = self.transfer_from_old_comm,
+            delta_ct = self.transfer_delta_ct,
+            delta_comm = self.transfer_delta_comm,
+            bundle = self.transfer_bundle,
+            transfer_from_new = self.transfer_from_new_comm,
+            transfer_to_new = self.transfer_to_new_comm,
+            // auditor handle
+            auditor_pk = self.auditor_pk,
+            auditor_handle = self.transfer_auditor_handle,
+            // accept
+            accept_env = self.accept_envelope,
+            accept_avail_new = self.accept_avail_new_comm,
+            accept_pending_new = self.accept_pending_new_comm,
+            // mint
+            mint_proof = self.mint_proof,
+            minted_ct = self.minted_ct,
+            mint_to_new = self.mint_to_new_comm,
+            mint_total_new = self.mint_total_new_comm,
+            // burn
+            burn_ct = self.burn_amount_ct,
+            burn_proof = self.burn_proof,
+            burn_from_old = self.burn_from_old_comm,
+            burn_total_old = self.burn_total_old_comm,
+            burn_from_new = self.burn_from_new_comm,
+            burn_total_new = self.burn_total_new_comm,
+            // claims
+            claims = claims,
+            // edge case: large mint
+            large_mint_value = self.large_mint_value,
+            large_mint_proof = self.large_mint_proof,
+            large_mint_ct = self.large_mint_ct,
+            large_mint_to_new = self.large_mint_to_new_comm,
+            large_mint_total_new = self.large_mint_total_new_comm,
+            // edge case: full burn
+            full_burn_value = self.full_burn_value,
+            full_burn_proof = self.full_burn_proof,
+            full_burn_ct = self.full_burn_ct,
+            full_burn_from_old = self.full_burn_from_old_comm,
+            full_burn_from_new = self.full_burn_from_new_comm,
+            full_burn_total_new = self.full_burn_total_new_comm,
+            // negative test vectors
+            truncated = self.malformed_truncated_bundle,
+            tampered = self.malformed_tampered_bundle,
+            invalid_pt = self.malformed_invalid_point,
+        )
+    }
+}
+
+/// Generate the default vectors as Rust source for `zkhe_vectors/src/generated.rs`.
+pub fn some_valid_proofs() -> String {
+    generate_vectors(&VectorConfig::default())
+        .expect("default vector config is valid")
+        .to_rust_source()
 }
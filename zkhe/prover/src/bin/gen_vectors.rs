@@ -1,14 +1,78 @@
-use std::{env, fs, path::PathBuf};
-use zkhe_prover::bench_vectors::some_valid_proofs; // move your write_proofs_to_file() into prover::bench_vectors
+//! Write deterministic vectors to `zkhe/vectors/src/generated.rs` (or `--out <path>`).
+//!
+//! Options:
+//!   --asset-id <hex>                  asset id bytes (default: SCALE-encoded u128 = 0)
+//!   --network-id <hex32>              network id bound into every proof (default: zero)
+//!   --genesis-hash <hex32> --para-id <u32>
+//!                                     derive the network id instead of passing it
+//!   --seed <hex32>                    base RNG seed (default: zero)
+//!   --owner-account <hex>             account the key ownership proof is bound to
+//!                                     (default: the benchmark whitelisted caller)
+//!   --out <path>                      output file
+use std::{env, fs, path::PathBuf, process};
+use zkhe_prover::{VectorConfig, derive_network_id, generate_vectors};
+
+fn usage(msg: &str) -> ! {
+    eprintln!("gen_vectors: {msg}");
+    eprintln!(
+        "usage: gen_vectors [--asset-id HEX] [--network-id HEX32 | --genesis-hash HEX32 --para-id N] [--seed HEX32] [--owner-account HEX] [--out PATH]"
+    );
+    process::exit(2)
+}
+
+fn parse_hex(s: &str) -> Vec<u8> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if !s.len().is_multiple_of(2) {
+        usage("hex value has odd length");
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap_or_else(|_| usage("invalid hex")))
+        .collect()
+}
+
+fn parse_hex32(s: &str) -> [u8; 32] {
+    parse_hex(s)
+        .try_into()
+        .unwrap_or_else(|_| usage("expected 32 hex bytes"))
+}
 
 fn main() {
-    // Write into zkhe/vectors/src/generated.rs
-    let workspace = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    let mut cfg = VectorConfig::default();
+    let mut genesis_hash = None;
+    let mut para_id = None;
+    // Write into zkhe/vectors/src/generated.rs by default
+    let mut dst = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
         .parent()
         .unwrap()
-        .to_path_buf();
-    let dst = workspace.join("vectors/src/generated.rs");
-    let code = some_valid_proofs();
-    fs::write(&dst, code).expect("write vectors");
+        .join("vectors/src/generated.rs");
+
+    let mut args = env::args().skip(1);
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage("missing value"));
+        match flag.as_str() {
+            "--asset-id" => cfg.asset_id = parse_hex(&value()),
+            "--network-id" => cfg.network_id = parse_hex32(&value()),
+            "--genesis-hash" => genesis_hash = Some(parse_hex32(&value())),
+            "--para-id" => {
+                para_id = Some(value().parse().unwrap_or_else(|_| usage("invalid para id")))
+            }
+            "--seed" => cfg.rng_seed = parse_hex32(&value()),
+            "--owner-account" => cfg.pk_owner_account = parse_hex(&value()),
+            "--out" => dst = PathBuf::from(value()),
+            other => usage(&format!("unknown argument {other}")),
+        }
+    }
+    match (genesis_hash, para_id) {
+        (Some(hash), Some(id)) => cfg.network_id = derive_network_id(&hash, id),
+        (None, None) => {}
+        _ => usage("--genesis-hash and --para-id must be given together"),
+    }
+
+    let vectors = generate_vectors(&cfg).unwrap_or_else(|e| {
+        eprintln!("gen_vectors: {e}");
+        process::exit(1)
+    });
+    fs::write(&dst, vectors.to_rust_source()).expect("write vectors");
     eprintln!("Wrote {}", dst.display());
 }
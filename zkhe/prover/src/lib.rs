@@ -17,11 +17,44 @@
 //!
 //! - [`prove_mint`] - Convert public assets to confidential (deposit)
 //! - [`prove_burn`] - Convert confidential assets to public (withdraw)
+//! - [`prove_confidential_burn`] - Burn confidential assets without revealing the amount
+//!
+//! ## Split Transfers
+//!
+//! - [`prove_sender_split`] - Pay several recipients out of one debit, for
+//!   `confidential_transfer_split`
+//!
+//! ## Selective Disclosure
+//!
+//! - [`prove_disclosure`] - Prove to anyone that a ciphertext under your key
+//!   decrypts to a given value, without revealing the secret key
+//!
+//! ## Key Registration
+//!
+//! - [`prove_pubkey_ownership`] - Prove knowledge of the secret key behind the
+//!   public key passed to `set_public_key`
+//!
+//! ## Account Closure
+//!
+//! - [`prove_zero_balance`] - Prove an account's available and pending balances are
+//!   zero so it can be closed
+//!
+//! ## Key Rotation
+//!
+//! - [`prove_rekey`] - Re-encrypt the available balance under a new key and prove it,
+//!   for `rotate_public_key`
+//!
+//! ## Auditing
+//!
+//! - [`prove_auditor_handle`] - Give an asset's auditor a decrypt handle for a transfer,
+//!   mint or burn ciphertext, appended to the proof of audited assets
+//! - [`prove_seizure`] - Show, with the auditor key alone, that an audited balance and
+//!   its ciphertext under that key hide the same amount, for `force_unshield`
 //!
 //! ## Quick Start
 //!
 //! ```rust,ignore
-//! use zkhe_prover::{prove_sender_transfer, SenderInput};
+//! use zkhe_prover::{derive_network_id, prove_sender_transfer, SenderInput};
 //! use curve25519_dalek::ristretto::RistrettoPoint;
 //! use curve25519_dalek::scalar::Scalar;
 //!
@@ -32,7 +65,7 @@
 //! // Prepare transfer input
 //! let input = SenderInput {
 //!     asset_id: vec![0u8; 32],
-//!     network_id: [0u8; 32],
+//!     network_id: derive_network_id(&genesis_hash, para_id),
 //!     sender_pk,
 //!     receiver_pk,
 //!     from_old_c: /* sender's current balance commitment */,
@@ -66,18 +99,64 @@
 //! minted_ct(64) || delta_comm(32) || link(192) || len1(2) || rp_pending || len2(2) || rp_total
 //! ```
 //!
+//! **Split Proof:**
+//! ```text
+//! count(2) || total_comm(32) || link(192) || count × (out_comm(32) || link(192))
+//!   || len(2) || range_from_new || count × (len(2) || range_out)
+//! ```
+//!
 //! **Burn Proof:**
 //! ```text
 //! delta_comm(32) || link(192) || len1(2) || rp_avail || len2(2) || rp_total || amount_le(8)
 //! ```
 //!
+//! ## Aggregated Range Proofs
+//!
+//! [`prove_receiver_accept_with`], [`prove_mint_with`] and [`prove_burn_with`]
+//! take a [`RangeProofMode`] (or a [`RangeProofConfig`]). With [`RangeProofMode::Aggregated`] the two range
+//! proofs are replaced by one 2-value Bulletproof in the second section and the
+//! first section is left empty (`len1 = 0`). This shrinks the range-proof bytes
+//! from 2×672 to 736 and cuts verification time by roughly 40%.
+//!
+//! ## Range-Proof Width
+//!
+//! Range proofs are 64 bits wide by default. An asset the chain configures for
+//! 32-bit amounts needs 32-bit proofs: pass a [`RangeProofConfig`] with `bits: 32`
+//! to the `_with` provers (a plain [`RangeProofMode`] converts to a 64-bit config),
+//! or use [`prove_sender_transfer_with`]. A 32-bit proof is 608 bytes instead of
+//! 672 and verifies in roughly half the time; values must stay below `2^32`.
+//!
+//! ## Transfer Floor
+//!
+//! Transfers of an asset with a minimum transfer amount must prove the amount is at
+//! least that floor; build them with [`prove_sender_transfer_above`].
+//!
+//! ## Solana Interop
+//!
+//! With the `solana-interop` feature, [`solana_interop`] converts ciphertexts and
+//! keypairs to and from `solana-zk-sdk` types.
+//!
+//! ## Network Id
+//!
+//! Every input carries a `network_id` that is bound into the proof transcript.
+//! Use [`derive_network_id`] with the target chain's genesis hash and para id;
+//! a proof made for one network does not verify on another.
+//!
+//! ## Submitting Proofs
+//!
+//! Extrinsics take each proof as `proof_system_id(1) || bytes` so the chain can tell
+//! which proof system produced it. Wrap every proof output with [`seal`] before
+//! submitting it; the verifier itself only ever sees the unwrapped bytes.
+//!
 //! ## Security Notes
 //!
 //! - All cryptographic scalars use full 256-bit entropy
-//! - Bulletproofs provide 64-bit range proofs
+//! - Bulletproofs provide 32- or 64-bit range proofs
 //! - Proofs are bound to transcript context for domain separation
 
 pub mod bench_vectors;
+#[cfg(feature = "solana-interop")]
+pub mod solana_interop;
 #[cfg(test)]
 mod tests;
 
@@ -91,13 +170,18 @@ use rand_chacha::ChaCha20Rng;
 use thiserror::Error;
 
 use zkhe_primitives::{
-    Ciphertext, PublicContext, SDK_VERSION, append_point, challenge_scalar as fs_chal, labels,
-    new_transcript, pedersen_h_generator, point_to_bytes,
+    AUDITOR_PROOF_LEN, Ciphertext, DEFAULT_RANGE_BITS, DISCLOSURE_PROOF_LEN,
+    PK_OWNERSHIP_PROOF_LEN, PublicContext, REKEY_PROOF_LEN, SDK_VERSION, SEIZURE_PROOF_LEN,
+    ZERO_BALANCE_PROOF_LEN, append_point, auditor_transcript, challenge_scalar as fs_chal,
+    disclosure_transcript, is_supported_range_bits, labels, new_transcript, pedersen_h_generator,
+    pk_ownership_transcript, point_from_bytes, point_to_bytes, range_context_bytes,
+    rekey_transcript, seizure_transcript, zero_balance_transcript,
 };
 
-// Interop check (optional, behind feature flag)
-#[cfg(feature = "solana-interop")]
-use solana_zk_sdk::encryption::elgamal as sdk_elgamal;
+pub use bench_vectors::{VectorConfig, Vectors, generate_vectors};
+
+/// Compute the `network_id` for prover inputs from a chain's genesis hash and para id.
+pub use zkhe_primitives::{SUPPORTED_RANGE_BITS, derive_network_id, seal};
 
 #[derive(Debug, Error)]
 pub enum ProverError {
@@ -135,13 +219,6 @@ fn pad_or_trim_32(x: &[u8]) -> [u8; 32] {
     out
 }
 
-fn transcript_context_bytes(t: &Transcript) -> [u8; 32] {
-    let mut clone = t.clone();
-    let mut out = [0u8; 32];
-    clone.challenge_bytes(b"ctx", &mut out);
-    out
-}
-
 // Updated: acceptance context must match verifier (receiver_pk, avail_old, pending_old, delta_comm)
 fn accept_ctx_bytes(
     network_id: [u8; 32],
@@ -150,19 +227,18 @@ fn accept_ctx_bytes(
     avail_old: &RistrettoPoint,
     pending_old: &RistrettoPoint,
     delta_comm: &RistrettoPoint,
+    bits: u8,
 ) -> [u8; 32] {
     let mut t = Transcript::new(labels::PROTOCOL);
-    t.append_message(b"proto", labels::PROTOCOL_V);
-    t.append_message(b"sdk_version", &SDK_VERSION.to_le_bytes());
-    t.append_message(b"network_id", &network_id);
-    t.append_message(b"asset_id", &asset_id);
-    append_point(&mut t, b"receiver_pk", receiver_pk);
-    append_point(&mut t, b"avail_old", avail_old);
-    append_point(&mut t, b"pending_old", pending_old);
-    append_point(&mut t, b"delta_comm", delta_comm);
-    let mut out = [0u8; 32];
-    t.challenge_bytes(b"ctx", &mut out);
-    out
+    t.append_message(labels::BIND_PROTO, labels::PROTOCOL_V);
+    t.append_message(labels::BIND_SDK_VERSION, &SDK_VERSION.to_le_bytes());
+    t.append_message(labels::BIND_NETWORK_ID, &network_id);
+    t.append_message(labels::BIND_ASSET_ID, &asset_id);
+    append_point(&mut t, labels::BIND_RECEIVER_PK, receiver_pk);
+    append_point(&mut t, labels::BIND_AVAIL_OLD, avail_old);
+    append_point(&mut t, labels::BIND_PENDING_OLD, pending_old);
+    append_point(&mut t, labels::BIND_DELTA_COMM, delta_comm);
+    range_context_bytes(&t, bits)
 }
 
 /// Encrypt Δv under **sender_pk** (matches verifier Eq2).
@@ -192,10 +268,47 @@ fn encode_link(
     out
 }
 
-/// Produce a 64-bit single-value Bulletproof range proof, with an explicit
+/// Link Σ-proof that `ElGamal(pk; v, k)` and `comm = v·G + rho·H` hide the same `v`,
+/// under a challenge drawn from `t` after binding `comm` (matches the verifier's
+/// `verify_link`).
+fn prove_link<R: RngCore>(
+    t: &mut Transcript,
+    rng: &mut R,
+    pk: &RistrettoPoint,
+    comm: &RistrettoPoint,
+    k: &Scalar,
+    v: u64,
+    rho: &Scalar,
+) -> [u8; 192] {
+    let h = pedersen_h_generator();
+    let a_k = random_scalar(rng);
+    let a_v = random_scalar(rng);
+    let a_r = random_scalar(rng);
+    let a1 = a_k * G;
+    let a2 = a_v * G + a_k * pk;
+    let a3 = a_v * G + a_r * h;
+
+    append_point(t, labels::BIND_DELTA_COMM, comm);
+    append_point(t, labels::SIGMA_A1, &a1);
+    append_point(t, labels::SIGMA_A2, &a2);
+    append_point(t, labels::SIGMA_A3, &a3);
+    let c = fs_chal(t, labels::CHAL_EQ);
+
+    encode_link(
+        &a1,
+        &a2,
+        &a3,
+        &(a_k + c * k),
+        &(a_v + c * Scalar::from(v)),
+        &(a_r + c * rho),
+    )
+}
+
+/// Produce a `bits`-wide single-value Bulletproof range proof, with an explicit
 /// `transcript_label` folded into the transcript so sender/receiver proofs use
 /// distinct transcript RNG streams.
 fn prove_range_u64(
+    bits: u8,
     transcript_label: &[u8],
     ctx_bytes: &[u8],
     commit_compressed: &[u8; 32],
@@ -205,10 +318,12 @@ fn prove_range_u64(
     use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
     use curve25519_dalek_ng as dalek_ng;
 
+    check_fits(bits, value_u64)?;
+
     // derive H in non-ng dalek, then convert to ng
     fn pedersen_h_generator_ng() -> dalek_ng::ristretto::RistrettoPoint {
         let h_std = curve25519_dalek::ristretto::RistrettoPoint::hash_from_bytes::<sha2::Sha512>(
-            b"Zether/PedersenH",
+            labels::PEDERSEN_H,
         );
         let bytes = h_std.compress().to_bytes();
         dalek_ng::ristretto::CompressedRistretto(bytes)
@@ -216,11 +331,11 @@ fn prove_range_u64(
             .expect("valid H")
     }
 
-    let mut t = merlin::Transcript::new(b"bp64");
+    let mut t = merlin::Transcript::new(labels::RANGE_TRANSCRIPT);
     // IMPORTANT: fold in the caller-provided label (must match verifier usage).
-    t.append_message(b"label", transcript_label);
-    t.append_message(b"ctx", ctx_bytes);
-    t.append_message(b"commit", commit_compressed);
+    t.append_message(labels::RANGE_LABEL, transcript_label);
+    t.append_message(labels::CTX, ctx_bytes);
+    t.append_message(labels::RANGE_COMMIT, commit_compressed);
 
     let blind_ng = dalek_ng::scalar::Scalar::from_bytes_mod_order(blind.to_bytes());
 
@@ -228,15 +343,177 @@ fn prove_range_u64(
         B: dalek_ng::constants::RISTRETTO_BASEPOINT_POINT,
         B_blinding: pedersen_h_generator_ng(),
     };
-    let bp_gens = BulletproofGens::new(64, 1);
+    let bp_gens = BulletproofGens::new(bits.into(), 1);
+
+    let mut rng = range_proof_rng(&t, &[value_u64], &[blind]);
+    let (proof, _bp_commit) = RangeProof::prove_single_with_rng(
+        &bp_gens,
+        &pg,
+        &mut t,
+        value_u64,
+        &blind_ng,
+        bits.into(),
+        &mut rng,
+    )
+    .map_err(|_| ProverError::RangeProof("bulletproof generation failed"))?;
 
-    let (proof, _bp_commit) =
-        RangeProof::prove_single(&bp_gens, &pg, &mut t, value_u64, &blind_ng, 64)
-            .map_err(|_| ProverError::RangeProof("bulletproof generation failed"))?;
+    Ok(proof.to_bytes())
+}
+
+/// Nonce RNG for a Bulletproof: the range transcript rekeyed with the secret witness.
+///
+/// Fixed external entropy makes proofs a deterministic function of their inputs, so
+/// generated vectors are reproducible; the witness keeps the nonces secret.
+fn range_proof_rng(t: &Transcript, values: &[u64], blinds: &[&Scalar]) -> merlin::TranscriptRng {
+    use rand_chacha_03::rand_core::SeedableRng as _;
+    let mut builder = t.build_rng();
+    for (v, r) in values.iter().zip(blinds) {
+        builder = builder
+            .rekey_with_witness_bytes(labels::WITNESS_VALUE, &v.to_le_bytes())
+            .rekey_with_witness_bytes(labels::WITNESS_BLIND, r.as_bytes());
+    }
+    builder.finalize(&mut rand_chacha_03::ChaCha20Rng::from_seed([0u8; 32]))
+}
+
+/// Produce one aggregated `bits`-wide Bulletproof over two commitments.
+///
+/// Transcript matches [`prove_range_u64`] with both commitments folded in order.
+fn prove_range_u64_aggregated(
+    bits: u8,
+    transcript_label: &[u8],
+    ctx_bytes: &[u8],
+    commits: [&[u8; 32]; 2],
+    values: [u64; 2],
+    blinds: [&Scalar; 2],
+) -> Result<Vec<u8>, ProverError> {
+    use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
+    use curve25519_dalek_ng as dalek_ng;
+
+    for v in values {
+        check_fits(bits, v)?;
+    }
+
+    let h_ng =
+        dalek_ng::ristretto::CompressedRistretto(pedersen_h_generator().compress().to_bytes())
+            .decompress()
+            .expect("valid H");
+
+    let mut t = merlin::Transcript::new(labels::RANGE_TRANSCRIPT);
+    t.append_message(labels::RANGE_LABEL, transcript_label);
+    t.append_message(labels::CTX, ctx_bytes);
+    for c in commits {
+        t.append_message(labels::RANGE_COMMIT, c);
+    }
+
+    let blinds_ng = blinds.map(|b| dalek_ng::scalar::Scalar::from_bytes_mod_order(b.to_bytes()));
+    let pg = PedersenGens {
+        B: dalek_ng::constants::RISTRETTO_BASEPOINT_POINT,
+        B_blinding: h_ng,
+    };
+    let bp_gens = BulletproofGens::new(bits.into(), 2);
+
+    let mut rng = range_proof_rng(&t, &values, &blinds);
+    let (proof, _bp_commits) = RangeProof::prove_multiple_with_rng(
+        &bp_gens,
+        &pg,
+        &mut t,
+        &values,
+        &blinds_ng,
+        bits.into(),
+        &mut rng,
+    )
+    .map_err(|_| ProverError::RangeProof("aggregated bulletproof generation failed"))?;
 
     Ok(proof.to_bytes())
 }
 
+/// Encoding of the two range proofs in accept, mint and burn proofs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RangeProofMode {
+    /// Two single-value proofs: `len1 || rp1 || len2 || rp2`.
+    #[default]
+    Separate,
+    /// One aggregated 2-value proof: `len1 = 0 || len2 || rp_agg`.
+    Aggregated,
+}
+
+/// How the range proofs of a proof are built: their encoding and bit width.
+///
+/// The width must be the one the chain stores for the asset; a proof of any other
+/// width is rejected. Amounts must be below `2^bits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeProofConfig {
+    pub mode: RangeProofMode,
+    /// One of [`SUPPORTED_RANGE_BITS`].
+    pub bits: u8,
+}
+
+impl RangeProofConfig {
+    /// `self` with range proofs `bits` wide.
+    pub fn with_bits(self, bits: u8) -> Self {
+        Self { bits, ..self }
+    }
+}
+
+impl Default for RangeProofConfig {
+    fn default() -> Self {
+        RangeProofMode::default().into()
+    }
+}
+
+impl From<RangeProofMode> for RangeProofConfig {
+    fn from(mode: RangeProofMode) -> Self {
+        Self {
+            mode,
+            bits: DEFAULT_RANGE_BITS,
+        }
+    }
+}
+
+fn check_range_bits(bits: u8) -> Result<(), ProverError> {
+    if !is_supported_range_bits(bits) {
+        return Err(ProverError::InvalidInput(
+            "unsupported range-proof bit width",
+        ));
+    }
+    Ok(())
+}
+
+/// Bulletproofs would happily emit a proof that fails to verify for an out-of-range value.
+fn check_fits(bits: u8, value: u64) -> Result<(), ProverError> {
+    if bits < 64 && value >> bits != 0 {
+        return Err(ProverError::RangeProof(
+            "value exceeds range-proof bit width",
+        ));
+    }
+    Ok(())
+}
+
+/// Produce the two length-prefixed range-proof sections for `config`.
+///
+/// In [`RangeProofMode::Aggregated`] the first section is empty.
+fn prove_range_pair(
+    config: RangeProofConfig,
+    labels: [&[u8]; 2],
+    agg_label: &[u8],
+    ctx_bytes: &[u8],
+    commits: [&[u8; 32]; 2],
+    values: [u64; 2],
+    blinds: [&Scalar; 2],
+) -> Result<(Vec<u8>, Vec<u8>), ProverError> {
+    let bits = config.bits;
+    match config.mode {
+        RangeProofMode::Separate => Ok((
+            prove_range_u64(bits, labels[0], ctx_bytes, commits[0], values[0], blinds[0])?,
+            prove_range_u64(bits, labels[1], ctx_bytes, commits[1], values[1], blinds[1])?,
+        )),
+        RangeProofMode::Aggregated => Ok((
+            Vec::new(),
+            prove_range_u64_aggregated(bits, agg_label, ctx_bytes, commits, values, blinds)?,
+        )),
+    }
+}
+
 // ========================= Sender Phase (unchanged) =========================
 
 pub struct SenderInput {
@@ -268,6 +545,10 @@ pub struct SenderOutput {
     pub delta_comm_bytes: [u8; 32],
     pub from_new_c: [u8; 32],
     pub to_new_c: [u8; 32], // computed for convenience (not applied on-chain in phase 1)
+    /// ElGamal nonce of `delta_ct_bytes` and blinding of `delta_comm_bytes`, for
+    /// [`prove_auditor_handle`].
+    pub nonce: Scalar,
+    pub delta_rho: Scalar,
 }
 
 /// Generate a ZK proof for the sender side of a confidential transfer.
@@ -285,6 +566,36 @@ pub struct SenderOutput {
 /// * `ProverError::Overflow` - If balance arithmetic would overflow/underflow
 /// * `ProverError::RangeProof` - If Bulletproof generation fails
 pub fn prove_sender_transfer(inp: &SenderInput) -> Result<SenderOutput, ProverError> {
+    prove_sender_transfer_with(inp, DEFAULT_RANGE_BITS)
+}
+
+/// [`prove_sender_transfer`] with a `bits`-wide range proof.
+pub fn prove_sender_transfer_with(
+    inp: &SenderInput,
+    bits: u8,
+) -> Result<SenderOutput, ProverError> {
+    sender_transfer(inp, bits, None)
+}
+
+/// [`prove_sender_transfer_with`] for an asset with a transfer floor: also proves
+/// the amount is at least `floor`.
+///
+/// # Errors
+/// * `ProverError::InvalidInput` - If `inp.delta_value` is below `floor`
+pub fn prove_sender_transfer_above(
+    inp: &SenderInput,
+    bits: u8,
+    floor: u64,
+) -> Result<SenderOutput, ProverError> {
+    sender_transfer(inp, bits, Some(floor))
+}
+
+fn sender_transfer(
+    inp: &SenderInput,
+    bits: u8,
+    floor: Option<u64>,
+) -> Result<SenderOutput, ProverError> {
+    check_range_bits(bits)?;
     let (v_from_old_u64, r_from_old) = inp.from_old_opening;
     let v_from_old = Scalar::from(v_from_old_u64);
     let dv_u64 = inp.delta_value;
@@ -304,21 +615,11 @@ pub fn prove_sender_transfer(inp: &SenderInput) -> Result<SenderOutput, ProverEr
 
     // SDK interop check (only when solana-interop feature is enabled)
     #[cfg(feature = "solana-interop")]
-    {
-        let sdk_ct = {
-            use solana_zk_sdk::encryption::elgamal::DecryptHandle;
-            use solana_zk_sdk::encryption::pedersen::PedersenCommitment;
-            let c_bytes = delta_ct.C.compress().to_bytes();
-            let d_bytes = delta_ct.D.compress().to_bytes();
-            let commit = PedersenCommitment::from_bytes(&c_bytes).expect("valid point");
-            let handle = DecryptHandle::from_bytes(&d_bytes).expect("valid point");
-            sdk_elgamal::ElGamalCiphertext {
-                commitment: commit,
-                handle,
-            }
-        };
-        assert_eq!(sdk_ct.to_bytes(), delta_ct.to_bytes());
-    }
+    debug_assert_eq!(
+        solana_interop::ciphertext_from_sdk(&solana_interop::ciphertext_to_sdk(&delta_ct)?)?
+            .to_bytes(),
+        delta_ct.to_bytes()
+    );
 
     // Public context
     let ctx = PublicContext {
@@ -339,9 +640,9 @@ pub fn prove_sender_transfer(inp: &SenderInput) -> Result<SenderOutput, ProverEr
     let a2 = a_v * G + a_k * inp.sender_pk;
     let a3 = a_v * G + a_r * h;
 
-    append_point(&mut t, b"a1", &a1);
-    append_point(&mut t, b"a2", &a2);
-    append_point(&mut t, b"a3", &a3);
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    append_point(&mut t, labels::SIGMA_A3, &a3);
 
     // Challenge
     let c = fs_chal(&mut t, labels::CHAL_EQ);
@@ -356,12 +657,13 @@ pub fn prove_sender_transfer(inp: &SenderInput) -> Result<SenderOutput, ProverEr
     let to_new_c = inp.to_old_c + delta_c;
 
     // Sender range proof bound to sender transcript context bytes
-    let ctx_bytes = transcript_context_bytes(&t);
+    let ctx_bytes = range_context_bytes(&t, bits);
     let from_new_bytes = point_to_bytes(&from_new_c);
     let to_new_bytes = point_to_bytes(&to_new_c);
 
     let range_from = prove_range_u64(
-        b"range_from_new", // MUST match verifier call-site label
+        bits,
+        labels::RANGE_FROM_NEW,
         &ctx_bytes,
         &from_new_bytes,
         v_from_old_u64
@@ -377,6 +679,21 @@ pub fn prove_sender_transfer(inp: &SenderInput) -> Result<SenderOutput, ProverEr
     bundle.extend_from_slice(&(range_from.len() as u16).to_le_bytes());
     bundle.extend_from_slice(&range_from);
     bundle.extend_from_slice(&(0u16).to_le_bytes()); // len2 = 0
+    if let Some(floor) = floor {
+        let above_floor = dv_u64
+            .checked_sub(floor)
+            .ok_or(ProverError::InvalidInput("transfer amount below floor"))?;
+        let range_floor = prove_range_u64(
+            bits,
+            labels::RANGE_AMOUNT_FLOOR,
+            &ctx_bytes,
+            &point_to_bytes(&(delta_c - Scalar::from(floor) * G)),
+            above_floor,
+            &rho,
+        )?;
+        bundle.extend_from_slice(&(range_floor.len() as u16).to_le_bytes());
+        bundle.extend_from_slice(&range_floor);
+    }
 
     let mut delta_comm_bytes = [0u8; 32];
     delta_comm_bytes.copy_from_slice(delta_c.compress().as_bytes());
@@ -387,6 +704,197 @@ pub fn prove_sender_transfer(inp: &SenderInput) -> Result<SenderOutput, ProverEr
         delta_comm_bytes,
         from_new_c: from_new_bytes,
         to_new_c: to_new_bytes,
+        nonce: k,
+        delta_rho: rho,
+    })
+}
+
+// ========================= Split Sender Phase =========================
+
+pub struct SplitInput {
+    pub asset_id: Vec<u8>,
+    pub network_id: [u8; 32],
+
+    pub sender_pk: RistrettoPoint,
+    pub from_old_opening: (u64, Scalar),
+
+    /// `(recipient_pk, value)` of each output, in the order they are submitted.
+    pub outputs: Vec<(RistrettoPoint, u64)>,
+
+    /// Deterministic RNG seed (tests).
+    pub rng_seed: [u8; 32],
+}
+
+pub struct SplitOutput {
+    /// `total` of `confidential_transfer_split`, encrypted under the sender's key.
+    pub total_ct_bytes: [u8; 64],
+    /// Each output encrypted under its recipient's key, in input order.
+    pub output_ct_bytes: Vec<[u8; 64]>,
+    /// Pedersen commitment to each output, which the recipient's pending balance grows
+    /// by, and its blinding, which the recipient needs to claim it.
+    pub output_comm_bytes: Vec<[u8; 32]>,
+    pub output_blindings: Vec<Scalar>,
+    pub proof_bytes: Vec<u8>,
+    pub from_new_c: [u8; 32],
+}
+
+/// Generate a ZK proof for paying several recipients out of one debit.
+///
+/// Each output is encrypted under its recipient's key and committed to on its own;
+/// the proof shows the output commitments add up to the committed total.
+///
+/// # Errors
+/// * `ProverError::InvalidInput` - If there are no outputs or more than `u16::MAX`
+/// * `ProverError::Overflow` - If the total overflows or exceeds the sender's balance
+/// * `ProverError::RangeProof` - If Bulletproof generation fails
+pub fn prove_sender_split(inp: &SplitInput) -> Result<SplitOutput, ProverError> {
+    prove_sender_split_with(inp, DEFAULT_RANGE_BITS)
+}
+
+/// [`prove_sender_split`] with `bits`-wide range proofs.
+pub fn prove_sender_split_with(inp: &SplitInput, bits: u8) -> Result<SplitOutput, ProverError> {
+    sender_split(inp, bits, 0)
+}
+
+/// [`prove_sender_split_with`] for an asset with a transfer floor: every output must
+/// be at least `floor`.
+///
+/// # Errors
+/// * `ProverError::InvalidInput` - If an output is below `floor`
+pub fn prove_sender_split_above(
+    inp: &SplitInput,
+    bits: u8,
+    floor: u64,
+) -> Result<SplitOutput, ProverError> {
+    sender_split(inp, bits, floor)
+}
+
+struct SplitPart {
+    pk: RistrettoPoint,
+    value: u64,
+    k: Scalar,
+    rho: Scalar,
+    ct: Ciphertext,
+    comm: RistrettoPoint,
+}
+
+fn sender_split(inp: &SplitInput, bits: u8, floor: u64) -> Result<SplitOutput, ProverError> {
+    check_range_bits(bits)?;
+    if inp.outputs.is_empty() || inp.outputs.len() > u16::MAX as usize {
+        return Err(ProverError::InvalidInput("split needs 1 to 65535 outputs"));
+    }
+    let (v_from_old_u64, r_from_old) = inp.from_old_opening;
+    let h = pedersen_h_generator();
+    let mut rng = ChaCha20Rng::from_seed(inp.rng_seed);
+
+    let mut parts = Vec::with_capacity(inp.outputs.len());
+    for (pk, value) in &inp.outputs {
+        if *value < floor {
+            return Err(ProverError::InvalidInput("split output below floor"));
+        }
+        let k = random_scalar(&mut rng);
+        let rho = random_scalar(&mut rng);
+        parts.push(SplitPart {
+            pk: *pk,
+            value: *value,
+            k,
+            rho,
+            ct: elgamal_encrypt_delta(pk, *value, &k),
+            comm: Scalar::from(*value) * G + rho * h,
+        });
+    }
+    let total_u64 = parts
+        .iter()
+        .try_fold(0u64, |acc, p| acc.checked_add(p.value))
+        .ok_or(ProverError::Overflow("split total"))?;
+    let k_total = random_scalar(&mut rng);
+    let rho_total: Scalar = parts.iter().map(|p| p.rho).sum();
+    let total_ct = elgamal_encrypt_delta(&inp.sender_pk, total_u64, &k_total);
+    let total_comm = Scalar::from(total_u64) * G + rho_total * h;
+
+    // Public context, then every output ciphertext and its key
+    let ctx = PublicContext {
+        network_id: inp.network_id,
+        sdk_version: SDK_VERSION,
+        asset_id: pad_or_trim_32(&inp.asset_id),
+        sender_pk: inp.sender_pk,
+        receiver_pk: inp.sender_pk,
+        auditor_pk: None,
+        fee_commitment: RistrettoPoint::identity(),
+        ciphertext_out: total_ct,
+        ciphertext_in: None,
+    };
+    let mut t = transcript_for(&ctx);
+    t.append_message(labels::TRANSFER_SPLIT, &(parts.len() as u16).to_le_bytes());
+    for p in &parts {
+        append_point(&mut t, labels::BIND_RECEIVER_PK, &p.pk);
+        append_point(&mut t, labels::BIND_OUT_C, &p.ct.C);
+        append_point(&mut t, labels::BIND_OUT_D, &p.ct.D);
+    }
+
+    let total_link = prove_link(
+        &mut t,
+        &mut rng,
+        &inp.sender_pk,
+        &total_comm,
+        &k_total,
+        total_u64,
+        &rho_total,
+    );
+    let links = parts
+        .iter()
+        .map(|p| prove_link(&mut t, &mut rng, &p.pk, &p.comm, &p.k, p.value, &p.rho))
+        .collect::<Vec<_>>();
+
+    // Range proofs: the sender's new balance, then every output above the floor
+    let v_from_new = v_from_old_u64
+        .checked_sub(total_u64)
+        .ok_or(ProverError::Overflow("sender balance - total"))?;
+    let r_from_new = r_from_old - rho_total;
+    let from_new_bytes = point_to_bytes(&(Scalar::from(v_from_new) * G + r_from_new * h));
+    let ctx_bytes = range_context_bytes(&t, bits);
+
+    let mut ranges = Vec::with_capacity(parts.len() + 1);
+    ranges.push(prove_range_u64(
+        bits,
+        labels::RANGE_FROM_NEW,
+        &ctx_bytes,
+        &from_new_bytes,
+        v_from_new,
+        &r_from_new,
+    )?);
+    for p in &parts {
+        ranges.push(prove_range_u64(
+            bits,
+            labels::RANGE_SPLIT_OUTPUT,
+            &ctx_bytes,
+            &point_to_bytes(&(p.comm - Scalar::from(floor) * G)),
+            p.value - floor,
+            &p.rho,
+        )?);
+    }
+
+    // count(2) || total part || output parts || range sections
+    let mut proof = Vec::new();
+    proof.extend_from_slice(&(parts.len() as u16).to_le_bytes());
+    proof.extend_from_slice(total_comm.compress().as_bytes());
+    proof.extend_from_slice(&total_link);
+    for (p, link) in parts.iter().zip(&links) {
+        proof.extend_from_slice(p.comm.compress().as_bytes());
+        proof.extend_from_slice(link);
+    }
+    for range in &ranges {
+        proof.extend_from_slice(&(range.len() as u16).to_le_bytes());
+        proof.extend_from_slice(range);
+    }
+
+    Ok(SplitOutput {
+        total_ct_bytes: total_ct.to_bytes(),
+        output_ct_bytes: parts.iter().map(|p| p.ct.to_bytes()).collect(),
+        output_comm_bytes: parts.iter().map(|p| point_to_bytes(&p.comm)).collect(),
+        output_blindings: parts.iter().map(|p| p.rho).collect(),
+        proof_bytes: proof,
+        from_new_c: from_new_bytes,
     })
 }
 
@@ -436,6 +944,16 @@ pub struct ReceiverAcceptOutput {
 pub fn prove_receiver_accept(
     inp: &ReceiverAcceptInput,
 ) -> Result<ReceiverAcceptOutput, ProverError> {
+    prove_receiver_accept_with(inp, RangeProofMode::Separate)
+}
+
+/// [`prove_receiver_accept`] with an explicit range-proof encoding and width.
+pub fn prove_receiver_accept_with(
+    inp: &ReceiverAcceptInput,
+    config: impl Into<RangeProofConfig>,
+) -> Result<ReceiverAcceptOutput, ProverError> {
+    let config = config.into();
+    check_range_bits(config.bits)?;
     let (v_av_u64, r_av_old) = inp.avail_old_opening;
     let (v_pend_u64, r_pend_old) = inp.pending_old_opening;
 
@@ -464,30 +982,28 @@ pub fn prove_receiver_accept(
         &inp.avail_old_c,
         &inp.pending_old_c,
         &inp.delta_comm,
+        config.bits,
     );
 
     let avail_new_bytes = point_to_bytes(&avail_new_c);
     let pending_new_bytes = point_to_bytes(&pending_new_c);
 
     // Produce both range proofs with the exact labels the verifier expects.
-    let rp_avail_new = prove_range_u64(
-        b"range_avail_new",
+    let (rp_avail_new, rp_pending_new) = prove_range_pair(
+        config,
+        [labels::RANGE_AVAIL_NEW, labels::RANGE_PENDING_NEW],
+        labels::RANGE_ACCEPT_AGG,
         &ctx_bytes,
-        &avail_new_bytes,
-        v_av_u64
-            .checked_add(dv_u64)
-            .ok_or(ProverError::Overflow("available balance + delta"))?,
-        &(r_av_old + rho),
-    )?;
-
-    let rp_pending_new = prove_range_u64(
-        b"range_pending_new",
-        &ctx_bytes,
-        &pending_new_bytes,
-        v_pend_u64
-            .checked_sub(dv_u64)
-            .ok_or(ProverError::Overflow("pending balance - delta"))?,
-        &(r_pend_old - rho),
+        [&avail_new_bytes, &pending_new_bytes],
+        [
+            v_av_u64
+                .checked_add(dv_u64)
+                .ok_or(ProverError::Overflow("available balance + delta"))?,
+            v_pend_u64
+                .checked_sub(dv_u64)
+                .ok_or(ProverError::Overflow("pending balance - delta"))?,
+        ],
+        [&(r_av_old + rho), &(r_pend_old - rho)],
     )?;
 
     // Envelope: ΔC(32) || len1(2) || rp_avail_new || len2(2) || rp_pending_new
@@ -534,6 +1050,10 @@ pub struct MintOutput {
     pub proof_bytes: Vec<u8>,       // matches verifier's verify_mint layout
     pub to_pending_new_c: [u8; 32], // convenience
     pub total_new_c: [u8; 32],      // convenience
+    /// ElGamal nonce of `minted_ct_bytes` and blinding of the minted commitment, for
+    /// [`prove_auditor_handle`].
+    pub nonce: Scalar,
+    pub delta_rho: Scalar,
 }
 
 /// Generate a ZK proof for minting (depositing) public assets into confidential balance.
@@ -551,6 +1071,16 @@ pub struct MintOutput {
 /// * `ProverError::Overflow` - If total supply would overflow
 /// * `ProverError::RangeProof` - If Bulletproof generation fails
 pub fn prove_mint(inp: &MintInput) -> Result<MintOutput, ProverError> {
+    prove_mint_with(inp, RangeProofMode::Separate)
+}
+
+/// [`prove_mint`] with an explicit range-proof encoding and width.
+pub fn prove_mint_with(
+    inp: &MintInput,
+    config: impl Into<RangeProofConfig>,
+) -> Result<MintOutput, ProverError> {
+    let config = config.into();
+    check_range_bits(config.bits)?;
     let (v_to_old_u64, r_to_old) = inp.to_pending_old_opening;
     let (v_total_old_u64, r_total_old) = inp.total_old_opening;
 
@@ -590,9 +1120,9 @@ pub fn prove_mint(inp: &MintInput) -> Result<MintOutput, ProverError> {
     let a2 = a_v * G + a_k * inp.to_pk;
     let a3 = a_v * G + a_r * h;
 
-    append_point(&mut t, b"a1", &a1);
-    append_point(&mut t, b"a2", &a2);
-    append_point(&mut t, b"a3", &a3);
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    append_point(&mut t, labels::SIGMA_A3, &a3);
 
     let c = fs_chal(&mut t, labels::CHAL_EQ);
     let z_k = a_k + c * k;
@@ -603,29 +1133,26 @@ pub fn prove_mint(inp: &MintInput) -> Result<MintOutput, ProverError> {
     let to_new = inp.to_pending_old_c + delta_c;
     let total_new = inp.total_old_c + delta_c;
 
-    let ctx_bytes = transcript_context_bytes(&t);
+    let ctx_bytes = range_context_bytes(&t, config.bits);
     let to_new_bytes = point_to_bytes(&to_new);
     let total_new_bytes = point_to_bytes(&total_new);
 
     // Range proofs
-    let rp_to_new = prove_range_u64(
-        b"range_to_pending_new",
-        &ctx_bytes,
-        &to_new_bytes,
-        v_to_old_u64
-            .checked_add(dv_u64)
-            .ok_or(ProverError::Overflow("pending balance + mint amount"))?,
-        &(r_to_old + rho),
-    )?;
-
-    let rp_total_new = prove_range_u64(
-        b"range_total_new",
+    let (rp_to_new, rp_total_new) = prove_range_pair(
+        config,
+        [labels::RANGE_TO_PENDING_NEW, labels::RANGE_TOTAL_NEW],
+        labels::RANGE_MINT_AGG,
         &ctx_bytes,
-        &total_new_bytes,
-        v_total_old_u64
-            .checked_add(dv_u64)
-            .ok_or(ProverError::Overflow("total supply + mint amount"))?,
-        &(r_total_old + rho),
+        [&to_new_bytes, &total_new_bytes],
+        [
+            v_to_old_u64
+                .checked_add(dv_u64)
+                .ok_or(ProverError::Overflow("pending balance + mint amount"))?,
+            v_total_old_u64
+                .checked_add(dv_u64)
+                .ok_or(ProverError::Overflow("total supply + mint amount"))?,
+        ],
+        [&(r_to_old + rho), &(r_total_old + rho)],
     )?;
 
     // Assemble proof bytes:
@@ -650,6 +1177,8 @@ pub fn prove_mint(inp: &MintInput) -> Result<MintOutput, ProverError> {
         proof_bytes: proof,
         to_pending_new_c: to_new_bytes,
         total_new_c: total_new_bytes,
+        nonce: k,
+        delta_rho: rho,
     })
 }
 
@@ -680,6 +1209,10 @@ pub struct BurnOutput {
     pub proof_bytes: Vec<u8>,       // matches verifier's verify_burn layout
     pub from_avail_new_c: [u8; 32], // convenience
     pub total_new_c: [u8; 32],      // convenience
+    /// ElGamal nonce of `amount_ct_bytes` and blinding of the burned commitment, for
+    /// [`prove_auditor_handle`].
+    pub nonce: Scalar,
+    pub delta_rho: Scalar,
 }
 
 /// Generate a ZK proof for burning (withdrawing) confidential assets to public balance.
@@ -697,6 +1230,47 @@ pub struct BurnOutput {
 /// * `ProverError::Overflow` - If balance would underflow
 /// * `ProverError::RangeProof` - If Bulletproof generation fails
 pub fn prove_burn(inp: &BurnInput) -> Result<BurnOutput, ProverError> {
+    prove_burn_with(inp, RangeProofMode::Separate)
+}
+
+/// [`prove_burn`] with an explicit range-proof encoding and width.
+pub fn prove_burn_with(
+    inp: &BurnInput,
+    config: impl Into<RangeProofConfig>,
+) -> Result<BurnOutput, ProverError> {
+    burn_proof(inp, config.into(), true)
+}
+
+/// Generate a ZK proof for burning confidential assets without disclosing the amount.
+///
+/// Available balance and total supply both drop by the hidden amount, so the asset's
+/// supply shrinks while the burned value stays confidential. Nothing is released to a
+/// public balance; use [`prove_burn`] to unshield instead.
+///
+/// The proof matches [`prove_burn`] minus the trailing amount and is bound to its own
+/// transcript domain, so neither kind of proof verifies as the other.
+///
+/// # Errors
+/// * `ProverError::Overflow` - If balance would underflow
+/// * `ProverError::RangeProof` - If Bulletproof generation fails
+pub fn prove_confidential_burn(inp: &BurnInput) -> Result<BurnOutput, ProverError> {
+    prove_confidential_burn_with(inp, RangeProofMode::Separate)
+}
+
+/// [`prove_confidential_burn`] with an explicit range-proof encoding and width.
+pub fn prove_confidential_burn_with(
+    inp: &BurnInput,
+    config: impl Into<RangeProofConfig>,
+) -> Result<BurnOutput, ProverError> {
+    burn_proof(inp, config.into(), false)
+}
+
+fn burn_proof(
+    inp: &BurnInput,
+    config: RangeProofConfig,
+    disclose_amount: bool,
+) -> Result<BurnOutput, ProverError> {
+    check_range_bits(config.bits)?;
     let (v_from_old_u64, r_from_old) = inp.from_avail_old_opening;
     let (v_total_old_u64, r_total_old) = inp.total_old_opening;
 
@@ -724,6 +1298,12 @@ pub fn prove_burn(inp: &BurnInput) -> Result<BurnOutput, ProverError> {
         ciphertext_in: None,
     };
     let mut t = transcript_for(&ctx);
+    let agg_label = if disclose_amount {
+        labels::RANGE_BURN_AGG
+    } else {
+        t.append_message(labels::CONFIDENTIAL_BURN, &[]);
+        labels::RANGE_CONFIDENTIAL_BURN_AGG
+    };
 
     // Σ-proof commitments - use full 256-bit entropy
     let a_k = random_scalar(&mut rng);
@@ -734,9 +1314,9 @@ pub fn prove_burn(inp: &BurnInput) -> Result<BurnOutput, ProverError> {
     let a2 = a_v * G + a_k * inp.from_pk;
     let a3 = a_v * G + a_r * h;
 
-    append_point(&mut t, b"a1", &a1);
-    append_point(&mut t, b"a2", &a2);
-    append_point(&mut t, b"a3", &a3);
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    append_point(&mut t, labels::SIGMA_A3, &a3);
 
     let c = fs_chal(&mut t, labels::CHAL_EQ);
     let z_k = a_k + c * k;
@@ -747,33 +1327,30 @@ pub fn prove_burn(inp: &BurnInput) -> Result<BurnOutput, ProverError> {
     let from_new = inp.from_avail_old_c - delta_c;
     let total_new = inp.total_old_c - delta_c;
 
-    let ctx_bytes = transcript_context_bytes(&t);
+    let ctx_bytes = range_context_bytes(&t, config.bits);
     let from_new_bytes = point_to_bytes(&from_new);
     let total_new_bytes = point_to_bytes(&total_new);
 
     // Range proofs for decreased values
-    let rp_from_new = prove_range_u64(
-        b"range_from_avail_new",
+    let (rp_from_new, rp_total_new) = prove_range_pair(
+        config,
+        [labels::RANGE_FROM_AVAIL_NEW, labels::RANGE_TOTAL_NEW],
+        agg_label,
         &ctx_bytes,
-        &from_new_bytes,
-        v_from_old_u64
-            .checked_sub(dv_u64)
-            .ok_or(ProverError::Overflow("available balance - burn amount"))?,
-        &(r_from_old - rho),
-    )?;
-
-    let rp_total_new = prove_range_u64(
-        b"range_total_new",
-        &ctx_bytes,
-        &total_new_bytes,
-        v_total_old_u64
-            .checked_sub(dv_u64)
-            .ok_or(ProverError::Overflow("total supply - burn amount"))?,
-        &(r_total_old - rho),
+        [&from_new_bytes, &total_new_bytes],
+        [
+            v_from_old_u64
+                .checked_sub(dv_u64)
+                .ok_or(ProverError::Overflow("available balance - burn amount"))?,
+            v_total_old_u64
+                .checked_sub(dv_u64)
+                .ok_or(ProverError::Overflow("total supply - burn amount"))?,
+        ],
+        [&(r_from_old - rho), &(r_total_old - rho)],
     )?;
 
     // Assemble proof:
-    // delta_comm(32) || link(192) || len1 || rp_from_new || len2 || rp_total_new || v_le_u64(8)
+    // delta_comm(32) || link(192) || len1 || rp_from_new || len2 || rp_total_new [|| v_le_u64(8)]
     let mut proof =
         Vec::with_capacity(32 + 192 + 2 + rp_from_new.len() + 2 + rp_total_new.len() + 8);
     proof.extend_from_slice(delta_c.compress().as_bytes());
@@ -785,12 +1362,490 @@ pub fn prove_burn(inp: &BurnInput) -> Result<BurnOutput, ProverError> {
     proof.extend_from_slice(&(rp_total_new.len() as u16).to_le_bytes());
     proof.extend_from_slice(&rp_total_new);
 
-    proof.extend_from_slice(&dv_u64.to_le_bytes());
+    if disclose_amount {
+        proof.extend_from_slice(&dv_u64.to_le_bytes());
+    }
 
     Ok(BurnOutput {
         amount_ct_bytes: amount_ct.to_bytes(),
         proof_bytes: proof,
         from_avail_new_c: from_new_bytes,
         total_new_c: total_new_bytes,
+        nonce: k,
+        delta_rho: rho,
     })
 }
+
+// ========================= Disclosure (viewing proof) =========================
+
+pub struct DisclosureInput {
+    pub asset_id: Vec<u8>,
+    pub network_id: [u8; 32],
+
+    /// Owner secret key; `pk = sk·G` must be the key the ciphertext is under.
+    pub sk: Scalar,
+
+    /// 64-byte ciphertext (C||D) to disclose.
+    pub ciphertext: [u8; 64],
+
+    /// Claimed plaintext; checked against the ciphertext before proving.
+    pub value: u64,
+
+    /// Deterministic seed for tests
+    pub rng_seed: [u8; 32],
+}
+
+/// Generate a disclosure proof that `inp.ciphertext` decrypts to `inp.value`.
+///
+/// The proof is a Chaum–Pedersen DLEQ showing the same `sk` links `pk = sk·G`
+/// and `D - value·G = sk·C`.
+///
+/// # Returns
+/// * `value_le(8) || A1(32) || A2(32) || z(32)`, as expected by the verifier's `disclose`
+///
+/// # Errors
+/// * `ProverError::Malformed` - If the ciphertext does not decode
+/// * `ProverError::InvalidInput` - If the ciphertext does not decrypt to `value`
+pub fn prove_disclosure(inp: &DisclosureInput) -> Result<Vec<u8>, ProverError> {
+    let ct = Ciphertext::from_bytes(&inp.ciphertext)
+        .map_err(|_| ProverError::Malformed("ciphertext"))?;
+    let pk = inp.sk * G;
+    let v = Scalar::from(inp.value);
+    if ct.D - inp.sk * ct.C != v * G {
+        return Err(ProverError::InvalidInput(
+            "ciphertext does not decrypt to value",
+        ));
+    }
+
+    let mut rng = ChaCha20Rng::from_seed(inp.rng_seed);
+    let a = random_scalar(&mut rng);
+    let a1 = a * G;
+    let a2 = a * ct.C;
+
+    let mut t = disclosure_transcript(
+        &inp.network_id,
+        &pad_or_trim_32(&inp.asset_id),
+        &pk,
+        &ct,
+        inp.value,
+    );
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    let c = fs_chal(&mut t, labels::CHAL_DISCLOSE);
+    let z = a + c * inp.sk;
+
+    let mut proof = Vec::with_capacity(DISCLOSURE_PROOF_LEN);
+    proof.extend_from_slice(&inp.value.to_le_bytes());
+    proof.extend_from_slice(a1.compress().as_bytes());
+    proof.extend_from_slice(a2.compress().as_bytes());
+    proof.extend_from_slice(&z.to_bytes());
+    Ok(proof)
+}
+
+// ========================= Key registration =========================
+
+pub struct PubkeyOwnershipInput {
+    pub network_id: [u8; 32],
+
+    /// SCALE-encoded account the key is registered for.
+    pub account: Vec<u8>,
+
+    /// Secret key; the registered key is `pk = sk·G`.
+    pub sk: Scalar,
+
+    /// Deterministic seed for tests
+    pub rng_seed: [u8; 32],
+}
+
+/// Generate a Schnorr proof of knowledge of `inp.sk`, bound to `inp.account`.
+///
+/// # Returns
+/// * `A(32) || z(32)`, as expected by the verifier's `verify_pubkey_ownership`
+///
+/// # Errors
+/// * `ProverError::InvalidInput` - If `sk` is zero
+pub fn prove_pubkey_ownership(inp: &PubkeyOwnershipInput) -> Result<Vec<u8>, ProverError> {
+    if inp.sk == Scalar::ZERO {
+        return Err(ProverError::InvalidInput("secret key is zero"));
+    }
+    let pk = inp.sk * G;
+
+    let mut rng = ChaCha20Rng::from_seed(inp.rng_seed);
+    let a = random_scalar(&mut rng);
+    let a_pt = a * G;
+
+    let mut t = pk_ownership_transcript(&inp.network_id, &inp.account, &pk);
+    append_point(&mut t, labels::SIGMA_A1, &a_pt);
+    let c = fs_chal(&mut t, labels::CHAL_PK_OWNERSHIP);
+    let z = a + c * inp.sk;
+
+    let mut proof = Vec::with_capacity(PK_OWNERSHIP_PROOF_LEN);
+    proof.extend_from_slice(a_pt.compress().as_bytes());
+    proof.extend_from_slice(&z.to_bytes());
+    Ok(proof)
+}
+
+// ========================= Account closure =========================
+
+pub struct ZeroBalanceInput {
+    pub asset_id: Vec<u8>,
+    pub network_id: [u8; 32],
+
+    /// Key the account is registered under.
+    pub pk: RistrettoPoint,
+
+    /// Current available commitment and its blinding; must equal `avail_blind·H`.
+    pub avail_c: RistrettoPoint,
+    pub avail_blind: Scalar,
+
+    /// Current pending commitment and its blinding; must equal `pending_blind·H`.
+    pub pending_c: RistrettoPoint,
+    pub pending_blind: Scalar,
+
+    /// Deterministic seed for tests
+    pub rng_seed: [u8; 32],
+}
+
+/// Generate a proof that an account's available and pending balances are both zero.
+///
+/// A commitment to zero is `r·H`, so the proof is a pair of Schnorr proofs of
+/// knowledge of `r` on base `H` sharing one challenge. Submit it to close the account.
+/// An account that never held funds has identity commitments; use zero blindings.
+///
+/// # Returns
+/// * `A_avail(32) || A_pending(32) || z_avail(32) || z_pending(32)`, as expected by
+///   the verifier's `verify_zero_balance`
+///
+/// # Errors
+/// * `ProverError::InvalidInput` - If a commitment is not its blinding times `H`
+///   (the balance is not zero or the blinding is wrong)
+pub fn prove_zero_balance(inp: &ZeroBalanceInput) -> Result<Vec<u8>, ProverError> {
+    let h = pedersen_h_generator();
+    let (avail, pending) = (inp.avail_c, inp.pending_c);
+    if avail != inp.avail_blind * h {
+        return Err(ProverError::InvalidInput("available balance is not zero"));
+    }
+    if pending != inp.pending_blind * h {
+        return Err(ProverError::InvalidInput("pending balance is not zero"));
+    }
+
+    let mut rng = ChaCha20Rng::from_seed(inp.rng_seed);
+    let a_avail = random_scalar(&mut rng);
+    let a_pending = random_scalar(&mut rng);
+    let a1 = a_avail * h;
+    let a2 = a_pending * h;
+
+    let mut t = zero_balance_transcript(
+        &inp.network_id,
+        &pad_or_trim_32(&inp.asset_id),
+        &inp.pk,
+        &avail,
+        &pending,
+    );
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    let c = fs_chal(&mut t, labels::CHAL_ZERO_BALANCE);
+    let z1 = a_avail + c * inp.avail_blind;
+    let z2 = a_pending + c * inp.pending_blind;
+
+    let mut proof = Vec::with_capacity(ZERO_BALANCE_PROOF_LEN);
+    proof.extend_from_slice(a1.compress().as_bytes());
+    proof.extend_from_slice(a2.compress().as_bytes());
+    proof.extend_from_slice(&z1.to_bytes());
+    proof.extend_from_slice(&z2.to_bytes());
+    Ok(proof)
+}
+
+// ========================= Key rotation =========================
+
+pub struct RekeyInput {
+    pub asset_id: Vec<u8>,
+    pub network_id: [u8; 32],
+
+    /// SCALE-encoded account rotating its key.
+    pub account: Vec<u8>,
+
+    /// Secret key the account is registered under.
+    pub old_sk: Scalar,
+
+    /// Secret key to rotate to; the new public key is `new_sk·G`.
+    pub new_sk: Scalar,
+
+    /// Current available commitment, its value and blinding.
+    pub avail_c: RistrettoPoint,
+    pub avail_value: u64,
+    pub avail_blind: Scalar,
+
+    /// Deterministic seed for tests
+    pub rng_seed: [u8; 32],
+}
+
+/// Generate a proof that re-encrypts an account's available balance under a new key.
+///
+/// Combines Schnorr proofs of knowledge of `old_sk` and `new_sk` with a link proof that
+/// a fresh ciphertext under `new_sk·G` encrypts the value committed in `avail_c`, all
+/// under one challenge. Submit it with the new key to rotate.
+///
+/// # Returns
+/// * `A_old(32) || A_new(32) || z_old(32) || z_new(32) || rekeyed_ct(64) || link(192)`,
+///   as expected by the verifier's `verify_rekey`
+///
+/// # Errors
+/// * `ProverError::InvalidInput` - If a secret key is zero or `avail_c` does not open
+///   to `avail_value` with `avail_blind`
+pub fn prove_rekey(inp: &RekeyInput) -> Result<Vec<u8>, ProverError> {
+    if inp.old_sk == Scalar::ZERO || inp.new_sk == Scalar::ZERO {
+        return Err(ProverError::InvalidInput("secret key is zero"));
+    }
+    let h = pedersen_h_generator();
+    let v = Scalar::from(inp.avail_value);
+    if inp.avail_c != v * G + inp.avail_blind * h {
+        return Err(ProverError::InvalidInput(
+            "available commitment does not open to value",
+        ));
+    }
+    let old_pk = inp.old_sk * G;
+    let new_pk = inp.new_sk * G;
+
+    let mut rng = ChaCha20Rng::from_seed(inp.rng_seed);
+    let k = random_scalar(&mut rng);
+    let ct = elgamal_encrypt_delta(&new_pk, inp.avail_value, &k);
+
+    let a_old = random_scalar(&mut rng);
+    let a_new = random_scalar(&mut rng);
+    let (rk, rv, rr) = (
+        random_scalar(&mut rng),
+        random_scalar(&mut rng),
+        random_scalar(&mut rng),
+    );
+    let a1 = rk * G;
+    let a2 = rv * G + rk * new_pk;
+    let a3 = rv * G + rr * h;
+    let a4 = a_old * G;
+    let a5 = a_new * G;
+
+    let mut t = rekey_transcript(
+        &inp.network_id,
+        &inp.account,
+        &pad_or_trim_32(&inp.asset_id),
+        &old_pk,
+        &new_pk,
+        &inp.avail_c,
+        &ct,
+    );
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    append_point(&mut t, labels::SIGMA_A3, &a3);
+    append_point(&mut t, labels::SIGMA_A4, &a4);
+    append_point(&mut t, labels::SIGMA_A5, &a5);
+    let c = fs_chal(&mut t, labels::CHAL_REKEY);
+
+    let z_old = a_old + c * inp.old_sk;
+    let z_new = a_new + c * inp.new_sk;
+    let z_k = rk + c * k;
+    let z_v = rv + c * v;
+    let z_r = rr + c * inp.avail_blind;
+
+    let mut proof = Vec::with_capacity(REKEY_PROOF_LEN);
+    proof.extend_from_slice(a4.compress().as_bytes());
+    proof.extend_from_slice(a5.compress().as_bytes());
+    proof.extend_from_slice(&z_old.to_bytes());
+    proof.extend_from_slice(&z_new.to_bytes());
+    proof.extend_from_slice(&ct.to_bytes());
+    proof.extend_from_slice(&encode_link(&a1, &a2, &a3, &z_k, &z_v, &z_r));
+    Ok(proof)
+}
+
+// ========================= Auditor handle =========================
+
+pub struct AuditorHandleInput {
+    pub asset_id: Vec<u8>,
+    pub network_id: [u8; 32],
+
+    /// Key the ciphertext is encrypted under: the sender's for transfers and burns, the
+    /// recipient's for mints.
+    pub pk: RistrettoPoint,
+
+    /// The asset's auditor key.
+    pub auditor_pk: RistrettoPoint,
+
+    /// The transfer, mint or burn ciphertext and the nonce it was made with, as
+    /// returned in the prover's output.
+    pub ct: [u8; 64],
+    pub nonce: Scalar,
+
+    /// Opening `(value, blind)` of the commitment the operation moves (its ΔC), as
+    /// returned in the prover's output (`delta_rho`).
+    pub delta_opening: (u64, Scalar),
+
+    /// Deterministic seed for tests
+    pub rng_seed: [u8; 32],
+}
+
+/// Generate a decrypt handle for an asset's auditor and prove it matches a ciphertext.
+///
+/// The handle reuses the ciphertext's nonce, so `C || D_auditor` encrypts the same value
+/// under `auditor_pk`. A Chaum–Pedersen proof shows the nonce of `C = k·G` also gives
+/// `D - D_auditor = k·(pk - auditor_pk)`. The handle also encrypts the blinding `r` of
+/// ΔC as `(b·G, r·H + b·auditor_pk)`, with a proof under the same challenge, so the
+/// chain can keep every audited balance's blinding under the auditor key for
+/// [`prove_seizure`]. Seal it and append it to the operation's sealed proof when the
+/// asset has an auditor.
+///
+/// # Returns
+/// * `D_auditor(32) || A1(32) || A2(32) || z(32) || blinding_C(32) || blinding_D(32) ||
+///   A3(32) || A4(32) || A5(32) || z_v(32) || z_r(32) || z_b(32)`, as expected by the
+///   verifier's `verify_auditor_handle`
+///
+/// # Errors
+/// * `ProverError::Malformed` - If `ct` is not a valid ciphertext
+/// * `ProverError::InvalidInput` - If `nonce` is not the nonce of `ct`
+pub fn prove_auditor_handle(inp: &AuditorHandleInput) -> Result<Vec<u8>, ProverError> {
+    let ct = Ciphertext::from_bytes(&inp.ct).map_err(|_| ProverError::Malformed("ciphertext"))?;
+    if ct.C != inp.nonce * G {
+        return Err(ProverError::InvalidInput("nonce does not open ciphertext"));
+    }
+    let auditor_d = ct.D - inp.nonce * (inp.pk - inp.auditor_pk);
+    let h = pedersen_h_generator();
+    let (value, rho) = inp.delta_opening;
+    let delta = Scalar::from(value) * G + rho * h;
+
+    let mut rng = ChaCha20Rng::from_seed(inp.rng_seed);
+    let b = random_scalar(&mut rng);
+    let blinding = Ciphertext {
+        C: b * G,
+        D: rho * h + b * inp.auditor_pk,
+    };
+    let a = random_scalar(&mut rng);
+    let a_v = random_scalar(&mut rng);
+    let a_r = random_scalar(&mut rng);
+    let a_b = random_scalar(&mut rng);
+    let a1 = a * G;
+    let a2 = a * (inp.pk - inp.auditor_pk);
+    let a3 = a_v * G + a_r * h;
+    let a4 = a_b * G;
+    let a5 = a_r * h + a_b * inp.auditor_pk;
+
+    let mut t = auditor_transcript(
+        &inp.network_id,
+        &pad_or_trim_32(&inp.asset_id),
+        &inp.pk,
+        &inp.auditor_pk,
+        &ct,
+        &auditor_d,
+        &delta,
+        &blinding,
+    );
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    append_point(&mut t, labels::SIGMA_A3, &a3);
+    append_point(&mut t, labels::SIGMA_A4, &a4);
+    append_point(&mut t, labels::SIGMA_A5, &a5);
+    let c = fs_chal(&mut t, labels::CHAL_AUDITOR);
+    let z = a + c * inp.nonce;
+    let z_v = a_v + c * Scalar::from(value);
+    let z_r = a_r + c * rho;
+    let z_b = a_b + c * b;
+
+    let mut proof = Vec::with_capacity(AUDITOR_PROOF_LEN);
+    proof.extend_from_slice(auditor_d.compress().as_bytes());
+    proof.extend_from_slice(a1.compress().as_bytes());
+    proof.extend_from_slice(a2.compress().as_bytes());
+    proof.extend_from_slice(&z.to_bytes());
+    proof.extend_from_slice(&blinding.to_bytes());
+    proof.extend_from_slice(a3.compress().as_bytes());
+    proof.extend_from_slice(a4.compress().as_bytes());
+    proof.extend_from_slice(a5.compress().as_bytes());
+    proof.extend_from_slice(&z_v.to_bytes());
+    proof.extend_from_slice(&z_r.to_bytes());
+    proof.extend_from_slice(&z_b.to_bytes());
+    Ok(proof)
+}
+
+// ========================= Seizure =========================
+
+pub struct SeizureInput {
+    pub asset_id: Vec<u8>,
+    pub network_id: [u8; 32],
+
+    /// The asset's auditor secret key.
+    pub auditor_sk: Scalar,
+
+    /// The holder's available balance commitment, as stored on chain.
+    pub avail: [u8; 32],
+
+    /// The chain's encryption of that balance's blinding under the auditor key, summed
+    /// from the auditor handles of every transition into and out of the balance.
+    pub blinding: [u8; 64],
+
+    /// The seized balance under the auditor key, as reconstructed from the audit log,
+    /// and the value the auditor decrypts it to.
+    pub ct: [u8; 64],
+    pub value: u64,
+
+    /// Deterministic seed for tests
+    pub rng_seed: [u8; 32],
+}
+
+/// Generate a seizure proof that `inp.ct` and the available balance commitment both
+/// hide the same value, from the auditor's secret key alone.
+///
+/// The proof discloses the value. A Chaum–Pedersen DLEQ over the auditor key shows it
+/// decrypts `ct` to the value, and decrypts `blinding` to `avail - value·G`: the
+/// commitment's blinding part, so `avail` opens to the value.
+///
+/// # Returns
+/// * `value_le(8) || A1(32) || A2(32) || A3(32) || z(32)`, as expected by the verifier's
+///   `verify_seizure`
+///
+/// # Errors
+/// * `ProverError::Malformed` - If `avail`, `blinding` or `ct` is not a valid point or
+///   ciphertext
+/// * `ProverError::InvalidInput` - If `ct` does not decrypt to `value` or the balance
+///   does not open to it
+pub fn prove_seizure(inp: &SeizureInput) -> Result<Vec<u8>, ProverError> {
+    let ct = Ciphertext::from_bytes(&inp.ct).map_err(|_| ProverError::Malformed("ciphertext"))?;
+    let blinding = Ciphertext::from_bytes(&inp.blinding)
+        .map_err(|_| ProverError::Malformed("blinding ciphertext"))?;
+    let avail =
+        point_from_bytes(&inp.avail).map_err(|_| ProverError::Malformed("available balance"))?;
+    let value = inp.value;
+    let value_g = Scalar::from(value) * G;
+    if ct.D - inp.auditor_sk * ct.C != value_g
+        || avail - value_g != blinding.D - inp.auditor_sk * blinding.C
+    {
+        return Err(ProverError::InvalidInput(
+            "ciphertext does not decrypt to the balance",
+        ));
+    }
+    let auditor_pk = inp.auditor_sk * G;
+
+    let mut rng = ChaCha20Rng::from_seed(inp.rng_seed);
+    let a = random_scalar(&mut rng);
+    let a1 = a * G;
+    let a2 = a * ct.C;
+    let a3 = a * blinding.C;
+
+    let mut t = seizure_transcript(
+        &inp.network_id,
+        &pad_or_trim_32(&inp.asset_id),
+        &auditor_pk,
+        &avail,
+        &blinding,
+        &ct,
+        value,
+    );
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    append_point(&mut t, labels::SIGMA_A3, &a3);
+    let c = fs_chal(&mut t, labels::CHAL_SEIZURE);
+    let z = a + c * inp.auditor_sk;
+
+    let mut proof = Vec::with_capacity(SEIZURE_PROOF_LEN);
+    proof.extend_from_slice(&value.to_le_bytes());
+    proof.extend_from_slice(a1.compress().as_bytes());
+    proof.extend_from_slice(a2.compress().as_bytes());
+    proof.extend_from_slice(a3.compress().as_bytes());
+    proof.extend_from_slice(&z.to_bytes());
+    Ok(proof)
+}
@@ -0,0 +1,217 @@
+//! Interop with `solana-zk-sdk` ElGamal types (feature `solana-interop`).
+//!
+//! ZK-ElGamal ciphertexts here are standard ElGamal over Ristretto:
+//!
+//! ```text
+//! C = k·G          D = v·G + k·pk          pk = sk·G
+//! ```
+//!
+//! The Solana SDK uses *twisted* ElGamal:
+//!
+//! ```text
+//! commitment = v·G + r·H    handle = r·P    P = s⁻¹·H
+//! ```
+//!
+//! Both decrypt the same way (`commitment - s·handle = v·G`), so a ZK-ElGamal
+//! ciphertext maps onto an SDK ciphertext with `D → commitment` and
+//! `C → handle`, and the SDK secret key is simply our scalar `sk`. Public keys
+//! are **not** interchangeable: an SDK pubkey is `s⁻¹·H`, ours is `s·G`. Key
+//! import therefore always goes through the secret half, and the SDK pubkey of
+//! an exported keypair is the SDK's own derivation.
+//!
+//! Ciphertexts produced by [`prove_mint`](crate::prove_mint) and
+//! [`prove_burn`](crate::prove_burn) can be handed to SPL confidential-token
+//! tooling for decryption with [`validate_mint_output`] /
+//! [`validate_burn_output`]. Proofs are not interchangeable.
+
+use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
+use solana_zk_sdk::encryption::{
+    elgamal::{DecryptHandle, ElGamalCiphertext, ElGamalKeypair, ElGamalSecretKey},
+    pedersen::PedersenCommitment,
+};
+use zkhe_primitives::Ciphertext;
+
+use crate::{BurnOutput, MintOutput, ProverError};
+
+/// Convert a ZK-ElGamal ciphertext into an SDK ciphertext (`D → commitment`, `C → handle`).
+pub fn ciphertext_to_sdk(ct: &Ciphertext) -> Result<ElGamalCiphertext, ProverError> {
+    let commitment = PedersenCommitment::from_bytes(&ct.D.compress().to_bytes())
+        .ok_or(ProverError::Malformed("sdk commitment"))?;
+    let handle = DecryptHandle::from_bytes(&ct.C.compress().to_bytes())
+        .ok_or(ProverError::Malformed("sdk decrypt handle"))?;
+    Ok(ElGamalCiphertext { commitment, handle })
+}
+
+/// Convert an SDK ciphertext back into a ZK-ElGamal ciphertext.
+pub fn ciphertext_from_sdk(ct: &ElGamalCiphertext) -> Result<Ciphertext, ProverError> {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&ct.handle.to_bytes());
+    bytes[32..].copy_from_slice(&ct.commitment.to_bytes());
+    Ciphertext::from_bytes(&bytes).map_err(|_| ProverError::Malformed("sdk ciphertext"))
+}
+
+/// Convert 64 ZK-ElGamal ciphertext bytes (`C‖D`) into SDK ciphertext bytes (`commitment‖handle`).
+pub fn ciphertext_bytes_to_sdk(bytes: &[u8; 64]) -> Result<[u8; 64], ProverError> {
+    let ct = Ciphertext::from_bytes(bytes).map_err(|_| ProverError::Malformed("ciphertext"))?;
+    Ok(ciphertext_to_sdk(&ct)?.to_bytes())
+}
+
+/// Convert 64 SDK ciphertext bytes (`commitment‖handle`) into ZK-ElGamal bytes (`C‖D`).
+pub fn ciphertext_bytes_from_sdk(bytes: &[u8; 64]) -> Result<[u8; 64], ProverError> {
+    let ct =
+        ElGamalCiphertext::from_bytes(bytes).ok_or(ProverError::Malformed("sdk ciphertext"))?;
+    Ok(ciphertext_from_sdk(&ct)?.to_bytes())
+}
+
+/// Export a ZK-ElGamal secret key as an SDK keypair.
+///
+/// The SDK pubkey is derived by the SDK (`s⁻¹·H`) and differs from ours.
+pub fn keypair_to_sdk(sk: &Scalar) -> Result<ElGamalKeypair, ProverError> {
+    if *sk == Scalar::ZERO {
+        return Err(ProverError::InvalidInput("zero secret key"));
+    }
+    let secret = ElGamalSecretKey::try_from(sk.as_bytes().as_slice())
+        .map_err(|_| ProverError::Malformed("sdk secret key"))?;
+    Ok(ElGamalKeypair::new(secret))
+}
+
+/// Import an SDK keypair, returning our `(sk, pk = sk·G)`.
+pub fn keypair_from_sdk(kp: &ElGamalKeypair) -> Result<(Scalar, RistrettoPoint), ProverError> {
+    let sk = Option::<Scalar>::from(Scalar::from_canonical_bytes(*kp.secret().as_bytes()))
+        .ok_or(ProverError::Malformed("sdk secret key"))?;
+    if sk == Scalar::ZERO {
+        return Err(ProverError::InvalidInput("zero secret key"));
+    }
+    Ok((
+        sk,
+        sk * curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT,
+    ))
+}
+
+/// Import a serialized SDK keypair (`ELGAMAL_KEYPAIR_LEN` bytes, pubkey‖secret).
+pub fn keypair_from_sdk_bytes(bytes: &[u8]) -> Result<(Scalar, RistrettoPoint), ProverError> {
+    let kp = ElGamalKeypair::try_from(bytes).map_err(|_| ProverError::Malformed("sdk keypair"))?;
+    keypair_from_sdk(&kp)
+}
+
+/// Decrypt a ZK-ElGamal ciphertext with the SDK, for amounts that fit in 32 bits.
+pub fn sdk_decrypt_u32(sk: &Scalar, ct_bytes: &[u8; 64]) -> Result<u64, ProverError> {
+    let kp = keypair_to_sdk(sk)?;
+    let ct = ElGamalCiphertext::from_bytes(&ciphertext_bytes_to_sdk(ct_bytes)?)
+        .ok_or(ProverError::Malformed("sdk ciphertext"))?;
+    kp.secret()
+        .decrypt_u32(&ct)
+        .ok_or(ProverError::InvalidInput("amount not decryptable as u32"))
+}
+
+fn validate_ciphertext(
+    sk: &Scalar,
+    ct_bytes: &[u8; 64],
+    expected: u64,
+) -> Result<ElGamalCiphertext, ProverError> {
+    let sdk_bytes = ciphertext_bytes_to_sdk(ct_bytes)?;
+    let ct = ElGamalCiphertext::from_bytes(&sdk_bytes)
+        .ok_or(ProverError::Malformed("sdk ciphertext"))?;
+    if sdk_decrypt_u32(sk, ct_bytes)? != expected {
+        return Err(ProverError::InvalidInput("sdk decryption mismatch"));
+    }
+    Ok(ct)
+}
+
+/// Check that a minted ciphertext decrypts to `mint_value` under SDK tooling.
+///
+/// Returns the SDK form of `minted_ct_bytes`.
+pub fn validate_mint_output(
+    out: &MintOutput,
+    to_sk: &Scalar,
+    mint_value: u64,
+) -> Result<ElGamalCiphertext, ProverError> {
+    validate_ciphertext(to_sk, &out.minted_ct_bytes, mint_value)
+}
+
+/// Check that a burn ciphertext decrypts to `burn_value` under SDK tooling.
+///
+/// Returns the SDK form of `amount_ct_bytes`.
+pub fn validate_burn_output(
+    out: &BurnOutput,
+    from_sk: &Scalar,
+    burn_value: u64,
+) -> Result<ElGamalCiphertext, ProverError> {
+    validate_ciphertext(from_sk, &out.amount_ct_bytes, burn_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BurnInput, MintInput, prove_burn, prove_mint};
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, traits::Identity};
+    use solana_zk_sdk::encryption::elgamal::ELGAMAL_KEYPAIR_LEN;
+
+    #[test]
+    fn ciphertext_round_trips_through_sdk() {
+        let pk = Scalar::from(9u64) * G;
+        let k = Scalar::from(1234u64);
+        let ct = Ciphertext {
+            C: k * G,
+            D: Scalar::from(55u64) * G + k * pk,
+        };
+        let sdk = ciphertext_to_sdk(&ct).unwrap();
+        assert_eq!(ciphertext_from_sdk(&sdk).unwrap().to_bytes(), ct.to_bytes());
+        assert_eq!(
+            ciphertext_bytes_from_sdk(&ciphertext_bytes_to_sdk(&ct.to_bytes()).unwrap()).unwrap(),
+            ct.to_bytes()
+        );
+        assert_eq!(
+            sdk_decrypt_u32(&Scalar::from(9u64), &ct.to_bytes()).unwrap(),
+            55
+        );
+    }
+
+    #[test]
+    fn keypair_round_trips_through_sdk() {
+        let sk = Scalar::from(5u64);
+        let kp = keypair_to_sdk(&sk).unwrap();
+        let bytes: [u8; ELGAMAL_KEYPAIR_LEN] = (&kp).into();
+        let (sk2, pk2) = keypair_from_sdk_bytes(&bytes).unwrap();
+        assert_eq!(sk2, sk);
+        assert_eq!(pk2, sk * G);
+        assert!(keypair_to_sdk(&Scalar::ZERO).is_err());
+    }
+
+    #[test]
+    fn mint_and_burn_ciphertexts_decrypt_with_sdk() {
+        let sk = Scalar::from(9u64);
+        let pk = sk * G;
+
+        let mint = prove_mint(&MintInput {
+            asset_id: b"TEST_ASSET".to_vec(),
+            network_id: [0u8; 32],
+            to_pk: pk,
+            to_pending_old_c: RistrettoPoint::identity(),
+            to_pending_old_opening: (0, Scalar::ZERO),
+            total_old_c: RistrettoPoint::identity(),
+            total_old_opening: (0, Scalar::ZERO),
+            mint_value: 77,
+            rng_seed: [0xA5; 32],
+        })
+        .unwrap();
+        validate_mint_output(&mint, &sk, 77).unwrap();
+        assert!(validate_mint_output(&mint, &sk, 78).is_err());
+
+        let h = zkhe_primitives::pedersen_h_generator();
+        let r = Scalar::from(3u64);
+        let burn = prove_burn(&BurnInput {
+            asset_id: b"TEST_ASSET".to_vec(),
+            network_id: [0u8; 32],
+            from_pk: pk,
+            from_avail_old_c: Scalar::from(200u64) * G + r * h,
+            from_avail_old_opening: (200, r),
+            total_old_c: Scalar::from(200u64) * G + r * h,
+            total_old_opening: (200, r),
+            burn_value: 120,
+            rng_seed: [0x5C; 32],
+        })
+        .unwrap();
+        validate_burn_output(&burn, &sk, 120).unwrap();
+    }
+}
@@ -88,3 +88,29 @@ fn sender_receiver_round_trip_shapes() {
     // env = 32 + 2 + len(rp_avail_new) + 2 + len(rp_pending_new)
     assert!(r_out.accept_envelope.len() > 32 + 2 + 2);
 }
+
+#[test]
+fn generated_vectors_are_deterministic_and_configurable() {
+    let cfg = VectorConfig::default();
+    let a = generate_vectors(&cfg).expect("default vectors");
+    assert_eq!(a, generate_vectors(&cfg).expect("default vectors"));
+    assert_eq!(a.to_rust_source(), bench_vectors::some_valid_proofs());
+
+    // A different network id changes every proof.
+    let b = generate_vectors(&VectorConfig {
+        network_id: [1u8; 32],
+        ..cfg.clone()
+    })
+    .expect("vectors");
+    assert_ne!(a.transfer_bundle, b.transfer_bundle);
+    assert_ne!(a.mint_proof, b.mint_proof);
+    assert_ne!(a.sender_pk_ownership_proof, b.sender_pk_ownership_proof);
+
+    assert!(matches!(
+        generate_vectors(&VectorConfig {
+            sdk_version: SDK_VERSION + 1,
+            ..cfg
+        }),
+        Err(ProverError::InvalidInput(_))
+    ));
+}
@@ -2,6 +2,8 @@
 // Deterministic vectors for verifier tests, runtime benches, and XCM tests.
 
 pub const ASSET_ID_BYTES: &[u8] = b"BENCH_ASSET";
+pub const NETWORK_ID: [u8; 32] = [0; 32];
+pub const PROOF_SYSTEM_ID: u8 = 0;
 pub const SENDER_PK32: [u8; 32] = [
     232, 130, 177, 49, 1, 107, 82, 193, 211, 51, 112, 128, 24, 124, 247, 104, 66, 62, 252, 203,
     181, 23, 187, 73, 90, 184, 18, 196, 22, 15, 244, 78,
@@ -11,6 +13,45 @@ pub const RECEIVER_PK32: [u8; 32] = [
     52, 178, 240, 214, 104, 82, 130, 169, 7, 96, 49,
 ];
 
+// ----- Key registration -----
+pub const PK_OWNER_ACCOUNT: &[u8] = &[
+    216, 97, 234, 30, 191, 72, 0, 212, 184, 159, 79, 247, 135, 173, 121, 238, 150, 217, 167, 8,
+    200, 91, 87, 218, 126, 184, 249, 221, 237, 166, 18, 145,
+];
+pub const SENDER_PK_OWNERSHIP_PROOF: &[u8] = &[
+    222, 250, 232, 42, 187, 42, 138, 255, 81, 71, 182, 112, 248, 140, 2, 64, 137, 39, 212, 53, 80,
+    178, 210, 65, 221, 187, 117, 8, 11, 58, 85, 28, 134, 29, 235, 204, 140, 229, 124, 133, 69, 107,
+    164, 230, 255, 19, 232, 223, 94, 134, 152, 212, 24, 85, 165, 24, 190, 192, 168, 113, 76, 189,
+    86, 4,
+];
+
+// ----- Key rotation (sender key, balance TRANSFER_FROM_OLD_COMM_32) -----
+pub const REKEY_NEW_PK32: [u8; 32] = [
+    170, 82, 224, 0, 223, 46, 22, 245, 95, 177, 3, 47, 195, 59, 196, 39, 66, 218, 214, 189, 90,
+    143, 192, 190, 1, 103, 67, 108, 89, 72, 80, 31,
+];
+pub const REKEY_PROOF: &[u8] = &[
+    46, 102, 182, 173, 176, 2, 163, 248, 81, 88, 240, 28, 80, 132, 72, 171, 251, 153, 242, 113, 46,
+    31, 57, 65, 32, 128, 141, 251, 89, 232, 252, 48, 28, 147, 57, 110, 163, 171, 17, 97, 67, 142,
+    133, 26, 224, 213, 110, 134, 37, 84, 198, 98, 86, 155, 23, 82, 228, 59, 238, 174, 175, 74, 42,
+    8, 137, 229, 156, 199, 171, 37, 66, 14, 134, 75, 14, 85, 129, 109, 53, 183, 42, 222, 252, 20,
+    145, 83, 62, 43, 126, 187, 79, 153, 136, 6, 190, 12, 79, 49, 87, 191, 57, 174, 180, 212, 105,
+    49, 173, 205, 16, 1, 69, 10, 223, 101, 158, 11, 148, 54, 74, 67, 193, 248, 51, 12, 123, 77,
+    215, 2, 236, 213, 129, 35, 146, 146, 187, 143, 249, 111, 166, 231, 209, 107, 251, 32, 94, 246,
+    40, 54, 208, 128, 18, 232, 60, 131, 26, 241, 99, 94, 88, 12, 44, 110, 116, 8, 26, 127, 224,
+    222, 188, 61, 9, 143, 184, 183, 83, 95, 69, 112, 51, 93, 163, 249, 143, 98, 201, 137, 167, 26,
+    82, 127, 247, 121, 108, 26, 157, 254, 61, 254, 235, 52, 190, 148, 132, 110, 127, 138, 247, 37,
+    89, 121, 250, 178, 165, 223, 226, 62, 57, 41, 108, 250, 210, 70, 9, 22, 20, 195, 83, 57, 156,
+    208, 178, 184, 153, 208, 244, 174, 82, 35, 38, 130, 170, 65, 161, 14, 178, 66, 203, 76, 230,
+    198, 21, 53, 199, 151, 200, 54, 26, 21, 34, 138, 59, 147, 197, 32, 165, 97, 186, 198, 35, 239,
+    244, 6, 46, 219, 82, 113, 90, 97, 64, 196, 43, 93, 72, 57, 135, 95, 167, 40, 208, 225, 17, 143,
+    247, 74, 66, 40, 246, 28, 45, 67, 198, 3, 236, 49, 137, 224, 69, 199, 54, 87, 38, 163, 153,
+    185, 43, 101, 122, 139, 164, 0, 147, 236, 166, 31, 100, 221, 197, 3, 49, 215, 50, 39, 93, 228,
+    236, 235, 28, 168, 6, 140, 177, 224, 9, 115, 54, 104, 244, 237, 139, 93, 251, 4, 107, 148, 59,
+    168, 226, 184, 144, 110, 24, 3, 136, 7, 46, 128, 81, 236, 152, 46, 164, 20, 252, 200, 129, 193,
+    114, 116, 143, 219, 0, 115, 61, 1,
+];
+
 // ----- Transfer (sender) -----
 pub const TRANSFER_FROM_OLD_COMM_32: [u8; 32] = [
     88, 125, 80, 47, 147, 195, 7, 62, 79, 113, 164, 122, 89, 113, 241, 216, 192, 209, 133, 129,
@@ -37,40 +78,40 @@ pub const TRANSFER_BUNDLE: &[u8] = &[
     190, 203, 179, 78, 136, 101, 50, 149, 127, 163, 12, 23, 106, 114, 11, 216, 11, 142, 255, 19,
     71, 31, 229, 128, 211, 83, 122, 19, 231, 2, 169, 190, 76, 73, 10, 60, 33, 27, 149, 41, 176,
     200, 5, 132, 166, 218, 11, 213, 118, 212, 60, 104, 251, 134, 120, 157, 77, 86, 124, 187, 225,
-    113, 211, 159, 239, 218, 238, 252, 49, 166, 50, 23, 252, 228, 175, 50, 168, 248, 4, 160, 2,
-    236, 29, 45, 74, 107, 167, 249, 229, 169, 157, 174, 178, 176, 93, 227, 227, 237, 204, 63, 144,
-    37, 238, 82, 91, 87, 17, 14, 235, 198, 171, 183, 117, 168, 241, 55, 203, 241, 119, 117, 254,
-    178, 184, 207, 9, 17, 215, 161, 64, 41, 164, 29, 25, 20, 38, 198, 48, 3, 215, 23, 86, 150, 55,
-    151, 108, 90, 119, 189, 168, 69, 140, 123, 75, 106, 228, 16, 72, 225, 196, 254, 4, 152, 69, 85,
-    71, 103, 61, 196, 23, 10, 202, 7, 144, 161, 134, 74, 45, 132, 222, 224, 138, 85, 215, 197, 143,
-    4, 202, 149, 61, 43, 53, 216, 176, 218, 40, 131, 56, 183, 164, 165, 196, 95, 189, 160, 212,
-    236, 28, 17, 84, 216, 16, 1, 125, 0, 75, 226, 194, 168, 108, 42, 188, 147, 56, 18, 135, 164,
-    232, 98, 106, 31, 27, 140, 213, 170, 47, 132, 47, 114, 225, 220, 5, 173, 178, 75, 92, 81, 51,
-    214, 238, 72, 59, 71, 117, 147, 145, 17, 65, 117, 107, 233, 240, 202, 23, 141, 156, 190, 118,
-    175, 97, 3, 219, 94, 13, 19, 56, 190, 172, 213, 180, 23, 124, 128, 206, 241, 198, 252, 20, 174,
-    163, 56, 109, 42, 122, 12, 75, 33, 28, 210, 16, 224, 17, 147, 230, 54, 15, 76, 111, 136, 111,
-    248, 249, 0, 213, 175, 202, 82, 4, 195, 243, 141, 213, 22, 20, 171, 223, 244, 216, 199, 26, 76,
-    198, 72, 23, 38, 198, 20, 10, 190, 75, 87, 16, 147, 140, 19, 8, 46, 65, 229, 209, 15, 134, 212,
-    130, 86, 34, 195, 159, 138, 197, 19, 217, 111, 120, 83, 255, 147, 191, 113, 84, 106, 246, 21,
-    171, 190, 62, 220, 38, 113, 133, 65, 224, 18, 48, 235, 237, 120, 241, 193, 208, 230, 38, 31,
-    104, 187, 123, 200, 255, 88, 231, 185, 70, 202, 124, 178, 247, 39, 4, 211, 90, 97, 112, 124,
-    86, 129, 36, 21, 49, 78, 151, 226, 11, 171, 79, 64, 227, 218, 47, 81, 27, 9, 236, 121, 43, 24,
-    60, 48, 11, 143, 6, 89, 17, 252, 90, 93, 146, 140, 6, 13, 247, 77, 247, 198, 76, 220, 148, 152,
-    19, 248, 240, 234, 224, 78, 215, 181, 83, 78, 64, 92, 161, 60, 51, 115, 108, 104, 201, 57, 184,
-    107, 162, 193, 193, 50, 176, 93, 125, 172, 22, 118, 150, 104, 144, 168, 181, 165, 72, 62, 14,
-    2, 244, 38, 185, 66, 100, 241, 47, 119, 149, 111, 133, 16, 215, 230, 81, 185, 158, 109, 106,
-    126, 174, 194, 103, 135, 130, 220, 63, 3, 127, 243, 83, 94, 152, 239, 39, 246, 154, 248, 157,
-    57, 189, 90, 41, 88, 132, 40, 11, 55, 115, 160, 235, 89, 106, 83, 88, 210, 121, 86, 148, 37,
-    91, 140, 13, 76, 45, 7, 120, 140, 135, 157, 61, 89, 134, 230, 21, 78, 125, 120, 206, 144, 23,
-    76, 104, 11, 143, 70, 151, 120, 156, 125, 10, 18, 163, 36, 76, 104, 15, 240, 50, 237, 204, 133,
-    173, 151, 15, 223, 223, 117, 55, 143, 76, 113, 165, 43, 60, 123, 132, 231, 238, 31, 166, 101,
-    37, 42, 112, 101, 116, 128, 133, 44, 214, 227, 240, 168, 4, 113, 63, 0, 160, 91, 122, 209, 40,
-    114, 123, 159, 108, 101, 215, 13, 180, 81, 61, 238, 197, 30, 43, 68, 53, 132, 249, 247, 170,
-    135, 141, 238, 200, 160, 72, 173, 161, 146, 49, 117, 147, 21, 210, 20, 109, 22, 31, 224, 168,
-    145, 231, 39, 251, 72, 28, 182, 26, 108, 254, 183, 86, 12, 86, 182, 164, 26, 56, 136, 237, 41,
-    145, 203, 72, 175, 253, 58, 150, 74, 136, 106, 44, 201, 164, 0, 227, 3, 26, 204, 14, 120, 124,
-    214, 16, 130, 226, 174, 244, 229, 10, 102, 77, 2, 45, 46, 65, 71, 180, 108, 48, 214, 168, 57,
-    96, 186, 46, 7, 164, 7, 243, 57, 11, 0, 0,
+    113, 211, 159, 239, 218, 238, 252, 49, 166, 50, 23, 252, 228, 175, 50, 168, 248, 4, 160, 2, 60,
+    203, 108, 38, 181, 137, 206, 16, 31, 170, 26, 81, 73, 78, 211, 86, 228, 124, 157, 153, 34, 158,
+    117, 213, 202, 127, 124, 174, 237, 115, 151, 121, 130, 18, 159, 237, 99, 129, 162, 250, 28, 26,
+    52, 151, 183, 202, 71, 131, 23, 103, 208, 147, 171, 36, 80, 143, 192, 76, 187, 84, 3, 42, 242,
+    92, 90, 32, 101, 192, 150, 251, 90, 209, 207, 65, 255, 7, 195, 25, 194, 28, 51, 138, 100, 113,
+    100, 147, 203, 187, 58, 104, 119, 189, 192, 123, 100, 60, 4, 139, 14, 240, 35, 111, 5, 159,
+    195, 164, 103, 36, 221, 123, 219, 46, 46, 172, 84, 171, 60, 90, 194, 150, 186, 240, 103, 148,
+    89, 183, 130, 43, 144, 33, 23, 67, 240, 7, 10, 89, 146, 25, 15, 72, 221, 225, 204, 171, 122,
+    208, 116, 87, 17, 202, 81, 229, 212, 227, 10, 150, 160, 1, 127, 12, 163, 238, 20, 249, 103, 85,
+    194, 157, 138, 112, 185, 254, 144, 86, 105, 157, 178, 77, 106, 120, 251, 241, 245, 141, 254,
+    135, 120, 3, 182, 162, 80, 2, 173, 254, 141, 234, 255, 63, 214, 10, 23, 233, 26, 146, 235, 70,
+    237, 180, 221, 214, 21, 246, 48, 61, 232, 191, 253, 158, 88, 196, 138, 5, 72, 7, 8, 27, 157,
+    153, 78, 216, 113, 219, 207, 141, 215, 122, 31, 110, 252, 214, 207, 21, 175, 189, 16, 188, 113,
+    216, 187, 70, 42, 69, 197, 103, 194, 48, 166, 196, 36, 194, 92, 238, 180, 129, 239, 60, 85, 60,
+    176, 85, 56, 102, 107, 164, 13, 74, 119, 105, 210, 255, 61, 10, 17, 172, 56, 39, 189, 26, 4,
+    172, 76, 118, 206, 42, 230, 132, 27, 108, 249, 61, 109, 236, 76, 40, 45, 160, 115, 234, 159,
+    201, 253, 109, 101, 125, 216, 235, 152, 71, 3, 68, 188, 179, 152, 157, 209, 66, 15, 181, 239,
+    24, 169, 199, 199, 248, 4, 98, 29, 149, 67, 100, 163, 54, 129, 157, 58, 138, 141, 54, 221, 121,
+    130, 27, 116, 41, 198, 140, 34, 229, 176, 66, 251, 79, 252, 62, 141, 131, 90, 143, 112, 55, 70,
+    225, 65, 175, 201, 196, 161, 224, 75, 129, 182, 183, 117, 65, 184, 247, 19, 248, 190, 102, 3,
+    43, 83, 141, 11, 38, 195, 158, 177, 45, 131, 111, 165, 226, 39, 112, 213, 240, 156, 80, 170,
+    93, 51, 148, 8, 28, 62, 128, 135, 48, 196, 43, 180, 250, 252, 169, 110, 35, 66, 228, 93, 215,
+    32, 18, 146, 22, 60, 205, 25, 216, 106, 24, 92, 128, 13, 156, 209, 52, 58, 42, 48, 106, 238,
+    218, 15, 121, 103, 223, 5, 203, 29, 77, 241, 244, 91, 56, 78, 140, 71, 3, 154, 125, 146, 86,
+    128, 132, 99, 184, 249, 15, 16, 132, 121, 9, 220, 2, 184, 171, 13, 131, 135, 237, 177, 245, 56,
+    213, 180, 26, 74, 183, 254, 234, 141, 235, 50, 130, 163, 109, 181, 59, 9, 95, 184, 137, 66,
+    236, 65, 116, 215, 23, 161, 238, 92, 56, 80, 224, 155, 212, 78, 126, 40, 43, 169, 91, 73, 209,
+    137, 120, 205, 44, 138, 62, 72, 11, 192, 124, 78, 192, 226, 150, 125, 14, 167, 199, 231, 162,
+    95, 220, 195, 134, 27, 46, 160, 228, 104, 87, 106, 150, 161, 130, 39, 180, 168, 26, 234, 19,
+    14, 141, 171, 135, 52, 103, 193, 167, 17, 170, 223, 209, 147, 230, 154, 148, 90, 15, 156, 172,
+    204, 52, 186, 136, 103, 7, 15, 237, 156, 197, 127, 119, 53, 214, 165, 12, 194, 22, 130, 235,
+    78, 89, 150, 194, 131, 27, 156, 109, 185, 229, 217, 252, 231, 50, 165, 215, 181, 240, 150, 122,
+    219, 43, 209, 7, 191, 82, 50, 200, 14, 31, 178, 176, 64, 68, 200, 201, 172, 90, 66, 87, 169,
+    23, 8, 213, 105, 146, 14, 76, 102, 239, 162, 38, 194, 123, 114, 6, 0, 0,
 ];
 pub const TRANSFER_FROM_NEW_COMM_32: [u8; 32] = [
     254, 246, 223, 20, 247, 64, 140, 20, 227, 101, 84, 82, 21, 211, 6, 244, 26, 106, 167, 66, 234,
@@ -81,75 +122,102 @@ pub const TRANSFER_TO_NEW_COMM_32: [u8; 32] = [
     221, 122, 16, 18, 111, 168, 152, 77, 203, 61, 32,
 ];
 
+// ----- Auditor handle for the transfer ciphertext (sender key) -----
+pub const AUDITOR_PK32: [u8; 32] = [
+    104, 40, 2, 179, 201, 1, 18, 224, 244, 231, 217, 133, 228, 35, 205, 43, 22, 197, 191, 166, 61,
+    156, 150, 124, 82, 187, 108, 183, 254, 167, 234, 126,
+];
+pub const TRANSFER_AUDITOR_HANDLE: &[u8] = &[
+    146, 195, 221, 73, 250, 41, 18, 2, 250, 159, 222, 13, 94, 234, 1, 134, 176, 15, 25, 209, 156,
+    255, 35, 122, 140, 6, 73, 124, 199, 10, 211, 70, 122, 30, 138, 9, 43, 73, 235, 221, 78, 179,
+    71, 160, 81, 169, 197, 185, 7, 158, 102, 168, 240, 39, 57, 248, 103, 152, 65, 58, 124, 198, 68,
+    101, 38, 59, 91, 1, 39, 226, 20, 94, 173, 160, 158, 168, 211, 70, 117, 232, 75, 73, 108, 38,
+    36, 197, 176, 190, 208, 100, 246, 93, 171, 5, 48, 14, 110, 178, 228, 125, 101, 42, 175, 4, 117,
+    161, 163, 117, 185, 114, 255, 152, 118, 25, 59, 102, 216, 47, 86, 167, 213, 37, 70, 176, 52,
+    28, 216, 6, 216, 212, 248, 101, 21, 119, 23, 179, 143, 112, 229, 252, 49, 13, 208, 163, 25,
+    132, 162, 59, 199, 250, 213, 169, 246, 215, 112, 231, 13, 179, 215, 103, 110, 15, 193, 127,
+    133, 109, 73, 78, 111, 248, 39, 9, 251, 252, 1, 112, 115, 34, 168, 190, 42, 140, 152, 244, 130,
+    196, 162, 6, 119, 29, 150, 31, 172, 255, 97, 90, 46, 86, 234, 241, 35, 129, 96, 94, 176, 105,
+    182, 110, 209, 163, 98, 32, 77, 183, 41, 4, 111, 89, 25, 160, 105, 255, 59, 99, 20, 87, 221,
+    162, 4, 165, 143, 100, 199, 229, 141, 178, 252, 70, 228, 153, 192, 93, 89, 210, 198, 185, 159,
+    252, 178, 11, 235, 187, 9, 92, 97, 2, 12, 186, 160, 205, 140, 227, 163, 181, 106, 195, 105, 85,
+    79, 16, 213, 121, 47, 73, 3, 219, 23, 59, 190, 8, 42, 108, 200, 17, 224, 15, 228, 102, 159,
+    209, 111, 176, 190, 141, 172, 155, 206, 138, 96, 159, 106, 176, 250, 210, 130, 47, 71, 151, 63,
+    153, 224, 29, 7, 17, 223, 35, 0, 162, 225, 13, 252, 16, 86, 36, 10, 38, 170, 71, 221, 125, 242,
+    87, 154, 233, 191, 198, 67, 222, 159, 197, 175, 69, 61, 31, 157, 185, 244, 78, 1, 105, 7, 2,
+    86, 250, 161, 254, 143, 212, 15, 176, 108, 58, 130, 197, 32, 120, 209, 29, 211, 222, 51, 176,
+    70, 84, 25, 78, 43, 68, 231, 192, 194, 49, 104, 5,
+];
+
 // ----- Receiver accept -----
 pub const ACCEPT_ENVELOPE: &[u8] = &[
     108, 134, 244, 63, 93, 90, 88, 172, 248, 243, 93, 47, 79, 247, 244, 73, 214, 149, 4, 245, 110,
-    221, 122, 16, 18, 111, 168, 152, 77, 203, 61, 32, 160, 2, 196, 149, 28, 193, 107, 95, 16, 153,
-    90, 62, 170, 247, 218, 112, 33, 117, 213, 68, 36, 49, 176, 197, 215, 205, 252, 54, 189, 139, 2,
-    187, 200, 10, 158, 88, 0, 220, 151, 179, 69, 97, 1, 6, 215, 86, 193, 233, 3, 36, 154, 183, 3,
-    185, 92, 29, 84, 36, 203, 109, 94, 168, 220, 225, 231, 107, 206, 3, 239, 216, 135, 160, 198,
-    172, 164, 229, 72, 42, 63, 31, 69, 202, 34, 202, 236, 37, 4, 200, 243, 234, 200, 49, 109, 58,
-    105, 19, 21, 1, 230, 103, 230, 227, 7, 117, 246, 182, 62, 60, 36, 223, 222, 158, 156, 40, 229,
-    63, 218, 83, 213, 233, 130, 25, 53, 91, 148, 163, 236, 95, 233, 105, 176, 112, 74, 252, 72,
-    206, 94, 194, 248, 241, 46, 229, 75, 98, 27, 213, 17, 71, 129, 170, 47, 169, 219, 140, 159,
-    240, 231, 81, 146, 209, 185, 2, 17, 242, 109, 223, 46, 136, 111, 186, 36, 244, 93, 182, 190,
-    97, 177, 105, 59, 145, 145, 173, 165, 211, 225, 202, 50, 241, 238, 60, 192, 133, 91, 4, 191,
-    112, 19, 46, 173, 200, 89, 116, 246, 145, 226, 180, 204, 166, 165, 67, 208, 209, 155, 136, 226,
-    137, 135, 209, 11, 18, 47, 255, 232, 106, 209, 14, 248, 32, 16, 157, 161, 135, 98, 154, 96, 56,
-    148, 222, 35, 55, 62, 89, 212, 220, 11, 224, 213, 225, 53, 116, 100, 39, 34, 162, 225, 235, 5,
-    37, 82, 201, 191, 136, 45, 223, 70, 74, 117, 255, 74, 226, 63, 53, 120, 185, 59, 158, 96, 43,
-    65, 76, 193, 161, 155, 1, 252, 57, 118, 205, 120, 14, 250, 230, 89, 6, 16, 160, 138, 43, 189,
-    160, 148, 15, 128, 47, 111, 61, 91, 197, 110, 32, 199, 211, 67, 73, 174, 174, 33, 175, 158,
-    119, 124, 111, 252, 24, 245, 25, 213, 224, 77, 68, 207, 138, 138, 237, 69, 37, 23, 82, 72, 137,
-    13, 120, 72, 34, 76, 6, 153, 65, 202, 62, 90, 25, 115, 21, 160, 5, 144, 115, 162, 216, 28, 250,
-    46, 177, 201, 101, 190, 192, 102, 230, 4, 119, 196, 80, 225, 234, 249, 106, 137, 246, 105, 227,
-    210, 82, 91, 51, 136, 110, 234, 66, 116, 86, 114, 220, 221, 63, 254, 82, 169, 248, 158, 166,
-    127, 23, 100, 124, 244, 70, 137, 75, 32, 32, 86, 63, 165, 77, 239, 59, 234, 74, 42, 74, 157,
-    252, 36, 16, 249, 238, 116, 17, 196, 134, 75, 182, 79, 138, 92, 55, 21, 133, 63, 112, 65, 110,
-    93, 236, 205, 154, 211, 76, 224, 121, 153, 233, 212, 208, 195, 207, 236, 35, 107, 69, 92, 27,
-    46, 189, 236, 164, 168, 173, 10, 6, 138, 111, 44, 220, 236, 174, 73, 89, 87, 17, 92, 251, 240,
-    221, 216, 232, 187, 44, 199, 212, 185, 182, 184, 190, 228, 153, 114, 251, 125, 10, 51, 1, 55,
-    150, 186, 169, 31, 107, 50, 125, 6, 9, 110, 251, 11, 10, 48, 96, 133, 229, 86, 71, 67, 247, 38,
-    47, 85, 89, 131, 51, 32, 92, 229, 221, 105, 76, 6, 168, 247, 30, 9, 70, 167, 85, 214, 4, 102,
-    246, 143, 31, 141, 90, 194, 146, 218, 43, 83, 64, 84, 55, 30, 136, 148, 248, 37, 93, 144, 103,
-    158, 204, 22, 148, 74, 77, 189, 88, 148, 10, 96, 68, 113, 63, 173, 42, 153, 222, 90, 189, 80,
-    250, 94, 6, 17, 91, 112, 8, 63, 229, 81, 91, 169, 223, 28, 211, 178, 70, 94, 55, 51, 236, 227,
-    42, 102, 212, 162, 79, 78, 50, 72, 203, 99, 163, 240, 238, 193, 149, 185, 30, 100, 26, 68, 0,
-    120, 131, 248, 174, 139, 29, 73, 4, 51, 184, 105, 172, 66, 36, 237, 122, 182, 248, 138, 87,
-    228, 45, 17, 66, 28, 133, 117, 104, 70, 34, 185, 198, 130, 254, 223, 73, 197, 159, 5, 7, 160,
-    2, 196, 146, 117, 53, 213, 141, 222, 78, 196, 69, 239, 63, 146, 85, 232, 21, 195, 11, 38, 20,
-    53, 219, 228, 166, 178, 104, 113, 123, 65, 7, 134, 37, 150, 108, 118, 64, 174, 1, 177, 65, 29,
-    160, 16, 196, 216, 5, 23, 147, 23, 195, 171, 9, 62, 59, 106, 29, 82, 246, 19, 159, 181, 127,
-    104, 69, 14, 169, 181, 236, 128, 120, 154, 248, 0, 110, 218, 94, 2, 228, 72, 194, 117, 165, 72,
-    253, 69, 7, 92, 152, 178, 179, 65, 113, 68, 12, 216, 43, 88, 62, 43, 255, 94, 122, 205, 103,
-    12, 46, 113, 110, 17, 135, 182, 189, 219, 65, 136, 20, 143, 240, 102, 118, 81, 127, 165, 96,
-    107, 190, 74, 29, 239, 189, 62, 94, 67, 36, 131, 73, 70, 212, 233, 36, 206, 165, 253, 128, 96,
-    145, 13, 107, 237, 82, 135, 3, 183, 94, 227, 22, 45, 43, 40, 2, 190, 15, 172, 10, 130, 15, 68,
-    234, 17, 151, 255, 58, 10, 202, 194, 98, 186, 131, 9, 71, 89, 38, 228, 239, 197, 198, 86, 158,
-    224, 150, 89, 9, 105, 114, 157, 11, 182, 183, 211, 159, 59, 206, 21, 5, 88, 206, 238, 21, 243,
-    8, 241, 114, 64, 51, 48, 55, 35, 230, 213, 178, 49, 212, 127, 11, 84, 244, 168, 98, 63, 122,
-    122, 176, 26, 16, 12, 255, 217, 11, 91, 3, 208, 213, 162, 134, 105, 120, 245, 29, 2, 116, 201,
-    139, 163, 93, 204, 43, 174, 209, 235, 103, 52, 69, 248, 134, 59, 200, 127, 214, 58, 213, 5,
-    104, 53, 100, 62, 121, 78, 250, 232, 223, 39, 168, 18, 114, 234, 125, 123, 103, 112, 235, 222,
-    165, 177, 22, 129, 21, 135, 186, 51, 83, 119, 60, 230, 59, 1, 255, 243, 7, 161, 20, 11, 26, 86,
-    166, 51, 144, 246, 200, 117, 91, 20, 247, 76, 153, 31, 229, 7, 139, 193, 236, 121, 54, 247,
-    177, 184, 39, 149, 210, 210, 84, 157, 146, 46, 19, 136, 235, 229, 117, 92, 210, 79, 29, 176,
-    141, 55, 183, 130, 171, 145, 114, 72, 14, 169, 132, 142, 8, 30, 35, 120, 151, 107, 86, 25, 25,
-    250, 24, 191, 223, 58, 235, 8, 193, 162, 116, 2, 192, 77, 184, 139, 174, 175, 148, 26, 175, 9,
-    200, 232, 59, 236, 18, 78, 49, 9, 161, 116, 255, 149, 153, 147, 114, 4, 167, 205, 213, 219, 94,
-    46, 74, 80, 195, 181, 32, 53, 222, 206, 253, 75, 179, 143, 48, 45, 62, 61, 135, 46, 120, 89,
-    225, 51, 130, 189, 104, 99, 132, 87, 27, 59, 66, 46, 251, 247, 216, 36, 216, 56, 5, 212, 203,
-    225, 164, 27, 149, 168, 241, 252, 214, 31, 216, 15, 101, 248, 123, 128, 8, 170, 187, 37, 75,
-    18, 120, 152, 255, 28, 202, 142, 131, 89, 95, 133, 105, 154, 150, 133, 56, 1, 181, 230, 202,
-    141, 227, 142, 229, 235, 24, 188, 232, 238, 138, 83, 182, 176, 50, 18, 195, 141, 61, 29, 3,
-    155, 187, 138, 176, 102, 135, 47, 93, 40, 97, 7, 29, 184, 111, 30, 169, 87, 235, 95, 94, 114,
-    3, 71, 238, 180, 54, 202, 171, 55, 89, 236, 45, 189, 228, 219, 33, 86, 126, 76, 197, 255, 182,
-    105, 233, 128, 71, 48, 214, 243, 65, 87, 43, 138, 166, 79, 6, 1, 84, 120, 57, 85, 186, 161, 16,
-    158, 50, 102, 220, 211, 76, 222, 50, 199, 112, 43, 14, 55, 214, 199, 168, 111, 52, 208, 211,
-    83, 207, 169, 143, 148, 31, 235, 90, 157, 128, 235, 252, 215, 106, 240, 123, 35, 24, 166, 116,
-    210, 162, 190, 74, 178, 138, 97, 191, 5, 104, 130, 112, 189, 65, 175, 233, 86, 2, 227, 164, 32,
-    233, 24, 16, 46, 241, 57, 150, 12, 237, 32, 42, 214, 221, 53, 219, 86, 75, 238, 152, 243, 33,
-    46, 37, 70, 228, 243, 98, 116, 15,
+    221, 122, 16, 18, 111, 168, 152, 77, 203, 61, 32, 160, 2, 206, 127, 155, 7, 83, 132, 57, 156,
+    166, 200, 46, 180, 171, 144, 64, 36, 233, 192, 203, 95, 194, 238, 105, 14, 90, 67, 147, 201,
+    138, 127, 113, 47, 248, 91, 190, 84, 53, 231, 189, 103, 65, 185, 178, 85, 63, 239, 227, 203,
+    89, 196, 255, 91, 179, 185, 249, 237, 117, 254, 51, 98, 98, 120, 173, 106, 172, 239, 26, 10,
+    219, 48, 174, 98, 187, 23, 159, 227, 63, 7, 13, 190, 184, 142, 21, 40, 116, 86, 94, 32, 16, 22,
+    70, 138, 90, 68, 61, 117, 50, 247, 59, 84, 112, 234, 86, 56, 12, 148, 62, 148, 92, 14, 253, 71,
+    78, 214, 11, 22, 101, 90, 217, 115, 76, 73, 101, 201, 24, 143, 62, 31, 12, 255, 194, 196, 35,
+    36, 144, 138, 180, 61, 149, 34, 216, 15, 9, 44, 80, 169, 182, 165, 167, 34, 179, 253, 104, 81,
+    220, 187, 233, 124, 120, 11, 27, 189, 6, 16, 165, 128, 50, 178, 26, 192, 164, 225, 224, 51,
+    158, 69, 18, 93, 78, 187, 167, 222, 121, 70, 125, 221, 156, 228, 166, 64, 89, 1, 97, 127, 232,
+    218, 246, 79, 217, 80, 69, 176, 193, 27, 198, 180, 33, 247, 180, 78, 194, 203, 87, 152, 101,
+    166, 167, 134, 233, 160, 51, 224, 166, 4, 6, 150, 249, 132, 196, 38, 86, 204, 169, 216, 199,
+    24, 166, 65, 166, 73, 116, 221, 213, 224, 124, 140, 124, 228, 50, 191, 178, 93, 14, 255, 35,
+    119, 208, 71, 159, 171, 51, 11, 187, 150, 39, 169, 129, 8, 36, 228, 13, 169, 253, 76, 110, 84,
+    137, 31, 80, 57, 112, 212, 253, 106, 195, 238, 174, 103, 60, 85, 1, 215, 84, 142, 93, 79, 82,
+    147, 39, 1, 106, 21, 252, 134, 236, 228, 68, 204, 39, 207, 131, 74, 105, 75, 225, 114, 197,
+    162, 233, 53, 152, 43, 243, 144, 56, 186, 68, 1, 120, 176, 8, 169, 252, 115, 239, 91, 18, 89,
+    89, 177, 116, 65, 194, 5, 96, 196, 76, 122, 170, 37, 85, 35, 162, 9, 145, 68, 206, 67, 120,
+    219, 215, 165, 164, 139, 105, 241, 214, 134, 83, 148, 184, 52, 65, 91, 124, 114, 52, 8, 94,
+    108, 173, 59, 113, 54, 34, 103, 246, 97, 232, 3, 215, 74, 159, 243, 25, 142, 97, 15, 88, 242,
+    221, 143, 209, 155, 5, 50, 251, 86, 227, 86, 41, 157, 177, 76, 75, 115, 144, 25, 156, 15, 140,
+    201, 154, 39, 159, 94, 124, 137, 76, 234, 71, 255, 151, 4, 152, 47, 238, 64, 221, 177, 144,
+    114, 145, 81, 161, 103, 98, 123, 92, 244, 133, 154, 134, 179, 56, 77, 168, 53, 110, 21, 144,
+    181, 47, 46, 238, 102, 83, 145, 199, 59, 80, 208, 60, 80, 36, 153, 244, 119, 255, 123, 122,
+    227, 84, 70, 118, 247, 73, 225, 236, 210, 91, 153, 84, 242, 230, 159, 157, 212, 99, 144, 171,
+    87, 155, 19, 44, 248, 143, 213, 104, 249, 62, 3, 188, 210, 148, 25, 150, 140, 18, 123, 131, 57,
+    187, 178, 249, 108, 194, 246, 95, 205, 224, 226, 105, 228, 160, 103, 96, 137, 28, 140, 32, 123,
+    181, 118, 174, 208, 10, 13, 120, 179, 109, 217, 163, 153, 122, 154, 245, 163, 223, 6, 73, 87,
+    2, 132, 240, 255, 38, 62, 145, 55, 37, 162, 185, 202, 76, 126, 188, 136, 178, 149, 74, 8, 136,
+    187, 25, 158, 189, 238, 205, 5, 82, 112, 196, 167, 104, 2, 219, 235, 137, 104, 184, 224, 186,
+    234, 236, 97, 225, 10, 219, 247, 209, 151, 181, 72, 123, 106, 116, 199, 181, 3, 104, 136, 52,
+    129, 142, 127, 106, 184, 221, 150, 177, 45, 227, 210, 85, 92, 188, 207, 188, 2, 11, 200, 149,
+    146, 154, 141, 229, 251, 181, 218, 75, 222, 228, 34, 245, 90, 93, 81, 238, 239, 89, 114, 146,
+    140, 79, 34, 25, 113, 13, 82, 68, 5, 160, 2, 244, 247, 94, 23, 26, 38, 64, 137, 76, 94, 111,
+    197, 22, 12, 94, 112, 57, 249, 124, 117, 74, 219, 139, 140, 227, 102, 130, 240, 100, 250, 182,
+    62, 8, 24, 254, 142, 183, 48, 52, 76, 193, 243, 9, 255, 141, 224, 54, 78, 211, 1, 108, 43, 242,
+    32, 75, 187, 44, 7, 238, 121, 125, 217, 81, 8, 52, 130, 4, 174, 27, 39, 177, 247, 62, 196, 170,
+    212, 172, 92, 189, 147, 38, 67, 156, 161, 158, 5, 208, 159, 17, 50, 246, 65, 194, 21, 152, 123,
+    120, 135, 15, 146, 45, 62, 11, 17, 86, 163, 193, 135, 63, 121, 206, 102, 217, 252, 16, 159,
+    241, 210, 122, 118, 120, 160, 104, 224, 31, 132, 80, 127, 89, 104, 133, 95, 114, 229, 169, 220,
+    250, 218, 222, 141, 66, 166, 219, 10, 22, 227, 99, 100, 216, 64, 139, 255, 31, 4, 186, 177,
+    111, 74, 162, 11, 208, 128, 225, 187, 254, 244, 178, 94, 190, 33, 60, 51, 216, 154, 31, 152,
+    217, 88, 176, 96, 106, 100, 198, 43, 81, 133, 133, 157, 118, 6, 180, 7, 181, 182, 122, 176, 52,
+    33, 183, 42, 13, 102, 80, 229, 252, 180, 253, 51, 159, 54, 239, 163, 211, 218, 25, 21, 218, 95,
+    0, 129, 187, 210, 146, 7, 188, 181, 252, 195, 235, 96, 222, 160, 213, 195, 90, 170, 133, 217,
+    28, 16, 95, 242, 124, 13, 71, 173, 243, 27, 120, 252, 162, 132, 231, 50, 29, 116, 136, 142,
+    236, 236, 184, 23, 85, 120, 179, 12, 133, 132, 192, 221, 150, 123, 220, 149, 163, 111, 199,
+    109, 117, 75, 229, 47, 75, 39, 90, 21, 198, 47, 170, 25, 0, 65, 139, 144, 245, 159, 62, 31,
+    197, 100, 24, 40, 211, 251, 4, 54, 163, 170, 204, 136, 199, 170, 171, 127, 15, 252, 104, 106,
+    77, 60, 18, 2, 175, 2, 137, 169, 71, 22, 187, 72, 46, 121, 198, 155, 203, 193, 128, 120, 87,
+    235, 66, 76, 54, 151, 204, 38, 104, 114, 101, 208, 4, 0, 206, 66, 160, 13, 144, 106, 63, 150,
+    79, 30, 8, 101, 95, 151, 0, 185, 126, 50, 160, 95, 146, 234, 185, 198, 220, 14, 44, 80, 183,
+    252, 250, 20, 150, 228, 193, 105, 208, 58, 220, 128, 150, 118, 248, 43, 254, 117, 68, 126, 158,
+    30, 179, 22, 163, 34, 69, 17, 35, 80, 204, 238, 14, 138, 135, 91, 204, 252, 126, 108, 45, 9,
+    45, 172, 100, 110, 31, 142, 251, 203, 249, 60, 57, 194, 147, 190, 16, 104, 219, 71, 87, 132,
+    121, 44, 35, 135, 56, 51, 92, 132, 62, 214, 21, 44, 142, 2, 239, 214, 136, 16, 86, 15, 42, 173,
+    175, 7, 222, 61, 172, 170, 86, 58, 141, 79, 63, 84, 9, 136, 242, 53, 80, 9, 28, 55, 206, 252,
+    135, 4, 232, 194, 138, 77, 236, 94, 158, 24, 44, 8, 5, 118, 247, 25, 164, 76, 151, 254, 151,
+    130, 127, 245, 177, 58, 158, 204, 125, 246, 199, 4, 65, 185, 249, 112, 230, 207, 19, 13, 248,
+    35, 218, 207, 168, 37, 43, 70, 220, 173, 156, 238, 61, 96, 68, 66, 154, 69, 18, 206, 39, 89,
+    118, 32, 164, 42, 252, 215, 199, 127, 235, 158, 35, 118, 130, 196, 18, 179, 41, 115, 204, 212,
+    59, 99, 183, 180, 239, 131, 240, 51, 54, 139, 37, 34, 133, 165, 11, 255, 168, 253, 179, 1, 97,
+    18, 15, 241, 113, 165, 132, 80, 88, 194, 145, 74, 180, 59, 45, 185, 254, 68, 174, 45, 246, 169,
+    128, 157, 209, 203, 40, 74, 42, 165, 85, 176, 251, 18, 65, 50, 41, 118, 247, 235, 135, 82, 227,
+    245, 184, 47, 104, 169, 81, 233, 159, 5, 143, 97, 96, 183, 11, 174, 80, 120, 89, 94, 180, 6,
+    238, 248, 69, 74, 174, 82, 14, 171, 91, 234, 185, 119, 212, 145, 202, 174, 78, 19, 251, 9,
 ];
 pub const ACCEPT_AVAIL_NEW_COMM_32: [u8; 32] = [
     108, 134, 244, 63, 93, 90, 88, 172, 248, 243, 93, 47, 79, 247, 244, 73, 214, 149, 4, 245, 110,
@@ -175,72 +243,71 @@ pub const MINT_PROOF: &[u8] = &[
     145, 230, 193, 176, 106, 233, 67, 209, 198, 209, 241, 177, 200, 38, 150, 209, 63, 138, 229,
     163, 152, 255, 123, 56, 207, 240, 2, 157, 211, 250, 215, 113, 136, 86, 249, 31, 43, 203, 63,
     75, 159, 210, 136, 245, 67, 70, 53, 250, 170, 113, 48, 70, 253, 122, 109, 116, 56, 107, 12,
-    160, 2, 90, 166, 214, 167, 103, 191, 27, 118, 244, 219, 220, 25, 148, 238, 225, 200, 178, 175,
-    229, 151, 136, 152, 165, 69, 143, 74, 132, 34, 191, 186, 141, 93, 212, 244, 9, 241, 113, 10,
-    130, 78, 22, 70, 179, 75, 170, 183, 216, 46, 77, 44, 15, 7, 33, 11, 64, 75, 237, 142, 69, 19,
-    129, 150, 17, 117, 44, 18, 233, 207, 12, 121, 61, 222, 7, 124, 102, 253, 109, 3, 44, 254, 39,
-    74, 10, 133, 202, 240, 119, 17, 61, 76, 187, 230, 108, 84, 31, 52, 62, 2, 129, 93, 239, 15,
-    113, 100, 19, 83, 7, 77, 22, 99, 53, 49, 89, 84, 32, 123, 232, 25, 33, 38, 106, 210, 43, 220,
-    72, 120, 30, 115, 12, 226, 247, 181, 9, 165, 189, 224, 219, 162, 233, 97, 83, 176, 40, 131, 70,
-    246, 159, 17, 194, 49, 205, 150, 214, 82, 86, 83, 35, 42, 230, 11, 200, 163, 112, 157, 54, 29,
-    56, 230, 226, 41, 25, 204, 22, 54, 135, 43, 27, 222, 209, 42, 189, 71, 154, 149, 115, 15, 86,
-    147, 231, 159, 132, 2, 101, 239, 11, 84, 52, 79, 223, 110, 73, 220, 205, 208, 148, 225, 131,
-    95, 158, 180, 242, 242, 129, 66, 36, 217, 190, 84, 48, 143, 221, 160, 172, 3, 26, 94, 161, 80,
-    231, 153, 56, 124, 158, 22, 116, 25, 70, 37, 112, 49, 227, 135, 31, 225, 190, 0, 4, 177, 68,
-    209, 30, 212, 39, 130, 90, 92, 188, 222, 208, 142, 56, 110, 31, 201, 94, 241, 99, 182, 236,
-    243, 38, 23, 122, 171, 112, 100, 51, 192, 27, 186, 100, 99, 159, 51, 180, 123, 49, 13, 106, 10,
-    61, 104, 149, 216, 223, 178, 11, 82, 193, 163, 202, 43, 158, 73, 29, 16, 44, 0, 173, 112, 198,
-    219, 144, 47, 112, 21, 128, 192, 120, 69, 12, 71, 11, 229, 30, 206, 249, 101, 26, 194, 170,
-    207, 33, 38, 133, 124, 102, 183, 78, 17, 163, 145, 101, 176, 149, 176, 135, 32, 173, 54, 36,
-    86, 46, 123, 20, 0, 72, 244, 240, 17, 139, 111, 143, 152, 36, 195, 171, 64, 44, 14, 27, 127,
-    59, 111, 223, 16, 62, 204, 27, 37, 24, 11, 189, 6, 74, 124, 14, 70, 194, 44, 31, 59, 114, 42,
-    107, 165, 250, 14, 78, 131, 26, 116, 220, 12, 156, 244, 218, 226, 229, 2, 245, 14, 219, 3, 73,
-    48, 150, 100, 149, 119, 145, 129, 236, 60, 108, 134, 10, 126, 140, 215, 255, 223, 136, 250,
-    151, 158, 232, 189, 250, 89, 206, 193, 236, 61, 45, 158, 232, 53, 236, 218, 190, 172, 158, 230,
-    207, 8, 155, 173, 232, 223, 11, 203, 84, 232, 167, 34, 244, 157, 91, 44, 7, 141, 64, 91, 173,
-    192, 186, 117, 59, 86, 106, 190, 191, 40, 43, 59, 32, 140, 37, 3, 243, 128, 142, 172, 240, 18,
-    140, 182, 170, 188, 249, 135, 214, 163, 33, 213, 118, 171, 158, 55, 141, 58, 164, 160, 157, 98,
-    112, 73, 156, 253, 166, 173, 163, 230, 109, 111, 131, 251, 62, 57, 222, 110, 157, 130, 212,
-    239, 6, 185, 39, 133, 229, 225, 43, 47, 24, 230, 19, 149, 201, 236, 77, 122, 30, 178, 30, 252,
-    106, 85, 53, 243, 201, 206, 175, 3, 18, 156, 79, 77, 190, 88, 165, 215, 75, 114, 200, 60, 16,
-    57, 18, 106, 136, 110, 137, 144, 143, 220, 202, 219, 58, 9, 87, 188, 187, 242, 226, 121, 55,
-    187, 163, 98, 220, 113, 4, 122, 80, 183, 25, 50, 254, 255, 8, 49, 197, 31, 175, 23, 111, 251,
-    114, 166, 55, 77, 204, 223, 63, 240, 34, 5, 243, 220, 155, 209, 1, 127, 70, 116, 63, 151, 22,
-    3, 223, 237, 103, 10, 60, 30, 123, 137, 223, 201, 234, 195, 41, 59, 22, 20, 83, 217, 226, 218,
-    130, 64, 230, 0, 231, 178, 134, 54, 4, 60, 85, 7, 160, 2, 158, 236, 211, 227, 87, 78, 197, 186,
-    17, 19, 113, 113, 96, 88, 178, 240, 172, 32, 73, 202, 115, 19, 210, 192, 198, 189, 154, 46,
-    174, 11, 19, 52, 232, 173, 131, 129, 188, 74, 248, 175, 65, 106, 78, 53, 49, 131, 53, 69, 52,
-    71, 255, 238, 216, 28, 98, 214, 166, 81, 73, 179, 59, 112, 207, 43, 88, 198, 56, 40, 132, 68,
-    70, 25, 234, 168, 224, 64, 121, 237, 205, 237, 93, 202, 17, 92, 153, 241, 228, 210, 237, 33,
-    60, 212, 76, 121, 222, 100, 160, 48, 199, 187, 191, 186, 248, 114, 15, 136, 162, 49, 152, 225,
-    105, 174, 245, 46, 108, 172, 167, 81, 232, 111, 174, 2, 166, 141, 41, 32, 253, 102, 207, 247,
-    29, 76, 211, 51, 240, 201, 66, 233, 237, 62, 218, 198, 13, 60, 34, 93, 240, 104, 74, 169, 24,
-    174, 230, 215, 84, 217, 91, 11, 39, 12, 213, 57, 70, 21, 29, 209, 213, 212, 206, 41, 131, 28,
-    171, 255, 55, 229, 196, 164, 252, 55, 74, 1, 60, 152, 201, 215, 201, 194, 103, 89, 0, 3, 141,
-    154, 104, 166, 101, 217, 203, 136, 41, 21, 52, 241, 199, 125, 70, 30, 125, 98, 170, 224, 125,
-    86, 2, 146, 193, 244, 171, 254, 239, 229, 166, 0, 176, 20, 75, 64, 91, 195, 185, 201, 189, 150,
-    141, 87, 82, 21, 194, 177, 203, 193, 2, 101, 119, 181, 115, 124, 236, 223, 16, 241, 126, 8,
-    175, 18, 36, 206, 149, 142, 236, 84, 189, 80, 156, 80, 151, 81, 146, 35, 135, 100, 25, 99, 220,
-    7, 83, 143, 131, 38, 114, 180, 213, 21, 17, 169, 26, 14, 186, 242, 234, 5, 131, 34, 111, 87,
-    224, 92, 138, 155, 131, 156, 103, 207, 118, 167, 123, 8, 107, 119, 52, 110, 3, 213, 97, 255,
-    190, 225, 119, 68, 48, 228, 16, 154, 171, 16, 10, 22, 120, 54, 166, 3, 199, 247, 146, 97, 107,
-    42, 216, 254, 65, 66, 183, 166, 169, 202, 38, 197, 196, 60, 43, 36, 72, 245, 252, 101, 32, 130,
-    66, 92, 95, 110, 178, 53, 98, 30, 33, 7, 195, 201, 172, 27, 6, 200, 59, 190, 146, 160, 156,
-    139, 255, 240, 194, 32, 162, 111, 149, 186, 7, 223, 62, 205, 151, 249, 24, 224, 156, 105, 188,
-    79, 159, 40, 187, 52, 7, 163, 254, 149, 168, 209, 127, 53, 102, 91, 159, 29, 238, 115, 79, 94,
-    190, 34, 76, 246, 127, 85, 20, 139, 162, 68, 234, 33, 34, 147, 8, 143, 248, 63, 217, 94, 176,
-    27, 36, 20, 230, 144, 34, 67, 230, 188, 147, 14, 200, 102, 85, 208, 138, 70, 183, 225, 109, 91,
-    5, 78, 10, 20, 243, 12, 101, 197, 159, 197, 119, 221, 127, 209, 2, 59, 102, 76, 196, 150, 170,
-    129, 246, 32, 79, 143, 67, 104, 152, 61, 97, 62, 139, 19, 130, 232, 122, 76, 176, 44, 7, 248,
-    175, 177, 231, 8, 118, 163, 93, 121, 46, 17, 177, 232, 82, 122, 220, 87, 170, 107, 72, 50, 43,
-    68, 28, 44, 179, 190, 166, 174, 190, 122, 96, 92, 9, 146, 161, 116, 216, 44, 41, 116, 194, 33,
-    200, 192, 11, 124, 244, 139, 10, 98, 6, 236, 130, 9, 97, 203, 26, 199, 103, 130, 36, 188, 21,
-    188, 176, 17, 240, 126, 202, 156, 240, 26, 212, 43, 27, 192, 206, 65, 86, 196, 18, 140, 104,
-    25, 159, 88, 164, 0, 141, 188, 158, 102, 50, 43, 166, 55, 208, 223, 103, 172, 145, 110, 220,
-    49, 141, 29, 77, 243, 184, 96, 109, 103, 9, 142, 82, 144, 254, 99, 36, 108, 109, 172, 244, 141,
-    25, 83, 32, 173, 233, 204, 8, 211, 18, 7, 182, 6, 240, 210, 80, 122, 249, 86, 48, 70, 124, 159,
-    189, 239, 208, 49, 58, 73, 228, 51, 223, 209, 86, 239, 83, 231, 173, 31, 56, 30, 78, 244, 248,
-    6,
+    160, 2, 186, 19, 56, 194, 75, 1, 41, 144, 50, 105, 19, 54, 126, 50, 46, 152, 168, 116, 10, 187,
+    35, 48, 240, 93, 235, 188, 204, 51, 173, 246, 150, 55, 14, 99, 110, 70, 187, 200, 85, 230, 177,
+    93, 155, 93, 204, 76, 7, 128, 149, 23, 165, 126, 147, 95, 140, 55, 21, 127, 5, 29, 142, 200,
+    231, 28, 136, 181, 226, 127, 45, 229, 189, 122, 4, 43, 41, 253, 145, 251, 37, 94, 165, 245, 75,
+    178, 234, 214, 233, 180, 255, 69, 57, 3, 184, 131, 91, 34, 154, 26, 240, 138, 102, 71, 110, 23,
+    17, 190, 29, 48, 108, 80, 119, 233, 141, 142, 62, 2, 13, 3, 214, 218, 118, 155, 11, 216, 203,
+    138, 230, 49, 242, 166, 179, 133, 173, 19, 62, 65, 223, 125, 101, 38, 173, 30, 245, 127, 31,
+    102, 22, 176, 178, 43, 137, 69, 170, 182, 147, 68, 254, 175, 246, 1, 130, 188, 36, 58, 26, 223,
+    126, 151, 103, 199, 175, 37, 40, 16, 31, 82, 101, 232, 161, 252, 97, 61, 30, 129, 5, 31, 155,
+    235, 79, 148, 248, 15, 254, 62, 0, 39, 180, 206, 75, 45, 231, 12, 91, 83, 69, 210, 111, 15,
+    146, 35, 23, 31, 24, 180, 208, 251, 193, 71, 133, 136, 240, 113, 235, 15, 44, 223, 232, 208,
+    161, 75, 44, 158, 14, 224, 242, 178, 134, 128, 7, 118, 61, 192, 230, 153, 187, 49, 11, 22, 255,
+    56, 76, 60, 122, 96, 140, 52, 16, 99, 167, 62, 242, 187, 127, 18, 78, 30, 148, 177, 253, 25,
+    143, 6, 152, 124, 93, 202, 5, 123, 17, 192, 156, 61, 174, 124, 249, 27, 200, 32, 8, 145, 245,
+    182, 160, 83, 145, 9, 160, 68, 82, 121, 79, 15, 78, 171, 34, 253, 120, 86, 212, 110, 37, 122,
+    134, 60, 56, 136, 213, 90, 216, 71, 96, 62, 54, 137, 5, 47, 32, 171, 160, 143, 213, 81, 53, 48,
+    90, 8, 219, 237, 210, 239, 168, 201, 80, 202, 94, 155, 192, 196, 221, 80, 92, 16, 202, 114,
+    159, 206, 2, 224, 160, 222, 140, 59, 7, 64, 231, 225, 127, 205, 97, 32, 38, 17, 117, 126, 221,
+    40, 171, 97, 145, 193, 199, 9, 7, 6, 8, 98, 123, 134, 142, 165, 238, 11, 221, 74, 99, 187, 33,
+    122, 177, 251, 55, 16, 185, 188, 84, 179, 116, 215, 135, 120, 158, 132, 121, 97, 103, 5, 34,
+    230, 127, 250, 99, 51, 196, 170, 95, 118, 163, 164, 232, 58, 200, 63, 85, 88, 240, 167, 106,
+    60, 248, 37, 173, 2, 155, 82, 127, 215, 133, 104, 114, 36, 171, 241, 86, 52, 93, 165, 143, 226,
+    113, 93, 189, 114, 69, 182, 155, 116, 134, 66, 108, 232, 194, 200, 76, 108, 150, 10, 215, 250,
+    131, 60, 194, 195, 71, 81, 115, 32, 207, 89, 233, 18, 249, 87, 98, 115, 193, 239, 14, 90, 41,
+    149, 180, 17, 3, 191, 191, 133, 84, 239, 81, 121, 117, 36, 62, 49, 105, 159, 25, 249, 202, 229,
+    121, 137, 253, 141, 131, 48, 6, 121, 109, 214, 65, 198, 65, 10, 78, 114, 83, 26, 220, 32, 182,
+    199, 39, 59, 86, 123, 125, 79, 252, 205, 162, 236, 129, 221, 21, 164, 204, 59, 49, 160, 242,
+    251, 152, 154, 183, 135, 58, 219, 158, 239, 21, 135, 190, 25, 119, 67, 44, 108, 76, 138, 68,
+    70, 112, 196, 89, 85, 232, 97, 72, 75, 175, 92, 110, 40, 219, 99, 74, 157, 111, 134, 251, 149,
+    233, 171, 109, 230, 227, 66, 254, 25, 91, 212, 7, 210, 113, 242, 159, 184, 233, 119, 204, 36,
+    148, 26, 82, 221, 89, 191, 120, 91, 85, 193, 76, 66, 19, 49, 199, 61, 72, 0, 129, 129, 123, 59,
+    229, 166, 5, 246, 86, 253, 170, 189, 13, 236, 1, 122, 125, 239, 107, 221, 101, 61, 54, 33, 176,
+    85, 196, 2, 41, 159, 50, 12, 160, 2, 36, 245, 163, 202, 154, 47, 36, 233, 136, 143, 88, 110,
+    230, 12, 204, 207, 169, 71, 184, 188, 246, 180, 77, 243, 43, 129, 153, 26, 107, 171, 55, 19,
+    82, 215, 87, 162, 218, 16, 54, 147, 140, 218, 10, 178, 103, 182, 195, 97, 4, 121, 185, 41, 195,
+    45, 169, 236, 252, 191, 6, 146, 8, 83, 247, 22, 196, 216, 184, 116, 117, 182, 242, 36, 33, 141,
+    60, 176, 105, 157, 213, 90, 65, 113, 101, 116, 128, 108, 116, 193, 248, 226, 127, 213, 35, 61,
+    238, 0, 238, 129, 123, 221, 183, 112, 99, 68, 188, 72, 134, 100, 183, 11, 178, 139, 242, 217,
+    229, 52, 175, 217, 40, 113, 131, 7, 200, 227, 12, 59, 49, 117, 209, 28, 15, 81, 132, 18, 32,
+    229, 82, 228, 81, 4, 223, 250, 15, 41, 17, 140, 3, 97, 104, 25, 119, 54, 161, 42, 91, 225, 86,
+    37, 182, 12, 194, 47, 124, 144, 121, 83, 9, 16, 26, 41, 109, 130, 221, 6, 28, 55, 144, 223,
+    198, 255, 29, 170, 13, 197, 63, 146, 23, 102, 84, 91, 71, 11, 216, 141, 95, 172, 38, 253, 227,
+    158, 176, 179, 165, 40, 135, 38, 11, 247, 54, 127, 28, 85, 22, 94, 161, 172, 18, 1, 21, 228,
+    86, 5, 147, 13, 10, 243, 224, 228, 201, 188, 70, 39, 215, 91, 248, 68, 202, 15, 173, 132, 49,
+    207, 179, 209, 37, 153, 75, 40, 74, 29, 115, 39, 171, 167, 212, 121, 72, 100, 135, 136, 46,
+    112, 79, 139, 190, 38, 142, 61, 51, 119, 243, 201, 18, 43, 46, 111, 202, 88, 88, 38, 23, 43,
+    71, 238, 247, 31, 177, 73, 164, 148, 175, 51, 63, 139, 115, 33, 140, 130, 166, 50, 31, 99, 129,
+    127, 65, 193, 94, 152, 129, 168, 198, 210, 24, 65, 35, 67, 119, 83, 31, 53, 26, 152, 29, 111,
+    26, 58, 216, 142, 160, 191, 5, 67, 80, 208, 162, 148, 220, 37, 158, 15, 151, 255, 19, 14, 31,
+    64, 48, 110, 154, 134, 85, 66, 64, 212, 181, 104, 200, 10, 39, 130, 142, 3, 61, 224, 158, 32,
+    108, 132, 103, 168, 89, 65, 26, 151, 190, 140, 7, 23, 203, 19, 107, 118, 252, 85, 90, 177, 195,
+    133, 167, 255, 146, 122, 34, 195, 246, 242, 3, 187, 90, 97, 52, 247, 131, 182, 243, 186, 250,
+    136, 228, 246, 120, 72, 128, 236, 168, 35, 108, 123, 226, 87, 91, 96, 9, 185, 68, 87, 178, 84,
+    98, 111, 181, 119, 68, 171, 183, 44, 242, 61, 248, 58, 215, 142, 100, 118, 149, 4, 114, 6, 172,
+    153, 29, 55, 153, 26, 115, 54, 192, 123, 250, 227, 64, 147, 172, 179, 179, 19, 94, 248, 55,
+    103, 135, 31, 76, 179, 176, 1, 175, 152, 49, 124, 148, 202, 255, 164, 123, 254, 25, 124, 225,
+    229, 26, 21, 125, 9, 111, 180, 156, 85, 216, 12, 156, 176, 74, 179, 114, 152, 110, 54, 142,
+    172, 66, 7, 248, 205, 134, 121, 60, 85, 9, 187, 182, 18, 214, 216, 65, 147, 177, 128, 188, 159,
+    241, 31, 57, 247, 134, 49, 125, 156, 222, 208, 64, 151, 223, 10, 198, 182, 226, 213, 146, 8,
+    247, 217, 8, 165, 207, 115, 62, 65, 62, 130, 254, 186, 55, 71, 40, 56, 180, 255, 27, 115, 237,
+    163, 46, 238, 39, 119, 162, 201, 232, 50, 144, 112, 5, 190, 31, 214, 236, 74, 11, 69, 61, 49,
+    227, 42, 11, 10, 229, 229, 234, 158, 124, 238, 157, 156, 48, 241, 54, 39, 254, 141, 149, 74,
+    108, 54, 114, 72, 66, 243, 226, 84, 1, 121, 223, 122, 93, 220, 227, 49, 249, 119, 59, 66, 160,
+    11, 85, 197, 209, 100, 140, 6, 118, 142, 90, 23, 9, 70, 165, 121, 15, 185, 245, 95, 42, 123,
+    71, 25, 110, 145, 210, 54, 243, 31, 47, 144, 84, 10, 199, 121, 204, 182, 199, 4,
 ];
 pub const MINTED_CT_64: [u8; 64] = [
     200, 2, 247, 76, 224, 163, 144, 169, 3, 207, 56, 193, 223, 48, 213, 113, 125, 91, 22, 161, 154,
@@ -275,72 +342,72 @@ pub const BURN_PROOF: &[u8] = &[
     42, 225, 248, 35, 158, 146, 60, 248, 107, 197, 254, 254, 177, 57, 100, 10, 205, 48, 187, 215,
     112, 242, 228, 234, 204, 17, 53, 150, 255, 27, 117, 15, 67, 190, 13, 114, 222, 53, 251, 6, 117,
     26, 81, 83, 114, 10, 208, 11, 64, 221, 61, 149, 215, 98, 7, 125, 31, 20, 255, 106, 25, 25, 176,
-    96, 40, 4, 3, 166, 195, 136, 186, 90, 30, 89, 171, 115, 173, 84, 194, 7, 160, 2, 118, 96, 164,
-    15, 209, 54, 87, 119, 33, 17, 127, 125, 123, 244, 67, 197, 62, 212, 152, 140, 221, 136, 30,
-    205, 139, 108, 241, 58, 72, 149, 158, 112, 34, 201, 147, 127, 39, 246, 50, 187, 57, 66, 144,
-    218, 194, 147, 49, 116, 125, 34, 24, 201, 76, 110, 120, 8, 28, 151, 126, 238, 171, 255, 93, 13,
-    96, 64, 90, 84, 108, 29, 85, 93, 82, 216, 230, 251, 46, 72, 91, 140, 151, 252, 239, 23, 89, 87,
-    2, 91, 185, 158, 101, 230, 89, 52, 195, 5, 170, 220, 63, 78, 96, 178, 73, 37, 200, 156, 46, 87,
-    91, 126, 68, 147, 216, 118, 226, 97, 217, 127, 94, 20, 97, 213, 136, 187, 85, 86, 230, 56, 93,
-    53, 144, 122, 240, 223, 33, 178, 255, 192, 119, 183, 137, 182, 30, 48, 142, 137, 1, 19, 243,
-    16, 224, 194, 61, 170, 29, 253, 58, 91, 231, 15, 255, 163, 55, 227, 238, 19, 177, 34, 231, 251,
-    13, 102, 31, 49, 225, 252, 31, 113, 248, 80, 94, 108, 130, 137, 207, 110, 18, 235, 99, 109, 18,
-    13, 175, 63, 71, 54, 148, 119, 35, 89, 246, 89, 251, 236, 73, 148, 176, 189, 21, 74, 202, 53,
-    248, 18, 33, 224, 166, 162, 44, 79, 225, 155, 148, 0, 138, 89, 43, 228, 143, 198, 94, 48, 245,
-    167, 151, 94, 212, 77, 115, 39, 26, 7, 106, 136, 77, 212, 229, 49, 175, 217, 227, 100, 163,
-    242, 216, 48, 78, 169, 30, 128, 112, 222, 28, 18, 0, 116, 117, 9, 105, 113, 69, 207, 140, 193,
-    26, 11, 114, 207, 234, 73, 97, 202, 238, 41, 154, 57, 15, 89, 182, 102, 64, 224, 238, 68, 233,
-    102, 189, 180, 127, 238, 189, 68, 78, 216, 200, 237, 123, 25, 112, 59, 205, 178, 105, 2, 211,
-    58, 112, 198, 85, 19, 120, 178, 180, 77, 185, 230, 170, 254, 41, 159, 15, 74, 145, 217, 67,
-    225, 60, 237, 190, 119, 85, 102, 234, 2, 119, 113, 81, 20, 193, 169, 210, 63, 28, 202, 81, 162,
-    140, 26, 203, 30, 158, 70, 139, 172, 119, 24, 135, 214, 242, 110, 196, 110, 8, 190, 203, 225,
-    163, 191, 19, 199, 215, 188, 115, 1, 228, 217, 50, 137, 183, 153, 120, 105, 111, 177, 250, 255,
-    188, 159, 60, 37, 107, 25, 37, 131, 44, 202, 178, 64, 96, 100, 89, 227, 195, 37, 221, 92, 34,
-    251, 220, 176, 116, 85, 149, 203, 24, 239, 83, 176, 192, 75, 18, 131, 141, 142, 67, 97, 137,
-    123, 173, 239, 239, 138, 79, 177, 66, 25, 254, 107, 14, 67, 204, 122, 16, 137, 227, 12, 135,
-    204, 69, 85, 102, 202, 69, 87, 59, 178, 182, 81, 69, 109, 187, 105, 21, 146, 118, 146, 147, 11,
-    74, 86, 58, 223, 32, 162, 16, 139, 135, 89, 88, 83, 208, 223, 53, 145, 38, 43, 236, 138, 208,
-    81, 74, 165, 9, 38, 34, 158, 199, 13, 102, 235, 233, 80, 106, 168, 77, 7, 137, 132, 112, 104,
-    19, 162, 81, 163, 19, 251, 226, 53, 162, 174, 184, 177, 20, 12, 29, 149, 188, 191, 5, 203, 191,
-    29, 145, 119, 192, 210, 212, 186, 197, 54, 229, 247, 182, 32, 81, 87, 187, 217, 56, 125, 23,
-    86, 66, 151, 91, 94, 133, 43, 201, 202, 20, 61, 143, 248, 109, 4, 8, 13, 86, 77, 132, 125, 16,
-    26, 101, 147, 224, 11, 238, 152, 128, 171, 214, 8, 114, 201, 52, 249, 146, 228, 96, 7, 145, 55,
-    136, 160, 122, 103, 36, 71, 112, 19, 234, 157, 23, 87, 202, 54, 105, 87, 33, 20, 63, 248, 212,
-    69, 23, 172, 147, 86, 191, 15, 190, 173, 114, 246, 151, 7, 193, 8, 16, 151, 243, 116, 225, 116,
-    101, 140, 164, 215, 130, 255, 4, 252, 157, 9, 245, 66, 233, 30, 72, 89, 131, 22, 232, 73, 244,
-    219, 99, 196, 254, 0, 160, 2, 254, 207, 165, 146, 115, 31, 218, 226, 13, 172, 54, 51, 201, 242,
-    18, 159, 179, 83, 118, 147, 137, 157, 240, 84, 124, 222, 17, 77, 61, 227, 102, 127, 214, 195,
-    75, 231, 94, 209, 114, 115, 169, 126, 69, 42, 195, 230, 101, 168, 22, 121, 130, 50, 82, 166,
-    149, 65, 28, 175, 210, 134, 106, 79, 0, 63, 208, 221, 138, 56, 58, 81, 177, 249, 226, 148, 219,
-    251, 223, 203, 237, 95, 239, 76, 80, 53, 150, 94, 182, 233, 48, 80, 199, 181, 0, 237, 161, 26,
-    8, 214, 88, 253, 30, 211, 142, 209, 94, 204, 42, 225, 200, 192, 19, 22, 232, 190, 69, 64, 53,
-    146, 254, 7, 73, 232, 76, 220, 19, 147, 18, 105, 157, 80, 43, 214, 91, 146, 148, 246, 117, 93,
-    29, 231, 100, 109, 180, 8, 28, 5, 187, 17, 104, 206, 206, 72, 214, 251, 131, 1, 7, 131, 34, 11,
-    70, 120, 49, 126, 152, 29, 183, 255, 179, 155, 66, 218, 145, 99, 171, 148, 252, 40, 230, 111,
-    95, 45, 47, 174, 110, 220, 197, 55, 116, 178, 53, 7, 215, 43, 57, 250, 133, 191, 243, 54, 229,
-    79, 110, 255, 130, 152, 145, 157, 94, 84, 234, 252, 109, 62, 16, 64, 51, 222, 64, 162, 52, 216,
-    252, 15, 166, 33, 172, 240, 182, 90, 173, 162, 100, 145, 26, 100, 118, 199, 74, 79, 125, 42,
-    90, 204, 191, 174, 198, 248, 118, 181, 153, 164, 197, 2, 133, 13, 254, 98, 83, 50, 172, 33, 42,
-    144, 190, 45, 150, 167, 18, 48, 185, 4, 240, 34, 186, 229, 234, 131, 84, 234, 248, 49, 221,
-    162, 100, 0, 242, 122, 230, 33, 67, 168, 202, 12, 8, 127, 141, 43, 189, 118, 114, 66, 180, 201,
-    86, 253, 205, 44, 54, 116, 206, 137, 220, 107, 191, 168, 101, 251, 148, 110, 36, 51, 25, 134,
-    26, 187, 9, 223, 17, 12, 217, 155, 171, 141, 234, 118, 78, 234, 96, 164, 25, 152, 124, 60, 124,
-    115, 55, 232, 35, 19, 151, 28, 166, 62, 139, 63, 173, 212, 127, 185, 156, 64, 231, 52, 94, 137,
-    39, 86, 179, 29, 212, 227, 179, 2, 218, 54, 232, 88, 133, 20, 68, 154, 44, 59, 166, 24, 11, 91,
-    95, 247, 124, 134, 181, 112, 229, 142, 124, 87, 84, 247, 118, 11, 85, 188, 170, 0, 24, 5, 89,
-    190, 154, 68, 10, 112, 199, 98, 88, 46, 97, 63, 69, 26, 240, 122, 90, 21, 193, 7, 166, 194, 61,
-    19, 114, 30, 254, 70, 249, 169, 75, 194, 180, 66, 238, 94, 250, 96, 120, 123, 46, 227, 44, 94,
-    106, 251, 170, 170, 223, 58, 177, 190, 96, 100, 4, 119, 146, 249, 160, 177, 145, 72, 180, 253,
-    107, 120, 225, 205, 79, 248, 111, 77, 152, 221, 172, 51, 39, 249, 180, 206, 201, 182, 180, 239,
-    211, 97, 35, 136, 98, 171, 21, 32, 48, 201, 181, 168, 63, 175, 59, 110, 221, 119, 233, 89, 64,
-    31, 88, 16, 125, 126, 232, 169, 81, 124, 54, 68, 180, 76, 77, 152, 205, 51, 113, 34, 138, 43,
-    174, 197, 150, 104, 255, 104, 179, 241, 190, 23, 64, 177, 159, 235, 187, 245, 95, 61, 234, 23,
-    68, 255, 133, 162, 99, 165, 26, 215, 142, 157, 54, 208, 231, 120, 167, 100, 200, 178, 31, 183,
-    193, 122, 44, 71, 122, 0, 149, 115, 218, 249, 148, 35, 77, 26, 106, 43, 53, 75, 138, 8, 70, 95,
-    207, 56, 26, 242, 222, 2, 205, 66, 53, 209, 117, 51, 145, 109, 117, 177, 163, 46, 237, 15, 82,
-    69, 190, 161, 200, 70, 183, 25, 215, 2, 67, 102, 235, 95, 103, 235, 61, 124, 159, 157, 76, 216,
-    79, 14, 96, 15, 193, 22, 116, 51, 38, 150, 205, 13, 25, 244, 101, 45, 123, 192, 37, 53, 137,
-    233, 99, 113, 144, 170, 19, 26, 159, 178, 60, 49, 246, 3, 120, 0, 0, 0, 0, 0, 0, 0,
+    96, 40, 4, 3, 166, 195, 136, 186, 90, 30, 89, 171, 115, 173, 84, 194, 7, 160, 2, 90, 191, 100,
+    18, 99, 172, 28, 48, 61, 71, 156, 237, 91, 19, 195, 15, 241, 227, 243, 4, 31, 142, 252, 101,
+    106, 43, 197, 94, 245, 64, 136, 29, 220, 54, 151, 243, 93, 244, 254, 25, 85, 232, 163, 20, 98,
+    237, 194, 39, 76, 71, 16, 33, 84, 197, 86, 218, 145, 138, 199, 153, 119, 162, 103, 96, 244,
+    245, 12, 117, 47, 205, 193, 63, 151, 39, 85, 165, 157, 187, 185, 58, 217, 244, 79, 150, 99, 37,
+    170, 70, 90, 172, 169, 174, 183, 153, 246, 33, 36, 179, 131, 53, 213, 105, 109, 249, 3, 171,
+    108, 143, 127, 192, 240, 140, 154, 210, 7, 247, 254, 13, 177, 90, 136, 35, 179, 83, 45, 85,
+    192, 79, 25, 190, 71, 168, 174, 36, 193, 59, 220, 85, 77, 144, 149, 70, 39, 147, 71, 40, 95,
+    85, 206, 108, 252, 235, 12, 54, 246, 221, 121, 53, 124, 9, 113, 150, 176, 197, 168, 121, 17,
+    249, 140, 83, 254, 221, 250, 194, 127, 140, 69, 47, 197, 202, 247, 74, 230, 216, 228, 207, 83,
+    191, 56, 34, 230, 14, 236, 100, 57, 23, 68, 93, 33, 246, 126, 177, 147, 217, 14, 130, 190, 210,
+    210, 199, 13, 160, 37, 136, 128, 16, 80, 50, 7, 114, 39, 133, 202, 15, 160, 198, 129, 178, 177,
+    121, 57, 172, 0, 244, 118, 138, 255, 47, 158, 201, 26, 39, 234, 232, 115, 236, 11, 110, 110,
+    18, 98, 35, 62, 233, 173, 8, 126, 180, 24, 98, 99, 132, 163, 147, 120, 72, 31, 27, 96, 98, 36,
+    232, 155, 115, 112, 61, 117, 174, 63, 194, 41, 91, 207, 175, 234, 178, 192, 20, 42, 249, 25,
+    29, 45, 99, 250, 5, 175, 94, 214, 59, 190, 224, 214, 204, 149, 188, 124, 222, 70, 205, 157, 25,
+    85, 15, 155, 159, 87, 201, 93, 78, 190, 172, 53, 76, 87, 161, 109, 240, 48, 36, 120, 151, 173,
+    136, 73, 92, 204, 174, 7, 245, 226, 109, 116, 124, 26, 103, 227, 181, 71, 118, 191, 84, 24,
+    245, 247, 142, 10, 169, 105, 246, 140, 54, 152, 247, 251, 14, 184, 200, 75, 57, 185, 62, 215,
+    102, 224, 4, 49, 52, 79, 165, 67, 203, 21, 104, 114, 204, 243, 232, 108, 223, 171, 222, 123,
+    155, 23, 205, 111, 128, 23, 7, 9, 78, 26, 199, 64, 59, 166, 153, 51, 130, 92, 131, 103, 69,
+    104, 57, 96, 237, 45, 87, 249, 131, 125, 117, 32, 206, 196, 243, 205, 201, 12, 229, 20, 125,
+    222, 95, 189, 175, 122, 189, 111, 118, 13, 251, 88, 203, 7, 98, 218, 251, 171, 13, 3, 9, 101,
+    238, 128, 143, 232, 163, 79, 119, 236, 221, 237, 107, 208, 114, 92, 217, 132, 64, 200, 61, 213,
+    220, 140, 85, 125, 78, 106, 96, 91, 102, 134, 81, 58, 37, 50, 163, 46, 185, 230, 150, 88, 95,
+    238, 8, 192, 198, 35, 95, 196, 63, 137, 37, 215, 10, 39, 252, 22, 23, 34, 83, 16, 101, 175, 89,
+    66, 223, 200, 214, 254, 167, 252, 194, 78, 28, 163, 133, 144, 136, 162, 63, 161, 72, 140, 45,
+    113, 100, 23, 143, 111, 20, 28, 146, 178, 142, 142, 9, 57, 241, 182, 207, 63, 150, 147, 183,
+    111, 45, 223, 22, 171, 68, 145, 13, 243, 232, 30, 80, 9, 229, 207, 68, 253, 4, 64, 86, 118, 22,
+    213, 26, 166, 178, 27, 249, 82, 173, 80, 17, 140, 57, 33, 176, 9, 238, 88, 166, 134, 119, 240,
+    63, 212, 232, 242, 24, 229, 68, 66, 168, 10, 152, 96, 56, 73, 1, 168, 147, 25, 145, 86, 121,
+    218, 4, 129, 132, 62, 224, 168, 27, 168, 229, 65, 46, 92, 155, 0, 131, 103, 8, 245, 130, 184,
+    105, 210, 240, 11, 244, 8, 214, 28, 64, 237, 4, 159, 115, 44, 37, 156, 186, 68, 139, 234, 97,
+    177, 204, 239, 148, 55, 241, 67, 11, 160, 2, 90, 103, 146, 44, 229, 86, 50, 62, 45, 151, 4,
+    255, 163, 206, 95, 86, 147, 9, 198, 155, 22, 208, 141, 167, 93, 166, 193, 118, 248, 2, 92, 25,
+    192, 29, 2, 205, 67, 5, 120, 141, 38, 192, 180, 194, 95, 214, 49, 203, 54, 249, 128, 101, 15,
+    130, 3, 202, 145, 206, 188, 215, 178, 82, 75, 87, 34, 26, 21, 16, 102, 91, 78, 61, 242, 209,
+    126, 244, 223, 82, 33, 72, 163, 26, 190, 111, 182, 118, 231, 180, 118, 131, 88, 204, 148, 179,
+    85, 74, 4, 163, 240, 196, 32, 121, 91, 150, 106, 82, 223, 86, 206, 109, 78, 162, 58, 181, 172,
+    48, 176, 78, 121, 72, 13, 172, 224, 215, 136, 77, 235, 36, 85, 61, 107, 107, 50, 166, 216, 0,
+    101, 157, 165, 11, 46, 104, 68, 241, 3, 45, 166, 141, 104, 41, 253, 10, 42, 233, 1, 200, 2, 59,
+    91, 7, 134, 204, 171, 118, 45, 145, 220, 9, 8, 59, 81, 10, 142, 140, 212, 87, 143, 77, 67, 38,
+    157, 85, 85, 201, 116, 195, 65, 5, 58, 0, 69, 3, 139, 3, 133, 48, 224, 162, 129, 200, 147, 5,
+    12, 80, 176, 93, 160, 219, 239, 150, 176, 56, 109, 206, 182, 191, 102, 191, 134, 159, 174, 147,
+    47, 6, 84, 142, 68, 168, 209, 214, 145, 202, 132, 104, 3, 214, 221, 231, 70, 134, 190, 222,
+    226, 27, 69, 37, 190, 223, 69, 49, 238, 125, 103, 76, 38, 124, 250, 185, 182, 77, 80, 113, 62,
+    240, 233, 217, 43, 5, 15, 113, 63, 67, 230, 136, 36, 70, 50, 251, 133, 242, 58, 120, 78, 105,
+    17, 245, 194, 78, 142, 234, 27, 49, 120, 130, 236, 82, 46, 176, 178, 114, 39, 233, 25, 78, 234,
+    96, 227, 30, 55, 5, 56, 60, 60, 95, 54, 44, 71, 26, 100, 118, 170, 101, 226, 7, 40, 217, 206,
+    14, 127, 159, 240, 218, 197, 184, 244, 69, 229, 229, 148, 26, 113, 90, 52, 253, 124, 228, 253,
+    202, 227, 147, 148, 18, 150, 89, 10, 145, 164, 95, 109, 98, 113, 100, 143, 69, 148, 79, 14, 93,
+    33, 139, 225, 54, 54, 90, 236, 178, 159, 78, 16, 87, 34, 95, 248, 37, 22, 106, 157, 52, 32,
+    148, 3, 97, 29, 188, 131, 139, 85, 110, 177, 227, 206, 217, 253, 76, 215, 191, 16, 35, 151,
+    244, 142, 64, 159, 128, 31, 52, 110, 204, 69, 110, 115, 79, 136, 85, 153, 1, 54, 87, 122, 43,
+    43, 253, 36, 99, 139, 247, 207, 25, 190, 12, 114, 109, 58, 81, 53, 4, 108, 29, 34, 148, 13,
+    140, 98, 233, 88, 29, 245, 82, 196, 64, 33, 66, 168, 11, 59, 64, 65, 49, 30, 71, 90, 193, 77,
+    240, 204, 20, 115, 234, 140, 118, 36, 176, 218, 250, 205, 146, 194, 78, 218, 169, 43, 5, 82,
+    85, 9, 190, 148, 147, 87, 233, 54, 163, 23, 150, 2, 77, 77, 252, 77, 8, 98, 101, 36, 115, 73,
+    92, 22, 98, 173, 250, 32, 236, 229, 72, 192, 165, 141, 106, 166, 54, 41, 71, 27, 246, 95, 178,
+    95, 31, 206, 116, 39, 207, 189, 124, 222, 35, 115, 77, 124, 213, 184, 241, 59, 223, 185, 101,
+    95, 27, 238, 215, 124, 62, 2, 93, 136, 163, 208, 147, 76, 214, 54, 238, 95, 212, 18, 40, 28,
+    49, 248, 121, 212, 29, 172, 11, 210, 170, 34, 202, 134, 98, 55, 205, 74, 98, 221, 255, 128,
+    171, 106, 193, 104, 190, 61, 254, 176, 145, 101, 59, 36, 189, 252, 8, 238, 10, 209, 135, 140,
+    104, 127, 88, 78, 233, 6, 110, 137, 38, 243, 233, 81, 238, 150, 29, 90, 192, 158, 234, 249,
+    218, 124, 3, 54, 153, 206, 109, 9, 173, 253, 116, 225, 63, 203, 160, 119, 187, 221, 204, 71,
+    95, 91, 193, 47, 81, 71, 48, 116, 164, 11, 176, 49, 124, 182, 15, 120, 0, 0, 0, 0, 0, 0, 0,
 ];
 pub const BURN_FROM_OLD_COMM_32: [u8; 32] = [
     148, 107, 226, 45, 97, 82, 34, 5, 193, 214, 229, 164, 198, 142, 101, 231, 7, 12, 177, 43, 148,
@@ -359,9 +426,350 @@ pub const BURN_TOTAL_NEW_COMM_32: [u8; 32] = [
     11, 234, 147, 228, 242, 50, 15, 1, 36, 64,
 ];
 
+// ----- Claim of 1 pending UTXO(s), ids 0..1 -----
+pub const CLAIM_1_UTXO_COMMS: &[[u8; 32]] = &[[
+    174, 188, 153, 202, 235, 74, 41, 122, 18, 111, 64, 249, 122, 30, 111, 15, 44, 26, 217, 4, 215,
+    229, 31, 73, 23, 59, 194, 109, 171, 217, 107, 124,
+]];
+pub const CLAIM_1_PENDING_OLD_COMM_32: [u8; 32] = [
+    174, 188, 153, 202, 235, 74, 41, 122, 18, 111, 64, 249, 122, 30, 111, 15, 44, 26, 217, 4, 215,
+    229, 31, 73, 23, 59, 194, 109, 171, 217, 107, 124,
+];
+pub const CLAIM_1: &[u8] = &[
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 188, 153, 202, 235, 74, 41, 122, 18, 111, 64, 249, 122, 30,
+    111, 15, 44, 26, 217, 4, 215, 229, 31, 73, 23, 59, 194, 109, 171, 217, 107, 124, 160, 2, 84,
+    209, 126, 102, 98, 55, 146, 0, 92, 211, 148, 12, 163, 108, 16, 23, 60, 166, 218, 88, 133, 84,
+    239, 17, 254, 159, 50, 2, 212, 17, 100, 21, 96, 96, 186, 61, 138, 188, 200, 227, 12, 96, 56,
+    119, 254, 217, 210, 247, 17, 158, 250, 67, 157, 57, 223, 65, 131, 59, 107, 25, 254, 247, 19,
+    89, 20, 25, 84, 187, 83, 111, 217, 162, 123, 28, 156, 103, 117, 254, 235, 251, 221, 125, 175,
+    190, 130, 76, 204, 224, 101, 205, 181, 21, 47, 130, 92, 78, 194, 71, 250, 230, 195, 219, 110,
+    187, 87, 4, 122, 231, 86, 28, 85, 125, 160, 227, 186, 41, 3, 34, 41, 21, 66, 100, 122, 42, 111,
+    87, 52, 58, 22, 34, 190, 130, 11, 103, 182, 51, 109, 82, 67, 18, 57, 221, 133, 9, 45, 162, 235,
+    29, 14, 144, 214, 193, 74, 52, 170, 66, 21, 191, 19, 15, 251, 102, 14, 236, 213, 41, 117, 118,
+    165, 184, 141, 153, 207, 205, 169, 101, 134, 191, 4, 106, 241, 146, 20, 192, 132, 224, 10, 134,
+    39, 71, 215, 6, 253, 222, 249, 13, 121, 139, 21, 66, 147, 63, 124, 123, 250, 119, 72, 205, 199,
+    20, 242, 65, 222, 237, 106, 1, 83, 161, 112, 136, 139, 158, 249, 10, 44, 49, 175, 219, 176,
+    107, 174, 51, 157, 163, 90, 60, 204, 186, 10, 47, 89, 233, 130, 53, 190, 39, 167, 236, 10, 226,
+    155, 149, 22, 202, 123, 30, 120, 97, 250, 143, 74, 30, 36, 220, 209, 75, 29, 246, 156, 163,
+    105, 40, 111, 24, 152, 72, 138, 2, 189, 55, 132, 6, 25, 6, 210, 179, 125, 90, 162, 170, 215,
+    37, 55, 83, 10, 222, 176, 205, 44, 211, 144, 222, 236, 51, 27, 241, 204, 239, 49, 120, 144,
+    144, 220, 62, 139, 34, 62, 83, 79, 31, 6, 225, 232, 171, 2, 215, 212, 65, 50, 220, 17, 246, 74,
+    236, 200, 26, 168, 23, 230, 137, 106, 241, 66, 134, 198, 195, 238, 45, 117, 14, 23, 7, 2, 123,
+    29, 3, 220, 21, 184, 162, 134, 96, 255, 162, 128, 60, 11, 247, 215, 39, 58, 128, 235, 93, 22,
+    65, 108, 210, 179, 46, 7, 110, 14, 102, 228, 78, 24, 215, 144, 254, 215, 246, 220, 159, 20,
+    160, 124, 167, 20, 131, 46, 166, 121, 107, 58, 88, 79, 197, 6, 118, 220, 146, 19, 231, 61, 89,
+    150, 92, 128, 77, 223, 80, 248, 73, 208, 225, 253, 231, 119, 201, 247, 131, 175, 69, 127, 207,
+    204, 211, 48, 74, 65, 126, 106, 84, 134, 226, 196, 61, 88, 187, 179, 28, 143, 235, 187, 59,
+    226, 170, 177, 160, 57, 38, 36, 120, 25, 55, 140, 1, 35, 26, 196, 195, 130, 18, 138, 72, 6,
+    254, 222, 16, 60, 124, 113, 157, 163, 2, 84, 50, 197, 52, 249, 152, 47, 226, 0, 135, 123, 23,
+    91, 1, 156, 157, 202, 130, 150, 117, 146, 7, 20, 24, 90, 4, 204, 207, 113, 131, 3, 107, 17, 12,
+    191, 112, 93, 204, 2, 14, 203, 230, 237, 13, 100, 71, 125, 98, 253, 241, 215, 145, 137, 224,
+    34, 196, 50, 55, 174, 82, 146, 136, 17, 91, 71, 223, 242, 171, 221, 119, 74, 132, 102, 207,
+    146, 52, 151, 118, 45, 110, 180, 249, 172, 205, 209, 15, 29, 33, 73, 21, 144, 107, 203, 165,
+    178, 157, 13, 141, 6, 16, 33, 159, 214, 221, 3, 68, 75, 238, 226, 105, 61, 177, 53, 57, 63,
+    141, 35, 210, 90, 145, 245, 93, 103, 94, 231, 105, 248, 51, 232, 255, 5, 183, 39, 168, 210,
+    229, 14, 174, 160, 176, 196, 87, 126, 70, 40, 138, 160, 249, 108, 229, 245, 200, 133, 12, 251,
+    44, 6, 143, 229, 148, 166, 153, 253, 95, 224, 20, 41, 139, 247, 100, 10, 163, 124, 237, 165,
+    165, 203, 127, 5, 1, 194, 14, 241, 25, 14, 12, 160, 2, 104, 230, 189, 239, 105, 102, 23, 73,
+    238, 254, 136, 237, 200, 75, 49, 252, 49, 22, 76, 95, 112, 10, 195, 138, 234, 54, 93, 3, 155,
+    141, 46, 32, 174, 81, 150, 231, 126, 35, 66, 184, 184, 27, 178, 9, 252, 230, 62, 76, 49, 132,
+    222, 98, 92, 157, 210, 246, 55, 121, 171, 153, 171, 232, 75, 85, 220, 195, 185, 159, 18, 62,
+    39, 88, 189, 183, 29, 230, 64, 161, 121, 112, 18, 65, 159, 2, 4, 203, 231, 245, 124, 174, 255,
+    235, 27, 196, 217, 125, 212, 24, 178, 194, 6, 35, 8, 211, 7, 71, 24, 60, 209, 5, 83, 119, 173,
+    122, 73, 4, 67, 25, 90, 229, 135, 153, 149, 204, 183, 81, 178, 12, 186, 122, 233, 171, 40, 227,
+    30, 19, 205, 231, 137, 148, 130, 119, 235, 105, 45, 9, 66, 157, 103, 198, 192, 192, 233, 48,
+    211, 237, 165, 164, 29, 12, 186, 47, 20, 116, 155, 230, 195, 148, 118, 140, 69, 131, 166, 134,
+    113, 195, 3, 101, 119, 172, 9, 0, 114, 165, 163, 26, 145, 20, 13, 31, 147, 12, 155, 15, 154,
+    243, 83, 203, 203, 23, 117, 133, 62, 12, 56, 207, 53, 89, 193, 78, 154, 196, 252, 62, 72, 118,
+    15, 82, 15, 111, 165, 142, 37, 11, 24, 233, 99, 232, 207, 33, 25, 209, 225, 150, 3, 74, 69,
+    228, 24, 85, 231, 245, 167, 211, 238, 233, 117, 251, 82, 190, 143, 143, 219, 238, 137, 105,
+    112, 149, 89, 106, 254, 59, 70, 14, 205, 128, 219, 49, 252, 136, 200, 96, 4, 59, 235, 233, 116,
+    216, 98, 226, 218, 20, 174, 2, 247, 25, 171, 99, 204, 247, 216, 128, 139, 124, 165, 219, 223,
+    177, 221, 193, 137, 73, 151, 74, 46, 152, 219, 115, 56, 22, 87, 64, 23, 149, 110, 103, 222, 59,
+    0, 92, 98, 77, 142, 219, 110, 197, 242, 203, 134, 53, 66, 48, 62, 242, 138, 91, 202, 105, 197,
+    101, 17, 208, 118, 146, 207, 98, 245, 47, 201, 74, 18, 45, 118, 92, 175, 205, 156, 107, 133,
+    230, 158, 255, 238, 25, 29, 43, 159, 167, 163, 59, 39, 50, 23, 174, 64, 39, 74, 246, 70, 86,
+    119, 253, 176, 23, 178, 171, 61, 8, 96, 189, 221, 33, 27, 165, 57, 20, 34, 75, 219, 255, 239,
+    20, 36, 75, 78, 125, 244, 91, 136, 235, 76, 100, 138, 6, 212, 14, 214, 13, 29, 172, 246, 116,
+    71, 254, 160, 125, 253, 10, 130, 128, 93, 50, 43, 164, 50, 125, 249, 146, 4, 131, 111, 195,
+    252, 247, 116, 0, 24, 122, 26, 73, 132, 10, 213, 67, 55, 213, 5, 35, 22, 27, 255, 39, 78, 180,
+    76, 213, 32, 202, 200, 55, 53, 225, 127, 18, 98, 243, 82, 197, 192, 75, 114, 7, 254, 131, 93,
+    3, 57, 152, 176, 83, 149, 172, 51, 233, 150, 119, 121, 194, 192, 192, 195, 8, 232, 216, 101,
+    254, 240, 186, 42, 235, 28, 67, 16, 250, 176, 94, 250, 5, 42, 102, 125, 184, 92, 236, 201, 239,
+    59, 173, 230, 157, 79, 186, 237, 156, 62, 104, 52, 245, 56, 96, 77, 222, 25, 76, 96, 84, 138,
+    46, 222, 134, 252, 104, 221, 17, 178, 236, 160, 18, 56, 176, 134, 214, 186, 139, 64, 28, 41,
+    220, 53, 250, 41, 219, 155, 78, 14, 79, 138, 228, 230, 243, 240, 220, 173, 238, 22, 56, 53,
+    155, 71, 251, 129, 143, 168, 202, 232, 120, 165, 206, 111, 148, 44, 198, 196, 159, 250, 211,
+    52, 94, 79, 224, 151, 72, 86, 163, 245, 41, 19, 74, 238, 2, 15, 103, 2, 230, 26, 224, 61, 145,
+    141, 206, 238, 217, 119, 251, 71, 26, 191, 28, 65, 9, 10, 58, 117, 111, 80, 235, 229, 245, 85,
+    36, 226, 62, 74, 32, 198, 13, 187, 234, 224, 31, 232, 57, 44, 72, 251, 139, 144, 236, 63, 108,
+    234, 1,
+];
+pub const CLAIM_1_AVAIL_NEW_COMM_32: [u8; 32] = [
+    174, 188, 153, 202, 235, 74, 41, 122, 18, 111, 64, 249, 122, 30, 111, 15, 44, 26, 217, 4, 215,
+    229, 31, 73, 23, 59, 194, 109, 171, 217, 107, 124,
+];
+pub const CLAIM_1_PENDING_NEW_COMM_32: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+// ----- Claim of 4 pending UTXO(s), ids 0..4 -----
+pub const CLAIM_4_UTXO_COMMS: &[[u8; 32]] = &[
+    [
+        174, 188, 153, 202, 235, 74, 41, 122, 18, 111, 64, 249, 122, 30, 111, 15, 44, 26, 217, 4,
+        215, 229, 31, 73, 23, 59, 194, 109, 171, 217, 107, 124,
+    ],
+    [
+        82, 136, 89, 250, 215, 190, 236, 68, 154, 226, 85, 188, 204, 252, 18, 177, 215, 150, 165,
+        204, 169, 186, 131, 92, 62, 158, 226, 245, 251, 146, 241, 112,
+    ],
+    [
+        88, 126, 56, 234, 176, 197, 25, 77, 170, 118, 59, 53, 157, 30, 123, 80, 187, 13, 152, 167,
+        232, 220, 232, 242, 98, 17, 94, 25, 52, 5, 216, 87,
+    ],
+    [
+        208, 113, 100, 155, 155, 164, 169, 80, 49, 34, 230, 246, 66, 115, 138, 24, 189, 71, 116,
+        62, 240, 141, 228, 12, 1, 40, 172, 177, 190, 99, 208, 5,
+    ],
+];
+pub const CLAIM_4_PENDING_OLD_COMM_32: [u8; 32] = [
+    170, 182, 76, 78, 90, 139, 192, 4, 202, 1, 250, 142, 104, 167, 142, 183, 4, 200, 30, 77, 56,
+    105, 67, 113, 101, 28, 54, 221, 14, 178, 207, 93,
+];
+pub const CLAIM_4: &[u8] = &[
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    0, 0, 170, 182, 76, 78, 90, 139, 192, 4, 202, 1, 250, 142, 104, 167, 142, 183, 4, 200, 30, 77,
+    56, 105, 67, 113, 101, 28, 54, 221, 14, 178, 207, 93, 160, 2, 58, 249, 90, 136, 183, 219, 177,
+    76, 74, 5, 69, 139, 161, 203, 5, 54, 148, 114, 208, 75, 58, 125, 232, 89, 87, 198, 21, 231,
+    110, 0, 155, 1, 100, 71, 71, 84, 95, 219, 54, 134, 27, 79, 160, 216, 29, 82, 11, 144, 143, 45,
+    217, 58, 215, 243, 39, 40, 141, 202, 185, 38, 7, 46, 148, 67, 40, 70, 108, 155, 28, 166, 218,
+    239, 251, 116, 115, 115, 203, 154, 144, 155, 249, 7, 167, 38, 247, 8, 159, 138, 21, 229, 163,
+    48, 81, 54, 159, 88, 152, 118, 26, 56, 64, 99, 55, 174, 127, 129, 63, 170, 114, 106, 134, 116,
+    20, 171, 178, 148, 89, 119, 252, 196, 231, 32, 15, 213, 182, 82, 254, 38, 171, 201, 184, 133,
+    222, 44, 116, 9, 227, 149, 252, 59, 189, 67, 22, 129, 87, 226, 55, 104, 69, 23, 109, 216, 255,
+    188, 116, 163, 161, 203, 35, 5, 60, 82, 76, 144, 185, 215, 18, 245, 133, 57, 184, 87, 222, 69,
+    112, 176, 6, 236, 194, 85, 144, 42, 32, 198, 172, 176, 125, 178, 236, 53, 126, 10, 212, 98, 81,
+    240, 111, 102, 25, 152, 114, 223, 133, 15, 45, 8, 219, 225, 160, 81, 162, 251, 23, 30, 232,
+    202, 189, 126, 245, 244, 142, 61, 247, 1, 254, 230, 32, 51, 221, 110, 169, 23, 86, 186, 46,
+    228, 127, 144, 8, 10, 226, 147, 214, 164, 103, 0, 27, 161, 5, 68, 10, 240, 165, 242, 154, 85,
+    202, 124, 48, 42, 27, 143, 145, 100, 174, 18, 3, 218, 44, 161, 248, 26, 38, 136, 163, 162, 26,
+    22, 162, 178, 143, 216, 227, 75, 98, 119, 255, 56, 140, 6, 237, 174, 226, 188, 45, 88, 216,
+    176, 25, 103, 42, 112, 124, 164, 67, 91, 59, 116, 4, 84, 204, 231, 38, 47, 2, 3, 201, 245, 31,
+    98, 168, 124, 78, 182, 21, 48, 185, 25, 100, 193, 138, 161, 90, 71, 55, 110, 244, 216, 21, 222,
+    43, 66, 134, 251, 96, 122, 28, 214, 249, 248, 108, 95, 26, 248, 68, 135, 211, 159, 107, 148,
+    138, 24, 31, 131, 50, 142, 245, 242, 54, 26, 172, 149, 231, 142, 168, 182, 29, 7, 213, 213, 97,
+    244, 84, 89, 164, 195, 86, 222, 194, 245, 187, 34, 30, 193, 171, 60, 83, 141, 77, 147, 109, 97,
+    225, 155, 114, 152, 178, 211, 65, 23, 53, 94, 92, 234, 191, 29, 220, 7, 192, 232, 96, 234, 27,
+    95, 34, 110, 100, 215, 211, 78, 211, 83, 35, 131, 97, 83, 97, 182, 166, 84, 68, 14, 108, 91,
+    156, 91, 253, 113, 146, 217, 196, 139, 8, 68, 146, 84, 7, 173, 173, 241, 191, 9, 160, 44, 86,
+    75, 133, 78, 224, 127, 226, 23, 197, 253, 214, 176, 138, 62, 215, 113, 222, 161, 54, 227, 21,
+    82, 201, 221, 206, 208, 98, 159, 16, 81, 225, 60, 131, 211, 201, 48, 127, 68, 108, 163, 1, 78,
+    112, 238, 20, 231, 204, 8, 254, 18, 79, 247, 40, 222, 109, 87, 21, 26, 112, 85, 243, 231, 168,
+    120, 80, 30, 188, 79, 15, 121, 114, 207, 24, 161, 21, 188, 121, 208, 175, 4, 244, 63, 47, 239,
+    158, 255, 205, 67, 4, 3, 119, 30, 168, 172, 213, 254, 76, 219, 123, 75, 167, 33, 66, 207, 223,
+    224, 161, 23, 79, 235, 86, 61, 222, 16, 252, 146, 79, 111, 69, 125, 94, 29, 28, 235, 38, 161,
+    92, 183, 169, 87, 110, 101, 162, 33, 28, 232, 194, 249, 15, 237, 55, 241, 6, 6, 168, 221, 137,
+    112, 50, 179, 74, 234, 4, 113, 101, 226, 76, 219, 106, 204, 72, 121, 245, 168, 60, 64, 96, 127,
+    152, 228, 222, 216, 141, 228, 215, 1, 255, 226, 165, 234, 143, 26, 10, 184, 181, 149, 23, 204,
+    74, 222, 6, 102, 8, 109, 127, 126, 20, 87, 34, 35, 31, 182, 50, 179, 205, 243, 15, 9, 160, 2,
+    246, 181, 251, 51, 95, 204, 254, 226, 164, 142, 53, 201, 79, 108, 220, 119, 7, 111, 125, 219,
+    203, 56, 112, 53, 228, 176, 209, 242, 119, 96, 248, 31, 106, 13, 235, 202, 181, 55, 41, 121,
+    176, 111, 54, 1, 27, 114, 90, 62, 123, 52, 104, 172, 146, 240, 71, 42, 180, 164, 175, 251, 206,
+    148, 127, 62, 22, 69, 39, 238, 165, 28, 242, 211, 137, 62, 19, 207, 209, 137, 30, 2, 209, 134,
+    197, 66, 18, 148, 84, 163, 46, 96, 173, 19, 77, 53, 243, 48, 14, 134, 35, 175, 42, 127, 38,
+    199, 228, 243, 89, 139, 168, 113, 49, 89, 63, 17, 218, 217, 96, 139, 228, 47, 119, 104, 218,
+    162, 29, 94, 132, 41, 223, 180, 49, 2, 81, 239, 168, 50, 11, 49, 177, 224, 33, 113, 41, 39, 66,
+    7, 97, 209, 149, 167, 158, 248, 136, 34, 14, 165, 111, 143, 206, 7, 109, 220, 60, 69, 141, 72,
+    228, 160, 119, 153, 147, 195, 59, 225, 78, 60, 59, 218, 119, 79, 108, 5, 148, 50, 111, 68, 228,
+    218, 210, 233, 51, 8, 159, 204, 215, 249, 50, 0, 75, 96, 74, 89, 111, 88, 45, 223, 35, 233,
+    248, 255, 123, 114, 25, 158, 198, 81, 30, 216, 174, 205, 183, 29, 62, 1, 2, 247, 242, 57, 117,
+    145, 127, 124, 221, 90, 237, 146, 156, 85, 246, 24, 191, 93, 90, 189, 46, 121, 160, 115, 154,
+    13, 126, 191, 231, 147, 196, 37, 60, 113, 156, 62, 80, 212, 254, 232, 69, 146, 32, 212, 69, 69,
+    144, 137, 162, 65, 178, 31, 113, 135, 37, 102, 158, 180, 230, 239, 93, 4, 15, 49, 238, 240,
+    140, 223, 93, 64, 72, 156, 250, 235, 92, 243, 158, 206, 180, 75, 124, 83, 164, 139, 21, 48,
+    218, 39, 166, 99, 194, 98, 105, 36, 248, 119, 158, 255, 70, 77, 113, 191, 224, 48, 189, 50, 36,
+    253, 124, 98, 62, 249, 15, 247, 123, 159, 199, 138, 31, 245, 93, 45, 40, 185, 194, 251, 177,
+    63, 178, 10, 149, 79, 125, 186, 245, 200, 191, 196, 240, 17, 13, 41, 217, 197, 251, 193, 50,
+    113, 153, 0, 207, 100, 56, 220, 237, 210, 59, 15, 127, 82, 96, 125, 87, 216, 1, 142, 3, 188,
+    70, 55, 101, 147, 176, 177, 254, 15, 132, 226, 60, 189, 149, 143, 85, 169, 182, 220, 65, 233,
+    150, 111, 4, 23, 94, 12, 42, 29, 91, 100, 220, 124, 132, 212, 133, 211, 154, 24, 172, 99, 196,
+    74, 5, 153, 201, 131, 143, 103, 63, 31, 249, 173, 162, 248, 6, 45, 14, 168, 195, 65, 8, 246,
+    86, 172, 225, 189, 59, 65, 211, 252, 28, 88, 117, 192, 36, 212, 196, 204, 35, 19, 198, 166, 83,
+    130, 141, 238, 216, 72, 138, 217, 109, 238, 77, 117, 21, 118, 151, 126, 75, 30, 28, 252, 156,
+    90, 26, 198, 132, 149, 145, 50, 81, 166, 168, 239, 101, 197, 90, 241, 156, 41, 56, 79, 28, 172,
+    228, 12, 133, 110, 44, 28, 175, 243, 2, 164, 242, 28, 82, 13, 246, 45, 17, 179, 154, 237, 117,
+    52, 83, 163, 214, 73, 45, 79, 174, 70, 203, 196, 140, 95, 142, 13, 171, 53, 13, 71, 95, 99,
+    147, 74, 207, 103, 185, 160, 103, 56, 70, 13, 94, 97, 175, 109, 44, 149, 248, 54, 38, 23, 2,
+    225, 78, 192, 91, 170, 118, 22, 49, 228, 115, 30, 135, 196, 203, 215, 168, 174, 84, 25, 164,
+    129, 109, 155, 215, 182, 216, 195, 87, 15, 201, 184, 178, 17, 157, 239, 222, 205, 246, 1, 128,
+    146, 145, 176, 249, 205, 54, 24, 253, 249, 214, 62, 99, 253, 180, 20, 183, 136, 251, 204, 103,
+    15, 112, 144, 108, 243, 148, 42, 145, 79, 37, 14, 60, 220, 127, 96, 85, 143, 220, 87, 129, 135,
+    255, 29, 162, 249, 231, 224, 106, 188, 128, 201, 248, 11,
+];
+pub const CLAIM_4_AVAIL_NEW_COMM_32: [u8; 32] = [
+    170, 182, 76, 78, 90, 139, 192, 4, 202, 1, 250, 142, 104, 167, 142, 183, 4, 200, 30, 77, 56,
+    105, 67, 113, 101, 28, 54, 221, 14, 178, 207, 93,
+];
+pub const CLAIM_4_PENDING_NEW_COMM_32: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+// ----- Claim of 16 pending UTXO(s), ids 0..16 -----
+pub const CLAIM_16_UTXO_COMMS: &[[u8; 32]] = &[
+    [
+        174, 188, 153, 202, 235, 74, 41, 122, 18, 111, 64, 249, 122, 30, 111, 15, 44, 26, 217, 4,
+        215, 229, 31, 73, 23, 59, 194, 109, 171, 217, 107, 124,
+    ],
+    [
+        82, 136, 89, 250, 215, 190, 236, 68, 154, 226, 85, 188, 204, 252, 18, 177, 215, 150, 165,
+        204, 169, 186, 131, 92, 62, 158, 226, 245, 251, 146, 241, 112,
+    ],
+    [
+        88, 126, 56, 234, 176, 197, 25, 77, 170, 118, 59, 53, 157, 30, 123, 80, 187, 13, 152, 167,
+        232, 220, 232, 242, 98, 17, 94, 25, 52, 5, 216, 87,
+    ],
+    [
+        208, 113, 100, 155, 155, 164, 169, 80, 49, 34, 230, 246, 66, 115, 138, 24, 189, 71, 116,
+        62, 240, 141, 228, 12, 1, 40, 172, 177, 190, 99, 208, 5,
+    ],
+    [
+        236, 96, 163, 156, 160, 172, 95, 94, 125, 106, 230, 57, 239, 128, 0, 135, 182, 107, 124,
+        250, 156, 85, 73, 30, 154, 166, 125, 27, 35, 155, 250, 86,
+    ],
+    [
+        58, 161, 191, 220, 64, 171, 186, 41, 179, 130, 134, 8, 195, 18, 53, 83, 121, 72, 7, 180,
+        225, 14, 32, 47, 144, 65, 255, 76, 92, 89, 86, 7,
+    ],
+    [
+        132, 47, 145, 38, 240, 118, 161, 215, 47, 103, 118, 152, 88, 90, 30, 215, 180, 142, 57,
+        227, 204, 114, 158, 8, 26, 73, 187, 14, 240, 25, 204, 99,
+    ],
+    [
+        182, 139, 33, 207, 81, 144, 196, 209, 2, 196, 106, 245, 42, 218, 144, 80, 252, 183, 86,
+        236, 157, 224, 89, 203, 141, 107, 57, 8, 223, 117, 50, 73,
+    ],
+    [
+        142, 163, 74, 177, 75, 98, 195, 21, 174, 73, 219, 116, 253, 24, 32, 165, 185, 88, 129, 102,
+        52, 11, 12, 180, 185, 19, 235, 115, 29, 173, 162, 65,
+    ],
+    [
+        90, 148, 147, 5, 161, 248, 167, 176, 19, 235, 74, 248, 153, 157, 126, 209, 141, 238, 26,
+        188, 1, 4, 202, 46, 156, 240, 107, 184, 27, 33, 125, 104,
+    ],
+    [
+        30, 86, 131, 168, 4, 71, 93, 111, 20, 22, 25, 80, 225, 55, 55, 208, 182, 199, 212, 237,
+        121, 253, 62, 158, 161, 134, 28, 54, 83, 151, 129, 54,
+    ],
+    [
+        14, 181, 91, 182, 210, 24, 176, 236, 85, 248, 232, 249, 105, 159, 146, 158, 103, 92, 98,
+        105, 24, 140, 190, 232, 104, 143, 163, 107, 254, 50, 20, 90,
+    ],
+    [
+        100, 20, 4, 193, 26, 67, 7, 243, 224, 25, 222, 118, 167, 0, 4, 122, 57, 220, 164, 200, 188,
+        219, 195, 112, 10, 39, 13, 58, 135, 237, 184, 97,
+    ],
+    [
+        134, 196, 158, 31, 219, 255, 126, 11, 69, 52, 104, 107, 181, 47, 23, 149, 133, 185, 149,
+        125, 181, 29, 231, 231, 203, 2, 124, 159, 17, 50, 197, 36,
+    ],
+    [
+        10, 45, 73, 193, 139, 24, 142, 222, 175, 207, 171, 58, 199, 87, 231, 111, 223, 123, 126,
+        151, 97, 167, 151, 198, 194, 63, 187, 94, 218, 54, 28, 79,
+    ],
+    [
+        4, 26, 233, 177, 196, 76, 111, 156, 53, 236, 86, 177, 198, 71, 108, 195, 196, 228, 6, 149,
+        229, 178, 249, 42, 159, 205, 162, 142, 196, 208, 196, 112,
+    ],
+];
+pub const CLAIM_16_PENDING_OLD_COMM_32: [u8; 32] = [
+    116, 74, 39, 132, 28, 17, 16, 188, 196, 230, 188, 26, 81, 181, 156, 158, 140, 234, 45, 172, 76,
+    255, 77, 225, 228, 245, 6, 203, 65, 55, 150, 55,
+];
+pub const CLAIM_16: &[u8] = &[
+    16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
+    0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0,
+    0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 15, 0,
+    0, 0, 0, 0, 0, 0, 116, 74, 39, 132, 28, 17, 16, 188, 196, 230, 188, 26, 81, 181, 156, 158, 140,
+    234, 45, 172, 76, 255, 77, 225, 228, 245, 6, 203, 65, 55, 150, 55, 160, 2, 190, 204, 206, 246,
+    62, 26, 223, 117, 26, 182, 128, 69, 96, 4, 46, 158, 79, 104, 88, 178, 251, 30, 19, 64, 162, 12,
+    73, 1, 172, 66, 255, 27, 114, 177, 248, 59, 83, 219, 0, 179, 177, 230, 16, 153, 205, 161, 31,
+    4, 176, 31, 49, 254, 69, 18, 91, 180, 1, 131, 212, 95, 211, 234, 73, 115, 206, 25, 243, 196,
+    135, 105, 134, 12, 139, 37, 83, 112, 197, 71, 233, 229, 97, 187, 61, 21, 84, 145, 244, 40, 226,
+    214, 115, 237, 56, 165, 172, 102, 236, 18, 137, 48, 61, 43, 34, 56, 196, 76, 161, 196, 148,
+    203, 67, 215, 101, 195, 169, 216, 75, 216, 193, 253, 162, 176, 33, 40, 217, 178, 85, 44, 179,
+    23, 183, 231, 151, 151, 216, 68, 247, 173, 111, 120, 32, 12, 130, 146, 26, 125, 34, 203, 162,
+    69, 59, 110, 88, 168, 117, 65, 16, 163, 7, 15, 239, 158, 122, 224, 157, 202, 159, 134, 182,
+    239, 156, 185, 53, 16, 22, 142, 165, 11, 28, 36, 1, 85, 218, 207, 107, 189, 180, 26, 8, 68, 64,
+    4, 172, 227, 158, 74, 248, 121, 160, 131, 105, 247, 1, 80, 90, 35, 195, 49, 118, 122, 91, 84,
+    102, 154, 71, 205, 68, 48, 68, 166, 255, 116, 198, 15, 72, 112, 239, 47, 20, 249, 163, 242,
+    217, 25, 197, 60, 0, 194, 142, 124, 236, 247, 214, 78, 197, 56, 97, 121, 134, 199, 228, 117,
+    105, 105, 243, 1, 60, 183, 10, 249, 26, 157, 53, 232, 2, 95, 205, 80, 12, 204, 243, 218, 136,
+    17, 178, 248, 80, 27, 60, 161, 73, 1, 53, 113, 196, 174, 204, 121, 54, 21, 95, 7, 137, 111, 44,
+    114, 158, 7, 133, 115, 212, 235, 182, 2, 99, 46, 154, 139, 249, 193, 241, 74, 75, 52, 94, 182,
+    192, 66, 137, 114, 42, 93, 21, 217, 216, 215, 255, 35, 2, 116, 157, 110, 247, 33, 229, 29, 123,
+    48, 25, 160, 41, 227, 73, 86, 191, 173, 190, 26, 210, 128, 143, 68, 194, 90, 40, 175, 96, 190,
+    175, 112, 82, 110, 101, 247, 106, 231, 63, 201, 243, 68, 141, 255, 44, 67, 119, 157, 123, 66,
+    238, 197, 244, 61, 232, 107, 14, 4, 26, 107, 65, 151, 237, 72, 205, 187, 130, 128, 135, 173,
+    244, 34, 127, 148, 85, 199, 133, 246, 241, 223, 241, 208, 46, 127, 197, 79, 171, 82, 160, 208,
+    67, 198, 2, 103, 241, 91, 147, 230, 89, 49, 38, 158, 109, 68, 17, 159, 7, 127, 116, 246, 64,
+    228, 178, 126, 4, 175, 236, 150, 28, 8, 66, 176, 7, 44, 67, 118, 71, 180, 71, 43, 56, 252, 174,
+    117, 170, 29, 166, 116, 219, 104, 65, 124, 253, 199, 228, 203, 140, 225, 90, 234, 43, 93, 4,
+    161, 27, 163, 162, 187, 178, 156, 22, 22, 169, 42, 91, 17, 232, 56, 128, 108, 56, 111, 166, 75,
+    43, 84, 229, 84, 182, 103, 200, 169, 137, 96, 224, 72, 185, 34, 36, 70, 243, 215, 66, 15, 98,
+    123, 185, 135, 72, 78, 206, 220, 121, 242, 212, 80, 206, 142, 229, 185, 65, 13, 112, 174, 53,
+    34, 244, 213, 108, 103, 128, 144, 118, 66, 151, 157, 149, 227, 30, 171, 231, 130, 173, 250,
+    115, 201, 110, 175, 157, 185, 77, 69, 43, 27, 19, 23, 142, 124, 226, 26, 142, 238, 28, 2, 157,
+    178, 137, 86, 232, 29, 78, 63, 84, 158, 208, 208, 54, 94, 248, 4, 167, 96, 10, 63, 253, 103,
+    112, 65, 42, 49, 4, 117, 214, 155, 80, 112, 170, 18, 242, 160, 28, 51, 43, 148, 6, 244, 240,
+    96, 63, 69, 219, 137, 87, 89, 42, 134, 17, 141, 190, 198, 1, 12, 42, 54, 244, 139, 189, 86,
+    129, 202, 98, 138, 193, 127, 163, 116, 142, 12, 222, 184, 210, 53, 109, 208, 243, 62, 87, 23,
+    241, 0, 56, 68, 179, 0, 160, 2, 2, 172, 227, 49, 142, 18, 46, 54, 191, 106, 190, 229, 102, 179,
+    56, 67, 41, 114, 239, 169, 172, 139, 142, 217, 75, 99, 49, 124, 100, 229, 118, 105, 128, 184,
+    76, 44, 31, 49, 214, 140, 197, 139, 91, 191, 147, 237, 25, 217, 146, 70, 154, 163, 8, 13, 1,
+    203, 8, 234, 135, 177, 18, 146, 42, 10, 18, 196, 137, 208, 29, 149, 113, 136, 212, 160, 47,
+    138, 25, 191, 157, 28, 220, 199, 151, 195, 39, 146, 44, 78, 138, 87, 219, 77, 227, 114, 127,
+    91, 148, 247, 156, 89, 52, 203, 120, 206, 252, 116, 169, 28, 41, 216, 18, 91, 152, 219, 235,
+    71, 228, 74, 224, 173, 11, 70, 245, 179, 243, 112, 160, 64, 125, 34, 5, 100, 118, 160, 215,
+    173, 82, 166, 205, 104, 51, 30, 1, 228, 117, 104, 55, 117, 73, 187, 230, 157, 222, 57, 140,
+    253, 165, 59, 233, 4, 70, 20, 24, 28, 240, 226, 64, 246, 1, 142, 168, 236, 143, 87, 251, 144,
+    188, 54, 231, 181, 218, 148, 138, 20, 4, 247, 192, 108, 182, 185, 42, 5, 234, 221, 74, 248,
+    185, 109, 40, 253, 1, 93, 92, 72, 85, 39, 57, 187, 32, 67, 27, 90, 3, 230, 43, 93, 194, 67,
+    152, 81, 86, 82, 214, 9, 218, 119, 144, 156, 116, 43, 8, 144, 221, 91, 149, 198, 102, 126, 104,
+    166, 41, 61, 79, 254, 105, 34, 146, 134, 227, 146, 35, 57, 216, 160, 213, 32, 44, 95, 225, 36,
+    137, 39, 9, 43, 96, 110, 171, 14, 41, 179, 76, 125, 170, 35, 83, 199, 255, 177, 43, 253, 30,
+    33, 238, 43, 203, 189, 24, 5, 190, 36, 155, 171, 193, 210, 66, 123, 77, 105, 196, 188, 25, 128,
+    138, 121, 131, 232, 229, 112, 106, 31, 177, 114, 91, 127, 80, 81, 237, 169, 68, 89, 180, 138,
+    144, 220, 62, 82, 131, 185, 187, 244, 193, 201, 92, 254, 3, 181, 115, 209, 96, 186, 62, 111,
+    192, 149, 212, 229, 81, 210, 160, 91, 167, 23, 206, 10, 119, 22, 23, 128, 227, 158, 18, 91,
+    108, 171, 212, 150, 253, 208, 242, 87, 148, 211, 23, 72, 63, 14, 233, 89, 99, 21, 230, 117, 84,
+    68, 184, 125, 107, 218, 54, 178, 8, 20, 224, 149, 125, 0, 206, 197, 86, 44, 9, 255, 254, 217,
+    66, 49, 110, 185, 27, 231, 69, 99, 170, 31, 32, 6, 28, 153, 106, 165, 224, 230, 211, 99, 149,
+    47, 136, 237, 49, 147, 104, 150, 28, 229, 217, 132, 193, 143, 186, 146, 220, 153, 4, 232, 78,
+    59, 139, 22, 132, 81, 28, 144, 253, 165, 240, 107, 40, 160, 228, 219, 134, 136, 44, 63, 219,
+    60, 157, 197, 72, 195, 109, 130, 51, 117, 114, 249, 71, 175, 166, 113, 30, 217, 172, 218, 137,
+    136, 49, 104, 114, 6, 5, 205, 104, 169, 243, 190, 24, 188, 154, 115, 72, 23, 202, 213, 32, 131,
+    143, 233, 68, 221, 167, 104, 248, 217, 184, 234, 67, 145, 22, 172, 3, 167, 70, 253, 103, 75,
+    32, 22, 211, 96, 95, 32, 124, 38, 76, 112, 134, 111, 97, 67, 84, 126, 234, 5, 94, 150, 214,
+    241, 201, 11, 120, 254, 178, 146, 223, 132, 194, 164, 229, 25, 95, 174, 106, 42, 181, 153, 174,
+    246, 115, 52, 36, 114, 119, 240, 3, 66, 112, 4, 75, 33, 44, 22, 34, 106, 112, 105, 232, 9, 85,
+    126, 194, 159, 158, 50, 252, 244, 237, 226, 219, 99, 179, 88, 243, 148, 144, 137, 12, 2, 139,
+    227, 9, 231, 128, 68, 163, 144, 37, 126, 45, 101, 70, 37, 194, 179, 42, 109, 92, 89, 33, 122,
+    60, 173, 154, 217, 22, 188, 95, 200, 159, 8, 202, 32, 2, 163, 223, 155, 69, 140, 251, 169, 160,
+    27, 23, 242, 118, 11, 173, 173, 207, 117, 249, 13, 143, 195, 210, 159, 65, 118, 188, 191, 199,
+    12,
+];
+pub const CLAIM_16_AVAIL_NEW_COMM_32: [u8; 32] = [
+    116, 74, 39, 132, 28, 17, 16, 188, 196, 230, 188, 26, 81, 181, 156, 158, 140, 234, 45, 172, 76,
+    255, 77, 225, 228, 245, 6, 203, 65, 55, 150, 55,
+];
+pub const CLAIM_16_PENDING_NEW_COMM_32: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
 // ===== EDGE CASE VECTORS =====
 
-// ----- Large value mint (1 billion) -----
+// ----- Large value mint -----
 pub const LARGE_MINT_VALUE: u64 = 1000000000;
 pub const LARGE_MINT_PROOF: &[u8] = &[
     46, 178, 54, 144, 57, 145, 245, 23, 174, 90, 164, 138, 92, 208, 215, 140, 58, 160, 180, 19,
@@ -378,72 +786,72 @@ pub const LARGE_MINT_PROOF: &[u8] = &[
     211, 168, 57, 22, 19, 74, 133, 194, 47, 63, 151, 43, 222, 89, 134, 191, 230, 240, 56, 156, 51,
     192, 103, 151, 62, 125, 99, 137, 11, 76, 227, 54, 135, 25, 253, 151, 141, 219, 249, 179, 253,
     227, 147, 145, 204, 214, 149, 8, 85, 92, 202, 234, 175, 28, 35, 228, 54, 104, 111, 162, 12,
-    160, 2, 72, 170, 193, 216, 244, 49, 70, 7, 97, 30, 125, 210, 43, 11, 5, 112, 6, 31, 195, 118,
-    196, 94, 140, 133, 41, 82, 182, 216, 97, 162, 234, 20, 100, 112, 1, 205, 145, 194, 134, 170,
-    65, 81, 202, 224, 133, 104, 98, 60, 104, 223, 15, 229, 122, 208, 64, 184, 215, 192, 239, 241,
-    88, 144, 66, 24, 56, 157, 80, 119, 12, 213, 250, 251, 18, 211, 11, 98, 120, 160, 211, 32, 122,
-    15, 204, 199, 142, 212, 139, 107, 80, 20, 243, 56, 80, 74, 89, 76, 20, 52, 186, 157, 191, 182,
-    142, 227, 181, 195, 60, 108, 115, 192, 3, 11, 54, 16, 52, 178, 100, 132, 23, 252, 106, 204, 95,
-    72, 254, 42, 63, 101, 103, 111, 8, 205, 61, 75, 59, 247, 35, 7, 180, 179, 123, 95, 95, 141,
-    215, 176, 210, 77, 127, 191, 217, 190, 255, 123, 204, 117, 166, 238, 13, 0, 185, 30, 246, 64,
-    199, 228, 45, 43, 139, 241, 109, 220, 10, 174, 233, 240, 203, 254, 47, 70, 130, 241, 136, 96,
-    46, 131, 95, 155, 109, 209, 5, 8, 27, 25, 99, 174, 171, 57, 181, 98, 51, 228, 235, 44, 97, 142,
-    69, 108, 43, 114, 245, 43, 178, 168, 123, 129, 190, 142, 208, 26, 89, 143, 138, 13, 164, 89,
-    89, 120, 51, 151, 169, 29, 173, 154, 39, 111, 135, 119, 223, 61, 216, 122, 36, 22, 53, 94, 143,
-    94, 244, 188, 62, 39, 53, 62, 217, 13, 28, 180, 149, 134, 89, 197, 5, 143, 126, 77, 74, 180,
-    228, 21, 66, 1, 56, 122, 100, 78, 173, 205, 236, 226, 159, 141, 116, 25, 98, 244, 189, 40, 148,
-    174, 4, 154, 70, 8, 249, 10, 19, 233, 152, 55, 10, 210, 213, 166, 169, 21, 201, 31, 74, 72,
-    130, 212, 224, 216, 129, 13, 215, 139, 21, 16, 180, 99, 216, 47, 111, 95, 122, 5, 45, 42, 54,
-    2, 23, 61, 22, 125, 170, 236, 13, 181, 82, 244, 10, 208, 95, 205, 219, 64, 201, 131, 193, 110,
-    160, 161, 73, 32, 97, 177, 168, 175, 198, 214, 229, 69, 39, 0, 9, 108, 81, 186, 108, 59, 27,
-    192, 170, 139, 23, 192, 221, 157, 174, 186, 212, 122, 140, 191, 87, 208, 22, 135, 151, 87, 19,
-    178, 85, 200, 15, 171, 213, 241, 116, 96, 164, 32, 89, 38, 145, 121, 22, 125, 187, 182, 183,
-    210, 62, 45, 24, 175, 169, 250, 80, 22, 1, 83, 134, 141, 17, 46, 37, 229, 72, 193, 43, 22, 151,
-    68, 50, 120, 188, 158, 253, 47, 4, 246, 35, 197, 242, 119, 84, 136, 148, 69, 93, 227, 104, 68,
-    192, 14, 182, 193, 52, 228, 19, 198, 77, 105, 41, 71, 244, 103, 180, 61, 151, 87, 125, 175,
-    147, 211, 85, 77, 102, 50, 179, 4, 54, 72, 45, 241, 130, 92, 40, 71, 190, 41, 29, 192, 90, 229,
-    132, 251, 213, 117, 86, 217, 202, 211, 16, 110, 65, 130, 215, 81, 138, 67, 151, 97, 251, 50,
-    210, 245, 100, 104, 49, 241, 42, 175, 77, 78, 195, 83, 85, 24, 124, 93, 31, 210, 161, 164, 77,
-    59, 197, 54, 165, 10, 62, 219, 135, 159, 225, 9, 65, 166, 46, 51, 176, 135, 198, 47, 146, 30,
-    207, 62, 42, 135, 111, 99, 82, 56, 190, 230, 14, 64, 170, 251, 117, 3, 126, 234, 212, 37, 139,
-    171, 123, 251, 158, 237, 29, 111, 36, 131, 123, 168, 186, 120, 127, 34, 220, 127, 177, 145, 77,
-    53, 172, 203, 37, 212, 56, 89, 53, 208, 190, 43, 150, 120, 137, 118, 82, 115, 73, 42, 44, 86,
-    88, 74, 114, 229, 37, 190, 148, 175, 146, 219, 171, 138, 99, 81, 160, 136, 78, 10, 226, 13, 61,
-    240, 71, 143, 90, 181, 20, 125, 2, 131, 69, 216, 93, 50, 174, 174, 114, 108, 4, 129, 87, 202,
-    36, 152, 61, 250, 81, 71, 242, 9, 160, 2, 70, 33, 192, 151, 83, 119, 85, 187, 52, 141, 53, 103,
-    92, 64, 217, 77, 57, 83, 201, 106, 177, 44, 119, 187, 135, 41, 116, 131, 162, 189, 153, 82,
-    216, 243, 42, 221, 194, 69, 97, 204, 95, 250, 16, 109, 222, 142, 164, 185, 198, 249, 228, 143,
-    55, 97, 41, 160, 245, 44, 181, 111, 117, 215, 237, 107, 90, 33, 49, 81, 107, 167, 197, 20, 137,
-    50, 230, 22, 77, 166, 244, 144, 101, 124, 73, 177, 45, 25, 102, 187, 59, 111, 130, 212, 114, 5,
-    78, 0, 16, 1, 231, 83, 82, 64, 230, 216, 94, 242, 81, 169, 138, 115, 212, 219, 93, 132, 229,
-    125, 184, 20, 24, 136, 75, 234, 0, 92, 200, 50, 106, 28, 43, 190, 225, 155, 182, 95, 31, 245,
-    216, 181, 167, 189, 198, 81, 206, 255, 121, 121, 70, 92, 111, 110, 2, 230, 191, 77, 125, 116,
-    22, 226, 26, 4, 178, 132, 10, 234, 197, 23, 65, 15, 101, 0, 14, 79, 128, 62, 18, 123, 80, 243,
-    78, 201, 136, 125, 41, 106, 155, 239, 47, 205, 92, 107, 137, 9, 175, 65, 168, 232, 216, 243,
-    212, 254, 30, 164, 127, 252, 254, 84, 101, 240, 108, 200, 135, 252, 63, 248, 151, 152, 199, 61,
-    214, 69, 225, 229, 12, 13, 44, 130, 141, 233, 223, 1, 122, 245, 179, 101, 176, 64, 20, 126,
-    215, 200, 144, 24, 143, 226, 167, 199, 27, 28, 244, 69, 176, 26, 112, 125, 184, 93, 144, 162,
-    47, 56, 249, 135, 196, 72, 165, 75, 202, 202, 233, 135, 68, 239, 175, 150, 5, 110, 107, 111,
-    16, 138, 61, 165, 209, 165, 189, 126, 233, 106, 64, 27, 164, 82, 0, 173, 132, 27, 158, 164, 21,
-    222, 112, 0, 156, 38, 17, 110, 191, 210, 250, 93, 85, 185, 131, 67, 226, 17, 199, 115, 94, 7,
-    140, 78, 227, 217, 44, 178, 131, 133, 122, 194, 139, 108, 196, 150, 204, 31, 242, 20, 173, 119,
-    137, 49, 27, 73, 165, 59, 145, 123, 251, 212, 131, 102, 206, 73, 158, 225, 163, 187, 233, 121,
-    102, 147, 111, 191, 220, 171, 169, 21, 93, 99, 82, 195, 33, 15, 127, 60, 134, 179, 0, 100, 187,
-    251, 225, 112, 86, 92, 27, 217, 8, 244, 90, 191, 159, 150, 206, 11, 208, 167, 37, 90, 208, 225,
-    241, 12, 63, 187, 35, 185, 11, 207, 107, 238, 170, 11, 103, 23, 204, 101, 82, 35, 74, 49, 6,
-    130, 222, 30, 192, 15, 127, 40, 27, 214, 96, 248, 145, 205, 233, 100, 225, 232, 253, 29, 121,
-    242, 184, 22, 164, 0, 180, 222, 37, 141, 184, 255, 137, 129, 217, 102, 243, 235, 12, 254, 246,
-    202, 136, 125, 142, 80, 171, 133, 13, 152, 168, 143, 14, 34, 210, 250, 250, 58, 92, 103, 136,
-    110, 109, 99, 197, 47, 37, 122, 139, 252, 32, 21, 53, 39, 22, 54, 229, 51, 69, 64, 1, 5, 112,
-    166, 157, 224, 35, 123, 66, 8, 202, 175, 155, 162, 41, 14, 20, 30, 240, 20, 82, 76, 83, 208,
-    28, 219, 17, 249, 212, 144, 56, 45, 121, 2, 177, 192, 74, 206, 149, 55, 143, 88, 170, 59, 36,
-    37, 193, 89, 107, 33, 192, 117, 25, 77, 132, 103, 218, 238, 189, 146, 155, 110, 103, 127, 22,
-    43, 149, 230, 88, 198, 173, 253, 86, 58, 198, 204, 7, 10, 11, 52, 188, 242, 243, 186, 213, 163,
-    115, 52, 238, 140, 100, 103, 75, 201, 194, 152, 106, 15, 229, 35, 123, 214, 191, 59, 7, 113,
-    60, 52, 234, 77, 202, 92, 117, 107, 108, 154, 161, 108, 142, 92, 202, 156, 90, 246, 163, 206,
-    51, 252, 1, 122, 135, 189, 244, 221, 230, 86, 9, 5, 139, 8, 154, 189, 121, 110, 132, 114, 93,
-    82, 184, 46, 136, 29, 127, 219, 180, 55, 75, 71, 201, 83, 241, 53, 71, 202, 111, 117, 37, 151,
-    141, 0,
+    160, 2, 92, 225, 115, 109, 9, 112, 227, 115, 203, 77, 75, 195, 82, 2, 61, 1, 136, 14, 30, 17,
+    90, 163, 195, 96, 169, 149, 79, 76, 56, 18, 4, 121, 228, 52, 71, 73, 209, 226, 118, 28, 38,
+    198, 80, 228, 140, 204, 216, 21, 61, 187, 25, 56, 162, 234, 143, 7, 245, 96, 54, 65, 181, 208,
+    178, 76, 182, 106, 127, 111, 77, 240, 221, 3, 198, 162, 133, 168, 251, 31, 120, 140, 57, 112,
+    158, 60, 17, 205, 73, 198, 217, 83, 213, 81, 33, 41, 170, 103, 158, 93, 150, 163, 136, 231,
+    120, 42, 199, 109, 28, 8, 191, 12, 96, 9, 110, 20, 137, 93, 165, 197, 216, 214, 133, 51, 188,
+    205, 63, 179, 107, 47, 107, 221, 145, 233, 72, 129, 0, 157, 60, 15, 198, 36, 132, 107, 220, 38,
+    25, 181, 147, 186, 56, 11, 250, 117, 75, 140, 184, 187, 146, 125, 14, 6, 232, 57, 144, 196, 77,
+    205, 171, 132, 219, 208, 56, 6, 229, 27, 125, 223, 188, 150, 233, 223, 141, 28, 209, 127, 101,
+    79, 40, 221, 245, 140, 37, 4, 231, 28, 43, 25, 100, 197, 247, 25, 125, 41, 218, 252, 239, 26,
+    86, 94, 21, 69, 98, 70, 167, 236, 2, 137, 22, 216, 191, 110, 68, 213, 11, 15, 142, 204, 175,
+    233, 89, 164, 171, 255, 237, 162, 103, 131, 186, 57, 46, 109, 213, 43, 73, 229, 253, 202, 221,
+    186, 92, 244, 109, 125, 181, 80, 224, 12, 236, 161, 77, 152, 167, 96, 129, 174, 183, 212, 39,
+    54, 181, 218, 87, 192, 28, 219, 115, 43, 73, 81, 221, 147, 112, 181, 110, 23, 149, 152, 24, 42,
+    194, 202, 45, 35, 244, 3, 100, 70, 189, 145, 1, 99, 216, 78, 206, 47, 106, 228, 40, 26, 54, 5,
+    6, 114, 130, 142, 187, 109, 130, 9, 59, 45, 34, 104, 143, 196, 192, 159, 209, 154, 249, 118,
+    21, 130, 166, 210, 67, 32, 154, 128, 222, 61, 245, 73, 116, 80, 149, 224, 202, 179, 23, 228,
+    227, 8, 40, 239, 162, 174, 174, 169, 246, 147, 249, 12, 83, 18, 42, 119, 41, 132, 137, 168, 37,
+    56, 242, 85, 171, 91, 41, 115, 107, 83, 190, 253, 38, 60, 246, 225, 173, 111, 249, 246, 161,
+    255, 213, 115, 150, 202, 107, 75, 110, 122, 82, 14, 201, 187, 48, 94, 128, 98, 35, 124, 45,
+    126, 112, 184, 113, 13, 26, 82, 72, 112, 174, 9, 148, 159, 65, 221, 232, 141, 205, 152, 165,
+    88, 71, 42, 119, 138, 84, 158, 174, 185, 109, 238, 164, 59, 180, 3, 21, 73, 16, 110, 122, 78,
+    71, 54, 229, 13, 140, 121, 98, 93, 116, 209, 255, 150, 173, 176, 36, 96, 214, 244, 121, 252,
+    252, 4, 50, 184, 92, 21, 217, 101, 186, 97, 183, 105, 224, 97, 197, 52, 166, 207, 20, 140, 73,
+    185, 7, 198, 102, 117, 245, 68, 208, 238, 196, 129, 12, 143, 255, 208, 100, 119, 175, 17, 90,
+    48, 30, 170, 233, 131, 198, 133, 38, 5, 40, 217, 250, 150, 215, 230, 244, 131, 9, 18, 159, 41,
+    168, 140, 30, 94, 132, 122, 149, 250, 21, 16, 226, 130, 164, 138, 152, 99, 83, 87, 217, 229,
+    10, 142, 32, 189, 134, 3, 157, 218, 27, 7, 147, 221, 254, 146, 155, 87, 176, 32, 135, 49, 92,
+    27, 84, 243, 160, 225, 91, 119, 31, 168, 153, 227, 3, 166, 222, 109, 164, 156, 188, 9, 174, 23,
+    198, 81, 66, 216, 23, 84, 47, 133, 208, 168, 197, 101, 38, 43, 74, 221, 1, 48, 50, 243, 246,
+    180, 244, 56, 156, 214, 170, 33, 76, 196, 31, 105, 95, 12, 179, 124, 39, 70, 121, 210, 217,
+    195, 20, 7, 75, 45, 181, 40, 141, 237, 85, 216, 71, 116, 65, 254, 32, 242, 118, 52, 181, 218,
+    200, 52, 91, 110, 11, 0, 144, 254, 58, 172, 2, 136, 188, 15, 160, 2, 252, 2, 135, 146, 218,
+    251, 227, 161, 62, 101, 187, 207, 11, 180, 41, 128, 10, 205, 166, 119, 197, 12, 134, 150, 16,
+    135, 199, 126, 122, 69, 32, 70, 58, 129, 166, 84, 58, 98, 116, 163, 56, 148, 15, 32, 168, 73,
+    37, 229, 121, 80, 235, 177, 80, 231, 84, 65, 238, 22, 71, 246, 242, 82, 0, 28, 206, 163, 183,
+    180, 28, 243, 100, 135, 153, 109, 49, 188, 253, 8, 94, 190, 207, 246, 135, 125, 237, 23, 9,
+    207, 153, 104, 152, 98, 225, 113, 36, 32, 70, 3, 146, 74, 100, 242, 140, 53, 85, 70, 165, 162,
+    11, 255, 14, 196, 63, 57, 66, 207, 245, 62, 144, 83, 137, 110, 157, 169, 115, 188, 178, 98, 25,
+    113, 83, 13, 178, 248, 148, 67, 115, 138, 146, 180, 60, 142, 8, 29, 20, 207, 18, 81, 135, 187,
+    54, 156, 215, 181, 187, 133, 235, 130, 53, 3, 140, 62, 14, 18, 163, 26, 213, 113, 96, 75, 24,
+    38, 131, 156, 170, 43, 84, 120, 105, 11, 49, 93, 214, 10, 1, 29, 247, 203, 158, 93, 46, 7, 11,
+    179, 219, 168, 105, 136, 114, 199, 2, 35, 140, 248, 176, 9, 218, 120, 187, 37, 184, 134, 223,
+    235, 252, 126, 227, 136, 253, 121, 106, 96, 16, 9, 192, 14, 198, 158, 100, 26, 177, 70, 226,
+    153, 138, 204, 121, 87, 116, 21, 193, 167, 251, 111, 143, 1, 182, 219, 172, 119, 184, 254, 53,
+    46, 79, 48, 12, 54, 82, 0, 182, 152, 185, 247, 101, 78, 37, 249, 142, 221, 50, 71, 255, 89, 38,
+    82, 109, 208, 94, 230, 185, 28, 70, 15, 43, 249, 90, 8, 12, 65, 133, 167, 199, 133, 225, 86,
+    25, 46, 250, 10, 255, 93, 185, 65, 214, 87, 72, 118, 175, 131, 210, 52, 200, 144, 72, 89, 228,
+    223, 219, 125, 214, 191, 11, 96, 68, 195, 205, 195, 34, 181, 67, 204, 202, 129, 81, 165, 127,
+    202, 202, 184, 61, 54, 230, 203, 163, 102, 63, 0, 17, 44, 200, 73, 214, 173, 174, 191, 131, 6,
+    55, 154, 230, 206, 140, 226, 221, 50, 112, 11, 65, 206, 65, 100, 165, 120, 104, 17, 89, 216,
+    109, 159, 33, 146, 65, 37, 48, 205, 6, 236, 13, 61, 99, 179, 51, 189, 85, 28, 119, 124, 241,
+    163, 35, 133, 208, 21, 96, 181, 145, 14, 195, 93, 191, 211, 95, 216, 117, 97, 228, 28, 31, 114,
+    181, 183, 199, 178, 78, 219, 136, 136, 234, 175, 255, 132, 93, 220, 206, 223, 18, 171, 51, 173,
+    40, 10, 36, 178, 113, 77, 136, 41, 158, 47, 129, 237, 146, 144, 114, 145, 203, 107, 86, 113,
+    20, 80, 155, 60, 7, 157, 183, 30, 1, 154, 184, 217, 100, 55, 121, 135, 245, 249, 91, 21, 130,
+    85, 122, 50, 35, 159, 115, 199, 42, 125, 246, 153, 239, 231, 48, 28, 58, 1, 26, 189, 214, 76,
+    217, 140, 152, 104, 221, 198, 235, 92, 250, 101, 206, 93, 215, 102, 93, 163, 189, 231, 41, 231,
+    51, 61, 161, 28, 61, 89, 66, 64, 123, 156, 35, 233, 206, 23, 251, 14, 139, 228, 193, 154, 92,
+    101, 244, 157, 76, 2, 21, 142, 248, 235, 58, 210, 223, 17, 181, 52, 185, 98, 248, 138, 53, 6,
+    201, 151, 73, 129, 207, 72, 233, 200, 63, 202, 55, 71, 156, 225, 118, 80, 188, 51, 44, 50, 182,
+    142, 84, 125, 86, 90, 215, 141, 122, 32, 70, 13, 57, 4, 248, 12, 23, 63, 199, 163, 65, 43, 29,
+    61, 150, 122, 108, 32, 242, 179, 24, 98, 182, 67, 75, 184, 48, 8, 200, 190, 20, 250, 36, 129,
+    164, 54, 129, 72, 199, 81, 193, 197, 129, 121, 243, 8, 167, 131, 233, 47, 71, 238, 46, 27, 163,
+    121, 249, 103, 45, 219, 147, 178, 102, 173, 221, 228, 3, 246, 3, 49, 199, 203, 213, 17, 203, 3,
+    28, 15,
 ];
 pub const LARGE_MINT_CT_64: [u8; 64] = [
     46, 178, 54, 144, 57, 145, 245, 23, 174, 90, 164, 138, 92, 208, 215, 140, 58, 160, 180, 19,
@@ -473,73 +881,73 @@ pub const FULL_BURN_PROOF: &[u8] = &[
     196, 93, 88, 159, 157, 175, 252, 14, 172, 193, 64, 244, 98, 210, 7, 186, 123, 7, 111, 146, 83,
     193, 69, 195, 102, 53, 143, 252, 17, 10, 241, 58, 223, 37, 184, 153, 36, 102, 181, 67, 58, 222,
     207, 27, 45, 134, 14, 65, 28, 216, 224, 106, 245, 105, 255, 237, 107, 77, 50, 205, 65, 138, 65,
-    118, 56, 178, 247, 117, 59, 46, 164, 45, 10, 25, 191, 175, 169, 183, 0, 160, 2, 60, 244, 212,
-    13, 183, 190, 17, 137, 13, 196, 51, 37, 33, 71, 213, 19, 201, 95, 164, 174, 241, 48, 31, 49,
-    121, 123, 212, 172, 1, 55, 112, 115, 206, 184, 52, 32, 216, 119, 97, 244, 167, 62, 70, 248,
-    141, 128, 236, 223, 9, 114, 75, 52, 14, 124, 186, 54, 48, 178, 167, 27, 245, 228, 90, 47, 154,
-    52, 141, 138, 100, 54, 17, 251, 59, 36, 114, 87, 204, 50, 17, 155, 44, 127, 45, 230, 214, 46,
-    248, 35, 176, 107, 116, 249, 60, 194, 85, 61, 40, 162, 115, 141, 15, 0, 215, 176, 46, 135, 225,
-    188, 236, 241, 113, 155, 112, 104, 247, 26, 229, 0, 74, 216, 136, 190, 31, 183, 105, 148, 47,
-    79, 182, 53, 141, 3, 200, 123, 154, 112, 13, 53, 193, 240, 60, 56, 54, 212, 168, 108, 91, 1,
-    238, 118, 40, 223, 246, 228, 186, 249, 209, 40, 27, 1, 226, 147, 240, 175, 49, 101, 188, 131,
-    244, 235, 155, 18, 244, 131, 142, 230, 59, 147, 21, 0, 124, 224, 146, 61, 241, 52, 60, 61, 3,
-    183, 79, 1, 161, 68, 124, 22, 141, 54, 170, 138, 212, 124, 206, 23, 221, 138, 227, 70, 84, 121,
-    159, 62, 237, 203, 153, 193, 22, 87, 134, 215, 232, 121, 109, 10, 214, 246, 83, 247, 249, 49,
-    99, 50, 190, 144, 109, 43, 246, 152, 142, 129, 108, 203, 151, 110, 200, 158, 191, 253, 175, 16,
-    237, 47, 62, 8, 68, 117, 240, 54, 176, 52, 149, 85, 104, 250, 75, 126, 228, 57, 36, 6, 205, 66,
-    120, 32, 113, 39, 236, 144, 100, 141, 60, 250, 153, 245, 225, 153, 115, 15, 184, 251, 46, 12,
-    89, 163, 0, 90, 25, 121, 141, 1, 67, 177, 169, 30, 196, 207, 199, 80, 244, 142, 172, 138, 245,
-    71, 171, 96, 94, 26, 92, 113, 2, 163, 90, 194, 41, 182, 76, 44, 53, 13, 14, 83, 76, 93, 137,
-    108, 128, 203, 89, 149, 184, 148, 220, 77, 198, 86, 188, 207, 218, 108, 117, 23, 172, 74, 113,
-    161, 25, 112, 176, 154, 163, 153, 11, 108, 54, 214, 106, 156, 19, 220, 142, 121, 56, 35, 243,
-    154, 240, 16, 169, 187, 36, 227, 166, 4, 228, 168, 216, 81, 178, 122, 108, 115, 229, 249, 255,
-    205, 95, 137, 198, 126, 103, 18, 200, 121, 80, 35, 8, 59, 105, 142, 132, 235, 15, 117, 0, 67,
-    60, 180, 206, 245, 36, 77, 23, 72, 146, 133, 108, 35, 227, 251, 34, 135, 225, 246, 71, 142,
-    144, 36, 177, 206, 243, 142, 40, 157, 63, 83, 63, 84, 164, 61, 115, 226, 37, 203, 179, 97, 156,
-    4, 145, 46, 81, 215, 98, 131, 15, 252, 183, 1, 158, 230, 33, 247, 217, 114, 25, 58, 158, 200,
-    69, 97, 240, 45, 216, 90, 2, 144, 58, 50, 2, 238, 30, 49, 96, 246, 228, 24, 46, 144, 94, 3,
-    194, 14, 223, 191, 40, 50, 0, 232, 69, 124, 37, 55, 236, 77, 0, 65, 242, 124, 130, 214, 249,
-    22, 164, 50, 190, 99, 229, 18, 61, 177, 43, 105, 219, 0, 251, 119, 106, 65, 12, 205, 85, 109,
-    49, 24, 26, 219, 82, 148, 21, 59, 199, 46, 14, 77, 98, 84, 52, 242, 224, 236, 21, 78, 123, 152,
-    36, 73, 46, 56, 170, 27, 107, 49, 123, 76, 253, 30, 60, 230, 246, 34, 78, 124, 72, 158, 213,
-    30, 41, 27, 141, 162, 253, 145, 246, 128, 26, 177, 197, 13, 50, 5, 158, 204, 82, 210, 123, 95,
-    95, 110, 0, 77, 171, 213, 177, 224, 159, 84, 195, 42, 32, 204, 193, 230, 37, 40, 213, 38, 134,
-    240, 146, 255, 215, 249, 52, 231, 108, 80, 247, 99, 165, 11, 146, 173, 220, 74, 21, 117, 94,
-    84, 161, 64, 14, 34, 228, 235, 122, 171, 167, 135, 174, 184, 69, 15, 196, 56, 195, 191, 174,
-    48, 64, 226, 223, 6, 160, 2, 222, 52, 154, 171, 58, 205, 183, 32, 193, 118, 233, 61, 69, 232,
-    107, 180, 83, 111, 158, 98, 250, 3, 8, 247, 194, 139, 41, 106, 18, 41, 119, 30, 74, 152, 29,
-    152, 12, 153, 157, 244, 151, 149, 88, 247, 116, 73, 108, 70, 58, 53, 182, 204, 58, 151, 108,
-    149, 136, 204, 16, 186, 45, 255, 159, 87, 110, 199, 37, 160, 145, 120, 85, 44, 121, 35, 157,
-    178, 46, 231, 182, 101, 200, 225, 96, 103, 168, 115, 43, 133, 129, 216, 224, 178, 51, 203, 116,
-    0, 238, 227, 137, 144, 78, 199, 103, 170, 112, 61, 234, 24, 230, 175, 88, 50, 65, 118, 245, 72,
-    20, 46, 251, 122, 127, 29, 103, 23, 77, 53, 71, 91, 95, 206, 246, 137, 66, 110, 157, 143, 233,
-    165, 173, 55, 207, 8, 187, 109, 207, 67, 82, 146, 91, 136, 208, 218, 214, 86, 47, 163, 212,
-    246, 140, 11, 227, 243, 228, 197, 147, 231, 35, 153, 73, 166, 79, 151, 71, 145, 235, 198, 27,
-    208, 86, 181, 19, 205, 236, 134, 129, 111, 80, 140, 103, 138, 213, 6, 158, 79, 174, 73, 197,
-    100, 208, 26, 10, 196, 66, 166, 68, 100, 78, 244, 70, 247, 25, 170, 189, 232, 164, 249, 198,
-    233, 161, 111, 243, 54, 188, 6, 136, 169, 80, 29, 235, 232, 229, 26, 74, 192, 38, 237, 18, 66,
-    201, 97, 104, 133, 41, 27, 122, 144, 200, 89, 246, 255, 21, 0, 188, 107, 165, 51, 196, 22, 24,
-    126, 154, 51, 33, 184, 168, 159, 50, 76, 71, 67, 145, 106, 235, 200, 62, 56, 141, 60, 168, 8,
-    117, 26, 214, 126, 193, 65, 186, 60, 64, 22, 121, 205, 251, 246, 45, 52, 119, 163, 84, 243,
-    136, 46, 214, 189, 101, 253, 170, 0, 81, 148, 26, 25, 192, 247, 166, 199, 13, 23, 129, 115,
-    132, 194, 93, 101, 187, 204, 102, 241, 228, 248, 46, 50, 243, 134, 163, 88, 15, 133, 117, 27,
-    79, 191, 149, 219, 18, 123, 19, 90, 248, 179, 184, 81, 188, 237, 244, 172, 185, 131, 236, 152,
-    129, 114, 165, 53, 161, 11, 246, 181, 171, 49, 137, 231, 230, 193, 145, 56, 34, 198, 50, 168,
-    65, 59, 75, 21, 186, 71, 94, 180, 60, 110, 24, 68, 233, 240, 242, 182, 36, 114, 83, 198, 0, 50,
-    147, 7, 198, 77, 194, 14, 158, 134, 83, 172, 170, 182, 57, 99, 24, 37, 101, 104, 17, 80, 196,
-    155, 165, 246, 46, 242, 3, 93, 100, 55, 48, 195, 157, 43, 14, 26, 175, 245, 207, 247, 164, 22,
-    34, 54, 147, 42, 92, 128, 117, 245, 116, 130, 68, 30, 173, 237, 6, 101, 216, 215, 104, 138,
-    163, 170, 4, 151, 214, 220, 0, 223, 61, 215, 68, 31, 10, 2, 68, 121, 36, 1, 123, 71, 163, 214,
-    85, 87, 219, 221, 148, 154, 49, 187, 93, 241, 197, 129, 39, 13, 66, 70, 122, 119, 165, 137,
-    102, 21, 219, 135, 208, 99, 76, 89, 217, 150, 175, 255, 94, 131, 61, 193, 194, 199, 184, 76,
-    174, 142, 98, 24, 70, 111, 18, 247, 25, 7, 171, 35, 178, 181, 172, 93, 169, 103, 106, 7, 133,
-    26, 231, 214, 195, 153, 80, 74, 107, 179, 151, 3, 193, 73, 146, 199, 171, 224, 48, 207, 236,
-    237, 244, 241, 225, 230, 98, 58, 238, 7, 4, 0, 1, 64, 139, 238, 186, 12, 14, 158, 157, 47, 148,
-    139, 66, 167, 65, 177, 166, 68, 95, 215, 2, 157, 46, 202, 178, 165, 47, 64, 176, 50, 208, 182,
-    53, 133, 34, 238, 180, 62, 155, 82, 216, 163, 65, 137, 128, 190, 177, 210, 136, 7, 166, 173,
-    87, 97, 247, 22, 10, 220, 56, 9, 48, 10, 26, 61, 255, 134, 9, 78, 114, 161, 190, 48, 80, 233,
-    12, 148, 162, 218, 136, 159, 154, 19, 23, 98, 156, 201, 45, 23, 80, 178, 32, 32, 171, 3, 232,
-    3, 0, 0, 0, 0, 0, 0,
+    118, 56, 178, 247, 117, 59, 46, 164, 45, 10, 25, 191, 175, 169, 183, 0, 160, 2, 176, 8, 81, 70,
+    36, 57, 240, 4, 211, 12, 254, 89, 210, 178, 158, 114, 182, 122, 205, 203, 81, 136, 44, 163,
+    102, 171, 184, 123, 145, 100, 46, 108, 42, 16, 12, 11, 216, 18, 190, 98, 80, 49, 213, 214, 108,
+    178, 97, 215, 53, 87, 37, 30, 128, 107, 208, 11, 215, 188, 26, 46, 74, 102, 151, 41, 86, 226,
+    105, 193, 54, 145, 230, 181, 52, 58, 22, 225, 252, 19, 38, 124, 17, 147, 123, 130, 196, 29,
+    103, 22, 134, 75, 220, 242, 169, 142, 81, 95, 214, 60, 41, 196, 75, 226, 15, 126, 112, 18, 236,
+    245, 70, 75, 93, 80, 65, 12, 58, 202, 129, 5, 39, 106, 146, 80, 45, 87, 17, 70, 236, 2, 248,
+    185, 128, 55, 163, 188, 10, 214, 33, 122, 155, 27, 204, 163, 203, 131, 237, 128, 167, 18, 201,
+    236, 62, 176, 59, 210, 54, 91, 211, 170, 177, 13, 78, 223, 182, 98, 42, 183, 255, 169, 7, 70,
+    203, 254, 166, 153, 242, 84, 198, 38, 137, 254, 123, 184, 5, 65, 153, 56, 205, 57, 227, 28,
+    129, 7, 234, 138, 59, 135, 33, 170, 133, 67, 76, 141, 213, 167, 20, 23, 106, 147, 205, 24, 57,
+    62, 27, 34, 4, 52, 188, 106, 117, 48, 191, 107, 126, 9, 110, 126, 16, 17, 46, 251, 251, 228,
+    43, 43, 173, 94, 200, 99, 68, 211, 50, 166, 255, 177, 132, 65, 153, 96, 208, 239, 159, 51, 163,
+    227, 60, 80, 234, 34, 199, 182, 7, 118, 139, 49, 176, 198, 113, 118, 184, 34, 42, 123, 83, 138,
+    79, 113, 160, 166, 75, 136, 234, 154, 176, 198, 115, 7, 157, 100, 198, 149, 82, 227, 208, 12,
+    7, 225, 154, 72, 88, 174, 166, 24, 4, 69, 222, 39, 71, 7, 10, 83, 187, 80, 217, 57, 244, 138,
+    241, 184, 217, 106, 146, 153, 183, 134, 4, 226, 36, 40, 91, 196, 108, 83, 176, 242, 183, 137,
+    128, 237, 218, 107, 177, 139, 255, 82, 67, 100, 9, 97, 160, 199, 180, 8, 104, 206, 250, 21, 15,
+    181, 15, 81, 123, 245, 177, 247, 218, 58, 67, 171, 8, 46, 153, 162, 98, 70, 77, 151, 94, 222,
+    37, 34, 112, 32, 239, 63, 84, 156, 40, 206, 40, 192, 224, 79, 170, 139, 73, 162, 178, 96, 232,
+    41, 44, 166, 78, 208, 224, 152, 164, 241, 35, 31, 240, 73, 61, 223, 139, 31, 230, 157, 105, 35,
+    140, 165, 123, 33, 150, 102, 143, 87, 147, 36, 191, 143, 239, 40, 228, 208, 189, 134, 255, 235,
+    210, 72, 234, 220, 23, 2, 85, 33, 136, 34, 131, 146, 12, 70, 149, 157, 74, 96, 0, 77, 27, 186,
+    50, 84, 32, 135, 170, 253, 76, 103, 91, 197, 129, 67, 91, 255, 212, 225, 12, 34, 102, 26, 47,
+    254, 164, 23, 170, 189, 14, 93, 34, 105, 159, 210, 30, 158, 54, 86, 248, 79, 245, 206, 33, 198,
+    248, 82, 218, 27, 13, 249, 163, 74, 236, 111, 208, 104, 213, 196, 175, 111, 13, 241, 5, 108,
+    145, 151, 94, 171, 208, 60, 127, 213, 187, 42, 189, 127, 90, 16, 236, 16, 245, 7, 161, 103,
+    234, 171, 115, 233, 102, 139, 118, 194, 139, 191, 196, 200, 88, 202, 208, 150, 59, 167, 67,
+    105, 230, 66, 238, 100, 3, 140, 89, 62, 244, 73, 226, 49, 20, 201, 8, 206, 123, 214, 10, 202,
+    96, 46, 230, 251, 95, 212, 29, 182, 235, 223, 3, 232, 183, 132, 36, 41, 188, 203, 28, 216, 178,
+    174, 116, 18, 17, 84, 218, 85, 39, 159, 243, 252, 76, 206, 60, 16, 33, 43, 197, 195, 118, 102,
+    144, 211, 142, 54, 104, 198, 188, 122, 240, 139, 194, 57, 48, 11, 206, 65, 146, 48, 5, 147,
+    136, 203, 183, 152, 207, 219, 131, 99, 70, 26, 157, 2, 247, 219, 186, 12, 238, 213, 121, 16,
+    249, 193, 223, 198, 225, 11, 160, 2, 198, 142, 222, 253, 190, 16, 224, 209, 236, 122, 232, 55,
+    241, 23, 214, 220, 103, 176, 255, 202, 74, 130, 88, 88, 3, 116, 6, 189, 62, 19, 70, 90, 60, 7,
+    50, 214, 196, 99, 183, 2, 212, 6, 25, 156, 129, 69, 194, 193, 45, 195, 102, 231, 104, 65, 238,
+    44, 63, 179, 151, 161, 181, 126, 165, 28, 178, 150, 189, 60, 111, 202, 241, 227, 231, 147, 170,
+    98, 50, 197, 52, 19, 27, 141, 73, 242, 126, 251, 56, 177, 195, 200, 72, 48, 170, 236, 222, 90,
+    134, 26, 93, 1, 136, 4, 179, 249, 238, 132, 3, 218, 30, 233, 186, 229, 184, 78, 208, 79, 160,
+    53, 97, 50, 250, 108, 163, 119, 218, 4, 4, 23, 123, 75, 103, 21, 40, 245, 126, 202, 27, 100,
+    11, 28, 219, 133, 96, 106, 0, 153, 119, 229, 196, 16, 117, 3, 138, 97, 188, 56, 10, 219, 97, 2,
+    221, 143, 112, 34, 184, 205, 246, 239, 249, 225, 52, 228, 75, 116, 157, 128, 115, 42, 150, 149,
+    169, 159, 211, 118, 209, 233, 40, 251, 24, 19, 228, 1, 53, 121, 63, 194, 87, 169, 105, 227, 88,
+    200, 24, 155, 106, 9, 145, 203, 228, 252, 82, 153, 185, 23, 202, 236, 161, 75, 98, 74, 183, 7,
+    66, 3, 186, 247, 246, 151, 187, 68, 177, 50, 236, 146, 234, 78, 251, 201, 187, 108, 211, 135,
+    125, 183, 48, 226, 36, 21, 0, 125, 231, 159, 90, 63, 188, 125, 100, 70, 43, 161, 210, 43, 248,
+    226, 160, 176, 164, 76, 156, 46, 25, 248, 222, 106, 178, 12, 255, 58, 58, 17, 120, 68, 73, 70,
+    174, 172, 56, 45, 20, 179, 136, 212, 70, 175, 39, 148, 179, 255, 255, 30, 25, 7, 150, 240, 90,
+    46, 19, 139, 139, 108, 231, 245, 105, 190, 12, 72, 100, 12, 221, 82, 142, 244, 70, 33, 86, 173,
+    184, 131, 135, 187, 170, 129, 51, 232, 137, 175, 77, 126, 86, 72, 106, 167, 75, 118, 172, 244,
+    237, 6, 127, 129, 116, 50, 216, 128, 6, 168, 38, 18, 69, 211, 1, 188, 149, 40, 215, 175, 42,
+    73, 183, 63, 190, 241, 102, 86, 19, 224, 93, 228, 146, 129, 227, 101, 31, 39, 100, 107, 116,
+    247, 229, 248, 122, 208, 79, 197, 98, 143, 95, 8, 63, 177, 54, 202, 6, 140, 32, 70, 17, 129,
+    71, 177, 168, 97, 215, 188, 92, 80, 90, 169, 158, 68, 231, 167, 211, 14, 253, 37, 57, 118, 142,
+    217, 4, 147, 74, 7, 105, 72, 146, 139, 88, 32, 251, 130, 158, 116, 208, 69, 193, 0, 188, 138,
+    60, 225, 127, 86, 9, 94, 234, 135, 31, 118, 47, 19, 210, 90, 143, 203, 99, 232, 127, 61, 140,
+    137, 67, 159, 60, 11, 19, 210, 22, 116, 14, 124, 213, 63, 220, 226, 63, 45, 16, 140, 254, 159,
+    224, 167, 49, 150, 71, 210, 85, 171, 54, 137, 179, 45, 154, 63, 241, 23, 35, 126, 246, 31, 70,
+    167, 12, 61, 117, 147, 70, 182, 105, 26, 163, 101, 56, 248, 219, 88, 212, 77, 22, 158, 108,
+    162, 216, 26, 196, 60, 104, 53, 199, 131, 239, 92, 100, 74, 162, 118, 248, 37, 159, 25, 58,
+    145, 92, 171, 99, 128, 166, 236, 8, 141, 48, 249, 116, 125, 21, 4, 56, 237, 113, 89, 203, 127,
+    53, 10, 4, 86, 248, 201, 225, 209, 90, 126, 253, 60, 139, 29, 170, 214, 101, 170, 91, 80, 103,
+    235, 148, 133, 200, 173, 172, 127, 243, 208, 174, 251, 106, 97, 175, 181, 1, 139, 99, 87, 246,
+    171, 43, 15, 174, 213, 81, 88, 116, 173, 49, 8, 253, 162, 140, 97, 67, 220, 69, 21, 91, 255,
+    227, 225, 185, 7, 61, 124, 204, 154, 88, 189, 113, 191, 140, 98, 54, 84, 189, 203, 34, 209,
+    115, 62, 181, 30, 6, 222, 50, 11, 118, 206, 175, 104, 203, 118, 100, 1, 232, 3, 0, 0, 0, 0, 0,
+    0,
 ];
 pub const FULL_BURN_CT_64: [u8; 64] = [
     178, 159, 217, 12, 197, 194, 245, 74, 126, 59, 129, 215, 28, 132, 134, 124, 172, 12, 107, 85,
@@ -583,40 +991,40 @@ pub const MALFORMED_TAMPERED_BUNDLE: &[u8] = &[
     203, 179, 78, 136, 101, 50, 149, 127, 163, 12, 23, 106, 114, 11, 216, 11, 142, 255, 19, 71, 31,
     229, 128, 211, 83, 122, 19, 231, 2, 169, 190, 76, 73, 10, 60, 33, 27, 149, 41, 176, 200, 5,
     132, 166, 218, 11, 213, 118, 212, 60, 104, 251, 134, 120, 157, 77, 86, 124, 187, 225, 113, 211,
-    159, 239, 218, 238, 252, 49, 166, 50, 23, 252, 228, 175, 50, 168, 248, 4, 160, 2, 236, 29, 45,
-    74, 107, 167, 249, 229, 169, 157, 174, 178, 176, 93, 227, 227, 237, 204, 63, 144, 37, 238, 82,
-    91, 87, 17, 14, 235, 198, 171, 183, 117, 168, 241, 55, 203, 241, 119, 117, 254, 178, 184, 207,
-    9, 17, 215, 161, 64, 41, 164, 29, 25, 20, 38, 198, 48, 3, 215, 23, 86, 150, 55, 151, 108, 90,
-    119, 189, 168, 69, 140, 123, 75, 106, 228, 16, 72, 225, 196, 254, 4, 152, 69, 85, 71, 103, 61,
-    196, 23, 10, 202, 7, 144, 161, 134, 74, 45, 132, 222, 224, 138, 85, 215, 197, 143, 4, 202, 149,
-    61, 43, 53, 216, 176, 218, 40, 131, 56, 183, 164, 165, 196, 95, 189, 160, 212, 236, 28, 17, 84,
-    216, 16, 1, 125, 0, 75, 226, 194, 168, 108, 42, 188, 147, 56, 18, 135, 164, 232, 98, 106, 31,
-    27, 140, 213, 170, 47, 132, 47, 114, 225, 220, 5, 173, 178, 75, 92, 81, 51, 214, 238, 72, 59,
-    71, 117, 147, 145, 17, 65, 117, 107, 233, 240, 202, 23, 141, 156, 190, 118, 175, 97, 3, 219,
-    94, 13, 19, 56, 190, 172, 213, 180, 23, 124, 128, 206, 241, 198, 252, 20, 174, 163, 56, 109,
-    42, 122, 12, 75, 33, 28, 210, 16, 224, 17, 147, 230, 54, 15, 76, 111, 136, 111, 248, 249, 0,
-    213, 175, 202, 82, 4, 195, 243, 141, 213, 22, 20, 171, 223, 244, 216, 199, 26, 76, 198, 72, 23,
-    38, 198, 20, 10, 190, 75, 87, 16, 147, 140, 19, 8, 46, 65, 229, 209, 15, 134, 212, 130, 86, 34,
-    195, 159, 138, 197, 19, 217, 111, 120, 83, 255, 147, 191, 113, 84, 106, 246, 21, 171, 190, 62,
-    220, 38, 113, 133, 65, 224, 18, 48, 235, 237, 120, 241, 193, 208, 230, 38, 31, 104, 187, 123,
-    200, 255, 88, 231, 185, 70, 202, 124, 178, 247, 39, 4, 211, 90, 97, 112, 124, 86, 129, 36, 21,
-    49, 78, 151, 226, 11, 171, 79, 64, 227, 218, 47, 81, 27, 9, 236, 121, 43, 24, 60, 48, 11, 143,
-    6, 89, 17, 252, 90, 93, 146, 140, 6, 13, 247, 77, 247, 198, 76, 220, 148, 152, 19, 248, 240,
-    234, 224, 78, 215, 181, 83, 78, 64, 92, 161, 60, 51, 115, 108, 104, 201, 57, 184, 107, 162,
-    193, 193, 50, 176, 93, 125, 172, 22, 118, 150, 104, 144, 168, 181, 165, 72, 62, 14, 2, 244, 38,
-    185, 66, 100, 241, 47, 119, 149, 111, 133, 16, 215, 230, 81, 185, 158, 109, 106, 126, 174, 194,
-    103, 135, 130, 220, 63, 3, 127, 243, 83, 94, 152, 239, 39, 246, 154, 248, 157, 57, 189, 90, 41,
-    88, 132, 40, 11, 55, 115, 160, 235, 89, 106, 83, 88, 210, 121, 86, 148, 37, 91, 140, 13, 76,
-    45, 7, 120, 140, 135, 157, 61, 89, 134, 230, 21, 78, 125, 120, 206, 144, 23, 76, 104, 11, 143,
-    70, 151, 120, 156, 125, 10, 18, 163, 36, 76, 104, 15, 240, 50, 237, 204, 133, 173, 151, 15,
-    223, 223, 117, 55, 143, 76, 113, 165, 43, 60, 123, 132, 231, 238, 31, 166, 101, 37, 42, 112,
-    101, 116, 128, 133, 44, 214, 227, 240, 168, 4, 113, 63, 0, 160, 91, 122, 209, 40, 114, 123,
-    159, 108, 101, 215, 13, 180, 81, 61, 238, 197, 30, 43, 68, 53, 132, 249, 247, 170, 135, 141,
-    238, 200, 160, 72, 173, 161, 146, 49, 117, 147, 21, 210, 20, 109, 22, 31, 224, 168, 145, 231,
-    39, 251, 72, 28, 182, 26, 108, 254, 183, 86, 12, 86, 182, 164, 26, 56, 136, 237, 41, 145, 203,
-    72, 175, 253, 58, 150, 74, 136, 106, 44, 201, 164, 0, 227, 3, 26, 204, 14, 120, 124, 214, 16,
-    130, 226, 174, 244, 229, 10, 102, 77, 2, 45, 46, 65, 71, 180, 108, 48, 214, 168, 57, 96, 186,
-    46, 7, 164, 7, 243, 57, 11, 0, 0,
+    159, 239, 218, 238, 252, 49, 166, 50, 23, 252, 228, 175, 50, 168, 248, 4, 160, 2, 60, 203, 108,
+    38, 181, 137, 206, 16, 31, 170, 26, 81, 73, 78, 211, 86, 228, 124, 157, 153, 34, 158, 117, 213,
+    202, 127, 124, 174, 237, 115, 151, 121, 130, 18, 159, 237, 99, 129, 162, 250, 28, 26, 52, 151,
+    183, 202, 71, 131, 23, 103, 208, 147, 171, 36, 80, 143, 192, 76, 187, 84, 3, 42, 242, 92, 90,
+    32, 101, 192, 150, 251, 90, 209, 207, 65, 255, 7, 195, 25, 194, 28, 51, 138, 100, 113, 100,
+    147, 203, 187, 58, 104, 119, 189, 192, 123, 100, 60, 4, 139, 14, 240, 35, 111, 5, 159, 195,
+    164, 103, 36, 221, 123, 219, 46, 46, 172, 84, 171, 60, 90, 194, 150, 186, 240, 103, 148, 89,
+    183, 130, 43, 144, 33, 23, 67, 240, 7, 10, 89, 146, 25, 15, 72, 221, 225, 204, 171, 122, 208,
+    116, 87, 17, 202, 81, 229, 212, 227, 10, 150, 160, 1, 127, 12, 163, 238, 20, 249, 103, 85, 194,
+    157, 138, 112, 185, 254, 144, 86, 105, 157, 178, 77, 106, 120, 251, 241, 245, 141, 254, 135,
+    120, 3, 182, 162, 80, 2, 173, 254, 141, 234, 255, 63, 214, 10, 23, 233, 26, 146, 235, 70, 237,
+    180, 221, 214, 21, 246, 48, 61, 232, 191, 253, 158, 88, 196, 138, 5, 72, 7, 8, 27, 157, 153,
+    78, 216, 113, 219, 207, 141, 215, 122, 31, 110, 252, 214, 207, 21, 175, 189, 16, 188, 113, 216,
+    187, 70, 42, 69, 197, 103, 194, 48, 166, 196, 36, 194, 92, 238, 180, 129, 239, 60, 85, 60, 176,
+    85, 56, 102, 107, 164, 13, 74, 119, 105, 210, 255, 61, 10, 17, 172, 56, 39, 189, 26, 4, 172,
+    76, 118, 206, 42, 230, 132, 27, 108, 249, 61, 109, 236, 76, 40, 45, 160, 115, 234, 159, 201,
+    253, 109, 101, 125, 216, 235, 152, 71, 3, 68, 188, 179, 152, 157, 209, 66, 15, 181, 239, 24,
+    169, 199, 199, 248, 4, 98, 29, 149, 67, 100, 163, 54, 129, 157, 58, 138, 141, 54, 221, 121,
+    130, 27, 116, 41, 198, 140, 34, 229, 176, 66, 251, 79, 252, 62, 141, 131, 90, 143, 112, 55, 70,
+    225, 65, 175, 201, 196, 161, 224, 75, 129, 182, 183, 117, 65, 184, 247, 19, 248, 190, 102, 3,
+    43, 83, 141, 11, 38, 195, 158, 177, 45, 131, 111, 165, 226, 39, 112, 213, 240, 156, 80, 170,
+    93, 51, 148, 8, 28, 62, 128, 135, 48, 196, 43, 180, 250, 252, 169, 110, 35, 66, 228, 93, 215,
+    32, 18, 146, 22, 60, 205, 25, 216, 106, 24, 92, 128, 13, 156, 209, 52, 58, 42, 48, 106, 238,
+    218, 15, 121, 103, 223, 5, 203, 29, 77, 241, 244, 91, 56, 78, 140, 71, 3, 154, 125, 146, 86,
+    128, 132, 99, 184, 249, 15, 16, 132, 121, 9, 220, 2, 184, 171, 13, 131, 135, 237, 177, 245, 56,
+    213, 180, 26, 74, 183, 254, 234, 141, 235, 50, 130, 163, 109, 181, 59, 9, 95, 184, 137, 66,
+    236, 65, 116, 215, 23, 161, 238, 92, 56, 80, 224, 155, 212, 78, 126, 40, 43, 169, 91, 73, 209,
+    137, 120, 205, 44, 138, 62, 72, 11, 192, 124, 78, 192, 226, 150, 125, 14, 167, 199, 231, 162,
+    95, 220, 195, 134, 27, 46, 160, 228, 104, 87, 106, 150, 161, 130, 39, 180, 168, 26, 234, 19,
+    14, 141, 171, 135, 52, 103, 193, 167, 17, 170, 223, 209, 147, 230, 154, 148, 90, 15, 156, 172,
+    204, 52, 186, 136, 103, 7, 15, 237, 156, 197, 127, 119, 53, 214, 165, 12, 194, 22, 130, 235,
+    78, 89, 150, 194, 131, 27, 156, 109, 185, 229, 217, 252, 231, 50, 165, 215, 181, 240, 150, 122,
+    219, 43, 209, 7, 191, 82, 50, 200, 14, 31, 178, 176, 64, 68, 200, 201, 172, 90, 66, 87, 169,
+    23, 8, 213, 105, 146, 14, 76, 102, 239, 162, 38, 194, 123, 114, 6, 0, 0,
 ];
 
 // ----- Invalid point (not on curve) -----
@@ -1,11 +1,16 @@
-use confidential_assets_primitives::{ZeroNetworkId, ZkVerifier as _};
+use confidential_assets_primitives::{
+    EncryptedAmount, PublicKeyBytes, ZeroNetworkId, ZkVerifier as _,
+};
 use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
 use std::hint::black_box;
 use zkhe_vectors::{
-    ACCEPT_ENVELOPE, ASSET_ID_BYTES, RECEIVER_PK32, SENDER_PK32, TRANSFER_BUNDLE,
+    ACCEPT_ENVELOPE, ASSET_ID_BYTES, BURN_AMOUNT_CT_64, BURN_FROM_OLD_COMM_32, BURN_PROOF,
+    BURN_TOTAL_OLD_COMM_32, CLAIM_1, CLAIM_1_PENDING_OLD_COMM_32, CLAIM_1_UTXO_COMMS, CLAIM_4,
+    CLAIM_4_PENDING_OLD_COMM_32, CLAIM_4_UTXO_COMMS, CLAIM_16, CLAIM_16_PENDING_OLD_COMM_32,
+    CLAIM_16_UTXO_COMMS, MINT_PROOF, RECEIVER_PK32, SENDER_PK32, TRANSFER_BUNDLE,
     TRANSFER_DELTA_COMM_32, TRANSFER_DELTA_CT_64, TRANSFER_FROM_OLD_COMM_32,
 };
-use zkhe_verifier::ZkheVerifier;
+use zkhe_verifier::{VerifierContext, ZkheVerifier};
 
 type Verifier = ZkheVerifier<ZeroNetworkId>;
 
@@ -56,5 +61,106 @@ fn bench_accept_verify(c: &mut Criterion) {
     g.finish();
 }
 
-criterion_group!(benches, bench_transfer_verify, bench_accept_verify);
+fn bench_mint_verify(c: &mut Criterion) {
+    let mut g = c.benchmark_group("verify_mint");
+    g.throughput(Throughput::Elements(1));
+
+    let to_pk = PublicKeyBytes::try_from(RECEIVER_PK32.to_vec()).expect("pk bv");
+
+    g.bench_function(BenchmarkId::from_parameter("mint"), |b| {
+        b.iter(|| {
+            let out = Verifier::verify_mint(&ASSET_ID_BYTES, &to_pk, &[], &[], MINT_PROOF)
+                .expect("mint verify");
+            black_box(out);
+        });
+    });
+
+    g.finish();
+}
+
+fn bench_burn_verify(c: &mut Criterion) {
+    let mut g = c.benchmark_group("verify_burn");
+    g.throughput(Throughput::Elements(1));
+
+    let from_pk = PublicKeyBytes::try_from(SENDER_PK32.to_vec()).expect("pk bv");
+    let amount_ct = EncryptedAmount::new(BURN_AMOUNT_CT_64);
+
+    g.bench_function(BenchmarkId::from_parameter("burn"), |b| {
+        b.iter(|| {
+            let out = Verifier::verify_burn(
+                &ASSET_ID_BYTES,
+                &from_pk,
+                &BURN_FROM_OLD_COMM_32,
+                &BURN_TOTAL_OLD_COMM_32,
+                &amount_ct,
+                BURN_PROOF,
+            )
+            .expect("burn verify");
+            black_box(out);
+        });
+    });
+
+    g.finish();
+}
+
+/// Claims of 1, 4, and 16 pending UTXOs into an empty available balance.
+fn bench_claim_verify(c: &mut Criterion) {
+    let mut g = c.benchmark_group("verify_claim");
+
+    let claims: [(&[[u8; 32]], [u8; 32], &[u8]); 3] = [
+        (CLAIM_1_UTXO_COMMS, CLAIM_1_PENDING_OLD_COMM_32, CLAIM_1),
+        (CLAIM_4_UTXO_COMMS, CLAIM_4_PENDING_OLD_COMM_32, CLAIM_4),
+        (CLAIM_16_UTXO_COMMS, CLAIM_16_PENDING_OLD_COMM_32, CLAIM_16),
+    ];
+
+    for (commits, pending_old, claim) in claims {
+        let utxos: Vec<(u64, [u8; 32])> = commits
+            .iter()
+            .enumerate()
+            .map(|(id, c)| (id as u64, *c))
+            .collect();
+
+        g.throughput(Throughput::Elements(utxos.len() as u64));
+        g.bench_function(BenchmarkId::from_parameter(utxos.len()), |b| {
+            b.iter(|| {
+                let out = Verifier::verify_claim(
+                    &ASSET_ID_BYTES,
+                    &RECEIVER_PK32,
+                    &IDENTITY_C32,
+                    &pending_old,
+                    &utxos,
+                    claim,
+                )
+                .expect("claim verify");
+                black_box(out);
+            });
+        });
+    }
+
+    g.finish();
+}
+
+/// Cost of deriving generator tables vs. reusing the cached context.
+fn bench_context_setup(c: &mut Criterion) {
+    let mut g = c.benchmark_group("verifier_context");
+
+    g.bench_function(BenchmarkId::from_parameter("new"), |b| {
+        b.iter(|| black_box(VerifierContext::new()));
+    });
+    g.bench_function(BenchmarkId::from_parameter("global"), |b| {
+        b.iter(|| black_box(VerifierContext::global()));
+    });
+
+    g.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_transfer_verify,
+    bench_accept_verify,
+    bench_mint_verify,
+    bench_burn_verify,
+    bench_claim_verify,
+    bench_context_setup
+);
 criterion_main!(benches);
@@ -16,6 +16,11 @@ fn main() {
     let paths = [
         "../../../target/criterion/verify_transfer_sent/transfer/new/estimates.json",
         "../../../target/criterion/verify_transfer_received/accept/new/estimates.json",
+        "../../../target/criterion/verify_mint/mint/new/estimates.json",
+        "../../../target/criterion/verify_burn/burn/new/estimates.json",
+        "../../../target/criterion/verify_claim/1/new/estimates.json",
+        "../../../target/criterion/verify_claim/4/new/estimates.json",
+        "../../../target/criterion/verify_claim/16/new/estimates.json",
     ];
 
     println!("{:<36} {:>12} {:>12}", "Benchmark", "mean_ms", "proofs/6s");
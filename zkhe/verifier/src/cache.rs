@@ -0,0 +1,240 @@
+//! Cache of range proofs that already verified.
+//!
+//! The same transaction is verified several times on a node: on tx-pool admission,
+//! on every re-validation, and again at block import. [`CachedRangeVerifier`] wraps a
+//! [`RangeProofVerifier`] and remembers which `(proof, public inputs)` pairs passed,
+//! keyed by `blake2b-256`, so those repeats skip the Bulletproof check.
+//!
+//! The cache only exists with the `std` feature (native execution, host functions,
+//! tooling). Without it, [`CachedRangeVerifier`] forwards every call unchanged, so a
+//! runtime can name it in its config regardless of the build target.
+
+use core::marker::PhantomData;
+use zkhe_primitives::RangeProofVerifier;
+
+/// Range-proof cache entries kept by [`CachedRangeVerifier`] unless configured.
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// [`RangeProofVerifier`] that skips proofs `R` has already accepted.
+///
+/// Holds at most `CAPACITY` entries, evicting the least recently used. Wrappers with
+/// the same `CAPACITY` share one cache, keyed by the inner verifier among other inputs;
+/// each capacity gets its own. Only successful verifications are cached; a rejected
+/// proof is re-checked every time.
+///
+/// ```ignore
+/// impl pallet_zkhe::Config for Runtime {
+///     type Verifier = zkhe_verifier::ZkheVerifier<
+///         RuntimeNetworkId,
+///         zkhe_verifier::CachedRangeVerifier<zkhe_verifier::BulletproofRangeVerifier, 4096>,
+///     >;
+///     // ...
+/// }
+/// ```
+pub struct CachedRangeVerifier<
+    R: RangeProofVerifier,
+    const CAPACITY: usize = DEFAULT_CACHE_CAPACITY,
+>(PhantomData<R>);
+
+impl<R: RangeProofVerifier, const CAPACITY: usize> RangeProofVerifier
+    for CachedRangeVerifier<R, CAPACITY>
+{
+    fn verify_range_proof(
+        transcript_label: &[u8],
+        context: &[u8],
+        commit_compressed: &[u8; 32],
+        proof_bytes: &[u8],
+    ) -> Result<(), ()> {
+        #[cfg(feature = "std")]
+        {
+            let key = imp::cache_key::<R>(
+                imp::SINGLE,
+                transcript_label,
+                context,
+                commit_compressed,
+                proof_bytes,
+            );
+            imp::verify_cached(CAPACITY, key, || {
+                R::verify_range_proof(transcript_label, context, commit_compressed, proof_bytes)
+            })
+        }
+        #[cfg(not(feature = "std"))]
+        R::verify_range_proof(transcript_label, context, commit_compressed, proof_bytes)
+    }
+
+    fn verify_aggregated_range_proof(
+        transcript_label: &[u8],
+        context: &[u8],
+        commits: &[[u8; 32]],
+        proof_bytes: &[u8],
+    ) -> Result<(), ()> {
+        #[cfg(feature = "std")]
+        {
+            let key = imp::cache_key::<R>(
+                imp::AGGREGATED,
+                transcript_label,
+                context,
+                commits.as_flattened(),
+                proof_bytes,
+            );
+            imp::verify_cached(CAPACITY, key, || {
+                R::verify_aggregated_range_proof(transcript_label, context, commits, proof_bytes)
+            })
+        }
+        #[cfg(not(feature = "std"))]
+        R::verify_aggregated_range_proof(transcript_label, context, commits, proof_bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+pub use imp::{ProofCache, clear_range_cache};
+
+#[cfg(feature = "std")]
+mod imp {
+    use blake2::{Blake2b, Digest, digest::consts::U32};
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::{Mutex, MutexGuard, OnceLock};
+
+    const DOMAIN: &[u8] = b"zkhe/range-cache/v1";
+    pub(super) const SINGLE: u8 = 1;
+    pub(super) const AGGREGATED: u8 = 2;
+
+    /// Bounded set of 32-byte keys with least-recently-used eviction.
+    pub struct ProofCache {
+        capacity: usize,
+        tick: u64,
+        /// key -> last use
+        entries: HashMap<[u8; 32], u64>,
+        /// last use -> key, oldest first
+        by_use: BTreeMap<u64, [u8; 32]>,
+    }
+
+    impl ProofCache {
+        /// Empty cache holding at most `capacity` keys; `0` disables caching.
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                capacity,
+                tick: 0,
+                entries: HashMap::new(),
+                by_use: BTreeMap::new(),
+            }
+        }
+
+        pub fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        pub fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.entries.is_empty()
+        }
+
+        /// Change the bound, evicting the oldest keys if it shrank.
+        pub fn set_capacity(&mut self, capacity: usize) {
+            self.capacity = capacity;
+            self.evict();
+        }
+
+        /// Whether `key` is cached; a hit marks it most recently used.
+        pub fn contains(&mut self, key: &[u8; 32]) -> bool {
+            let Some(last) = self.entries.get(key).copied() else {
+                return false;
+            };
+            self.by_use.remove(&last);
+            self.tick += 1;
+            self.by_use.insert(self.tick, *key);
+            self.entries.insert(*key, self.tick);
+            true
+        }
+
+        /// Insert `key` as most recently used.
+        pub fn insert(&mut self, key: [u8; 32]) {
+            if self.contains(&key) || self.capacity == 0 {
+                return;
+            }
+            self.tick += 1;
+            self.entries.insert(key, self.tick);
+            self.by_use.insert(self.tick, key);
+            self.evict();
+        }
+
+        pub fn clear(&mut self) {
+            self.entries.clear();
+            self.by_use.clear();
+        }
+
+        fn evict(&mut self) {
+            while self.entries.len() > self.capacity {
+                let Some((_, key)) = self.by_use.pop_first() else {
+                    break;
+                };
+                self.entries.remove(&key);
+            }
+        }
+    }
+
+    /// One cache per `CAPACITY` in use, so wrappers of different sizes never resize
+    /// each other's.
+    static CACHES: OnceLock<Mutex<BTreeMap<usize, ProofCache>>> = OnceLock::new();
+
+    fn caches() -> MutexGuard<'static, BTreeMap<usize, ProofCache>> {
+        CACHES
+            .get_or_init(|| Mutex::new(BTreeMap::new()))
+            .lock()
+            // entries are only ever inserted after a successful check
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn with_cache<O>(capacity: usize, f: impl FnOnce(&mut ProofCache) -> O) -> O {
+        f(caches()
+            .entry(capacity)
+            .or_insert_with(|| ProofCache::new(capacity)))
+    }
+
+    /// Drop every cached range-proof result, keeping each cache's capacity.
+    pub fn clear_range_cache() {
+        caches().values_mut().for_each(ProofCache::clear);
+    }
+
+    /// `blake2b-256` over the inner verifier, proof kind and every input, each
+    /// length-prefixed so no two input tuples share an encoding.
+    pub(super) fn cache_key<R>(
+        kind: u8,
+        label: &[u8],
+        context: &[u8],
+        commits: &[u8],
+        proof: &[u8],
+    ) -> [u8; 32] {
+        let mut h = Blake2b::<U32>::new();
+        h.update(DOMAIN);
+        for part in [
+            core::any::type_name::<R>().as_bytes(),
+            &[kind],
+            label,
+            context,
+            commits,
+            proof,
+        ] {
+            h.update((part.len() as u64).to_le_bytes());
+            h.update(part);
+        }
+        h.finalize().into()
+    }
+
+    pub(super) fn verify_cached(
+        capacity: usize,
+        key: [u8; 32],
+        verify: impl FnOnce() -> Result<(), ()>,
+    ) -> Result<(), ()> {
+        if with_cache(capacity, |cache| cache.contains(&key)) {
+            return Ok(());
+        }
+        // Verify without holding the lock so concurrent checks are not serialized.
+        verify()?;
+        with_cache(capacity, |cache| cache.insert(key));
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//! Precomputed generator tables shared across verifications.
+//!
+//! `BulletproofGens::new(64, 2)` and the Pedersen `H` generator are expensive
+//! to derive (hash-to-curve per generator). [`VerifierContext`] builds them once;
+//! [`VerifierContext::global`] lazily initializes a process-wide instance that
+//! [`ZkheVerifier`](crate::ZkheVerifier) and
+//! [`BulletproofRangeVerifier`](crate::BulletproofRangeVerifier) use.
+
+use bulletproofs::{BulletproofGens, PedersenGens};
+use curve25519_dalek::ristretto::RistrettoPoint;
+use zkhe_primitives::pedersen_h_generator;
+
+/// Widest range proof checked by this crate; narrower proofs use a prefix of the generators.
+pub const RANGE_BITS: usize = 64;
+
+/// Maximum number of values covered by one aggregated range proof.
+pub const MAX_AGGREGATION: usize = 2;
+
+static GLOBAL: spin::Once<VerifierContext> = spin::Once::new();
+
+/// Generator tables needed to verify ZK-ElGamal proofs.
+///
+/// Construct once and reuse, or use [`VerifierContext::global`].
+pub struct VerifierContext {
+    /// Pedersen blinding generator `H` (dalek 4).
+    h: RistrettoPoint,
+    /// Bulletproof generators for up to [`MAX_AGGREGATION`] parties of [`RANGE_BITS`] bits.
+    bp_gens: BulletproofGens,
+    /// Pedersen generators `(G, H)` in bulletproofs' dalek-ng representation.
+    pc_gens: PedersenGens,
+}
+
+impl VerifierContext {
+    /// Derive all generator tables. Prefer [`VerifierContext::global`] on hot paths.
+    pub fn new() -> Self {
+        use curve25519_dalek_ng::{
+            constants::RISTRETTO_BASEPOINT_POINT as G_NG, ristretto::CompressedRistretto,
+        };
+
+        let h = pedersen_h_generator();
+        let h_ng = CompressedRistretto(h.compress().to_bytes())
+            .decompress()
+            .expect("valid H");
+        Self {
+            h,
+            bp_gens: BulletproofGens::new(RANGE_BITS, MAX_AGGREGATION),
+            pc_gens: PedersenGens {
+                B: G_NG,
+                B_blinding: h_ng,
+            },
+        }
+    }
+
+    /// Lazily-initialized shared context.
+    pub fn global() -> &'static Self {
+        GLOBAL.call_once(Self::new)
+    }
+
+    /// Pedersen blinding generator `H`.
+    pub fn h(&self) -> RistrettoPoint {
+        self.h
+    }
+
+    /// Bulletproof generators (capacity 64, [`MAX_AGGREGATION`] parties).
+    pub fn bp_gens(&self) -> &BulletproofGens {
+        &self.bp_gens
+    }
+
+    /// Pedersen generators used by range proofs.
+    pub fn pc_gens(&self) -> &PedersenGens {
+        &self.pc_gens
+    }
+
+    /// Verify a 64-bit range proof against this context's generators.
+    ///
+    /// Same semantics as [`BulletproofRangeVerifier`](crate::BulletproofRangeVerifier).
+    pub fn verify_range_proof(
+        &self,
+        transcript_label: &[u8],
+        context: &[u8],
+        commit_compressed: &[u8; 32],
+        proof_bytes: &[u8],
+    ) -> Result<(), ()> {
+        crate::range::verify_with_context(
+            self,
+            transcript_label,
+            context,
+            commit_compressed,
+            proof_bytes,
+        )
+    }
+
+    /// Verify an aggregated 64-bit range proof over `commits` (in prover order).
+    pub fn verify_aggregated_range_proof(
+        &self,
+        transcript_label: &[u8],
+        context: &[u8],
+        commits: &[[u8; 32]],
+        proof_bytes: &[u8],
+    ) -> Result<(), ()> {
+        crate::range::verify_aggregated_with_context(
+            self,
+            transcript_label,
+            context,
+            commits,
+            proof_bytes,
+        )
+    }
+}
+
+impl Default for VerifierContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -6,21 +6,85 @@
 //! ## Supported Operations
 //!
 //! - [`ZkheVerifier::verify_transfer_sent`] - Verify sender's transfer proof
+//! - [`ZkheVerifier::verify_transfer_split`] - Verify one debit split across several recipients
 //! - [`ZkheVerifier::verify_transfer_received`] - Verify receiver's acceptance proof
 //! - [`ZkheVerifier::verify_mint`] - Verify mint (deposit) proof
 //! - [`ZkheVerifier::verify_burn`] - Verify burn (withdraw) proof
+//! - [`ZkheVerifier::verify_confidential_burn`] - Verify burn proof that keeps the amount hidden
+//! - [`ZkheVerifier::disclose`] - Verify an owner's disclosure of a ciphertext's value
+//! - [`ZkheVerifier::verify_pubkey_ownership`] - Verify knowledge of a registered key's secret
+//! - [`ZkheVerifier::verify_zero_balance`] - Verify an account's balances are zero before closing it
+//! - [`ZkheVerifier::verify_rekey`] - Verify a balance re-encrypted under a rotated key
+//! - [`ZkheVerifier::verify_auditor_handle`] - Verify an auditor's decrypt handle for a ciphertext
+//! - [`ZkheVerifier::verify_seizure`] - Verify an audited balance seizure discloses the seized amount
+//!
+//! ## Aggregated Range Proofs
+//!
+//! Accept, mint and burn proofs carry two range proofs. Instead of two separate
+//! proofs, a prover may leave the first section empty (`len1 = 0`) and put one
+//! aggregated 2-value Bulletproof in the second section, which is smaller and
+//! cheaper to verify. Both forms are accepted.
+//!
+//! ## Range-Proof Width
+//!
+//! Range proofs are 64 bits wide unless the verifier's [`RangeBitsProvider`] gives
+//! an asset 32 bits. Every range section must have the length of a proof of the
+//! asset's width, and any width other than 64 is bound into the proof context, so
+//! a proof made for one width is rejected under the other.
+//!
+//! ## Transfer Floor
+//!
+//! An asset whose [`RangeBitsProvider::transfer_floor`] is non-zero only accepts
+//! transfer proofs with a third range section proving `ΔC - floor·G` in range, i.e.
+//! that the amount is at least the floor. Without a floor the section is optional
+//! and, when present, proves the amount itself is in range.
+//! Split proofs have no optional section: each output's range proof is over
+//! `out_comm - floor·G`, so every output must clear the floor.
+//!
+//! ## Range-Proof Cache
+//!
+//! [`CachedRangeVerifier`] wraps a range verifier and, with the `std` feature,
+//! remembers proofs that already verified so tx-pool re-validation and block import
+//! do not repeat the Bulletproof check. Without `std` it is a pass-through.
+//!
+//! ## Proof System
+//!
+//! [`ZkheVerifier`] implements [`ProofSystem`] with id
+//! [`zkhe_primitives::PROOF_SYSTEM_ID`]. Pallets receive proofs as
+//! `id(1) || body` and pass only `body` to the [`ZkVerifier`] methods, so the
+//! verifier itself never sees the envelope; clients wrap prover output with
+//! [`zkhe_primitives::seal`].
 //!
 //! ## Error Handling
 //!
-//! All verification functions return [`VerifierError`] on failure, which provides
-//! detailed information about what went wrong for debugging purposes.
+//! Verification failures are reported internally as [`VerifierError`] and surfaced
+//! through the [`ZkVerifier`] trait as [`VerifyError`], which pallets can carry in
+//! their dispatch errors.
+//!
+//! [`zkhe_primitives::validate_shape`] backs [`ZkVerifier::validate_shape`], letting
+//! callers reject badly shaped proofs before any storage reads. It also decompresses
+//! the few points each proof opens with, so a transaction pool can drop proofs with
+//! invalid points without running the proof itself.
+//!
+//! Every encoding is checked for canonicity: points must re-compress to the same
+//! bytes, scalars must be reduced mod ℓ, and no bytes may follow the last proof
+//! section. This keeps each proof's byte string unique.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+mod cache;
+pub use cache::{CachedRangeVerifier, DEFAULT_CACHE_CAPACITY};
+#[cfg(feature = "std")]
+pub use cache::{ProofCache, clear_range_cache};
+
+mod context;
+pub use context::{MAX_AGGREGATION, RANGE_BITS, VerifierContext};
+
 #[allow(dead_code, unused)]
 mod range;
 pub use range::BulletproofRangeVerifier;
+pub use zkhe_primitives::GenesisNetworkId;
 
 use core::fmt;
 
@@ -29,7 +93,8 @@ mod tests;
 
 use alloc::vec::Vec;
 use confidential_assets_primitives::{
-    EncryptedAmount, NetworkIdProvider, PublicKeyBytes, ZkVerifier,
+    DefaultRangeBits, EncryptedAmount, NetworkIdProvider, ProofKind, ProofSystem, ProofSystemId,
+    PublicKeyBytes, RangeBitsProvider, VerifyError, ZkVerifier, is_supported_range_bits,
 };
 use curve25519_dalek::{
     constants::RISTRETTO_BASEPOINT_POINT as G,
@@ -39,19 +104,15 @@ use curve25519_dalek::{
 };
 use merlin::Transcript;
 use zkhe_primitives::{
-    Ciphertext, FixedProof, PublicContext, RangeProofVerifier, SDK_VERSION, append_point,
-    challenge_scalar as fs_chal, labels, new_transcript, pedersen_h_generator, point_from_bytes,
-    point_to_bytes,
+    AUDITOR_PROOF_LEN, Ciphertext, DISCLOSURE_PROOF_LEN, FixedProof, LINK_PROOF_LEN,
+    MINT_PROOF_MIN_LEN, PK_OWNERSHIP_PROOF_LEN, PublicContext, REKEY_PROOF_LEN, RangeProofVerifier,
+    SDK_VERSION, SEIZURE_PROOF_LEN, SPLIT_PART_LEN, ZERO_BALANCE_PROOF_LEN, append_point,
+    auditor_transcript, challenge_scalar as fs_chal, disclosure_transcript, labels, new_transcript,
+    pk_ownership_transcript, point_from_bytes, point_to_bytes, range_context_bytes,
+    range_proof_len, rekey_transcript, scalar_from_canonical, seizure_transcript,
+    zero_balance_transcript,
 };
 
-/// Minimum length of a mint proof bundle:
-/// - 64 bytes: minted ElGamal ciphertext (C||D)
-/// - 32 bytes: delta commitment
-/// - 192 bytes: link proof
-/// - 2 bytes: pending range proof length prefix
-/// - 2 bytes: total range proof length prefix
-const MINT_PROOF_MIN_LEN: usize = 64 + 32 + 192 + 2 + 2;
-
 /// Errors that can occur during proof verification.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum VerifierError {
@@ -69,6 +130,10 @@ pub enum VerifierError {
     RangeProofFailed,
     /// Proof bytes are too short for the expected format
     ProofTooShort,
+    /// A scalar or point is not in its unique canonical encoding
+    NonCanonical,
+    /// Extra bytes follow the last section of a proof
+    TrailingBytes,
 }
 
 impl fmt::Display for VerifierError {
@@ -81,12 +146,28 @@ impl fmt::Display for VerifierError {
             Self::PendingCommitMismatch => write!(f, "pending commitments sum mismatch"),
             Self::RangeProofFailed => write!(f, "range proof verification failed"),
             Self::ProofTooShort => write!(f, "proof bytes too short"),
+            Self::NonCanonical => write!(f, "non-canonical scalar or point encoding"),
+            Self::TrailingBytes => write!(f, "trailing bytes after proof"),
         }
     }
 }
 
-impl From<VerifierError> for () {
-    fn from(_: VerifierError) {}
+impl From<VerifierError> for VerifyError {
+    fn from(e: VerifierError) -> Self {
+        match e {
+            VerifierError::InvalidPoint => VerifyError::InvalidPoint,
+            VerifierError::InvalidCiphertext => VerifyError::InvalidCiphertext,
+            VerifierError::MalformedProof | VerifierError::ProofTooShort => {
+                VerifyError::MalformedProof
+            }
+            VerifierError::LinkProofFailed => VerifyError::LinkProofInvalid,
+            VerifierError::PendingCommitMismatch => VerifyError::PendingCommitMismatch,
+            VerifierError::RangeProofFailed => VerifyError::RangeProofInvalid,
+            VerifierError::NonCanonical | VerifierError::TrailingBytes => {
+                VerifyError::NonCanonicalEncoding
+            }
+        }
+    }
 }
 
 /// The ZK-ElGamal verifier implementation.
@@ -95,6 +176,15 @@ impl From<VerifierError> for () {
 /// separation in proofs. Each chain should configure a unique network ID to prevent
 /// cross-chain replay attacks.
 ///
+/// `R` verifies the Bulletproof range proofs. The default,
+/// [`BulletproofRangeVerifier`], runs in the runtime (pure WASM); runtimes whose
+/// node registers `zkhe-host-functions` can use its `HostRangeVerifier` instead.
+/// Either can be wrapped in [`CachedRangeVerifier`].
+///
+/// `B` gives each asset's range-proof bit width. The default,
+/// [`DefaultRangeBits`], uses 64 bits everywhere; `pallet-zkhe` implements
+/// [`RangeBitsProvider`] over its per-asset setting.
+///
 /// # Example
 /// ```ignore
 /// use confidential_assets_primitives::NetworkIdProvider;
@@ -113,13 +203,69 @@ impl From<VerifierError> for () {
 ///     // ...
 /// }
 /// ```
-pub struct ZkheVerifier<N: NetworkIdProvider>(core::marker::PhantomData<N>);
+pub struct ZkheVerifier<
+    N: NetworkIdProvider,
+    R: RangeProofVerifier = BulletproofRangeVerifier,
+    B: RangeBitsProvider = DefaultRangeBits,
+>(core::marker::PhantomData<(N, R, B)>);
+
+impl<N: NetworkIdProvider, R: RangeProofVerifier, B: RangeBitsProvider> ProofSystem
+    for ZkheVerifier<N, R, B>
+{
+    const ID: ProofSystemId = zkhe_primitives::PROOF_SYSTEM_ID;
+
+    fn proof_len(kind: ProofKind) -> Option<(usize, usize)> {
+        zkhe_primitives::proof_len(kind)
+    }
+}
 
-impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
-    // Using () for trait compatibility; use verify_* methods directly for detailed errors
-    type Error = ();
+impl<N: NetworkIdProvider, R: RangeProofVerifier, B: RangeBitsProvider> ZkVerifier
+    for ZkheVerifier<N, R, B>
+{
     type NetworkIdProvider = N;
 
+    fn validate_shape(kind: ProofKind, proof: &[u8]) -> Result<(), VerifyError> {
+        zkhe_primitives::validate_shape(kind, proof).map_err(|_| VerifyError::MalformedProof)?;
+        leading_points(kind, proof)
+            .chunks_exact(32)
+            .try_for_each(|p| canonical_point(&array32(p)?).map(|_| ()))
+            .map_err(Into::into)
+    }
+
+    // ---------------- Key registration path ----------------
+    //
+    // proof layout:
+    //   A(32) || z(32)
+    //
+    // Schnorr: z*G == A + c*pk
+    fn verify_pubkey_ownership(
+        account: &[u8],
+        pk_bytes: &[u8],
+        proof_bytes: &[u8],
+    ) -> Result<(), VerifyError> {
+        let pk = parse_point32(pk_bytes)?;
+        // sk = 0 is known to everyone
+        if pk.is_identity() {
+            return Err(VerifierError::InvalidPoint.into());
+        }
+        if proof_bytes.len() < PK_OWNERSHIP_PROOF_LEN {
+            return Err(VerifierError::ProofTooShort.into());
+        }
+        ensure_no_trailing(proof_bytes, PK_OWNERSHIP_PROOF_LEN)?;
+
+        let a = canonical_point(&array32(&proof_bytes[0..32])?)?;
+        let z = canonical_scalar(&array32(&proof_bytes[32..64])?)?;
+
+        let mut t = pk_ownership_transcript(&N::network_id(), account, &pk);
+        append_point(&mut t, labels::SIGMA_A1, &a);
+        let c: Scalar = fs_chal(&mut t, labels::CHAL_PK_OWNERSHIP);
+
+        if !((z * G) - (a + c * pk)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        Ok(())
+    }
+
     // ---------------- Sender path ----------------
     fn verify_transfer_sent(
         asset: &[u8],
@@ -129,13 +275,13 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         to_old_bytes: &[u8],
         delta_ct_bytes: &[u8],
         proof_bundle_bytes: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), Self::Error> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         let from_pk = parse_point32(from_pk_bytes)?;
         let to_pk = parse_point32(to_pk_bytes)?;
         let from_old = parse_point32_allow_empty_identity(from_old_bytes)?;
         let to_old = parse_point32_allow_empty_identity(to_old_bytes)?;
-        let delta_ct = Ciphertext::from_bytes(delta_ct_bytes).map_err(|_| ())?;
-        let proof = TransferProof::parse(proof_bundle_bytes)?;
+        let delta_ct = canonical_ciphertext(delta_ct_bytes)?;
+        let proof = TransferProof::parse_sender(proof_bundle_bytes)?;
 
         // public context
         let asset_id = pad_or_trim_32(asset);
@@ -154,23 +300,23 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
 
         // link Σ-proof
         let (a1, a2, a3, z_k, z_v, z_r) = parse_link_from_192(proof.link_raw.as_bytes())?;
-        append_point(&mut t, b"a1", &a1);
-        append_point(&mut t, b"a2", &a2);
-        append_point(&mut t, b"a3", &a3);
+        append_point(&mut t, labels::SIGMA_A1, &a1);
+        append_point(&mut t, labels::SIGMA_A2, &a2);
+        append_point(&mut t, labels::SIGMA_A3, &a3);
         let c: Scalar = fs_chal(&mut t, labels::CHAL_EQ);
 
         // Eq1: z_k*G == a1 + c*C
         if !((z_k * G) - (a1 + c * delta_ct.C)).is_identity() {
-            return Err(());
+            return Err(VerifierError::LinkProofFailed.into());
         }
         // Eq2: z_v*G + z_k*pk == a2 + c*D
         if !((z_v * G + z_k * from_pk) - (a2 + c * delta_ct.D)).is_identity() {
-            return Err(());
+            return Err(VerifierError::LinkProofFailed.into());
         }
         // Eq3: z_v*G + z_r*H == a3 + c*ΔC
-        let h = pedersen_h_generator();
+        let h = VerifierContext::global().h();
         if !((z_v * G + z_r * h) - (a3 + c * proof.delta_comm)).is_identity() {
-            return Err(());
+            return Err(VerifierError::LinkProofFailed.into());
         }
 
         // compute new commitments
@@ -178,30 +324,162 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         let to_new = to_old + proof.delta_comm;
 
         // optional range proofs
-        let ctx_bytes = transcript_context_bytes(&t);
+        let bits = B::range_bits(asset);
+        let ctx_bytes = range_context_bytes(&t, bits);
         let from_new_bytes = point_to_bytes(&from_new);
         let to_new_bytes = point_to_bytes(&to_new);
 
         if !proof.range_from_new.is_empty() {
-            BulletproofRangeVerifier::verify_range_proof(
-                b"range_from_new",
+            check_range_len(bits, 1, proof.range_from_new)?;
+            R::verify_range_proof(
+                labels::RANGE_FROM_NEW,
                 &ctx_bytes,
                 &from_new_bytes,
                 proof.range_from_new,
-            )?;
+            )
+            .map_err(|_| VerifierError::RangeProofFailed)?;
         }
         if !proof.range_to_new.is_empty() {
-            BulletproofRangeVerifier::verify_range_proof(
-                b"range_to_new",
+            check_range_len(bits, 1, proof.range_to_new)?;
+            R::verify_range_proof(
+                labels::RANGE_TO_NEW,
                 &ctx_bytes,
                 &to_new_bytes,
                 proof.range_to_new,
-            )?;
+            )
+            .map_err(|_| VerifierError::RangeProofFailed)?;
+        }
+
+        let floor = B::transfer_floor(asset);
+        match proof.range_floor {
+            Some(range_floor) => {
+                check_range_len(bits, 1, range_floor)?;
+                let above_floor = point_to_bytes(&(proof.delta_comm - Scalar::from(floor) * G));
+                R::verify_range_proof(
+                    labels::RANGE_AMOUNT_FLOOR,
+                    &ctx_bytes,
+                    &above_floor,
+                    range_floor,
+                )
+                .map_err(|_| VerifierError::RangeProofFailed)?;
+            }
+            None if floor > 0 => return Err(VerifierError::RangeProofFailed.into()),
+            None => {}
         }
 
         Ok((from_new_bytes.to_vec(), to_new_bytes.to_vec()))
     }
 
+    // ---------------- Split sender path ----------------
+    //
+    // proof layout:
+    //   count(2) || total_comm(32) || link(192) || count × (out_comm(32) || link(192))
+    //   || len(2) || rp_from_new || count × (len(2) || rp_out)
+    //
+    // The total link ties `total` (under from_pk) to total_comm, each output link ties
+    // its ciphertext (under the recipient's key) to out_comm. The output commitments
+    // must add up to total_comm, from_old - total_comm must be in range, and so must
+    // each out_comm less the asset's transfer floor.
+    //
+    // returns (from_new_available_commit, out_comm of each output)
+    fn verify_transfer_split(
+        asset: &[u8],
+        from_pk_bytes: &[u8],
+        from_old_bytes: &[u8],
+        total_bytes: &EncryptedAmount,
+        outputs: &[(PublicKeyBytes, EncryptedAmount)],
+        proof_bytes: &[u8],
+    ) -> Result<(Vec<u8>, Vec<[u8; 32]>), VerifyError> {
+        let from_pk = parse_point32(from_pk_bytes)?;
+        let from_old = parse_point32_allow_empty_identity(from_old_bytes)?;
+        let total = canonical_ciphertext(total_bytes.as_ref())?;
+        let outputs = outputs
+            .iter()
+            .map(|(pk, ct)| {
+                Ok((
+                    parse_point32(pk.as_slice())?,
+                    canonical_ciphertext(ct.as_ref())?,
+                ))
+            })
+            .collect::<Result<Vec<_>, VerifierError>>()?;
+        let proof = SplitProof::parse(proof_bytes, outputs.len())?;
+
+        // public context, then every output ciphertext and its key
+        let asset_id = pad_or_trim_32(asset);
+        let ctx = PublicContext {
+            network_id: N::network_id(),
+            sdk_version: SDK_VERSION,
+            asset_id,
+            sender_pk: from_pk,
+            receiver_pk: from_pk,
+            auditor_pk: None,
+            fee_commitment: RistrettoPoint::identity(),
+            ciphertext_out: total,
+            ciphertext_in: None,
+        };
+        let mut t = new_transcript(&ctx);
+        t.append_message(
+            labels::TRANSFER_SPLIT,
+            &(outputs.len() as u16).to_le_bytes(),
+        );
+        for (pk, ct) in &outputs {
+            append_point(&mut t, labels::BIND_RECEIVER_PK, pk);
+            append_point(&mut t, labels::BIND_OUT_C, &ct.C);
+            append_point(&mut t, labels::BIND_OUT_D, &ct.D);
+        }
+
+        // link Σ-proofs and Σ out_comm == total_comm
+        verify_link(
+            &mut t,
+            &proof.total_link,
+            &total,
+            &from_pk,
+            &proof.total_comm,
+        )?;
+        let mut sum = RistrettoPoint::identity();
+        for ((pk, ct), (out_comm, link)) in outputs.iter().zip(proof.outputs.iter()) {
+            verify_link(&mut t, link, ct, pk, out_comm)?;
+            sum += out_comm;
+        }
+        if !points_eq(&sum, &proof.total_comm) {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+
+        // range proofs: the sender's new balance and every output above the floor
+        let from_new = from_old - proof.total_comm;
+        let bits = B::range_bits(asset);
+        let ctx_bytes = range_context_bytes(&t, bits);
+        let from_new_bytes = point_to_bytes(&from_new);
+
+        check_range_len(bits, 1, proof.range_from_new)?;
+        R::verify_range_proof(
+            labels::RANGE_FROM_NEW,
+            &ctx_bytes,
+            &from_new_bytes,
+            proof.range_from_new,
+        )
+        .map_err(|_| VerifierError::RangeProofFailed)?;
+
+        let floor = Scalar::from(B::transfer_floor(asset)) * G;
+        for ((out_comm, _), range) in proof.outputs.iter().zip(proof.range_outputs.iter()) {
+            check_range_len(bits, 1, range)?;
+            R::verify_range_proof(
+                labels::RANGE_SPLIT_OUTPUT,
+                &ctx_bytes,
+                &point_to_bytes(&(out_comm - floor)),
+                range,
+            )
+            .map_err(|_| VerifierError::RangeProofFailed)?;
+        }
+
+        let out_comms = proof
+            .outputs
+            .iter()
+            .map(|(out_comm, _)| point_to_bytes(out_comm))
+            .collect();
+        Ok((from_new_bytes.to_vec(), out_comms))
+    }
+
     // ---------------- Receiver path ----------------
     //
     // The pallet passes the consumed pending UTXOs as compressed Pedersen commitments:
@@ -217,7 +495,7 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         pending_old_bytes: &[u8],
         pending_commits: &[[u8; 32]],
         accept_envelope_bytes: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>), Self::Error> {
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
         let who_pk = parse_point32(who_pk_bytes)?;
         let avail_old = parse_point32_allow_empty_identity(avail_old_bytes)?;
         let pending_old = parse_point32_allow_empty_identity(pending_old_bytes)?;
@@ -226,30 +504,27 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         // 1) Σ pending commitments must equal ΔC
         let mut sum = RistrettoPoint::identity();
         for c_bytes in pending_commits {
-            let c = point_from_bytes(c_bytes).map_err(|_| ())?;
+            let c = canonical_point(c_bytes)?;
             sum += c;
         }
         if !points_eq(&sum, &env.delta_comm) {
-            return Err(());
+            return Err(VerifierError::PendingCommitMismatch.into());
         }
 
         // 2) Acceptance context shared by both range proofs
         let asset_id = pad_or_trim_32(asset);
         let mut t = Transcript::new(labels::PROTOCOL);
-        t.append_message(b"proto", labels::PROTOCOL_V);
-        t.append_message(b"sdk_version", &SDK_VERSION.to_le_bytes());
-        t.append_message(b"network_id", &N::network_id());
-        t.append_message(b"asset_id", &asset_id);
-        append_point(&mut t, b"receiver_pk", &who_pk);
-        append_point(&mut t, b"avail_old", &avail_old);
-        append_point(&mut t, b"pending_old", &pending_old);
-        append_point(&mut t, b"delta_comm", &env.delta_comm);
-
-        let mut ctx_bytes = [0u8; 32];
-        {
-            let mut t2 = t.clone();
-            t2.challenge_bytes(b"ctx", &mut ctx_bytes);
-        }
+        t.append_message(labels::BIND_PROTO, labels::PROTOCOL_V);
+        t.append_message(labels::BIND_SDK_VERSION, &SDK_VERSION.to_le_bytes());
+        t.append_message(labels::BIND_NETWORK_ID, &N::network_id());
+        t.append_message(labels::BIND_ASSET_ID, &asset_id);
+        append_point(&mut t, labels::BIND_RECEIVER_PK, &who_pk);
+        append_point(&mut t, labels::BIND_AVAIL_OLD, &avail_old);
+        append_point(&mut t, labels::BIND_PENDING_OLD, &pending_old);
+        append_point(&mut t, labels::BIND_DELTA_COMM, &env.delta_comm);
+
+        let bits = B::range_bits(asset);
+        let ctx_bytes = range_context_bytes(&t, bits);
 
         // 3) Compute new commitments and verify range proofs
         let avail_new = avail_old + env.delta_comm;
@@ -258,27 +533,352 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         let avail_new_bytes = point_to_bytes(&avail_new);
         let pending_new_bytes = point_to_bytes(&pending_new);
 
-        BulletproofRangeVerifier::verify_range_proof(
-            b"range_avail_new",
+        verify_range_pair::<R>(
+            bits,
+            [labels::RANGE_AVAIL_NEW, labels::RANGE_PENDING_NEW],
+            labels::RANGE_ACCEPT_AGG,
             &ctx_bytes,
-            &avail_new_bytes,
-            env.range_avail_new,
-        )?;
-
-        BulletproofRangeVerifier::verify_range_proof(
-            b"range_pending_new",
-            &ctx_bytes,
-            &pending_new_bytes,
-            env.range_pending_new,
+            [avail_new_bytes, pending_new_bytes],
+            [env.range_avail_new, env.range_pending_new],
         )?;
 
         Ok((avail_new_bytes.to_vec(), pending_new_bytes.to_vec()))
     }
 
-    fn disclose(_asset: &[u8], _who_pk: &[u8], _cipher: &[u8]) -> Result<u64, Self::Error> {
-        // Disclosure requires the secret key which the verifier doesn't have
-        // This is a placeholder that should not be called on-chain
-        Err(())
+    // ---------------- Disclosure path ----------------
+    //
+    // proof layout:
+    //   value_le(8) || A1(32) || A2(32) || z(32)
+    //
+    // DLEQ: z*G == A1 + c*pk  and  z*C == A2 + c*(D - value*G)
+    fn disclose(
+        asset: &[u8],
+        who_pk_bytes: &[u8],
+        cipher_bytes: &[u8],
+        proof_bytes: &[u8],
+    ) -> Result<u64, VerifyError> {
+        let who_pk = parse_point32(who_pk_bytes)?;
+        let ct = canonical_ciphertext(cipher_bytes)?;
+        if proof_bytes.len() < DISCLOSURE_PROOF_LEN {
+            return Err(VerifierError::ProofTooShort.into());
+        }
+        ensure_no_trailing(proof_bytes, DISCLOSURE_PROOF_LEN)?;
+
+        let mut value_le = [0u8; 8];
+        value_le.copy_from_slice(&proof_bytes[0..8]);
+        let value = u64::from_le_bytes(value_le);
+        let a1 = canonical_point(&array32(&proof_bytes[8..40])?)?;
+        let a2 = canonical_point(&array32(&proof_bytes[40..72])?)?;
+        let z = canonical_scalar(&array32(&proof_bytes[72..104])?)?;
+
+        let asset_id = pad_or_trim_32(asset);
+        let mut t = disclosure_transcript(&N::network_id(), &asset_id, &who_pk, &ct, value);
+        append_point(&mut t, labels::SIGMA_A1, &a1);
+        append_point(&mut t, labels::SIGMA_A2, &a2);
+        let c: Scalar = fs_chal(&mut t, labels::CHAL_DISCLOSE);
+
+        // Eq1: z*G == A1 + c*pk
+        if !((z * G) - (a1 + c * who_pk)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq2: z*C == A2 + c*(D - v*G)
+        let shared = ct.D - Scalar::from(value) * G;
+        if !((z * ct.C) - (a2 + c * shared)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+
+        Ok(value)
+    }
+
+    // ---------------- Seizure path ----------------
+    //
+    // proof layout:
+    //   value_le(8) || A1(32) || A2(32) || A3(32) || z(32)
+    //
+    // A disclosure DLEQ over the auditor key: it decrypts `ct` to the value, and
+    // decrypts `blinding` to the H-part of avail once the value is taken out:
+    //   z*G  == A1 + c*auditor_pk
+    //   z*C  == A2 + c*(D - value*G)
+    //   z*Cb == A3 + c*(Db - (avail - value*G))
+    // so avail = value*G + r*H for the r the backend tracked, and needs no range proof.
+    fn verify_seizure(
+        asset: &[u8],
+        auditor_pk_bytes: &[u8],
+        avail_bytes: &[u8],
+        blinding: &EncryptedAmount,
+        ct: &EncryptedAmount,
+        proof_bytes: &[u8],
+    ) -> Result<u64, VerifyError> {
+        let auditor_pk = parse_point32(auditor_pk_bytes)?;
+        // sk = 0 is known to everyone
+        if auditor_pk.is_identity() {
+            return Err(VerifierError::InvalidPoint.into());
+        }
+        let avail = parse_point32_allow_empty_identity(avail_bytes)?;
+        let blinding = canonical_ciphertext(blinding.as_ref())?;
+        let ct = canonical_ciphertext(ct.as_ref())?;
+        if proof_bytes.len() < SEIZURE_PROOF_LEN {
+            return Err(VerifierError::ProofTooShort.into());
+        }
+        ensure_no_trailing(proof_bytes, SEIZURE_PROOF_LEN)?;
+
+        let mut value_le = [0u8; 8];
+        value_le.copy_from_slice(&proof_bytes[0..8]);
+        let value = u64::from_le_bytes(value_le);
+        let a1 = canonical_point(&array32(&proof_bytes[8..40])?)?;
+        let a2 = canonical_point(&array32(&proof_bytes[40..72])?)?;
+        let a3 = canonical_point(&array32(&proof_bytes[72..104])?)?;
+        let z = canonical_scalar(&array32(&proof_bytes[104..136])?)?;
+
+        let asset_id = pad_or_trim_32(asset);
+        let mut t = seizure_transcript(
+            &N::network_id(),
+            &asset_id,
+            &auditor_pk,
+            &avail,
+            &blinding,
+            &ct,
+            value,
+        );
+        append_point(&mut t, labels::SIGMA_A1, &a1);
+        append_point(&mut t, labels::SIGMA_A2, &a2);
+        append_point(&mut t, labels::SIGMA_A3, &a3);
+        let c: Scalar = fs_chal(&mut t, labels::CHAL_SEIZURE);
+
+        let value_g = Scalar::from(value) * G;
+        // Eq1: z*G == A1 + c*auditor_pk
+        if !((z * G) - (a1 + c * auditor_pk)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq2: z*C == A2 + c*(D - v*G)
+        if !((z * ct.C) - (a2 + c * (ct.D - value_g))).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq3: z*Cb == A3 + c*(Db - (avail - v*G))
+        let shared = blinding.D - (avail - value_g);
+        if !((z * blinding.C) - (a3 + c * shared)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+
+        Ok(value)
+    }
+
+    // Account closure: Schnorr proofs of knowledge of r_avail, r_pending with
+    // avail = r_avail·H and pending = r_pending·H, under one challenge.
+    fn verify_zero_balance(
+        asset: &[u8],
+        who_pk_bytes: &[u8],
+        avail_bytes: &[u8],
+        pending_bytes: &[u8],
+        proof_bytes: &[u8],
+    ) -> Result<(), VerifyError> {
+        let who_pk = parse_point32(who_pk_bytes)?;
+        let avail = parse_point32_allow_empty_identity(avail_bytes)?;
+        let pending = parse_point32_allow_empty_identity(pending_bytes)?;
+        if proof_bytes.len() < ZERO_BALANCE_PROOF_LEN {
+            return Err(VerifierError::ProofTooShort.into());
+        }
+        ensure_no_trailing(proof_bytes, ZERO_BALANCE_PROOF_LEN)?;
+
+        let a1 = canonical_point(&array32(&proof_bytes[0..32])?)?;
+        let a2 = canonical_point(&array32(&proof_bytes[32..64])?)?;
+        let z1 = canonical_scalar(&array32(&proof_bytes[64..96])?)?;
+        let z2 = canonical_scalar(&array32(&proof_bytes[96..128])?)?;
+
+        let asset_id = pad_or_trim_32(asset);
+        let mut t = zero_balance_transcript(&N::network_id(), &asset_id, &who_pk, &avail, &pending);
+        append_point(&mut t, labels::SIGMA_A1, &a1);
+        append_point(&mut t, labels::SIGMA_A2, &a2);
+        let c: Scalar = fs_chal(&mut t, labels::CHAL_ZERO_BALANCE);
+
+        // Eq1: z1*H == A1 + c*avail
+        let h = VerifierContext::global().h();
+        if !((z1 * h) - (a1 + c * avail)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq2: z2*H == A2 + c*pending
+        if !((z2 * h) - (a2 + c * pending)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        Ok(())
+    }
+
+    // ---------------- Key rotation path ----------------
+    //
+    // proof layout:
+    //   A_old(32) || A_new(32) || z_old(32) || z_new(32) || ct(64) || link(192)
+    //
+    // Schnorr proofs of both secret keys, plus a link proof that ct = (k·G, v·G + k·new_pk)
+    // with avail = v·G + r·H, all under one challenge.
+    fn verify_rekey(
+        asset: &[u8],
+        account: &[u8],
+        old_pk_bytes: &[u8],
+        new_pk_bytes: &[u8],
+        avail_bytes: &[u8],
+        proof_bytes: &[u8],
+    ) -> Result<EncryptedAmount, VerifyError> {
+        let old_pk = parse_point32(old_pk_bytes)?;
+        let new_pk = parse_point32(new_pk_bytes)?;
+        // sk = 0 is known to everyone
+        if new_pk.is_identity() {
+            return Err(VerifierError::InvalidPoint.into());
+        }
+        let avail = parse_point32_allow_empty_identity(avail_bytes)?;
+        if proof_bytes.len() < REKEY_PROOF_LEN {
+            return Err(VerifierError::ProofTooShort.into());
+        }
+        ensure_no_trailing(proof_bytes, REKEY_PROOF_LEN)?;
+
+        let a4 = canonical_point(&array32(&proof_bytes[0..32])?)?;
+        let a5 = canonical_point(&array32(&proof_bytes[32..64])?)?;
+        let z_old = canonical_scalar(&array32(&proof_bytes[64..96])?)?;
+        let z_new = canonical_scalar(&array32(&proof_bytes[96..128])?)?;
+        let ct = canonical_ciphertext(&proof_bytes[128..192])?;
+        let link: &[u8; 192] = proof_bytes[192..REKEY_PROOF_LEN]
+            .try_into()
+            .map_err(|_| VerifierError::MalformedProof)?;
+        let (a1, a2, a3, z_k, z_v, z_r) = parse_link_from_192(link)?;
+
+        let asset_id = pad_or_trim_32(asset);
+        let mut t = rekey_transcript(
+            &N::network_id(),
+            account,
+            &asset_id,
+            &old_pk,
+            &new_pk,
+            &avail,
+            &ct,
+        );
+        append_point(&mut t, labels::SIGMA_A1, &a1);
+        append_point(&mut t, labels::SIGMA_A2, &a2);
+        append_point(&mut t, labels::SIGMA_A3, &a3);
+        append_point(&mut t, labels::SIGMA_A4, &a4);
+        append_point(&mut t, labels::SIGMA_A5, &a5);
+        let c: Scalar = fs_chal(&mut t, labels::CHAL_REKEY);
+
+        // Eq1: z_old*G == A_old + c*old_pk
+        if !((z_old * G) - (a4 + c * old_pk)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq2: z_new*G == A_new + c*new_pk
+        if !((z_new * G) - (a5 + c * new_pk)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq3: z_k*G == a1 + c*C
+        if !((z_k * G) - (a1 + c * ct.C)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq4: z_v*G + z_k*new_pk == a2 + c*D
+        if !((z_v * G + z_k * new_pk) - (a2 + c * ct.D)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq5: z_v*G + z_r*H == a3 + c*avail
+        let h = VerifierContext::global().h();
+        if !((z_v * G + z_r * h) - (a3 + c * avail)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        Ok(EncryptedAmount::new(ct.to_bytes()))
+    }
+
+    // ---------------- Auditor handle ----------------
+    //
+    // proof layout:
+    //   D_auditor(32) || A1(32) || A2(32) || z(32) ||
+    //   Cb(32) || Db(32) || A3(32) || A4(32) || A5(32) || z_v(32) || z_r(32) || z_b(32)
+    //
+    // A Chaum–Pedersen proof that the nonce k of C = k*G also gives
+    // D - D_auditor = k*(pk - auditor_pk), so (C, D_auditor) encrypts the value of
+    // (C, D) under auditor_pk. Under the same challenge, (Cb, Db) = (b*G, r*H +
+    // b*auditor_pk) encrypts the blinding r of delta = v*G + r*H:
+    //   z_v*G + z_r*H           == A3 + c*delta
+    //   z_b*G                   == A4 + c*Cb
+    //   z_r*H + z_b*auditor_pk  == A5 + c*Db
+    //
+    // returns (C || D_auditor, Cb || Db)
+    fn verify_auditor_handle(
+        asset: &[u8],
+        pk_bytes: &[u8],
+        auditor_pk_bytes: &[u8],
+        ct: &EncryptedAmount,
+        delta: (&[u8], &[u8]),
+        proof_bytes: &[u8],
+    ) -> Result<(EncryptedAmount, EncryptedAmount), VerifyError> {
+        let pk = parse_point32(pk_bytes)?;
+        let auditor_pk = parse_point32(auditor_pk_bytes)?;
+        // sk = 0 is known to everyone
+        if auditor_pk.is_identity() {
+            return Err(VerifierError::InvalidPoint.into());
+        }
+        let ct = canonical_ciphertext(ct.as_ref())?;
+        let delta = parse_point32_allow_empty_identity(delta.0)?
+            - parse_point32_allow_empty_identity(delta.1)?;
+        if proof_bytes.len() < AUDITOR_PROOF_LEN {
+            return Err(VerifierError::ProofTooShort.into());
+        }
+        ensure_no_trailing(proof_bytes, AUDITOR_PROOF_LEN)?;
+
+        let auditor_d = canonical_point(&array32(&proof_bytes[0..32])?)?;
+        let a1 = canonical_point(&array32(&proof_bytes[32..64])?)?;
+        let a2 = canonical_point(&array32(&proof_bytes[64..96])?)?;
+        let z = canonical_scalar(&array32(&proof_bytes[96..128])?)?;
+        let blinding = canonical_ciphertext(&proof_bytes[128..192])?;
+        let a3 = canonical_point(&array32(&proof_bytes[192..224])?)?;
+        let a4 = canonical_point(&array32(&proof_bytes[224..256])?)?;
+        let a5 = canonical_point(&array32(&proof_bytes[256..288])?)?;
+        let z_v = canonical_scalar(&array32(&proof_bytes[288..320])?)?;
+        let z_r = canonical_scalar(&array32(&proof_bytes[320..352])?)?;
+        let z_b = canonical_scalar(&array32(&proof_bytes[352..384])?)?;
+
+        let asset_id = pad_or_trim_32(asset);
+        let mut t = auditor_transcript(
+            &N::network_id(),
+            &asset_id,
+            &pk,
+            &auditor_pk,
+            &ct,
+            &auditor_d,
+            &delta,
+            &blinding,
+        );
+        append_point(&mut t, labels::SIGMA_A1, &a1);
+        append_point(&mut t, labels::SIGMA_A2, &a2);
+        append_point(&mut t, labels::SIGMA_A3, &a3);
+        append_point(&mut t, labels::SIGMA_A4, &a4);
+        append_point(&mut t, labels::SIGMA_A5, &a5);
+        let c: Scalar = fs_chal(&mut t, labels::CHAL_AUDITOR);
+
+        // Eq1: z*G == A1 + c*C
+        if !((z * G) - (a1 + c * ct.C)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq2: z*(pk - auditor_pk) == A2 + c*(D - D_auditor)
+        if !((z * (pk - auditor_pk)) - (a2 + c * (ct.D - auditor_d))).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        let h = VerifierContext::global().h();
+        // Eq3: z_v*G + z_r*H == A3 + c*delta
+        if !((z_v * G + z_r * h) - (a3 + c * delta)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq4: z_b*G == A4 + c*Cb
+        if !((z_b * G) - (a4 + c * blinding.C)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        // Eq5: z_r*H + z_b*auditor_pk == A5 + c*Db
+        if !((z_r * h + z_b * auditor_pk) - (a5 + c * blinding.D)).is_identity() {
+            return Err(VerifierError::LinkProofFailed.into());
+        }
+        Ok((
+            EncryptedAmount::new(
+                Ciphertext {
+                    C: ct.C,
+                    D: auditor_d,
+                }
+                .to_bytes(),
+            ),
+            EncryptedAmount::new(blinding.to_bytes()),
+        ))
     }
 
     // ---------------- Mint path ----------------
@@ -293,7 +893,7 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         to_old_pending_bytes: &[u8],
         total_old_bytes: &[u8],
         proof_bytes: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), Self::Error> {
+    ) -> Result<(Vec<u8>, Vec<u8>, EncryptedAmount), VerifyError> {
         // parse keys/olds
         let to_pk = parse_point32(to_pk_bytes.as_slice())?;
         let to_old = parse_point32_allow_empty_identity(to_old_pending_bytes)?;
@@ -301,17 +901,13 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
 
         // parse proof blob
         if proof_bytes.len() < MINT_PROOF_MIN_LEN {
-            return Err(());
+            return Err(VerifierError::ProofTooShort.into());
         }
-        let minted_ct = {
-            let ct =
-                zkhe_primitives::Ciphertext::from_bytes(&proof_bytes[0..64]).map_err(|_| ())?;
-            ct
-        };
+        let minted_ct = canonical_ciphertext(&proof_bytes[0..64])?;
         let delta_comm = {
             let mut b = [0u8; 32];
             b.copy_from_slice(&proof_bytes[64..96]);
-            point_from_bytes(&b).map_err(|_| ())?
+            canonical_point(&b)?
         };
         let link_raw = {
             let mut a = [0u8; 192];
@@ -322,12 +918,12 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         // offsets for range proofs
         let mut off = 96 + 192;
         if proof_bytes.len() < off + 2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort.into());
         }
         let len1 = u16::from_le_bytes([proof_bytes[off], proof_bytes[off + 1]]) as usize;
         off += 2;
         if proof_bytes.len() < off + len1 + 2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort.into());
         }
         let rp_to_pending_new = &proof_bytes[off..off + len1];
         off += len1;
@@ -335,9 +931,10 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         let len2 = u16::from_le_bytes([proof_bytes[off], proof_bytes[off + 1]]) as usize;
         off += 2;
         if proof_bytes.len() < off + len2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort.into());
         }
         let rp_total_new = &proof_bytes[off..off + len2];
+        ensure_no_trailing(proof_bytes, off + len2)?;
 
         // Public context (reuse sender-style transcript, binding ciphertext_out)
         let asset_id = pad_or_trim_32(asset);
@@ -356,23 +953,23 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
 
         // link proof check (same equations as sender path)
         let (a1, a2, a3, z_k, z_v, z_r) = parse_link_from_192(&link_raw)?;
-        append_point(&mut t, b"a1", &a1);
-        append_point(&mut t, b"a2", &a2);
-        append_point(&mut t, b"a3", &a3);
+        append_point(&mut t, labels::SIGMA_A1, &a1);
+        append_point(&mut t, labels::SIGMA_A2, &a2);
+        append_point(&mut t, labels::SIGMA_A3, &a3);
         let c: Scalar = fs_chal(&mut t, labels::CHAL_EQ);
 
         // Eq1: z_k*G == a1 + c*C
         if !((z_k * G) - (a1 + c * minted_ct.C)).is_identity() {
-            return Err(());
+            return Err(VerifierError::LinkProofFailed.into());
         }
         // Eq2: z_v*G + z_k*to_pk == a2 + c*D
         if !((z_v * G + z_k * to_pk) - (a2 + c * minted_ct.D)).is_identity() {
-            return Err(());
+            return Err(VerifierError::LinkProofFailed.into());
         }
         // Eq3: z_v*G + z_r*H == a3 + c*ΔC
-        let h = pedersen_h_generator();
+        let h = VerifierContext::global().h();
         if !((z_v * G + z_r * h) - (a3 + c * delta_comm)).is_identity() {
-            return Err(());
+            return Err(VerifierError::LinkProofFailed.into());
         }
 
         // compute new commits
@@ -380,28 +977,24 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         let total_new = total_old + delta_comm;
 
         // verify range proofs for both new commitments
-        let ctx_bytes = transcript_context_bytes(&t);
+        let bits = B::range_bits(asset);
+        let ctx_bytes = range_context_bytes(&t, bits);
         let to_new_bytes = point_to_bytes(&to_new);
         let total_new_bytes = point_to_bytes(&total_new);
 
-        BulletproofRangeVerifier::verify_range_proof(
-            b"range_to_pending_new",
+        verify_range_pair::<R>(
+            bits,
+            [labels::RANGE_TO_PENDING_NEW, labels::RANGE_TOTAL_NEW],
+            labels::RANGE_MINT_AGG,
             &ctx_bytes,
-            &to_new_bytes,
-            rp_to_pending_new,
-        )?;
-
-        BulletproofRangeVerifier::verify_range_proof(
-            b"range_total_new",
-            &ctx_bytes,
-            &total_new_bytes,
-            rp_total_new,
+            [to_new_bytes, total_new_bytes],
+            [rp_to_pending_new, rp_total_new],
         )?;
 
         Ok((
             to_new_bytes.to_vec(),
             total_new_bytes.to_vec(),
-            minted_ct.to_bytes(),
+            EncryptedAmount::new(minted_ct.to_bytes()),
         ))
     }
 
@@ -418,120 +1011,153 @@ impl<N: NetworkIdProvider> ZkVerifier for ZkheVerifier<N> {
         total_old_bytes: &[u8],
         amount_ciphertext_bytes: &EncryptedAmount,
         proof_bytes: &[u8],
-    ) -> Result<(Vec<u8>, Vec<u8>, u64), Self::Error> {
-        // parse inputs
-        let from_pk = parse_point32(from_pk_bytes.as_slice())?;
-        let from_old = parse_point32_allow_empty_identity(from_old_available_bytes)?;
-        let total_old = parse_point32_allow_empty_identity(total_old_bytes)?;
-        let amount_ct = zkhe_primitives::Ciphertext::from_bytes(&amount_ciphertext_bytes[..])
-            .map_err(|_| ())?;
-
-        // parse proof blob
+    ) -> Result<(Vec<u8>, Vec<u8>, u64), VerifyError> {
         if proof_bytes.len() < 32 + 192 + 2 + 2 + 8 {
-            return Err(());
-        }
-        let delta_comm = {
-            let mut b = [0u8; 32];
-            b.copy_from_slice(&proof_bytes[0..32]);
-            point_from_bytes(&b).map_err(|_| ())?
-        };
-        let link_raw = {
-            let mut a = [0u8; 192];
-            a.copy_from_slice(&proof_bytes[32..32 + 192]);
-            a
-        };
-
-        let mut off = 32 + 192;
-        if proof_bytes.len() < off + 2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort.into());
         }
-        let len1 = u16::from_le_bytes([proof_bytes[off], proof_bytes[off + 1]]) as usize;
-        off += 2;
-        if proof_bytes.len() < off + len1 + 2 {
-            return Err(());
-        }
-        let rp_from_avail_new = &proof_bytes[off..off + len1];
-        off += len1;
+        let (body, amount_le) = proof_bytes.split_at(proof_bytes.len() - 8);
+        let disclosed = u64::from_le_bytes(amount_le.try_into().expect("8 bytes"));
+
+        let (from_new_bytes, total_new_bytes) = verify_burn_body::<N, R, B>(
+            asset,
+            from_pk_bytes,
+            from_old_available_bytes,
+            total_old_bytes,
+            amount_ciphertext_bytes,
+            body,
+            BurnKind::Disclosed,
+        )?;
 
-        let len2 = u16::from_le_bytes([proof_bytes[off], proof_bytes[off + 1]]) as usize;
-        off += 2;
-        if proof_bytes.len() < off + len2 + 8 {
-            return Err(());
-        }
-        let rp_total_new = &proof_bytes[off..off + len2];
-        off += len2;
+        Ok((from_new_bytes.to_vec(), total_new_bytes.to_vec(), disclosed))
+    }
 
-        let mut amount_le = [0u8; 8];
-        amount_le.copy_from_slice(&proof_bytes[off..off + 8]);
-        let disclosed = u64::from_le_bytes(amount_le);
+    // ---------------- Confidential burn path ----------------
+    //
+    // proof layout:
+    //   delta_comm(32) || link(192) || len1(2) || rp_from_avail_new || len2(2) || rp_total_new
+    //
+    // returns (from_new_available_commit, total_new_commit)
+    fn verify_confidential_burn(
+        asset: &[u8],
+        from_pk_bytes: &PublicKeyBytes,
+        from_old_available_bytes: &[u8],
+        total_old_bytes: &[u8],
+        amount_ciphertext_bytes: &EncryptedAmount,
+        proof_bytes: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), VerifyError> {
+        let (from_new_bytes, total_new_bytes) = verify_burn_body::<N, R, B>(
+            asset,
+            from_pk_bytes,
+            from_old_available_bytes,
+            total_old_bytes,
+            amount_ciphertext_bytes,
+            proof_bytes,
+            BurnKind::Confidential,
+        )?;
 
-        // Public context (bind to ciphertext_out = amount_ct under from_pk)
-        let asset_id = pad_or_trim_32(asset);
-        let ctx = zkhe_primitives::PublicContext {
-            network_id: N::network_id(),
-            sdk_version: SDK_VERSION,
-            asset_id,
-            sender_pk: from_pk,
-            receiver_pk: from_pk,
-            auditor_pk: None,
-            fee_commitment: RistrettoPoint::identity(),
-            ciphertext_out: amount_ct,
-            ciphertext_in: None,
-        };
-        let mut t = new_transcript(&ctx);
+        Ok((from_new_bytes.to_vec(), total_new_bytes.to_vec()))
+    }
+}
 
-        // link proof check (same equations)
-        let (a1, a2, a3, z_k, z_v, z_r) = parse_link_from_192(&link_raw)?;
-        append_point(&mut t, b"a1", &a1);
-        append_point(&mut t, b"a2", &a2);
-        append_point(&mut t, b"a3", &a3);
-        let c: Scalar = fs_chal(&mut t, labels::CHAL_EQ);
+/// Whether a burn discloses its amount. Confidential burns use their own transcript
+/// domain, so neither kind of proof verifies as the other.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BurnKind {
+    Disclosed,
+    Confidential,
+}
 
-        // Eq1: z_k*G == a1 + c*C
-        if !((z_k * G) - (a1 + c * amount_ct.C)).is_identity() {
-            return Err(());
-        }
-        // Eq2: z_v*G + z_k*from_pk == a2 + c*D
-        if !((z_v * G + z_k * from_pk) - (a2 + c * amount_ct.D)).is_identity() {
-            return Err(());
-        }
-        // Eq3: z_v*G + z_r*H == a3 + c*ΔC
-        let h = pedersen_h_generator();
-        if !((z_v * G + z_r * h) - (a3 + c * delta_comm)).is_identity() {
-            return Err(());
+/// Check a burn proof without its trailing amount:
+/// delta_comm(32) || link(192) || len1(2) || rp_from_avail_new || len2(2) || rp_total_new
+///
+/// Returns the new available and total-supply commitments (old - ΔC).
+fn verify_burn_body<N: NetworkIdProvider, R: RangeProofVerifier, B: RangeBitsProvider>(
+    asset: &[u8],
+    from_pk_bytes: &PublicKeyBytes,
+    from_old_available_bytes: &[u8],
+    total_old_bytes: &[u8],
+    amount_ciphertext_bytes: &EncryptedAmount,
+    proof_bytes: &[u8],
+    kind: BurnKind,
+) -> Result<([u8; 32], [u8; 32]), VerifyError> {
+    // parse inputs
+    let from_pk = parse_point32(from_pk_bytes.as_slice())?;
+    let from_old = parse_point32_allow_empty_identity(from_old_available_bytes)?;
+    let total_old = parse_point32_allow_empty_identity(total_old_bytes)?;
+    let amount_ct = canonical_ciphertext(amount_ciphertext_bytes.as_ref())?;
+
+    // parse proof blob
+    let proof = TransferProof::parse(proof_bytes)?;
+
+    // Public context (bind to ciphertext_out = amount_ct under from_pk)
+    let asset_id = pad_or_trim_32(asset);
+    let ctx = zkhe_primitives::PublicContext {
+        network_id: N::network_id(),
+        sdk_version: SDK_VERSION,
+        asset_id,
+        sender_pk: from_pk,
+        receiver_pk: from_pk,
+        auditor_pk: None,
+        fee_commitment: RistrettoPoint::identity(),
+        ciphertext_out: amount_ct,
+        ciphertext_in: None,
+    };
+    let mut t = new_transcript(&ctx);
+    let agg_label = match kind {
+        BurnKind::Disclosed => labels::RANGE_BURN_AGG,
+        BurnKind::Confidential => {
+            t.append_message(labels::CONFIDENTIAL_BURN, &[]);
+            labels::RANGE_CONFIDENTIAL_BURN_AGG
         }
-
-        // compute new commits (subtract Δ)
-        let from_new = from_old - delta_comm;
-        let total_new = total_old - delta_comm;
-
-        // verify ranges
-        let ctx_bytes = transcript_context_bytes(&t);
-        let from_new_bytes = point_to_bytes(&from_new);
-        let total_new_bytes = point_to_bytes(&total_new);
-
-        BulletproofRangeVerifier::verify_range_proof(
-            b"range_from_avail_new",
-            &ctx_bytes,
-            &from_new_bytes,
-            rp_from_avail_new,
-        )?;
-
-        BulletproofRangeVerifier::verify_range_proof(
-            b"range_total_new",
-            &ctx_bytes,
-            &total_new_bytes,
-            rp_total_new,
-        )?;
-
-        Ok((from_new_bytes.to_vec(), total_new_bytes.to_vec(), disclosed))
+    };
+
+    // link proof check (same equations)
+    let (a1, a2, a3, z_k, z_v, z_r) = parse_link_from_192(proof.link_raw.as_bytes())?;
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    append_point(&mut t, labels::SIGMA_A3, &a3);
+    let c: Scalar = fs_chal(&mut t, labels::CHAL_EQ);
+
+    // Eq1: z_k*G == a1 + c*C
+    if !((z_k * G) - (a1 + c * amount_ct.C)).is_identity() {
+        return Err(VerifierError::LinkProofFailed.into());
+    }
+    // Eq2: z_v*G + z_k*from_pk == a2 + c*D
+    if !((z_v * G + z_k * from_pk) - (a2 + c * amount_ct.D)).is_identity() {
+        return Err(VerifierError::LinkProofFailed.into());
     }
+    // Eq3: z_v*G + z_r*H == a3 + c*ΔC
+    let h = VerifierContext::global().h();
+    if !((z_v * G + z_r * h) - (a3 + c * proof.delta_comm)).is_identity() {
+        return Err(VerifierError::LinkProofFailed.into());
+    }
+
+    // compute new commits (subtract Δ)
+    let from_new = from_old - proof.delta_comm;
+    let total_new = total_old - proof.delta_comm;
+
+    // verify ranges
+    let bits = B::range_bits(asset);
+    let ctx_bytes = range_context_bytes(&t, bits);
+    let from_new_bytes = point_to_bytes(&from_new);
+    let total_new_bytes = point_to_bytes(&total_new);
+
+    verify_range_pair::<R>(
+        bits,
+        [labels::RANGE_FROM_AVAIL_NEW, labels::RANGE_TOTAL_NEW],
+        agg_label,
+        &ctx_bytes,
+        [from_new_bytes, total_new_bytes],
+        [proof.range_from_new, proof.range_to_new],
+    )?;
+
+    Ok((from_new_bytes, total_new_bytes))
 }
 
 // ---------------- Proof byte “contracts” ----------------
 
 /// 192-byte link-proof: A1(32)||A2(32)||A3(32)||z_k(32)||z_v(32)||z_r(32)
-type LinkProofBytes = FixedProof<192>;
+type LinkProofBytes = FixedProof<LINK_PROOF_LEN>;
 
 /// Sender bundle: delta_comm(32) || link(192) || len1(2) || range_from || len2(2) || range_to
 struct TransferProof<'a> {
@@ -539,21 +1165,33 @@ struct TransferProof<'a> {
     link_raw: LinkProofBytes,
     range_from_new: &'a [u8],
     range_to_new: &'a [u8],
+    /// Sender bundles only.
+    range_floor: Option<&'a [u8]>,
 }
 
 impl<'a> TransferProof<'a> {
-    fn parse(bytes: &'a [u8]) -> Result<Self, ()> {
+    fn parse(bytes: &'a [u8]) -> Result<Self, VerifierError> {
+        Self::parse_sections(bytes, false)
+    }
+
+    /// A sender bundle, which may end with a `len3(2) || rp_floor` section.
+    fn parse_sender(bytes: &'a [u8]) -> Result<Self, VerifierError> {
+        Self::parse_sections(bytes, true)
+    }
+
+    fn parse_sections(bytes: &'a [u8], floor: bool) -> Result<Self, VerifierError> {
         if bytes.len() < 32 + 192 + 2 + 2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort);
         }
-        let delta_comm = point_from_bytes(&array32(&bytes[0..32])?).map_err(|_| ())?;
-        let link_raw = LinkProofBytes::from_slice(&bytes[32..32 + 192]).map_err(|_| ())?;
+        let delta_comm = canonical_point(&array32(&bytes[0..32])?)?;
+        let link_raw = LinkProofBytes::from_slice(&bytes[32..32 + 192])
+            .map_err(|_| VerifierError::MalformedProof)?;
 
         let mut off = 32 + 192;
         let len1 = u16::from_le_bytes([bytes[off], bytes[off + 1]]) as usize;
         off += 2;
         if bytes.len() < off + len1 + 2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort);
         }
         let range1 = &bytes[off..off + len1];
         off += len1;
@@ -561,15 +1199,95 @@ impl<'a> TransferProof<'a> {
         let len2 = u16::from_le_bytes([bytes[off], bytes[off + 1]]) as usize;
         off += 2;
         if bytes.len() < off + len2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort);
         }
         let range2 = &bytes[off..off + len2];
+        off += len2;
+
+        let mut range3 = None;
+        if floor && bytes.len() >= off + 2 {
+            let len3 = u16::from_le_bytes([bytes[off], bytes[off + 1]]) as usize;
+            off += 2;
+            if bytes.len() < off + len3 {
+                return Err(VerifierError::ProofTooShort);
+            }
+            range3 = Some(&bytes[off..off + len3]);
+            off += len3;
+        }
+        ensure_no_trailing(bytes, off)?;
 
         Ok(Self {
             delta_comm,
             link_raw,
             range_from_new: range1,
             range_to_new: range2,
+            range_floor: range3,
+        })
+    }
+}
+
+/// Split transfer proof:
+/// count(2) || total_comm(32) || link(192) || count × (out_comm(32) || link(192))
+/// || len(2) || rp_from_new || count × (len(2) || rp_out)
+struct SplitProof<'a> {
+    total_comm: RistrettoPoint,
+    total_link: LinkProofBytes,
+    /// `(out_comm, link)` of each output, in order.
+    outputs: Vec<(RistrettoPoint, LinkProofBytes)>,
+    range_from_new: &'a [u8],
+    range_outputs: Vec<&'a [u8]>,
+}
+
+impl<'a> SplitProof<'a> {
+    /// Parse a proof that must cover exactly `outputs` outputs.
+    fn parse(bytes: &'a [u8], outputs: usize) -> Result<Self, VerifierError> {
+        if bytes.len() < 2 {
+            return Err(VerifierError::ProofTooShort);
+        }
+        let count = u16::from_le_bytes([bytes[0], bytes[1]]) as usize;
+        if count == 0 || count != outputs {
+            return Err(VerifierError::MalformedProof);
+        }
+
+        // the total's part, then one per output
+        let mut off = 2;
+        let mut parts = Vec::with_capacity(count + 1);
+        for _ in 0..=count {
+            let part = bytes
+                .get(off..off + SPLIT_PART_LEN)
+                .ok_or(VerifierError::ProofTooShort)?;
+            let comm = canonical_point(&array32(&part[..32])?)?;
+            let link = LinkProofBytes::from_slice(&part[32..])
+                .map_err(|_| VerifierError::MalformedProof)?;
+            parts.push((comm, link));
+            off += SPLIT_PART_LEN;
+        }
+
+        // the sender's range section, then one per output
+        let mut ranges = Vec::with_capacity(count + 1);
+        for _ in 0..=count {
+            let prefix = bytes
+                .get(off..off + 2)
+                .ok_or(VerifierError::ProofTooShort)?;
+            let len = u16::from_le_bytes([prefix[0], prefix[1]]) as usize;
+            off += 2;
+            ranges.push(
+                bytes
+                    .get(off..off + len)
+                    .ok_or(VerifierError::ProofTooShort)?,
+            );
+            off += len;
+        }
+        ensure_no_trailing(bytes, off)?;
+
+        let (total_comm, total_link) = parts.remove(0);
+        let range_from_new = ranges.remove(0);
+        Ok(Self {
+            total_comm,
+            total_link,
+            outputs: parts,
+            range_from_new,
+            range_outputs: ranges,
         })
     }
 }
@@ -583,17 +1301,17 @@ struct AcceptEnvelope<'a> {
 }
 
 impl<'a> AcceptEnvelope<'a> {
-    fn parse(bytes: &'a [u8]) -> Result<Self, ()> {
+    fn parse(bytes: &'a [u8]) -> Result<Self, VerifierError> {
         if bytes.len() < 32 + 2 + 2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort);
         }
-        let delta_comm = point_from_bytes(&array32(&bytes[0..32])?).map_err(|_| ())?;
+        let delta_comm = canonical_point(&array32(&bytes[0..32])?)?;
 
         let mut off = 32;
         let len1 = u16::from_le_bytes([bytes[off], bytes[off + 1]]) as usize;
         off += 2;
         if bytes.len() < off + len1 + 2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort);
         }
         let rp1 = &bytes[off..off + len1];
         off += len1;
@@ -601,9 +1319,10 @@ impl<'a> AcceptEnvelope<'a> {
         let len2 = u16::from_le_bytes([bytes[off], bytes[off + 1]]) as usize;
         off += 2;
         if bytes.len() < off + len2 {
-            return Err(());
+            return Err(VerifierError::ProofTooShort);
         }
         let rp2 = &bytes[off..off + len2];
+        ensure_no_trailing(bytes, off + len2)?;
 
         Ok(Self {
             delta_comm,
@@ -615,16 +1334,136 @@ impl<'a> AcceptEnvelope<'a> {
 
 // ---------------- Helpers ----------------
 
-fn parse_point32(bytes: &[u8]) -> Result<RistrettoPoint, ()> {
+/// Verify the two range proofs of an accept/mint/burn proof.
+///
+/// An empty first section means the second holds a single aggregated proof
+/// over both commitments (under `agg_label`); otherwise each section is a
+/// separate single-value proof. Every proof must be `bits` wide.
+fn verify_range_pair<R: RangeProofVerifier>(
+    bits: u8,
+    labels: [&[u8]; 2],
+    agg_label: &[u8],
+    ctx_bytes: &[u8],
+    commits: [[u8; 32]; 2],
+    proofs: [&[u8]; 2],
+) -> Result<(), VerifierError> {
+    if proofs[0].is_empty() {
+        check_range_len(bits, 2, proofs[1])?;
+        return R::verify_aggregated_range_proof(agg_label, ctx_bytes, &commits, proofs[1])
+            .map_err(|_| VerifierError::RangeProofFailed);
+    }
+    for ((label, commit), proof) in labels.iter().zip(commits.iter()).zip(proofs.iter()) {
+        check_range_len(bits, 1, proof)?;
+        R::verify_range_proof(label, ctx_bytes, commit, proof)
+            .map_err(|_| VerifierError::RangeProofFailed)?;
+    }
+    Ok(())
+}
+
+/// A range proof over `values` commitments must have the length of a `bits`-wide one,
+/// so a proof made for another width never reaches the range verifier.
+fn check_range_len(bits: u8, values: usize, proof: &[u8]) -> Result<(), VerifierError> {
+    if !is_supported_range_bits(bits) || proof.len() != range_proof_len(bits, values) {
+        return Err(VerifierError::RangeProofFailed);
+    }
+    Ok(())
+}
+
+/// Decode a compressed point, rejecting any encoding other than the canonical one.
+fn canonical_point(bytes: &[u8; 32]) -> Result<RistrettoPoint, VerifierError> {
+    let p = point_from_bytes(bytes).map_err(|_| VerifierError::InvalidPoint)?;
+    if point_to_bytes(&p) != *bytes {
+        return Err(VerifierError::NonCanonical);
+    }
+    Ok(p)
+}
+
+/// Decode a scalar, rejecting encodings ≥ ℓ (which would make proofs malleable).
+fn canonical_scalar(bytes: &[u8; 32]) -> Result<Scalar, VerifierError> {
+    scalar_from_canonical(bytes).map_err(|_| VerifierError::NonCanonical)
+}
+
+/// Decode a 64-byte ciphertext with both halves in canonical form.
+fn canonical_ciphertext(bytes: &[u8]) -> Result<Ciphertext, VerifierError> {
+    let ct = Ciphertext::from_bytes(bytes).map_err(|_| VerifierError::InvalidCiphertext)?;
+    if ct.to_bytes()[..] != *bytes {
+        return Err(VerifierError::NonCanonical);
+    }
+    Ok(ct)
+}
+
+/// The points a `kind` body opens with (ΔC, the minted ciphertext, a Schnorr nonce
+/// commitment), checked by `validate_shape` so malformed points fail before any
+/// storage is read. `body` must already have the shape of `kind`.
+fn leading_points(kind: ProofKind, body: &[u8]) -> &[u8] {
+    let start = match kind {
+        ProofKind::Claim => 2 + 8 * u16::from_le_bytes([body[0], body[1]]) as usize,
+        ProofKind::TransferSplit => 2,
+        ProofKind::Seizure => 8,
+        _ => 0,
+    };
+    let len = match kind {
+        ProofKind::Transfer
+        | ProofKind::Accept
+        | ProofKind::Claim
+        | ProofKind::Burn
+        | ProofKind::ConfidentialBurn
+        | ProofKind::TransferSplit
+        | ProofKind::PubkeyOwnership => 32,
+        ProofKind::Mint | ProofKind::Seizure => 96,
+        _ => 0,
+    };
+    &body[start..start + len]
+}
+
+/// Check a link Σ-proof that `ct = (k·G, v·G + k·pk)` and `comm = v·G + r·H` hide
+/// the same `v`, under a challenge drawn from `t` after binding `comm`.
+fn verify_link(
+    t: &mut Transcript,
+    link: &LinkProofBytes,
+    ct: &Ciphertext,
+    pk: &RistrettoPoint,
+    comm: &RistrettoPoint,
+) -> Result<(), VerifierError> {
+    append_point(t, labels::BIND_DELTA_COMM, comm);
+    let (a1, a2, a3, z_k, z_v, z_r) = parse_link_from_192(link.as_bytes())?;
+    append_point(t, labels::SIGMA_A1, &a1);
+    append_point(t, labels::SIGMA_A2, &a2);
+    append_point(t, labels::SIGMA_A3, &a3);
+    let c: Scalar = fs_chal(t, labels::CHAL_EQ);
+
+    // Eq1: z_k*G == a1 + c*C
+    if !((z_k * G) - (a1 + c * ct.C)).is_identity() {
+        return Err(VerifierError::LinkProofFailed);
+    }
+    // Eq2: z_v*G + z_k*pk == a2 + c*D
+    if !((z_v * G + z_k * pk) - (a2 + c * ct.D)).is_identity() {
+        return Err(VerifierError::LinkProofFailed);
+    }
+    // Eq3: z_v*G + z_r*H == a3 + c*comm
+    let h = VerifierContext::global().h();
+    if !((z_v * G + z_r * h) - (a3 + c * comm)).is_identity() {
+        return Err(VerifierError::LinkProofFailed);
+    }
+    Ok(())
+}
+
+/// Proof encodings must be unique: nothing may follow the last section.
+fn ensure_no_trailing(bytes: &[u8], end: usize) -> Result<(), VerifierError> {
+    if bytes.len() != end {
+        return Err(VerifierError::TrailingBytes);
+    }
+    Ok(())
+}
+
+fn parse_point32(bytes: &[u8]) -> Result<RistrettoPoint, VerifierError> {
     if bytes.len() != 32 {
-        return Err(());
+        return Err(VerifierError::InvalidPoint);
     }
-    let mut b = [0u8; 32];
-    b.copy_from_slice(bytes);
-    point_from_bytes(&b).map_err(|_| ())
+    canonical_point(&array32(bytes)?)
 }
 
-fn parse_point32_allow_empty_identity(bytes: &[u8]) -> Result<RistrettoPoint, ()> {
+fn parse_point32_allow_empty_identity(bytes: &[u8]) -> Result<RistrettoPoint, VerifierError> {
     if bytes.is_empty() {
         Ok(RistrettoPoint::identity())
     } else {
@@ -643,33 +1482,26 @@ fn parse_link_from_192(
         Scalar,
         Scalar,
     ),
-    (),
+    VerifierError,
 > {
-    let a1 = point_from_bytes(&array32(&raw[0..32])?).map_err(|_| ())?;
-    let a2 = point_from_bytes(&array32(&raw[32..64])?).map_err(|_| ())?;
-    let a3 = point_from_bytes(&array32(&raw[64..96])?).map_err(|_| ())?;
-    let z_k = Scalar::from_bytes_mod_order(array32(&raw[96..128])?);
-    let z_v = Scalar::from_bytes_mod_order(array32(&raw[128..160])?);
-    let z_r = Scalar::from_bytes_mod_order(array32(&raw[160..192])?);
+    let a1 = canonical_point(&array32(&raw[0..32])?)?;
+    let a2 = canonical_point(&array32(&raw[32..64])?)?;
+    let a3 = canonical_point(&array32(&raw[64..96])?)?;
+    let z_k = canonical_scalar(&array32(&raw[96..128])?)?;
+    let z_v = canonical_scalar(&array32(&raw[128..160])?)?;
+    let z_r = canonical_scalar(&array32(&raw[160..192])?)?;
     Ok((a1, a2, a3, z_k, z_v, z_r))
 }
 
-fn array32(slice: &[u8]) -> Result<[u8; 32], ()> {
+fn array32(slice: &[u8]) -> Result<[u8; 32], VerifierError> {
     if slice.len() != 32 {
-        return Err(());
+        return Err(VerifierError::MalformedProof);
     }
     let mut out = [0u8; 32];
     out.copy_from_slice(slice);
     Ok(out)
 }
 
-fn transcript_context_bytes(t: &Transcript) -> [u8; 32] {
-    let mut tr = t.clone();
-    let mut out = [0u8; 32];
-    tr.challenge_bytes(b"ctx", &mut out);
-    out
-}
-
 fn pad_or_trim_32(x: &[u8]) -> [u8; 32] {
     let mut out = [0u8; 32];
     if x.len() >= 32 {
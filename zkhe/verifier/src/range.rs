@@ -3,7 +3,9 @@
 use alloc::string::String;
 use core::result::Result;
 use merlin::Transcript;
-use zkhe_primitives::RangeProofVerifier;
+use zkhe_primitives::{RangeProofVerifier, SUPPORTED_RANGE_BITS, labels, range_proof_len};
+
+use crate::context::{MAX_AGGREGATION, VerifierContext};
 
 // --- DEBUG UTILITIES (enabled only in debug builds with std/test) ---
 // Only output debug info when debug_assertions are enabled AND std feature is available.
@@ -34,17 +36,10 @@ fn hex(_: &[u8]) -> String {
     String::new()
 }
 
-#[inline]
-fn pedersen_h_generator_ng() -> curve25519_dalek_ng::ristretto::RistrettoPoint {
-    use curve25519_dalek_ng::ristretto::CompressedRistretto as CompressedRistrettoNg;
-    let h_std = curve25519_dalek::ristretto::RistrettoPoint::hash_from_bytes::<sha2::Sha512>(
-        b"Zether/PedersenH",
-    );
-    let bytes = h_std.compress().to_bytes();
-    CompressedRistrettoNg(bytes).decompress().expect("valid H")
-}
-
-/// Bulletproofs-backed range verifier for 64-bit single-value proofs.
+/// Bulletproofs-backed range verifier for single-value and aggregated proofs.
+///
+/// The bit width (any of [`SUPPORTED_RANGE_BITS`]) follows from the proof length;
+/// [`ZkheVerifier`](crate::ZkheVerifier) checks that length against the asset's width.
 pub struct BulletproofRangeVerifier;
 
 impl RangeProofVerifier for BulletproofRangeVerifier {
@@ -54,59 +49,139 @@ impl RangeProofVerifier for BulletproofRangeVerifier {
         commit_compressed: &[u8; 32],
         proof_bytes: &[u8],
     ) -> Result<(), ()> {
-        use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
-        use curve25519_dalek_ng::ristretto::CompressedRistretto;
-
-        // 0) Input trace (guarded by cfg)
-        dbgln!("-- verify_range_proof --");
-        dbgln!("ctx.len = {}", context.len());
-        dbgln!("commit = {}", hex(commit_compressed));
-        dbgln!("proof_len = {}", proof_bytes.len());
-
-        // 1) Rebuild the transcript exactly like the prover
-        let mut t = Transcript::new(b"bp64");
-        // IMPORTANT: fold in the caller-provided label to make verifier RNG unique per call-site.
-        t.append_message(b"label", transcript_label);
-        t.append_message(b"ctx", context);
-        t.append_message(b"commit", commit_compressed);
-
-        // 2) Parse the proof
-        let proof = RangeProof::from_bytes(proof_bytes).map_err(|_| {
-            dbgln!("proof: failed to parse");
+        verify_with_context(
+            VerifierContext::global(),
+            transcript_label,
+            context,
+            commit_compressed,
+            proof_bytes,
+        )
+    }
+
+    fn verify_aggregated_range_proof(
+        transcript_label: &[u8],
+        context: &[u8],
+        commits: &[[u8; 32]],
+        proof_bytes: &[u8],
+    ) -> Result<(), ()> {
+        verify_aggregated_with_context(
+            VerifierContext::global(),
+            transcript_label,
+            context,
+            commits,
+            proof_bytes,
+        )
+    }
+}
+
+pub(crate) fn verify_with_context(
+    gens: &VerifierContext,
+    transcript_label: &[u8],
+    context: &[u8],
+    commit_compressed: &[u8; 32],
+    proof_bytes: &[u8],
+) -> Result<(), ()> {
+    use bulletproofs::RangeProof;
+    use curve25519_dalek_ng::ristretto::CompressedRistretto;
+
+    // 0) Input trace (guarded by cfg)
+    dbgln!("-- verify_range_proof --");
+    dbgln!("ctx.len = {}", context.len());
+    dbgln!("commit = {}", hex(commit_compressed));
+    dbgln!("proof_len = {}", proof_bytes.len());
+
+    // 1) Rebuild the transcript exactly like the prover
+    let mut t = Transcript::new(labels::RANGE_TRANSCRIPT);
+    // IMPORTANT: fold in the caller-provided label to make verifier RNG unique per call-site.
+    t.append_message(labels::RANGE_LABEL, transcript_label);
+    t.append_message(labels::CTX, context);
+    t.append_message(labels::RANGE_COMMIT, commit_compressed);
+
+    // 2) Parse the proof
+    let n = bits_for_len(proof_bytes.len(), 1).ok_or(())?;
+    let proof = RangeProof::from_bytes(proof_bytes).map_err(|_| {
+        dbgln!("proof: failed to parse");
+    })?;
+
+    // 3) Generators must match the prover exactly (precomputed in the context)
+    let pedersen_gens = gens.pc_gens();
+
+    dbgln!("gens.B = {}", hex(pedersen_gens.B.compress().as_bytes()));
+    dbgln!(
+        "gens.H = {}",
+        hex(pedersen_gens.B_blinding.compress().as_bytes())
+    );
+
+    // 4) Commitment as compressed point
+    let v = CompressedRistretto(*commit_compressed);
+    let v_ok = v.decompress().is_some();
+    dbgln!("commit decompress ok? {}", v_ok);
+
+    // 5) Deterministic verifier RNG derived from the transcript
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+    let mut ext = ChaCha20Rng::from_seed([0u8; 32]);
+    let mut rng = t.build_rng().finalize(&mut ext);
+
+    // 6) Verify
+    dbgln!("calling verify_single_with_rng(n={})...", n);
+    proof
+        .verify_single_with_rng(gens.bp_gens(), pedersen_gens, &mut t, &v, n, &mut rng)
+        .map_err(|_| {
+            dbgln!("verify_single_with_rng: FAILED");
         })?;
 
-        // 3) Generators must match the prover exactly
-        let bp_gens = BulletproofGens::new(64, 1);
-        use curve25519_dalek_ng::constants::RISTRETTO_BASEPOINT_POINT as G_NG;
-        let h_ng = pedersen_h_generator_ng();
-        let pedersen_gens = PedersenGens {
-            B: G_NG,
-            B_blinding: h_ng,
-        };
-
-        dbgln!("gens.B = {}", hex(G_NG.compress().as_bytes()));
-        dbgln!("gens.H = {}", hex(h_ng.compress().as_bytes()));
-
-        // 4) Commitment as compressed point
-        let v = CompressedRistretto(*commit_compressed);
-        let v_ok = v.decompress().is_some();
-        dbgln!("commit decompress ok? {}", v_ok);
-
-        // 5) Deterministic verifier RNG derived from the transcript
-        use rand_chacha::ChaCha20Rng;
-        use rand_core::SeedableRng;
-        let mut ext = ChaCha20Rng::from_seed([0u8; 32]);
-        let mut rng = t.build_rng().finalize(&mut ext);
-
-        // 6) Verify
-        dbgln!("calling verify_single_with_rng(n=64)...");
-        proof
-            .verify_single_with_rng(&bp_gens, &pedersen_gens, &mut t, &v, 64, &mut rng)
-            .map_err(|_| {
-                dbgln!("verify_single_with_rng: FAILED");
-            })?;
-
-        dbgln!("verify_single_with_rng: OK");
-        Ok(())
+    dbgln!("verify_single_with_rng: OK");
+    Ok(())
+}
+
+pub(crate) fn verify_aggregated_with_context(
+    gens: &VerifierContext,
+    transcript_label: &[u8],
+    context: &[u8],
+    commits: &[[u8; 32]],
+    proof_bytes: &[u8],
+) -> Result<(), ()> {
+    use alloc::vec::Vec;
+    use bulletproofs::RangeProof;
+    use curve25519_dalek_ng::ristretto::CompressedRistretto;
+
+    // Bulletproofs aggregation needs a power-of-two party count within capacity.
+    if commits.is_empty() || commits.len() > MAX_AGGREGATION || !commits.len().is_power_of_two() {
+        dbgln!("aggregated: unsupported party count {}", commits.len());
+        return Err(());
     }
+
+    // Same transcript as the single-value proof, with every commitment folded in order.
+    let mut t = Transcript::new(labels::RANGE_TRANSCRIPT);
+    t.append_message(labels::RANGE_LABEL, transcript_label);
+    t.append_message(labels::CTX, context);
+    for c in commits {
+        t.append_message(labels::RANGE_COMMIT, c);
+    }
+
+    let n = bits_for_len(proof_bytes.len(), commits.len()).ok_or(())?;
+    let proof = RangeProof::from_bytes(proof_bytes).map_err(|_| {
+        dbgln!("aggregated proof: failed to parse");
+    })?;
+    let vs: Vec<CompressedRistretto> = commits.iter().map(|c| CompressedRistretto(*c)).collect();
+
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+    let mut ext = ChaCha20Rng::from_seed([0u8; 32]);
+    let mut rng = t.build_rng().finalize(&mut ext);
+
+    proof
+        .verify_multiple_with_rng(gens.bp_gens(), gens.pc_gens(), &mut t, &vs, n, &mut rng)
+        .map_err(|_| {
+            dbgln!("verify_multiple_with_rng: FAILED");
+        })
+}
+
+/// Bit width of a `parties`-value Bulletproof that is `len` bytes long.
+fn bits_for_len(len: usize, parties: usize) -> Option<usize> {
+    SUPPORTED_RANGE_BITS
+        .into_iter()
+        .find(|&bits| range_proof_len(bits, parties) == len)
+        .map(usize::from)
 }
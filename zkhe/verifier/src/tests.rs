@@ -1,8 +1,28 @@
 //! Unit tests for the no_std ZK ElGamal verifier using pre-generated vectors.
 //! Covered:
 //!   1) Happy path: sender + receiver proofs verify and new commitments match vectors
-//!   2) Rejection: tampered sender bundle is rejected
+//!   2) Rejection: tampered sender bundle is rejected, with a structured `VerifyError` reason
 //!   3) Range proof only: parse sender bundle, reconstruct transcript context, and verify range proof
+//!   4) Explicit `VerifierContext` agrees with the cached global generators
+//!   5) Malleability: trailing bytes and non-canonical scalars are rejected
+//!   6) Aggregated range proofs: accept/mint/burn proofs from the prover verify and are smaller
+//!   7) Claims: explicit UTXO ids are checked against the supplied commitments and returned
+//!   8) Network binding: vectors made for network id zero fail under a derived network id
+//!   9) Selective disclosure: an owner's proof reveals a ciphertext's value; wrong values fail
+//!  10) Generated vectors: `generate_vectors` output for a custom asset/network verifies end to end
+//!  11) Shape checks: vectors pass `validate_shape`, truncated/extended/mislabelled bytes do not
+//!  12) Transcript labels: the `labels` registry has no duplicates
+//!  13) Key registration: ownership proofs verify only for the key, account and network they bind
+//!  14) Range-proof cache: repeated proofs skip the inner verifier; LRU eviction is bounded
+//!  15) Proof system: sealed vectors open to their body; other ids and bad lengths are rejected
+//!  16) Ciphertext types: `Commitment`/`EncryptedAmount` arithmetic matches the verified transitions
+//!  17) Confidential burn: hidden-amount burns verify; disclosed burn proofs are not accepted as one
+//!  18) Zero balance: closure proofs verify for zero commitments only, bound to key and asset
+//!  19) Range width: 32-bit proofs verify for 32-bit assets only, and 64-bit proofs only for 64-bit ones
+//!  20) Key rotation: rekey proofs return a ciphertext the new key decrypts, bound to both keys
+//!  21) Auditor handles: a transfer's handle and blinding decrypt under the auditor key and open from an audited proof
+//!  22) Split transfers: outputs under their recipients' keys add up to the total and are each range checked
+//!  23) Seizure: the disclosed amount is tied to the auditor ciphertext and, through its tracked blinding, the stored balance
 
 use confidential_assets_primitives::ZkVerifier as ZkVerifierTrait;
 use confidential_assets_primitives::{EncryptedAmount, NetworkIdProvider, PublicKeyBytes};
@@ -11,7 +31,7 @@ use curve25519_dalek::{
     ristretto::RistrettoPoint,
     traits::{Identity, IsIdentity},
 };
-use zkhe_primitives::RangeProofVerifier;
+use zkhe_primitives::{RangeProofVerifier, labels};
 // Import the verifier marker struct from the crate root and its range verifier.
 use crate::{BulletproofRangeVerifier, ZkheVerifier};
 // Pre-generated deterministic vectors
@@ -89,7 +109,7 @@ fn sender_range_context_from_bundle(
 ) -> [u8; 32] {
     use curve25519_dalek::ristretto::CompressedRistretto;
     use zkhe_primitives::{
-        Ciphertext, PublicContext, SDK_VERSION, append_point, challenge_scalar as fs_chal, labels,
+        Ciphertext, PublicContext, SDK_VERSION, append_point, challenge_scalar as fs_chal,
         new_transcript,
     };
 
@@ -126,9 +146,9 @@ fn sender_range_context_from_bundle(
         .decompress()
         .expect("A3");
 
-    append_point(&mut t, b"a1", &a1);
-    append_point(&mut t, b"a2", &a2);
-    append_point(&mut t, b"a3", &a3);
+    append_point(&mut t, labels::SIGMA_A1, &a1);
+    append_point(&mut t, labels::SIGMA_A2, &a2);
+    append_point(&mut t, labels::SIGMA_A3, &a3);
 
     // Challenge for the Σ-link (advances transcript)
     let _c = fs_chal(&mut t, labels::CHAL_EQ);
@@ -136,7 +156,7 @@ fn sender_range_context_from_bundle(
     // Squeeze the same 32 bytes the prover used to bind the range proof
     let mut ctx_bytes = [0u8; 32];
     let mut t_clone = t.clone();
-    t_clone.challenge_bytes(b"ctx", &mut ctx_bytes);
+    t_clone.challenge_bytes(labels::CTX, &mut ctx_bytes);
     ctx_bytes
 }
 
@@ -227,6 +247,189 @@ fn rejects_tampered_sender_bundle() {
     assert!(err.is_err(), "tampered sender bundle must be rejected");
 }
 
+#[test]
+fn rejections_carry_structured_reason() {
+    use confidential_assets_primitives::VerifyError;
+
+    // Truncated bundle
+    let err = <TestVerifier as ZkVerifierTrait>::verify_transfer_sent(
+        &ASSET_ID_BYTES,
+        &SENDER_PK32,
+        &RECEIVER_PK32,
+        &TRANSFER_FROM_OLD_COMM_32,
+        &[],
+        &TRANSFER_DELTA_CT_64,
+        &TRANSFER_BUNDLE[..100],
+    )
+    .unwrap_err();
+    assert_eq!(err, VerifyError::MalformedProof);
+
+    // Pending commitments that do not sum to ΔC
+    let err = <TestVerifier as ZkVerifierTrait>::verify_transfer_received(
+        &ASSET_ID_BYTES,
+        &RECEIVER_PK32,
+        &[],
+        &TRANSFER_DELTA_COMM_32,
+        &[TRANSFER_FROM_OLD_COMM_32],
+        ACCEPT_ENVELOPE,
+    )
+    .unwrap_err();
+    assert_eq!(err, VerifyError::PendingCommitMismatch);
+
+    // Bad public key
+    let err = <TestVerifier as ZkVerifierTrait>::verify_transfer_sent(
+        &ASSET_ID_BYTES,
+        &[0xFFu8; 32],
+        &RECEIVER_PK32,
+        &TRANSFER_FROM_OLD_COMM_32,
+        &[],
+        &TRANSFER_DELTA_CT_64,
+        TRANSFER_BUNDLE,
+    )
+    .unwrap_err();
+    assert_eq!(err, VerifyError::InvalidPoint);
+
+    assert_eq!(
+        <TestVerifier as ZkVerifierTrait>::disclose(
+            &ASSET_ID_BYTES,
+            &SENDER_PK32,
+            &[0u8; 64],
+            &[0u8; 8]
+        ),
+        Err(VerifyError::MalformedProof)
+    );
+}
+
+#[test]
+fn non_canonical_encodings_are_rejected() {
+    use confidential_assets_primitives::VerifyError;
+
+    let send = |bundle: &[u8]| {
+        <TestVerifier as ZkVerifierTrait>::verify_transfer_sent(
+            &ASSET_ID_BYTES,
+            &SENDER_PK32,
+            &RECEIVER_PK32,
+            &TRANSFER_FROM_OLD_COMM_32,
+            &[0u8; 32],
+            &TRANSFER_DELTA_CT_64,
+            bundle,
+        )
+    };
+
+    // Trailing byte after the last range proof
+    let mut bundle = TRANSFER_BUNDLE.to_vec();
+    bundle.push(0);
+    assert_eq!(send(&bundle), Err(VerifyError::NonCanonicalEncoding));
+
+    // z_k replaced by an encoding ≥ ℓ
+    let mut bundle = TRANSFER_BUNDLE.to_vec();
+    bundle[32 + 96..32 + 128].copy_from_slice(&[0xFF; 32]);
+    assert_eq!(send(&bundle), Err(VerifyError::NonCanonicalEncoding));
+
+    // Trailing byte after the accept envelope
+    let mut envelope = ACCEPT_ENVELOPE.to_vec();
+    envelope.push(0);
+    let err = <TestVerifier as ZkVerifierTrait>::verify_transfer_received(
+        &ASSET_ID_BYTES,
+        &RECEIVER_PK32,
+        &[0u8; 32],
+        &TRANSFER_DELTA_COMM_32,
+        &[TRANSFER_DELTA_COMM_32],
+        &envelope,
+    )
+    .unwrap_err();
+    assert_eq!(err, VerifyError::NonCanonicalEncoding);
+
+    // The unmodified vectors still pass the stricter parser
+    assert!(send(TRANSFER_BUNDLE).is_ok());
+}
+
+#[test]
+fn claim_checks_listed_utxos_and_returns_consumed_ids() {
+    use confidential_assets_primitives::VerifyError;
+
+    let claim = |ids: &[u64]| {
+        let mut v = (ids.len() as u16).to_le_bytes().to_vec();
+        for id in ids {
+            v.extend_from_slice(&id.to_le_bytes());
+        }
+        v.extend_from_slice(ACCEPT_ENVELOPE);
+        v
+    };
+    let verify = |utxos: &[(u64, [u8; 32])], claim: &[u8]| {
+        <TestVerifier as ZkVerifierTrait>::verify_claim(
+            &ASSET_ID_BYTES,
+            &RECEIVER_PK32,
+            &[],
+            &TRANSFER_DELTA_COMM_32,
+            utxos,
+            claim,
+        )
+    };
+
+    let (avail_new, _, consumed) =
+        verify(&[(5, TRANSFER_DELTA_COMM_32)], &claim(&[5])).expect("claim verify");
+    assert_eq!(avail_new.as_slice(), &ACCEPT_AVAIL_NEW_COMM_32);
+    assert_eq!(consumed, vec![5]);
+
+    // Supplied UTXOs must be exactly the ones the claim lists
+    assert_eq!(
+        verify(&[(6, TRANSFER_DELTA_COMM_32)], &claim(&[5])),
+        Err(VerifyError::PendingCommitMismatch)
+    );
+    // No id may be consumed twice
+    assert_eq!(
+        verify(
+            &[(5, TRANSFER_DELTA_COMM_32), (5, TRANSFER_DELTA_COMM_32)],
+            &claim(&[5, 5])
+        ),
+        Err(VerifyError::MalformedProof)
+    );
+
+    // Multi-UTXO bench vector: all sixteen deposits claimed at once
+    let utxos: Vec<(u64, [u8; 32])> = (0u64..).zip(CLAIM_16_UTXO_COMMS.iter().copied()).collect();
+    let (avail_new, pending_new, consumed) = <TestVerifier as ZkVerifierTrait>::verify_claim(
+        &ASSET_ID_BYTES,
+        &RECEIVER_PK32,
+        &[],
+        &CLAIM_16_PENDING_OLD_COMM_32,
+        &utxos,
+        CLAIM_16,
+    )
+    .expect("claim verify");
+    assert_eq!(avail_new.as_slice(), &CLAIM_16_AVAIL_NEW_COMM_32);
+    assert_eq!(pending_new.as_slice(), &CLAIM_16_PENDING_NEW_COMM_32);
+    assert_eq!(consumed, (0..16).collect::<Vec<u64>>());
+}
+
+#[test]
+fn proofs_do_not_replay_across_networks() {
+    use confidential_assets_primitives::VerifyError;
+
+    struct OtherNetworkId;
+    impl NetworkIdProvider for OtherNetworkId {
+        fn network_id() -> [u8; 32] {
+            zkhe_primitives::derive_network_id(&[1u8; 32], 1000)
+        }
+    }
+
+    assert_ne!(
+        zkhe_primitives::derive_network_id(&[1u8; 32], 1000),
+        zkhe_primitives::derive_network_id(&[1u8; 32], 1001)
+    );
+    let err = <ZkheVerifier<OtherNetworkId> as ZkVerifierTrait>::verify_transfer_sent(
+        &ASSET_ID_BYTES,
+        &SENDER_PK32,
+        &RECEIVER_PK32,
+        &TRANSFER_FROM_OLD_COMM_32,
+        &[],
+        &TRANSFER_DELTA_CT_64,
+        TRANSFER_BUNDLE,
+    )
+    .unwrap_err();
+    assert_eq!(err, VerifyError::LinkProofInvalid);
+}
+
 #[test]
 fn range_proof_from_sender_bundle_verifies() {
     use curve25519_dalek::ristretto::CompressedRistretto;
@@ -260,7 +463,7 @@ fn range_proof_from_sender_bundle_verifies() {
 
     // Range proof verify
     match BulletproofRangeVerifier::verify_range_proof(
-        b"range_from_new",
+        labels::RANGE_FROM_NEW,
         &ctx_bytes,
         &commit32,
         parsed.range_from_new,
@@ -272,6 +475,44 @@ fn range_proof_from_sender_bundle_verifies() {
     }
 }
 
+#[test]
+fn explicit_context_matches_global_generators() {
+    use crate::VerifierContext;
+
+    let ctx = VerifierContext::new();
+    assert_eq!(ctx.h(), zkhe_primitives::pedersen_h_generator());
+    assert_eq!(ctx.h(), VerifierContext::global().h());
+    assert!(core::ptr::eq(
+        VerifierContext::global(),
+        VerifierContext::global()
+    ));
+
+    // An owned context verifies the same range proof as the cached global one.
+    let parsed = parse_sender_bundle(TRANSFER_BUNDLE).expect("parse bundle");
+    let pk_sender_pt = curve25519_dalek::ristretto::CompressedRistretto(SENDER_PK32)
+        .decompress()
+        .expect("pk_s");
+    let pk_receiver_pt = curve25519_dalek::ristretto::CompressedRistretto(RECEIVER_PK32)
+        .decompress()
+        .expect("pk_r");
+    let ctx_bytes = sender_range_context_from_bundle(
+        &ASSET_ID_BYTES,
+        &pk_sender_pt,
+        &pk_receiver_pt,
+        &TRANSFER_DELTA_CT_64,
+        &parsed.link_raw_192,
+    );
+    assert!(
+        ctx.verify_range_proof(
+            labels::RANGE_FROM_NEW,
+            &ctx_bytes,
+            &TRANSFER_FROM_NEW_COMM_32,
+            parsed.range_from_new,
+        )
+        .is_ok()
+    );
+}
+
 #[test]
 fn identity_commitment_is_zero_point() {
     let zero = RistrettoPoint::default();
@@ -293,7 +534,7 @@ fn mint_round_trip() {
         )
         .expect("mint verify");
 
-    assert_eq!(minted_ct_bytes, MINTED_CT_64);
+    assert_eq!(minted_ct_bytes.to_bytes(), MINTED_CT_64);
     assert_eq!(to_new_bytes.as_slice(), &MINT_TO_NEW_COMM_32);
     assert_eq!(total_new_bytes.as_slice(), &MINT_TOTAL_NEW_COMM_32);
 }
@@ -327,3 +568,1435 @@ fn burn_round_trip() {
     assert_eq!(from_new_bytes.as_slice(), &BURN_FROM_NEW_COMM_32);
     assert_eq!(total_new_bytes.as_slice(), &BURN_TOTAL_NEW_COMM_32);
 }
+
+#[test]
+fn confidential_burn_round_trip() {
+    use confidential_assets_primitives::{Commitment, ProofKind};
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_prover::*;
+
+    let pk = Scalar::from(9u64) * G;
+    let pk_bv = PublicKeyBytes::try_from(pk.compress().to_bytes().to_vec()).expect("pk bv");
+    let h = zkhe_primitives::pedersen_h_generator();
+    let r = Scalar::from(3u64);
+    let old_c = Scalar::from(200u64) * G + r * h;
+    let old = old_c.compress().to_bytes();
+    let input = BurnInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        from_pk: pk,
+        from_avail_old_c: old_c,
+        from_avail_old_opening: (200, r),
+        total_old_c: old_c,
+        total_old_opening: (200, r),
+        burn_value: 120,
+        rng_seed: [4u8; 32],
+    };
+
+    for mode in [RangeProofMode::Separate, RangeProofMode::Aggregated] {
+        let burn = prove_confidential_burn_with(&input, mode).expect("confidential burn prove");
+        <TestVerifier as ZkVerifierTrait>::validate_shape(
+            ProofKind::ConfidentialBurn,
+            &burn.proof_bytes,
+        )
+        .expect("confidential burn shape");
+        let (from_new, total_new) = <TestVerifier as ZkVerifierTrait>::verify_confidential_burn(
+            &ASSET_ID_BYTES,
+            &pk_bv,
+            &old,
+            &old,
+            &EncryptedAmount::new(burn.amount_ct_bytes),
+            &burn.proof_bytes,
+        )
+        .expect("confidential burn verify");
+        assert_eq!(from_new.as_slice(), &burn.from_avail_new_c);
+        assert_eq!(total_new.as_slice(), &burn.total_new_c);
+
+        // Supply drops by exactly the burned ciphertext's commitment
+        let delta = Commitment::try_from(&burn.proof_bytes[..32]).expect("delta");
+        assert_eq!(
+            Commitment::new(old).sub(&delta).expect("sub").to_bytes(),
+            burn.total_new_c
+        );
+    }
+
+    // A disclosed burn proof, with or without its amount, is not a confidential burn
+    let burn = prove_burn(&input).expect("burn prove");
+    let amount_ct = EncryptedAmount::new(burn.amount_ct_bytes);
+    let stripped = &burn.proof_bytes[..burn.proof_bytes.len() - 8];
+    for proof in [&burn.proof_bytes[..], stripped] {
+        assert!(
+            <TestVerifier as ZkVerifierTrait>::verify_confidential_burn(
+                &ASSET_ID_BYTES,
+                &pk_bv,
+                &old,
+                &old,
+                &amount_ct,
+                proof,
+            )
+            .is_err()
+        );
+    }
+
+    // ...and a confidential burn proof with an amount appended is not a disclosed burn
+    let hidden = prove_confidential_burn(&input).expect("confidential burn prove");
+    let mut disclosed = hidden.proof_bytes.clone();
+    disclosed.extend_from_slice(&120u64.to_le_bytes());
+    assert!(
+        <TestVerifier as ZkVerifierTrait>::verify_burn(
+            &ASSET_ID_BYTES,
+            &pk_bv,
+            &old,
+            &old,
+            &EncryptedAmount::new(hidden.amount_ct_bytes),
+            &disclosed,
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn zero_balance_proof_binds_commitments() {
+    use confidential_assets_primitives::ProofKind;
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_prover::{ZeroBalanceInput, prove_zero_balance};
+
+    let pk = Scalar::from(9u64) * G;
+    let pk_bytes = pk.compress().to_bytes();
+    let h = zkhe_primitives::pedersen_h_generator();
+    let (r_avail, r_pending) = (Scalar::from(11u64), Scalar::from(13u64));
+    let avail = (r_avail * h).compress().to_bytes();
+    let pending = (r_pending * h).compress().to_bytes();
+    let input = ZeroBalanceInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        pk,
+        avail_c: r_avail * h,
+        avail_blind: r_avail,
+        pending_c: r_pending * h,
+        pending_blind: r_pending,
+        rng_seed: [6u8; 32],
+    };
+    let proof = prove_zero_balance(&input).expect("zero balance prove");
+    <TestVerifier as ZkVerifierTrait>::validate_shape(ProofKind::ZeroBalance, &proof)
+        .expect("zero balance shape");
+    let verify = |asset: &[u8], pk: &[u8], avail: &[u8], pending: &[u8], proof: &[u8]| {
+        <TestVerifier as ZkVerifierTrait>::verify_zero_balance(asset, pk, avail, pending, proof)
+    };
+    verify(&ASSET_ID_BYTES, &pk_bytes, &avail, &pending, &proof).expect("zero balance verify");
+
+    // Wrong commitment, key or asset
+    let one = (G + r_avail * h).compress().to_bytes();
+    assert!(verify(&ASSET_ID_BYTES, &pk_bytes, &one, &pending, &proof).is_err());
+    assert!(verify(&ASSET_ID_BYTES, &pk_bytes, &avail, &avail, &proof).is_err());
+    let other_pk = (Scalar::from(10u64) * G).compress().to_bytes();
+    assert!(verify(&ASSET_ID_BYTES, &other_pk, &avail, &pending, &proof).is_err());
+    assert!(verify(&[9u8; 32], &pk_bytes, &avail, &pending, &proof).is_err());
+
+    // A never-used account has identity (empty) commitments
+    let empty = prove_zero_balance(&ZeroBalanceInput {
+        avail_c: RistrettoPoint::identity(),
+        avail_blind: Scalar::ZERO,
+        pending_c: RistrettoPoint::identity(),
+        pending_blind: Scalar::ZERO,
+        ..input
+    })
+    .expect("empty prove");
+    verify(&ASSET_ID_BYTES, &pk_bytes, &[], &[], &empty).expect("empty verify");
+
+    // A non-zero balance cannot be proven zero
+    assert!(
+        prove_zero_balance(&ZeroBalanceInput {
+            asset_id: ASSET_ID_BYTES.to_vec(),
+            network_id: [0u8; 32],
+            pk,
+            avail_c: G + r_avail * h,
+            avail_blind: r_avail,
+            pending_c: r_pending * h,
+            pending_blind: r_pending,
+            rng_seed: [6u8; 32],
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn rekey_proof_reencrypts_available_balance() {
+    use confidential_assets_primitives::ProofKind;
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_prover::{RekeyInput, prove_rekey};
+
+    let (old_sk, new_sk) = (Scalar::from(9u64), Scalar::from(21u64));
+    let old_pk = (old_sk * G).compress().to_bytes();
+    let new_pk = (new_sk * G).compress().to_bytes();
+    let h = zkhe_primitives::pedersen_h_generator();
+    let (value, blind) = (1_234u64, Scalar::from(17u64));
+    let avail_c = Scalar::from(value) * G + blind * h;
+    let avail = avail_c.compress().to_bytes();
+    let account = b"alice".to_vec();
+    let input = RekeyInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        account: account.clone(),
+        old_sk,
+        new_sk,
+        avail_c,
+        avail_value: value,
+        avail_blind: blind,
+        rng_seed: [8u8; 32],
+    };
+    let proof = prove_rekey(&input).expect("rekey prove");
+    <TestVerifier as ZkVerifierTrait>::validate_shape(ProofKind::Rekey, &proof)
+        .expect("rekey shape");
+    let verify = |account: &[u8], old: &[u8], new: &[u8], avail: &[u8], proof: &[u8]| {
+        <TestVerifier as ZkVerifierTrait>::verify_rekey(
+            &ASSET_ID_BYTES,
+            account,
+            old,
+            new,
+            avail,
+            proof,
+        )
+    };
+    let ct = verify(&account, &old_pk, &new_pk, &avail, &proof).expect("rekey verify");
+    verify(
+        PK_OWNER_ACCOUNT,
+        &SENDER_PK32,
+        &REKEY_NEW_PK32,
+        &TRANSFER_FROM_OLD_COMM_32,
+        REKEY_PROOF,
+    )
+    .expect("rekey vector verify");
+
+    // The new key decrypts the returned ciphertext to the available value
+    let ct = zkhe_primitives::Ciphertext::from_bytes(ct.as_bytes()).expect("ct");
+    assert_eq!(ct.D - new_sk * ct.C, Scalar::from(value) * G);
+
+    // Wrong account, either key, or balance
+    assert!(verify(b"bob", &old_pk, &new_pk, &avail, &proof).is_err());
+    let other_pk = (Scalar::from(10u64) * G).compress().to_bytes();
+    assert!(verify(&account, &other_pk, &new_pk, &avail, &proof).is_err());
+    assert!(verify(&account, &old_pk, &other_pk, &avail, &proof).is_err());
+    let more = (avail_c + G).compress().to_bytes();
+    assert!(verify(&account, &old_pk, &new_pk, &more, &proof).is_err());
+
+    // A ciphertext swapped into the proof breaks the link
+    let mut swapped = proof.clone();
+    swapped[128..192].copy_from_slice(&[0u8; 64]);
+    assert!(verify(&account, &old_pk, &new_pk, &avail, &swapped).is_err());
+
+    // Rotating to the identity key is rejected outright
+    let identity = RistrettoPoint::identity().compress().to_bytes();
+    assert!(verify(&account, &old_pk, &identity, &avail, &proof).is_err());
+
+    // An empty balance rekeys to an encryption of zero
+    let empty = prove_rekey(&RekeyInput {
+        avail_c: RistrettoPoint::identity(),
+        avail_value: 0,
+        avail_blind: Scalar::ZERO,
+        ..input
+    })
+    .expect("empty prove");
+    verify(&account, &old_pk, &new_pk, &[], &empty).expect("empty verify");
+
+    // The prover refuses a commitment that does not open to the claimed value
+    assert!(
+        prove_rekey(&RekeyInput {
+            asset_id: ASSET_ID_BYTES.to_vec(),
+            network_id: [0u8; 32],
+            account,
+            old_sk,
+            new_sk,
+            avail_c,
+            avail_value: value + 1,
+            avail_blind: blind,
+            rng_seed: [8u8; 32],
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn auditor_handle_decrypts_transfer_amount() {
+    use confidential_assets_primitives::{ProofKind, ProofSystem, seal_proof};
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_prover::{
+        AuditorHandleInput, SenderInput, prove_auditor_handle, prove_sender_transfer,
+    };
+
+    let sender_pk = Scalar::from(9u64) * G;
+    let auditor_sk = Scalar::from(31u64);
+    let auditor_pk = auditor_sk * G;
+    let h = zkhe_primitives::pedersen_h_generator();
+    let sent = prove_sender_transfer(&SenderInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        sender_pk,
+        receiver_pk: Scalar::from(21u64) * G,
+        from_old_c: Scalar::from(100u64) * G + Scalar::from(5u64) * h,
+        from_old_opening: (100, Scalar::from(5u64)),
+        to_old_c: RistrettoPoint::identity(),
+        delta_value: 30,
+        rng_seed: [3u8; 32],
+        fee_c: None,
+    })
+    .expect("transfer prove");
+    let input = AuditorHandleInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        pk: sender_pk,
+        auditor_pk,
+        ct: sent.delta_ct_bytes,
+        nonce: sent.nonce,
+        delta_opening: (30, sent.delta_rho),
+        rng_seed: [4u8; 32],
+    };
+    let handle = prove_auditor_handle(&input).expect("auditor prove");
+    <TestVerifier as ZkVerifierTrait>::validate_shape(ProofKind::AuditorHandle, &handle)
+        .expect("auditor shape");
+
+    let pk = sender_pk.compress().to_bytes();
+    let auditor = auditor_pk.compress().to_bytes();
+    let ct = EncryptedAmount::new(sent.delta_ct_bytes);
+    // The transfer moves ΔC out of the sender's balance
+    let from_old = (Scalar::from(100u64) * G + Scalar::from(5u64) * h)
+        .compress()
+        .to_bytes();
+    let delta = (&from_old[..], &sent.from_new_c[..]);
+    let verify = |pk: &[u8], auditor: &[u8], ct: &EncryptedAmount, proof: &[u8]| {
+        <TestVerifier as ZkVerifierTrait>::verify_auditor_handle(
+            &ASSET_ID_BYTES,
+            pk,
+            auditor,
+            ct,
+            delta,
+            proof,
+        )
+    };
+
+    // The handle comes appended to the transfer proof and decrypts under the auditor key
+    let mut audited = seal_proof(<TestVerifier as ProofSystem>::ID, &sent.sender_bundle_bytes);
+    audited.extend(seal_proof(<TestVerifier as ProofSystem>::ID, &handle));
+    let (bundle, opened) =
+        <TestVerifier as ProofSystem>::open_audited(ProofKind::Transfer, &audited)
+            .expect("open audited");
+    assert_eq!(bundle, &sent.sender_bundle_bytes[..]);
+    let (audit_ct, blinding) = verify(&pk, &auditor, &ct, opened).expect("auditor verify");
+    <TestVerifier as ZkVerifierTrait>::verify_auditor_handle(
+        &ASSET_ID_BYTES,
+        &SENDER_PK32,
+        &AUDITOR_PK32,
+        &EncryptedAmount::new(TRANSFER_DELTA_CT_64),
+        (&TRANSFER_FROM_OLD_COMM_32, &TRANSFER_FROM_NEW_COMM_32),
+        TRANSFER_AUDITOR_HANDLE,
+    )
+    .expect("auditor vector verify");
+    // The handle reuses the transfer's nonce
+    assert_eq!(audit_ct.nonce_point(), ct.nonce_point());
+    let audit_ct = zkhe_primitives::Ciphertext::from_bytes(audit_ct.as_bytes()).expect("ct");
+    assert_eq!(
+        audit_ct.D - auditor_sk * audit_ct.C,
+        Scalar::from(30u64) * G
+    );
+    // The blinding ciphertext decrypts to the H-part of ΔC
+    let blinding = zkhe_primitives::Ciphertext::from_bytes(blinding.as_bytes()).expect("ct");
+    assert_eq!(blinding.D - auditor_sk * blinding.C, sent.delta_rho * h);
+
+    // The handle only fits the commitment the transfer moved
+    let other_delta = (&from_old[..], &sent.to_new_c[..]);
+    assert!(
+        <TestVerifier as ZkVerifierTrait>::verify_auditor_handle(
+            &ASSET_ID_BYTES,
+            &pk,
+            &auditor,
+            &ct,
+            other_delta,
+            &handle,
+        )
+        .is_err()
+    );
+
+    // Wrong holder or auditor key, or another ciphertext
+    let other = (Scalar::from(10u64) * G).compress().to_bytes();
+    assert!(verify(&other, &auditor, &ct, &handle).is_err());
+    assert!(verify(&pk, &other, &ct, &handle).is_err());
+    assert!(
+        verify(
+            &pk,
+            &auditor,
+            &EncryptedAmount::new(TRANSFER_DELTA_CT_64),
+            &handle
+        )
+        .is_err()
+    );
+
+    // A handle for another value breaks the proof
+    let mut shifted = handle.clone();
+    let d = zkhe_primitives::point_from_bytes(&handle[..32].try_into().unwrap()).unwrap() + G;
+    shifted[..32].copy_from_slice(d.compress().as_bytes());
+    assert!(verify(&pk, &auditor, &ct, &shifted).is_err());
+
+    // The identity auditor key is rejected outright
+    let identity = RistrettoPoint::identity().compress().to_bytes();
+    assert!(verify(&pk, &identity, &ct, &handle).is_err());
+
+    // Unaudited proofs are too short to hold a handle, or lose their tail
+    assert!(
+        <TestVerifier as ProofSystem>::open_audited(ProofKind::Transfer, &audited[..100]).is_err()
+    );
+    let plain = seal_proof(<TestVerifier as ProofSystem>::ID, &sent.sender_bundle_bytes);
+    assert!(<TestVerifier as ProofSystem>::open_audited(ProofKind::Transfer, &plain).is_err());
+
+    // The prover refuses a nonce that did not make the ciphertext
+    assert!(
+        prove_auditor_handle(&AuditorHandleInput {
+            nonce: sent.nonce + Scalar::ONE,
+            ..input
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn aggregated_range_proofs_verify() {
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_prover::*;
+
+    let sk = Scalar::from(9u64);
+    let pk = sk * G;
+    let pk_bv = PublicKeyBytes::try_from(pk.compress().to_bytes().to_vec()).expect("pk bv");
+    let h = zkhe_primitives::pedersen_h_generator();
+    let id = RistrettoPoint::identity().compress().to_bytes();
+
+    // Accept: pending = ΔC moves into available
+    let rho = Scalar::from(77u64);
+    let delta_comm = Scalar::from(50u64) * G + rho * h;
+    let accept = |mode| {
+        prove_receiver_accept_with(
+            &ReceiverAcceptInput {
+                asset_id: ASSET_ID_BYTES.to_vec(),
+                network_id: [0u8; 32],
+                receiver_pk: pk,
+                avail_old_c: RistrettoPoint::identity(),
+                avail_old_opening: (0, Scalar::ZERO),
+                pending_old_c: delta_comm,
+                pending_old_opening: (50, rho),
+                delta_comm,
+                delta_value: 50,
+                delta_rho: rho,
+            },
+            mode,
+        )
+        .expect("accept prove")
+    };
+    let separate = accept(RangeProofMode::Separate);
+    let aggregated = accept(RangeProofMode::Aggregated);
+    assert!(aggregated.accept_envelope.len() < separate.accept_envelope.len());
+    let (avail_new, pending_new) = <TestVerifier as ZkVerifierTrait>::verify_transfer_received(
+        &ASSET_ID_BYTES,
+        &pk.compress().to_bytes(),
+        &id,
+        &delta_comm.compress().to_bytes(),
+        &[delta_comm.compress().to_bytes()],
+        &aggregated.accept_envelope,
+    )
+    .expect("aggregated accept verify");
+    assert_eq!(avail_new.as_slice(), &aggregated.avail_new_c);
+    assert_eq!(pending_new.as_slice(), &aggregated.pending_new_c);
+
+    // Mint
+    let mint = prove_mint_with(
+        &MintInput {
+            asset_id: ASSET_ID_BYTES.to_vec(),
+            network_id: [0u8; 32],
+            to_pk: pk,
+            to_pending_old_c: RistrettoPoint::identity(),
+            to_pending_old_opening: (0, Scalar::ZERO),
+            total_old_c: RistrettoPoint::identity(),
+            total_old_opening: (0, Scalar::ZERO),
+            mint_value: 500,
+            rng_seed: [3u8; 32],
+        },
+        RangeProofMode::Aggregated,
+    )
+    .expect("mint prove");
+    let (to_new, total_new, _) = <TestVerifier as ZkVerifierTrait>::verify_mint(
+        &ASSET_ID_BYTES,
+        &pk_bv,
+        &id,
+        &id,
+        &mint.proof_bytes,
+    )
+    .expect("aggregated mint verify");
+    assert_eq!(to_new.as_slice(), &mint.to_pending_new_c);
+    assert_eq!(total_new.as_slice(), &mint.total_new_c);
+
+    // Burn
+    let r = Scalar::from(3u64);
+    let old_c = Scalar::from(200u64) * G + r * h;
+    let burn = prove_burn_with(
+        &BurnInput {
+            asset_id: ASSET_ID_BYTES.to_vec(),
+            network_id: [0u8; 32],
+            from_pk: pk,
+            from_avail_old_c: old_c,
+            from_avail_old_opening: (200, r),
+            total_old_c: old_c,
+            total_old_opening: (200, r),
+            burn_value: 120,
+            rng_seed: [4u8; 32],
+        },
+        RangeProofMode::Aggregated,
+    )
+    .expect("burn prove");
+    let (_, _, disclosed) = <TestVerifier as ZkVerifierTrait>::verify_burn(
+        &ASSET_ID_BYTES,
+        &pk_bv,
+        &old_c.compress().to_bytes(),
+        &old_c.compress().to_bytes(),
+        &EncryptedAmount::new(burn.amount_ct_bytes),
+        &burn.proof_bytes,
+    )
+    .expect("aggregated burn verify");
+    assert_eq!(disclosed, 120);
+
+    // Tampered aggregated proof
+    let mut env = aggregated.accept_envelope.clone();
+    let last = env.len() - 1;
+    env[last] ^= 1;
+    assert!(
+        <TestVerifier as ZkVerifierTrait>::verify_transfer_received(
+            &ASSET_ID_BYTES,
+            &pk.compress().to_bytes(),
+            &id,
+            &delta_comm.compress().to_bytes(),
+            &[delta_comm.compress().to_bytes()],
+            &env,
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn disclosure_proof_reveals_value() {
+    use confidential_assets_primitives::VerifyError;
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_prover::{DisclosureInput, MintInput, prove_disclosure, prove_mint};
+
+    let sk = Scalar::from(9u64);
+    let pk = (sk * G).compress().to_bytes();
+    let mint = prove_mint(&MintInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        to_pk: sk * G,
+        to_pending_old_c: RistrettoPoint::identity(),
+        to_pending_old_opening: (0, Scalar::ZERO),
+        total_old_c: RistrettoPoint::identity(),
+        total_old_opening: (0, Scalar::ZERO),
+        mint_value: 321,
+        rng_seed: [6u8; 32],
+    })
+    .expect("mint prove");
+
+    let input = |value| DisclosureInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        sk,
+        ciphertext: mint.minted_ct_bytes,
+        value,
+        rng_seed: [7u8; 32],
+    };
+    assert!(prove_disclosure(&input(322)).is_err());
+
+    let proof = prove_disclosure(&input(321)).expect("disclosure prove");
+    let disclose = |proof: &[u8]| {
+        <TestVerifier as ZkVerifierTrait>::disclose(
+            &ASSET_ID_BYTES,
+            &pk,
+            &mint.minted_ct_bytes,
+            proof,
+        )
+    };
+    assert_eq!(disclose(&proof), Ok(321));
+
+    // Claiming a different value with the same proof fails
+    let mut forged = proof.clone();
+    forged[0..8].copy_from_slice(&322u64.to_le_bytes());
+    assert_eq!(disclose(&forged), Err(VerifyError::LinkProofInvalid));
+
+    // Another key cannot disclose the ciphertext
+    let other_pk = (Scalar::from(10u64) * G).compress().to_bytes();
+    assert!(
+        <TestVerifier as ZkVerifierTrait>::disclose(
+            &ASSET_ID_BYTES,
+            &other_pk,
+            &mint.minted_ct_bytes,
+            &proof
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn generated_vectors_verify_for_custom_network() {
+    use zkhe_prover::{VectorConfig, generate_vectors};
+
+    struct CustomNetworkId;
+    impl NetworkIdProvider for CustomNetworkId {
+        fn network_id() -> [u8; 32] {
+            zkhe_primitives::derive_network_id(&[3u8; 32], 2000)
+        }
+    }
+    type CustomVerifier = ZkheVerifier<CustomNetworkId>;
+
+    let v = generate_vectors(&VectorConfig {
+        asset_id: b"CUSTOM".to_vec(),
+        network_id: CustomNetworkId::network_id(),
+        ..Default::default()
+    })
+    .expect("generate vectors");
+
+    let (from_new, to_new) = <CustomVerifier as ZkVerifierTrait>::verify_transfer_sent(
+        &v.asset_id,
+        &v.sender_pk,
+        &v.receiver_pk,
+        &v.transfer_from_old_comm,
+        &[],
+        &v.transfer_delta_ct,
+        &v.transfer_bundle,
+    )
+    .expect("transfer verify");
+    assert_eq!(from_new.as_slice(), &v.transfer_from_new_comm);
+    assert_eq!(to_new.as_slice(), &v.transfer_to_new_comm);
+
+    let (avail_new, pending_new) = <CustomVerifier as ZkVerifierTrait>::verify_transfer_received(
+        &v.asset_id,
+        &v.receiver_pk,
+        &[],
+        &v.transfer_delta_comm,
+        &[v.transfer_delta_comm],
+        &v.accept_envelope,
+    )
+    .expect("accept verify");
+    assert_eq!(avail_new.as_slice(), &v.accept_avail_new_comm);
+    assert_eq!(pending_new.as_slice(), &v.accept_pending_new_comm);
+
+    let to_pk = PublicKeyBytes::try_from(v.receiver_pk.to_vec()).expect("pk bv");
+    let (_, _, minted_ct) = <CustomVerifier as ZkVerifierTrait>::verify_mint(
+        &v.asset_id,
+        &to_pk,
+        &[],
+        &[],
+        &v.mint_proof,
+    )
+    .expect("mint verify");
+    assert_eq!(minted_ct.to_bytes(), v.minted_ct);
+
+    let from_pk = PublicKeyBytes::try_from(v.sender_pk.to_vec()).expect("pk bv");
+    let amount_ct = EncryptedAmount::try_from(v.burn_amount_ct.to_vec()).expect("ct bv");
+    let (_, _, disclosed) = <CustomVerifier as ZkVerifierTrait>::verify_burn(
+        &v.asset_id,
+        &from_pk,
+        &v.burn_from_old_comm,
+        &v.burn_total_old_comm,
+        &amount_ct,
+        &v.burn_proof,
+    )
+    .expect("burn verify");
+    assert_eq!(disclosed, 120);
+
+    for claim in &v.claims {
+        let utxos: Vec<(u64, [u8; 32])> = (0u64..).zip(claim.utxo_comms.iter().copied()).collect();
+        let (avail_new, pending_new, consumed) = <CustomVerifier as ZkVerifierTrait>::verify_claim(
+            &v.asset_id,
+            &v.receiver_pk,
+            &[],
+            &claim.pending_old_comm,
+            &utxos,
+            &claim.claim,
+        )
+        .expect("claim verify");
+        assert_eq!(avail_new.as_slice(), &claim.avail_new_comm);
+        assert_eq!(pending_new.as_slice(), &claim.pending_new_comm);
+        assert_eq!(consumed.len(), utxos.len());
+    }
+
+    // The same proofs do not verify under the default network id.
+    assert!(
+        <TestVerifier as ZkVerifierTrait>::verify_transfer_sent(
+            &v.asset_id,
+            &v.sender_pk,
+            &v.receiver_pk,
+            &v.transfer_from_old_comm,
+            &[],
+            &v.transfer_delta_ct,
+            &v.transfer_bundle,
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn validate_shape_matches_vector_layouts() {
+    use confidential_assets_primitives::{ProofKind, VerifyError};
+
+    let shape = |kind, bytes: &[u8]| <TestVerifier as ZkVerifierTrait>::validate_shape(kind, bytes);
+    let mut claim = Vec::from(1u16.to_le_bytes());
+    claim.extend_from_slice(&0u64.to_le_bytes());
+    claim.extend_from_slice(ACCEPT_ENVELOPE);
+
+    for (kind, bytes) in [
+        (ProofKind::Transfer, TRANSFER_BUNDLE),
+        (ProofKind::Accept, ACCEPT_ENVELOPE),
+        (ProofKind::Claim, &claim[..]),
+        (ProofKind::Mint, MINT_PROOF),
+        (ProofKind::Burn, BURN_PROOF),
+        (ProofKind::Burn, FULL_BURN_PROOF),
+    ] {
+        assert_eq!(shape(kind, bytes), Ok(()), "{kind:?}");
+        assert!(bytes.len() <= zkhe_primitives::MINT_PROOF_MAX_LEN);
+        assert_eq!(
+            shape(kind, &bytes[..bytes.len() - 1]),
+            Err(VerifyError::MalformedProof)
+        );
+        let mut extended = bytes.to_vec();
+        extended.push(0);
+        assert_eq!(shape(kind, &extended), Err(VerifyError::MalformedProof));
+    }
+
+    assert_eq!(
+        shape(ProofKind::Transfer, MALFORMED_TRUNCATED_BUNDLE),
+        Err(VerifyError::MalformedProof)
+    );
+    assert_eq!(
+        shape(ProofKind::Mint, TRANSFER_BUNDLE),
+        Err(VerifyError::MalformedProof)
+    );
+
+    // The leading points must decompress
+    for (kind, bytes, at) in [
+        (ProofKind::Transfer, TRANSFER_BUNDLE, 0),
+        (ProofKind::Claim, &claim[..], 10),
+        (ProofKind::Mint, MINT_PROOF, 64),
+        (ProofKind::Burn, BURN_PROOF, 0),
+    ] {
+        let mut bad = bytes.to_vec();
+        bad[at..at + 32].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            shape(kind, &bad),
+            Err(VerifyError::InvalidPoint),
+            "{kind:?}"
+        );
+    }
+    assert_eq!(
+        shape(
+            ProofKind::Disclosure,
+            &[0u8; zkhe_primitives::DISCLOSURE_PROOF_LEN]
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn transcript_labels_are_unique() {
+    assert!(labels::all_distinct(labels::ALL));
+    assert!(!labels::all_distinct(&[labels::CTX, b"ctx"]));
+    assert!(labels::ALL.contains(&labels::RANGE_FROM_NEW));
+}
+
+#[test]
+fn pubkey_ownership_proof_binds_account() {
+    use confidential_assets_primitives::VerifyError;
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_prover::{PubkeyOwnershipInput, prove_pubkey_ownership};
+
+    let sk = Scalar::from(11u64);
+    let pk = (sk * G).compress().to_bytes();
+    let input = |sk| PubkeyOwnershipInput {
+        network_id: [0u8; 32],
+        account: b"alice".to_vec(),
+        sk,
+        rng_seed: [8u8; 32],
+    };
+    assert!(prove_pubkey_ownership(&input(Scalar::ZERO)).is_err());
+    let proof = prove_pubkey_ownership(&input(sk)).expect("ownership prove");
+    assert_eq!(proof.len(), zkhe_primitives::PK_OWNERSHIP_PROOF_LEN);
+
+    let verify = |account: &[u8], pk: &[u8], proof: &[u8]| {
+        <TestVerifier as ZkVerifierTrait>::verify_pubkey_ownership(account, pk, proof)
+    };
+    assert_eq!(verify(b"alice", &pk, &proof), Ok(()));
+    assert_eq!(
+        verify(PK_OWNER_ACCOUNT, &SENDER_PK32, SENDER_PK_OWNERSHIP_PROOF),
+        Ok(())
+    );
+
+    // Replaying the proof for another account, or for another key, fails
+    assert_eq!(
+        verify(b"mallory", &pk, &proof),
+        Err(VerifyError::LinkProofInvalid)
+    );
+    let other_pk = (Scalar::from(12u64) * G).compress().to_bytes();
+    assert_eq!(
+        verify(b"alice", &other_pk, &proof),
+        Err(VerifyError::LinkProofInvalid)
+    );
+
+    // Proofs are bound to the network
+    struct OtherNetwork;
+    impl NetworkIdProvider for OtherNetwork {
+        fn network_id() -> [u8; 32] {
+            [1u8; 32]
+        }
+    }
+    assert_eq!(
+        <ZkheVerifier<OtherNetwork> as ZkVerifierTrait>::verify_pubkey_ownership(
+            b"alice", &pk, &proof
+        ),
+        Err(VerifyError::LinkProofInvalid)
+    );
+
+    // The identity key has a publicly known secret
+    let identity = RistrettoPoint::identity().compress().to_bytes();
+    assert_eq!(
+        verify(b"alice", &identity, &proof),
+        Err(VerifyError::InvalidPoint)
+    );
+
+    let mut extended = proof.clone();
+    extended.push(0);
+    assert_eq!(
+        verify(b"alice", &pk, &extended),
+        Err(VerifyError::NonCanonicalEncoding)
+    );
+    assert_eq!(
+        <TestVerifier as ZkVerifierTrait>::validate_shape(
+            confidential_assets_primitives::ProofKind::PubkeyOwnership,
+            &proof
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn cached_range_verifier_skips_repeated_proofs() {
+    use crate::{CachedRangeVerifier, ProofCache, clear_range_cache};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    struct Counting;
+    impl RangeProofVerifier for Counting {
+        fn verify_range_proof(
+            label: &[u8],
+            context: &[u8],
+            commit: &[u8; 32],
+            proof: &[u8],
+        ) -> Result<(), ()> {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            BulletproofRangeVerifier::verify_range_proof(label, context, commit, proof)
+        }
+    }
+    type Cached = ZkheVerifier<TestNetworkId, CachedRangeVerifier<Counting, 8>>;
+    type Tiny = ZkheVerifier<TestNetworkId, CachedRangeVerifier<Counting, 1>>;
+
+    fn send_via<V: ZkVerifierTrait>(
+        bundle: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), confidential_assets_primitives::VerifyError> {
+        V::verify_transfer_sent(
+            ASSET_ID_BYTES,
+            &SENDER_PK32,
+            &RECEIVER_PK32,
+            &TRANSFER_FROM_OLD_COMM_32,
+            &[],
+            &TRANSFER_DELTA_CT_64,
+            bundle,
+        )
+    }
+    let send = send_via::<Cached>;
+    let first = send(TRANSFER_BUNDLE).expect("sender bundle verifies");
+    let calls = CALLS.load(Ordering::SeqCst);
+    assert!(calls > 0);
+
+    // A replay is answered from the cache with the same result
+    assert_eq!(send(TRANSFER_BUNDLE), Ok(first.clone()));
+    assert_eq!(CALLS.load(Ordering::SeqCst), calls);
+
+    // A smaller cache is separate and does not shrink this one
+    let to_pk = PublicKeyBytes::try_from(RECEIVER_PK32.to_vec()).expect("pk bv");
+    assert!(
+        <Tiny as ZkVerifierTrait>::verify_mint(ASSET_ID_BYTES, &to_pk, &[], &[], MINT_PROOF)
+            .is_ok()
+    );
+    let calls = CALLS.load(Ordering::SeqCst);
+    assert_eq!(send(TRANSFER_BUNDLE), Ok(first.clone()));
+    assert_eq!(CALLS.load(Ordering::SeqCst), calls);
+
+    // Clearing drops the entries but not the capacity
+    clear_range_cache();
+    assert_eq!(send(TRANSFER_BUNDLE), Ok(first.clone()));
+    let calls_after_clear = CALLS.load(Ordering::SeqCst);
+    assert!(calls_after_clear > calls);
+    assert_eq!(send(TRANSFER_BUNDLE), Ok(first));
+    assert_eq!(CALLS.load(Ordering::SeqCst), calls_after_clear);
+
+    // A tampered bundle still fails
+    assert!(send(MALFORMED_TAMPERED_BUNDLE).is_err());
+
+    // LRU: touching a key keeps it past the capacity bound
+    let mut cache = ProofCache::new(2);
+    cache.insert([1; 32]);
+    cache.insert([2; 32]);
+    assert!(cache.contains(&[1; 32]));
+    cache.insert([3; 32]);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains(&[1; 32]));
+    assert!(!cache.contains(&[2; 32]));
+    cache.set_capacity(0);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn proof_system_envelope_is_checked() {
+    use confidential_assets_primitives::{ProofKind, ProofSystem, VerifyError, seal_proof};
+
+    let sealed = zkhe_primitives::seal(TRANSFER_BUNDLE);
+    assert_eq!(sealed[0], <TestVerifier as ProofSystem>::ID);
+    assert_eq!(
+        <TestVerifier as ProofSystem>::open(ProofKind::Transfer, &sealed),
+        Ok(TRANSFER_BUNDLE)
+    );
+
+    // A proof for another system never reaches the verifier
+    let foreign = seal_proof(<TestVerifier as ProofSystem>::ID + 1, TRANSFER_BUNDLE);
+    assert_eq!(
+        <TestVerifier as ProofSystem>::open(ProofKind::Transfer, &foreign),
+        Err(VerifyError::UnknownProofSystem)
+    );
+    assert_eq!(
+        <TestVerifier as ProofSystem>::open(ProofKind::Transfer, &[]),
+        Err(VerifyError::MalformedProof)
+    );
+
+    // Bodies outside the advertised bounds are rejected before the shape check
+    let (min, max) = <TestVerifier as ProofSystem>::proof_len(ProofKind::Transfer).unwrap();
+    assert!(min <= TRANSFER_BUNDLE.len() && TRANSFER_BUNDLE.len() <= max);
+    let short = zkhe_primitives::seal(&TRANSFER_BUNDLE[..min - 1]);
+    assert_eq!(
+        <TestVerifier as ProofSystem>::open(ProofKind::Transfer, &short),
+        Err(VerifyError::MalformedProof)
+    );
+    let sealed_pk = zkhe_primitives::seal(&[0u8; 64]);
+    assert!(<TestVerifier as ProofSystem>::open(ProofKind::PubkeyOwnership, &sealed_pk).is_ok());
+}
+
+#[test]
+fn ciphertext_arithmetic_matches_vectors() {
+    use confidential_assets_primitives::{Commitment, VerifyError};
+
+    // Sender: available(from) -= ΔC
+    let from_new = Commitment::new(TRANSFER_FROM_OLD_COMM_32)
+        .sub(&Commitment::new(TRANSFER_DELTA_COMM_32))
+        .expect("valid points");
+    assert_eq!(from_new, Commitment::new(TRANSFER_FROM_NEW_COMM_32));
+
+    // Mint onto empty state: pending(to) = identity + ΔC, ΔC sits after the minted ciphertext
+    let mint_delta = Commitment::try_from(&MINT_PROOF[64..96]).expect("32 bytes");
+    let to_new = Commitment::identity()
+        .add(&mint_delta)
+        .expect("valid points");
+    assert_eq!(to_new, Commitment::new(MINT_TO_NEW_COMM_32));
+
+    // Ciphertexts add and subtract component-wise
+    let minted = EncryptedAmount::new(MINTED_CT_64);
+    let delta = EncryptedAmount::new(TRANSFER_DELTA_CT_64);
+    let sum = delta.add(&minted).expect("valid ciphertexts");
+    let parts =
+        |ct: &EncryptedAmount| zkhe_primitives::Ciphertext::from_bytes(ct.as_bytes()).unwrap();
+    assert_eq!(parts(&sum).C, parts(&delta).C + parts(&minted).C);
+    assert_eq!(sum.sub(&minted), Ok(delta));
+    assert!(delta.sub(&delta).unwrap().is_zero());
+    assert_eq!(EncryptedAmount::zero().add(&delta), Ok(delta));
+
+    // Bytes that are not a point are rejected
+    let bad = Commitment::new(MALFORMED_INVALID_POINT);
+    assert_eq!(bad.add(&from_new), Err(VerifyError::InvalidPoint));
+    let bad_ct = EncryptedAmount::from_parts(MALFORMED_INVALID_POINT, delta.masked());
+    assert_eq!(bad_ct.add(&delta), Err(VerifyError::InvalidCiphertext));
+}
+
+#[test]
+fn range_bits_follow_asset_configuration() {
+    use confidential_assets_primitives::{RangeBitsProvider, VerifyError};
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_primitives::{RANGE_PROOF_LEN, range_proof_len};
+    use zkhe_prover::*;
+
+    struct Bits32;
+    impl RangeBitsProvider for Bits32 {
+        fn range_bits(_asset: &[u8]) -> u8 {
+            32
+        }
+    }
+    type Verifier32 = ZkheVerifier<TestNetworkId, BulletproofRangeVerifier, Bits32>;
+
+    let pk = Scalar::from(9u64) * G;
+    let pk_bv = PublicKeyBytes::try_from(pk.compress().to_bytes().to_vec()).expect("pk bv");
+    let id = [0u8; 0];
+    let mint_input = |mint_value| MintInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        to_pk: pk,
+        to_pending_old_c: RistrettoPoint::identity(),
+        to_pending_old_opening: (0, Scalar::ZERO),
+        total_old_c: RistrettoPoint::identity(),
+        total_old_opening: (0, Scalar::ZERO),
+        mint_value,
+        rng_seed: [3u8; 32],
+    };
+    let narrow = RangeProofConfig::default().with_bits(32);
+
+    // 32-bit mints verify for a 32-bit asset, in both encodings
+    for config in [narrow, RangeProofMode::Aggregated.into()] {
+        let config = config.with_bits(32);
+        let mint = prove_mint_with(&mint_input(500), config).expect("32-bit mint prove");
+        let (to_new, total_new, _) = <Verifier32 as ZkVerifierTrait>::verify_mint(
+            &ASSET_ID_BYTES,
+            &pk_bv,
+            &id,
+            &id,
+            &mint.proof_bytes,
+        )
+        .expect("32-bit mint verify");
+        assert_eq!(to_new.as_slice(), &mint.to_pending_new_c);
+        assert_eq!(total_new.as_slice(), &mint.total_new_c);
+
+        // ...but not for a 64-bit one
+        assert_eq!(
+            <TestVerifier as ZkVerifierTrait>::verify_mint(
+                &ASSET_ID_BYTES,
+                &pk_bv,
+                &id,
+                &id,
+                &mint.proof_bytes,
+            )
+            .unwrap_err(),
+            VerifyError::RangeProofInvalid
+        );
+    }
+
+    // Narrow proofs are smaller: 608 bytes against 672
+    let mint = prove_mint_with(&mint_input(500), narrow).expect("32-bit mint prove");
+    let wide = prove_mint(&mint_input(500)).expect("64-bit mint prove");
+    assert_eq!(
+        wide.proof_bytes.len() - mint.proof_bytes.len(),
+        2 * (RANGE_PROOF_LEN - range_proof_len(32, 1))
+    );
+
+    // 64-bit proofs are rejected for a 32-bit asset
+    assert_eq!(
+        <Verifier32 as ZkVerifierTrait>::verify_mint(
+            &ASSET_ID_BYTES,
+            &pk_bv,
+            &id,
+            &id,
+            &wide.proof_bytes,
+        )
+        .unwrap_err(),
+        VerifyError::RangeProofInvalid
+    );
+
+    // Sender proofs carry the asset's width too
+    let h = zkhe_primitives::pedersen_h_generator();
+    let r = Scalar::from(3u64);
+    let from_old_c = Scalar::from(200u64) * G + r * h;
+    let to_pk = Scalar::from(11u64) * G;
+    let sender = SenderInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        sender_pk: pk,
+        receiver_pk: to_pk,
+        from_old_c,
+        from_old_opening: (200, r),
+        to_old_c: RistrettoPoint::identity(),
+        delta_value: 50,
+        rng_seed: [5u8; 32],
+        fee_c: None,
+    };
+    let sent = prove_sender_transfer_with(&sender, 32).expect("32-bit sender prove");
+    let verify_sent = |bundle: &[u8]| {
+        <Verifier32 as ZkVerifierTrait>::verify_transfer_sent(
+            &ASSET_ID_BYTES,
+            &pk.compress().to_bytes(),
+            &to_pk.compress().to_bytes(),
+            &from_old_c.compress().to_bytes(),
+            &id,
+            &sent.delta_ct_bytes,
+            bundle,
+        )
+    };
+    let (from_new, _) = verify_sent(&sent.sender_bundle_bytes).expect("32-bit sender verify");
+    assert_eq!(from_new.as_slice(), &sent.from_new_c);
+    let wide_sent = prove_sender_transfer(&sender).expect("64-bit sender prove");
+    assert!(verify_sent(&wide_sent.sender_bundle_bytes).is_err());
+
+    // Values of 2^32 and up have no 32-bit proof; widths other than 32/64 are refused
+    assert!(matches!(
+        prove_mint_with(&mint_input(1 << 32), narrow),
+        Err(ProverError::RangeProof(_))
+    ));
+    assert!(matches!(
+        prove_mint_with(&mint_input(500), narrow.with_bits(128)),
+        Err(ProverError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn transfers_of_floored_assets_prove_the_floor() {
+    use confidential_assets_primitives::{
+        DefaultRangeBits, ProofKind, RangeBitsProvider, VerifyError,
+    };
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_primitives::validate_shape;
+    use zkhe_prover::*;
+
+    struct Floor40;
+    impl RangeBitsProvider for Floor40 {
+        fn range_bits(asset: &[u8]) -> u8 {
+            DefaultRangeBits::range_bits(asset)
+        }
+        fn transfer_floor(_asset: &[u8]) -> u64 {
+            40
+        }
+    }
+    type FloorVerifier = ZkheVerifier<TestNetworkId, BulletproofRangeVerifier, Floor40>;
+
+    let h = zkhe_primitives::pedersen_h_generator();
+    let pk = Scalar::from(9u64) * G;
+    let r = Scalar::from(3u64);
+    let from_old_c = Scalar::from(200u64) * G + r * h;
+    let to_pk = Scalar::from(11u64) * G;
+    let sender = |delta_value| SenderInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        sender_pk: pk,
+        receiver_pk: to_pk,
+        from_old_c,
+        from_old_opening: (200, r),
+        to_old_c: RistrettoPoint::identity(),
+        delta_value,
+        rng_seed: [5u8; 32],
+        fee_c: None,
+    };
+    let verify = |sent: &SenderOutput, floored: bool| {
+        let verify_transfer_sent = if floored {
+            <FloorVerifier as ZkVerifierTrait>::verify_transfer_sent
+        } else {
+            <TestVerifier as ZkVerifierTrait>::verify_transfer_sent
+        };
+        verify_transfer_sent(
+            &ASSET_ID_BYTES,
+            &pk.compress().to_bytes(),
+            &to_pk.compress().to_bytes(),
+            &from_old_c.compress().to_bytes(),
+            &[],
+            &sent.delta_ct_bytes,
+            &sent.sender_bundle_bytes,
+        )
+    };
+
+    // A floor proof is well shaped and verifies with or without a floor on the asset
+    let above = prove_sender_transfer_above(&sender(50), 64, 40).expect("floor prove");
+    assert!(validate_shape(ProofKind::Transfer, &above.sender_bundle_bytes).is_ok());
+    assert!(verify(&above, true).is_ok());
+    assert!(verify(&above, false).is_ok());
+
+    // A plain proof is rejected once the asset has a floor
+    let plain = prove_sender_transfer(&sender(50)).expect("plain prove");
+    assert!(verify(&plain, false).is_ok());
+    assert_eq!(
+        verify(&plain, true).unwrap_err(),
+        VerifyError::RangeProofInvalid
+    );
+
+    // A floor proof against a lower floor does not pass a higher one
+    let low = prove_sender_transfer_above(&sender(30), 64, 20).expect("low floor prove");
+    assert!(verify(&low, true).is_err());
+
+    // The prover refuses amounts below the floor
+    assert!(matches!(
+        prove_sender_transfer_above(&sender(30), 64, 40),
+        Err(ProverError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn split_proofs_balance_and_range_check_every_output() {
+    use confidential_assets_primitives::{
+        DefaultRangeBits, ProofKind, RangeBitsProvider, VerifyError,
+    };
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_primitives::validate_shape;
+    use zkhe_prover::*;
+
+    struct Floor40;
+    impl RangeBitsProvider for Floor40 {
+        fn range_bits(asset: &[u8]) -> u8 {
+            DefaultRangeBits::range_bits(asset)
+        }
+        fn transfer_floor(_asset: &[u8]) -> u64 {
+            40
+        }
+    }
+    type FloorVerifier = ZkheVerifier<TestNetworkId, BulletproofRangeVerifier, Floor40>;
+
+    let h = zkhe_primitives::pedersen_h_generator();
+    let pk = Scalar::from(9u64) * G;
+    let r = Scalar::from(3u64);
+    let from_old_c = Scalar::from(200u64) * G + r * h;
+    let alice_sk = Scalar::from(11u64);
+    let bob_sk = Scalar::from(13u64);
+    let split = |values: [u64; 2]| SplitInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        sender_pk: pk,
+        from_old_opening: (200, r),
+        outputs: vec![(alice_sk * G, values[0]), (bob_sk * G, values[1])],
+        rng_seed: [6u8; 32],
+    };
+    let recipients = |out: &SplitOutput| -> Vec<(PublicKeyBytes, EncryptedAmount)> {
+        [alice_sk, bob_sk]
+            .iter()
+            .zip(&out.output_ct_bytes)
+            .map(|(sk, ct)| {
+                (
+                    PublicKeyBytes::try_from((sk * G).compress().to_bytes().to_vec()).unwrap(),
+                    EncryptedAmount::new(*ct),
+                )
+            })
+            .collect()
+    };
+    let verify =
+        |out: &SplitOutput, outputs: &[(PublicKeyBytes, EncryptedAmount)], proof: &[u8]| {
+            <TestVerifier as ZkVerifierTrait>::verify_transfer_split(
+                &ASSET_ID_BYTES,
+                &pk.compress().to_bytes(),
+                &from_old_c.compress().to_bytes(),
+                &EncryptedAmount::new(out.total_ct_bytes),
+                outputs,
+                proof,
+            )
+        };
+
+    // A split verifies, debits the total and returns each output's commitment
+    let out = prove_sender_split(&split([30, 50])).expect("split prove");
+    assert!(validate_shape(ProofKind::TransferSplit, &out.proof_bytes).is_ok());
+    let outputs = recipients(&out);
+    let (from_new, out_comms) = verify(&out, &outputs, &out.proof_bytes).expect("split verify");
+    assert_eq!(from_new, out.from_new_c.to_vec());
+    assert_eq!(out_comms, out.output_comm_bytes);
+
+    // Each commitment opens to its output's value under the blinding handed to the
+    // recipient, so it can claim the deposit
+    for ((value, rho), comm) in [30u64, 50]
+        .iter()
+        .zip(&out.output_blindings)
+        .zip(&out_comms)
+    {
+        assert_eq!(
+            zkhe_primitives::point_to_bytes(&(Scalar::from(*value) * G + rho * h)),
+            *comm
+        );
+    }
+
+    // Each output decrypts under its recipient's key
+    for ((sk, value), ct) in [(alice_sk, 30u64), (bob_sk, 50)]
+        .iter()
+        .zip(&out.output_ct_bytes)
+    {
+        let ct = zkhe_primitives::Ciphertext::from_bytes(ct).expect("ct");
+        assert_eq!(ct.D - sk * ct.C, Scalar::from(*value) * G);
+    }
+
+    // Reordered, missing or foreign outputs are rejected
+    let swapped = vec![outputs[1].clone(), outputs[0].clone()];
+    assert!(verify(&out, &swapped, &out.proof_bytes).is_err());
+    assert_eq!(
+        verify(&out, &outputs[..1], &out.proof_bytes).unwrap_err(),
+        VerifyError::MalformedProof
+    );
+    let other = prove_sender_split(&split([50, 30])).expect("other prove");
+    let mixed = vec![outputs[0].clone(), recipients(&other)[1].clone()];
+    assert!(verify(&out, &mixed, &out.proof_bytes).is_err());
+
+    // Output commitments that do not add up to the total break the proof
+    let mut shifted = out.proof_bytes.clone();
+    let off = 2 + 224;
+    let c =
+        zkhe_primitives::point_from_bytes(&shifted[off..off + 32].try_into().unwrap()).unwrap() + G;
+    shifted[off..off + 32].copy_from_slice(c.compress().as_bytes());
+    assert_eq!(
+        verify(&out, &outputs, &shifted).unwrap_err(),
+        VerifyError::LinkProofInvalid
+    );
+
+    // Trailing bytes and a dropped range section are rejected
+    let mut extended = out.proof_bytes.clone();
+    extended.push(0);
+    assert!(verify(&out, &outputs, &extended).is_err());
+    assert!(validate_shape(ProofKind::TransferSplit, &extended).is_err());
+    let truncated = &out.proof_bytes[..out.proof_bytes.len() - 10];
+    assert!(verify(&out, &outputs, truncated).is_err());
+    assert!(validate_shape(ProofKind::TransferSplit, truncated).is_err());
+
+    // A split over the balance cannot be proven
+    assert!(matches!(
+        prove_sender_split(&split([150, 60])),
+        Err(ProverError::Overflow(_))
+    ));
+
+    // Floored assets need every output above the floor
+    let verify_floored = |out: &SplitOutput| {
+        <FloorVerifier as ZkVerifierTrait>::verify_transfer_split(
+            &ASSET_ID_BYTES,
+            &pk.compress().to_bytes(),
+            &from_old_c.compress().to_bytes(),
+            &EncryptedAmount::new(out.total_ct_bytes),
+            &recipients(out),
+            &out.proof_bytes,
+        )
+    };
+    assert_eq!(
+        verify_floored(&out).unwrap_err(),
+        VerifyError::RangeProofInvalid
+    );
+    let above = prove_sender_split_above(&split([45, 50]), 64, 40).expect("floor prove");
+    assert!(verify_floored(&above).is_ok());
+    assert!(matches!(
+        prove_sender_split_above(&split([30, 50]), 64, 40),
+        Err(ProverError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn seizure_ties_the_disclosed_amount_to_the_balance() {
+    use confidential_assets_primitives::{ProofKind, ProofSystem, VerifyError, seal_proof};
+    use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT as G, scalar::Scalar};
+    use zkhe_primitives::{Ciphertext, validate_shape};
+    use zkhe_prover::{ProverError, SeizureInput, prove_seizure};
+
+    let h = zkhe_primitives::pedersen_h_generator();
+    let auditor_sk = Scalar::from(31u64);
+    let auditor_pk = auditor_sk * G;
+    let blind = Scalar::from(8u64);
+    let avail = Scalar::from(70u64) * G + blind * h;
+    let encrypt = |value: u64| {
+        let k = Scalar::from(5u64);
+        Ciphertext {
+            C: k * G,
+            D: Scalar::from(value) * G + k * auditor_pk,
+        }
+        .to_bytes()
+    };
+    // What the chain tracks for the balance: its blinding under the auditor key
+    let encrypt_blind = |r: Scalar| {
+        let k = Scalar::from(6u64);
+        Ciphertext {
+            C: k * G,
+            D: r * h + k * auditor_pk,
+        }
+        .to_bytes()
+    };
+    let input = SeizureInput {
+        asset_id: ASSET_ID_BYTES.to_vec(),
+        network_id: [0u8; 32],
+        auditor_sk,
+        avail: avail.compress().to_bytes(),
+        blinding: encrypt_blind(blind),
+        ct: encrypt(70),
+        value: 70,
+        rng_seed: [7u8; 32],
+    };
+    let proof = prove_seizure(&input).expect("seizure prove");
+    assert!(validate_shape(ProofKind::Seizure, &proof).is_ok());
+
+    let verify = |auditor: &RistrettoPoint,
+                  avail: &RistrettoPoint,
+                  blinding: [u8; 64],
+                  ct: [u8; 64],
+                  proof: &[u8]| {
+        <TestVerifier as ZkVerifierTrait>::verify_seizure(
+            &ASSET_ID_BYTES,
+            &auditor.compress().to_bytes(),
+            &avail.compress().to_bytes(),
+            &EncryptedAmount::new(blinding),
+            &EncryptedAmount::new(ct),
+            proof,
+        )
+    };
+    let tracked = encrypt_blind(blind);
+
+    // The sealed proof opens and discloses the balance's amount
+    let sealed = seal_proof(<TestVerifier as ProofSystem>::ID, &proof);
+    let body = <TestVerifier as ProofSystem>::open(ProofKind::Seizure, &sealed).expect("open");
+    assert_eq!(
+        verify(&auditor_pk, &avail, tracked, encrypt(70), body),
+        Ok(70)
+    );
+
+    // A balance that moved since, or another holder's, does not match the proof
+    let moved = Scalar::from(71u64) * G + blind * h;
+    assert_eq!(
+        verify(&auditor_pk, &moved, tracked, encrypt(70), &proof),
+        Err(VerifyError::LinkProofInvalid)
+    );
+    let reblinded = Scalar::from(70u64) * G + Scalar::from(9u64) * h;
+    assert!(verify(&auditor_pk, &reblinded, tracked, encrypt(70), &proof).is_err());
+    assert!(
+        verify(
+            &auditor_pk,
+            &avail,
+            encrypt_blind(Scalar::from(9u64)),
+            encrypt(70),
+            &proof
+        )
+        .is_err()
+    );
+
+    // Nor does a ciphertext for another amount or another auditor key
+    assert!(verify(&auditor_pk, &avail, tracked, encrypt(71), &proof).is_err());
+    assert!(
+        verify(
+            &(Scalar::from(32u64) * G),
+            &avail,
+            tracked,
+            encrypt(70),
+            &proof
+        )
+        .is_err()
+    );
+
+    // Disclosing another amount breaks the proof
+    let mut inflated = proof.clone();
+    inflated[..8].copy_from_slice(&700u64.to_le_bytes());
+    assert!(verify(&auditor_pk, &avail, tracked, encrypt(70), &inflated).is_err());
+    let mut extended = proof.clone();
+    extended.push(0);
+    assert!(verify(&auditor_pk, &avail, tracked, encrypt(70), &extended).is_err());
+
+    // The prover refuses a ciphertext or a balance that does not hold the value
+    assert!(matches!(
+        prove_seizure(&SeizureInput {
+            asset_id: input.asset_id.clone(),
+            ct: encrypt(69),
+            ..input
+        }),
+        Err(ProverError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        prove_seizure(&SeizureInput {
+            avail: moved.compress().to_bytes(),
+            ..input
+        }),
+        Err(ProverError::InvalidInput(_))
+    ));
+}